@@ -0,0 +1,220 @@
+//! IndexedBlock - Shielded pool data extracted from one finalized block
+
+use frame_system::{EventRecord, Phase};
+use pallet_shielded_pool::Event as PoolEvent;
+use pallet_shielded_pool_rpc::{map_pool_event, ShieldedEvent, ShieldedEventType};
+use scale_codec::{Decode, Encode};
+use sp_core::H256;
+
+use crate::error::IndexerError;
+
+/// Audit and selective-disclosure event of the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEvent {
+	pub block_number: u64,
+	pub extrinsic_index: u32,
+	/// Event variant, e.g. `DisclosureRequested`
+	pub kind: &'static str,
+	/// SCALE-encoded pallet event
+	pub event: Vec<u8>,
+}
+
+/// New note of the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexedCommitment {
+	pub leaf_index: u32,
+	pub commitment: String,
+	pub memo: Option<String>,
+}
+
+/// Pool activity of one finalized block.
+#[derive(Debug, Clone, Default)]
+pub struct IndexedBlock {
+	pub number: u64,
+	pub hash: H256,
+	/// Shield, transfer and unshield events, in emission order
+	pub events: Vec<ShieldedEvent>,
+	pub audit_events: Vec<AuditEvent>,
+}
+
+impl IndexedBlock {
+	/// Extracts the pool events of block `number` from its raw
+	/// `System::Events` storage (`None` if the block has no events).
+	pub fn decode<R>(
+		number: u64,
+		hash: H256,
+		raw_events: Option<&[u8]>,
+	) -> Result<Self, IndexerError>
+	where
+		R: pallet_shielded_pool::Config,
+		<R as frame_system::Config>::RuntimeEvent: TryInto<PoolEvent<R>>,
+	{
+		let mut block = Self {
+			number,
+			hash,
+			..Default::default()
+		};
+		let Some(mut raw_events) = raw_events else {
+			return Ok(block);
+		};
+
+		type Records<R> = Vec<
+			EventRecord<
+				<R as frame_system::Config>::RuntimeEvent,
+				<R as frame_system::Config>::Hash,
+			>,
+		>;
+		let records =
+			Records::<R>::decode(&mut raw_events).map_err(|error| IndexerError::Decode {
+				block: number,
+				error,
+			})?;
+
+		for record in records {
+			// Pool events are only emitted while applying extrinsics
+			let Phase::ApplyExtrinsic(extrinsic_index) = record.phase else {
+				continue;
+			};
+			let Ok(event) = TryInto::<PoolEvent<R>>::try_into(record.event) else {
+				continue;
+			};
+
+			if let Some(kind) = audit_kind(&event) {
+				block.audit_events.push(AuditEvent {
+					block_number: number,
+					extrinsic_index,
+					kind,
+					event: event.encode(),
+				});
+			} else if let Some(event_type) = map_pool_event(event) {
+				block.events.push(ShieldedEvent {
+					block_number: number,
+					extrinsic_index,
+					event_type,
+				});
+			}
+		}
+
+		Ok(block)
+	}
+
+	/// Notes added to the tree by this block, in leaf order
+	pub fn commitments(&self) -> Vec<IndexedCommitment> {
+		let mut commitments = Vec::new();
+		for event in &self.events {
+			match &event.event_type {
+				ShieldedEventType::Shield {
+					commitment,
+					leaf_index,
+					encrypted_memo,
+					..
+				} => commitments.push(IndexedCommitment {
+					leaf_index: *leaf_index,
+					commitment: commitment.clone(),
+					memo: encrypted_memo.clone(),
+				}),
+				ShieldedEventType::PrivateTransfer {
+					commitments: outputs,
+					leaf_indices,
+					encrypted_memos,
+					..
+				} => {
+					let mut memos = encrypted_memos.iter().flatten();
+					commitments.extend(leaf_indices.iter().zip(outputs).map(
+						|(leaf_index, commitment)| IndexedCommitment {
+							leaf_index: *leaf_index,
+							commitment: commitment.clone(),
+							memo: memos.next().cloned(),
+						},
+					));
+				}
+				ShieldedEventType::Unshield { .. } => {}
+			}
+		}
+		commitments
+	}
+
+	/// Nullifiers spent in this block, in emission order
+	pub fn nullifiers(&self) -> Vec<String> {
+		self.events
+			.iter()
+			.flat_map(|event| match &event.event_type {
+				ShieldedEventType::PrivateTransfer { nullifiers, .. } => nullifiers.clone(),
+				ShieldedEventType::Unshield { nullifier, .. } => vec![nullifier.clone()],
+				ShieldedEventType::Shield { .. } => Vec::new(),
+			})
+			.collect()
+	}
+}
+
+/// Variant name of an audit or disclosure event, `None` for other events
+fn audit_kind<R: pallet_shielded_pool::Config>(event: &PoolEvent<R>) -> Option<&'static str> {
+	match event {
+		PoolEvent::AuditPolicySet { .. } => Some("AuditPolicySet"),
+		PoolEvent::DisclosureVerifyingKeyUpdated { .. } => Some("DisclosureVerifyingKeyUpdated"),
+		PoolEvent::DisclosureSubmitted { .. } => Some("DisclosureSubmitted"),
+		PoolEvent::DisclosureVerified { .. } => Some("DisclosureVerified"),
+		PoolEvent::AuditTrailRecorded { .. } => Some("AuditTrailRecorded"),
+		PoolEvent::DisclosureRequested { .. } => Some("DisclosureRequested"),
+		PoolEvent::DisclosureApproved { .. } => Some("DisclosureApproved"),
+		PoolEvent::DisclosureRejected { .. } => Some("DisclosureRejected"),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn event(event_type: ShieldedEventType) -> ShieldedEvent {
+		ShieldedEvent {
+			block_number: 7,
+			extrinsic_index: 1,
+			event_type,
+		}
+	}
+
+	#[test]
+	fn should_collect_commitments_and_nullifiers() {
+		let block = IndexedBlock {
+			number: 7,
+			events: vec![
+				event(ShieldedEventType::Shield {
+					depositor: "0x01".into(),
+					asset_id: 0,
+					amount: 100,
+					commitment: "0xaa".into(),
+					leaf_index: 4,
+					encrypted_memo: Some("0xm1".into()),
+				}),
+				event(ShieldedEventType::PrivateTransfer {
+					nullifiers: vec!["0xn1".into(), "0xn2".into()],
+					commitments: vec!["0xbb".into(), "0xcc".into()],
+					leaf_indices: vec![5, 6],
+					encrypted_memos: None,
+				}),
+				event(ShieldedEventType::Unshield {
+					nullifier: "0xn3".into(),
+					asset_id: 0,
+					amount: 50,
+					recipient: "0x02".into(),
+				}),
+			],
+			..Default::default()
+		};
+
+		let commitments = block.commitments();
+		assert_eq!(
+			commitments
+				.iter()
+				.map(|c| (c.leaf_index, c.commitment.as_str(), c.memo.as_deref()))
+				.collect::<Vec<_>>(),
+			vec![
+				(4, "0xaa", Some("0xm1")),
+				(5, "0xbb", None),
+				(6, "0xcc", None)
+			]
+		);
+		assert_eq!(block.nullifiers(), vec!["0xn1", "0xn2", "0xn3"]);
+	}
+}
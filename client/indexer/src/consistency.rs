@@ -0,0 +1,247 @@
+//! ConsistencyReport - Comparison of the indexed tree with the chain
+
+use jsonrpsee::{
+	core::client::ClientT,
+	rpc_params,
+	ws_client::{WsClient, WsClientBuilder},
+};
+use pallet_shielded_pool::{
+	infrastructure::merkle_tree::compute_root_from_leaves_poseidon, Hash, DEFAULT_TREE_DEPTH,
+};
+use scale_codec::Decode;
+use serde::{Deserialize, Serialize};
+use sp_core::{
+	bytes::{from_hex, to_hex},
+	hashing::twox_128,
+	storage::StorageKey,
+	Bytes, H256,
+};
+
+use crate::{db::IndexerDb, error::IndexerError};
+
+/// Merkle tree state of the chain at one block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainTree {
+	/// Canonical hash at the block's height, `None` if the node has none
+	pub block_hash: Option<H256>,
+	/// `ShieldedPool::PoseidonRoot`
+	pub root: Hash,
+	/// `ShieldedPool::MerkleTreeSize`
+	pub tree_size: u32,
+}
+
+/// Result of recomputing the root from the indexed leaves and comparing it
+/// with the chain at the last indexed block.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConsistencyReport {
+	/// Last indexed block, where both sides are compared
+	pub block_number: u64,
+	pub block_hash: H256,
+	pub consistent: bool,
+	/// Root recomputed from the indexed leaves
+	pub indexed_root: String,
+	pub chain_root: String,
+	pub indexed_leaves: u32,
+	pub chain_leaves: u32,
+	/// Divergences found, empty when `consistent`
+	pub divergences: Vec<String>,
+}
+
+impl ConsistencyReport {
+	/// Compares the indexed leaves of block `block_number`, ordered by leaf
+	/// index, with the chain tree at that block.
+	pub fn compare(
+		block_number: u64,
+		block_hash: H256,
+		leaves: &[(u32, String)],
+		chain: ChainTree,
+	) -> Self {
+		let mut divergences = Vec::new();
+		if chain.block_hash != Some(block_hash) {
+			divergences.push(match chain.block_hash {
+				Some(hash) => format!("Block #{block_number} is not canonical, chain has {hash:?}"),
+				None => format!("Block #{block_number} is unknown to the node"),
+			});
+		}
+
+		let mut commitments = Vec::with_capacity(leaves.len());
+		for (expected, (leaf_index, commitment)) in (0u32..).zip(leaves) {
+			if *leaf_index != expected {
+				divergences.push(format!("Leaf {expected} is missing from the index"));
+				break;
+			}
+			match from_hex(commitment)
+				.ok()
+				.and_then(|bytes| bytes.try_into().ok())
+			{
+				Some(commitment) => commitments.push(commitment),
+				None => {
+					divergences.push(format!("Leaf {leaf_index} has an invalid commitment"));
+					break;
+				}
+			}
+		}
+
+		let indexed_leaves = leaves.len() as u32;
+		if indexed_leaves != chain.tree_size {
+			divergences.push(format!(
+				"Index has {indexed_leaves} leaves, chain has {}",
+				chain.tree_size
+			));
+		}
+		let indexed_root = compute_root_from_leaves_poseidon::<DEFAULT_TREE_DEPTH>(&commitments);
+		if indexed_root != chain.root {
+			divergences.push("Recomputed root differs from PoseidonRoot".into());
+		}
+
+		Self {
+			block_number,
+			block_hash,
+			consistent: divergences.is_empty(),
+			indexed_root: to_hex(&indexed_root, false),
+			chain_root: to_hex(&chain.root, false),
+			indexed_leaves,
+			chain_leaves: chain.tree_size,
+			divergences,
+		}
+	}
+}
+
+/// Checks the last indexed block against the node at `node_url`, `None` if
+/// nothing is indexed yet.
+///
+/// Reads every indexed leaf and recomputes the root, so it costs one full
+/// tree rebuild.
+pub async fn check_consistency(
+	db: &IndexerDb,
+	node_url: &str,
+) -> Result<Option<ConsistencyReport>, IndexerError> {
+	let Some(block_number) = db.last_indexed_block().await? else {
+		return Ok(None);
+	};
+	let block_hash = db
+		.block_hash(block_number)
+		.await?
+		.ok_or(IndexerError::BlockNotFound(block_number))?;
+	let leaves = db.leaves(block_number).await?;
+
+	let node = WsClientBuilder::default().build(node_url).await?;
+	let chain = chain_tree(&node, block_number, block_hash).await?;
+
+	Ok(Some(ConsistencyReport::compare(
+		block_number,
+		block_hash,
+		&leaves,
+		chain,
+	)))
+}
+
+/// Tree state of the node at `block_hash`, with the canonical hash of
+/// `block_number`.
+async fn chain_tree(
+	node: &WsClient,
+	block_number: u64,
+	block_hash: H256,
+) -> Result<ChainTree, IndexerError> {
+	let canonical: Option<H256> = node
+		.request("chain_getBlockHash", rpc_params![block_number])
+		.await?;
+	let root: Option<Bytes> = node
+		.request(
+			"state_getStorage",
+			rpc_params![pool_storage_key(b"PoseidonRoot"), block_hash],
+		)
+		.await?;
+	let tree_size: Option<Bytes> = node
+		.request(
+			"state_getStorage",
+			rpc_params![pool_storage_key(b"MerkleTreeSize"), block_hash],
+		)
+		.await?;
+
+	// Both are `ValueQuery`, so absent means default
+	let decode = |error| IndexerError::Decode {
+		block: block_number,
+		error,
+	};
+	Ok(ChainTree {
+		block_hash: canonical,
+		root: root
+			.map(|bytes| Hash::decode(&mut &bytes[..]))
+			.transpose()
+			.map_err(decode)?
+			.unwrap_or_default(),
+		tree_size: tree_size
+			.map(|bytes| u32::decode(&mut &bytes[..]))
+			.transpose()
+			.map_err(decode)?
+			.unwrap_or_default(),
+	})
+}
+
+/// Storage key of the `ShieldedPool` storage value `item`
+fn pool_storage_key(item: &[u8]) -> StorageKey {
+	StorageKey([twox_128(b"ShieldedPool"), twox_128(item)].concat())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn leaves(count: u8) -> Vec<(u32, String)> {
+		(0..count)
+			.map(|i| (i as u32, to_hex(&[i + 1; 32], false)))
+			.collect()
+	}
+
+	fn chain(leaves: &[(u32, String)]) -> ChainTree {
+		let commitments: Vec<Hash> = leaves
+			.iter()
+			.map(|(_, c)| from_hex(c).unwrap().try_into().unwrap())
+			.collect();
+		ChainTree {
+			block_hash: Some(H256::repeat_byte(7)),
+			root: compute_root_from_leaves_poseidon::<DEFAULT_TREE_DEPTH>(&commitments),
+			tree_size: leaves.len() as u32,
+		}
+	}
+
+	#[test]
+	fn should_report_matching_tree_as_consistent() {
+		let leaves = leaves(3);
+		let report = ConsistencyReport::compare(5, H256::repeat_byte(7), &leaves, chain(&leaves));
+
+		assert!(report.consistent, "{:?}", report.divergences);
+		assert_eq!(report.indexed_root, report.chain_root);
+		assert_eq!(report.indexed_leaves, 3);
+	}
+
+	#[test]
+	fn should_report_missing_leaves_and_root_mismatch() {
+		let all = leaves(3);
+		let mut indexed = all.clone();
+		indexed.remove(1);
+
+		let report = ConsistencyReport::compare(5, H256::repeat_byte(7), &indexed, chain(&all));
+
+		assert!(!report.consistent);
+		assert_eq!(
+			report.divergences,
+			vec![
+				"Leaf 1 is missing from the index",
+				"Index has 2 leaves, chain has 3",
+				"Recomputed root differs from PoseidonRoot",
+			]
+		);
+	}
+
+	#[test]
+	fn should_report_reorged_block() {
+		let leaves = leaves(1);
+		let report = ConsistencyReport::compare(5, H256::repeat_byte(9), &leaves, chain(&leaves));
+
+		assert!(!report.consistent);
+		assert_eq!(report.divergences.len(), 1);
+		assert!(report.divergences[0].starts_with("Block #5 is not canonical"));
+	}
+}
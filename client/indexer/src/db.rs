@@ -0,0 +1,388 @@
+//! IndexerDb - SQLite store of the indexed pool activity
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use pallet_shielded_pool_rpc::{CompactBlock, CompactOutput, ShieldedEvent};
+use sp_core::H256;
+use sqlx::{
+	sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions},
+	Row,
+};
+
+use crate::{block::IndexedBlock, error::IndexerError};
+
+/// SQLite store of the indexed blocks.
+///
+/// Every indexed block has a row in `blocks`, whether or not it has pool
+/// activity, so the highest row is where indexing resumes.
+#[derive(Clone)]
+pub struct IndexerDb {
+	pool: SqlitePool,
+}
+
+impl IndexerDb {
+	/// Opens (or creates) the database at `path`; `:memory:` for an
+	/// in-memory database.
+	pub async fn open(path: &str) -> Result<Self, IndexerError> {
+		let options = SqliteConnectOptions::from_str(&format!("sqlite:{path}"))?
+			.create_if_missing(true)
+			// https://www.sqlite.org/wal.html
+			.journal_mode(SqliteJournalMode::Wal);
+		// An in-memory database only lives as long as its one connection
+		let max_connections = if path == ":memory:" { 1 } else { 8 };
+		let pool = SqlitePoolOptions::new()
+			.max_connections(max_connections)
+			.connect_with(options)
+			.await?;
+
+		let db = Self { pool };
+		db.create_tables_if_not_exist().await?;
+		Ok(db)
+	}
+
+	/// Highest indexed block, `None` for an empty database
+	pub async fn last_indexed_block(&self) -> Result<Option<u64>, IndexerError> {
+		let row = sqlx::query("SELECT MAX(block_number) FROM blocks")
+			.fetch_one(&self.pool)
+			.await?;
+		Ok(row
+			.try_get::<Option<i64>, _>(0)?
+			.map(|number| number as u64))
+	}
+
+	/// Hash of indexed block `number`
+	pub async fn block_hash(&self, number: u64) -> Result<Option<H256>, IndexerError> {
+		let row = sqlx::query("SELECT block_hash FROM blocks WHERE block_number = ?")
+			.bind(number as i64)
+			.fetch_optional(&self.pool)
+			.await?;
+		row.map(|row| {
+			let hash: Vec<u8> = row.try_get(0)?;
+			Ok(H256::from_slice(&hash))
+		})
+		.transpose()
+	}
+
+	/// Leaf index and commitment of every leaf added up to `to_block`,
+	/// ordered by leaf index
+	pub async fn leaves(&self, to_block: u64) -> Result<Vec<(u32, String)>, IndexerError> {
+		let rows = sqlx::query(
+			"SELECT leaf_index, commitment FROM commitments
+			WHERE block_number <= ? ORDER BY leaf_index",
+		)
+		.bind(to_block as i64)
+		.fetch_all(&self.pool)
+		.await?;
+
+		rows.into_iter()
+			.map(|row| Ok((row.try_get(0)?, row.try_get(1)?)))
+			.collect()
+	}
+
+	/// Stores `block` and everything it changed in one transaction.
+	pub async fn insert_block(&self, block: &IndexedBlock) -> Result<(), IndexerError> {
+		let number = block.number as i64;
+		let mut tx = self.pool.begin().await?;
+
+		sqlx::query("INSERT INTO blocks(block_number, block_hash) VALUES (?, ?)")
+			.bind(number)
+			.bind(block.hash.as_bytes())
+			.execute(&mut *tx)
+			.await?;
+
+		for event in &block.events {
+			sqlx::query(
+				"INSERT INTO events(block_number, extrinsic_index, event) VALUES (?, ?, ?)",
+			)
+			.bind(number)
+			.bind(event.extrinsic_index)
+			.bind(serde_json::to_string(&event.event_type)?)
+			.execute(&mut *tx)
+			.await?;
+		}
+		for commitment in block.commitments() {
+			sqlx::query(
+				"INSERT INTO commitments(leaf_index, commitment, memo, block_number)
+				VALUES (?, ?, ?, ?)",
+			)
+			.bind(commitment.leaf_index)
+			.bind(commitment.commitment)
+			.bind(commitment.memo)
+			.bind(number)
+			.execute(&mut *tx)
+			.await?;
+		}
+		for nullifier in block.nullifiers() {
+			sqlx::query("INSERT INTO nullifiers(nullifier, block_number) VALUES (?, ?)")
+				.bind(nullifier)
+				.bind(number)
+				.execute(&mut *tx)
+				.await?;
+		}
+		for audit in &block.audit_events {
+			sqlx::query(
+				"INSERT INTO audit_events(block_number, extrinsic_index, kind, event)
+				VALUES (?, ?, ?, ?)",
+			)
+			.bind(number)
+			.bind(audit.extrinsic_index)
+			.bind(audit.kind)
+			.bind(&audit.event)
+			.execute(&mut *tx)
+			.await?;
+		}
+
+		tx.commit().await?;
+		Ok(())
+	}
+
+	/// Pool events of blocks `from_block..=to_block`, in emission order
+	pub async fn events(
+		&self,
+		from_block: u64,
+		to_block: u64,
+	) -> Result<Vec<ShieldedEvent>, IndexerError> {
+		let rows = sqlx::query(
+			"SELECT block_number, extrinsic_index, event FROM events
+			WHERE block_number BETWEEN ? AND ? ORDER BY id",
+		)
+		.bind(from_block as i64)
+		.bind(to_block as i64)
+		.fetch_all(&self.pool)
+		.await?;
+
+		rows.into_iter()
+			.map(|row| -> Result<_, IndexerError> {
+				Ok(ShieldedEvent {
+					block_number: row.try_get::<i64, _>(0)? as u64,
+					extrinsic_index: row.try_get(1)?,
+					event_type: serde_json::from_str(row.try_get(2)?)?,
+				})
+			})
+			.collect()
+	}
+
+	/// Compact blocks of `from_block..=to_block`; blocks without pool
+	/// activity are left out.
+	pub async fn compact_blocks(
+		&self,
+		from_block: u64,
+		to_block: u64,
+	) -> Result<Vec<CompactBlock<H256>>, IndexerError> {
+		let (from, to) = (from_block as i64, to_block as i64);
+		let mut blocks = BTreeMap::new();
+
+		let hashes = sqlx::query(
+			"SELECT block_number, block_hash FROM blocks WHERE block_number BETWEEN ? AND ?",
+		)
+		.bind(from)
+		.bind(to)
+		.fetch_all(&self.pool)
+		.await?
+		.into_iter()
+		.map(|row| {
+			let hash: Vec<u8> = row.try_get(1)?;
+			Ok((row.try_get::<i64, _>(0)?, H256::from_slice(&hash)))
+		})
+		.collect::<Result<BTreeMap<_, _>, sqlx::Error>>()?;
+
+		let outputs = sqlx::query(
+			"SELECT block_number, leaf_index, commitment, memo FROM commitments
+			WHERE block_number BETWEEN ? AND ? ORDER BY leaf_index",
+		)
+		.bind(from)
+		.bind(to)
+		.fetch_all(&self.pool)
+		.await?;
+		for row in outputs {
+			compact_block(&mut blocks, &hashes, row.try_get(0)?)
+				.outputs
+				.push(CompactOutput {
+					leaf_index: row.try_get(1)?,
+					commitment: row.try_get(2)?,
+					memo: row.try_get(3)?,
+				});
+		}
+
+		let nullifiers = sqlx::query(
+			"SELECT block_number, nullifier FROM nullifiers
+			WHERE block_number BETWEEN ? AND ? ORDER BY rowid",
+		)
+		.bind(from)
+		.bind(to)
+		.fetch_all(&self.pool)
+		.await?;
+		for row in nullifiers {
+			compact_block(&mut blocks, &hashes, row.try_get(0)?)
+				.nullifiers
+				.push(row.try_get(1)?);
+		}
+
+		Ok(blocks.into_values().collect())
+	}
+
+	/// Creates the tables and indices if they do not exist yet.
+	async fn create_tables_if_not_exist(&self) -> Result<(), IndexerError> {
+		sqlx::query(
+			"BEGIN;
+			CREATE TABLE IF NOT EXISTS blocks (
+				block_number INTEGER PRIMARY KEY,
+				block_hash BLOB NOT NULL
+			);
+			CREATE TABLE IF NOT EXISTS events (
+				id INTEGER PRIMARY KEY,
+				block_number INTEGER NOT NULL,
+				extrinsic_index INTEGER NOT NULL,
+				event TEXT NOT NULL
+			);
+			CREATE TABLE IF NOT EXISTS commitments (
+				leaf_index INTEGER PRIMARY KEY,
+				commitment TEXT NOT NULL,
+				memo TEXT,
+				block_number INTEGER NOT NULL
+			);
+			CREATE TABLE IF NOT EXISTS nullifiers (
+				nullifier TEXT PRIMARY KEY,
+				block_number INTEGER NOT NULL
+			);
+			CREATE TABLE IF NOT EXISTS audit_events (
+				id INTEGER PRIMARY KEY,
+				block_number INTEGER NOT NULL,
+				extrinsic_index INTEGER NOT NULL,
+				kind TEXT NOT NULL,
+				event BLOB NOT NULL
+			);
+			CREATE INDEX IF NOT EXISTS events_block_idx ON events (block_number);
+			CREATE INDEX IF NOT EXISTS commitments_block_idx ON commitments (block_number);
+			CREATE INDEX IF NOT EXISTS nullifiers_block_idx ON nullifiers (block_number);
+			CREATE INDEX IF NOT EXISTS audit_events_block_idx ON audit_events (block_number);
+			COMMIT;",
+		)
+		.execute(&self.pool)
+		.await?;
+		Ok(())
+	}
+}
+
+/// Entry of block `number` in `blocks`, created empty on first use
+fn compact_block<'a>(
+	blocks: &'a mut BTreeMap<i64, CompactBlock<H256>>,
+	hashes: &BTreeMap<i64, H256>,
+	number: i64,
+) -> &'a mut CompactBlock<H256> {
+	blocks.entry(number).or_insert_with(|| CompactBlock {
+		block_number: number as u64,
+		block_hash: hashes.get(&number).copied().unwrap_or_default(),
+		outputs: Vec::new(),
+		nullifiers: Vec::new(),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use pallet_shielded_pool_rpc::ShieldedEventType;
+
+	fn block(number: u64, events: Vec<ShieldedEventType>) -> IndexedBlock {
+		IndexedBlock {
+			number,
+			hash: H256::repeat_byte(number as u8),
+			events: events
+				.into_iter()
+				.map(|event_type| ShieldedEvent {
+					block_number: number,
+					extrinsic_index: 1,
+					event_type,
+				})
+				.collect(),
+			audit_events: Vec::new(),
+		}
+	}
+
+	fn shield(commitment: &str, leaf_index: u32) -> ShieldedEventType {
+		ShieldedEventType::Shield {
+			depositor: "0x01".into(),
+			asset_id: 0,
+			amount: 100,
+			commitment: commitment.into(),
+			leaf_index,
+			encrypted_memo: Some("0x6d656d6f".into()),
+		}
+	}
+
+	#[tokio::test]
+	async fn should_resume_after_last_indexed_block() {
+		let db = IndexerDb::open(":memory:")
+			.await
+			.expect("database must open");
+		assert_eq!(db.last_indexed_block().await.expect("query"), None);
+
+		db.insert_block(&block(0, Vec::new()))
+			.await
+			.expect("insert");
+		db.insert_block(&block(1, Vec::new()))
+			.await
+			.expect("insert");
+
+		assert_eq!(db.last_indexed_block().await.expect("query"), Some(1));
+		assert!(db.insert_block(&block(1, Vec::new())).await.is_err());
+	}
+
+	#[tokio::test]
+	async fn should_serve_events_and_compact_blocks() {
+		let db = IndexerDb::open(":memory:")
+			.await
+			.expect("database must open");
+		db.insert_block(&block(1, vec![shield("0xaa", 0)]))
+			.await
+			.expect("insert");
+		db.insert_block(&block(2, Vec::new()))
+			.await
+			.expect("insert");
+		db.insert_block(&block(
+			3,
+			vec![ShieldedEventType::PrivateTransfer {
+				nullifiers: vec!["0xn1".into()],
+				commitments: vec!["0xbb".into(), "0xcc".into()],
+				leaf_indices: vec![1, 2],
+				encrypted_memos: None,
+			}],
+		))
+		.await
+		.expect("insert");
+
+		let events = db.events(1, 3).await.expect("query");
+		assert_eq!(
+			events.iter().map(|e| e.block_number).collect::<Vec<_>>(),
+			vec![1, 3]
+		);
+
+		let blocks = db.compact_blocks(1, 3).await.expect("query");
+		assert_eq!(blocks.len(), 2);
+		assert_eq!(blocks[0].block_hash, H256::repeat_byte(1));
+		assert_eq!(blocks[0].outputs[0].commitment, "0xaa");
+		assert_eq!(blocks[0].outputs[0].memo.as_deref(), Some("0x6d656d6f"));
+		assert_eq!(
+			blocks[1]
+				.outputs
+				.iter()
+				.map(|o| o.leaf_index)
+				.collect::<Vec<_>>(),
+			vec![1, 2]
+		);
+		assert_eq!(blocks[1].nullifiers, vec!["0xn1"]);
+
+		assert!(db.compact_blocks(2, 2).await.expect("query").is_empty());
+
+		assert_eq!(
+			db.leaves(3).await.expect("query"),
+			vec![(0, "0xaa".into()), (1, "0xbb".into()), (2, "0xcc".into())]
+		);
+		assert_eq!(db.leaves(2).await.expect("query"), vec![(0, "0xaa".into())]);
+		assert_eq!(
+			db.block_hash(2).await.expect("query"),
+			Some(H256::repeat_byte(2))
+		);
+		assert_eq!(db.block_hash(4).await.expect("query"), None);
+	}
+}
@@ -0,0 +1,27 @@
+//! IndexerError - Errors of the indexer
+
+/// Errors of the indexer.
+#[derive(Debug, thiserror::Error)]
+pub enum IndexerError {
+	/// The database query failed.
+	#[error("Database error: {0}")]
+	Database(#[from] sqlx::Error),
+	/// The node RPC call failed.
+	#[error("Node RPC error: {0}")]
+	Node(#[from] jsonrpsee::core::ClientError),
+	/// The events of a block could not be decoded.
+	#[error("Failed to decode events of block {block}: {error}")]
+	Decode {
+		block: u64,
+		error: scale_codec::Error,
+	},
+	/// A stored event could not be (de)serialized.
+	#[error("Invalid stored event: {0}")]
+	Json(#[from] serde_json::Error),
+	/// The node does not know a finalized block.
+	#[error("Block {0} not found")]
+	BlockNotFound(u64),
+	/// The node follow subscription ended.
+	#[error("Finalized head subscription closed")]
+	SubscriptionClosed,
+}
@@ -0,0 +1,111 @@
+//! Follower - Indexes the finalized blocks of a node
+
+use std::marker::PhantomData;
+
+use jsonrpsee::{
+	core::client::{ClientT, Subscription, SubscriptionClientT},
+	rpc_params,
+	ws_client::{WsClient, WsClientBuilder},
+};
+use pallet_shielded_pool::Event as PoolEvent;
+use serde::Deserialize;
+use sp_core::{hashing::twox_128, storage::StorageKey, Bytes, H256, U256};
+
+use crate::{block::IndexedBlock, db::IndexerDb, error::IndexerError};
+
+/// Number of the head announced by `chain_subscribeFinalizedHeads`.
+#[derive(Deserialize)]
+struct FinalizedHead {
+	number: U256,
+}
+
+/// Follows the finalized heads of a node over WebSocket and indexes every
+/// finalized block, in order, into an [`IndexerDb`].
+///
+/// Only finalized blocks are indexed, so the database never has to roll
+/// back. Each block's events are read at that block while it is still
+/// recent, which a pruned node can serve.
+pub struct Follower<R> {
+	node: WsClient,
+	db: IndexerDb,
+	/// First block indexed into an empty database
+	start_block: u64,
+	_marker: PhantomData<R>,
+}
+
+impl<R> Follower<R>
+where
+	R: pallet_shielded_pool::Config,
+	<R as frame_system::Config>::RuntimeEvent: TryInto<PoolEvent<R>>,
+{
+	/// Connects to the node WebSocket RPC at `url`.
+	pub async fn connect(url: &str, db: IndexerDb, start_block: u64) -> Result<Self, IndexerError> {
+		Ok(Self {
+			node: WsClientBuilder::default().build(url).await?,
+			db,
+			start_block,
+			_marker: PhantomData,
+		})
+	}
+
+	/// Indexes the finalized blocks missing from the database, then every
+	/// newly finalized block. Only returns on error.
+	pub async fn run(self) -> Result<(), IndexerError> {
+		let mut heads: Subscription<FinalizedHead> = self
+			.node
+			.subscribe(
+				"chain_subscribeFinalizedHeads",
+				rpc_params![],
+				"chain_unsubscribeFinalizedHeads",
+			)
+			.await?;
+		let mut next = match self.db.last_indexed_block().await? {
+			Some(last) => last + 1,
+			None => self.start_block,
+		};
+		log::info!(target: "orbinum-indexer", "Indexing finalized blocks from #{next}");
+
+		while let Some(head) = heads.next().await {
+			let finalized = head?.number.low_u64();
+			while next <= finalized {
+				self.index_block(next).await?;
+				next += 1;
+			}
+		}
+
+		Err(IndexerError::SubscriptionClosed)
+	}
+
+	/// Indexes finalized block `number`.
+	async fn index_block(&self, number: u64) -> Result<(), IndexerError> {
+		let hash: Option<H256> = self
+			.node
+			.request("chain_getBlockHash", rpc_params![number])
+			.await?;
+		let hash = hash.ok_or(IndexerError::BlockNotFound(number))?;
+		let raw_events: Option<Bytes> = self
+			.node
+			.request("state_getStorage", rpc_params![system_events_key(), hash])
+			.await?;
+
+		let block = IndexedBlock::decode::<R>(number, hash, raw_events.as_deref())?;
+		self.db.insert_block(&block).await?;
+
+		if block.events.is_empty() && block.audit_events.is_empty() {
+			log::debug!(target: "orbinum-indexer", "Indexed block #{number}");
+		} else {
+			log::info!(
+				target: "orbinum-indexer",
+				"Indexed block #{number}: {} pool events, {} audit events",
+				block.events.len(),
+				block.audit_events.len(),
+			);
+		}
+		Ok(())
+	}
+}
+
+/// Storage key of `System::Events`
+fn system_events_key() -> StorageKey {
+	StorageKey([twox_128(b"System"), twox_128(b"Events")].concat())
+}
@@ -0,0 +1,29 @@
+//! Orbinum Indexer - Off-chain index of the shielded pool
+//!
+//! Follows the finalized blocks of a node, keeps the pool's commitments,
+//! memos, nullifiers, events and audit events in SQLite and serves the
+//! wallet sync endpoints (`orbinum_v2_getCompactBlocks`,
+//! `orbinum_v2_scanEvents`) from there, so wallets can sync without an
+//! archive node.
+//!
+//! ## Architecture
+//!
+//! - [`Follower`]: subscribes to finalized heads and indexes each block
+//! - [`IndexedBlock`]: pool data decoded from a block's `System::Events`
+//! - [`IndexerDb`]: SQLite store of the indexed blocks
+//! - [`Indexer`]: JSON-RPC server of the scan endpoints
+//! - [`ConsistencyReport`]: indexed tree checked against the chain's root
+
+pub mod block;
+pub mod consistency;
+pub mod db;
+pub mod error;
+pub mod follower;
+pub mod rpc;
+
+pub use block::{AuditEvent, IndexedBlock, IndexedCommitment};
+pub use consistency::{ChainTree, ConsistencyReport};
+pub use db::IndexerDb;
+pub use error::IndexerError;
+pub use follower::Follower;
+pub use rpc::{Indexer, IndexerApiServer};
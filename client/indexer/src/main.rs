@@ -0,0 +1,74 @@
+//! orbinum-indexer - Indexes the shielded pool of an Orbinum node into SQLite
+//! and serves wallet sync from it
+
+use std::{net::SocketAddr, time::Duration};
+
+use clap::Parser;
+use jsonrpsee::server::Server;
+use orbinum_indexer::{Follower, Indexer, IndexerApiServer, IndexerDb, IndexerError};
+use orbinum_runtime::Runtime;
+
+/// Delay before reconnecting to the node after the follower failed.
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+	/// WebSocket RPC endpoint of the node to follow.
+	#[arg(long, default_value = "ws://127.0.0.1:9944")]
+	node_url: String,
+
+	/// SQLite database file.
+	#[arg(long, default_value = "orbinum-indexer.db")]
+	database: String,
+
+	/// Address the indexer RPC server listens on.
+	#[arg(long, default_value = "127.0.0.1:9955")]
+	rpc_addr: SocketAddr,
+
+	/// First block indexed into an empty database. Set it to a block the node
+	/// still has state for when following a pruned node.
+	#[arg(long, default_value = "0")]
+	start_block: u64,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+	let cli = Cli::parse();
+
+	let db = IndexerDb::open(&cli.database).await?;
+	let server = Server::builder().build(cli.rpc_addr).await?;
+	let handle = server.start(Indexer::new(db.clone(), cli.node_url.clone()).into_rpc());
+	log::info!(target: "orbinum-indexer", "RPC server listening on {}", cli.rpc_addr);
+
+	tokio::select! {
+		_ = follow(&cli.node_url, db, cli.start_block) => {}
+		_ = tokio::signal::ctrl_c() => {}
+	}
+
+	handle.stop()?;
+	handle.stopped().await;
+	Ok(())
+}
+
+/// Runs the follower, reconnecting whenever it fails.
+async fn follow(node_url: &str, db: IndexerDb, start_block: u64) {
+	loop {
+		let result: Result<(), IndexerError> = async {
+			Follower::<Runtime>::connect(node_url, db.clone(), start_block)
+				.await?
+				.run()
+				.await
+		}
+		.await;
+		if let Err(e) = result {
+			log::warn!(
+				target: "orbinum-indexer",
+				"Following {node_url} failed: {e}; reconnecting in {}s",
+				RECONNECT_DELAY.as_secs(),
+			);
+		}
+		tokio::time::sleep(RECONNECT_DELAY).await;
+	}
+}
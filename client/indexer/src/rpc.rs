@@ -0,0 +1,309 @@
+//! IndexerApi - Wallet sync endpoints served from the indexer database
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
+use pallet_shielded_pool_rpc::{
+	CompactBlockPage, DeprecationLog, ShieldedEvent, ShieldedEventPage, DEFAULT_SCAN_LIMIT,
+	MAX_SCAN_BLOCKS, MAX_SCAN_LIMIT,
+};
+use sp_core::H256;
+
+use crate::{
+	consistency::{check_consistency, ConsistencyReport},
+	db::IndexerDb,
+};
+
+/// Scan endpoints of `pallet-shielded-pool-rpc`, answered from the indexer
+/// database instead of node state.
+///
+/// Pages follow the node endpoints, but only cover finalized blocks
+/// indexed so far. As on the node, the `shieldedPool_` names are deprecated
+/// aliases of the `orbinum_v2_` methods.
+#[rpc(server)]
+pub trait IndexerApi {
+	/// Compact blocks of `from_block..=to_block`.
+	///
+	/// Reads at most [`MAX_SCAN_BLOCKS`] blocks; `next_block` tells where to
+	/// continue.
+	#[method(name = "orbinum_v2_getCompactBlocks")]
+	async fn get_compact_blocks(
+		&self,
+		from_block: u64,
+		to_block: u64,
+	) -> RpcResult<CompactBlockPage<H256>>;
+
+	/// Deprecated alias of `orbinum_v2_getCompactBlocks`.
+	#[method(name = "shieldedPool_getCompactBlocks")]
+	async fn get_compact_blocks_deprecated(
+		&self,
+		from_block: u64,
+		to_block: u64,
+	) -> RpcResult<CompactBlockPage<H256>>;
+
+	/// Shielded pool events of blocks `from_block..=to_block`.
+	///
+	/// Reads at most [`MAX_SCAN_BLOCKS`] blocks and stops after the block that
+	/// reaches `limit` events; `next_block` tells where to continue.
+	#[method(name = "orbinum_v2_scanEvents")]
+	async fn scan_events(
+		&self,
+		from_block: u64,
+		to_block: u64,
+		limit: Option<u32>,
+	) -> RpcResult<ShieldedEventPage>;
+
+	/// Deprecated alias of `orbinum_v2_scanEvents`.
+	#[method(name = "shieldedPool_scanEvents")]
+	async fn scan_events_deprecated(
+		&self,
+		from_block: u64,
+		to_block: u64,
+		limit: Option<u32>,
+	) -> RpcResult<ShieldedEventPage>;
+
+	/// Highest indexed block, `None` before the first block is indexed
+	#[method(name = "orbinum_v2_lastIndexedBlock")]
+	async fn last_indexed_block(&self) -> RpcResult<Option<u64>>;
+
+	/// Deprecated alias of `orbinum_v2_lastIndexedBlock`.
+	#[method(name = "shieldedPool_lastIndexedBlock")]
+	async fn last_indexed_block_deprecated(&self) -> RpcResult<Option<u64>>;
+
+	/// Recomputes the root from the indexed leaves and compares it with the
+	/// node's `PoseidonRoot` at the last indexed block, `None` before the
+	/// first block is indexed.
+	#[method(name = "orbinum_v2_checkConsistency")]
+	async fn check_consistency(&self) -> RpcResult<Option<ConsistencyReport>>;
+}
+
+/// Indexer RPC handler.
+pub struct Indexer {
+	db: IndexerDb,
+	/// WebSocket RPC endpoint of the node consistency checks compare against
+	node_url: String,
+	/// Usage log of the deprecated `shieldedPool_` aliases
+	deprecations: DeprecationLog,
+}
+
+impl Indexer {
+	/// Creates a new `Indexer`.
+	pub fn new(db: IndexerDb, node_url: String) -> Self {
+		Self {
+			db,
+			node_url,
+			deprecations: DeprecationLog::new(),
+		}
+	}
+
+	/// Last block of `from_block..=to_block` a page may read and the last
+	/// block of the whole range that is indexed, `None` if nothing is.
+	async fn page_bounds(&self, from_block: u64, to_block: u64) -> RpcResult<Option<(u64, u64)>> {
+		if from_block > to_block {
+			return Err(rpc_error("from_block must not exceed to_block"));
+		}
+		let Some(indexed) = self.db.last_indexed_block().await.map_err(rpc_error)? else {
+			return Ok(None);
+		};
+
+		let last = to_block.min(indexed);
+		let bound = from_block.saturating_add(MAX_SCAN_BLOCKS - 1).min(last);
+		Ok(Some((bound, last)))
+	}
+}
+
+#[jsonrpsee::core::async_trait]
+impl IndexerApiServer for Indexer {
+	async fn get_compact_blocks(
+		&self,
+		from_block: u64,
+		to_block: u64,
+	) -> RpcResult<CompactBlockPage<H256>> {
+		let Some((bound, last)) = self.page_bounds(from_block, to_block).await? else {
+			return Ok(CompactBlockPage {
+				blocks: Vec::new(),
+				next_block: None,
+			});
+		};
+
+		let blocks = if from_block <= bound {
+			self.db
+				.compact_blocks(from_block, bound)
+				.await
+				.map_err(rpc_error)?
+		} else {
+			Vec::new()
+		};
+
+		Ok(CompactBlockPage {
+			blocks,
+			next_block: (bound < last).then_some(bound + 1),
+		})
+	}
+
+	async fn get_compact_blocks_deprecated(
+		&self,
+		from_block: u64,
+		to_block: u64,
+	) -> RpcResult<CompactBlockPage<H256>> {
+		self.deprecations.record(
+			"shieldedPool_getCompactBlocks",
+			"orbinum_v2_getCompactBlocks",
+		);
+		self.get_compact_blocks(from_block, to_block).await
+	}
+
+	async fn scan_events(
+		&self,
+		from_block: u64,
+		to_block: u64,
+		limit: Option<u32>,
+	) -> RpcResult<ShieldedEventPage> {
+		let limit = limit.unwrap_or(DEFAULT_SCAN_LIMIT);
+		if limit == 0 || limit > MAX_SCAN_LIMIT {
+			return Err(rpc_error(format!(
+				"limit must be between 1 and {MAX_SCAN_LIMIT}"
+			)));
+		}
+		let Some((bound, last)) = self.page_bounds(from_block, to_block).await? else {
+			return Ok(ShieldedEventPage {
+				events: Vec::new(),
+				next_block: None,
+			});
+		};
+		if from_block > bound {
+			return Ok(ShieldedEventPage {
+				events: Vec::new(),
+				next_block: None,
+			});
+		}
+
+		let mut events: Vec<ShieldedEvent> = Vec::new();
+		let mut next = bound + 1;
+		for event in self.db.events(from_block, bound).await.map_err(rpc_error)? {
+			// Pages end on a block boundary
+			let new_block = events
+				.last()
+				.is_some_and(|last| last.block_number != event.block_number);
+			if new_block && events.len() >= limit as usize {
+				next = event.block_number;
+				break;
+			}
+			events.push(event);
+		}
+
+		Ok(ShieldedEventPage {
+			events,
+			next_block: (next <= last).then_some(next),
+		})
+	}
+
+	async fn scan_events_deprecated(
+		&self,
+		from_block: u64,
+		to_block: u64,
+		limit: Option<u32>,
+	) -> RpcResult<ShieldedEventPage> {
+		self.deprecations
+			.record("shieldedPool_scanEvents", "orbinum_v2_scanEvents");
+		self.scan_events(from_block, to_block, limit).await
+	}
+
+	async fn last_indexed_block(&self) -> RpcResult<Option<u64>> {
+		self.db.last_indexed_block().await.map_err(rpc_error)
+	}
+
+	async fn last_indexed_block_deprecated(&self) -> RpcResult<Option<u64>> {
+		self.deprecations.record(
+			"shieldedPool_lastIndexedBlock",
+			"orbinum_v2_lastIndexedBlock",
+		);
+		self.last_indexed_block().await
+	}
+
+	async fn check_consistency(&self) -> RpcResult<Option<ConsistencyReport>> {
+		check_consistency(&self.db, &self.node_url)
+			.await
+			.map_err(rpc_error)
+	}
+}
+
+fn rpc_error(message: impl ToString) -> ErrorObjectOwned {
+	ErrorObjectOwned::owned(1, message.to_string(), None::<()>)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::block::IndexedBlock;
+	use pallet_shielded_pool_rpc::ShieldedEventType;
+
+	async fn indexer(blocks: u64) -> Indexer {
+		let db = IndexerDb::open(":memory:")
+			.await
+			.expect("database must open");
+		for number in 0..blocks {
+			// Two unshields in every odd block
+			let events = if number % 2 == 1 {
+				(0..2)
+					.map(|i| ShieldedEvent {
+						block_number: number,
+						extrinsic_index: i,
+						event_type: ShieldedEventType::Unshield {
+							nullifier: format!("0x{number:02x}{i:02x}"),
+							asset_id: 0,
+							amount: 1,
+							recipient: "0x01".into(),
+						},
+					})
+					.collect()
+			} else {
+				Vec::new()
+			};
+			db.insert_block(&IndexedBlock {
+				number,
+				hash: H256::repeat_byte(number as u8),
+				events,
+				audit_events: Vec::new(),
+			})
+			.await
+			.expect("insert");
+		}
+		Indexer::new(db, "ws://127.0.0.1:9944".into())
+	}
+
+	#[tokio::test]
+	async fn should_page_events_on_block_boundaries() {
+		let indexer = indexer(6).await;
+
+		let page = indexer.scan_events(0, 10, Some(3)).await.expect("scan");
+		assert_eq!(
+			page.events
+				.iter()
+				.map(|e| e.block_number)
+				.collect::<Vec<_>>(),
+			vec![1, 1, 3, 3]
+		);
+		assert_eq!(page.next_block, Some(5));
+
+		let page = indexer.scan_events(5, 10, Some(3)).await.expect("scan");
+		assert_eq!(page.events.len(), 2);
+		assert_eq!(page.next_block, None);
+	}
+
+	#[tokio::test]
+	async fn should_limit_compact_blocks_to_indexed_range() {
+		let indexer = indexer(4).await;
+
+		let page = indexer.get_compact_blocks(0, 100).await.expect("page");
+		assert_eq!(
+			page.blocks
+				.iter()
+				.map(|b| b.block_number)
+				.collect::<Vec<_>>(),
+			vec![1, 3]
+		);
+		assert_eq!(page.next_block, None);
+		assert_eq!(indexer.last_indexed_block().await.expect("status"), Some(3));
+
+		assert!(indexer.get_compact_blocks(2, 1).await.is_err());
+	}
+}
@@ -0,0 +1,48 @@
+//! Circuit - Circuits the service can prove
+
+use serde::{Deserialize, Serialize};
+
+/// Circuit of a proof job, named like its key file.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Hash,
+	Serialize,
+	Deserialize
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Circuit {
+	/// Private transfer (2 inputs, 2 outputs)
+	Transfer,
+	/// Viewing key ownership (selective disclosure)
+	ViewingKeyOwnership,
+}
+
+impl Circuit {
+	/// All circuits, in listing order.
+	pub const ALL: [Circuit; 2] = [Circuit::Transfer, Circuit::ViewingKeyOwnership];
+
+	/// Name used in requests and key file names.
+	pub fn name(&self) -> &'static str {
+		match self {
+			Circuit::Transfer => "transfer",
+			Circuit::ViewingKeyOwnership => "viewing_key_ownership",
+		}
+	}
+
+	/// File the circuit's proving key is stored in.
+	pub fn key_file(&self) -> String {
+		format!("{}.pk", self.name())
+	}
+}
+
+impl core::fmt::Display for Circuit {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(self.name())
+	}
+}
@@ -0,0 +1,29 @@
+//! ServiceError - Errors of the proving service
+
+use crate::circuit::Circuit;
+
+/// Errors of the proving service.
+#[derive(Debug, thiserror::Error)]
+pub enum ServiceError {
+	/// The proof request is malformed or its witness is inconsistent.
+	#[error("Invalid request: {0}")]
+	InvalidRequest(String),
+	/// No proving key is installed for the circuit.
+	#[error("No proving key for circuit {0}")]
+	KeyNotFound(Circuit),
+	/// A key file does not hold a valid proving key.
+	#[error("Invalid proving key: {0}")]
+	InvalidKey(String),
+	/// The job queue is at capacity.
+	#[error("Job queue is full")]
+	QueueFull,
+	/// The job is unknown or was pruned.
+	#[error("Job {0} not found")]
+	JobNotFound(u64),
+	/// Reading or writing a key file failed.
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
+	/// Proving failed.
+	#[error("Proving failed: {0}")]
+	Prover(String),
+}
@@ -0,0 +1,295 @@
+//! JobQueue - Bounded proof queue served by worker threads
+//!
+//! Proving is CPU-bound and takes seconds per proof, so jobs run on plain
+//! threads rather than the RPC runtime. Clients submit a request, get a job
+//! id and poll it; finished jobs are kept until [`MAX_FINISHED_JOBS`] newer
+//! ones have finished.
+
+use std::{
+	collections::{BTreeMap, VecDeque},
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		mpsc::{self, Receiver, SyncSender, TrySendError},
+		Arc, Mutex, MutexGuard, PoisonError,
+	},
+	thread,
+	time::Instant,
+};
+
+use orbinum_zk_circuits::application::prover::{ProverRng, SerializedProof};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	error::ServiceError,
+	keys::KeyStore,
+	request::{ProofJob, ProofRequest},
+};
+
+/// Finished jobs kept for polling.
+pub const MAX_FINISHED_JOBS: usize = 1024;
+
+/// Proof in pallet byte layout, hex encoded.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofResult {
+	/// `0x`-prefixed compressed Groth16 proof
+	pub proof: String,
+	/// `0x`-prefixed 32-byte little-endian public inputs, in schema order
+	pub public_inputs: Vec<String>,
+}
+
+impl From<SerializedProof> for ProofResult {
+	fn from(proof: SerializedProof) -> Self {
+		Self {
+			proof: format!("0x{}", hex::encode(proof.proof)),
+			public_inputs: proof
+				.public_inputs
+				.iter()
+				.map(|input| format!("0x{}", hex::encode(input)))
+				.collect(),
+		}
+	}
+}
+
+/// State of a proof job.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum JobStatus {
+	Queued,
+	Running,
+	Done { proof: ProofResult },
+	Failed { error: String },
+}
+
+impl JobStatus {
+	fn is_finished(&self) -> bool {
+		matches!(self, JobStatus::Done { .. } | JobStatus::Failed { .. })
+	}
+}
+
+/// Load of the queue.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueueStatus {
+	pub queued: usize,
+	pub running: usize,
+	pub workers: usize,
+	/// Jobs accepted before submissions are refused
+	pub max_queue: usize,
+}
+
+#[derive(Default)]
+struct Jobs {
+	statuses: BTreeMap<u64, JobStatus>,
+	/// Finished job ids, oldest first
+	finished: VecDeque<u64>,
+}
+
+/// Proof job queue.
+pub struct JobQueue {
+	sender: SyncSender<(u64, ProofJob)>,
+	jobs: Arc<Mutex<Jobs>>,
+	keys: Arc<KeyStore>,
+	next_id: AtomicU64,
+	workers: usize,
+	max_queue: usize,
+}
+
+impl JobQueue {
+	/// Starts `workers` proving threads behind a queue of `max_queue` jobs.
+	pub fn start(keys: Arc<KeyStore>, workers: usize, max_queue: usize) -> Self {
+		let workers = workers.max(1);
+		let (sender, receiver) = mpsc::sync_channel(max_queue);
+		let receiver = Arc::new(Mutex::new(receiver));
+		let jobs = Arc::new(Mutex::new(Jobs::default()));
+
+		for worker in 0..workers {
+			let (receiver, jobs, keys) = (receiver.clone(), jobs.clone(), keys.clone());
+			thread::Builder::new()
+				.name(format!("prover-worker-{worker}"))
+				.spawn(move || run_worker(&receiver, &jobs, &keys))
+				.expect("failed to spawn prover worker");
+		}
+
+		Self {
+			sender,
+			jobs,
+			keys,
+			next_id: AtomicU64::new(1),
+			workers,
+			max_queue,
+		}
+	}
+
+	/// Checks `request` and queues it, returning its job id.
+	pub fn submit(&self, request: ProofRequest) -> Result<u64, ServiceError> {
+		let circuit = request.circuit();
+		if !self.keys.contains(circuit) {
+			return Err(ServiceError::KeyNotFound(circuit));
+		}
+		let job = request.into_job()?;
+
+		let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+		lock(&self.jobs).statuses.insert(id, JobStatus::Queued);
+		if let Err(e) = self.sender.try_send((id, job)) {
+			lock(&self.jobs).statuses.remove(&id);
+			return Err(match e {
+				TrySendError::Full(_) => ServiceError::QueueFull,
+				TrySendError::Disconnected(_) => ServiceError::Prover("workers stopped".into()),
+			});
+		}
+
+		log::debug!(target: "prover-service", "Queued {circuit} job {id}");
+		Ok(id)
+	}
+
+	/// State of job `id`.
+	pub fn status(&self, id: u64) -> Result<JobStatus, ServiceError> {
+		lock(&self.jobs)
+			.statuses
+			.get(&id)
+			.cloned()
+			.ok_or(ServiceError::JobNotFound(id))
+	}
+
+	/// Load of the queue.
+	pub fn queue_status(&self) -> QueueStatus {
+		let jobs = lock(&self.jobs);
+		let count = |status: &JobStatus| jobs.statuses.values().filter(|s| *s == status).count();
+		QueueStatus {
+			queued: count(&JobStatus::Queued),
+			running: count(&JobStatus::Running),
+			workers: self.workers,
+			max_queue: self.max_queue,
+		}
+	}
+}
+
+fn run_worker(receiver: &Mutex<Receiver<(u64, ProofJob)>>, jobs: &Mutex<Jobs>, keys: &KeyStore) {
+	let mut rng = ProverRng::os();
+	loop {
+		// The guard is dropped before proving, so idle workers keep receiving
+		let Ok((id, job)) = lock(receiver).recv() else {
+			return;
+		};
+		set_status(jobs, id, JobStatus::Running);
+
+		let circuit = job.circuit();
+		let started = Instant::now();
+		let result = keys
+			.proving_key(circuit)
+			.and_then(|pk| job.prove(&pk, &mut rng));
+
+		let status = match result {
+			Ok(proof) => {
+				log::info!(
+					target: "prover-service",
+					"Proved {circuit} job {id} in {}ms",
+					started.elapsed().as_millis(),
+				);
+				JobStatus::Done {
+					proof: proof.into(),
+				}
+			}
+			Err(e) => {
+				log::warn!(target: "prover-service", "{circuit} job {id} failed: {e}");
+				JobStatus::Failed {
+					error: e.to_string(),
+				}
+			}
+		};
+		set_status(jobs, id, status);
+	}
+}
+
+fn set_status(jobs: &Mutex<Jobs>, id: u64, status: JobStatus) {
+	let mut jobs = lock(jobs);
+	let finished = status.is_finished();
+	jobs.statuses.insert(id, status);
+
+	if finished {
+		jobs.finished.push_back(id);
+		while jobs.finished.len() > MAX_FINISHED_JOBS {
+			if let Some(oldest) = jobs.finished.pop_front() {
+				jobs.statuses.remove(&oldest);
+			}
+		}
+	}
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+	mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{circuit::Circuit, keys::tests::write_viewing_key_pk};
+	use orbinum_zk_circuits::{
+		application::circuits::viewing_key::viewing_key_hash_native, Bn254Fr,
+	};
+	use orbinum_zk_core::FieldElement;
+	use std::time::Duration;
+
+	fn viewing_key_request(viewing_key: u64) -> ProofRequest {
+		serde_json::from_value(serde_json::json!({
+			"circuit": "viewing_key_ownership",
+			"viewing_key": FieldElement::from_u64(viewing_key).to_hex(),
+			"commitment": "0x2a",
+		}))
+		.unwrap()
+	}
+
+	fn wait_for(queue: &JobQueue, id: u64) -> JobStatus {
+		for _ in 0..600 {
+			let status = queue.status(id).unwrap();
+			if status.is_finished() {
+				return status;
+			}
+			thread::sleep(Duration::from_millis(100));
+		}
+		panic!("job {id} did not finish");
+	}
+
+	#[test]
+	fn should_prove_submitted_job() {
+		let dir = tempfile::tempdir().unwrap();
+		let source = write_viewing_key_pk(dir.path());
+		let keys = Arc::new(KeyStore::open(dir.path().join("keys")).unwrap());
+		keys.import(Circuit::ViewingKeyOwnership, &source).unwrap();
+		let queue = JobQueue::start(keys, 1, 4);
+
+		let id = queue.submit(viewing_key_request(9)).unwrap();
+		let JobStatus::Done { proof } = wait_for(&queue, id) else {
+			panic!("job {id} failed");
+		};
+
+		let expected_hash = viewing_key_hash_native(Bn254Fr::from(9u64));
+		assert_eq!(
+			proof.public_inputs,
+			vec![
+				format!(
+					"0x{}",
+					hex::encode(FieldElement::new(expected_hash).to_le_bytes())
+				),
+				format!(
+					"0x{}",
+					hex::encode(FieldElement::from_u64(42).to_le_bytes())
+				),
+			]
+		);
+		assert!(proof.proof.len() > 2);
+		assert_eq!(queue.queue_status().queued, 0);
+	}
+
+	#[test]
+	fn should_reject_job_without_key() {
+		let dir = tempfile::tempdir().unwrap();
+		let keys = Arc::new(KeyStore::open(dir.path()).unwrap());
+		let queue = JobQueue::start(keys, 1, 4);
+
+		assert!(matches!(
+			queue.submit(viewing_key_request(9)),
+			Err(ServiceError::KeyNotFound(Circuit::ViewingKeyOwnership))
+		));
+		assert!(matches!(queue.status(1), Err(ServiceError::JobNotFound(1))));
+	}
+}
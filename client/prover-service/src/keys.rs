@@ -0,0 +1,209 @@
+//! KeyStore - Proving keys kept on disk
+//!
+//! One compressed arkworks proving key per circuit (`<circuit>.pk`, see
+//! `prover::serialize_pk`). Keys are validated when imported and loaded into
+//! memory on first use, so a restart does not pay for circuits nobody proves.
+
+use std::{
+	collections::BTreeMap,
+	fs, io,
+	path::{Path, PathBuf},
+	sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use ark_bn254::Bn254;
+use ark_groth16::ProvingKey;
+use orbinum_zk_circuits::application::prover::deserialize_pk;
+use serde::{Deserialize, Serialize};
+
+use crate::{circuit::Circuit, error::ServiceError};
+
+/// Installed proving key.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyInfo {
+	pub circuit: Circuit,
+	/// Key file
+	pub path: String,
+	pub size_bytes: u64,
+	/// Whether the key is loaded in memory
+	pub loaded: bool,
+}
+
+/// Directory of proving keys.
+pub struct KeyStore {
+	dir: PathBuf,
+	loaded: Mutex<BTreeMap<Circuit, Arc<ProvingKey<Bn254>>>>,
+}
+
+impl KeyStore {
+	/// Opens the key directory, creating it if missing.
+	pub fn open(dir: impl Into<PathBuf>) -> Result<Self, ServiceError> {
+		let dir = dir.into();
+		fs::create_dir_all(&dir)?;
+		Ok(Self {
+			dir,
+			loaded: Mutex::new(BTreeMap::new()),
+		})
+	}
+
+	/// Key file of `circuit`.
+	pub fn path(&self, circuit: Circuit) -> PathBuf {
+		self.dir.join(circuit.key_file())
+	}
+
+	/// Whether a key is installed for `circuit`.
+	pub fn contains(&self, circuit: Circuit) -> bool {
+		self.path(circuit).is_file()
+	}
+
+	/// Proving key of `circuit`, loaded from disk on first use.
+	pub fn proving_key(&self, circuit: Circuit) -> Result<Arc<ProvingKey<Bn254>>, ServiceError> {
+		if let Some(pk) = self.cache().get(&circuit) {
+			return Ok(pk.clone());
+		}
+
+		// Loading takes seconds for large circuits; don't hold the cache meanwhile
+		let bytes = read_key(&self.path(circuit), circuit)?;
+		let pk = Arc::new(parse_key(&bytes)?);
+		log::info!(target: "prover-service", "Loaded {circuit} proving key");
+
+		Ok(self.cache().entry(circuit).or_insert(pk).clone())
+	}
+
+	/// Validates the key at `source` and installs it for `circuit`,
+	/// replacing any previous key.
+	pub fn import(&self, circuit: Circuit, source: &Path) -> Result<KeyInfo, ServiceError> {
+		let bytes = fs::read(source)?;
+		let pk = parse_key(&bytes)?;
+
+		// Write next to the target and rename, so workers never read a partial key
+		let path = self.path(circuit);
+		let tmp = path.with_extension("pk.tmp");
+		fs::write(&tmp, &bytes)?;
+		fs::rename(&tmp, &path)?;
+		self.cache().insert(circuit, Arc::new(pk));
+		log::info!(target: "prover-service", "Imported {circuit} proving key from {}", source.display());
+
+		self.info(circuit)?
+			.ok_or(ServiceError::KeyNotFound(circuit))
+	}
+
+	/// Uninstalls the key of `circuit`.
+	pub fn remove(&self, circuit: Circuit) -> Result<(), ServiceError> {
+		self.cache().remove(&circuit);
+		fs::remove_file(self.path(circuit)).map_err(|e| match e.kind() {
+			io::ErrorKind::NotFound => ServiceError::KeyNotFound(circuit),
+			_ => e.into(),
+		})
+	}
+
+	/// Installed keys.
+	pub fn list(&self) -> Result<Vec<KeyInfo>, ServiceError> {
+		let mut keys = Vec::new();
+		for circuit in Circuit::ALL {
+			keys.extend(self.info(circuit)?);
+		}
+		Ok(keys)
+	}
+
+	fn info(&self, circuit: Circuit) -> Result<Option<KeyInfo>, ServiceError> {
+		let path = self.path(circuit);
+		let metadata = match fs::metadata(&path) {
+			Ok(metadata) => metadata,
+			Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+			Err(e) => return Err(e.into()),
+		};
+
+		Ok(Some(KeyInfo {
+			circuit,
+			path: path.display().to_string(),
+			size_bytes: metadata.len(),
+			loaded: self.cache().contains_key(&circuit),
+		}))
+	}
+
+	fn cache(&self) -> MutexGuard<'_, BTreeMap<Circuit, Arc<ProvingKey<Bn254>>>> {
+		self.loaded.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+}
+
+fn read_key(path: &Path, circuit: Circuit) -> Result<Vec<u8>, ServiceError> {
+	fs::read(path).map_err(|e| match e.kind() {
+		io::ErrorKind::NotFound => ServiceError::KeyNotFound(circuit),
+		_ => e.into(),
+	})
+}
+
+fn parse_key(bytes: &[u8]) -> Result<ProvingKey<Bn254>, ServiceError> {
+	deserialize_pk(bytes).map_err(|e| ServiceError::InvalidKey(format!("{e:?}")))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use super::*;
+	use orbinum_zk_circuits::application::{
+		circuits::viewing_key::ViewingKeyOwnershipCircuit,
+		prover::{serialize_pk, setup, ProverRng},
+	};
+
+	/// Viewing key ownership proving key, written to `dir/source.pk`.
+	pub(crate) fn write_viewing_key_pk(dir: &Path) -> PathBuf {
+		let mut rng = ProverRng::deterministic(7);
+		let (pk, _) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+		let path = dir.join("source.pk");
+		fs::write(&path, serialize_pk(&pk).unwrap()).unwrap();
+		path
+	}
+
+	#[test]
+	fn should_import_list_and_remove_key() {
+		let dir = tempfile::tempdir().unwrap();
+		let source = write_viewing_key_pk(dir.path());
+		let store = KeyStore::open(dir.path().join("keys")).unwrap();
+
+		assert!(store.list().unwrap().is_empty());
+
+		let info = store.import(Circuit::ViewingKeyOwnership, &source).unwrap();
+		assert_eq!(info.circuit, Circuit::ViewingKeyOwnership);
+		assert_eq!(info.size_bytes, fs::metadata(&source).unwrap().len());
+		assert!(info.loaded);
+		assert_eq!(store.list().unwrap(), vec![info]);
+
+		store.remove(Circuit::ViewingKeyOwnership).unwrap();
+		assert!(store.list().unwrap().is_empty());
+		assert!(matches!(
+			store.remove(Circuit::ViewingKeyOwnership),
+			Err(ServiceError::KeyNotFound(Circuit::ViewingKeyOwnership))
+		));
+	}
+
+	#[test]
+	fn should_load_installed_key_on_first_use() {
+		let dir = tempfile::tempdir().unwrap();
+		let source = write_viewing_key_pk(dir.path());
+		fs::copy(&source, dir.path().join("viewing_key_ownership.pk")).unwrap();
+		let store = KeyStore::open(dir.path()).unwrap();
+
+		assert!(!store.list().unwrap()[0].loaded);
+		store.proving_key(Circuit::ViewingKeyOwnership).unwrap();
+		assert!(store.list().unwrap()[0].loaded);
+		assert!(matches!(
+			store.proving_key(Circuit::Transfer),
+			Err(ServiceError::KeyNotFound(Circuit::Transfer))
+		));
+	}
+
+	#[test]
+	fn should_reject_invalid_key() {
+		let dir = tempfile::tempdir().unwrap();
+		let source = dir.path().join("garbage.pk");
+		fs::write(&source, [1u8; 64]).unwrap();
+		let store = KeyStore::open(dir.path().join("keys")).unwrap();
+
+		assert!(matches!(
+			store.import(Circuit::Transfer, &source),
+			Err(ServiceError::InvalidKey(_))
+		));
+		assert!(!store.contains(Circuit::Transfer));
+	}
+}
@@ -0,0 +1,31 @@
+//! Orbinum Prover Service - Delegated Groth16 proving
+//!
+//! Lets wallets that cannot prove locally (mobile, browser) hand the witness
+//! of a transaction to a machine their user controls. The service keeps the
+//! proving keys on disk, queues proof jobs on a fixed pool of worker threads
+//! and returns proofs in the byte layout `pallet-zk-verifier` expects.
+//!
+//! Witnesses contain spending keys: the service never logs them, keeps them
+//! in memory only until their job is proven, and should only be reachable
+//! by its user (loopback or an authenticated tunnel).
+//!
+//! ## Architecture
+//!
+//! - [`KeyStore`]: proving keys on disk, loaded on first use
+//! - [`ProofRequest`]: witness of one proof, as submitted over RPC
+//! - [`JobQueue`]: bounded queue and worker threads proving the requests
+//! - [`Prover`]: JSON-RPC server of the `prover_*` endpoints
+
+pub mod circuit;
+pub mod error;
+pub mod jobs;
+pub mod keys;
+pub mod request;
+pub mod rpc;
+
+pub use circuit::Circuit;
+pub use error::ServiceError;
+pub use jobs::{JobQueue, JobStatus, ProofResult, QueueStatus};
+pub use keys::{KeyInfo, KeyStore};
+pub use request::{ProofJob, ProofRequest};
+pub use rpc::{Prover, ProverApiServer};
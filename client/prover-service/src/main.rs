@@ -0,0 +1,57 @@
+//! prover-service - Proves Orbinum transactions for wallets that delegate
+//! proving to a machine their user controls
+
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
+
+use clap::Parser;
+use jsonrpsee::server::Server;
+use orbinum_prover_service::{JobQueue, KeyStore, Prover, ProverApiServer};
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+	/// Address the RPC server listens on. Requests carry spending keys: keep
+	/// it on loopback and reach it through an authenticated tunnel.
+	#[arg(long, default_value = "127.0.0.1:9966")]
+	rpc_addr: SocketAddr,
+
+	/// Directory of the proving keys (`<circuit>.pk`).
+	#[arg(long, default_value = "prover-keys")]
+	keys_dir: PathBuf,
+
+	/// Proving threads. Each holds one witness and its proving state in memory.
+	#[arg(long, default_value = "1")]
+	workers: usize,
+
+	/// Jobs waiting for a worker before submissions are refused.
+	#[arg(long, default_value = "16")]
+	max_queue: usize,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+	let cli = Cli::parse();
+
+	let keys = Arc::new(KeyStore::open(&cli.keys_dir)?);
+	for key in keys.list()? {
+		log::info!(target: "prover-service", "Found {} proving key at {}", key.circuit, key.path);
+	}
+	let queue = JobQueue::start(keys.clone(), cli.workers, cli.max_queue);
+
+	if !cli.rpc_addr.ip().is_loopback() {
+		log::warn!(
+			target: "prover-service",
+			"RPC server is reachable beyond loopback; witnesses travel unencrypted",
+		);
+	}
+	let server = Server::builder().build(cli.rpc_addr).await?;
+	let handle = server.start(Prover::new(keys, queue).into_rpc());
+	log::info!(target: "prover-service", "RPC server listening on {}", cli.rpc_addr);
+
+	tokio::signal::ctrl_c().await?;
+
+	handle.stop()?;
+	handle.stopped().await;
+	Ok(())
+}
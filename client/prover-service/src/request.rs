@@ -0,0 +1,261 @@
+//! ProofRequest - Witnesses submitted for proving
+//!
+//! Field elements are `0x`-prefixed big-endian hex, like `FieldElement::to_hex`
+//! and snarkjs inputs. Requests are checked and turned into circuits when they
+//! are submitted, so a bad witness is rejected before it takes a queue slot.
+//!
+//! Requests carry spending keys and are deliberately not `Debug`.
+
+use ark_bn254::Bn254;
+use ark_groth16::ProvingKey;
+use orbinum_zk_circuits::{
+	application::{
+		circuits::{
+			note::Note,
+			transfer::{TransferCircuit, TransferWitness, TREE_DEPTH},
+			viewing_key::ViewingKeyOwnershipCircuit,
+		},
+		prover::{prove_transfer, prove_viewing_key_ownership, ProverRng, SerializedProof},
+	},
+	Bn254Fr,
+};
+use orbinum_zk_core::FieldElement;
+use serde::Deserialize;
+
+use crate::{circuit::Circuit, error::ServiceError};
+
+/// Witness of one proof, tagged with its circuit.
+///
+/// Transfer witnesses are boxed: they are far larger than the others.
+#[derive(Clone, Deserialize)]
+#[serde(tag = "circuit", rename_all = "snake_case")]
+pub enum ProofRequest {
+	Transfer(Box<TransferRequest>),
+	ViewingKeyOwnership(ViewingKeyOwnershipRequest),
+}
+
+/// Note of a transfer witness.
+#[derive(Clone, Deserialize)]
+pub struct NoteRequest {
+	pub value: u64,
+	pub asset_id: u64,
+	pub owner_pubkey: String,
+	pub blinding: String,
+}
+
+/// Merkle path of one input note, from leaf to root.
+#[derive(Clone, Deserialize)]
+pub struct MerklePathRequest {
+	/// Sibling hashes
+	pub elements: Vec<String>,
+	/// Left/right position at each level (`true` = right child)
+	pub positions: Vec<bool>,
+}
+
+/// Private transfer witness.
+#[derive(Clone, Deserialize)]
+pub struct TransferRequest {
+	pub merkle_root: String,
+	pub input_notes: [NoteRequest; 2],
+	pub spending_keys: [String; 2],
+	pub merkle_paths: [MerklePathRequest; 2],
+	pub output_notes: [NoteRequest; 2],
+}
+
+/// Viewing key ownership witness.
+#[derive(Clone, Deserialize)]
+pub struct ViewingKeyOwnershipRequest {
+	/// Viewing key as a field element (`viewing_key_to_field`)
+	pub viewing_key: String,
+	/// Commitment being disclosed
+	pub commitment: String,
+}
+
+/// Checked request, ready to prove.
+pub enum ProofJob {
+	Transfer(Box<TransferCircuit>),
+	ViewingKeyOwnership(ViewingKeyOwnershipCircuit),
+}
+
+impl ProofRequest {
+	/// Circuit the request is proven with.
+	pub fn circuit(&self) -> Circuit {
+		match self {
+			ProofRequest::Transfer(_) => Circuit::Transfer,
+			ProofRequest::ViewingKeyOwnership(_) => Circuit::ViewingKeyOwnership,
+		}
+	}
+
+	/// Parses and checks the witness.
+	pub fn into_job(self) -> Result<ProofJob, ServiceError> {
+		match self {
+			ProofRequest::Transfer(request) => request
+				.into_circuit()
+				.map(|circuit| ProofJob::Transfer(Box::new(circuit))),
+			ProofRequest::ViewingKeyOwnership(request) => Ok(ProofJob::ViewingKeyOwnership(
+				ViewingKeyOwnershipCircuit::new(
+					parse_field(&request.viewing_key, "viewing_key")?,
+					parse_field(&request.commitment, "commitment")?,
+				),
+			)),
+		}
+	}
+}
+
+impl TransferRequest {
+	fn into_circuit(self) -> Result<TransferCircuit, ServiceError> {
+		let [first_path, second_path] = self.merkle_paths;
+		let (first_elements, first_positions) = parse_path(first_path)?;
+		let (second_elements, second_positions) = parse_path(second_path)?;
+		let [first_key, second_key] = self.spending_keys;
+
+		let witness = TransferWitness::new(
+			parse_notes(self.input_notes, "input_notes")?,
+			[
+				parse_field(&first_key, "spending_keys")?,
+				parse_field(&second_key, "spending_keys")?,
+			],
+			[first_elements, second_elements],
+			[first_positions, second_positions],
+			parse_notes(self.output_notes, "output_notes")?,
+		);
+		witness
+			.validate()
+			.map_err(|e| ServiceError::InvalidRequest(e.into()))?;
+
+		Ok(TransferCircuit::new(
+			witness,
+			parse_field(&self.merkle_root, "merkle_root")?,
+		))
+	}
+}
+
+impl ProofJob {
+	/// Circuit the job is proven with.
+	pub fn circuit(&self) -> Circuit {
+		match self {
+			ProofJob::Transfer(_) => Circuit::Transfer,
+			ProofJob::ViewingKeyOwnership(_) => Circuit::ViewingKeyOwnership,
+		}
+	}
+
+	/// Proves the job against its circuit's proving key.
+	pub fn prove(
+		self,
+		pk: &ProvingKey<Bn254>,
+		rng: &mut ProverRng,
+	) -> Result<SerializedProof, ServiceError> {
+		match self {
+			ProofJob::Transfer(circuit) => prove_transfer(pk, *circuit, rng),
+			ProofJob::ViewingKeyOwnership(circuit) => prove_viewing_key_ownership(pk, circuit, rng),
+		}
+		.map_err(|e| ServiceError::Prover(format!("{e:?}")))
+	}
+}
+
+fn parse_field(value: &str, field: &str) -> Result<Bn254Fr, ServiceError> {
+	FieldElement::from_hex(value)
+		.map(|element| element.inner())
+		.map_err(|e| ServiceError::InvalidRequest(format!("{field}: {e}")))
+}
+
+fn parse_notes(notes: [NoteRequest; 2], field: &str) -> Result<[Note; 2], ServiceError> {
+	let [first, second] = notes;
+	Ok([parse_note(first, field)?, parse_note(second, field)?])
+}
+
+fn parse_note(note: NoteRequest, field: &str) -> Result<Note, ServiceError> {
+	Ok(Note::new(
+		note.value,
+		note.asset_id,
+		parse_field(&note.owner_pubkey, field)?,
+		parse_field(&note.blinding, field)?,
+	))
+}
+
+fn parse_path(
+	path: MerklePathRequest,
+) -> Result<([Bn254Fr; TREE_DEPTH], [bool; TREE_DEPTH]), ServiceError> {
+	if path.elements.len() != TREE_DEPTH || path.positions.len() != TREE_DEPTH {
+		return Err(ServiceError::InvalidRequest(format!(
+			"merkle_paths: expected {TREE_DEPTH} elements and positions"
+		)));
+	}
+
+	let mut elements = [Bn254Fr::from(0u64); TREE_DEPTH];
+	for (element, value) in elements.iter_mut().zip(&path.elements) {
+		*element = parse_field(value, "merkle_paths")?;
+	}
+	let mut positions = [false; TREE_DEPTH];
+	positions.copy_from_slice(&path.positions);
+
+	Ok((elements, positions))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	fn note(value: u64) -> serde_json::Value {
+		json!({ "value": value, "asset_id": 0, "owner_pubkey": "0x01", "blinding": "0x02" })
+	}
+
+	fn path(depth: usize) -> serde_json::Value {
+		json!({ "elements": vec!["0x03"; depth], "positions": vec![false; depth] })
+	}
+
+	fn transfer(outputs: [u64; 2], depth: usize) -> ProofRequest {
+		serde_json::from_value(json!({
+			"circuit": "transfer",
+			"merkle_root": "0x04",
+			"input_notes": [note(60), note(40)],
+			"spending_keys": ["0x05", "0x06"],
+			"merkle_paths": [path(depth), path(depth)],
+			"output_notes": [note(outputs[0]), note(outputs[1])],
+		}))
+		.unwrap()
+	}
+
+	#[test]
+	fn should_parse_transfer_request() {
+		let request = transfer([70, 30], TREE_DEPTH);
+		assert_eq!(request.circuit(), Circuit::Transfer);
+
+		let Ok(ProofJob::Transfer(circuit)) = request.into_job() else {
+			panic!("expected a transfer job");
+		};
+		assert_eq!(circuit.merkle_root, Some(Bn254Fr::from(4u64)));
+		let witness = circuit.witness.unwrap();
+		assert_eq!(witness.spending_keys[1], Bn254Fr::from(6u64));
+		assert_eq!(witness.merkle_path_elements[0][0], Bn254Fr::from(3u64));
+		assert_eq!(witness.output_notes[0].value, Bn254Fr::from(70u64));
+	}
+
+	#[test]
+	fn should_reject_inconsistent_transfer() {
+		assert!(matches!(
+			transfer([70, 31], TREE_DEPTH).into_job(),
+			Err(ServiceError::InvalidRequest(_))
+		));
+		assert!(matches!(
+			transfer([70, 30], 20).into_job(),
+			Err(ServiceError::InvalidRequest(_))
+		));
+	}
+
+	#[test]
+	fn should_reject_non_canonical_field() {
+		let request: ProofRequest = serde_json::from_value(json!({
+			"circuit": "viewing_key_ownership",
+			"viewing_key": format!("0x{}", "ff".repeat(32)),
+			"commitment": "0x01",
+		}))
+		.unwrap();
+
+		assert!(matches!(
+			request.into_job(),
+			Err(ServiceError::InvalidRequest(e)) if e.starts_with("viewing_key")
+		));
+	}
+}
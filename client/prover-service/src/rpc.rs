@@ -0,0 +1,92 @@
+//! ProverApi - Proving endpoints
+//!
+//! Proofs are asynchronous: `prover_submit` returns a job id that clients
+//! poll with `prover_getJob` until the job is `done` or `failed`.
+
+use std::{path::PathBuf, sync::Arc};
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
+
+use crate::{
+	circuit::Circuit,
+	jobs::{JobQueue, JobStatus, QueueStatus},
+	keys::{KeyInfo, KeyStore},
+	request::ProofRequest,
+};
+
+/// Proving service API.
+#[rpc(server)]
+pub trait ProverApi {
+	/// Checks a witness and queues it for proving, returning the job id.
+	///
+	/// Fails right away if the witness is malformed, no key is installed
+	/// for its circuit or the queue is full.
+	#[method(name = "prover_submit")]
+	fn submit(&self, request: ProofRequest) -> RpcResult<u64>;
+
+	/// State of a job, with the proof once it is done.
+	#[method(name = "prover_getJob")]
+	fn get_job(&self, job_id: u64) -> RpcResult<JobStatus>;
+
+	/// Load of the job queue.
+	#[method(name = "prover_status")]
+	fn status(&self) -> RpcResult<QueueStatus>;
+
+	/// Installed proving keys.
+	#[method(name = "prover_listKeys")]
+	fn list_keys(&self) -> RpcResult<Vec<KeyInfo>>;
+
+	/// Validates the proving key file at `path` (on the service's machine)
+	/// and installs it for `circuit`.
+	#[method(name = "prover_importKey", blocking)]
+	fn import_key(&self, circuit: Circuit, path: String) -> RpcResult<KeyInfo>;
+
+	/// Uninstalls the proving key of `circuit`.
+	#[method(name = "prover_removeKey")]
+	fn remove_key(&self, circuit: Circuit) -> RpcResult<()>;
+}
+
+/// Proving service RPC handler.
+pub struct Prover {
+	keys: Arc<KeyStore>,
+	queue: JobQueue,
+}
+
+impl Prover {
+	/// Creates a new `Prover`.
+	pub fn new(keys: Arc<KeyStore>, queue: JobQueue) -> Self {
+		Self { keys, queue }
+	}
+}
+
+impl ProverApiServer for Prover {
+	fn submit(&self, request: ProofRequest) -> RpcResult<u64> {
+		self.queue.submit(request).map_err(rpc_error)
+	}
+
+	fn get_job(&self, job_id: u64) -> RpcResult<JobStatus> {
+		self.queue.status(job_id).map_err(rpc_error)
+	}
+
+	fn status(&self) -> RpcResult<QueueStatus> {
+		Ok(self.queue.queue_status())
+	}
+
+	fn list_keys(&self) -> RpcResult<Vec<KeyInfo>> {
+		self.keys.list().map_err(rpc_error)
+	}
+
+	fn import_key(&self, circuit: Circuit, path: String) -> RpcResult<KeyInfo> {
+		self.keys
+			.import(circuit, &PathBuf::from(path))
+			.map_err(rpc_error)
+	}
+
+	fn remove_key(&self, circuit: Circuit) -> RpcResult<()> {
+		self.keys.remove(circuit).map_err(rpc_error)
+	}
+}
+
+fn rpc_error(message: impl ToString) -> ErrorObjectOwned {
+	ErrorObjectOwned::owned(1, message.to_string(), None::<()>)
+}
@@ -25,4 +25,7 @@
 pub mod orbinum;
 
 // Re-exportar tipos principales para facilitar importación
-pub use orbinum::{PrivacyApiServer, PrivacyRpcServer, SubstrateStorageAdapter};
+pub use orbinum::{
+	CostClass, DeprecationLog, PrivacyApiServer, PrivacyPubSubApiServer, PrivacyPubSubServer,
+	PrivacyRpcServer, RateLimitConfig, RateLimiter, RpcMetrics, SubstrateStorageAdapter,
+};
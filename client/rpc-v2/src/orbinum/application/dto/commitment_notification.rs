@@ -0,0 +1,77 @@
+//! CommitmentNotification DTO - New leaf pushed to commitment subscribers
+
+use serde::{Deserialize, Serialize};
+
+/// Notification DTO for `orbinum_v2_subscribeCommitments`.
+///
+/// One notification is pushed per leaf appended to the Merkle tree.
+/// It maps from `domain::CommitmentLeaf`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitmentNotification {
+	/// Leaf index in the Merkle tree.
+	pub leaf_index: u32,
+	/// Commitment in hex format.
+	pub commitment: String,
+	/// Encrypted memo in hex format (`None` if no memo was stored).
+	pub encrypted_memo: Option<String>,
+	/// Number of the block that inserted the leaf.
+	pub block_number: u64,
+	/// Hash of the block that inserted the leaf, in hex format.
+	pub block_hash: String,
+}
+
+impl CommitmentNotification {
+	/// Creates a new `CommitmentNotification`.
+	pub fn new(
+		leaf_index: u32,
+		commitment: String,
+		encrypted_memo: Option<String>,
+		block_number: u64,
+		block_hash: String,
+	) -> Self {
+		Self {
+			leaf_index,
+			commitment,
+			encrypted_memo,
+			block_number,
+			block_hash,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_create_commitment_notification() {
+		let notification = CommitmentNotification::new(
+			4,
+			"0xaa".to_string(),
+			Some("0xbb".to_string()),
+			10,
+			"0xcc".to_string(),
+		);
+
+		assert_eq!(notification.leaf_index, 4);
+		assert_eq!(notification.commitment, "0xaa");
+		assert_eq!(notification.encrypted_memo.as_deref(), Some("0xbb"));
+		assert_eq!(notification.block_number, 10);
+		assert_eq!(notification.block_hash, "0xcc");
+	}
+
+	#[test]
+	fn should_support_expected_traits() {
+		fn assert_serialize<T: Serialize>() {}
+		fn assert_deserialize<T: for<'de> Deserialize<'de>>() {}
+		fn assert_clone<T: Clone>() {}
+		fn assert_debug<T: core::fmt::Debug>() {}
+		fn assert_eq_trait<T: Eq>() {}
+
+		assert_serialize::<CommitmentNotification>();
+		assert_deserialize::<CommitmentNotification>();
+		assert_clone::<CommitmentNotification>();
+		assert_debug::<CommitmentNotification>();
+		assert_eq_trait::<CommitmentNotification>();
+	}
+}
@@ -5,10 +5,16 @@
 //! - Support JSON-RPC serialization/deserialization
 //! - Enable API versioning without changing the domain layer
 
+mod commitment_notification;
 mod merkle_proof_response;
+mod nullifier_notification;
 mod nullifier_status_response;
 mod pool_stats_response;
+mod root_notification;
 
+pub use commitment_notification::CommitmentNotification;
 pub use merkle_proof_response::MerkleProofResponse;
+pub use nullifier_notification::NullifierNotification;
 pub use nullifier_status_response::NullifierStatusResponse;
 pub use pool_stats_response::PoolStatsResponse;
+pub use root_notification::RootNotification;
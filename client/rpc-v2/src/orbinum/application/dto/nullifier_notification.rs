@@ -0,0 +1,56 @@
+//! NullifierNotification DTO - Spent nullifier pushed to nullifier subscribers
+
+use serde::{Deserialize, Serialize};
+
+/// Notification DTO for `orbinum_v2_subscribeNullifiers`.
+///
+/// One notification is pushed per nullifier consumed by a new best block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NullifierNotification {
+	/// Nullifier in hex format.
+	pub nullifier: String,
+	/// Number of the block that spent the nullifier.
+	pub block_number: u64,
+	/// Hash of the block that spent the nullifier, in hex format.
+	pub block_hash: String,
+}
+
+impl NullifierNotification {
+	/// Creates a new `NullifierNotification`.
+	pub fn new(nullifier: String, block_number: u64, block_hash: String) -> Self {
+		Self {
+			nullifier,
+			block_number,
+			block_hash,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_create_nullifier_notification() {
+		let notification = NullifierNotification::new("0xaa".to_string(), 3, "0xbb".to_string());
+
+		assert_eq!(notification.nullifier, "0xaa");
+		assert_eq!(notification.block_number, 3);
+		assert_eq!(notification.block_hash, "0xbb");
+	}
+
+	#[test]
+	fn should_support_expected_traits() {
+		fn assert_serialize<T: Serialize>() {}
+		fn assert_deserialize<T: for<'de> Deserialize<'de>>() {}
+		fn assert_clone<T: Clone>() {}
+		fn assert_debug<T: core::fmt::Debug>() {}
+		fn assert_eq_trait<T: Eq>() {}
+
+		assert_serialize::<NullifierNotification>();
+		assert_deserialize::<NullifierNotification>();
+		assert_clone::<NullifierNotification>();
+		assert_debug::<NullifierNotification>();
+		assert_eq_trait::<NullifierNotification>();
+	}
+}
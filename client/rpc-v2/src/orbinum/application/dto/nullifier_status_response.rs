@@ -11,14 +11,21 @@ pub struct NullifierStatusResponse {
 	pub nullifier: String,
 	/// `true` if the nullifier is spent, `false` if available.
 	pub is_spent: bool,
+	/// Block in which the nullifier was spent (`None` if available).
+	pub block: Option<u64>,
 }
 
 impl NullifierStatusResponse {
 	/// Creates a new `NullifierStatusResponse`.
-	pub fn new(nullifier: String, is_spent: bool) -> Self {
+	///
+	/// # Parameters
+	/// - `nullifier`: Nullifier in hex format
+	/// - `block`: Block in which the nullifier was spent, if any
+	pub fn new(nullifier: String, block: Option<u64>) -> Self {
 		Self {
 			nullifier,
-			is_spent,
+			is_spent: block.is_some(),
+			block,
 		}
 	}
 }
@@ -29,10 +36,19 @@ mod tests {
 
 	#[test]
 	fn should_create_nullifier_status_response() {
-		let response = NullifierStatusResponse::new("0xdeadbeef".to_string(), true);
+		let response = NullifierStatusResponse::new("0xdeadbeef".to_string(), Some(9));
 
 		assert_eq!(response.nullifier, "0xdeadbeef");
 		assert!(response.is_spent);
+		assert_eq!(response.block, Some(9));
+	}
+
+	#[test]
+	fn should_create_unspent_nullifier_status_response() {
+		let response = NullifierStatusResponse::new("0xdeadbeef".to_string(), None);
+
+		assert!(!response.is_spent);
+		assert_eq!(response.block, None);
 	}
 
 	#[test]
@@ -5,6 +5,9 @@ use serde::{Deserialize, Serialize};
 /// Response DTO for pool statistics.
 ///
 /// Maps from `domain::PoolStatistics` into JSON-friendly fields.
+///
+/// `asset_id`, the tree utilization and the historic-root window are only
+/// reported by `orbinum_v2_getPoolStats`; they are `null` otherwise.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PoolStatsResponse {
 	/// Merkle tree root (hex string).
@@ -15,6 +18,16 @@ pub struct PoolStatsResponse {
 	pub total_balance: u128,
 	/// Tree depth.
 	pub tree_depth: u32,
+	/// Asset the balance refers to (`None` = all assets).
+	pub asset_id: Option<u32>,
+	/// Number of leaves the tree can hold.
+	pub tree_capacity: Option<u32>,
+	/// Tree utilization in basis points (10000 = full).
+	pub tree_utilization_bps: Option<u32>,
+	/// Number of roots currently accepted as historic.
+	pub historic_roots: Option<u32>,
+	/// Maximum number of historic roots kept.
+	pub max_historic_roots: Option<u32>,
 }
 
 impl PoolStatsResponse {
@@ -30,8 +43,33 @@ impl PoolStatsResponse {
 			commitment_count,
 			total_balance,
 			tree_depth,
+			asset_id: None,
+			tree_capacity: None,
+			tree_utilization_bps: None,
+			historic_roots: None,
+			max_historic_roots: None,
 		}
 	}
+
+	/// Sets the asset the balance refers to.
+	pub fn with_asset(mut self, asset_id: Option<u32>) -> Self {
+		self.asset_id = asset_id;
+		self
+	}
+
+	/// Sets tree capacity and utilization (in basis points).
+	pub fn with_tree_utilization(mut self, capacity: u32, utilization_bps: u32) -> Self {
+		self.tree_capacity = Some(capacity);
+		self.tree_utilization_bps = Some(utilization_bps);
+		self
+	}
+
+	/// Sets the historic-root window (roots stored, maximum kept).
+	pub fn with_historic_roots(mut self, stored: u32, max: u32) -> Self {
+		self.historic_roots = Some(stored);
+		self.max_historic_roots = Some(max);
+		self
+	}
 }
 
 #[cfg(test)]
@@ -46,6 +84,22 @@ mod tests {
 		assert_eq!(response.commitment_count, 42);
 		assert_eq!(response.total_balance, 1_000_000u128);
 		assert_eq!(response.tree_depth, 32);
+		assert_eq!(response.asset_id, None);
+		assert_eq!(response.historic_roots, None);
+	}
+
+	#[test]
+	fn should_set_runtime_pool_stats_fields() {
+		let response = PoolStatsResponse::new("0x1234".to_string(), 4, 500u128, 4)
+			.with_asset(Some(2))
+			.with_tree_utilization(16, 2_500)
+			.with_historic_roots(4, 100);
+
+		assert_eq!(response.asset_id, Some(2));
+		assert_eq!(response.tree_capacity, Some(16));
+		assert_eq!(response.tree_utilization_bps, Some(2_500));
+		assert_eq!(response.historic_roots, Some(4));
+		assert_eq!(response.max_historic_roots, Some(100));
 	}
 
 	#[test]
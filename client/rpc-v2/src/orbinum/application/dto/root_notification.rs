@@ -0,0 +1,46 @@
+//! RootNotification DTO - New Merkle root pushed to root subscribers
+
+use serde::{Deserialize, Serialize};
+
+/// Notification DTO for `orbinum_v2_subscribeRoots`.
+///
+/// One notification is pushed per new best block that changed the root.
+/// It maps from `domain::RootUpdate`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RootNotification {
+	/// New Merkle root in hex format.
+	pub root: String,
+	/// Number of leaves the root commits to.
+	pub tree_size: u32,
+	/// Number of the block that produced the root.
+	pub block_number: u64,
+	/// Hash of the block that produced the root, in hex format.
+	pub block_hash: String,
+}
+
+impl RootNotification {
+	/// Creates a new `RootNotification`.
+	pub fn new(root: String, tree_size: u32, block_number: u64, block_hash: String) -> Self {
+		Self {
+			root,
+			tree_size,
+			block_number,
+			block_hash,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_create_root_notification() {
+		let notification = RootNotification::new("0xaa".to_string(), 5, 3, "0xbb".to_string());
+
+		assert_eq!(notification.root, "0xaa");
+		assert_eq!(notification.tree_size, 5);
+		assert_eq!(notification.block_number, 3);
+		assert_eq!(notification.block_hash, "0xbb");
+	}
+}
@@ -9,10 +9,16 @@ pub mod dto;
 pub mod services;
 
 // Service re-exports
-pub use services::{MerkleProofService, NullifierService, PoolQueryService};
+pub use services::{
+	CommitmentStreamService, MerkleProofService, NullifierService, NullifierStreamService,
+	PoolQueryService, RootStreamService,
+};
 
 // DTO re-exports
-pub use dto::{MerkleProofResponse, NullifierStatusResponse, PoolStatsResponse};
+pub use dto::{
+	CommitmentNotification, MerkleProofResponse, NullifierNotification, NullifierStatusResponse,
+	PoolStatsResponse, RootNotification,
+};
 
 /// Application layer error type.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -0,0 +1,168 @@
+//! CommitmentStreamService - Service for collecting leaves appended by a block
+
+use crate::orbinum::{
+	application::ApplicationResult,
+	domain::{
+		BlockHash, CommitmentLeaf, DomainError, ImportedBlock, MemoQuery, MerkleTreeQuery, TreeSize,
+	},
+};
+
+/// Service for collecting the commitments a block appended to the tree.
+///
+/// # Algorithm
+/// 1. Read `tree_size` at the parent block and at the block
+/// 2. Every index in `parent_size..block_size` is a new leaf
+/// 3. Fetch each leaf commitment and its encrypted memo at the block
+pub struct CommitmentStreamService<Q> {
+	query: Q,
+}
+
+impl<Q> CommitmentStreamService<Q>
+where
+	Q: MerkleTreeQuery + MemoQuery,
+{
+	/// Creates a new `CommitmentStreamService`.
+	pub fn new(query: Q) -> Self {
+		Self { query }
+	}
+
+	/// Returns the leaves appended by `block`, in leaf-index order.
+	///
+	/// # Parameters
+	/// - `block`: Block that became the new best block
+	///
+	/// # Returns
+	/// - New leaves (empty when the block did not touch the tree)
+	///
+	/// # Errors
+	/// - `Domain`: Storage query errors
+	pub fn new_leaves(&self, block: &ImportedBlock) -> ApplicationResult<Vec<CommitmentLeaf>> {
+		// 1. Tree size before and after the block
+		let previous_size = self.tree_size_or_empty(block.parent_hash())?;
+		let current_size = self.tree_size_or_empty(block.hash())?;
+
+		// 2. Fetch each appended leaf with its memo
+		(previous_size.value()..current_size.value())
+			.map(|leaf_index| {
+				let commitment = self.query.get_leaf(block.hash(), leaf_index)?;
+				let encrypted_memo = self.query.get_encrypted_memo(block.hash(), commitment)?;
+				Ok(CommitmentLeaf::new(
+					leaf_index,
+					commitment,
+					encrypted_memo,
+					block.hash(),
+					block.number(),
+				))
+			})
+			.collect()
+	}
+
+	/// Returns the tree size at a block, treating an uninitialized tree as empty.
+	fn tree_size_or_empty(&self, block_hash: BlockHash) -> ApplicationResult<TreeSize> {
+		match self.query.get_tree_size(block_hash) {
+			Ok(size) => Ok(size),
+			Err(DomainError::MerkleTreeNotInitialized) => Ok(TreeSize::new(0)),
+			Err(e) => Err(e.into()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orbinum::domain::{Commitment, DomainResult};
+
+	fn parent() -> BlockHash {
+		BlockHash::new([1u8; 32])
+	}
+
+	fn block() -> BlockHash {
+		BlockHash::new([2u8; 32])
+	}
+
+	#[derive(Clone, Copy)]
+	struct MockQuery {
+		parent_size: Option<u32>,
+		block_size: u32,
+	}
+
+	impl MerkleTreeQuery for MockQuery {
+		fn get_merkle_root(&self, _block_hash: BlockHash) -> DomainResult<Commitment> {
+			Ok(Commitment::new([0u8; 32]))
+		}
+
+		fn get_tree_size(&self, block_hash: BlockHash) -> DomainResult<TreeSize> {
+			if block_hash == parent() {
+				self.parent_size
+					.map(TreeSize::new)
+					.ok_or(DomainError::MerkleTreeNotInitialized)
+			} else {
+				Ok(TreeSize::new(self.block_size))
+			}
+		}
+
+		fn get_leaf(&self, _block_hash: BlockHash, leaf_index: u32) -> DomainResult<Commitment> {
+			Ok(Commitment::new([leaf_index as u8; 32]))
+		}
+	}
+
+	impl MemoQuery for MockQuery {
+		fn get_encrypted_memo(
+			&self,
+			_block_hash: BlockHash,
+			commitment: Commitment,
+		) -> DomainResult<Option<Vec<u8>>> {
+			Ok((commitment.as_bytes()[0] % 2 == 0).then(|| vec![commitment.as_bytes()[0]; 3]))
+		}
+	}
+
+	#[test]
+	fn should_return_leaves_appended_by_block() {
+		let service = CommitmentStreamService::new(MockQuery {
+			parent_size: Some(2),
+			block_size: 4,
+		});
+
+		let leaves = service
+			.new_leaves(&ImportedBlock::new(block(), parent(), 9))
+			.expect("new_leaves should succeed");
+
+		assert_eq!(leaves.len(), 2);
+		assert_eq!(leaves[0].leaf_index(), 2);
+		assert_eq!(leaves[0].commitment(), Commitment::new([2u8; 32]));
+		assert_eq!(leaves[0].encrypted_memo(), Some(&[2u8; 3][..]));
+		assert_eq!(leaves[1].leaf_index(), 3);
+		assert_eq!(leaves[1].encrypted_memo(), None);
+		assert_eq!(leaves[1].block_hash(), block());
+		assert_eq!(leaves[1].block_number(), 9);
+	}
+
+	#[test]
+	fn should_start_from_zero_when_parent_tree_is_uninitialized() {
+		let service = CommitmentStreamService::new(MockQuery {
+			parent_size: None,
+			block_size: 1,
+		});
+
+		let leaves = service
+			.new_leaves(&ImportedBlock::new(block(), parent(), 1))
+			.expect("new_leaves should succeed");
+
+		assert_eq!(leaves.len(), 1);
+		assert_eq!(leaves[0].leaf_index(), 0);
+	}
+
+	#[test]
+	fn should_return_no_leaves_when_tree_did_not_grow() {
+		let service = CommitmentStreamService::new(MockQuery {
+			parent_size: Some(5),
+			block_size: 5,
+		});
+
+		let leaves = service
+			.new_leaves(&ImportedBlock::new(block(), parent(), 3))
+			.expect("new_leaves should succeed");
+
+		assert!(leaves.is_empty());
+	}
+}
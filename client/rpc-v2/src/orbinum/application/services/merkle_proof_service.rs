@@ -1,8 +1,16 @@
 //! MerkleProofService - Service for generating Merkle proofs
 
+use std::{
+	collections::HashMap,
+	sync::{Mutex, MutexGuard, PoisonError},
+};
+
 use crate::orbinum::{
 	application::{ApplicationError, ApplicationResult},
-	domain::{BlockchainQuery, Commitment, MerkleProofPath, MerkleTreeQuery, TreeDepth, TreeSize},
+	domain::{
+		BlockHash, BlockchainQuery, Commitment, MerkleProofPath, MerkleTreeQuery, TreeDepth,
+		TreeSize,
+	},
 };
 
 // Import zero-hash function from the pallet
@@ -11,6 +19,9 @@ use pallet_shielded_pool::infrastructure::merkle_tree::get_zero_hash_cached;
 // Logging
 extern crate log;
 
+/// Maximum number of proofs kept for the current Merkle root.
+const PROOF_CACHE_CAPACITY: usize = 4_096;
+
 /// Service for generating Merkle proofs.
 ///
 /// This service coordinates proof generation using:
@@ -20,10 +31,13 @@ extern crate log;
 /// # Algorithm
 /// 1. Get `best_hash` from the blockchain
 /// 2. Validate `leaf_index < tree_size`
-/// 3. Traverse leaf-to-root collecting siblings
-/// 4. Return `MerkleProofPath`
+/// 3. Serve the proof from the cache if the root is unchanged
+/// 4. Otherwise traverse leaf-to-root collecting siblings and cache the result
+/// 5. Return `MerkleProofPath`
 pub struct MerkleProofService<Q> {
 	query: Q,
+	/// Proofs against the current Merkle root.
+	cache: Mutex<ProofCache>,
 }
 
 impl<Q> MerkleProofService<Q>
@@ -32,7 +46,10 @@ where
 {
 	/// Creates a new `MerkleProofService`.
 	pub fn new(query: Q) -> Self {
-		Self { query }
+		Self {
+			query,
+			cache: Mutex::new(ProofCache::default()),
+		}
 	}
 
 	/// Generates a Merkle proof for the given leaf index.
@@ -48,6 +65,16 @@ where
 	/// - `TreeNotInitialized`: If the tree is empty
 	/// - `Domain`: Storage query errors
 	pub fn generate_proof(&self, leaf_index: u32) -> ApplicationResult<MerkleProofPath> {
+		self.generate_proof_cached(leaf_index)
+			.map(|(proof, _)| proof)
+	}
+
+	/// Generates a Merkle proof like [`Self::generate_proof`], also telling
+	/// whether it was served from the cache.
+	pub fn generate_proof_cached(
+		&self,
+		leaf_index: u32,
+	) -> ApplicationResult<(MerkleProofPath, bool)> {
 		// 1. Get best block hash
 		let block_hash = self.query.best_hash()?;
 
@@ -67,14 +94,30 @@ where
 			});
 		}
 
-		// 5. Build sibling path (with zero hashes up to depth 20)
+		// 5. Serve from the cache while the root is unchanged
+		let root = self.query.get_merkle_root(block_hash)?;
+		let key = self.lock_cache().key(root, block_hash, leaf_index);
+		if let Some(proof) = self.lock_cache().proofs.get(&key) {
+			return Ok((proof.clone(), true));
+		}
+
+		// 6. Build sibling path (with zero hashes up to depth 20)
 		let path = self.collect_sibling_path(block_hash, leaf_index)?;
 
-		// 6. Fixed tree depth for Sparse Merkle Tree
+		// 7. Fixed tree depth for Sparse Merkle Tree
 		let tree_depth = TreeDepth::new(20); // Fixed depth for circuit compatibility
 
-		// 7. Return MerkleProofPath
-		Ok(MerkleProofPath::new(path, leaf_index, tree_depth))
+		// 8. Cache and return MerkleProofPath
+		let proof = MerkleProofPath::new(path, leaf_index, tree_depth);
+		self.lock_cache().insert(key, proof.clone());
+		Ok((proof, false))
+	}
+
+	/// Locks the proof cache.
+	///
+	/// A poisoned lock is recovered: every cache update leaves it consistent.
+	fn lock_cache(&self) -> MutexGuard<'_, ProofCache> {
+		self.cache.lock().unwrap_or_else(PoisonError::into_inner)
 	}
 
 	/// Collects sibling path for a **Sparse Merkle Tree**.
@@ -168,10 +211,60 @@ where
 	}
 }
 
+/// Proofs served since the last Merkle root update.
+///
+/// Entries are keyed by `(block, leaf_index)`, where `block` is the first
+/// block seen with the current root. A proof stays valid until the next
+/// `MerkleRootUpdated`, so every later block with the same root shares it;
+/// a new root invalidates the whole cache.
+#[derive(Default)]
+struct ProofCache {
+	/// Current root and the first block seen with it.
+	anchor: Option<(Commitment, BlockHash)>,
+	/// Cached proofs of the current root.
+	proofs: HashMap<(BlockHash, u32), MerkleProofPath>,
+}
+
+impl ProofCache {
+	/// Returns the key of `leaf_index` at a block with `root`, dropping the
+	/// proofs of a previous root.
+	fn key(
+		&mut self,
+		root: Commitment,
+		block_hash: BlockHash,
+		leaf_index: u32,
+	) -> (BlockHash, u32) {
+		let anchor = match self.anchor {
+			Some((cached_root, anchor)) if cached_root == root => anchor,
+			_ => {
+				self.anchor = Some((root, block_hash));
+				self.proofs.clear();
+				block_hash
+			}
+		};
+		(anchor, leaf_index)
+	}
+
+	/// Caches `proof` unless the root moved on while it was built.
+	fn insert(&mut self, key: (BlockHash, u32), proof: MerkleProofPath) {
+		if self.anchor.map(|(_, anchor)| anchor) != Some(key.0) {
+			return;
+		}
+		if self.proofs.len() >= PROOF_CACHE_CAPACITY {
+			self.proofs.clear();
+		}
+		self.proofs.insert(key, proof);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::orbinum::domain::{BlockHash, DomainError, DomainResult};
+	use crate::orbinum::domain::{DomainError, DomainResult};
+	use std::sync::{
+		atomic::{AtomicU32, Ordering},
+		Arc,
+	};
 
 	#[derive(Clone, Copy)]
 	struct MockQuery {
@@ -215,6 +308,42 @@ mod tests {
 		}
 	}
 
+	/// Query counting leaf reads, with a root the test can move.
+	#[derive(Clone, Default)]
+	struct CountingQuery {
+		root: Arc<Mutex<Commitment>>,
+		leaf_reads: Arc<AtomicU32>,
+	}
+
+	impl BlockchainQuery for CountingQuery {
+		fn best_hash(&self) -> DomainResult<BlockHash> {
+			Ok(BlockHash::new(*self.root.lock().unwrap().as_bytes()))
+		}
+
+		fn storage_at(
+			&self,
+			_block_hash: BlockHash,
+			_storage_key: &[u8],
+		) -> DomainResult<Option<Vec<u8>>> {
+			Ok(None)
+		}
+	}
+
+	impl MerkleTreeQuery for CountingQuery {
+		fn get_merkle_root(&self, _block_hash: BlockHash) -> DomainResult<Commitment> {
+			Ok(*self.root.lock().unwrap())
+		}
+
+		fn get_tree_size(&self, _block_hash: BlockHash) -> DomainResult<TreeSize> {
+			Ok(TreeSize::new(4))
+		}
+
+		fn get_leaf(&self, _block_hash: BlockHash, leaf_index: u32) -> DomainResult<Commitment> {
+			self.leaf_reads.fetch_add(1, Ordering::SeqCst);
+			Ok(Commitment::new([leaf_index as u8; 32]))
+		}
+	}
+
 	#[test]
 	fn should_return_tree_not_initialized_when_tree_is_empty() {
 		let query = MockQuery {
@@ -284,4 +413,34 @@ mod tests {
 		assert_eq!(size.value(), 5);
 		assert_eq!(depth.value(), TreeDepth::from_tree_size(5).value());
 	}
+
+	#[test]
+	fn should_serve_cached_proof_until_root_changes() {
+		let query = CountingQuery::default();
+		let service = MerkleProofService::new(query.clone());
+
+		let first = service
+			.generate_proof(3)
+			.expect("proof generation must succeed");
+		let reads = query.leaf_reads.load(Ordering::SeqCst);
+		let cached = service
+			.generate_proof(3)
+			.expect("proof generation must succeed");
+
+		assert_eq!(first, cached);
+		assert!(
+			service
+				.generate_proof_cached(3)
+				.expect("proof generation must succeed")
+				.1
+		);
+		assert_eq!(query.leaf_reads.load(Ordering::SeqCst), reads);
+
+		*query.root.lock().unwrap() = Commitment::new([5u8; 32]);
+		service
+			.generate_proof(3)
+			.expect("proof generation must succeed");
+
+		assert_eq!(query.leaf_reads.load(Ordering::SeqCst), 2 * reads);
+	}
 }
@@ -3,10 +3,16 @@
 //! Services coordinate business operations using domain ports.
 //! They are FRAME-independent and can be tested with mocks.
 
+mod commitment_stream_service;
 mod merkle_proof_service;
 mod nullifier_service;
+mod nullifier_stream_service;
 mod pool_query_service;
+mod root_stream_service;
 
+pub use commitment_stream_service::CommitmentStreamService;
 pub use merkle_proof_service::MerkleProofService;
 pub use nullifier_service::NullifierService;
+pub use nullifier_stream_service::NullifierStreamService;
 pub use pool_query_service::PoolQueryService;
+pub use root_stream_service::RootStreamService;
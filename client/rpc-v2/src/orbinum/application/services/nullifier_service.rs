@@ -43,6 +43,25 @@ where
 		Ok(is_spent)
 	}
 
+	/// Returns the block in which a nullifier was spent.
+	///
+	/// # Parameters
+	/// - `nullifier`: Nullifier to check
+	///
+	/// # Returns
+	/// - `Some(block_number)`: Nullifier was spent in that block
+	/// - `None`: Nullifier is still available
+	///
+	/// # Errors
+	/// - `Domain`: Storage or runtime API query errors
+	pub fn get_spent_block(&self, nullifier: Nullifier) -> ApplicationResult<Option<u64>> {
+		let block_hash = self.query.best_hash()?;
+
+		let spent_block = self.query.get_spent_block(block_hash, nullifier)?;
+
+		Ok(spent_block)
+	}
+
 	/// Checks multiple nullifiers in batch.
 	///
 	/// # Parameters
@@ -91,12 +110,12 @@ mod tests {
 	}
 
 	impl NullifierQuery for MockQuery {
-		fn is_nullifier_spent(
+		fn get_spent_block(
 			&self,
 			_block_hash: BlockHash,
 			nullifier: Nullifier,
-		) -> DomainResult<bool> {
-			Ok(nullifier.as_bytes()[0] == 1)
+		) -> DomainResult<Option<u64>> {
+			Ok((nullifier.as_bytes()[0] == 1).then_some(12))
 		}
 	}
 
@@ -112,6 +131,24 @@ mod tests {
 			.expect("available query must succeed"));
 	}
 
+	#[test]
+	fn should_return_spent_block() {
+		let service = NullifierService::new(MockQuery);
+
+		assert_eq!(
+			service
+				.get_spent_block(Nullifier::new([1u8; 32]))
+				.expect("spent query must succeed"),
+			Some(12)
+		);
+		assert_eq!(
+			service
+				.get_spent_block(Nullifier::new([0u8; 32]))
+				.expect("available query must succeed"),
+			None
+		);
+	}
+
 	#[test]
 	fn should_check_nullifiers_in_batch() {
 		let service = NullifierService::new(MockQuery);
@@ -0,0 +1,79 @@
+//! NullifierStreamService - Service for collecting nullifiers spent by a block
+
+use crate::orbinum::{
+	application::ApplicationResult,
+	domain::{ImportedBlock, Nullifier, SpentNullifierQuery},
+};
+
+/// Service for collecting the nullifiers a block consumed.
+///
+/// Coordinates per-block spend queries through domain ports.
+pub struct NullifierStreamService<Q> {
+	query: Q,
+}
+
+impl<Q> NullifierStreamService<Q>
+where
+	Q: SpentNullifierQuery,
+{
+	/// Creates a new `NullifierStreamService`.
+	pub fn new(query: Q) -> Self {
+		Self { query }
+	}
+
+	/// Returns the nullifiers spent by `block`.
+	///
+	/// # Parameters
+	/// - `block`: Block that became the new best block
+	///
+	/// # Returns
+	/// - Spent nullifiers (empty when the block spent no notes)
+	///
+	/// # Errors
+	/// - `Domain`: Runtime API errors
+	pub fn spent_nullifiers(&self, block: &ImportedBlock) -> ApplicationResult<Vec<Nullifier>> {
+		Ok(self.query.get_spent_nullifiers(block.hash())?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orbinum::domain::{BlockHash, DomainError, DomainResult};
+
+	#[derive(Clone, Copy)]
+	struct MockQuery {
+		fail: bool,
+	}
+
+	impl SpentNullifierQuery for MockQuery {
+		fn get_spent_nullifiers(&self, block_hash: BlockHash) -> DomainResult<Vec<Nullifier>> {
+			if self.fail {
+				return Err(DomainError::RuntimeApiError("unavailable".to_string()));
+			}
+			Ok(vec![Nullifier::new(*block_hash.as_bytes())])
+		}
+	}
+
+	fn block() -> ImportedBlock {
+		ImportedBlock::new(BlockHash::new([7u8; 32]), BlockHash::new([6u8; 32]), 7)
+	}
+
+	#[test]
+	fn should_return_nullifiers_spent_by_block() {
+		let service = NullifierStreamService::new(MockQuery { fail: false });
+
+		let nullifiers = service
+			.spent_nullifiers(&block())
+			.expect("spent_nullifiers should succeed");
+
+		assert_eq!(nullifiers, vec![Nullifier::new([7u8; 32])]);
+	}
+
+	#[test]
+	fn should_propagate_query_errors() {
+		let service = NullifierStreamService::new(MockQuery { fail: true });
+
+		assert!(service.spent_nullifiers(&block()).is_err());
+	}
+}
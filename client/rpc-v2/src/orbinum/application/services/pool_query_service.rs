@@ -3,8 +3,8 @@
 use crate::orbinum::{
 	application::{ApplicationError, ApplicationResult},
 	domain::{
-		AssetId, BlockchainQuery, Commitment, MerkleTreeQuery, PoolQuery, PoolStatistics,
-		TreeDepth, TreeSize,
+		AssetId, BlockHash, BlockchainQuery, Commitment, MerkleTreeQuery, PoolQuery,
+		PoolStatistics, PoolStatsQuery, TreeDepth, TreeSize,
 	},
 };
 
@@ -106,10 +106,38 @@ where
 	}
 }
 
+impl<Q> PoolQueryService<Q>
+where
+	Q: BlockchainQuery + PoolStatsQuery,
+{
+	/// Returns pool statistics computed by the runtime.
+	///
+	/// Unlike `get_statistics`, an empty pool is not an error.
+	///
+	/// # Parameters
+	/// - `asset_id`: Asset the balance refers to (`None` = total pool balance)
+	/// - `at`: Block to query (`None` = best block); older blocks need an archive node
+	///
+	/// # Returns
+	/// - `PoolStatistics`: Pool metrics including the historic-root window
+	pub fn get_pool_stats(
+		&self,
+		asset_id: Option<AssetId>,
+		at: Option<BlockHash>,
+	) -> ApplicationResult<PoolStatistics> {
+		let block_hash = match at {
+			Some(block_hash) => block_hash,
+			None => self.query.best_hash()?,
+		};
+		let stats = self.query.get_pool_stats(block_hash, asset_id)?;
+		Ok(stats)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::orbinum::domain::{BlockHash, DomainResult};
+	use crate::orbinum::domain::DomainResult;
 
 	#[derive(Clone, Copy)]
 	struct MockQuery {
@@ -160,6 +188,29 @@ mod tests {
 		}
 	}
 
+	impl PoolStatsQuery for MockQuery {
+		fn get_pool_stats(
+			&self,
+			block_hash: BlockHash,
+			asset_id: Option<AssetId>,
+		) -> DomainResult<PoolStatistics> {
+			let balance = match asset_id {
+				Some(asset_id) => self.get_asset_balance(block_hash, asset_id)?,
+				// The pool is empty before the best block
+				None if block_hash != BlockHash::new([8u8; 32]) => 0,
+				None => self.total_balance,
+			};
+			Ok(PoolStatistics::new(
+				self.root,
+				TreeSize::new(self.tree_size),
+				balance,
+				TreeDepth::new(20),
+			)
+			.with_asset(asset_id)
+			.with_historic_roots(self.tree_size, 100))
+		}
+	}
+
 	#[test]
 	fn should_return_pool_not_initialized_for_empty_tree() {
 		let service = PoolQueryService::new(MockQuery {
@@ -228,4 +279,45 @@ mod tests {
 			70
 		);
 	}
+
+	#[test]
+	fn should_return_runtime_pool_stats_for_asset() {
+		let service = PoolQueryService::new(MockQuery {
+			root: Commitment::new([5u8; 32]),
+			tree_size: 0,
+			total_balance: 1_000,
+		});
+
+		let total = service
+			.get_pool_stats(None, None)
+			.expect("pool stats query must succeed");
+		let asset = service
+			.get_pool_stats(Some(AssetId::new(3)), None)
+			.expect("asset pool stats query must succeed");
+
+		assert_eq!(total.total_balance(), 1_000);
+		assert_eq!(total.asset_id(), None);
+		assert_eq!(asset.total_balance(), 30);
+		assert_eq!(asset.asset_id(), Some(AssetId::new(3)));
+		assert_eq!(asset.historic_roots(), Some((0, 100)));
+	}
+
+	#[test]
+	fn should_return_pool_stats_at_given_block() {
+		let service = PoolQueryService::new(MockQuery {
+			root: Commitment::new([5u8; 32]),
+			tree_size: 0,
+			total_balance: 1_000,
+		});
+
+		let historic = service
+			.get_pool_stats(None, Some(BlockHash::new([1u8; 32])))
+			.expect("historic pool stats query must succeed");
+		let best = service
+			.get_pool_stats(None, Some(BlockHash::new([8u8; 32])))
+			.expect("best block pool stats query must succeed");
+
+		assert_eq!(historic.total_balance(), 0);
+		assert_eq!(best.total_balance(), 1_000);
+	}
 }
@@ -0,0 +1,152 @@
+//! RootStreamService - Service for detecting Merkle root changes of a block
+
+use crate::orbinum::{
+	application::ApplicationResult,
+	domain::{BlockHash, Commitment, DomainError, ImportedBlock, MerkleTreeQuery, RootUpdate},
+};
+
+/// Service for detecting the Merkle root a block produced.
+///
+/// # Algorithm
+/// 1. Read the root at the parent block and at the block
+/// 2. A different root means the block emitted `MerkleRootUpdated`
+/// 3. Read the tree size at the block for the update
+pub struct RootStreamService<Q> {
+	query: Q,
+}
+
+impl<Q> RootStreamService<Q>
+where
+	Q: MerkleTreeQuery,
+{
+	/// Creates a new `RootStreamService`.
+	pub fn new(query: Q) -> Self {
+		Self { query }
+	}
+
+	/// Returns the root update produced by `block`.
+	///
+	/// # Parameters
+	/// - `block`: Block that became the new best block
+	///
+	/// # Returns
+	/// - `Some(update)` when the block changed the root, `None` otherwise
+	///
+	/// # Errors
+	/// - `Domain`: Storage query errors
+	pub fn root_update(&self, block: &ImportedBlock) -> ApplicationResult<Option<RootUpdate>> {
+		// 1. Root before and after the block
+		let previous_root = self.root_or_none(block.parent_hash())?;
+		let Some(root) = self.root_or_none(block.hash())? else {
+			return Ok(None);
+		};
+
+		// 2. Unchanged root: no `MerkleRootUpdated` in this block
+		if previous_root == Some(root) {
+			return Ok(None);
+		}
+
+		// 3. Tree size the new root commits to
+		let tree_size = self.query.get_tree_size(block.hash())?;
+		Ok(Some(RootUpdate::new(
+			root,
+			tree_size,
+			block.hash(),
+			block.number(),
+		)))
+	}
+
+	/// Returns the root at a block, `None` while the tree is uninitialized.
+	fn root_or_none(&self, block_hash: BlockHash) -> ApplicationResult<Option<Commitment>> {
+		match self.query.get_merkle_root(block_hash) {
+			Ok(root) => Ok(Some(root)),
+			Err(DomainError::MerkleTreeNotInitialized) => Ok(None),
+			Err(e) => Err(e.into()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orbinum::domain::{DomainResult, TreeSize};
+
+	fn parent() -> BlockHash {
+		BlockHash::new([1u8; 32])
+	}
+
+	fn block() -> ImportedBlock {
+		ImportedBlock::new(BlockHash::new([2u8; 32]), parent(), 2)
+	}
+
+	#[derive(Clone, Copy)]
+	struct MockQuery {
+		parent_root: Option<u8>,
+		block_root: u8,
+	}
+
+	impl MerkleTreeQuery for MockQuery {
+		fn get_merkle_root(&self, block_hash: BlockHash) -> DomainResult<Commitment> {
+			let root = if block_hash == parent() {
+				self.parent_root
+			} else {
+				Some(self.block_root)
+			};
+			root.map(|byte| Commitment::new([byte; 32]))
+				.ok_or(DomainError::MerkleTreeNotInitialized)
+		}
+
+		fn get_tree_size(&self, _block_hash: BlockHash) -> DomainResult<TreeSize> {
+			Ok(TreeSize::new(7))
+		}
+
+		fn get_leaf(&self, _block_hash: BlockHash, _leaf_index: u32) -> DomainResult<Commitment> {
+			Ok(Commitment::new([0u8; 32]))
+		}
+	}
+
+	#[test]
+	fn should_report_changed_root() {
+		let service = RootStreamService::new(MockQuery {
+			parent_root: Some(3),
+			block_root: 4,
+		});
+
+		let update = service
+			.root_update(&block())
+			.expect("root_update should succeed")
+			.expect("root changed");
+
+		assert_eq!(update.root(), Commitment::new([4u8; 32]));
+		assert_eq!(update.tree_size().value(), 7);
+		assert_eq!(update.block_number(), 2);
+	}
+
+	#[test]
+	fn should_report_first_root() {
+		let service = RootStreamService::new(MockQuery {
+			parent_root: None,
+			block_root: 4,
+		});
+
+		assert!(service
+			.root_update(&block())
+			.expect("root_update should succeed")
+			.is_some());
+	}
+
+	#[test]
+	fn should_skip_unchanged_root() {
+		let service = RootStreamService::new(MockQuery {
+			parent_root: Some(4),
+			block_root: 4,
+		});
+
+		assert_eq!(
+			service
+				.root_update(&block())
+				.expect("root_update should succeed"),
+			None
+		);
+	}
+}
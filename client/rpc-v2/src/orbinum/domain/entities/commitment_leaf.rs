@@ -0,0 +1,87 @@
+//! CommitmentLeaf entity - Leaf appended to the Merkle tree
+
+use crate::orbinum::domain::{BlockHash, Commitment};
+
+/// Commitment inserted into the Merkle tree by a block.
+///
+/// Holds everything a wallet needs to extend its local tree and
+/// trial-decrypt the note: position, commitment, and encrypted memo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitmentLeaf {
+	/// Leaf index in the Merkle tree.
+	leaf_index: u32,
+	/// Commitment stored at the leaf.
+	commitment: Commitment,
+	/// Encrypted memo attached to the commitment, if any.
+	encrypted_memo: Option<Vec<u8>>,
+	/// Hash of the block that inserted the leaf.
+	block_hash: BlockHash,
+	/// Number of the block that inserted the leaf.
+	block_number: u64,
+}
+
+impl CommitmentLeaf {
+	/// Creates a new `CommitmentLeaf`.
+	pub fn new(
+		leaf_index: u32,
+		commitment: Commitment,
+		encrypted_memo: Option<Vec<u8>>,
+		block_hash: BlockHash,
+		block_number: u64,
+	) -> Self {
+		Self {
+			leaf_index,
+			commitment,
+			encrypted_memo,
+			block_hash,
+			block_number,
+		}
+	}
+
+	/// Returns the leaf index.
+	pub fn leaf_index(&self) -> u32 {
+		self.leaf_index
+	}
+
+	/// Returns the commitment.
+	pub fn commitment(&self) -> Commitment {
+		self.commitment
+	}
+
+	/// Returns the encrypted memo bytes.
+	pub fn encrypted_memo(&self) -> Option<&[u8]> {
+		self.encrypted_memo.as_deref()
+	}
+
+	/// Returns the hash of the inserting block.
+	pub fn block_hash(&self) -> BlockHash {
+		self.block_hash
+	}
+
+	/// Returns the number of the inserting block.
+	pub fn block_number(&self) -> u64 {
+		self.block_number
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_expose_leaf_fields() {
+		let leaf = CommitmentLeaf::new(
+			3,
+			Commitment::new([0xAAu8; 32]),
+			Some(vec![1, 2, 3]),
+			BlockHash::new([4u8; 32]),
+			12,
+		);
+
+		assert_eq!(leaf.leaf_index(), 3);
+		assert_eq!(leaf.commitment(), Commitment::new([0xAAu8; 32]));
+		assert_eq!(leaf.encrypted_memo(), Some(&[1u8, 2, 3][..]));
+		assert_eq!(leaf.block_hash(), BlockHash::new([4u8; 32]));
+		assert_eq!(leaf.block_number(), 12);
+	}
+}
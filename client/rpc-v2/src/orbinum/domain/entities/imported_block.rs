@@ -0,0 +1,57 @@
+//! ImportedBlock entity - Block announced by the block stream
+
+use crate::orbinum::domain::BlockHash;
+
+/// Block that became the new best block.
+///
+/// Carries the parent hash so consumers can diff storage between the
+/// block and the state it was built on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportedBlock {
+	/// Block hash.
+	hash: BlockHash,
+	/// Parent block hash.
+	parent_hash: BlockHash,
+	/// Block number.
+	number: u64,
+}
+
+impl ImportedBlock {
+	/// Creates a new `ImportedBlock`.
+	pub fn new(hash: BlockHash, parent_hash: BlockHash, number: u64) -> Self {
+		Self {
+			hash,
+			parent_hash,
+			number,
+		}
+	}
+
+	/// Returns the block hash.
+	pub fn hash(&self) -> BlockHash {
+		self.hash
+	}
+
+	/// Returns the parent block hash.
+	pub fn parent_hash(&self) -> BlockHash {
+		self.parent_hash
+	}
+
+	/// Returns the block number.
+	pub fn number(&self) -> u64 {
+		self.number
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_expose_block_fields() {
+		let block = ImportedBlock::new(BlockHash::new([2u8; 32]), BlockHash::new([1u8; 32]), 7);
+
+		assert_eq!(block.hash(), BlockHash::new([2u8; 32]));
+		assert_eq!(block.parent_hash(), BlockHash::new([1u8; 32]));
+		assert_eq!(block.number(), 7);
+	}
+}
@@ -4,8 +4,14 @@
 //! pallet entities. For shared entities (`Commitment`, `Nullifier`),
 //! we reuse the ones from `pallet-shielded-pool`.
 
+mod commitment_leaf;
+mod imported_block;
 mod merkle_proof_path;
 mod pool_statistics;
+mod root_update;
 
+pub use commitment_leaf::CommitmentLeaf;
+pub use imported_block::ImportedBlock;
 pub use merkle_proof_path::MerkleProofPath;
 pub use pool_statistics::PoolStatistics;
+pub use root_update::RootUpdate;
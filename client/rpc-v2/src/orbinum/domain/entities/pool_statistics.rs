@@ -1,6 +1,6 @@
 //! PoolStatistics entity - Shielded pool statistics
 
-use crate::orbinum::domain::{ports::PoolBalance, Commitment, TreeDepth, TreeSize};
+use crate::orbinum::domain::{ports::PoolBalance, AssetId, Commitment, TreeDepth, TreeSize};
 
 /// Aggregated shielded pool statistics.
 ///
@@ -10,8 +10,9 @@ use crate::orbinum::domain::{ports::PoolBalance, Commitment, TreeDepth, TreeSize
 /// # Components
 /// - `merkle_root`: Current Merkle tree root
 /// - `commitment_count`: Total number of commitments in the tree
-/// - `total_balance`: Total pool balance (all assets)
+/// - `total_balance`: Total pool balance (all assets, or `asset_id` only)
 /// - `tree_depth`: Merkle tree depth
+/// - `historic_roots`: Historic-root window (stored, maximum), when known
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PoolStatistics {
 	/// Merkle tree root.
@@ -22,6 +23,10 @@ pub struct PoolStatistics {
 	total_balance: PoolBalance,
 	/// Tree depth.
 	tree_depth: TreeDepth,
+	/// Asset the balance refers to (`None` = all assets).
+	asset_id: Option<AssetId>,
+	/// Historic roots stored and the maximum kept.
+	historic_roots: Option<(u32, u32)>,
 }
 
 impl PoolStatistics {
@@ -43,9 +48,23 @@ impl PoolStatistics {
 			commitment_count,
 			total_balance,
 			tree_depth,
+			asset_id: None,
+			historic_roots: None,
 		}
 	}
 
+	/// Scopes the balance to a single asset.
+	pub fn with_asset(mut self, asset_id: Option<AssetId>) -> Self {
+		self.asset_id = asset_id;
+		self
+	}
+
+	/// Sets the historic-root window (roots stored, maximum kept).
+	pub fn with_historic_roots(mut self, stored: u32, max: u32) -> Self {
+		self.historic_roots = Some((stored, max));
+		self
+	}
+
 	/// Returns the tree root.
 	pub fn merkle_root(&self) -> Commitment {
 		self.merkle_root
@@ -66,6 +85,31 @@ impl PoolStatistics {
 		self.tree_depth
 	}
 
+	/// Returns the asset the balance refers to (`None` = all assets).
+	pub fn asset_id(&self) -> Option<AssetId> {
+		self.asset_id
+	}
+
+	/// Returns the historic-root window (roots stored, maximum kept).
+	pub fn historic_roots(&self) -> Option<(u32, u32)> {
+		self.historic_roots
+	}
+
+	/// Returns the number of leaves the tree can hold.
+	pub fn tree_capacity(&self) -> u32 {
+		self.tree_depth.max_leaves()
+	}
+
+	/// Returns tree utilization in basis points (10_000 = full).
+	pub fn tree_utilization_bps(&self) -> u32 {
+		match self.tree_capacity() {
+			0 => 0,
+			capacity => {
+				(u64::from(self.commitment_count.value()) * 10_000 / u64::from(capacity)) as u32
+			}
+		}
+	}
+
 	/// Checks if the pool is initialized (at least one commitment).
 	pub fn is_initialized(&self) -> bool {
 		self.commitment_count.value() > 0
@@ -90,6 +134,36 @@ mod tests {
 		assert_eq!(stats.commitment_count().value(), 10);
 		assert_eq!(stats.total_balance(), 2_500);
 		assert_eq!(stats.tree_depth().value(), 4);
+		assert_eq!(stats.asset_id(), None);
+		assert_eq!(stats.historic_roots(), None);
+	}
+
+	#[test]
+	fn should_report_asset_and_historic_roots() {
+		let stats = PoolStatistics::new(
+			Commitment::new([4u8; 32]),
+			TreeSize::new(10),
+			2_500,
+			TreeDepth::new(4),
+		)
+		.with_asset(Some(AssetId::new(3)))
+		.with_historic_roots(7, 100);
+
+		assert_eq!(stats.asset_id(), Some(AssetId::new(3)));
+		assert_eq!(stats.historic_roots(), Some((7, 100)));
+	}
+
+	#[test]
+	fn should_compute_tree_utilization() {
+		let stats = PoolStatistics::new(
+			Commitment::new([4u8; 32]),
+			TreeSize::new(4),
+			0,
+			TreeDepth::new(4),
+		);
+
+		assert_eq!(stats.tree_capacity(), 16);
+		assert_eq!(stats.tree_utilization_bps(), 2_500);
 	}
 
 	#[test]
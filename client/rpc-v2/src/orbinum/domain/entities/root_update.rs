@@ -0,0 +1,76 @@
+//! RootUpdate entity - Merkle root change produced by a block
+
+use crate::orbinum::domain::{BlockHash, Commitment, TreeSize};
+
+/// New Merkle root produced by a block.
+///
+/// Emitted whenever a block changes the tree root (the pallet's
+/// `MerkleRootUpdated`), so provers can re-anchor their proofs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RootUpdate {
+	/// New Merkle root.
+	root: Commitment,
+	/// Tree size after the block.
+	tree_size: TreeSize,
+	/// Hash of the block that produced the root.
+	block_hash: BlockHash,
+	/// Number of the block that produced the root.
+	block_number: u64,
+}
+
+impl RootUpdate {
+	/// Creates a new `RootUpdate`.
+	pub fn new(
+		root: Commitment,
+		tree_size: TreeSize,
+		block_hash: BlockHash,
+		block_number: u64,
+	) -> Self {
+		Self {
+			root,
+			tree_size,
+			block_hash,
+			block_number,
+		}
+	}
+
+	/// Returns the new root.
+	pub fn root(&self) -> Commitment {
+		self.root
+	}
+
+	/// Returns the tree size after the block.
+	pub fn tree_size(&self) -> TreeSize {
+		self.tree_size
+	}
+
+	/// Returns the hash of the producing block.
+	pub fn block_hash(&self) -> BlockHash {
+		self.block_hash
+	}
+
+	/// Returns the number of the producing block.
+	pub fn block_number(&self) -> u64 {
+		self.block_number
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_expose_update_fields() {
+		let update = RootUpdate::new(
+			Commitment::new([0xAAu8; 32]),
+			TreeSize::new(5),
+			BlockHash::new([4u8; 32]),
+			12,
+		);
+
+		assert_eq!(update.root(), Commitment::new([0xAAu8; 32]));
+		assert_eq!(update.tree_size().value(), 5);
+		assert_eq!(update.block_hash(), BlockHash::new([4u8; 32]));
+		assert_eq!(update.block_number(), 12);
+	}
+}
@@ -20,11 +20,14 @@ pub mod value_objects;
 pub use pallet_shielded_pool::domain::{AssetId, Commitment, Nullifier};
 
 // Re-exports of RPC-specific entities
-pub use entities::{MerkleProofPath, PoolStatistics};
+pub use entities::{CommitmentLeaf, ImportedBlock, MerkleProofPath, PoolStatistics, RootUpdate};
 pub use value_objects::{BlockHash, TreeDepth, TreeSize};
 
 // Re-exports of ports
-pub use ports::{BlockchainQuery, MerkleTreeQuery, NullifierQuery, PoolQuery};
+pub use ports::{
+	BlockStream, BlockchainQuery, MemoQuery, MerkleTreeQuery, NullifierQuery, PoolQuery,
+	PoolStatsQuery, SpentNullifierQuery,
+};
 
 /// RPC domain error type.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -43,6 +46,8 @@ pub enum DomainError {
 	PoolNotInitialized,
 	/// Storage decoding error.
 	StorageDecodeError(String),
+	/// Runtime API call failed.
+	RuntimeApiError(String),
 	/// Calculation error.
 	CalculationError(String),
 }
@@ -62,6 +67,7 @@ impl core::fmt::Display for DomainError {
 			Self::NullifierNotFound => write!(f, "Nullifier not found"),
 			Self::PoolNotInitialized => write!(f, "Pool not initialized"),
 			Self::StorageDecodeError(msg) => write!(f, "Storage decode error: {msg}"),
+			Self::RuntimeApiError(msg) => write!(f, "Runtime API error: {msg}"),
 			Self::CalculationError(msg) => write!(f, "Calculation error: {msg}"),
 		}
 	}
@@ -105,6 +111,10 @@ mod tests {
 			DomainError::StorageDecodeError("bad scale".to_string()).to_string(),
 			"Storage decode error: bad scale"
 		);
+		assert_eq!(
+			DomainError::RuntimeApiError("unknown method".to_string()).to_string(),
+			"Runtime API error: unknown method"
+		);
 		assert_eq!(
 			DomainError::CalculationError("overflow".to_string()).to_string(),
 			"Calculation error: overflow"
@@ -0,0 +1,39 @@
+//! BlockStream port - Interface for new best block notifications
+
+use futures::stream::BoxStream;
+
+use crate::orbinum::domain::ImportedBlock;
+
+/// Port for following the chain head.
+///
+/// Abstracts block import notifications so subscriptions can react to
+/// new blocks without depending on `sc_client_api`.
+pub trait BlockStream: Send + Sync {
+	/// Returns a stream yielding every block that becomes the new best block.
+	fn best_block_stream(&self) -> BoxStream<'static, ImportedBlock>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orbinum::domain::BlockHash;
+	use futures::{executor::block_on, stream, StreamExt};
+
+	#[derive(Clone, Copy)]
+	struct MockBlockStream;
+
+	impl BlockStream for MockBlockStream {
+		fn best_block_stream(&self) -> BoxStream<'static, ImportedBlock> {
+			let block = ImportedBlock::new(BlockHash::new([2u8; 32]), BlockHash::new([1u8; 32]), 1);
+			stream::iter(vec![block]).boxed()
+		}
+	}
+
+	#[test]
+	fn should_yield_best_blocks() {
+		let blocks: Vec<_> = block_on(MockBlockStream.best_block_stream().collect());
+
+		assert_eq!(blocks.len(), 1);
+		assert_eq!(blocks[0].number(), 1);
+	}
+}
@@ -0,0 +1,60 @@
+//! MemoQuery port - Interface for encrypted memo queries
+
+use crate::orbinum::domain::{BlockHash, Commitment, DomainResult};
+
+/// Port for querying encrypted memos attached to commitments.
+///
+/// This trait abstracts access to pallet `CommitmentMemos` storage.
+pub trait MemoQuery: Send + Sync {
+	/// Returns the encrypted memo stored for a commitment.
+	///
+	/// # Parameters
+	/// - `block_hash`: Block hash to query
+	/// - `commitment`: Commitment the memo is attached to
+	///
+	/// # Returns
+	/// - `Some(Vec<u8>)`: Raw encrypted memo bytes
+	/// - `None`: No memo stored for the commitment
+	fn get_encrypted_memo(
+		&self,
+		block_hash: BlockHash,
+		commitment: Commitment,
+	) -> DomainResult<Option<Vec<u8>>>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Clone, Copy)]
+	struct MockMemoQuery;
+
+	impl MemoQuery for MockMemoQuery {
+		fn get_encrypted_memo(
+			&self,
+			_block_hash: BlockHash,
+			commitment: Commitment,
+		) -> DomainResult<Option<Vec<u8>>> {
+			Ok((commitment.as_bytes()[0] == 1).then(|| vec![0xEE; 4]))
+		}
+	}
+
+	#[test]
+	fn should_return_memo_when_stored() {
+		let query = MockMemoQuery;
+		let block_hash = BlockHash::new([3u8; 32]);
+
+		assert_eq!(
+			query
+				.get_encrypted_memo(block_hash, Commitment::new([1u8; 32]))
+				.expect("memo query should succeed"),
+			Some(vec![0xEE; 4])
+		);
+		assert_eq!(
+			query
+				.get_encrypted_memo(block_hash, Commitment::new([2u8; 32]))
+				.expect("memo query should succeed"),
+			None
+		);
+	}
+}
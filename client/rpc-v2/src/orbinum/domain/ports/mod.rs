@@ -4,12 +4,20 @@
 //! adapters. This decouples the domain from implementation details
 //! (Substrate, storage, etc.).
 
+mod block_stream;
 mod blockchain_query;
+mod memo_query;
 mod merkle_tree_query;
 mod nullifier_query;
 mod pool_query;
+mod pool_stats_query;
+mod spent_nullifier_query;
 
+pub use block_stream::BlockStream;
 pub use blockchain_query::BlockchainQuery;
+pub use memo_query::MemoQuery;
 pub use merkle_tree_query::MerkleTreeQuery;
 pub use nullifier_query::NullifierQuery;
 pub use pool_query::{PoolBalance, PoolQuery};
+pub use pool_stats_query::PoolStatsQuery;
+pub use spent_nullifier_query::SpentNullifierQuery;
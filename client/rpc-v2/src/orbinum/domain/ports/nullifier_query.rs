@@ -7,6 +7,21 @@ use crate::orbinum::domain::{BlockHash, DomainResult, Nullifier};
 /// Nullifiers prevent double-spending by marking notes as spent.
 /// This trait abstracts access to pallet `NullifierSet` storage.
 pub trait NullifierQuery: Send + Sync {
+	/// Returns the block in which a nullifier was spent.
+	///
+	/// # Parameters
+	/// - `block_hash`: Block hash to query
+	/// - `nullifier`: Nullifier to check
+	///
+	/// # Returns
+	/// - `Some(block_number)` if nullifier exists in the set (already spent)
+	/// - `None` if nullifier is still available
+	fn get_spent_block(
+		&self,
+		block_hash: BlockHash,
+		nullifier: Nullifier,
+	) -> DomainResult<Option<u64>>;
+
 	/// Checks whether a nullifier has been spent.
 	///
 	/// # Parameters
@@ -16,8 +31,13 @@ pub trait NullifierQuery: Send + Sync {
 	/// # Returns
 	/// - `true` if nullifier exists in the set (already spent)
 	/// - `false` if nullifier is still available
-	fn is_nullifier_spent(&self, block_hash: BlockHash, nullifier: Nullifier)
-		-> DomainResult<bool>;
+	fn is_nullifier_spent(
+		&self,
+		block_hash: BlockHash,
+		nullifier: Nullifier,
+	) -> DomainResult<bool> {
+		Ok(self.get_spent_block(block_hash, nullifier)?.is_some())
+	}
 }
 
 #[cfg(test)]
@@ -28,12 +48,12 @@ mod tests {
 	struct MockNullifierQuery;
 
 	impl NullifierQuery for MockNullifierQuery {
-		fn is_nullifier_spent(
+		fn get_spent_block(
 			&self,
 			_block_hash: BlockHash,
 			nullifier: Nullifier,
-		) -> DomainResult<bool> {
-			Ok(nullifier.as_bytes()[0] == 0xFF)
+		) -> DomainResult<Option<u64>> {
+			Ok((nullifier.as_bytes()[0] == 0xFF).then_some(8))
 		}
 	}
 
@@ -51,4 +71,23 @@ mod tests {
 			.is_nullifier_spent(block_hash, available)
 			.expect("nullifier query should succeed"));
 	}
+
+	#[test]
+	fn should_return_spent_block() {
+		let query = MockNullifierQuery;
+		let block_hash = BlockHash::new([3u8; 32]);
+
+		assert_eq!(
+			query
+				.get_spent_block(block_hash, Nullifier::new([0xFFu8; 32]))
+				.expect("nullifier query should succeed"),
+			Some(8)
+		);
+		assert_eq!(
+			query
+				.get_spent_block(block_hash, Nullifier::new([0x01u8; 32]))
+				.expect("nullifier query should succeed"),
+			None
+		);
+	}
 }
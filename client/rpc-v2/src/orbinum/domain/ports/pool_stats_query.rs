@@ -0,0 +1,60 @@
+//! PoolStatsQuery port - Interface for runtime pool statistics
+
+use crate::orbinum::domain::{AssetId, BlockHash, DomainResult, PoolStatistics};
+
+/// Port for querying pool statistics computed by the runtime.
+///
+/// Unlike `PoolQuery`, which decodes individual storage items, the runtime
+/// also reports the configured tree depth and historic-root window.
+pub trait PoolStatsQuery: Send + Sync {
+	/// Returns pool statistics at a block.
+	///
+	/// # Parameters
+	/// - `block_hash`: Block hash to query
+	/// - `asset_id`: Asset the balance refers to (`None` = total pool balance)
+	///
+	/// # Returns
+	/// - `PoolStatistics`: Pool snapshot including the historic-root window
+	fn get_pool_stats(
+		&self,
+		block_hash: BlockHash,
+		asset_id: Option<AssetId>,
+	) -> DomainResult<PoolStatistics>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orbinum::domain::{Commitment, TreeDepth, TreeSize};
+
+	#[derive(Clone, Copy)]
+	struct MockPoolStatsQuery;
+
+	impl PoolStatsQuery for MockPoolStatsQuery {
+		fn get_pool_stats(
+			&self,
+			_block_hash: BlockHash,
+			asset_id: Option<AssetId>,
+		) -> DomainResult<PoolStatistics> {
+			Ok(PoolStatistics::new(
+				Commitment::new([1u8; 32]),
+				TreeSize::new(3),
+				asset_id.map_or(100, |id| id.inner() as u128),
+				TreeDepth::new(20),
+			)
+			.with_asset(asset_id))
+		}
+	}
+
+	#[test]
+	fn should_return_pool_stats_for_asset() {
+		let query = MockPoolStatsQuery;
+
+		let stats = query
+			.get_pool_stats(BlockHash::new([4u8; 32]), Some(AssetId::new(7)))
+			.expect("pool stats query should succeed");
+
+		assert_eq!(stats.asset_id(), Some(AssetId::new(7)));
+		assert_eq!(stats.total_balance(), 7);
+	}
+}
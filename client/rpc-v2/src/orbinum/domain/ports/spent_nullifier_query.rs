@@ -0,0 +1,43 @@
+//! SpentNullifierQuery port - Interface for per-block nullifier queries
+
+use crate::orbinum::domain::{BlockHash, DomainResult, Nullifier};
+
+/// Port for querying the nullifiers a block consumed.
+///
+/// `NullifierSet` cannot be enumerated per block, so implementations read
+/// the block's spend events instead (through the runtime API).
+pub trait SpentNullifierQuery: Send + Sync {
+	/// Returns the nullifiers spent in a block.
+	///
+	/// # Parameters
+	/// - `block_hash`: Block hash to query
+	///
+	/// # Returns
+	/// - Nullifiers spent by the block, in event order
+	fn get_spent_nullifiers(&self, block_hash: BlockHash) -> DomainResult<Vec<Nullifier>>;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Clone, Copy)]
+	struct MockSpentNullifierQuery;
+
+	impl SpentNullifierQuery for MockSpentNullifierQuery {
+		fn get_spent_nullifiers(&self, block_hash: BlockHash) -> DomainResult<Vec<Nullifier>> {
+			Ok(vec![Nullifier::new(*block_hash.as_bytes())])
+		}
+	}
+
+	#[test]
+	fn should_return_spent_nullifiers() {
+		let query = MockSpentNullifierQuery;
+
+		let nullifiers = query
+			.get_spent_nullifiers(BlockHash::new([4u8; 32]))
+			.expect("spent nullifier query should succeed");
+
+		assert_eq!(nullifiers, vec![Nullifier::new([4u8; 32])]);
+	}
+}
@@ -1,15 +1,19 @@
 //! SubstrateStorageAdapter - Implements ports using a Substrate client
 
-use sc_client_api::StorageProvider as ScStorageProvider;
+use futures::{stream::BoxStream, StreamExt};
+use pallet_shielded_pool_runtime_api::{ShieldedPoolRuntimeApi, SHIELDED_POOL_API_V2};
+use sc_client_api::{BlockchainEvents, StorageProvider as ScStorageProvider};
 use scale_codec::Decode;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block as BlockT;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
 use std::{marker::PhantomData, sync::Arc};
 
 use crate::orbinum::{
 	domain::{
-		AssetId, BlockHash, BlockchainQuery, Commitment, DomainError, DomainResult,
-		MerkleTreeQuery, Nullifier, NullifierQuery, PoolQuery, TreeSize,
+		AssetId, BlockHash, BlockStream, BlockchainQuery, Commitment, DomainError, DomainResult,
+		ImportedBlock, MemoQuery, MerkleTreeQuery, Nullifier, NullifierQuery, PoolQuery,
+		PoolStatistics, PoolStatsQuery, SpentNullifierQuery, TreeDepth, TreeSize,
 	},
 	infrastructure::{mappers::DomainMapper, storage::storage_keys},
 };
@@ -47,6 +51,38 @@ impl<C, B, BE> SubstrateStorageAdapter<C, B, BE> {
 	}
 }
 
+impl<C, B: BlockT, BE> SubstrateStorageAdapter<C, B, BE> {
+	/// Converts a domain `BlockHash` to the Substrate block hash type.
+	fn substrate_hash(block_hash: BlockHash) -> DomainResult<B::Hash> {
+		<B as BlockT>::Hash::decode(&mut block_hash.as_slice())
+			.map_err(|_| DomainError::CalculationError("Invalid block hash".to_string()))
+	}
+}
+
+impl<C, B, BE> SubstrateStorageAdapter<C, B, BE>
+where
+	C: ProvideRuntimeApi<B>,
+	C::Api: ShieldedPoolRuntimeApi<B>,
+	B: BlockT,
+{
+	/// Fails unless the runtime at `hash` serves the queries added in
+	/// version 2 of `ShieldedPoolRuntimeApi`, which blocks before the
+	/// upgrade lack.
+	fn ensure_pool_api_v2(&self, hash: B::Hash) -> DomainResult<()> {
+		let version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn ShieldedPoolRuntimeApi<B>>(hash)
+			.map_err(|e| DomainError::RuntimeApiError(format!("{e:?}")))?;
+		match version {
+			Some(version) if version >= SHIELDED_POOL_API_V2 => Ok(()),
+			_ => Err(DomainError::RuntimeApiError(
+				"Not supported by the runtime at this block".to_string(),
+			)),
+		}
+	}
+}
+
 // ============================================================================
 // BlockchainQuery implementation
 // ============================================================================
@@ -72,9 +108,7 @@ where
 		storage_key: &[u8],
 	) -> DomainResult<Option<Vec<u8>>> {
 		// Convert domain `BlockHash` to Substrate hash
-		let block_h256 = sp_core::H256::from_slice(block_hash.as_bytes());
-		let hash = <B as BlockT>::Hash::decode(&mut block_h256.as_bytes())
-			.map_err(|_| DomainError::CalculationError("Invalid block hash".to_string()))?;
+		let hash = Self::substrate_hash(block_hash)?;
 
 		// Convert `storage_key` into Substrate `StorageKey`
 		let storage_key = sp_core::storage::StorageKey(storage_key.to_vec());
@@ -148,23 +182,24 @@ where
 
 impl<C, B, BE> NullifierQuery for SubstrateStorageAdapter<C, B, BE>
 where
-	C: HeaderBackend<B> + ScStorageProvider<B, BE> + Send + Sync,
+	C: ProvideRuntimeApi<B> + Send + Sync,
+	C::Api: ShieldedPoolRuntimeApi<B>,
 	B: BlockT,
-	BE: sc_client_api::Backend<B> + Send + Sync,
+	BE: Send + Sync,
 {
-	fn is_nullifier_spent(
+	fn get_spent_block(
 		&self,
 		block_hash: BlockHash,
 		nullifier: Nullifier,
-	) -> DomainResult<bool> {
-		// Convert domain `Nullifier` to `H256`
-		let nullifier_h256 = DomainMapper::nullifier_to_h256(nullifier);
-
-		let storage_key = storage_keys::nullifier_spent(&nullifier_h256);
-		let data = self.storage_at(block_hash, &storage_key)?;
-
-		// If any data exists in storage, the nullifier is spent
-		Ok(data.is_some())
+	) -> DomainResult<Option<u64>> {
+		let hash = Self::substrate_hash(block_hash)?;
+		self.ensure_pool_api_v2(hash)?;
+
+		// The runtime reads `NullifierSet` and returns the spend block
+		self.client
+			.runtime_api()
+			.get_nullifier_spent_block(hash, *nullifier.as_bytes())
+			.map_err(|e| DomainError::RuntimeApiError(format!("{e:?}")))
 	}
 }
 
@@ -209,6 +244,126 @@ where
 	}
 }
 
+// ============================================================================
+// MemoQuery implementation
+// ============================================================================
+
+impl<C, B, BE> MemoQuery for SubstrateStorageAdapter<C, B, BE>
+where
+	C: HeaderBackend<B> + ScStorageProvider<B, BE> + Send + Sync,
+	B: BlockT,
+	BE: sc_client_api::Backend<B> + Send + Sync,
+{
+	fn get_encrypted_memo(
+		&self,
+		block_hash: BlockHash,
+		commitment: Commitment,
+	) -> DomainResult<Option<Vec<u8>>> {
+		let commitment_h256 = DomainMapper::commitment_to_h256(commitment);
+
+		let storage_key = storage_keys::commitment_memo(&commitment_h256);
+		let Some(data) = self.storage_at(block_hash, &storage_key)? else {
+			return Ok(None);
+		};
+
+		// `EncryptedMemo` is a SCALE-encoded `BoundedVec<u8, _>`
+		let memo = Vec::<u8>::decode(&mut &data[..]).map_err(|e| {
+			DomainError::StorageDecodeError(format!("Failed to decode encrypted memo: {e}"))
+		})?;
+
+		Ok(Some(memo))
+	}
+}
+
+// ============================================================================
+// PoolStatsQuery implementation
+// ============================================================================
+
+impl<C, B, BE> PoolStatsQuery for SubstrateStorageAdapter<C, B, BE>
+where
+	C: ProvideRuntimeApi<B> + Send + Sync,
+	C::Api: ShieldedPoolRuntimeApi<B>,
+	B: BlockT,
+	BE: Send + Sync,
+{
+	fn get_pool_stats(
+		&self,
+		block_hash: BlockHash,
+		asset_id: Option<AssetId>,
+	) -> DomainResult<PoolStatistics> {
+		let hash = Self::substrate_hash(block_hash)?;
+		self.ensure_pool_api_v2(hash)?;
+
+		let stats = self
+			.client
+			.runtime_api()
+			.get_pool_stats(hash, asset_id.map(|id| id.inner()))
+			.map_err(|e| DomainError::RuntimeApiError(format!("{e:?}")))?;
+
+		Ok(PoolStatistics::new(
+			Commitment::new(stats.merkle_root),
+			TreeSize::new(stats.commitment_count),
+			stats.balance,
+			TreeDepth::new(stats.tree_depth),
+		)
+		.with_asset(stats.asset_id.map(AssetId::new))
+		.with_historic_roots(stats.historic_roots, stats.max_historic_roots))
+	}
+}
+
+// ============================================================================
+// SpentNullifierQuery implementation
+// ============================================================================
+
+impl<C, B, BE> SpentNullifierQuery for SubstrateStorageAdapter<C, B, BE>
+where
+	C: ProvideRuntimeApi<B> + Send + Sync,
+	C::Api: ShieldedPoolRuntimeApi<B>,
+	B: BlockT,
+	BE: Send + Sync,
+{
+	fn get_spent_nullifiers(&self, block_hash: BlockHash) -> DomainResult<Vec<Nullifier>> {
+		let hash = Self::substrate_hash(block_hash)?;
+		self.ensure_pool_api_v2(hash)?;
+
+		// Spends are only visible through the block's events
+		let nullifiers = self
+			.client
+			.runtime_api()
+			.get_spent_nullifiers(hash)
+			.map_err(|e| DomainError::RuntimeApiError(format!("{e:?}")))?;
+
+		Ok(nullifiers.into_iter().map(Nullifier::new).collect())
+	}
+}
+
+// ============================================================================
+// BlockStream implementation
+// ============================================================================
+
+impl<C, B, BE> BlockStream for SubstrateStorageAdapter<C, B, BE>
+where
+	C: BlockchainEvents<B> + Send + Sync,
+	B: BlockT,
+	BE: Send + Sync,
+{
+	fn best_block_stream(&self) -> BoxStream<'static, ImportedBlock> {
+		self.client
+			.import_notification_stream()
+			.filter_map(|notification| async move {
+				if !notification.is_new_best {
+					return None;
+				}
+				let hash = BlockHash::from_slice(notification.hash.as_ref())?;
+				let parent_hash =
+					BlockHash::from_slice(notification.header.parent_hash().as_ref())?;
+				let number: u64 = (*notification.header.number()).unique_saturated_into();
+				Some(ImportedBlock::new(hash, parent_hash, number))
+			})
+			.boxed()
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -122,6 +122,27 @@ pub fn nullifier_spent(nullifier: &H256) -> Vec<u8> {
 	key
 }
 
+/// Builds the storage key for `CommitmentMemos` map (`StorageMap<Commitment, EncryptedMemo>`).
+///
+/// # Storage Item
+/// `pallet_shielded_pool::CommitmentMemos::<T>::get(commitment)`
+///
+/// # Parameters
+/// - `commitment`: Commitment hash (`H256`)
+///
+/// # Returns
+/// `twox_128("ShieldedPool") + twox_128("CommitmentMemos") + blake2_128_concat(commitment)`
+pub fn commitment_memo(commitment: &H256) -> Vec<u8> {
+	let mut key = twox_128(PALLET_SHIELDED_POOL)
+		.iter()
+		.chain(twox_128(b"CommitmentMemos").iter())
+		.copied()
+		.collect::<Vec<_>>();
+
+	key.extend_from_slice(&blake2_128_concat(commitment.as_bytes()));
+	key
+}
+
 /// Helper: `blake2_128_concat` hashing.
 ///
 /// # Algorithm
@@ -171,6 +192,20 @@ mod tests {
 		assert_eq!(key.len(), 80);
 	}
 
+	#[test]
+	fn should_build_commitment_memo_key_with_expected_prefix() {
+		let commitment = H256::from([7u8; 32]);
+		let key = commitment_memo(&commitment);
+
+		// 32 bytes prefix + 16-byte hash + 32-byte commitment
+		assert_eq!(key.len(), 80);
+		assert_eq!(
+			&key[..32],
+			[twox_128(PALLET_SHIELDED_POOL), twox_128(b"CommitmentMemos")].concat()
+		);
+		assert_eq!(&key[48..], commitment.as_bytes());
+	}
+
 	#[test]
 	fn should_build_pool_balance_keys_with_expected_lengths() {
 		let total = pool_balance();
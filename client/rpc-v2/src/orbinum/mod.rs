@@ -13,6 +13,7 @@
 //! │  (API trait, Server, Handlers, Validation)              │
 //! │  - PrivacyApiServer                                     │
 //! │  - PrivacyRpcServer                                     │
+//! │  - PrivacyPubSubServer                                  │
 //! │  - Handlers (MerkleProofHandler, etc.)                  │
 //! └───────────────────┬─────────────────────────────────────┘
 //!                     │
@@ -23,6 +24,9 @@
 //! │  - MerkleProofService                                   │
 //! │  - PoolQueryService                                     │
 //! │  - NullifierService                                     │
+//! │  - CommitmentStreamService                              │
+//! │  - NullifierStreamService                               │
+//! │  - RootStreamService                                    │
 //! └───────────────────┬─────────────────────────────────────┘
 //!                     │
 //!                     ▼
@@ -73,19 +77,25 @@ pub mod presentation;
 
 // Domain layer
 pub use domain::{
-	AssetId, BlockHash, BlockchainQuery, Commitment, DomainError, DomainResult,
-	MerkleProofPath as DomainMerkleProofPath, MerkleTreeQuery, Nullifier, NullifierQuery,
-	PoolQuery, PoolStatistics, TreeDepth, TreeSize,
+	AssetId, BlockHash, BlockStream, BlockchainQuery, Commitment, CommitmentLeaf, DomainError,
+	DomainResult, ImportedBlock, MemoQuery, MerkleProofPath as DomainMerkleProofPath,
+	MerkleTreeQuery, Nullifier, NullifierQuery, PoolQuery, PoolStatistics, PoolStatsQuery,
+	RootUpdate, SpentNullifierQuery, TreeDepth, TreeSize,
 };
 
 // Application layer
 pub use application::{
-	ApplicationError, ApplicationResult, MerkleProofResponse, MerkleProofService, NullifierService,
-	NullifierStatusResponse, PoolQueryService, PoolStatsResponse,
+	ApplicationError, ApplicationResult, CommitmentNotification, CommitmentStreamService,
+	MerkleProofResponse, MerkleProofService, NullifierNotification, NullifierService,
+	NullifierStatusResponse, NullifierStreamService, PoolQueryService, PoolStatsResponse,
+	RootNotification, RootStreamService,
 };
 
 // Infrastructure layer
 pub use infrastructure::{CommitmentMapper, DomainMapper, SubstrateStorageAdapter};
 
 // Presentation layer
-pub use presentation::{PrivacyApiServer, PrivacyRpcServer};
+pub use presentation::{
+	CostClass, DeprecationLog, PrivacyApiServer, PrivacyPubSubApiServer, PrivacyPubSubServer,
+	PrivacyRpcServer, RateLimitConfig, RateLimiter, RpcMetrics,
+};
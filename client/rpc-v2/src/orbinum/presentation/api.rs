@@ -15,6 +15,16 @@ use crate::orbinum::application::{
 /// - `privacy_getMerkleProof`: Fetch Merkle proof for a commitment leaf
 /// - `privacy_getNullifierStatus`: Check whether a nullifier is spent
 /// - `privacy_getPoolStats`: Fetch pool statistics
+/// - `orbinum_v2_isNullifierSpent`: Check whether and where a nullifier was spent
+/// - `orbinum_v2_getPoolStats`: Fetch runtime pool statistics, optionally per asset
+///
+/// The `shieldedPool_isNullifierSpent` and `shieldedPool_getPoolStats` names
+/// of the last two are deprecated aliases: they answer like the `orbinum_v2_`
+/// methods and log their use.
+///
+/// With a [`RateLimiter`](crate::orbinum::presentation::RateLimiter) installed,
+/// `privacy_getMerkleProof` is charged as a heavy call and every other method
+/// as a light one.
 #[rpc(server)]
 pub trait PrivacyApi {
 	/// Returns the current Merkle tree root.
@@ -40,7 +50,7 @@ pub trait PrivacyApi {
 	///   "id": 1
 	/// }
 	/// ```
-	#[method(name = "privacy_getMerkleRoot")]
+	#[method(name = "privacy_getMerkleRoot", with_extensions)]
 	fn get_merkle_root(&self) -> RpcResult<String>;
 
 	/// Returns a Merkle proof for a leaf at the given index.
@@ -73,7 +83,7 @@ pub trait PrivacyApi {
 	///   "id": 1
 	/// }
 	/// ```
-	#[method(name = "privacy_getMerkleProof")]
+	#[method(name = "privacy_getMerkleProof", with_extensions)]
 	fn get_merkle_proof(&self, leaf_index: u32) -> RpcResult<MerkleProofResponse>;
 
 	/// Checks whether a nullifier has been spent.
@@ -100,12 +110,13 @@ pub trait PrivacyApi {
 	///   "jsonrpc": "2.0",
 	///   "result": {
 	///     "nullifier": "0xabcd...1234",
-	///     "is_spent": true
+	///     "is_spent": true,
+	///     "block": 1200
 	///   },
 	///   "id": 1
 	/// }
 	/// ```
-	#[method(name = "privacy_getNullifierStatus")]
+	#[method(name = "privacy_getNullifierStatus", with_extensions)]
 	fn get_nullifier_status(&self, nullifier: String) -> RpcResult<NullifierStatusResponse>;
 
 	/// Returns complete shielded pool statistics.
@@ -136,6 +147,106 @@ pub trait PrivacyApi {
 	///   "id": 1
 	/// }
 	/// ```
-	#[method(name = "privacy_getPoolStats")]
+	#[method(name = "privacy_getPoolStats", with_extensions)]
 	fn get_pool_stats(&self) -> RpcResult<PoolStatsResponse>;
+
+	/// Checks whether a nullifier has been spent and in which block.
+	///
+	/// Backed by the `ShieldedPoolRuntimeApi::get_nullifier_spent_block`
+	/// runtime API, which reads `NullifierSet`.
+	///
+	/// # Parameters
+	/// - `nullifier`: Nullifier hash hex string (with or without `0x`)
+	///
+	/// # Returns
+	/// - `NullifierStatusResponse`: Nullifier status (`is_spent`, `block`)
+	///
+	/// # Example
+	/// ```json
+	/// {
+	///   "jsonrpc": "2.0",
+	///   "method": "orbinum_v2_isNullifierSpent",
+	///   "params": ["0xabcd...1234"],
+	///   "id": 1
+	/// }
+	/// ```
+	///
+	/// # Response
+	/// ```json
+	/// {
+	///   "jsonrpc": "2.0",
+	///   "result": {
+	///     "nullifier": "0xabcd...1234",
+	///     "is_spent": false,
+	///     "block": null
+	///   },
+	///   "id": 1
+	/// }
+	/// ```
+	#[method(name = "orbinum_v2_isNullifierSpent", with_extensions)]
+	fn is_nullifier_spent(&self, nullifier: String) -> RpcResult<NullifierStatusResponse>;
+
+	/// Deprecated alias of `orbinum_v2_isNullifierSpent`.
+	#[method(name = "shieldedPool_isNullifierSpent", with_extensions)]
+	fn is_nullifier_spent_deprecated(
+		&self,
+		nullifier: String,
+	) -> RpcResult<NullifierStatusResponse>;
+
+	/// Returns pool statistics, optionally scoped to one asset.
+	///
+	/// Backed by the `ShieldedPoolRuntimeApi::get_pool_stats` runtime API.
+	/// Note counts and tree utilization are pool-wide; only the balance is
+	/// scoped to `asset_id`.
+	///
+	/// # Parameters
+	/// - `asset_id`: Asset identifier (omit or `null` for the total pool balance)
+	/// - `at`: Hex block hash to query (omit for the best block). Blocks whose
+	///   state was pruned need an archive node.
+	///
+	/// # Returns
+	/// - `PoolStatsResponse`: Pool stats including tree utilization and historic-root window
+	///
+	/// # Example
+	/// ```json
+	/// {
+	///   "jsonrpc": "2.0",
+	///   "method": "orbinum_v2_getPoolStats",
+	///   "params": [0, "0xabcd...1234"],
+	///   "id": 1
+	/// }
+	/// ```
+	///
+	/// # Response
+	/// ```json
+	/// {
+	///   "jsonrpc": "2.0",
+	///   "result": {
+	///     "merkle_root": "0x1234...abcd",
+	///     "commitment_count": 100,
+	///     "total_balance": 1000000000000000000,
+	///     "tree_depth": 20,
+	///     "asset_id": 0,
+	///     "tree_capacity": 1048576,
+	///     "tree_utilization_bps": 0,
+	///     "historic_roots": 100,
+	///     "max_historic_roots": 100
+	///   },
+	///   "id": 1
+	/// }
+	/// ```
+	#[method(name = "orbinum_v2_getPoolStats", with_extensions)]
+	fn get_pool_stats_for_asset(
+		&self,
+		asset_id: Option<u32>,
+		at: Option<String>,
+	) -> RpcResult<PoolStatsResponse>;
+
+	/// Deprecated alias of `orbinum_v2_getPoolStats`.
+	#[method(name = "shieldedPool_getPoolStats", with_extensions)]
+	fn get_pool_stats_for_asset_deprecated(
+		&self,
+		asset_id: Option<u32>,
+		at: Option<String>,
+	) -> RpcResult<PoolStatsResponse>;
 }
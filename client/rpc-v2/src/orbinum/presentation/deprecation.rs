@@ -0,0 +1,63 @@
+//! DeprecationLog - Usage logging of deprecated privacy RPC method names
+
+use std::{
+	collections::HashSet,
+	sync::{Mutex, PoisonError},
+};
+
+/// Logs calls to deprecated method names.
+///
+/// The first call of each deprecated name is logged as a warning naming its
+/// replacement, later calls at debug level so busy wallets don't flood the
+/// node log. Per-method request metrics keep counting every call under the
+/// deprecated name.
+#[derive(Default)]
+pub struct DeprecationLog {
+	/// Deprecated names already warned about.
+	warned: Mutex<HashSet<&'static str>>,
+}
+
+impl DeprecationLog {
+	/// Creates a new `DeprecationLog`.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Records one call of the deprecated `method`, replaced by `replacement`.
+	///
+	/// Returns `true` if this call was logged as a warning.
+	pub fn record(&self, method: &'static str, replacement: &'static str) -> bool {
+		let first = self
+			.warned
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.insert(method);
+
+		if first {
+			log::warn!(
+				target: "privacy-rpc",
+				"`{method}` is deprecated and will be removed, use `{replacement}` instead",
+			);
+		} else {
+			log::debug!(target: "privacy-rpc", "Deprecated `{method}` called");
+		}
+		first
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_warn_once_per_method() {
+		let log = DeprecationLog::new();
+
+		assert!(log.record("shieldedPool_getPoolStats", "orbinum_v2_getPoolStats"));
+		assert!(!log.record("shieldedPool_getPoolStats", "orbinum_v2_getPoolStats"));
+		assert!(log.record(
+			"shieldedPool_isNullifierSpent",
+			"orbinum_v2_isNullifierSpent"
+		));
+	}
+}
@@ -0,0 +1,132 @@
+//! CommitmentSubscriptionHandler - Handler for commitment subscription notifications
+
+use std::sync::Arc;
+
+use crate::orbinum::{
+	application::{CommitmentNotification, CommitmentStreamService},
+	domain::{CommitmentLeaf, ImportedBlock},
+	infrastructure::mappers::CommitmentMapper,
+};
+
+/// Handler for `orbinum_v2_subscribeCommitments`.
+///
+/// Turns each new best block into one notification per appended leaf.
+pub struct CommitmentSubscriptionHandler<Q> {
+	stream_service: Arc<CommitmentStreamService<Q>>,
+}
+
+impl<Q> CommitmentSubscriptionHandler<Q>
+where
+	Q: crate::orbinum::domain::MerkleTreeQuery + crate::orbinum::domain::MemoQuery,
+{
+	/// Creates a new `CommitmentSubscriptionHandler`.
+	pub fn new(stream_service: Arc<CommitmentStreamService<Q>>) -> Self {
+		Self { stream_service }
+	}
+
+	/// Handles a new best block.
+	///
+	/// # Returns
+	/// - One `CommitmentNotification` per leaf appended by the block
+	///
+	/// Storage errors are logged and yield no notifications, so a single
+	/// unreadable block does not terminate the subscription.
+	pub fn handle(&self, block: &ImportedBlock) -> Vec<CommitmentNotification> {
+		match self.stream_service.new_leaves(block) {
+			Ok(leaves) => leaves.iter().map(Self::to_notification).collect(),
+			Err(e) => {
+				log::warn!(
+					target: "privacy-rpc",
+					"Failed to collect commitments for block #{}: {e}",
+					block.number()
+				);
+				Vec::new()
+			}
+		}
+	}
+
+	/// Maps a domain leaf to its notification DTO.
+	fn to_notification(leaf: &CommitmentLeaf) -> CommitmentNotification {
+		CommitmentNotification::new(
+			leaf.leaf_index(),
+			CommitmentMapper::to_hex_string(leaf.commitment()),
+			leaf.encrypted_memo()
+				.map(|memo| format!("0x{}", hex::encode(memo))),
+			leaf.block_number(),
+			format!("0x{}", hex::encode(leaf.block_hash().as_bytes())),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orbinum::domain::{
+		BlockHash, Commitment, DomainError, DomainResult, MemoQuery, MerkleTreeQuery, TreeSize,
+	};
+
+	#[derive(Clone, Copy)]
+	struct MockQuery {
+		fail: bool,
+	}
+
+	impl MerkleTreeQuery for MockQuery {
+		fn get_merkle_root(&self, _block_hash: BlockHash) -> DomainResult<Commitment> {
+			Ok(Commitment::new([0u8; 32]))
+		}
+
+		fn get_tree_size(&self, block_hash: BlockHash) -> DomainResult<TreeSize> {
+			if self.fail {
+				return Err(DomainError::StorageNotAvailable);
+			}
+			Ok(TreeSize::new(block_hash.as_bytes()[0] as u32))
+		}
+
+		fn get_leaf(&self, _block_hash: BlockHash, _leaf_index: u32) -> DomainResult<Commitment> {
+			Ok(Commitment::new([0xABu8; 32]))
+		}
+	}
+
+	impl MemoQuery for MockQuery {
+		fn get_encrypted_memo(
+			&self,
+			_block_hash: BlockHash,
+			_commitment: Commitment,
+		) -> DomainResult<Option<Vec<u8>>> {
+			Ok(Some(vec![0x01, 0x02]))
+		}
+	}
+
+	fn block() -> ImportedBlock {
+		ImportedBlock::new(BlockHash::new([1u8; 32]), BlockHash::new([0u8; 32]), 5)
+	}
+
+	#[test]
+	fn should_map_new_leaves_to_notifications() {
+		let service = Arc::new(CommitmentStreamService::new(MockQuery { fail: false }));
+		let handler = CommitmentSubscriptionHandler::new(service);
+
+		let notifications = handler.handle(&block());
+
+		assert_eq!(notifications.len(), 1);
+		assert_eq!(notifications[0].leaf_index, 0);
+		assert_eq!(
+			notifications[0].commitment,
+			format!("0x{}", "ab".repeat(32))
+		);
+		assert_eq!(notifications[0].encrypted_memo.as_deref(), Some("0x0102"));
+		assert_eq!(notifications[0].block_number, 5);
+		assert_eq!(
+			notifications[0].block_hash,
+			format!("0x{}", "01".repeat(32))
+		);
+	}
+
+	#[test]
+	fn should_yield_nothing_when_storage_fails() {
+		let service = Arc::new(CommitmentStreamService::new(MockQuery { fail: true }));
+		let handler = CommitmentSubscriptionHandler::new(service);
+
+		assert!(handler.handle(&block()).is_empty());
+	}
+}
@@ -7,12 +7,17 @@ use jsonrpsee::core::RpcResult;
 use crate::orbinum::{
 	application::{MerkleProofResponse, MerkleProofService},
 	infrastructure::mappers::CommitmentMapper,
-	presentation::validation::{RequestValidator, RpcError},
+	presentation::{
+		metrics::RpcMetrics,
+		validation::{RequestValidator, RpcError},
+	},
 };
 
 /// Handler for `privacy_getMerkleProof`.
 pub struct MerkleProofHandler<Q> {
 	merkle_service: Arc<MerkleProofService<Q>>,
+	/// Proof cache metrics, if Prometheus is enabled.
+	metrics: Option<Arc<RpcMetrics>>,
 }
 
 impl<Q> MerkleProofHandler<Q>
@@ -21,7 +26,16 @@ where
 {
 	/// Creates a new `MerkleProofHandler`.
 	pub fn new(merkle_service: Arc<MerkleProofService<Q>>) -> Self {
-		Self { merkle_service }
+		Self {
+			merkle_service,
+			metrics: None,
+		}
+	}
+
+	/// Records proof cache hits and misses in `metrics`.
+	pub fn with_metrics(mut self, metrics: Arc<RpcMetrics>) -> Self {
+		self.metrics = Some(metrics);
+		self
 	}
 
 	/// Handles request to generate a Merkle proof.
@@ -40,10 +54,13 @@ where
 		RequestValidator::validate_leaf_index(leaf_index)?;
 
 		// 2. Generate proof from service
-		let proof_path = self
+		let (proof_path, cached) = self
 			.merkle_service
-			.generate_proof(leaf_index)
+			.generate_proof_cached(leaf_index)
 			.map_err(RpcError::from_application_error)?;
+		if let Some(metrics) = &self.metrics {
+			metrics.record_proof_cache(cached);
+		}
 
 		// 3. Map domain entity to DTO
 		let (path, leaf_idx, tree_depth) = proof_path.into_parts();
@@ -124,6 +141,37 @@ mod tests {
 		assert_eq!(response.path[0], format!("0x{}", "bb".repeat(32)));
 	}
 
+	#[test]
+	fn should_record_proof_cache_lookups() {
+		let query = MockQuery {
+			tree_size: 2,
+			sibling: Commitment::new([0xBBu8; 32]),
+		};
+		let registry = prometheus_endpoint::Registry::new();
+		let metrics = Arc::new(RpcMetrics::register(&registry).expect("metrics must register"));
+		let service = Arc::new(MerkleProofService::new(query));
+		let handler = MerkleProofHandler::new(service).with_metrics(metrics);
+
+		handler.handle(0).expect("handler should succeed");
+		handler.handle(0).expect("handler should succeed");
+
+		let lookups = |result: &str| {
+			registry
+				.gather()
+				.into_iter()
+				.find(|family| family.get_name() == "orbinum_privacy_rpc_merkle_proof_cache_total")
+				.and_then(|family| {
+					family
+						.get_metric()
+						.iter()
+						.find(|metric| metric.get_label()[0].get_value() == result)
+						.map(|metric| metric.get_counter().get_value())
+				})
+		};
+		assert_eq!(lookups("miss"), Some(1.0));
+		assert_eq!(lookups("hit"), Some(1.0));
+	}
+
 	#[test]
 	fn should_fail_for_invalid_leaf_index() {
 		let query = MockQuery {
@@ -6,12 +6,18 @@
 //! - Mapping domain entities to DTOs
 //! - Error handling
 
+mod commitment_subscription_handler;
 mod merkle_proof_handler;
 mod merkle_root_handler;
 mod nullifier_status_handler;
+mod nullifier_subscription_handler;
 mod pool_stats_handler;
+mod root_subscription_handler;
 
+pub use commitment_subscription_handler::CommitmentSubscriptionHandler;
 pub use merkle_proof_handler::MerkleProofHandler;
 pub use merkle_root_handler::MerkleRootHandler;
 pub use nullifier_status_handler::NullifierStatusHandler;
+pub use nullifier_subscription_handler::NullifierSubscriptionHandler;
 pub use pool_stats_handler::PoolStatsHandler;
+pub use root_subscription_handler::RootSubscriptionHandler;
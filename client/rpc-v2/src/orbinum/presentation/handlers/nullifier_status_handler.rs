@@ -10,7 +10,7 @@ use crate::orbinum::{
 	presentation::validation::{RequestValidator, RpcError},
 };
 
-/// Handler for `privacy_getNullifierStatus`.
+/// Handler for `privacy_getNullifierStatus` and `orbinum_v2_isNullifierSpent`.
 pub struct NullifierStatusHandler<Q> {
 	nullifier_service: Arc<NullifierService<Q>>,
 }
@@ -30,7 +30,7 @@ where
 	/// - `nullifier_hex`: Nullifier hash hex string (with or without `0x`)
 	///
 	/// # Returns
-	/// - `NullifierStatusResponse`: DTO with nullifier and `is_spent`
+	/// - `NullifierStatusResponse`: DTO with nullifier, `is_spent`, and spend block
 	///
 	/// # Errors
 	/// - `InvalidNullifier`: If hex string is invalid
@@ -40,13 +40,13 @@ where
 		let nullifier = DomainMapper::h256_to_nullifier(nullifier_h256);
 
 		// 2. Query status from service
-		let is_spent = self
+		let spent_block = self
 			.nullifier_service
-			.is_spent(nullifier)
+			.get_spent_block(nullifier)
 			.map_err(RpcError::from_application_error)?;
 
 		// 3. Build response DTO
-		let response = NullifierStatusResponse::new(nullifier_hex, is_spent);
+		let response = NullifierStatusResponse::new(nullifier_hex, spent_block);
 
 		Ok(response)
 	}
@@ -77,12 +77,12 @@ mod tests {
 	}
 
 	impl NullifierQuery for MockQuery {
-		fn is_nullifier_spent(
+		fn get_spent_block(
 			&self,
 			_block_hash: BlockHash,
 			nullifier: Nullifier,
-		) -> DomainResult<bool> {
-			Ok(nullifier.as_bytes()[0] == 0xFF)
+		) -> DomainResult<Option<u64>> {
+			Ok((nullifier.as_bytes()[0] == 0xFF).then_some(30))
 		}
 	}
 
@@ -98,6 +98,7 @@ mod tests {
 
 		assert_eq!(response.nullifier, nullifier_hex);
 		assert!(response.is_spent);
+		assert_eq!(response.block, Some(30));
 	}
 
 	#[test]
@@ -0,0 +1,113 @@
+//! NullifierSubscriptionHandler - Handler for nullifier subscription notifications
+
+use std::sync::Arc;
+
+use crate::orbinum::{
+	application::{NullifierNotification, NullifierStreamService},
+	domain::ImportedBlock,
+	infrastructure::mappers::DomainMapper,
+};
+
+/// Handler for `orbinum_v2_subscribeNullifiers`.
+///
+/// Turns each new best block into one notification per spent nullifier.
+pub struct NullifierSubscriptionHandler<Q> {
+	stream_service: Arc<NullifierStreamService<Q>>,
+}
+
+impl<Q> NullifierSubscriptionHandler<Q>
+where
+	Q: crate::orbinum::domain::SpentNullifierQuery,
+{
+	/// Creates a new `NullifierSubscriptionHandler`.
+	pub fn new(stream_service: Arc<NullifierStreamService<Q>>) -> Self {
+		Self { stream_service }
+	}
+
+	/// Handles a new best block.
+	///
+	/// # Returns
+	/// - One `NullifierNotification` per nullifier spent by the block
+	///
+	/// Runtime API errors are logged and yield no notifications, so a single
+	/// unreadable block does not terminate the subscription.
+	pub fn handle(&self, block: &ImportedBlock) -> Vec<NullifierNotification> {
+		let nullifiers = match self.stream_service.spent_nullifiers(block) {
+			Ok(nullifiers) => nullifiers,
+			Err(e) => {
+				log::warn!(
+					target: "privacy-rpc",
+					"Failed to collect nullifiers for block #{}: {e}",
+					block.number()
+				);
+				return Vec::new();
+			}
+		};
+
+		let block_hash = format!("0x{}", hex::encode(block.hash().as_bytes()));
+		nullifiers
+			.into_iter()
+			.map(|nullifier| {
+				NullifierNotification::new(
+					DomainMapper::h256_to_hex(DomainMapper::nullifier_to_h256(nullifier)),
+					block.number(),
+					block_hash.clone(),
+				)
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orbinum::domain::{
+		BlockHash, DomainError, DomainResult, Nullifier, SpentNullifierQuery,
+	};
+
+	#[derive(Clone, Copy)]
+	struct MockQuery {
+		fail: bool,
+	}
+
+	impl SpentNullifierQuery for MockQuery {
+		fn get_spent_nullifiers(&self, _block_hash: BlockHash) -> DomainResult<Vec<Nullifier>> {
+			if self.fail {
+				return Err(DomainError::RuntimeApiError("unavailable".to_string()));
+			}
+			Ok(vec![
+				Nullifier::new([0xCDu8; 32]),
+				Nullifier::new([0xEFu8; 32]),
+			])
+		}
+	}
+
+	fn block() -> ImportedBlock {
+		ImportedBlock::new(BlockHash::new([2u8; 32]), BlockHash::new([1u8; 32]), 2)
+	}
+
+	#[test]
+	fn should_map_spent_nullifiers_to_notifications() {
+		let service = Arc::new(NullifierStreamService::new(MockQuery { fail: false }));
+		let handler = NullifierSubscriptionHandler::new(service);
+
+		let notifications = handler.handle(&block());
+
+		assert_eq!(notifications.len(), 2);
+		assert_eq!(notifications[0].nullifier, format!("0x{}", "cd".repeat(32)));
+		assert_eq!(notifications[1].nullifier, format!("0x{}", "ef".repeat(32)));
+		assert_eq!(notifications[1].block_number, 2);
+		assert_eq!(
+			notifications[1].block_hash,
+			format!("0x{}", "02".repeat(32))
+		);
+	}
+
+	#[test]
+	fn should_yield_nothing_when_runtime_api_fails() {
+		let service = Arc::new(NullifierStreamService::new(MockQuery { fail: true }));
+		let handler = NullifierSubscriptionHandler::new(service);
+
+		assert!(handler.handle(&block()).is_empty());
+	}
+}
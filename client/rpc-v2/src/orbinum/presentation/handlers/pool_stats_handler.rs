@@ -6,11 +6,12 @@ use jsonrpsee::core::RpcResult;
 
 use crate::orbinum::{
 	application::{PoolQueryService, PoolStatsResponse},
+	domain::{AssetId, BlockHash},
 	infrastructure::mappers::CommitmentMapper,
-	presentation::validation::RpcError,
+	presentation::validation::{RequestValidator, RpcError},
 };
 
-/// Handler for `privacy_getPoolStats`.
+/// Handler for `privacy_getPoolStats` and `orbinum_v2_getPoolStats`.
 pub struct PoolStatsHandler<Q> {
 	pool_service: Arc<PoolQueryService<Q>>,
 }
@@ -54,12 +55,62 @@ where
 	}
 }
 
+impl<Q> PoolStatsHandler<Q>
+where
+	Q: crate::orbinum::domain::BlockchainQuery + crate::orbinum::domain::PoolStatsQuery,
+{
+	/// Handles request to fetch runtime pool statistics.
+	///
+	/// # Parameters
+	/// - `asset_id`: Asset the balance refers to (`None` = total pool balance)
+	/// - `at`: Hex block hash to query (`None` = best block)
+	///
+	/// # Returns
+	/// - `PoolStatsResponse`: DTO including tree utilization and historic-root window
+	///
+	/// # Errors
+	/// - `InvalidBlockHash`: If `at` is not a 32-byte hex string
+	pub fn handle_for_asset(
+		&self,
+		asset_id: Option<u32>,
+		at: Option<String>,
+	) -> RpcResult<PoolStatsResponse> {
+		// 1. Validate input
+		let at = at
+			.map(|hash| RequestValidator::validate_block_hash_hex(&hash))
+			.transpose()?
+			.map(|hash| BlockHash::new(hash.0));
+
+		// 2. Fetch statistics from the runtime
+		let stats = self
+			.pool_service
+			.get_pool_stats(asset_id.map(AssetId::new), at)
+			.map_err(RpcError::from_application_error)?;
+
+		// 3. Map domain entity to DTO
+		let mut response = PoolStatsResponse::new(
+			CommitmentMapper::to_hex_string(stats.merkle_root()),
+			stats.commitment_count().value(),
+			stats.total_balance(),
+			stats.tree_depth().value(),
+		)
+		.with_asset(stats.asset_id().map(|id| id.inner()))
+		.with_tree_utilization(stats.tree_capacity(), stats.tree_utilization_bps());
+
+		if let Some((stored, max)) = stats.historic_roots() {
+			response = response.with_historic_roots(stored, max);
+		}
+
+		Ok(response)
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::orbinum::domain::{
-		AssetId, BlockHash, BlockchainQuery, Commitment, DomainResult, MerkleTreeQuery, PoolQuery,
-		TreeSize,
+		BlockchainQuery, Commitment, DomainResult, MerkleTreeQuery, PoolQuery, PoolStatistics,
+		PoolStatsQuery, TreeDepth, TreeSize,
 	};
 
 	#[derive(Clone, Copy)]
@@ -111,6 +162,23 @@ mod tests {
 		}
 	}
 
+	impl PoolStatsQuery for MockQuery {
+		fn get_pool_stats(
+			&self,
+			_block_hash: BlockHash,
+			asset_id: Option<AssetId>,
+		) -> DomainResult<PoolStatistics> {
+			Ok(PoolStatistics::new(
+				self.root,
+				TreeSize::new(self.tree_size),
+				self.total_balance,
+				TreeDepth::new(4),
+			)
+			.with_asset(asset_id)
+			.with_historic_roots(3, 100))
+		}
+	}
+
 	#[test]
 	fn should_return_pool_stats_response() {
 		let query = MockQuery {
@@ -146,4 +214,44 @@ mod tests {
 
 		assert!(result.is_err());
 	}
+
+	#[test]
+	fn should_return_runtime_pool_stats_for_asset() {
+		let query = MockQuery {
+			root: Commitment::new([0xCCu8; 32]),
+			tree_size: 4,
+			total_balance: 50,
+		};
+		let service = Arc::new(PoolQueryService::new(query));
+		let handler = PoolStatsHandler::new(service);
+
+		let response = handler
+			.handle_for_asset(Some(1), None)
+			.expect("handler should succeed");
+
+		assert_eq!(response.asset_id, Some(1));
+		assert_eq!(response.total_balance, 50);
+		assert_eq!(response.tree_capacity, Some(16));
+		assert_eq!(response.tree_utilization_bps, Some(2_500));
+		assert_eq!(response.historic_roots, Some(3));
+		assert_eq!(response.max_historic_roots, Some(100));
+	}
+
+	#[test]
+	fn should_reject_invalid_block_hash() {
+		let query = MockQuery {
+			root: Commitment::new([0xCCu8; 32]),
+			tree_size: 4,
+			total_balance: 50,
+		};
+		let service = Arc::new(PoolQueryService::new(query));
+		let handler = PoolStatsHandler::new(service);
+
+		assert!(handler
+			.handle_for_asset(None, Some(format!("0x{}", "11".repeat(32))))
+			.is_ok());
+		assert!(handler
+			.handle_for_asset(None, Some("0x1234".to_string()))
+			.is_err());
+	}
 }
@@ -0,0 +1,112 @@
+//! RootSubscriptionHandler - Handler for Merkle root subscription notifications
+
+use std::sync::Arc;
+
+use crate::orbinum::{
+	application::{RootNotification, RootStreamService},
+	domain::{ImportedBlock, RootUpdate},
+	infrastructure::mappers::CommitmentMapper,
+};
+
+/// Handler for `orbinum_v2_subscribeRoots`.
+///
+/// Turns each new best block that changed the root into one notification.
+pub struct RootSubscriptionHandler<Q> {
+	stream_service: Arc<RootStreamService<Q>>,
+}
+
+impl<Q> RootSubscriptionHandler<Q>
+where
+	Q: crate::orbinum::domain::MerkleTreeQuery,
+{
+	/// Creates a new `RootSubscriptionHandler`.
+	pub fn new(stream_service: Arc<RootStreamService<Q>>) -> Self {
+		Self { stream_service }
+	}
+
+	/// Handles a new best block.
+	///
+	/// # Returns
+	/// - `Some(RootNotification)` if the block changed the root
+	///
+	/// Storage errors are logged and yield no notification, so a single
+	/// unreadable block does not terminate the subscription.
+	pub fn handle(&self, block: &ImportedBlock) -> Option<RootNotification> {
+		match self.stream_service.root_update(block) {
+			Ok(update) => update.as_ref().map(Self::to_notification),
+			Err(e) => {
+				log::warn!(
+					target: "privacy-rpc",
+					"Failed to read Merkle root for block #{}: {e}",
+					block.number()
+				);
+				None
+			}
+		}
+	}
+
+	/// Maps a domain root update to its notification DTO.
+	fn to_notification(update: &RootUpdate) -> RootNotification {
+		RootNotification::new(
+			CommitmentMapper::to_hex_string(update.root()),
+			update.tree_size().value(),
+			update.block_number(),
+			format!("0x{}", hex::encode(update.block_hash().as_bytes())),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orbinum::domain::{
+		BlockHash, Commitment, DomainError, DomainResult, MerkleTreeQuery, TreeSize,
+	};
+
+	#[derive(Clone, Copy)]
+	struct MockQuery {
+		fail: bool,
+	}
+
+	impl MerkleTreeQuery for MockQuery {
+		fn get_merkle_root(&self, block_hash: BlockHash) -> DomainResult<Commitment> {
+			if self.fail {
+				return Err(DomainError::StorageNotAvailable);
+			}
+			Ok(Commitment::new(*block_hash.as_bytes()))
+		}
+
+		fn get_tree_size(&self, _block_hash: BlockHash) -> DomainResult<TreeSize> {
+			Ok(TreeSize::new(9))
+		}
+
+		fn get_leaf(&self, _block_hash: BlockHash, _leaf_index: u32) -> DomainResult<Commitment> {
+			Ok(Commitment::new([0u8; 32]))
+		}
+	}
+
+	fn block() -> ImportedBlock {
+		ImportedBlock::new(BlockHash::new([2u8; 32]), BlockHash::new([1u8; 32]), 2)
+	}
+
+	#[test]
+	fn should_map_root_update_to_notification() {
+		let service = Arc::new(RootStreamService::new(MockQuery { fail: false }));
+		let handler = RootSubscriptionHandler::new(service);
+
+		let notification = handler.handle(&block()).expect("root changed");
+
+		assert_eq!(notification.root, format!("0x{}", "02".repeat(32)));
+		assert_eq!(notification.tree_size, 9);
+		assert_eq!(notification.block_number, 2);
+		assert_eq!(notification.block_hash, format!("0x{}", "02".repeat(32)));
+	}
+
+	#[test]
+	fn should_yield_nothing_when_storage_fails() {
+		let service = Arc::new(RootStreamService::new(MockQuery { fail: true }));
+		let handler = RootSubscriptionHandler::new(service);
+
+		assert!(handler.handle(&block()).is_none());
+	}
+}
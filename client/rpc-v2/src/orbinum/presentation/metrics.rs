@@ -0,0 +1,135 @@
+//! Prometheus metrics for the privacy RPC endpoints
+
+use std::time::Instant;
+
+use jsonrpsee::core::RpcResult;
+use prometheus_endpoint::{
+	register, CounterVec, HistogramOpts, HistogramVec, Opts, PrometheusError, Registry, U64,
+};
+
+/// Request metrics of the privacy RPC endpoints.
+///
+/// # Metrics
+/// - `orbinum_privacy_rpc_requests_total{method, status}`: served requests
+/// - `orbinum_privacy_rpc_request_duration_seconds{method}`: handler latency
+/// - `orbinum_privacy_rpc_merkle_proof_cache_total{result}`: proof cache hits and misses
+pub struct RpcMetrics {
+	/// Requests per method and outcome (`ok` or `error`).
+	requests: CounterVec<U64>,
+	/// Handler latency per method.
+	latency: HistogramVec,
+	/// Merkle proof cache lookups per result (`hit` or `miss`).
+	proof_cache: CounterVec<U64>,
+}
+
+impl RpcMetrics {
+	/// Registers the metrics in `registry`.
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			requests: register(
+				CounterVec::new(
+					Opts::new(
+						"orbinum_privacy_rpc_requests_total",
+						"Privacy RPC requests served.",
+					),
+					&["method", "status"],
+				)?,
+				registry,
+			)?,
+			latency: register(
+				HistogramVec::new(
+					HistogramOpts::new(
+						"orbinum_privacy_rpc_request_duration_seconds",
+						"Privacy RPC handler latency.",
+					)
+					.buckets(vec![
+						0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0,
+					]),
+					&["method"],
+				)?,
+				registry,
+			)?,
+			proof_cache: register(
+				CounterVec::new(
+					Opts::new(
+						"orbinum_privacy_rpc_merkle_proof_cache_total",
+						"Merkle proof cache lookups.",
+					),
+					&["result"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	/// Runs the handler of `method`, recording its latency and outcome.
+	pub fn observe<T>(&self, method: &str, handler: impl FnOnce() -> RpcResult<T>) -> RpcResult<T> {
+		let started = Instant::now();
+		let result = handler();
+
+		self.latency
+			.with_label_values(&[method])
+			.observe(started.elapsed().as_secs_f64());
+		let status = if result.is_ok() { "ok" } else { "error" };
+		self.requests.with_label_values(&[method, status]).inc();
+
+		result
+	}
+
+	/// Records one Merkle proof cache lookup.
+	pub fn record_proof_cache(&self, hit: bool) {
+		let result = if hit { "hit" } else { "miss" };
+		self.proof_cache.with_label_values(&[result]).inc();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use jsonrpsee::types::ErrorObjectOwned;
+
+	#[test]
+	fn should_count_requests_by_status() {
+		let metrics = RpcMetrics::register(&Registry::new()).expect("metrics must register");
+
+		let ok = metrics.observe("privacy_getMerkleRoot", || Ok(1));
+		let err: RpcResult<()> = metrics.observe("privacy_getMerkleRoot", || {
+			Err(ErrorObjectOwned::owned(1, "failed", None::<()>))
+		});
+
+		assert_eq!(ok.expect("result must pass through"), 1);
+		assert!(err.is_err());
+		for status in ["ok", "error"] {
+			let count = metrics
+				.requests
+				.with_label_values(&["privacy_getMerkleRoot", status])
+				.get();
+			assert_eq!(count, 1);
+		}
+		let samples = metrics
+			.latency
+			.with_label_values(&["privacy_getMerkleRoot"])
+			.get_sample_count();
+		assert_eq!(samples, 2);
+	}
+
+	#[test]
+	fn should_count_proof_cache_lookups() {
+		let metrics = RpcMetrics::register(&Registry::new()).expect("metrics must register");
+
+		metrics.record_proof_cache(true);
+		metrics.record_proof_cache(true);
+		metrics.record_proof_cache(false);
+
+		assert_eq!(metrics.proof_cache.with_label_values(&["hit"]).get(), 2);
+		assert_eq!(metrics.proof_cache.with_label_values(&["miss"]).get(), 1);
+	}
+
+	#[test]
+	fn should_fail_to_register_twice() {
+		let registry = Registry::new();
+		let _metrics = RpcMetrics::register(&registry).expect("metrics must register");
+
+		assert!(RpcMetrics::register(&registry).is_err());
+	}
+}
@@ -1,7 +1,7 @@
 //! Presentation layer - RPC interface
 //!
 //! This layer exposes the RPC API and handles:
-//! - RPC trait definition (`jsonrpsee`)
+//! - RPC trait definitions (`jsonrpsee`), including subscriptions
 //! - RPC server implementation
 //! - Endpoint-specific handlers
 //! - Input validation
@@ -9,10 +9,20 @@
 //! This layer delegates business logic to the application layer.
 
 pub mod api;
+pub mod deprecation;
 pub mod handlers;
+pub mod metrics;
+pub mod pubsub_api;
+pub mod pubsub_server;
+pub mod rate_limit;
 pub mod server;
 pub mod validation;
 
 // Re-exports
 pub use api::PrivacyApiServer;
+pub use deprecation::DeprecationLog;
+pub use metrics::RpcMetrics;
+pub use pubsub_api::PrivacyPubSubApiServer;
+pub use pubsub_server::PrivacyPubSubServer;
+pub use rate_limit::{CostClass, RateLimitConfig, RateLimiter};
 pub use server::PrivacyRpcServer;
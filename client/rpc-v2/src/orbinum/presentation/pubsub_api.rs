@@ -0,0 +1,165 @@
+//! Privacy PUB-SUB RPC API definition (jsonrpsee trait)
+//!
+//! Defines subscriptions that push shielded pool updates to wallets.
+
+use jsonrpsee::proc_macros::rpc;
+
+use crate::orbinum::application::{
+	CommitmentNotification, NullifierNotification, RootNotification,
+};
+
+/// Privacy PUB-SUB RPC API
+///
+/// JSON-RPC subscriptions for shielded pool updates:
+/// - `orbinum_v2_subscribeCommitments`: Stream every new Merkle leaf
+/// - `orbinum_v2_subscribeNullifiers`: Stream every spent nullifier
+/// - `orbinum_v2_subscribeRoots`: Stream every Merkle root change
+///
+/// The `shieldedPool_` names of these subscriptions are deprecated aliases:
+/// they push the same notifications under their old notification method and
+/// log their use.
+#[rpc(server)]
+pub trait PrivacyPubSubApi {
+	/// Subscribes to commitments appended to the Merkle tree.
+	///
+	/// Pushes one notification per new leaf of every new best block, so
+	/// wallets can maintain their local tree without polling.
+	///
+	/// # Example
+	/// ```json
+	/// {
+	///   "jsonrpc": "2.0",
+	///   "method": "orbinum_v2_subscribeCommitments",
+	///   "params": [],
+	///   "id": 1
+	/// }
+	/// ```
+	///
+	/// # Notification
+	/// ```json
+	/// {
+	///   "jsonrpc": "2.0",
+	///   "method": "orbinum_v2_commitment",
+	///   "params": {
+	///     "subscription": "0x1a2b...",
+	///     "result": {
+	///       "leaf_index": 42,
+	///       "commitment": "0x1234...abcd",
+	///       "encrypted_memo": "0x0a0b...",
+	///       "block_number": 1200,
+	///       "block_hash": "0x5678...ef01"
+	///     }
+	///   }
+	/// }
+	/// ```
+	#[subscription(
+		name = "orbinum_v2_subscribeCommitments" => "orbinum_v2_commitment",
+		unsubscribe = "orbinum_v2_unsubscribeCommitments",
+		item = CommitmentNotification
+	)]
+	fn subscribe_commitments(&self);
+
+	/// Deprecated alias of `orbinum_v2_subscribeCommitments`, notifying with
+	/// `shieldedPool_commitment`.
+	#[subscription(
+		name = "shieldedPool_subscribeCommitments" => "shieldedPool_commitment",
+		unsubscribe = "shieldedPool_unsubscribeCommitments",
+		item = CommitmentNotification
+	)]
+	fn subscribe_commitments_deprecated(&self);
+
+	/// Subscribes to nullifiers as they are spent.
+	///
+	/// Pushes one notification per nullifier consumed by every new best
+	/// block, so wallets learn immediately when one of their notes is spent
+	/// (e.g. from another device) and can invalidate local balances.
+	///
+	/// # Example
+	/// ```json
+	/// {
+	///   "jsonrpc": "2.0",
+	///   "method": "orbinum_v2_subscribeNullifiers",
+	///   "params": [],
+	///   "id": 1
+	/// }
+	/// ```
+	///
+	/// # Notification
+	/// ```json
+	/// {
+	///   "jsonrpc": "2.0",
+	///   "method": "orbinum_v2_nullifier",
+	///   "params": {
+	///     "subscription": "0x1a2b...",
+	///     "result": {
+	///       "nullifier": "0xabcd...1234",
+	///       "block_number": 1200,
+	///       "block_hash": "0x5678...ef01"
+	///     }
+	///   }
+	/// }
+	/// ```
+	#[subscription(
+		name = "orbinum_v2_subscribeNullifiers" => "orbinum_v2_nullifier",
+		unsubscribe = "orbinum_v2_unsubscribeNullifiers",
+		item = NullifierNotification
+	)]
+	fn subscribe_nullifiers(&self);
+
+	/// Deprecated alias of `orbinum_v2_subscribeNullifiers`, notifying with
+	/// `shieldedPool_nullifier`.
+	#[subscription(
+		name = "shieldedPool_subscribeNullifiers" => "shieldedPool_nullifier",
+		unsubscribe = "shieldedPool_unsubscribeNullifiers",
+		item = NullifierNotification
+	)]
+	fn subscribe_nullifiers_deprecated(&self);
+
+	/// Subscribes to Merkle root changes.
+	///
+	/// Pushes one notification per new best block that changed the root
+	/// (emitted `MerkleRootUpdated`), so provers learn that their anchor
+	/// root moved and can re-anchor before submitting.
+	///
+	/// # Example
+	/// ```json
+	/// {
+	///   "jsonrpc": "2.0",
+	///   "method": "orbinum_v2_subscribeRoots",
+	///   "params": [],
+	///   "id": 1
+	/// }
+	/// ```
+	///
+	/// # Notification
+	/// ```json
+	/// {
+	///   "jsonrpc": "2.0",
+	///   "method": "orbinum_v2_root",
+	///   "params": {
+	///     "subscription": "0x1a2b...",
+	///     "result": {
+	///       "root": "0x9abc...def0",
+	///       "tree_size": 43,
+	///       "block_number": 1200,
+	///       "block_hash": "0x5678...ef01"
+	///     }
+	///   }
+	/// }
+	/// ```
+	#[subscription(
+		name = "orbinum_v2_subscribeRoots" => "orbinum_v2_root",
+		unsubscribe = "orbinum_v2_unsubscribeRoots",
+		item = RootNotification
+	)]
+	fn subscribe_roots(&self);
+
+	/// Deprecated alias of `orbinum_v2_subscribeRoots`, notifying with
+	/// `shieldedPool_root`.
+	#[subscription(
+		name = "shieldedPool_subscribeRoots" => "shieldedPool_root",
+		unsubscribe = "shieldedPool_unsubscribeRoots",
+		item = RootNotification
+	)]
+	fn subscribe_roots_deprecated(&self);
+}
@@ -0,0 +1,250 @@
+//! Privacy PUB-SUB RPC Server implementation
+//!
+//! Implements `PrivacyPubSubApiServer` by piping block notifications
+//! through subscription handlers.
+
+use std::sync::Arc;
+
+use futures::{stream, FutureExt, Stream, StreamExt};
+use jsonrpsee::server::PendingSubscriptionSink;
+use sc_rpc::{
+	utils::{BoundedVecDeque, PendingSubscription},
+	SubscriptionTaskExecutor,
+};
+use serde::Serialize;
+
+use crate::orbinum::{
+	application::{CommitmentStreamService, NullifierStreamService, RootStreamService},
+	presentation::{
+		deprecation::DeprecationLog,
+		handlers::{
+			CommitmentSubscriptionHandler, NullifierSubscriptionHandler, RootSubscriptionHandler,
+		},
+		pubsub_api::PrivacyPubSubApiServer,
+	},
+};
+
+/// Maximum number of notifications buffered per subscriber.
+const SUBSCRIPTION_BUFFER: usize = 16;
+
+/// Privacy PUB-SUB RPC Server
+///
+/// Each subscription follows the best block stream and is driven by a
+/// task spawned on the RPC subscription executor.
+///
+/// # Generics
+/// - `Q`: Query port implementing all required traits
+pub struct PrivacyPubSubServer<Q> {
+	/// Source of new best blocks.
+	query: Q,
+	/// Handler for commitment subscriptions.
+	commitment_handler: Arc<CommitmentSubscriptionHandler<Q>>,
+	/// Handler for nullifier subscriptions.
+	nullifier_handler: Arc<NullifierSubscriptionHandler<Q>>,
+	/// Handler for Merkle root subscriptions.
+	root_handler: Arc<RootSubscriptionHandler<Q>>,
+	/// Executor running subscription tasks.
+	executor: SubscriptionTaskExecutor,
+	/// Usage log of the deprecated `shieldedPool_` aliases.
+	deprecations: DeprecationLog,
+}
+
+impl<Q> PrivacyPubSubServer<Q>
+where
+	Q: crate::orbinum::domain::BlockStream
+		+ crate::orbinum::domain::MerkleTreeQuery
+		+ crate::orbinum::domain::MemoQuery
+		+ crate::orbinum::domain::SpentNullifierQuery
+		+ Clone
+		+ 'static,
+{
+	/// Creates a new `PrivacyPubSubServer`.
+	///
+	/// # Parameters
+	/// - `query`: Implementation of all required ports (typically `SubstrateStorageAdapter`)
+	/// - `executor`: Executor used to spawn subscription tasks
+	pub fn new(query: Q, executor: SubscriptionTaskExecutor) -> Self {
+		let stream_service = Arc::new(CommitmentStreamService::new(query.clone()));
+		let commitment_handler = Arc::new(CommitmentSubscriptionHandler::new(stream_service));
+		let nullifier_service = Arc::new(NullifierStreamService::new(query.clone()));
+		let nullifier_handler = Arc::new(NullifierSubscriptionHandler::new(nullifier_service));
+		let root_service = Arc::new(RootStreamService::new(query.clone()));
+		let root_handler = Arc::new(RootSubscriptionHandler::new(root_service));
+
+		Self {
+			query,
+			commitment_handler,
+			nullifier_handler,
+			root_handler,
+			executor,
+			deprecations: DeprecationLog::new(),
+		}
+	}
+
+	/// Pipes `notifications` into the pending subscription on a spawned task.
+	fn spawn_subscription<T, S>(&self, pending: PendingSubscriptionSink, notifications: S)
+	where
+		T: Serialize + Send + 'static,
+		S: Stream<Item = T> + Unpin + Send + 'static,
+	{
+		let fut = async move {
+			PendingSubscription::from(pending)
+				.pipe_from_stream(notifications, BoundedVecDeque::new(SUBSCRIPTION_BUFFER))
+				.await
+		}
+		.boxed();
+
+		self.executor
+			.spawn("privacy-rpc-subscription", Some("rpc"), fut);
+	}
+}
+
+impl<Q> PrivacyPubSubApiServer for PrivacyPubSubServer<Q>
+where
+	Q: crate::orbinum::domain::BlockStream
+		+ crate::orbinum::domain::MerkleTreeQuery
+		+ crate::orbinum::domain::MemoQuery
+		+ crate::orbinum::domain::SpentNullifierQuery
+		+ Clone
+		+ 'static,
+{
+	fn subscribe_commitments(&self, pending: PendingSubscriptionSink) {
+		let handler = self.commitment_handler.clone();
+		let notifications = self
+			.query
+			.best_block_stream()
+			.flat_map(move |block| stream::iter(handler.handle(&block)));
+
+		self.spawn_subscription(pending, notifications);
+	}
+
+	fn subscribe_commitments_deprecated(&self, pending: PendingSubscriptionSink) {
+		self.deprecations.record(
+			"shieldedPool_subscribeCommitments",
+			"orbinum_v2_subscribeCommitments",
+		);
+		self.subscribe_commitments(pending);
+	}
+
+	fn subscribe_nullifiers(&self, pending: PendingSubscriptionSink) {
+		let handler = self.nullifier_handler.clone();
+		let notifications = self
+			.query
+			.best_block_stream()
+			.flat_map(move |block| stream::iter(handler.handle(&block)));
+
+		self.spawn_subscription(pending, notifications);
+	}
+
+	fn subscribe_nullifiers_deprecated(&self, pending: PendingSubscriptionSink) {
+		self.deprecations.record(
+			"shieldedPool_subscribeNullifiers",
+			"orbinum_v2_subscribeNullifiers",
+		);
+		self.subscribe_nullifiers(pending);
+	}
+
+	fn subscribe_roots(&self, pending: PendingSubscriptionSink) {
+		let handler = self.root_handler.clone();
+		let notifications = self
+			.query
+			.best_block_stream()
+			.flat_map(move |block| stream::iter(handler.handle(&block)));
+
+		self.spawn_subscription(pending, notifications);
+	}
+
+	fn subscribe_roots_deprecated(&self, pending: PendingSubscriptionSink) {
+		self.deprecations
+			.record("shieldedPool_subscribeRoots", "orbinum_v2_subscribeRoots");
+		self.subscribe_roots(pending);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::orbinum::domain::{
+		BlockHash, BlockStream, Commitment, DomainResult, ImportedBlock, MemoQuery,
+		MerkleTreeQuery, Nullifier, SpentNullifierQuery, TreeSize,
+	};
+	use futures::stream::BoxStream;
+	use sp_core::traits::SpawnNamed;
+
+	#[derive(Clone, Copy)]
+	struct MockQuery;
+
+	impl BlockStream for MockQuery {
+		fn best_block_stream(&self) -> BoxStream<'static, ImportedBlock> {
+			stream::empty().boxed()
+		}
+	}
+
+	impl MerkleTreeQuery for MockQuery {
+		fn get_merkle_root(&self, _block_hash: BlockHash) -> DomainResult<Commitment> {
+			Ok(Commitment::new([0u8; 32]))
+		}
+
+		fn get_tree_size(&self, _block_hash: BlockHash) -> DomainResult<TreeSize> {
+			Ok(TreeSize::new(0))
+		}
+
+		fn get_leaf(&self, _block_hash: BlockHash, _leaf_index: u32) -> DomainResult<Commitment> {
+			Ok(Commitment::new([0u8; 32]))
+		}
+	}
+
+	impl MemoQuery for MockQuery {
+		fn get_encrypted_memo(
+			&self,
+			_block_hash: BlockHash,
+			_commitment: Commitment,
+		) -> DomainResult<Option<Vec<u8>>> {
+			Ok(None)
+		}
+	}
+
+	impl SpentNullifierQuery for MockQuery {
+		fn get_spent_nullifiers(&self, _block_hash: BlockHash) -> DomainResult<Vec<Nullifier>> {
+			Ok(Vec::new())
+		}
+	}
+
+	struct NoopSpawner;
+
+	impl SpawnNamed for NoopSpawner {
+		fn spawn_blocking(
+			&self,
+			_name: &'static str,
+			_group: Option<&'static str>,
+			_future: futures::future::BoxFuture<'static, ()>,
+		) {
+		}
+
+		fn spawn(
+			&self,
+			_name: &'static str,
+			_group: Option<&'static str>,
+			_future: futures::future::BoxFuture<'static, ()>,
+		) {
+		}
+	}
+
+	#[test]
+	fn should_register_subscription_methods() {
+		let server = PrivacyPubSubServer::new(MockQuery, Arc::new(NoopSpawner));
+		let module = server.into_rpc();
+		let methods: Vec<_> = module.method_names().collect();
+
+		for kind in ["Commitments", "Nullifiers", "Roots"] {
+			assert!(methods.contains(&format!("orbinum_v2_subscribe{kind}").as_str()));
+			assert!(methods.contains(&format!("orbinum_v2_unsubscribe{kind}").as_str()));
+		}
+		assert!(methods.contains(&"shieldedPool_subscribeCommitments"));
+		assert!(methods.contains(&"shieldedPool_unsubscribeCommitments"));
+		assert!(methods.contains(&"shieldedPool_subscribeNullifiers"));
+		assert!(methods.contains(&"shieldedPool_unsubscribeNullifiers"));
+		assert!(methods.contains(&"shieldedPool_subscribeRoots"));
+		assert!(methods.contains(&"shieldedPool_unsubscribeRoots"));
+	}
+}
@@ -0,0 +1,260 @@
+//! RateLimiter - Per-connection cost budget for the privacy RPC endpoints
+
+use std::{
+	collections::HashMap,
+	num::NonZeroU32,
+	sync::{Mutex, PoisonError},
+	time::Instant,
+};
+
+use jsonrpsee::{core::RpcResult, ConnectionId, Extensions};
+
+use crate::orbinum::presentation::validation::RpcError;
+
+/// Connections tracked before idle buckets are dropped.
+const MAX_TRACKED_CONNECTIONS: usize = 10_000;
+
+/// Items of a batch call charged as one `CostClass::Heavy` call.
+pub const BATCH_ITEMS_PER_HEAVY_CALL: usize = 64;
+
+/// Cost class of an RPC method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostClass {
+	/// Single storage reads (roots, nullifier status, stats): costs 1 unit.
+	Light,
+	/// Reads proportional to the tree or to a block range (Merkle proofs,
+	/// event scans, snapshot export): costs `RateLimitConfig::heavy_cost` units.
+	Heavy,
+}
+
+/// Rate limit settings.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitConfig {
+	/// Cost units each connection may spend per second; also the burst size.
+	pub units_per_second: NonZeroU32,
+	/// Cost of one `CostClass::Heavy` call; heavy calls are always rejected
+	/// when it exceeds `units_per_second`.
+	pub heavy_cost: u32,
+}
+
+impl RateLimitConfig {
+	/// Creates a config with the default heavy cost of 10 units.
+	pub fn new(units_per_second: NonZeroU32) -> Self {
+		Self {
+			units_per_second,
+			heavy_cost: 10,
+		}
+	}
+
+	/// Units charged for one call of `class`.
+	fn cost(&self, class: CostClass) -> f64 {
+		match class {
+			CostClass::Light => 1.0,
+			CostClass::Heavy => f64::from(self.heavy_cost),
+		}
+	}
+}
+
+/// Token bucket of one connection.
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+	/// Units left.
+	units: f64,
+	/// Last refill.
+	updated: Instant,
+}
+
+/// Per-connection token-bucket rate limiter.
+///
+/// Every connection starts with a full budget of `units_per_second` units,
+/// refilled continuously at that rate; a call is rejected when its cost
+/// exceeds the units left. Share one limiter between RPC modules so a
+/// connection's budget covers all of them.
+pub struct RateLimiter {
+	config: RateLimitConfig,
+	buckets: Mutex<HashMap<ConnectionId, Bucket>>,
+}
+
+impl RateLimiter {
+	/// Creates a new `RateLimiter`.
+	pub fn new(config: RateLimitConfig) -> Self {
+		Self {
+			config,
+			buckets: Mutex::new(HashMap::new()),
+		}
+	}
+
+	/// Charges one call of `class` to the connection of the request.
+	///
+	/// # Errors
+	/// - `RateLimited`: The connection has not enough units left
+	pub fn check(&self, ext: &Extensions, class: CostClass) -> RpcResult<()> {
+		self.charge_at(Self::connection(ext), class, 1, Instant::now())
+	}
+
+	/// Charges a batch call looking up `items` items to the connection of the
+	/// request: one heavy call per [`BATCH_ITEMS_PER_HEAVY_CALL`] items,
+	/// rounded up.
+	///
+	/// # Errors
+	/// - `RateLimited`: The connection has not enough units left
+	pub fn check_batch(&self, ext: &Extensions, items: usize) -> RpcResult<()> {
+		let calls = items.div_ceil(BATCH_ITEMS_PER_HEAVY_CALL).max(1);
+		self.charge_at(
+			Self::connection(ext),
+			CostClass::Heavy,
+			u32::try_from(calls).unwrap_or(u32::MAX),
+			Instant::now(),
+		)
+	}
+
+	/// Connection of the request
+	fn connection(ext: &Extensions) -> ConnectionId {
+		// The server tags every request with its connection; untagged calls share a bucket
+		ext.get::<ConnectionId>()
+			.copied()
+			.unwrap_or(ConnectionId(usize::MAX))
+	}
+
+	#[cfg(test)]
+	fn check_at(&self, connection: ConnectionId, class: CostClass, now: Instant) -> RpcResult<()> {
+		self.charge_at(connection, class, 1, now)
+	}
+
+	/// Charges `calls` calls of `class` at once.
+	fn charge_at(
+		&self,
+		connection: ConnectionId,
+		class: CostClass,
+		calls: u32,
+		now: Instant,
+	) -> RpcResult<()> {
+		let capacity = f64::from(self.config.units_per_second.get());
+		let cost = self.config.cost(class) * f64::from(calls);
+
+		let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+		if buckets.len() >= MAX_TRACKED_CONNECTIONS && !buckets.contains_key(&connection) {
+			Self::prune(&mut buckets, capacity, now);
+		}
+
+		let bucket = buckets.entry(connection).or_insert(Bucket {
+			units: capacity,
+			updated: now,
+		});
+		let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+		bucket.units = (bucket.units + elapsed * capacity).min(capacity);
+		bucket.updated = now;
+
+		if bucket.units < cost {
+			return Err(RpcError::rate_limited(class).into());
+		}
+		bucket.units -= cost;
+		Ok(())
+	}
+
+	/// Drops the buckets of connections that have been idle long enough to
+	/// refill completely; they are indistinguishable from new connections.
+	fn prune(buckets: &mut HashMap<ConnectionId, Bucket>, capacity: f64, now: Instant) {
+		buckets.retain(|_, bucket| {
+			let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+			bucket.units + elapsed * capacity < capacity
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	fn limiter(units_per_second: u32, heavy_cost: u32) -> RateLimiter {
+		RateLimiter::new(RateLimitConfig {
+			units_per_second: NonZeroU32::new(units_per_second).expect("non-zero"),
+			heavy_cost,
+		})
+	}
+
+	#[test]
+	fn should_reject_calls_over_budget() {
+		let limiter = limiter(3, 10);
+		let now = Instant::now();
+
+		for _ in 0..3 {
+			assert!(limiter
+				.check_at(ConnectionId(1), CostClass::Light, now)
+				.is_ok());
+		}
+		let result = limiter.check_at(ConnectionId(1), CostClass::Light, now);
+
+		assert_eq!(result.expect_err("budget is spent").code(), -32007);
+	}
+
+	#[test]
+	fn should_charge_heavy_calls_more() {
+		let limiter = limiter(20, 10);
+		let now = Instant::now();
+
+		assert!(limiter
+			.check_at(ConnectionId(1), CostClass::Heavy, now)
+			.is_ok());
+		assert!(limiter
+			.check_at(ConnectionId(1), CostClass::Heavy, now)
+			.is_ok());
+		assert!(limiter
+			.check_at(ConnectionId(1), CostClass::Heavy, now)
+			.is_err());
+		assert!(limiter
+			.check_at(ConnectionId(1), CostClass::Light, now)
+			.is_err());
+	}
+
+	#[test]
+	fn should_charge_batches_per_item_chunk() {
+		let limiter = limiter(40, 10);
+		let mut ext = Extensions::new();
+		ext.insert(ConnectionId(1));
+
+		// 65 items cost two heavy calls, a single item one
+		assert!(limiter.check_batch(&ext, 65).is_ok());
+		assert!(limiter.check_batch(&ext, 1).is_ok());
+		assert!(limiter.check_batch(&ext, 1).is_ok());
+		assert!(limiter.check_batch(&ext, 1).is_err());
+	}
+
+	#[test]
+	fn should_refill_over_time() {
+		let limiter = limiter(10, 10);
+		let now = Instant::now();
+
+		assert!(limiter
+			.check_at(ConnectionId(1), CostClass::Heavy, now)
+			.is_ok());
+		assert!(limiter
+			.check_at(ConnectionId(1), CostClass::Light, now)
+			.is_err());
+
+		let later = now + Duration::from_millis(500);
+		assert!(limiter
+			.check_at(ConnectionId(1), CostClass::Heavy, later)
+			.is_err());
+		assert!(limiter
+			.check_at(ConnectionId(1), CostClass::Light, later)
+			.is_ok());
+	}
+
+	#[test]
+	fn should_keep_separate_budgets_per_connection() {
+		let limiter = limiter(10, 10);
+		let now = Instant::now();
+
+		assert!(limiter
+			.check_at(ConnectionId(1), CostClass::Heavy, now)
+			.is_ok());
+		assert!(limiter
+			.check_at(ConnectionId(2), CostClass::Heavy, now)
+			.is_ok());
+		assert!(limiter
+			.check_at(ConnectionId(1), CostClass::Heavy, now)
+			.is_err());
+	}
+}
@@ -4,7 +4,8 @@
 
 use std::sync::Arc;
 
-use jsonrpsee::core::RpcResult;
+use jsonrpsee::{core::RpcResult, Extensions};
+use prometheus_endpoint::Registry;
 
 use crate::orbinum::{
 	application::{
@@ -13,9 +14,12 @@ use crate::orbinum::{
 	},
 	presentation::{
 		api::PrivacyApiServer,
+		deprecation::DeprecationLog,
 		handlers::{
 			MerkleProofHandler, MerkleRootHandler, NullifierStatusHandler, PoolStatsHandler,
 		},
+		metrics::RpcMetrics,
+		rate_limit::{CostClass, RateLimiter},
 	},
 };
 
@@ -52,6 +56,12 @@ pub struct PrivacyRpcServer<Q> {
 	nullifier_handler: NullifierStatusHandler<Q>,
 	/// Handler for pool stats endpoint.
 	pool_stats_handler: PoolStatsHandler<Q>,
+	/// Request metrics, if Prometheus is enabled.
+	metrics: Option<Arc<RpcMetrics>>,
+	/// Per-connection rate limiter, if enabled.
+	rate_limiter: Option<Arc<RateLimiter>>,
+	/// Usage log of the deprecated `shieldedPool_` aliases.
+	deprecations: DeprecationLog,
 }
 
 impl<Q> PrivacyRpcServer<Q>
@@ -60,6 +70,7 @@ where
 		+ crate::orbinum::domain::MerkleTreeQuery
 		+ crate::orbinum::domain::NullifierQuery
 		+ crate::orbinum::domain::PoolQuery
+		+ crate::orbinum::domain::PoolStatsQuery
 		+ Clone
 		+ 'static,
 {
@@ -84,6 +95,60 @@ where
 			merkle_proof_handler,
 			nullifier_handler,
 			pool_stats_handler,
+			metrics: None,
+			rate_limiter: None,
+			deprecations: DeprecationLog::new(),
+		}
+	}
+
+	/// Charges every request to its connection's budget in `rate_limiter`.
+	pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+		self.rate_limiter = rate_limiter;
+		self
+	}
+
+	/// Exports request and proof cache metrics to `registry`.
+	///
+	/// Metrics stay disabled when `registry` is `None` or registration fails.
+	pub fn with_prometheus(mut self, registry: Option<&Registry>) -> Self {
+		let Some(registry) = registry else {
+			return self;
+		};
+		match RpcMetrics::register(registry) {
+			Ok(metrics) => {
+				let metrics = Arc::new(metrics);
+				self.merkle_proof_handler = self.merkle_proof_handler.with_metrics(metrics.clone());
+				self.metrics = Some(metrics);
+			}
+			Err(e) => log::error!(target: "privacy-rpc", "Failed to register metrics: {e:?}"),
+		}
+		self
+	}
+
+	/// Request metrics registered by [`Self::with_prometheus`], to share with
+	/// other privacy RPC modules.
+	pub fn metrics(&self) -> Option<Arc<RpcMetrics>> {
+		self.metrics.clone()
+	}
+
+	/// Runs the handler of `method` once the rate limiter admits a call of
+	/// `class`, recording it when metrics are enabled.
+	fn serve<T>(
+		&self,
+		ext: &Extensions,
+		method: &str,
+		class: CostClass,
+		handler: impl FnOnce() -> RpcResult<T>,
+	) -> RpcResult<T> {
+		let handler = || {
+			if let Some(rate_limiter) = &self.rate_limiter {
+				rate_limiter.check(ext, class)?;
+			}
+			handler()
+		};
+		match &self.metrics {
+			Some(metrics) => metrics.observe(method, handler),
+			None => handler(),
 		}
 	}
 }
@@ -94,33 +159,107 @@ where
 		+ crate::orbinum::domain::MerkleTreeQuery
 		+ crate::orbinum::domain::NullifierQuery
 		+ crate::orbinum::domain::PoolQuery
+		+ crate::orbinum::domain::PoolStatsQuery
 		+ Clone
 		+ 'static,
 {
-	fn get_merkle_root(&self) -> RpcResult<String> {
-		self.merkle_root_handler.handle()
+	fn get_merkle_root(&self, ext: &Extensions) -> RpcResult<String> {
+		self.serve(ext, "privacy_getMerkleRoot", CostClass::Light, || {
+			self.merkle_root_handler.handle()
+		})
+	}
+
+	fn get_merkle_proof(
+		&self,
+		ext: &Extensions,
+		leaf_index: u32,
+	) -> RpcResult<MerkleProofResponse> {
+		self.serve(ext, "privacy_getMerkleProof", CostClass::Heavy, || {
+			self.merkle_proof_handler.handle(leaf_index)
+		})
+	}
+
+	fn get_nullifier_status(
+		&self,
+		ext: &Extensions,
+		nullifier: String,
+	) -> RpcResult<NullifierStatusResponse> {
+		self.serve(ext, "privacy_getNullifierStatus", CostClass::Light, || {
+			self.nullifier_handler.handle(nullifier)
+		})
 	}
 
-	fn get_merkle_proof(&self, leaf_index: u32) -> RpcResult<MerkleProofResponse> {
-		self.merkle_proof_handler.handle(leaf_index)
+	fn get_pool_stats(&self, ext: &Extensions) -> RpcResult<PoolStatsResponse> {
+		self.serve(ext, "privacy_getPoolStats", CostClass::Light, || {
+			self.pool_stats_handler.handle()
+		})
 	}
 
-	fn get_nullifier_status(&self, nullifier: String) -> RpcResult<NullifierStatusResponse> {
-		self.nullifier_handler.handle(nullifier)
+	fn is_nullifier_spent(
+		&self,
+		ext: &Extensions,
+		nullifier: String,
+	) -> RpcResult<NullifierStatusResponse> {
+		self.serve(ext, "orbinum_v2_isNullifierSpent", CostClass::Light, || {
+			self.nullifier_handler.handle(nullifier)
+		})
 	}
 
-	fn get_pool_stats(&self) -> RpcResult<PoolStatsResponse> {
-		self.pool_stats_handler.handle()
+	fn is_nullifier_spent_deprecated(
+		&self,
+		ext: &Extensions,
+		nullifier: String,
+	) -> RpcResult<NullifierStatusResponse> {
+		self.deprecations.record(
+			"shieldedPool_isNullifierSpent",
+			"orbinum_v2_isNullifierSpent",
+		);
+		self.serve(
+			ext,
+			"shieldedPool_isNullifierSpent",
+			CostClass::Light,
+			|| self.nullifier_handler.handle(nullifier),
+		)
+	}
+
+	fn get_pool_stats_for_asset(
+		&self,
+		ext: &Extensions,
+		asset_id: Option<u32>,
+		at: Option<String>,
+	) -> RpcResult<PoolStatsResponse> {
+		self.serve(ext, "orbinum_v2_getPoolStats", CostClass::Light, || {
+			self.pool_stats_handler.handle_for_asset(asset_id, at)
+		})
+	}
+
+	fn get_pool_stats_for_asset_deprecated(
+		&self,
+		ext: &Extensions,
+		asset_id: Option<u32>,
+		at: Option<String>,
+	) -> RpcResult<PoolStatsResponse> {
+		self.deprecations
+			.record("shieldedPool_getPoolStats", "orbinum_v2_getPoolStats");
+		self.serve(ext, "shieldedPool_getPoolStats", CostClass::Light, || {
+			self.pool_stats_handler.handle_for_asset(asset_id, at)
+		})
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::orbinum::domain::{
-		AssetId, BlockHash, BlockchainQuery, Commitment, DomainError, DomainResult,
-		MerkleTreeQuery, Nullifier, NullifierQuery, PoolQuery, TreeSize,
+	use crate::orbinum::{
+		domain::{
+			AssetId, BlockHash, BlockchainQuery, Commitment, DomainError, DomainResult,
+			MerkleTreeQuery, Nullifier, NullifierQuery, PoolQuery, PoolStatistics, PoolStatsQuery,
+			TreeDepth, TreeSize,
+		},
+		presentation::RateLimitConfig,
 	};
+	use jsonrpsee::ConnectionId;
+	use std::num::NonZeroU32;
 
 	#[derive(Clone, Copy)]
 	struct MockQuery {
@@ -167,12 +306,12 @@ mod tests {
 	}
 
 	impl NullifierQuery for MockQuery {
-		fn is_nullifier_spent(
+		fn get_spent_block(
 			&self,
 			_block_hash: BlockHash,
 			_nullifier: Nullifier,
-		) -> DomainResult<bool> {
-			Ok(self.nullifier_spent)
+		) -> DomainResult<Option<u64>> {
+			Ok(self.nullifier_spent.then_some(3))
 		}
 	}
 
@@ -190,8 +329,26 @@ mod tests {
 		}
 	}
 
+	impl PoolStatsQuery for MockQuery {
+		fn get_pool_stats(
+			&self,
+			_block_hash: BlockHash,
+			asset_id: Option<AssetId>,
+		) -> DomainResult<PoolStatistics> {
+			Ok(PoolStatistics::new(
+				self.root,
+				TreeSize::new(self.tree_size),
+				self.total_balance,
+				TreeDepth::new(20),
+			)
+			.with_asset(asset_id)
+			.with_historic_roots(1, 100))
+		}
+	}
+
 	#[test]
 	fn should_delegate_all_endpoints_successfully() {
+		let ext = Extensions::new();
 		let server = PrivacyRpcServer::new(MockQuery {
 			root: Commitment::new([0x11u8; 32]),
 			tree_size: 2,
@@ -201,27 +358,127 @@ mod tests {
 		});
 
 		let root = server
-			.get_merkle_root()
+			.get_merkle_root(&ext)
 			.expect("get_merkle_root should succeed");
 		let proof = server
-			.get_merkle_proof(0)
+			.get_merkle_proof(&ext, 0)
 			.expect("get_merkle_proof should succeed");
 		let nullifier = server
-			.get_nullifier_status(format!("0x{}", "11".repeat(32)))
+			.get_nullifier_status(&ext, format!("0x{}", "11".repeat(32)))
 			.expect("get_nullifier_status should succeed");
 		let stats = server
-			.get_pool_stats()
+			.get_pool_stats(&ext)
 			.expect("get_pool_stats should succeed");
+		let asset_stats = server
+			.get_pool_stats_for_asset(&ext, Some(0), None)
+			.expect("get_pool_stats_for_asset should succeed");
+		let historic_stats = server
+			.get_pool_stats_for_asset(&ext, None, Some(format!("0x{}", "33".repeat(32))))
+			.expect("get_pool_stats_for_asset at a block should succeed");
 
 		assert_eq!(root, format!("0x{}", "11".repeat(32)));
 		assert_eq!(proof.leaf_index, 0);
 		assert_eq!(proof.tree_depth, 20);
 		assert!(nullifier.is_spent);
+		assert_eq!(nullifier.block, Some(3));
 		assert_eq!(stats.total_balance, 777);
+		assert_eq!(asset_stats.asset_id, Some(0));
+		assert_eq!(asset_stats.max_historic_roots, Some(100));
+		assert_eq!(historic_stats.asset_id, None);
+	}
+
+	#[test]
+	fn should_serve_deprecated_aliases_like_v2_methods() {
+		let ext = Extensions::new();
+		let server = PrivacyRpcServer::new(MockQuery {
+			root: Commitment::new([0x11u8; 32]),
+			tree_size: 2,
+			total_balance: 777,
+			nullifier_spent: true,
+			sibling: Commitment::new([0x22u8; 32]),
+		});
+		let nullifier = format!("0x{}", "11".repeat(32));
+
+		let status = server
+			.is_nullifier_spent_deprecated(&ext, nullifier.clone())
+			.expect("deprecated alias should succeed");
+		let stats = server
+			.get_pool_stats_for_asset_deprecated(&ext, Some(0), None)
+			.expect("deprecated alias should succeed");
+
+		let v2_status = server
+			.is_nullifier_spent(&ext, nullifier)
+			.expect("v2 method should succeed");
+		assert_eq!(status.block, v2_status.block);
+		assert_eq!(stats.asset_id, Some(0));
+
+		let module = server.into_rpc();
+		let methods: Vec<_> = module.method_names().collect();
+		for method in [
+			"orbinum_v2_isNullifierSpent",
+			"orbinum_v2_getPoolStats",
+			"shieldedPool_isNullifierSpent",
+			"shieldedPool_getPoolStats",
+		] {
+			assert!(methods.contains(&method), "{method} is not registered");
+		}
+	}
+
+	#[test]
+	fn should_export_request_metrics() {
+		let ext = Extensions::new();
+		let registry = Registry::new();
+		let server = PrivacyRpcServer::new(MockQuery {
+			root: Commitment::new([0x11u8; 32]),
+			tree_size: 1,
+			total_balance: 777,
+			nullifier_spent: false,
+			sibling: Commitment::new([0x22u8; 32]),
+		})
+		.with_prometheus(Some(&registry));
+
+		server
+			.get_merkle_root(&ext)
+			.expect("get_merkle_root should succeed");
+		assert!(server.get_merkle_proof(&ext, 1).is_err());
+
+		let names: Vec<_> = registry
+			.gather()
+			.iter()
+			.map(|family| family.get_name().to_string())
+			.collect();
+		assert!(names.contains(&"orbinum_privacy_rpc_requests_total".to_string()));
+		assert!(names.contains(&"orbinum_privacy_rpc_request_duration_seconds".to_string()));
+	}
+
+	#[test]
+	fn should_charge_merkle_proofs_as_heavy_calls() {
+		let mut ext = Extensions::new();
+		ext.insert(ConnectionId(1));
+		let rate_limiter = RateLimiter::new(RateLimitConfig {
+			units_per_second: NonZeroU32::new(10).expect("non-zero"),
+			heavy_cost: 10,
+		});
+		let server = PrivacyRpcServer::new(MockQuery {
+			root: Commitment::new([0x11u8; 32]),
+			tree_size: 2,
+			total_balance: 777,
+			nullifier_spent: false,
+			sibling: Commitment::new([0x22u8; 32]),
+		})
+		.with_rate_limiter(Some(Arc::new(rate_limiter)));
+
+		server
+			.get_merkle_proof(&ext, 0)
+			.expect("get_merkle_proof should succeed");
+		let result = server.get_merkle_root(&ext);
+
+		assert_eq!(result.expect_err("budget is spent").code(), -32007);
 	}
 
 	#[test]
 	fn should_fail_merkle_proof_when_leaf_index_is_invalid() {
+		let ext = Extensions::new();
 		let server = PrivacyRpcServer::new(MockQuery {
 			root: Commitment::new([0x11u8; 32]),
 			tree_size: 1,
@@ -230,7 +487,7 @@ mod tests {
 			sibling: Commitment::new([0x22u8; 32]),
 		});
 
-		let result = server.get_merkle_proof(1);
+		let result = server.get_merkle_proof(&ext, 1);
 
 		assert!(result.is_err());
 	}
@@ -81,6 +81,21 @@ impl RequestValidator {
 			.map_err(|_| RpcError::invalid_commitment("Invalid commitment hex".to_string()).into())
 	}
 
+	/// Validates and parses a block hash hex string.
+	///
+	/// # Parameters
+	/// - `block_hash_hex`: Hex string (with or without `0x` prefix)
+	///
+	/// # Returns
+	/// - `H256`: Parsed block hash
+	///
+	/// # Errors
+	/// - `InvalidBlockHash`: If format is invalid
+	pub fn validate_block_hash_hex(block_hash_hex: &str) -> RpcResult<H256> {
+		Self::validate_nullifier_hex(block_hash_hex)
+			.map_err(|_| RpcError::invalid_block_hash("Invalid block hash hex".to_string()).into())
+	}
+
 	/// Validates an asset ID.
 	///
 	/// # Parameters
@@ -134,6 +149,13 @@ mod tests {
 		assert!(RequestValidator::validate_commitment_hex("0x1234").is_err());
 	}
 
+	#[test]
+	fn should_validate_block_hash_hex() {
+		let hex = "0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+		assert!(RequestValidator::validate_block_hash_hex(hex).is_ok());
+		assert!(RequestValidator::validate_block_hash_hex("0xbb").is_err());
+	}
+
 	#[test]
 	fn should_accept_leaf_index_and_asset_id() {
 		assert!(RequestValidator::validate_leaf_index(0).is_ok());
@@ -2,7 +2,7 @@
 
 use jsonrpsee::types::{error::INTERNAL_ERROR_CODE, ErrorObjectOwned};
 
-use crate::orbinum::application::ApplicationError;
+use crate::orbinum::{application::ApplicationError, presentation::rate_limit::CostClass};
 
 /// Error type for the presentation layer.
 #[derive(Debug, Clone)]
@@ -50,6 +50,23 @@ impl RpcError {
 		Self::new(-32006, format!("Storage not available: {msg}"))
 	}
 
+	/// Error: Connection ran out of rate limit budget.
+	pub fn rate_limited(class: CostClass) -> Self {
+		let class = match class {
+			CostClass::Light => "light",
+			CostClass::Heavy => "heavy",
+		};
+		Self::new(
+			-32007,
+			format!("Rate limit exceeded for {class} request, retry later"),
+		)
+	}
+
+	/// Error: Invalid block hash.
+	pub fn invalid_block_hash(msg: String) -> Self {
+		Self::new(-32008, format!("Invalid block hash: {msg}"))
+	}
+
 	/// Internal error.
 	pub fn internal_error(msg: String) -> Self {
 		Self::new(INTERNAL_ERROR_CODE, format!("Internal error: {msg}"))
@@ -88,6 +105,7 @@ impl RpcError {
 				Self::storage_not_available(msg)
 			}
 			crate::orbinum::domain::DomainError::CalculationError(msg) => Self::internal_error(msg),
+			crate::orbinum::domain::DomainError::RuntimeApiError(msg) => Self::internal_error(msg),
 			crate::orbinum::domain::DomainError::NullifierNotFound => {
 				Self::invalid_nullifier("Nullifier not found".to_string())
 			}
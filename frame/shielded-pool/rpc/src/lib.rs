@@ -1,11 +1,60 @@
-use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
-use pallet_shielded_pool_runtime_api::ShieldedPoolRuntimeApi;
+use fc_rpc_v2::{CostClass, RateLimiter, RpcMetrics};
+// Shared with the indexer, which serves the same deprecated aliases
+pub use fc_rpc_v2::DeprecationLog;
+use frame_system::{EventRecord, Phase, pallet_prelude::BlockNumberFor};
+use jsonrpsee::{Extensions, core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
+use pallet_shielded_pool::{
+	AuditPolicy, AuditTrail, Auditor, BalanceOf, DisclosureCondition, DisclosureProof,
+	DisclosureRequest, Event as PoolEvent, ProofCheck, TransferValidation,
+};
+use pallet_shielded_pool_runtime_api::{
+	SHIELDED_POOL_API_V2, ShieldedPoolAuditApi, ShieldedPoolRuntimeApi,
+};
+use parity_scale_codec::{Compact, Decode, DecodeAll, Encode};
+use sc_client_api::{Backend, StorageProvider};
 use serde::{Deserialize, Serialize};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::traits::Block as BlockT;
+use sp_core::{hashing::twox_128, storage::StorageKey};
+use sp_runtime::{
+	SaturatedConversion,
+	traits::{Block as BlockT, NumberFor},
+};
 use std::sync::Arc;
 
+const LOG_TARGET: &str = "shielded-pool-rpc";
+
+/// Maximum number of blocks read by one `scan_events` or
+/// `get_compact_blocks` call
+pub const MAX_SCAN_BLOCKS: u64 = 1_000;
+
+/// Maximum number of commitments one `get_memos` call may look up
+pub const MAX_MEMO_BATCH: usize = 256;
+
+/// Maximum number of nullifiers one `are_nullifiers_spent` call may look up
+pub const MAX_NULLIFIER_BATCH: usize = 1_024;
+
+/// Largest page a `get_memos_range` call may request
+pub const MAX_MEMO_PAGE: u32 = 1_000;
+
+/// Largest page a `get_leaves` call may request
+pub const MAX_LEAF_PAGE: u32 = 4_096;
+
+/// Event limit of a `scan_events` page when the caller gives none
+pub const DEFAULT_SCAN_LIMIT: u32 = 1_000;
+
+/// Largest event limit a caller may request per page
+pub const MAX_SCAN_LIMIT: u32 = 10_000;
+
+/// Leaves per `export_tree_snapshot` chunk
+pub const SNAPSHOT_CHUNK_LEAVES: u32 = 4_096;
+
+/// Blocks covered by one `get_privacy_metrics` activity bucket
+pub const PRIVACY_BUCKET_BLOCKS: u64 = 100;
+
+/// Activity buckets returned by `get_privacy_metrics`
+pub const PRIVACY_BUCKETS: u64 = 10;
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct MerkleTreeInfo {
 	pub root: String,
@@ -20,6 +69,235 @@ pub struct MerkleProof {
 	pub siblings: Vec<String>,
 }
 
+/// An account's audit policy.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuditPolicyInfo {
+	pub auditors: Vec<AuditorInfo>,
+	pub conditions: Vec<DisclosureConditionInfo>,
+	/// Minimum blocks between two disclosures
+	pub max_frequency: Option<u64>,
+	pub version: u32,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum AuditorInfo {
+	Account { account: String },
+	Role { role: String },
+	CredentialHolder { credential: String },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum DisclosureConditionInfo {
+	Always,
+	TimeDelay {
+		after_block: u64,
+	},
+	AmountThreshold {
+		min_amount: u128,
+	},
+	JudicialOrder {
+		court_id: String,
+		case_id: String,
+	},
+	Custom {
+		condition_id: String,
+		params: String,
+	},
+}
+
+/// A pending disclosure request.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DisclosureRequestInfo {
+	pub auditor: String,
+	pub target: String,
+	pub requested_at: u64,
+	pub reason: String,
+	pub evidence: Option<String>,
+}
+
+/// A disclosure request awaiting the target's approval or rejection.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PendingDisclosureInfo {
+	#[serde(flatten)]
+	pub request: DisclosureRequestInfo,
+	/// Last block the request can be approved in
+	pub expires_at: u64,
+}
+
+/// An approved disclosure of a commitment.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DisclosureProofInfo {
+	pub commitment: String,
+	pub zk_proof: String,
+	pub disclosed_data: String,
+	pub timestamp: u64,
+}
+
+/// An audit trail entry, recorded when a disclosure is approved.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuditTrailInfo {
+	pub account: String,
+	pub auditor: String,
+	/// Block the disclosure was recorded in
+	pub block_number: u64,
+	pub disclosure_type: String,
+	pub trail_hash: String,
+}
+
+/// A registered shielded pool asset.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssetInfo {
+	pub asset_id: u32,
+	pub name: String,
+	pub symbol: String,
+	pub decimals: u8,
+	/// Only verified assets can be shielded
+	pub is_verified: bool,
+	pub contract_address: Option<String>,
+	/// Block the asset was registered in
+	pub created_at: u64,
+	pub creator: String,
+}
+
+/// Spent status of a batch of nullifiers, in request order.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NullifierBatchStatus {
+	/// Hex bitmap, bit `i % 8` of byte `i / 8` set if nullifier `i` is spent
+	pub spent: String,
+	/// Block each nullifier was spent in, `None` if unspent
+	pub spent_blocks: Vec<Option<u64>>,
+}
+
+/// A root still accepted for proofs.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HistoricRoot {
+	pub root: String,
+	/// Block the root was produced in
+	pub block_number: u64,
+	/// Further tree insertions the root survives before it is pruned;
+	/// a proof against a root at `0` fails once another leaf is added
+	pub insertions_until_pruned: u32,
+}
+
+/// Everything needed to prove a spend of one note, read at one block.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SpendWitness {
+	/// Root the path leads to: the current root at the queried block
+	pub anchor_root: String,
+	/// Block the anchor root was produced in
+	pub anchor_block: u64,
+	pub leaf_index: u32,
+	pub siblings: Vec<String>,
+	/// Further tree insertions the anchor survives before it is pruned
+	pub insertions_until_pruned: u32,
+	/// Blocks until the anchor is pruned, estimated from the average rate
+	/// new roots entered the historic window; `None` with fewer than two
+	/// roots in the window
+	pub blocks_until_pruned: Option<u64>,
+}
+
+/// Anonymity-set size and recent pool activity.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PrivacyMetrics {
+	/// Unspent notes in the tree, of every asset: transfers hide the asset
+	/// of their notes, so the pool has no per-asset anonymity set.
+	pub anonymity_set_size: u32,
+	pub commitment_count: u32,
+	pub spent_nullifiers: u32,
+	/// Oldest first, the last one ends at the queried block
+	pub buckets: Vec<ActivityBucket>,
+}
+
+/// Pool activity in blocks `from_block..=to_block`, across all assets.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ActivityBucket {
+	pub from_block: u64,
+	pub to_block: u64,
+	pub deposits: u32,
+	pub deposit_volume: u128,
+	pub withdrawals: u32,
+	pub withdrawal_volume: u128,
+	pub commitments_added: u32,
+	pub nullifiers_spent: u32,
+}
+
+impl ActivityBucket {
+	fn record(&mut self, event: &ShieldedEventType) {
+		match event {
+			ShieldedEventType::Shield { amount, .. } => {
+				self.commitments_added += 1;
+				self.deposits += 1;
+				self.deposit_volume = self.deposit_volume.saturating_add(*amount);
+			}
+			ShieldedEventType::PrivateTransfer {
+				nullifiers,
+				commitments,
+				..
+			} => {
+				self.commitments_added += commitments.len() as u32;
+				self.nullifiers_spent += nullifiers.len() as u32;
+			}
+			ShieldedEventType::Unshield { amount, .. } => {
+				self.nullifiers_spent += 1;
+				self.withdrawals += 1;
+				self.withdrawal_volume = self.withdrawal_volume.saturating_add(*amount);
+			}
+		}
+	}
+}
+
+/// One chunk of a Merkle tree checkpoint.
+///
+/// The first chunk carries the frontier; fetch the remaining leaves with
+/// `at = block_hash` and `start_leaf = next_leaf` until it is `None`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TreeSnapshotChunk<BlockHash> {
+	/// Block the snapshot is taken at
+	pub block_hash: BlockHash,
+	pub block_number: u64,
+	pub root: String,
+	pub tree_size: u32,
+	/// Rightmost node of each level, leaf level first; only in the first chunk
+	pub frontier: Option<Vec<String>>,
+	pub start_leaf: u32,
+	pub leaves: Vec<String>,
+	/// First leaf not yet returned, `None` once the end of the tree is reached
+	pub next_leaf: Option<u32>,
+}
+
+/// One page of `get_leaves` results.
+///
+/// `root` and `tree_size` are read at the same block as the leaves, so a
+/// wallet can check its local tree against them.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LeafPage {
+	pub root: String,
+	pub tree_size: u32,
+	pub start_leaf: u32,
+	pub leaves: Vec<String>,
+	/// First leaf not yet returned, `None` once the end of the tree is reached
+	pub next_leaf: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LeafMemo {
+	pub leaf_index: u32,
+	pub commitment: String,
+	pub memo: Option<String>,
+}
+
+/// One page of `get_memos_range` results.
+///
+/// Resume with `start_leaf = next_leaf` until it is `None`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MemoPage {
+	pub memos: Vec<LeafMemo>,
+	/// First leaf not yet returned, `None` once the end of the tree is reached
+	pub next_leaf: Option<u32>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ShieldedEvent {
 	pub block_number: u64,
@@ -27,11 +305,69 @@ pub struct ShieldedEvent {
 	pub event_type: ShieldedEventType,
 }
 
+/// Pool activity of one block, reduced to what wallets need to sync.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompactBlock<BlockHash> {
+	pub block_number: u64,
+	pub block_hash: BlockHash,
+	/// New commitments, in leaf order
+	pub outputs: Vec<CompactOutput>,
+	pub nullifiers: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompactOutput {
+	pub leaf_index: u32,
+	pub commitment: String,
+	/// Encrypted memo; recipients detect their notes from its MAC
+	pub memo: Option<String>,
+}
+
+/// One page of `get_compact_blocks` results.
+///
+/// Blocks without pool activity are left out: resume with
+/// `from_block = next_block`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompactBlockPage<BlockHash> {
+	pub blocks: Vec<CompactBlock<BlockHash>>,
+	/// First block not yet scanned, `None` once the range is exhausted
+	pub next_block: Option<u64>,
+}
+
+/// One page of `scan_events` results.
+///
+/// Pages end on a block boundary: resume with `from_block = next_block`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ShieldedEventPage {
+	pub events: Vec<ShieldedEvent>,
+	/// First block not yet scanned, `None` once the range is exhausted
+	pub next_block: Option<u64>,
+}
+
+/// Outcome of `validate_transfer`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TransferValidationInfo {
+	/// Whether `private_transfer` would pass every checked condition
+	pub valid: bool,
+	/// Proof size and input/output counts fit the extrinsic's bounds
+	pub within_bounds: bool,
+	pub root_known: bool,
+	/// Nullifiers already in the nullifier set
+	pub spent_nullifiers: Vec<String>,
+	/// `valid`, `invalid`, `verification_failed` or `not_checked`
+	pub proof: String,
+	/// Failed checks in the order the extrinsic runs them, named after the
+	/// pallet error it would fail with; `ExceedsCallBounds` when the call
+	/// could not even be encoded
+	pub errors: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(tag = "type")]
 pub enum ShieldedEventType {
 	Shield {
 		depositor: String,
+		asset_id: u32,
 		amount: u128,
 		commitment: String,
 		leaf_index: u32,
@@ -45,50 +381,845 @@ pub enum ShieldedEventType {
 	},
 	Unshield {
 		nullifier: String,
+		asset_id: u32,
 		amount: u128,
 		recipient: String,
 	},
 }
 
+/// Shielded pool JSON-RPC API
+///
+/// Methods use the versioned `orbinum_v2_` prefix. Those first published
+/// under the `shieldedPool_` prefix keep that name as a deprecated alias
+/// that answers the same way and logs its use.
 #[rpc(client, server)]
 pub trait ShieldedPoolApi<BlockHash> {
+	/// Merkle tree root, size and depth at block `at` (best block if `None`)
+	#[method(name = "orbinum_v2_getMerkleTreeInfo")]
+	fn get_merkle_tree_info(&self, at: Option<BlockHash>) -> RpcResult<MerkleTreeInfo>;
+
+	/// Deprecated alias of `orbinum_v2_getMerkleTreeInfo`.
 	#[method(name = "shieldedPool_getMerkleTreeInfo")]
-	fn get_merkle_tree_info(&self) -> RpcResult<MerkleTreeInfo>;
+	fn get_merkle_tree_info_deprecated(&self, at: Option<BlockHash>) -> RpcResult<MerkleTreeInfo>;
+
+	/// Merkle proof of `commitment` against the root at block `at`
+	/// (best block if `None`).
+	///
+	/// Provers targeting a historic root pass the block that root was
+	/// produced in, so the proof does not follow the moving best block.
+	#[method(name = "orbinum_v2_getMerkleProof", with_extensions)]
+	fn get_merkle_proof(&self, commitment: String, at: Option<BlockHash>)
+	-> RpcResult<MerkleProof>;
+
+	/// Deprecated alias of `orbinum_v2_getMerkleProof`.
+	#[method(name = "shieldedPool_getMerkleProof", with_extensions)]
+	fn get_merkle_proof_deprecated(
+		&self,
+		commitment: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<MerkleProof>;
+
+	/// Anchor root, leaf index, Merkle path and pruning horizon of
+	/// `commitment` at block `at` (best block if `None`), in one call.
+	///
+	/// Replaces `getMerkleProof` + `getHistoricRoots` + `getMerkleTreeInfo`
+	/// before building a transfer proof.
+	#[method(name = "orbinum_v2_getSpendWitness", with_extensions)]
+	fn get_spend_witness(
+		&self,
+		commitment: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<SpendWitness>;
+
+	/// Leaf index of `commitment` at block `at` (best block if `None`),
+	/// `None` if the commitment is not in the tree.
+	///
+	/// A single `CommitmentIndex` read, without building a Merkle proof.
+	#[method(name = "orbinum_v2_getCommitmentIndex")]
+	fn get_commitment_index(
+		&self,
+		commitment: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u32>>;
+
+	/// Deprecated alias of `orbinum_v2_getCommitmentIndex`.
+	#[method(name = "shieldedPool_getCommitmentIndex")]
+	fn get_commitment_index_deprecated(
+		&self,
+		commitment: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u32>>;
+
+	/// Encrypted memo of `commitment` at block `at` (best block if `None`),
+	/// `None` if no memo is stored.
+	#[method(name = "orbinum_v2_getMemo", with_extensions)]
+	fn get_memo(&self, commitment: String, at: Option<BlockHash>) -> RpcResult<Option<String>>;
+
+	/// Deprecated alias of `orbinum_v2_getMemo`.
+	#[method(name = "shieldedPool_getMemo", with_extensions)]
+	fn get_memo_deprecated(
+		&self,
+		commitment: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<String>>;
+
+	/// Encrypted memos of up to [`MAX_MEMO_BATCH`] commitments, in request
+	/// order, all read at the same block.
+	#[method(name = "orbinum_v2_getMemos", with_extensions)]
+	fn get_memos(
+		&self,
+		commitments: Vec<String>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Option<String>>>;
+
+	/// Deprecated alias of `orbinum_v2_getMemos`.
+	#[method(name = "shieldedPool_getMemos", with_extensions)]
+	fn get_memos_deprecated(
+		&self,
+		commitments: Vec<String>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Option<String>>>;
+
+	/// Spent status of up to [`MAX_NULLIFIER_BATCH`] nullifiers, all read at
+	/// block `at` (best block if `None`).
+	#[method(name = "orbinum_v2_areNullifiersSpent", with_extensions)]
+	fn are_nullifiers_spent(
+		&self,
+		nullifiers: Vec<String>,
+		at: Option<BlockHash>,
+	) -> RpcResult<NullifierBatchStatus>;
+
+	/// Audit policy of `account` (hex-encoded account id) at block `at`
+	/// (best block if `None`), `None` if the account has none.
+	#[method(name = "orbinum_v2_getAuditPolicy")]
+	fn get_audit_policy(
+		&self,
+		account: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AuditPolicyInfo>>;
 
-	#[method(name = "shieldedPool_getMerkleProof")]
-	fn get_merkle_proof(&self, commitment: String) -> RpcResult<MerkleProof>;
+	/// Deprecated alias of `orbinum_v2_getAuditPolicy`.
+	#[method(name = "shieldedPool_getAuditPolicy")]
+	fn get_audit_policy_deprecated(
+		&self,
+		account: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AuditPolicyInfo>>;
 
-	#[method(name = "shieldedPool_scanEvents")]
-	fn scan_events(&self, from_block: u64, to_block: u64) -> RpcResult<Vec<ShieldedEvent>>;
+	/// Pending disclosure requests targeting `target` (hex-encoded account id)
+	/// at block `at` (best block if `None`).
+	#[method(name = "orbinum_v2_getDisclosureRequests")]
+	fn get_disclosure_requests(
+		&self,
+		target: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DisclosureRequestInfo>>;
+
+	/// Deprecated alias of `orbinum_v2_getDisclosureRequests`.
+	#[method(name = "shieldedPool_getDisclosureRequests")]
+	fn get_disclosure_requests_deprecated(
+		&self,
+		target: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<DisclosureRequestInfo>>;
+
+	/// Disclosure requests targeting `account` (hex-encoded account id) that
+	/// can still be approved at block `at` (best block if `None`).
+	#[method(name = "orbinum_v2_getPendingDisclosures")]
+	fn get_pending_disclosures(
+		&self,
+		account: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PendingDisclosureInfo>>;
+
+	/// Deprecated alias of `orbinum_v2_getPendingDisclosures`.
+	#[method(name = "shieldedPool_getPendingDisclosures")]
+	fn get_pending_disclosures_deprecated(
+		&self,
+		account: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PendingDisclosureInfo>>;
+
+	/// Approved disclosure proof of `commitment` at block `at` (best block if
+	/// `None`), `None` if the commitment was not disclosed.
+	#[method(name = "orbinum_v2_getDisclosureProof")]
+	fn get_disclosure_proof(
+		&self,
+		commitment: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<DisclosureProofInfo>>;
+
+	/// Deprecated alias of `orbinum_v2_getDisclosureProof`.
+	#[method(name = "shieldedPool_getDisclosureProof")]
+	fn get_disclosure_proof_deprecated(
+		&self,
+		commitment: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<DisclosureProofInfo>>;
+
+	/// Audit trail entry recorded under `trail_hash` at block `at` (best
+	/// block if `None`), `None` if no entry has that hash.
+	///
+	/// `trail_hash` is the one emitted in `DisclosureApproved`.
+	#[method(name = "orbinum_v2_getAuditTrail")]
+	fn get_audit_trail(
+		&self,
+		trail_hash: String,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AuditTrailInfo>>;
+
+	/// Assets registered at block `at` (best block if `None`), ordered by
+	/// asset id. Includes unverified assets.
+	#[method(name = "orbinum_v2_listAssets")]
+	fn list_assets(&self, at: Option<BlockHash>) -> RpcResult<Vec<AssetInfo>>;
+
+	/// Deprecated alias of `orbinum_v2_listAssets`.
+	#[method(name = "shieldedPool_listAssets")]
+	fn list_assets_deprecated(&self, at: Option<BlockHash>) -> RpcResult<Vec<AssetInfo>>;
+
+	/// Roots accepted for proofs at block `at` (best block if `None`),
+	/// oldest first.
+	///
+	/// Provers should pick a recent root; `insertions_until_pruned` warns
+	/// when a proof's root is about to leave the window.
+	#[method(name = "orbinum_v2_getHistoricRoots")]
+	fn get_historic_roots(&self, at: Option<BlockHash>) -> RpcResult<Vec<HistoricRoot>>;
+
+	/// Deprecated alias of `orbinum_v2_getHistoricRoots`.
+	#[method(name = "shieldedPool_getHistoricRoots")]
+	fn get_historic_roots_deprecated(&self, at: Option<BlockHash>) -> RpcResult<Vec<HistoricRoot>>;
+
+	/// Leaves `start_leaf..start_leaf + count` with their commitments and
+	/// encrypted memos, for wallets recovering from seed.
+	///
+	/// `count` is at most [`MAX_MEMO_PAGE`]; `next_leaf` tells where to continue.
+	#[method(name = "orbinum_v2_getMemosRange", with_extensions)]
+	fn get_memos_range(
+		&self,
+		start_leaf: u32,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<MemoPage>;
+
+	/// Deprecated alias of `orbinum_v2_getMemosRange`.
+	#[method(name = "shieldedPool_getMemosRange", with_extensions)]
+	fn get_memos_range_deprecated(
+		&self,
+		start_leaf: u32,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<MemoPage>;
+
+	/// Commitments of leaves `start_leaf..start_leaf + count` at block `at`
+	/// (best block if `None`).
+	///
+	/// `count` is at most [`MAX_LEAF_PAGE`]; `next_leaf` tells where to continue.
+	#[method(name = "orbinum_v2_getLeaves", with_extensions)]
+	fn get_leaves(&self, start_leaf: u32, count: u32, at: Option<BlockHash>)
+	-> RpcResult<LeafPage>;
+
+	/// Deprecated alias of `orbinum_v2_getLeaves`.
+	#[method(name = "shieldedPool_getLeaves", with_extensions)]
+	fn get_leaves_deprecated(
+		&self,
+		start_leaf: u32,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<LeafPage>;
+
+	/// Anonymity-set size at block `at` (best block if `None`) and pool
+	/// activity over the [`PRIVACY_BUCKETS`] buckets of
+	/// [`PRIVACY_BUCKET_BLOCKS`] blocks up to it.
+	#[method(name = "orbinum_v2_getPrivacyMetrics", with_extensions)]
+	fn get_privacy_metrics(&self, at: Option<BlockHash>) -> RpcResult<PrivacyMetrics>;
+
+	/// Deprecated alias of `orbinum_v2_getPrivacyMetrics`.
+	#[method(name = "shieldedPool_getPrivacyMetrics", with_extensions)]
+	fn get_privacy_metrics_deprecated(&self, at: Option<BlockHash>) -> RpcResult<PrivacyMetrics>;
+
+	/// Merkle tree checkpoint at block `at` (best block if `None`).
+	///
+	/// Without `start_leaf` returns the frontier and the first
+	/// [`SNAPSHOT_CHUNK_LEAVES`] leaves; with it, only the leaves from there.
+	/// A wallet that only tracks new notes can stop after the frontier.
+	#[method(name = "orbinum_v2_exportTreeSnapshot", with_extensions)]
+	fn export_tree_snapshot(
+		&self,
+		at: Option<BlockHash>,
+		start_leaf: Option<u32>,
+	) -> RpcResult<TreeSnapshotChunk<BlockHash>>;
+
+	/// Deprecated alias of `orbinum_v2_exportTreeSnapshot`.
+	#[method(name = "shieldedPool_exportTreeSnapshot", with_extensions)]
+	fn export_tree_snapshot_deprecated(
+		&self,
+		at: Option<BlockHash>,
+		start_leaf: Option<u32>,
+	) -> RpcResult<TreeSnapshotChunk<BlockHash>>;
+
+	/// Compact blocks of `from_block..=to_block`: new commitments with their
+	/// memos and spent nullifiers only.
+	///
+	/// Reads at most [`MAX_SCAN_BLOCKS`] blocks; `next_block` tells where to
+	/// continue.
+	#[method(name = "orbinum_v2_getCompactBlocks", with_extensions)]
+	fn get_compact_blocks(
+		&self,
+		from_block: u64,
+		to_block: u64,
+	) -> RpcResult<CompactBlockPage<BlockHash>>;
+
+	/// Deprecated alias of `orbinum_v2_getCompactBlocks`.
+	#[method(name = "shieldedPool_getCompactBlocks", with_extensions)]
+	fn get_compact_blocks_deprecated(
+		&self,
+		from_block: u64,
+		to_block: u64,
+	) -> RpcResult<CompactBlockPage<BlockHash>>;
+
+	/// Shielded pool events of blocks `from_block..=to_block`.
+	///
+	/// Reads at most [`MAX_SCAN_BLOCKS`] blocks and stops after the block that
+	/// reaches `limit` events; `next_block` tells where to continue.
+	#[method(name = "orbinum_v2_scanEvents", with_extensions)]
+	fn scan_events(
+		&self,
+		from_block: u64,
+		to_block: u64,
+		limit: Option<u32>,
+	) -> RpcResult<ShieldedEventPage>;
+
+	/// Deprecated alias of `orbinum_v2_scanEvents`.
+	///
+	/// Keeps the original response: the events of the page only, without
+	/// `next_block`.
+	#[method(name = "shieldedPool_scanEvents", with_extensions)]
+	fn scan_events_deprecated(
+		&self,
+		from_block: u64,
+		to_block: u64,
+		limit: Option<u32>,
+	) -> RpcResult<Vec<ShieldedEvent>>;
+
+	/// Dry-runs the checks of `private_transfer` at block `at` (best block if
+	/// `None`): Merkle root known, nullifiers unspent and proof valid.
+	///
+	/// Memos are not checked. Unlike the extrinsic, every check runs, so all
+	/// failures are reported at once.
+	#[method(name = "orbinum_v2_validateTransfer", with_extensions)]
+	fn validate_transfer(
+		&self,
+		proof: String,
+		merkle_root: String,
+		nullifiers: Vec<String>,
+		commitments: Vec<String>,
+		at: Option<BlockHash>,
+	) -> RpcResult<TransferValidationInfo>;
+
+	/// Deprecated alias of `orbinum_v2_validateTransfer`.
+	#[method(name = "shieldedPool_validateTransfer", with_extensions)]
+	fn validate_transfer_deprecated(
+		&self,
+		proof: String,
+		merkle_root: String,
+		nullifiers: Vec<String>,
+		commitments: Vec<String>,
+		at: Option<BlockHash>,
+	) -> RpcResult<TransferValidationInfo>;
 }
 
-pub struct ShieldedPool<C, B> {
+/// Shielded pool RPC handler.
+///
+/// `BE` is the client backend the `System::Events` storage is read from and
+/// `R` the runtime whose event type that storage decodes to.
+pub struct ShieldedPool<C, B, BE, R> {
 	client: Arc<C>, // We keep client generic, but implement for specific bounds
-	_marker: std::marker::PhantomData<B>,
+	rate_limiter: Option<Arc<RateLimiter>>,
+	/// Usage log of the deprecated `shieldedPool_` aliases
+	deprecations: DeprecationLog,
+	/// Request metrics, if Prometheus is enabled
+	metrics: Option<Arc<RpcMetrics>>,
+	_marker: std::marker::PhantomData<(B, BE, R)>,
 }
 
-impl<C, B> ShieldedPool<C, B> {
+impl<C, B, BE, R> ShieldedPool<C, B, BE, R> {
 	pub fn new(client: Arc<C>) -> Self {
 		Self {
 			client,
+			rate_limiter: None,
+			deprecations: DeprecationLog::new(),
+			metrics: None,
 			_marker: Default::default(),
 		}
 	}
+
+	/// Charges the heavy methods (Merkle proofs, block range scans and
+	/// snapshot export) and the batch lookups to the caller's connection
+	/// budget in `rate_limiter`.
+	pub fn with_rate_limiter(mut self, rate_limiter: Option<Arc<RateLimiter>>) -> Self {
+		self.rate_limiter = rate_limiter;
+		self
+	}
+
+	/// Records the latency and outcome of every request in `metrics`,
+	/// shared with the privacy RPC (see `PrivacyRpcServer::metrics`).
+	pub fn with_metrics(mut self, metrics: Option<Arc<RpcMetrics>>) -> Self {
+		self.metrics = metrics;
+		self
+	}
+
+	/// Runs the handler of `method`, recording it when metrics are enabled
+	fn serve<T>(&self, method: &str, handler: impl FnOnce() -> RpcResult<T>) -> RpcResult<T> {
+		match &self.metrics {
+			Some(metrics) => metrics.observe(method, handler),
+			None => handler(),
+		}
+	}
+
+	/// Charges one heavy call to the connection of the request
+	fn charge_heavy(&self, ext: &Extensions) -> RpcResult<()> {
+		match &self.rate_limiter {
+			Some(rate_limiter) => rate_limiter.check(ext, CostClass::Heavy),
+			None => Ok(()),
+		}
+	}
+
+	/// Charges a batch call looking up `items` items, scaled by their count
+	fn charge_batch(&self, ext: &Extensions, items: usize) -> RpcResult<()> {
+		match &self.rate_limiter {
+			Some(rate_limiter) => rate_limiter.check_batch(ext, items),
+			None => Ok(()),
+		}
+	}
+}
+
+/// Storage key of `System::Events`
+fn system_events_key() -> StorageKey {
+	StorageKey([twox_128(b"System"), twox_128(b"Events")].concat())
 }
 
-impl<C, B> ShieldedPoolApiServer<B::Hash> for ShieldedPool<C, B>
+fn rpc_error(message: impl Into<String>) -> ErrorObjectOwned {
+	ErrorObjectOwned::owned(1, message.into(), None::<()>)
+}
+
+/// Parses a 32-byte commitment from hex (with or without `0x`)
+fn parse_commitment(commitment_hex: &str) -> RpcResult<[u8; 32]> {
+	let commitment_bytes = hex::decode(commitment_hex.trim_start_matches("0x"))
+		.map_err(|e| rpc_error(format!("Invalid hex: {e}")))?;
+
+	commitment_bytes
+		.try_into()
+		.map_err(|_| rpc_error("Commitment must be 32 bytes"))
+}
+
+/// Parses a SCALE-encoded account id from hex (with or without `0x`)
+fn parse_account<AccountId: Decode>(account_hex: &str) -> RpcResult<AccountId> {
+	let account_bytes = hex::decode(account_hex.trim_start_matches("0x"))
+		.map_err(|e| rpc_error(format!("Invalid hex: {e}")))?;
+
+	AccountId::decode_all(&mut &account_bytes[..])
+		.map_err(|e| rpc_error(format!("Invalid account id: {e}")))
+}
+
+/// Parses arbitrary bytes from hex (with or without `0x`)
+fn parse_bytes(bytes_hex: &str) -> RpcResult<Vec<u8>> {
+	hex::decode(bytes_hex.trim_start_matches("0x"))
+		.map_err(|e| rpc_error(format!("Invalid hex: {e}")))
+}
+
+fn hex_string(bytes: &[u8]) -> String {
+	format!("0x{}", hex::encode(bytes))
+}
+
+fn map_transfer_validation(validation: TransferValidation) -> TransferValidationInfo {
+	// Same order as the checks of `private_transfer`
+	let mut errors = Vec::new();
+	if !validation.within_bounds {
+		errors.push("ExceedsCallBounds".to_string());
+	}
+	if !validation.root_known {
+		errors.push("UnknownMerkleRoot".to_string());
+	}
+	if !validation.spent_nullifiers.is_empty() {
+		errors.push("NullifierAlreadyUsed".to_string());
+	}
+	let proof = match validation.proof {
+		ProofCheck::Valid => "valid",
+		ProofCheck::Invalid => {
+			errors.push("InvalidProof".to_string());
+			"invalid"
+		}
+		ProofCheck::VerificationFailed => {
+			errors.push("ProofVerificationFailed".to_string());
+			"verification_failed"
+		}
+		ProofCheck::NotChecked => "not_checked",
+	};
+
+	TransferValidationInfo {
+		valid: validation.is_valid(),
+		within_bounds: validation.within_bounds,
+		root_known: validation.root_known,
+		spent_nullifiers: validation
+			.spent_nullifiers
+			.iter()
+			.map(|nullifier| hex_string(&nullifier.0))
+			.collect(),
+		proof: proof.to_string(),
+		errors,
+	}
+}
+
+fn map_audit_policy<R: pallet_shielded_pool::Config>(
+	policy: AuditPolicy<R::AccountId, BalanceOf<R>, BlockNumberFor<R>>,
+) -> AuditPolicyInfo {
+	AuditPolicyInfo {
+		auditors: policy
+			.auditors
+			.into_iter()
+			.map(|auditor| match auditor {
+				Auditor::Account(account) => AuditorInfo::Account {
+					account: hex_string(&account.encode()),
+				},
+				Auditor::Role { role } => AuditorInfo::Role {
+					role: hex_string(&role),
+				},
+				Auditor::CredentialHolder { credential } => AuditorInfo::CredentialHolder {
+					credential: hex_string(&credential),
+				},
+			})
+			.collect(),
+		conditions: policy
+			.conditions
+			.into_iter()
+			.map(|condition| match condition {
+				DisclosureCondition::Always => DisclosureConditionInfo::Always,
+				DisclosureCondition::TimeDelay { after_block } => {
+					DisclosureConditionInfo::TimeDelay {
+						after_block: after_block.saturated_into(),
+					}
+				}
+				DisclosureCondition::AmountThreshold { min_amount } => {
+					DisclosureConditionInfo::AmountThreshold {
+						min_amount: min_amount.saturated_into(),
+					}
+				}
+				DisclosureCondition::JudicialOrder { court_id, case_id } => {
+					DisclosureConditionInfo::JudicialOrder {
+						court_id: hex_string(&court_id),
+						case_id: hex_string(&case_id),
+					}
+				}
+				DisclosureCondition::Custom {
+					condition_id,
+					params,
+				} => DisclosureConditionInfo::Custom {
+					condition_id: hex_string(&condition_id),
+					params: hex_string(&params),
+				},
+			})
+			.collect(),
+		max_frequency: policy.max_frequency.map(|blocks| blocks.saturated_into()),
+		version: policy.version,
+	}
+}
+
+fn map_disclosure_request<R: pallet_shielded_pool::Config>(
+	request: DisclosureRequest<R::AccountId, BlockNumberFor<R>>,
+) -> DisclosureRequestInfo {
+	DisclosureRequestInfo {
+		auditor: hex_string(&request.auditor.encode()),
+		target: hex_string(&request.target.encode()),
+		requested_at: request.requested_at.saturated_into(),
+		reason: String::from_utf8_lossy(&request.reason).into_owned(),
+		evidence: request.evidence.map(|evidence| hex_string(&evidence)),
+	}
+}
+
+fn map_disclosure_proof(proof: DisclosureProof) -> DisclosureProofInfo {
+	DisclosureProofInfo {
+		commitment: hex_string(&proof.commitment.0),
+		zk_proof: hex_string(&proof.zk_proof),
+		disclosed_data: hex_string(&proof.disclosed_data),
+		timestamp: proof.timestamp,
+	}
+}
+
+fn map_audit_trail<R: pallet_shielded_pool::Config>(
+	trail: AuditTrail<R::AccountId, BlockNumberFor<R>>,
+) -> AuditTrailInfo {
+	AuditTrailInfo {
+		account: hex_string(&trail.account.encode()),
+		auditor: hex_string(&trail.auditor.encode()),
+		block_number: trail.timestamp.saturated_into(),
+		disclosure_type: String::from_utf8_lossy(&trail.disclosure_type).into_owned(),
+		trail_hash: hex_string(&trail.trail_hash),
+	}
+}
+
+/// Maps a shielded pool event to its DTO, `None` for events not exposed here
+pub fn map_pool_event<R: pallet_shielded_pool::Config>(
+	event: PoolEvent<R>,
+) -> Option<ShieldedEventType> {
+	let memo = |memo: &pallet_shielded_pool::FrameEncryptedMemo| {
+		(!memo.0.is_empty()).then(|| hex_string(&memo.0))
+	};
+
+	match event {
+		PoolEvent::Shielded {
+			depositor,
+			asset_id,
+			amount,
+			commitment,
+			encrypted_memo,
+			leaf_index,
+		} => Some(ShieldedEventType::Shield {
+			depositor: hex_string(&depositor.encode()),
+			asset_id,
+			amount: amount.saturated_into(),
+			commitment: hex_string(&commitment.0),
+			leaf_index,
+			encrypted_memo: memo(&encrypted_memo),
+		}),
+		PoolEvent::PrivateTransfer {
+			nullifiers,
+			commitments,
+			encrypted_memos,
+			leaf_indices,
+		} => Some(ShieldedEventType::PrivateTransfer {
+			nullifiers: nullifiers.iter().map(|n| hex_string(&n.0)).collect(),
+			commitments: commitments.iter().map(|c| hex_string(&c.0)).collect(),
+			leaf_indices: leaf_indices.into_inner(),
+			encrypted_memos: (!encrypted_memos.is_empty())
+				.then(|| encrypted_memos.iter().map(|m| hex_string(&m.0)).collect()),
+		}),
+		PoolEvent::Unshielded {
+			nullifier,
+			asset_id,
+			amount,
+			recipient,
+		} => Some(ShieldedEventType::Unshield {
+			nullifier: hex_string(&nullifier.0),
+			asset_id,
+			amount: amount.saturated_into(),
+			recipient: hex_string(&recipient.encode()),
+		}),
+		_ => None,
+	}
+}
+
+impl<C, B, BE, R> ShieldedPool<C, B, BE, R>
 where
-	C: ProvideRuntimeApi<B> + HeaderBackend<B> + 'static,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + StorageProvider<B, BE>,
 	C::Api: ShieldedPoolRuntimeApi<B>,
 	B: BlockT,
+	BE: Backend<B>,
+	R: pallet_shielded_pool::Config,
+	<R as frame_system::Config>::RuntimeEvent: TryInto<PoolEvent<R>>,
 {
-	fn get_merkle_tree_info(&self) -> RpcResult<MerkleTreeInfo> {
+	/// Hash of canonical block `number`
+	fn block_hash(&self, number: u64) -> RpcResult<B::Hash> {
+		self.client
+			.hash(number.saturated_into::<NumberFor<B>>())
+			.map_err(|e| rpc_error(format!("Header error: {e}")))?
+			.ok_or_else(|| rpc_error(format!("Block {number} not found")))
+	}
+
+	/// Compact form of block `number`, `None` if it has no pool activity
+	fn compact_block(&self, number: u64) -> RpcResult<Option<CompactBlock<B::Hash>>> {
+		let events = self.block_events(number)?;
+		if events.is_empty() {
+			return Ok(None);
+		}
+
+		let mut block = CompactBlock {
+			block_number: number,
+			block_hash: self.block_hash(number)?,
+			outputs: Vec::new(),
+			nullifiers: Vec::new(),
+		};
+		for event in events {
+			match event.event_type {
+				ShieldedEventType::Shield {
+					commitment,
+					leaf_index,
+					encrypted_memo,
+					..
+				} => block.outputs.push(CompactOutput {
+					leaf_index,
+					commitment,
+					memo: encrypted_memo,
+				}),
+				ShieldedEventType::PrivateTransfer {
+					nullifiers,
+					commitments,
+					leaf_indices,
+					encrypted_memos,
+				} => {
+					let mut memos = encrypted_memos.unwrap_or_default().into_iter();
+					block
+						.outputs
+						.extend(leaf_indices.into_iter().zip(commitments).map(
+							|(leaf_index, commitment)| CompactOutput {
+								leaf_index,
+								commitment,
+								memo: memos.next(),
+							},
+						));
+					block.nullifiers.extend(nullifiers);
+				}
+				ShieldedEventType::Unshield { nullifier, .. } => block.nullifiers.push(nullifier),
+			}
+		}
+
+		Ok(Some(block))
+	}
+
+	/// Shielded pool events emitted by extrinsics of block `number`
+	///
+	/// Records that do not decode to the runtime event type of this node are
+	/// skipped with a warning rather than failing the call.
+	fn block_events(&self, number: u64) -> RpcResult<Vec<ShieldedEvent>> {
+		let hash = self.block_hash(number)?;
+
+		// Pool events of runtimes before version 2 of the API predate
+		// multi-asset pools and would decode to the wrong fields
+		let version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn ShieldedPoolRuntimeApi<B>>(hash)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		if version.unwrap_or_default() < SHIELDED_POOL_API_V2 {
+			log::debug!(
+				target: LOG_TARGET,
+				"Skipping events of block {number}: runtime predates asset-aware pool events",
+			);
+			return Ok(Vec::new());
+		}
+
+		let Some(raw) = self
+			.client
+			.storage(hash, &system_events_key())
+			.map_err(|e| rpc_error(format!("Storage error: {e}")))?
+		else {
+			return Ok(Vec::new());
+		};
+
+		type Record<R> = EventRecord<
+			<R as frame_system::Config>::RuntimeEvent,
+			<R as frame_system::Config>::Hash,
+		>;
+		let mut input = &raw.0[..];
+		let count = Compact::<u32>::decode(&mut input)
+			.map_err(|e| rpc_error(format!("Failed to decode events of block {number}: {e}")))?
+			.0;
+
+		let mut events = Vec::new();
+		for index in 0..count {
+			// Records have no length prefix, so none after a failure can be located
+			let record = match Record::<R>::decode(&mut input) {
+				Ok(record) => record,
+				Err(e) => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Skipping events {index}..{count} of block {number}: {e}",
+					);
+					break;
+				}
+			};
+			// Pool events are only emitted while applying extrinsics
+			let Phase::ApplyExtrinsic(extrinsic_index) = record.phase else {
+				continue;
+			};
+			let Ok(event): Result<PoolEvent<R>, _> = record.event.try_into() else {
+				continue;
+			};
+			let Some(event_type) = map_pool_event(event) else {
+				continue;
+			};
+			events.push(ShieldedEvent {
+				block_number: number,
+				extrinsic_index,
+				event_type,
+			});
+		}
+
+		Ok(events)
+	}
+
+	/// Pool activity of blocks `from_block..=to_block`
+	fn activity_bucket(&self, from_block: u64, to_block: u64) -> RpcResult<ActivityBucket> {
+		let mut bucket = ActivityBucket {
+			from_block,
+			to_block,
+			..Default::default()
+		};
+		for number in from_block..=to_block {
+			for event in self.block_events(number)? {
+				bucket.record(&event.event_type);
+			}
+		}
+		Ok(bucket)
+	}
+}
+
+impl<C, B, BE, R> ShieldedPool<C, B, BE, R>
+where
+	C: ProvideRuntimeApi<B>,
+	C::Api: ShieldedPoolRuntimeApi<B>
+		+ ShieldedPoolAuditApi<B, R::AccountId, BalanceOf<R>, BlockNumberFor<R>>,
+	B: BlockT,
+	R: pallet_shielded_pool::Config,
+{
+	/// Fails unless the runtime at `at` serves the queries added in version 2
+	/// of `ShieldedPoolRuntimeApi`, which blocks before the upgrade lack
+	fn ensure_pool_api_v2(&self, at: B::Hash) -> RpcResult<()> {
+		let version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn ShieldedPoolRuntimeApi<B>>(at)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		match version {
+			Some(version) if version >= SHIELDED_POOL_API_V2 => Ok(()),
+			_ => Err(rpc_error("Not supported by the runtime at this block")),
+		}
+	}
+
+	/// Fails unless the runtime at `at` implements `ShieldedPoolAuditApi`
+	fn ensure_audit_api(&self, at: B::Hash) -> RpcResult<()> {
+		let supported = self
+			.client
+			.runtime_api()
+			.has_api::<dyn ShieldedPoolAuditApi<B, R::AccountId, BalanceOf<R>, BlockNumberFor<R>>>(
+				at,
+			)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		if !supported {
+			return Err(rpc_error("Not supported by the runtime at this block"));
+		}
+		Ok(())
+	}
+}
+
+// Handlers of the API methods, run through `serve` under the name they were
+// called by
+impl<C, B, BE, R> ShieldedPool<C, B, BE, R>
+where
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + StorageProvider<B, BE> + Send + Sync + 'static,
+	C::Api: ShieldedPoolRuntimeApi<B>
+		+ ShieldedPoolAuditApi<B, R::AccountId, BalanceOf<R>, BlockNumberFor<R>>,
+	B: BlockT,
+	BE: Backend<B> + Send + Sync + 'static,
+	R: pallet_shielded_pool::Config + Send + Sync + 'static,
+	<R as frame_system::Config>::RuntimeEvent: TryInto<PoolEvent<R>>,
+{
+	fn merkle_tree_info(&self, at: Option<B::Hash>) -> RpcResult<MerkleTreeInfo> {
 		let api = self.client.runtime_api();
-		let best_block = self.client.info().best_hash;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
 
 		// Call runtime API
 		let (root, tree_size, depth) = api
-			.get_merkle_tree_info(best_block)
+			.get_merkle_tree_info(at)
 			.map_err(|e| ErrorObjectOwned::owned(1, format!("Runtime error: {e}"), None::<()>))?;
 
 		Ok(MerkleTreeInfo {
@@ -98,34 +1229,30 @@ where
 		})
 	}
 
-	fn get_merkle_proof(&self, commitment_hex: String) -> RpcResult<MerkleProof> {
-		let commitment_bytes = hex::decode(commitment_hex.trim_start_matches("0x"))
-			.map_err(|e| ErrorObjectOwned::owned(1, format!("Invalid hex: {e}"), None::<()>))?;
-
-		let mut commitment = [0u8; 32];
-		if commitment_bytes.len() != 32 {
-			return Err(ErrorObjectOwned::owned(
-				1,
-				"Commitment must be 32 bytes",
-				None::<()>,
-			));
-		}
-		commitment.copy_from_slice(&commitment_bytes);
+	fn merkle_proof(
+		&self,
+		ext: &Extensions,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<MerkleProof> {
+		self.charge_heavy(ext)?;
+		let commitment = parse_commitment(&commitment_hex)?;
 
 		let api = self.client.runtime_api();
-		let best_block = self.client.info().best_hash;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
 
+		// Proof and root are read at the same block so they always match
 		let (leaf_index, proof) = api
-			.get_merkle_proof_for_commitment(best_block, commitment)
+			.get_merkle_proof_for_commitment(at, commitment)
 			.map_err(|e| ErrorObjectOwned::owned(1, format!("Runtime error: {e}"), None::<()>))?
 			.ok_or_else(|| {
 				ErrorObjectOwned::owned(1, "Commitment not found in tree", None::<()>)
 			})?;
 
 		let root_hash = api
-			.get_merkle_tree_info(best_block)
+			.get_merkle_tree_info(at)
 			.map(|(root, _, _)| root)
-			.unwrap_or([0u8; 32]);
+			.map_err(|e| ErrorObjectOwned::owned(1, format!("Runtime error: {e}"), None::<()>))?;
 
 		Ok(MerkleProof {
 			root: format!("0x{}", hex::encode(root_hash)),
@@ -138,17 +1265,1044 @@ where
 		})
 	}
 
-	fn scan_events(&self, _from_block: u64, _to_block: u64) -> RpcResult<Vec<ShieldedEvent>> {
-		// Event scanning is not implemented via runtime API
-		// This functionality should be implemented by:
-		// 1. Indexing events in an off-chain database (recommended)
-		// 2. Using Substrate's archive node with state queries
-		// 3. Implementing a custom indexer service
-		//
-		// Returning empty list as placeholder.
-		// TODO: Implement proper event indexing strategy
+	fn spend_witness(
+		&self,
+		ext: &Extensions,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<SpendWitness> {
+		self.charge_heavy(ext)?;
+		let commitment = parse_commitment(&commitment_hex)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
+		let api = self.client.runtime_api();
+
+		let block_number = self
+			.client
+			.number(at)
+			.map_err(|e| rpc_error(format!("Header error: {e}")))?
+			.ok_or_else(|| rpc_error("Block not found"))?
+			.saturated_into::<u64>();
+		let (leaf_index, proof) = api
+			.get_merkle_proof_for_commitment(at, commitment)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?
+			.ok_or_else(|| rpc_error("Commitment not found in tree"))?;
+		let (anchor_root, _, _) = api
+			.get_merkle_tree_info(at)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		let roots = api
+			.get_historic_roots(at)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		let max_roots = api
+			.get_pool_stats(at, None)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?
+			.max_historic_roots;
+
+		let position = roots
+			.iter()
+			.rposition(|(root, _)| *root == anchor_root)
+			.ok_or_else(|| rpc_error("Anchor root is not in the historic window"))?;
+		let anchor_block = roots[position].1;
+		// Same count as `getHistoricRoots`
+		let free_slots = max_roots.saturating_sub(roots.len() as u32);
+		let insertions_until_pruned = free_slots.saturating_add(position as u32);
+
+		// Roots after the oldest one entered the window since its block
+		let blocks_until_pruned = match roots.first() {
+			Some((_, oldest_block)) if roots.len() > 1 => {
+				let window_blocks = block_number.saturating_sub(*oldest_block);
+				Some(
+					window_blocks.saturating_mul(insertions_until_pruned as u64)
+						/ (roots.len() as u64 - 1),
+				)
+			}
+			_ => None,
+		};
+
+		Ok(SpendWitness {
+			anchor_root: hex_string(&anchor_root),
+			anchor_block,
+			leaf_index,
+			siblings: proof.siblings.iter().map(|h| hex_string(h)).collect(),
+			insertions_until_pruned,
+			blocks_until_pruned,
+		})
+	}
+
+	fn commitment_index(
+		&self,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<u32>> {
+		let commitment = parse_commitment(&commitment_hex)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
+
+		self.client
+			.runtime_api()
+			.get_commitment_index(at, commitment)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))
+	}
+
+	fn encrypted_memo(
+		&self,
+		ext: &Extensions,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<String>> {
+		self.encrypted_memos(ext, vec![commitment_hex], at)
+			.map(|mut memos| memos.pop().flatten())
+	}
+
+	fn encrypted_memos(
+		&self,
+		ext: &Extensions,
+		commitments: Vec<String>,
+		at: Option<B::Hash>,
+	) -> RpcResult<Vec<Option<String>>> {
+		if commitments.len() > MAX_MEMO_BATCH {
+			return Err(rpc_error(format!(
+				"At most {MAX_MEMO_BATCH} commitments per request"
+			)));
+		}
+		self.charge_batch(ext, commitments.len())?;
+		let commitments = commitments
+			.iter()
+			.map(|commitment| parse_commitment(commitment))
+			.collect::<RpcResult<Vec<_>>>()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
+
+		commitments
+			.into_iter()
+			.map(|commitment| {
+				api.get_encrypted_memo(at, commitment)
+					.map(|memo| memo.map(|memo| hex_string(&memo)))
+					.map_err(|e| rpc_error(format!("Runtime error: {e}")))
+			})
+			.collect()
+	}
+
+	fn nullifier_statuses(
+		&self,
+		ext: &Extensions,
+		nullifiers: Vec<String>,
+		at: Option<B::Hash>,
+	) -> RpcResult<NullifierBatchStatus> {
+		if nullifiers.len() > MAX_NULLIFIER_BATCH {
+			return Err(rpc_error(format!(
+				"At most {MAX_NULLIFIER_BATCH} nullifiers per request"
+			)));
+		}
+		self.charge_batch(ext, nullifiers.len())?;
+		let nullifiers = nullifiers
+			.iter()
+			.map(|nullifier| parse_commitment(nullifier))
+			.collect::<RpcResult<Vec<_>>>()?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
+
+		let spent_blocks = nullifiers
+			.into_iter()
+			.map(|nullifier| {
+				api.get_nullifier_spent_block(at, nullifier)
+					.map_err(|e| rpc_error(format!("Runtime error: {e}")))
+			})
+			.collect::<RpcResult<Vec<_>>>()?;
+
+		let mut bitmap = vec![0u8; spent_blocks.len().div_ceil(8)];
+		for (i, block) in spent_blocks.iter().enumerate() {
+			if block.is_some() {
+				bitmap[i / 8] |= 1 << (i % 8);
+			}
+		}
+
+		Ok(NullifierBatchStatus {
+			spent: hex_string(&bitmap),
+			spent_blocks,
+		})
+	}
+
+	fn audit_policy(
+		&self,
+		account_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<AuditPolicyInfo>> {
+		let account = parse_account::<R::AccountId>(&account_hex)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_audit_api(at)?;
+
+		let policy = self
+			.client
+			.runtime_api()
+			.get_audit_policy(at, account)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+
+		Ok(policy.map(map_audit_policy::<R>))
+	}
+
+	fn disclosure_requests(
+		&self,
+		target_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Vec<DisclosureRequestInfo>> {
+		let target = parse_account::<R::AccountId>(&target_hex)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_audit_api(at)?;
+
+		let requests = self
+			.client
+			.runtime_api()
+			.get_disclosure_requests(at, target)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+
+		Ok(requests
+			.into_iter()
+			.map(map_disclosure_request::<R>)
+			.collect())
+	}
+
+	fn pending_disclosures(
+		&self,
+		account_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Vec<PendingDisclosureInfo>> {
+		let account = parse_account::<R::AccountId>(&account_hex)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_audit_api(at)?;
+
+		let pending = self
+			.client
+			.runtime_api()
+			.get_pending_disclosures(at, account)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+
+		Ok(pending
+			.into_iter()
+			.map(|(request, expires_at)| PendingDisclosureInfo {
+				request: map_disclosure_request::<R>(request),
+				expires_at: expires_at.saturated_into(),
+			})
+			.collect())
+	}
+
+	fn disclosure_proof(
+		&self,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<DisclosureProofInfo>> {
+		let commitment = parse_commitment(&commitment_hex)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_audit_api(at)?;
+
+		let proof = self
+			.client
+			.runtime_api()
+			.get_disclosure_proof(at, commitment)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+
+		Ok(proof.map(map_disclosure_proof))
+	}
+
+	fn audit_trail(
+		&self,
+		trail_hash_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<AuditTrailInfo>> {
+		let trail_hash = parse_commitment(&trail_hash_hex)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_audit_api(at)?;
+
+		let trail = self
+			.client
+			.runtime_api()
+			.get_audit_trail(at, trail_hash)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+
+		Ok(trail.map(map_audit_trail::<R>))
+	}
+
+	fn assets(&self, at: Option<B::Hash>) -> RpcResult<Vec<AssetInfo>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
+
+		let assets = self
+			.client
+			.runtime_api()
+			.list_assets(at)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+
+		Ok(assets
+			.into_iter()
+			.map(|(asset_id, asset)| AssetInfo {
+				asset_id,
+				name: String::from_utf8_lossy(&asset.name).into_owned(),
+				symbol: String::from_utf8_lossy(&asset.symbol).into_owned(),
+				decimals: asset.decimals,
+				is_verified: asset.is_verified,
+				contract_address: asset.contract_address.map(|address| hex_string(&address)),
+				created_at: asset.created_at,
+				creator: hex_string(&asset.creator),
+			})
+			.collect())
+	}
+
+	fn historic_roots(&self, at: Option<B::Hash>) -> RpcResult<Vec<HistoricRoot>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
 
-		log::warn!("scan_events called but not implemented - use event indexer instead");
-		Ok(Vec::new())
+		let roots = api
+			.get_historic_roots(at)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		let max_roots = api
+			.get_pool_stats(at, None)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?
+			.max_historic_roots;
+
+		// Each insertion into a full window prunes the oldest root
+		let free_slots = max_roots.saturating_sub(roots.len() as u32);
+		Ok(roots
+			.into_iter()
+			.enumerate()
+			.map(|(position, (root, block_number))| HistoricRoot {
+				root: hex_string(&root),
+				block_number,
+				insertions_until_pruned: free_slots.saturating_add(position as u32),
+			})
+			.collect())
+	}
+
+	fn memo_page(
+		&self,
+		ext: &Extensions,
+		start_leaf: u32,
+		count: u32,
+		at: Option<B::Hash>,
+	) -> RpcResult<MemoPage> {
+		if count == 0 || count > MAX_MEMO_PAGE {
+			return Err(rpc_error(format!(
+				"count must be between 1 and {MAX_MEMO_PAGE}"
+			)));
+		}
+		self.charge_batch(ext, count as usize)?;
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
+
+		// Page and tree size are read at the same block
+		let (_, tree_size, _) = api
+			.get_merkle_tree_info(at)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		let entries = api
+			.get_memos_range(at, start_leaf, count)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+
+		let next_leaf = start_leaf.saturating_add(count);
+		Ok(MemoPage {
+			memos: entries
+				.into_iter()
+				.map(|(leaf_index, commitment, memo)| LeafMemo {
+					leaf_index,
+					commitment: hex_string(&commitment),
+					memo: memo.map(|memo| hex_string(&memo)),
+				})
+				.collect(),
+			next_leaf: (next_leaf < tree_size).then_some(next_leaf),
+		})
+	}
+
+	fn leaf_page(
+		&self,
+		ext: &Extensions,
+		start_leaf: u32,
+		count: u32,
+		at: Option<B::Hash>,
+	) -> RpcResult<LeafPage> {
+		self.charge_heavy(ext)?;
+		if count == 0 || count > MAX_LEAF_PAGE {
+			return Err(rpc_error(format!(
+				"count must be between 1 and {MAX_LEAF_PAGE}"
+			)));
+		}
+
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
+
+		// Leaves, root and tree size are read at the same block
+		let (root, tree_size, _) = api
+			.get_merkle_tree_info(at)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		let leaves = api
+			.get_leaves(at, start_leaf, count)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+
+		let next_leaf = start_leaf.saturating_add(count);
+		Ok(LeafPage {
+			root: hex_string(&root),
+			tree_size,
+			start_leaf,
+			leaves: leaves.iter().map(|leaf| hex_string(leaf)).collect(),
+			next_leaf: (next_leaf < tree_size).then_some(next_leaf),
+		})
+	}
+
+	fn tree_snapshot(
+		&self,
+		ext: &Extensions,
+		at: Option<B::Hash>,
+		start_leaf: Option<u32>,
+	) -> RpcResult<TreeSnapshotChunk<B::Hash>> {
+		self.charge_heavy(ext)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
+		let api = self.client.runtime_api();
+
+		let block_number = self
+			.client
+			.number(at)
+			.map_err(|e| rpc_error(format!("Header error: {e}")))?
+			.ok_or_else(|| rpc_error("Block not found"))?
+			.saturated_into::<u64>();
+		let (root, tree_size, _depth) = api
+			.get_merkle_tree_info(at)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+
+		let frontier = match start_leaf {
+			Some(_) => None,
+			None => Some(
+				api.get_tree_frontier(at)
+					.map_err(|e| rpc_error(format!("Runtime error: {e}")))?
+					.iter()
+					.map(|node| hex_string(node))
+					.collect(),
+			),
+		};
+
+		let start_leaf = start_leaf.unwrap_or(0);
+		let leaves = api
+			.get_leaves(at, start_leaf, SNAPSHOT_CHUNK_LEAVES)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		let next_leaf = start_leaf.saturating_add(leaves.len() as u32);
+
+		Ok(TreeSnapshotChunk {
+			block_hash: at,
+			block_number,
+			root: hex_string(&root),
+			tree_size,
+			frontier,
+			start_leaf,
+			leaves: leaves.iter().map(|leaf| hex_string(leaf)).collect(),
+			next_leaf: (next_leaf < tree_size).then_some(next_leaf),
+		})
+	}
+
+	fn privacy_metrics(&self, ext: &Extensions, at: Option<B::Hash>) -> RpcResult<PrivacyMetrics> {
+		self.charge_heavy(ext)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
+		let api = self.client.runtime_api();
+
+		let (_root, commitment_count, _depth) = api
+			.get_merkle_tree_info(at)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		let spent_nullifiers = api
+			.get_spent_nullifier_count(at)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+		let last = self
+			.client
+			.number(at)
+			.map_err(|e| rpc_error(format!("Header error: {e}")))?
+			.ok_or_else(|| rpc_error("Block not found"))?
+			.saturated_into::<u64>();
+
+		let mut buckets = Vec::new();
+		let mut to_block = last;
+		for _ in 0..PRIVACY_BUCKETS {
+			let from_block = (to_block + 1).saturating_sub(PRIVACY_BUCKET_BLOCKS);
+			buckets.push(self.activity_bucket(from_block, to_block)?);
+			if from_block == 0 {
+				break;
+			}
+			to_block = from_block - 1;
+		}
+		buckets.reverse();
+
+		Ok(PrivacyMetrics {
+			anonymity_set_size: commitment_count.saturating_sub(spent_nullifiers),
+			commitment_count,
+			spent_nullifiers,
+			buckets,
+		})
+	}
+
+	fn compact_blocks(
+		&self,
+		ext: &Extensions,
+		from_block: u64,
+		to_block: u64,
+	) -> RpcResult<CompactBlockPage<B::Hash>> {
+		self.charge_heavy(ext)?;
+		if from_block > to_block {
+			return Err(rpc_error("from_block must not exceed to_block"));
+		}
+
+		let best = self.client.info().best_number.saturated_into::<u64>();
+		let last = to_block.min(best);
+		let bound = from_block.saturating_add(MAX_SCAN_BLOCKS - 1).min(last);
+
+		let mut blocks = Vec::new();
+		for number in from_block..=bound {
+			blocks.extend(self.compact_block(number)?);
+		}
+
+		Ok(CompactBlockPage {
+			blocks,
+			next_block: (bound < last).then_some(bound + 1),
+		})
+	}
+
+	fn event_page(
+		&self,
+		ext: &Extensions,
+		from_block: u64,
+		to_block: u64,
+		limit: Option<u32>,
+	) -> RpcResult<ShieldedEventPage> {
+		self.charge_heavy(ext)?;
+		if from_block > to_block {
+			return Err(rpc_error("from_block must not exceed to_block"));
+		}
+		let limit = limit.unwrap_or(DEFAULT_SCAN_LIMIT);
+		if limit == 0 || limit > MAX_SCAN_LIMIT {
+			return Err(rpc_error(format!(
+				"limit must be between 1 and {MAX_SCAN_LIMIT}"
+			)));
+		}
+
+		let best = self.client.info().best_number.saturated_into::<u64>();
+		let last = to_block.min(best);
+		let bound = from_block.saturating_add(MAX_SCAN_BLOCKS - 1).min(last);
+
+		let mut events = Vec::new();
+		let mut number = from_block;
+		while number <= bound && events.len() < limit as usize {
+			events.extend(self.block_events(number)?);
+			number += 1;
+		}
+
+		Ok(ShieldedEventPage {
+			events,
+			next_block: (number <= last).then_some(number),
+		})
+	}
+
+	fn transfer_validation(
+		&self,
+		ext: &Extensions,
+		proof_hex: String,
+		merkle_root_hex: String,
+		nullifiers_hex: Vec<String>,
+		commitments_hex: Vec<String>,
+		at: Option<B::Hash>,
+	) -> RpcResult<TransferValidationInfo> {
+		self.charge_heavy(ext)?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.ensure_pool_api_v2(at)?;
+
+		let proof = parse_bytes(&proof_hex)?;
+		let merkle_root = parse_commitment(&merkle_root_hex)?;
+		let nullifiers = nullifiers_hex
+			.iter()
+			.map(|nullifier| parse_commitment(nullifier))
+			.collect::<RpcResult<Vec<_>>>()?;
+		let commitments = commitments_hex
+			.iter()
+			.map(|commitment| parse_commitment(commitment))
+			.collect::<RpcResult<Vec<_>>>()?;
+
+		let validation = self
+			.client
+			.runtime_api()
+			.validate_transfer(at, proof, merkle_root, nullifiers, commitments)
+			.map_err(|e| rpc_error(format!("Runtime error: {e}")))?;
+
+		Ok(map_transfer_validation(validation))
+	}
+}
+
+impl<C, B, BE, R> ShieldedPoolApiServer<B::Hash> for ShieldedPool<C, B, BE, R>
+where
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + StorageProvider<B, BE> + Send + Sync + 'static,
+	C::Api: ShieldedPoolRuntimeApi<B>
+		+ ShieldedPoolAuditApi<B, R::AccountId, BalanceOf<R>, BlockNumberFor<R>>,
+	B: BlockT,
+	BE: Backend<B> + Send + Sync + 'static,
+	R: pallet_shielded_pool::Config + Send + Sync + 'static,
+	<R as frame_system::Config>::RuntimeEvent: TryInto<PoolEvent<R>>,
+{
+	fn get_merkle_tree_info(&self, at: Option<B::Hash>) -> RpcResult<MerkleTreeInfo> {
+		self.serve("orbinum_v2_getMerkleTreeInfo", || self.merkle_tree_info(at))
+	}
+
+	fn get_merkle_tree_info_deprecated(&self, at: Option<B::Hash>) -> RpcResult<MerkleTreeInfo> {
+		self.deprecations.record(
+			"shieldedPool_getMerkleTreeInfo",
+			"orbinum_v2_getMerkleTreeInfo",
+		);
+		self.serve("shieldedPool_getMerkleTreeInfo", || {
+			self.merkle_tree_info(at)
+		})
+	}
+
+	fn get_merkle_proof(
+		&self,
+		ext: &Extensions,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<MerkleProof> {
+		self.serve("orbinum_v2_getMerkleProof", || {
+			self.merkle_proof(ext, commitment_hex, at)
+		})
+	}
+
+	fn get_merkle_proof_deprecated(
+		&self,
+		ext: &Extensions,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<MerkleProof> {
+		self.deprecations
+			.record("shieldedPool_getMerkleProof", "orbinum_v2_getMerkleProof");
+		self.serve("shieldedPool_getMerkleProof", || {
+			self.merkle_proof(ext, commitment_hex, at)
+		})
+	}
+
+	fn get_spend_witness(
+		&self,
+		ext: &Extensions,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<SpendWitness> {
+		self.serve("orbinum_v2_getSpendWitness", || {
+			self.spend_witness(ext, commitment_hex, at)
+		})
+	}
+
+	fn get_commitment_index(
+		&self,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<u32>> {
+		self.serve("orbinum_v2_getCommitmentIndex", || {
+			self.commitment_index(commitment_hex, at)
+		})
+	}
+
+	fn get_commitment_index_deprecated(
+		&self,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<u32>> {
+		self.deprecations.record(
+			"shieldedPool_getCommitmentIndex",
+			"orbinum_v2_getCommitmentIndex",
+		);
+		self.serve("shieldedPool_getCommitmentIndex", || {
+			self.commitment_index(commitment_hex, at)
+		})
+	}
+
+	fn get_memo(
+		&self,
+		ext: &Extensions,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<String>> {
+		self.serve("orbinum_v2_getMemo", || {
+			self.encrypted_memo(ext, commitment_hex, at)
+		})
+	}
+
+	fn get_memo_deprecated(
+		&self,
+		ext: &Extensions,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<String>> {
+		self.deprecations
+			.record("shieldedPool_getMemo", "orbinum_v2_getMemo");
+		self.serve("shieldedPool_getMemo", || {
+			self.encrypted_memo(ext, commitment_hex, at)
+		})
+	}
+
+	fn get_memos(
+		&self,
+		ext: &Extensions,
+		commitments: Vec<String>,
+		at: Option<B::Hash>,
+	) -> RpcResult<Vec<Option<String>>> {
+		self.serve("orbinum_v2_getMemos", || {
+			self.encrypted_memos(ext, commitments, at)
+		})
+	}
+
+	fn get_memos_deprecated(
+		&self,
+		ext: &Extensions,
+		commitments: Vec<String>,
+		at: Option<B::Hash>,
+	) -> RpcResult<Vec<Option<String>>> {
+		self.deprecations
+			.record("shieldedPool_getMemos", "orbinum_v2_getMemos");
+		self.serve("shieldedPool_getMemos", || {
+			self.encrypted_memos(ext, commitments, at)
+		})
+	}
+
+	fn are_nullifiers_spent(
+		&self,
+		ext: &Extensions,
+		nullifiers: Vec<String>,
+		at: Option<B::Hash>,
+	) -> RpcResult<NullifierBatchStatus> {
+		self.serve("orbinum_v2_areNullifiersSpent", || {
+			self.nullifier_statuses(ext, nullifiers, at)
+		})
+	}
+
+	fn get_audit_policy(
+		&self,
+		account_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<AuditPolicyInfo>> {
+		self.serve("orbinum_v2_getAuditPolicy", || {
+			self.audit_policy(account_hex, at)
+		})
+	}
+
+	fn get_audit_policy_deprecated(
+		&self,
+		account_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<AuditPolicyInfo>> {
+		self.deprecations
+			.record("shieldedPool_getAuditPolicy", "orbinum_v2_getAuditPolicy");
+		self.serve("shieldedPool_getAuditPolicy", || {
+			self.audit_policy(account_hex, at)
+		})
+	}
+
+	fn get_disclosure_requests(
+		&self,
+		target_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Vec<DisclosureRequestInfo>> {
+		self.serve("orbinum_v2_getDisclosureRequests", || {
+			self.disclosure_requests(target_hex, at)
+		})
+	}
+
+	fn get_disclosure_requests_deprecated(
+		&self,
+		target_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Vec<DisclosureRequestInfo>> {
+		self.deprecations.record(
+			"shieldedPool_getDisclosureRequests",
+			"orbinum_v2_getDisclosureRequests",
+		);
+		self.serve("shieldedPool_getDisclosureRequests", || {
+			self.disclosure_requests(target_hex, at)
+		})
+	}
+
+	fn get_pending_disclosures(
+		&self,
+		account_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Vec<PendingDisclosureInfo>> {
+		self.serve("orbinum_v2_getPendingDisclosures", || {
+			self.pending_disclosures(account_hex, at)
+		})
+	}
+
+	fn get_pending_disclosures_deprecated(
+		&self,
+		account_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Vec<PendingDisclosureInfo>> {
+		self.deprecations.record(
+			"shieldedPool_getPendingDisclosures",
+			"orbinum_v2_getPendingDisclosures",
+		);
+		self.serve("shieldedPool_getPendingDisclosures", || {
+			self.pending_disclosures(account_hex, at)
+		})
+	}
+
+	fn get_disclosure_proof(
+		&self,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<DisclosureProofInfo>> {
+		self.serve("orbinum_v2_getDisclosureProof", || {
+			self.disclosure_proof(commitment_hex, at)
+		})
+	}
+
+	fn get_disclosure_proof_deprecated(
+		&self,
+		commitment_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<DisclosureProofInfo>> {
+		self.deprecations.record(
+			"shieldedPool_getDisclosureProof",
+			"orbinum_v2_getDisclosureProof",
+		);
+		self.serve("shieldedPool_getDisclosureProof", || {
+			self.disclosure_proof(commitment_hex, at)
+		})
+	}
+
+	fn get_audit_trail(
+		&self,
+		trail_hash_hex: String,
+		at: Option<B::Hash>,
+	) -> RpcResult<Option<AuditTrailInfo>> {
+		self.serve("orbinum_v2_getAuditTrail", || {
+			self.audit_trail(trail_hash_hex, at)
+		})
+	}
+
+	fn list_assets(&self, at: Option<B::Hash>) -> RpcResult<Vec<AssetInfo>> {
+		self.serve("orbinum_v2_listAssets", || self.assets(at))
+	}
+
+	fn list_assets_deprecated(&self, at: Option<B::Hash>) -> RpcResult<Vec<AssetInfo>> {
+		self.deprecations
+			.record("shieldedPool_listAssets", "orbinum_v2_listAssets");
+		self.serve("shieldedPool_listAssets", || self.assets(at))
+	}
+
+	fn get_historic_roots(&self, at: Option<B::Hash>) -> RpcResult<Vec<HistoricRoot>> {
+		self.serve("orbinum_v2_getHistoricRoots", || self.historic_roots(at))
+	}
+
+	fn get_historic_roots_deprecated(&self, at: Option<B::Hash>) -> RpcResult<Vec<HistoricRoot>> {
+		self.deprecations.record(
+			"shieldedPool_getHistoricRoots",
+			"orbinum_v2_getHistoricRoots",
+		);
+		self.serve("shieldedPool_getHistoricRoots", || self.historic_roots(at))
+	}
+
+	fn get_memos_range(
+		&self,
+		ext: &Extensions,
+		start_leaf: u32,
+		count: u32,
+		at: Option<B::Hash>,
+	) -> RpcResult<MemoPage> {
+		self.serve("orbinum_v2_getMemosRange", || {
+			self.memo_page(ext, start_leaf, count, at)
+		})
+	}
+
+	fn get_memos_range_deprecated(
+		&self,
+		ext: &Extensions,
+		start_leaf: u32,
+		count: u32,
+		at: Option<B::Hash>,
+	) -> RpcResult<MemoPage> {
+		self.deprecations
+			.record("shieldedPool_getMemosRange", "orbinum_v2_getMemosRange");
+		self.serve("shieldedPool_getMemosRange", || {
+			self.memo_page(ext, start_leaf, count, at)
+		})
+	}
+
+	fn get_leaves(
+		&self,
+		ext: &Extensions,
+		start_leaf: u32,
+		count: u32,
+		at: Option<B::Hash>,
+	) -> RpcResult<LeafPage> {
+		self.serve("orbinum_v2_getLeaves", || {
+			self.leaf_page(ext, start_leaf, count, at)
+		})
+	}
+
+	fn get_leaves_deprecated(
+		&self,
+		ext: &Extensions,
+		start_leaf: u32,
+		count: u32,
+		at: Option<B::Hash>,
+	) -> RpcResult<LeafPage> {
+		self.deprecations
+			.record("shieldedPool_getLeaves", "orbinum_v2_getLeaves");
+		self.serve("shieldedPool_getLeaves", || {
+			self.leaf_page(ext, start_leaf, count, at)
+		})
+	}
+
+	fn export_tree_snapshot(
+		&self,
+		ext: &Extensions,
+		at: Option<B::Hash>,
+		start_leaf: Option<u32>,
+	) -> RpcResult<TreeSnapshotChunk<B::Hash>> {
+		self.serve("orbinum_v2_exportTreeSnapshot", || {
+			self.tree_snapshot(ext, at, start_leaf)
+		})
+	}
+
+	fn export_tree_snapshot_deprecated(
+		&self,
+		ext: &Extensions,
+		at: Option<B::Hash>,
+		start_leaf: Option<u32>,
+	) -> RpcResult<TreeSnapshotChunk<B::Hash>> {
+		self.deprecations.record(
+			"shieldedPool_exportTreeSnapshot",
+			"orbinum_v2_exportTreeSnapshot",
+		);
+		self.serve("shieldedPool_exportTreeSnapshot", || {
+			self.tree_snapshot(ext, at, start_leaf)
+		})
+	}
+
+	fn get_privacy_metrics(
+		&self,
+		ext: &Extensions,
+		at: Option<B::Hash>,
+	) -> RpcResult<PrivacyMetrics> {
+		self.serve("orbinum_v2_getPrivacyMetrics", || {
+			self.privacy_metrics(ext, at)
+		})
+	}
+
+	fn get_privacy_metrics_deprecated(
+		&self,
+		ext: &Extensions,
+		at: Option<B::Hash>,
+	) -> RpcResult<PrivacyMetrics> {
+		self.deprecations.record(
+			"shieldedPool_getPrivacyMetrics",
+			"orbinum_v2_getPrivacyMetrics",
+		);
+		self.serve("shieldedPool_getPrivacyMetrics", || {
+			self.privacy_metrics(ext, at)
+		})
+	}
+
+	fn get_compact_blocks(
+		&self,
+		ext: &Extensions,
+		from_block: u64,
+		to_block: u64,
+	) -> RpcResult<CompactBlockPage<B::Hash>> {
+		self.serve("orbinum_v2_getCompactBlocks", || {
+			self.compact_blocks(ext, from_block, to_block)
+		})
+	}
+
+	fn get_compact_blocks_deprecated(
+		&self,
+		ext: &Extensions,
+		from_block: u64,
+		to_block: u64,
+	) -> RpcResult<CompactBlockPage<B::Hash>> {
+		self.deprecations.record(
+			"shieldedPool_getCompactBlocks",
+			"orbinum_v2_getCompactBlocks",
+		);
+		self.serve("shieldedPool_getCompactBlocks", || {
+			self.compact_blocks(ext, from_block, to_block)
+		})
+	}
+
+	fn scan_events(
+		&self,
+		ext: &Extensions,
+		from_block: u64,
+		to_block: u64,
+		limit: Option<u32>,
+	) -> RpcResult<ShieldedEventPage> {
+		self.serve("orbinum_v2_scanEvents", || {
+			self.event_page(ext, from_block, to_block, limit)
+		})
+	}
+
+	fn scan_events_deprecated(
+		&self,
+		ext: &Extensions,
+		from_block: u64,
+		to_block: u64,
+		limit: Option<u32>,
+	) -> RpcResult<Vec<ShieldedEvent>> {
+		self.deprecations
+			.record("shieldedPool_scanEvents", "orbinum_v2_scanEvents");
+		self.serve("shieldedPool_scanEvents", || {
+			self.event_page(ext, from_block, to_block, limit)
+				.map(|page| page.events)
+		})
+	}
+
+	fn validate_transfer(
+		&self,
+		ext: &Extensions,
+		proof_hex: String,
+		merkle_root_hex: String,
+		nullifiers_hex: Vec<String>,
+		commitments_hex: Vec<String>,
+		at: Option<B::Hash>,
+	) -> RpcResult<TransferValidationInfo> {
+		self.serve("orbinum_v2_validateTransfer", || {
+			self.transfer_validation(
+				ext,
+				proof_hex,
+				merkle_root_hex,
+				nullifiers_hex,
+				commitments_hex,
+				at,
+			)
+		})
+	}
+
+	fn validate_transfer_deprecated(
+		&self,
+		ext: &Extensions,
+		proof_hex: String,
+		merkle_root_hex: String,
+		nullifiers_hex: Vec<String>,
+		commitments_hex: Vec<String>,
+		at: Option<B::Hash>,
+	) -> RpcResult<TransferValidationInfo> {
+		self.deprecations.record(
+			"shieldedPool_validateTransfer",
+			"orbinum_v2_validateTransfer",
+		);
+		self.serve("shieldedPool_validateTransfer", || {
+			self.transfer_validation(
+				ext,
+				proof_hex,
+				merkle_root_hex,
+				nullifiers_hex,
+				commitments_hex,
+				at,
+			)
+		})
 	}
 }
@@ -1,8 +1,17 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use pallet_shielded_pool::{DefaultMerklePath, Hash};
+use pallet_shielded_pool::{
+	AuditPolicy, AuditTrail, DefaultMerklePath, DisclosureProof, DisclosureRequest,
+	EncodedAssetMetadata, Hash, PoolStats, ProofJob, TransferValidation,
+};
+use sp_runtime::traits::Block as BlockT;
+
+/// Version of `ShieldedPoolRuntimeApi` adding every query past the Merkle
+/// proof ones; callers reading older blocks check for it first
+pub const SHIELDED_POOL_API_V2: u32 = 2;
 
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait ShieldedPoolRuntimeApi {
 		/// Get the Merkle tree information (root, size, depth)
 		fn get_merkle_tree_info() -> (Hash, u32, u32);
@@ -10,8 +19,101 @@ sp_api::decl_runtime_apis! {
 		/// Get the Merkle proof for a given leaf index
 		fn get_merkle_proof(leaf_index: u32) -> Option<DefaultMerklePath>;
 
-		/// Get the Merkle proof for a given commitment
-		/// (This requires scanning the leaves in the runtime, which is expensive but convenient)
+		/// Get the Merkle proof for a given commitment, located through the
+		/// commitment index
 		fn get_merkle_proof_for_commitment(commitment: Hash) -> Option<(u32, DefaultMerklePath)>;
+
+		/// Get the leaf index of a commitment (None if not in the tree)
+		#[api_version(2)]
+		fn get_commitment_index(commitment: Hash) -> Option<u32>;
+
+		/// Get the encrypted memo of a commitment (None if no memo is stored)
+		#[api_version(2)]
+		fn get_encrypted_memo(commitment: Hash) -> Option<sp_std::vec::Vec<u8>>;
+
+		/// Get the commitments of leaves `start_leaf..start_leaf + count`
+		#[api_version(2)]
+		fn get_leaves(start_leaf: u32, count: u32) -> sp_std::vec::Vec<Hash>;
+
+		/// Get the rightmost node of each Merkle tree level, leaf level first
+		#[api_version(2)]
+		fn get_tree_frontier() -> sp_std::vec::Vec<Hash>;
+
+		/// Get (leaf_index, commitment, memo) for leaves `start_leaf..start_leaf + count`
+		#[api_version(2)]
+		fn get_memos_range(
+			start_leaf: u32,
+			count: u32,
+		) -> sp_std::vec::Vec<(u32, Hash, Option<sp_std::vec::Vec<u8>>)>;
+
+		/// Get the historic roots window as (root, block number), oldest first
+		#[api_version(2)]
+		fn get_historic_roots() -> sp_std::vec::Vec<(Hash, u64)>;
+
+		/// List registered assets as (asset_id, metadata), ordered by asset_id
+		#[api_version(2)]
+		fn list_assets() -> sp_std::vec::Vec<(u32, EncodedAssetMetadata)>;
+
+		/// Get pool statistics, with the balance scoped to `asset_id` if given
+		#[api_version(2)]
+		fn get_pool_stats(asset_id: Option<u32>) -> PoolStats;
+
+		/// Get the block in which a nullifier was spent (None if unspent)
+		#[api_version(2)]
+		fn get_nullifier_spent_block(nullifier: Hash) -> Option<u64>;
+
+		/// Get the nullifiers spent in the block this API is called at
+		#[api_version(2)]
+		fn get_spent_nullifiers() -> sp_std::vec::Vec<Hash>;
+
+		/// Get the number of nullifiers spent so far
+		#[api_version(2)]
+		fn get_spent_nullifier_count() -> u32;
+
+		/// Dry-run the checks of `private_transfer` (root, nullifiers, proof) on the given inputs
+		#[api_version(2)]
+		fn validate_transfer(
+			proof: sp_std::vec::Vec<u8>,
+			merkle_root: Hash,
+			nullifiers: sp_std::vec::Vec<Hash>,
+			commitments: sp_std::vec::Vec<Hash>,
+		) -> TransferValidation;
+
+		/// Get the Groth16 checks the shielded calls of `extrinsics` will perform
+		///
+		/// Called at the parent of the block containing `extrinsics`, so the
+		/// node can verify their proofs in parallel before executing the block.
+		#[api_version(2)]
+		fn get_proof_jobs(
+			extrinsics: sp_std::vec::Vec<<Block as BlockT>::Extrinsic>,
+		) -> sp_std::vec::Vec<ProofJob>;
+	}
+
+	/// Audit policy and selective-disclosure queries
+	pub trait ShieldedPoolAuditApi<AccountId, Balance, BlockNumber>
+	where
+		AccountId: parity_scale_codec::Codec,
+		Balance: parity_scale_codec::Codec,
+		BlockNumber: parity_scale_codec::Codec,
+	{
+		/// Get the audit policy of an account (None if it has none)
+		fn get_audit_policy(account: AccountId) -> Option<AuditPolicy<AccountId, Balance, BlockNumber>>;
+
+		/// Get the pending disclosure requests targeting an account
+		fn get_disclosure_requests(
+			target: AccountId,
+		) -> sp_std::vec::Vec<DisclosureRequest<AccountId, BlockNumber>>;
+
+		/// Get the approved disclosure proof of a commitment (None if not disclosed)
+		fn get_disclosure_proof(commitment: Hash) -> Option<DisclosureProof>;
+
+		/// Get the audit trail entry recorded under a trail hash (None if unknown)
+		fn get_audit_trail(trail_hash: Hash) -> Option<AuditTrail<AccountId, BlockNumber>>;
+
+		/// Get the unexpired disclosure requests targeting an account, each
+		/// with the last block it can be approved in
+		fn get_pending_disclosures(
+			target: AccountId,
+		) -> sp_std::vec::Vec<(DisclosureRequest<AccountId, BlockNumber>, BlockNumber)>;
 	}
 }
@@ -2,7 +2,8 @@
 
 use crate::{
 	domain::entities::AssetMetadata,
-	pallet::{Assets, Config, Error, Event, NextAssetId, Pallet},
+	infrastructure::frame_types::EncryptedMemo,
+	pallet::{Assets, Config, Error, Event, MemoEscrowRequired, NextAssetId, Pallet},
 };
 use frame_support::{BoundedVec, pallet_prelude::*};
 use sp_runtime::traits::AccountIdConversion;
@@ -70,4 +71,31 @@ impl AssetService {
 
 		Ok(())
 	}
+
+	/// Set whether an asset requires escrow-mode memos
+	pub fn set_memo_escrow_required<T: Config>(asset_id: u32, required: bool) -> DispatchResult {
+		ensure!(
+			Assets::<T>::contains_key(asset_id),
+			Error::<T>::InvalidAssetId
+		);
+
+		MemoEscrowRequired::<T>::insert(asset_id, required);
+
+		Pallet::<T>::deposit_event(Event::MemoEscrowRequirementSet { asset_id, required });
+
+		Ok(())
+	}
+
+	/// Validate a memo against the asset's memo policy
+	///
+	/// Memos must be well formed; when the asset requires escrow they must
+	/// also declare an escrow slot.
+	pub fn ensure_memo_policy<T: Config>(asset_id: u32, memo: &EncryptedMemo) -> DispatchResult {
+		ensure!(memo.is_well_formed(), Error::<T>::InvalidMemoSize);
+		ensure!(
+			!MemoEscrowRequired::<T>::get(asset_id) || memo.has_escrow_slot(),
+			Error::<T>::MemoEscrowRequired
+		);
+		Ok(())
+	}
 }
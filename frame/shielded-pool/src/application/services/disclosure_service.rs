@@ -154,15 +154,19 @@ impl DisclosureService {
 		zk_proof: BoundedVec<u8, ConstU32<2048>>,
 		disclosed_data: BoundedVec<u8, ConstU32<512>>,
 	) -> DispatchResult {
-		// Check that disclosure request exists
-		let _request = DisclosureRequests::<T>::get(target, auditor)
+		// Check that disclosure request exists and is still open
+		let request = DisclosureRequests::<T>::get(target, auditor)
 			.ok_or(Error::<T>::DisclosureRequestNotFound)?;
+		let current_block = frame_system::Pallet::<T>::block_number();
+		ensure!(
+			current_block <= request.expires_at(T::DisclosureRequestExpiration::get()),
+			Error::<T>::DisclosureRequestExpired
+		);
 
 		// Get audit policy
 		let policy = AuditPolicies::<T>::get(target).ok_or(Error::<T>::AuditPolicyNotFound)?;
 
 		// Verify disclosure conditions are met
-		let current_block = frame_system::Pallet::<T>::block_number();
 		let conditions_met = policy.conditions.iter().any(|condition| match condition {
 			DisclosureCondition::Always => true,
 			DisclosureCondition::TimeDelay { after_block } => current_block >= *after_block,
@@ -172,7 +176,7 @@ impl DisclosureService {
 			DisclosureCondition::JudicialOrder {
 				court_id: _,
 				case_id: _,
-			} => _request.evidence.is_some(),
+			} => request.evidence.is_some(),
 			DisclosureCondition::Custom {
 				condition_id: _,
 				params: _,
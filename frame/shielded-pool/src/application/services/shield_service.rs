@@ -8,9 +8,9 @@ use frame_support::{
 };
 
 use crate::{
-	application::DepositInfo,
+	application::{DepositInfo, services::asset_service::AssetService},
 	domain::Commitment,
-	infrastructure::frame_types::{EncryptedMemo, MAX_ENCRYPTED_MEMO_SIZE},
+	infrastructure::frame_types::EncryptedMemo,
 	pallet::{
 		Assets, CommitmentMemos, Config, Deposits, Error, Event, MerkleTreeSize, Pallet,
 		PoolBalance, PoolBalancePerAsset,
@@ -26,7 +26,7 @@ impl ShieldService {
 	/// # Process
 	/// 1. Validate asset exists and is verified
 	/// 2. Validate amount meets minimum threshold
-	/// 3. Validate memo size
+	/// 3. Validate memo structure against the asset's memo policy
 	/// 4. Check tree capacity
 	/// 5. Transfer tokens to pool
 	/// 6. Insert commitment into tree
@@ -51,11 +51,8 @@ impl ShieldService {
 			Error::<T>::AmountTooSmall
 		);
 
-		// 3. Validate memo size
-		ensure!(
-			encrypted_memo.0.len() == MAX_ENCRYPTED_MEMO_SIZE as usize,
-			Error::<T>::InvalidMemoSize
-		);
+		// 3. Validate memo structure (escrow slot when the asset requires it)
+		AssetService::ensure_memo_policy::<T>(asset_id, &encrypted_memo)?;
 
 		// 4. Check tree capacity
 		let current_size = MerkleTreeSize::<T>::get();
@@ -101,6 +98,7 @@ impl ShieldService {
 		// 10. Emit event
 		Pallet::<T>::deposit_event(Event::Shielded {
 			depositor,
+			asset_id,
 			amount,
 			commitment,
 			encrypted_memo,
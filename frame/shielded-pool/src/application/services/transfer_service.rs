@@ -1,17 +1,24 @@
 //! Transfer service - Handles private transfer logic
 
 use crate::{
-	domain::{Commitment, Nullifier},
+	domain::{
+		Commitment, Nullifier,
+		value_objects::{ProofCheck, TransferValidation},
+	},
 	infrastructure::{
-		frame_types::{EncryptedMemo, MAX_ENCRYPTED_MEMO_SIZE},
-		repositories::MerkleRepository,
+		frame_types::EncryptedMemo,
+		repositories::{MerkleRepository, NullifierRepository},
 	},
 	pallet::{CommitmentMemos, Config, Error, Event, NullifierSet, Pallet},
 };
 use frame_support::{BoundedVec, pallet_prelude::*};
 use frame_system;
-#[cfg(not(feature = "runtime-benchmarks"))]
-use pallet_zk_verifier::ZkVerifierPort;
+use pallet_zk_verifier::{ProofJob, ZkVerifierPort};
+
+/// Maximum proof size accepted by `private_transfer`
+const MAX_PROOF_SIZE: u32 = 512;
+/// Maximum nullifiers (and commitments) of one `private_transfer`
+const MAX_TRANSFER_IO: u32 = 2;
 
 pub struct TransferService;
 
@@ -30,12 +37,9 @@ impl TransferService {
 			Error::<T>::MemoCommitmentMismatch
 		);
 
-		// 2. Validate all memo sizes
+		// 2. Validate all memo structures
 		for memo in encrypted_memos.iter() {
-			ensure!(
-				memo.0.len() == MAX_ENCRYPTED_MEMO_SIZE as usize,
-				Error::<T>::InvalidMemoSize
-			);
+			ensure!(memo.is_well_formed(), Error::<T>::InvalidMemoSize);
 		}
 
 		// 3. Verify Merkle root is known (Poseidon only)
@@ -84,7 +88,7 @@ impl TransferService {
 		// 7. Mark nullifiers as used
 		let current_block = frame_system::Pallet::<T>::block_number();
 		for nullifier in nullifiers.iter() {
-			NullifierSet::<T>::insert(nullifier, current_block);
+			NullifierRepository::mark_as_used::<T>(*nullifier, current_block);
 		}
 
 		// 8. Add new commitments to tree and store memos
@@ -107,4 +111,95 @@ impl TransferService {
 
 		Ok(())
 	}
+
+	/// Dry-run the checks of `execute` without writing storage
+	///
+	/// Runs the root, nullifier and proof checks on the same inputs as the
+	/// extrinsic and reports every failure instead of stopping at the first.
+	/// The proof is not verified when the inputs exceed the call's bounds.
+	pub fn validate<T: Config>(
+		proof: &[u8],
+		merkle_root: [u8; 32],
+		nullifiers: &[Nullifier],
+		commitments: &[Commitment],
+	) -> TransferValidation {
+		let within_bounds = proof.len() <= MAX_PROOF_SIZE as usize
+			&& nullifiers.len() <= MAX_TRANSFER_IO as usize
+			&& commitments.len() <= MAX_TRANSFER_IO as usize;
+
+		let root_known = MerkleRepository::is_known_root::<T>(&merkle_root);
+
+		let spent_nullifiers = nullifiers
+			.iter()
+			.filter(|nullifier| NullifierSet::<T>::contains_key(*nullifier))
+			.copied()
+			.collect();
+
+		let proof = if within_bounds {
+			Self::check_proof::<T>(proof, &merkle_root, nullifiers, commitments)
+		} else {
+			ProofCheck::NotChecked
+		};
+
+		TransferValidation {
+			within_bounds,
+			root_known,
+			spent_nullifiers,
+			proof,
+		}
+	}
+
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	fn check_proof<T: Config>(
+		proof: &[u8],
+		merkle_root: &[u8; 32],
+		nullifiers: &[Nullifier],
+		commitments: &[Commitment],
+	) -> ProofCheck {
+		let nullifier_arrays: sp_std::vec::Vec<[u8; 32]> = nullifiers.iter().map(|n| n.0).collect();
+		let commitment_arrays: sp_std::vec::Vec<[u8; 32]> =
+			commitments.iter().map(|c| c.0).collect();
+
+		match T::ZkVerifier::verify_transfer_proof(
+			proof,
+			merkle_root,
+			&nullifier_arrays,
+			&commitment_arrays,
+			None, // Use active version
+		) {
+			Ok(true) => ProofCheck::Valid,
+			Ok(false) => ProofCheck::Invalid,
+			Err(_) => ProofCheck::VerificationFailed,
+		}
+	}
+
+	/// Proof verification is skipped in benchmarking mode, as in `execute`
+	#[cfg(feature = "runtime-benchmarks")]
+	fn check_proof<T: Config>(
+		_proof: &[u8],
+		_merkle_root: &[u8; 32],
+		_nullifiers: &[Nullifier],
+		_commitments: &[Commitment],
+	) -> ProofCheck {
+		ProofCheck::NotChecked
+	}
+
+	/// Proof verification `execute` will perform, for pre-verification
+	///
+	/// `None` if no transfer key is active or the proof is oversized.
+	pub fn proof_job<T: Config>(
+		proof: &[u8],
+		merkle_root: &[u8; 32],
+		nullifiers: &[Nullifier],
+		commitments: &[Commitment],
+	) -> Option<ProofJob> {
+		if proof.len() > MAX_PROOF_SIZE as usize {
+			return None;
+		}
+		let nullifier_arrays: sp_std::vec::Vec<[u8; 32]> = nullifiers.iter().map(|n| n.0).collect();
+		let commitment_arrays: sp_std::vec::Vec<[u8; 32]> =
+			commitments.iter().map(|c| c.0).collect();
+
+		T::ZkVerifier::transfer_proof_job(proof, merkle_root, &nullifier_arrays, &commitment_arrays)
+	}
 }
@@ -2,7 +2,10 @@
 
 use crate::{
 	domain::entities::Nullifier,
-	infrastructure::repositories::MerkleRepository,
+	infrastructure::{
+		merkle_tree::hash_pair_poseidon,
+		repositories::{MerkleRepository, NullifierRepository},
+	},
 	pallet::{
 		Assets, Config, Error, Event, NullifierSet, Pallet, PoolBalance, PoolBalancePerAsset,
 	},
@@ -12,14 +15,63 @@ use frame_support::{
 	traits::{Currency, ExistenceRequirement},
 };
 use frame_system;
-#[cfg(not(feature = "runtime-benchmarks"))]
-use pallet_zk_verifier::ZkVerifierPort;
-#[cfg(not(feature = "runtime-benchmarks"))]
+use pallet_zk_verifier::{ProofJob, ZkVerifierPort};
 use parity_scale_codec::Encode;
 
 pub struct UnshieldService;
 
 impl UnshieldService {
+	/// Recipient binding committed to by the unshield proof
+	///
+	/// `Poseidon(recipient, nonce)` over LE field elements. The recipient is the
+	/// SCALE-encoded (big-endian) account, reversed into LE field encoding.
+	pub fn recipient_binding(recipient: &[u8; 32], nonce: &[u8; 32]) -> [u8; 32] {
+		let mut recipient_le = *recipient;
+		recipient_le.reverse();
+		hash_pair_poseidon(&recipient_le, nonce)
+	}
+
+	/// Account bytes and their binding to `nonce`, over the SCALE encoding
+	///
+	/// AccountId32 is encoded in SCALE as exactly 32 bytes. The verifier picks
+	/// which of the two the active unshield key takes as public input.
+	fn account_binding<T: Config>(
+		recipient: &<T as frame_system::Config>::AccountId,
+		nonce: &[u8; 32],
+	) -> ([u8; 32], [u8; 32]) {
+		let recipient_bytes: [u8; 32] = recipient.encode().try_into().unwrap_or([0u8; 32]);
+		(
+			recipient_bytes,
+			Self::recipient_binding(&recipient_bytes, nonce),
+		)
+	}
+
+	/// Proof verification `execute` will perform, for pre-verification
+	///
+	/// `None` if no unshield key is active or the amount does not fit a u128.
+	pub fn proof_job<T: Config>(
+		proof: &[u8],
+		merkle_root: &[u8; 32],
+		nullifier: &Nullifier,
+		asset_id: u32,
+		amount: <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance,
+		recipient: &<T as frame_system::Config>::AccountId,
+		recipient_nonce: &[u8; 32],
+	) -> Option<ProofJob> {
+		let amount_u128: u128 = amount.try_into().ok()?;
+		let (recipient_bytes, recipient_binding) =
+			Self::account_binding::<T>(recipient, recipient_nonce);
+		T::ZkVerifier::unshield_proof_job(
+			proof,
+			merkle_root,
+			&nullifier.0,
+			amount_u128,
+			&recipient_bytes,
+			&recipient_binding,
+			asset_id,
+		)
+	}
+
 	/// Execute unshield operation
 	pub fn execute<T: Config>(
 		_proof: &[u8],
@@ -28,6 +80,7 @@ impl UnshieldService {
 		asset_id: u32,
 		amount: <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance,
 		recipient: <T as frame_system::Config>::AccountId,
+		recipient_nonce: [u8; 32],
 	) -> DispatchResult {
 		// 1. Validate asset exists and is verified
 		let asset = Assets::<T>::get(asset_id).ok_or(Error::<T>::InvalidAssetId)?;
@@ -72,9 +125,8 @@ impl UnshieldService {
 		// Pass merkle_root/nullifier as-is (no endianness conversion).
 		#[cfg(not(feature = "runtime-benchmarks"))]
 		{
-			// AccountId32 is encoded in SCALE as exactly 32 bytes.
-			// Convert directly without truncation.
-			let recipient_bytes: [u8; 32] = recipient.encode().try_into().unwrap_or([0u8; 32]);
+			let (recipient_bytes, recipient_binding) =
+				Self::account_binding::<T>(&recipient, &recipient_nonce);
 
 			let valid = T::ZkVerifier::verify_unshield_proof(
 				_proof,
@@ -82,6 +134,7 @@ impl UnshieldService {
 				&nullifier.0,
 				amount_u128,
 				&recipient_bytes,
+				&recipient_binding,
 				asset_id,
 				None, // Use active version
 			)
@@ -92,7 +145,7 @@ impl UnshieldService {
 
 		// In benchmarking mode, suppress unused variable warning
 		#[cfg(feature = "runtime-benchmarks")]
-		let _ = amount_u128;
+		let _ = (amount_u128, recipient_nonce);
 
 		// 8. Transfer tokens from pool to recipient
 		T::Currency::transfer(
@@ -118,11 +171,12 @@ impl UnshieldService {
 
 		// 11. Mark nullifier as used to prevent double-spending
 		let current_block = frame_system::Pallet::<T>::block_number();
-		NullifierSet::<T>::insert(nullifier, current_block);
+		NullifierRepository::mark_as_used::<T>(nullifier, current_block);
 
 		// 12. Emit event
 		Pallet::<T>::deposit_event(Event::Unshielded {
 			nullifier,
+			asset_id,
 			amount,
 			recipient,
 		});
@@ -177,9 +177,14 @@ impl DisclosureUseCase {
 		zk_proof: BoundedVec<u8, ConstU32<2048>>,
 		disclosed_data: BoundedVec<u8, ConstU32<512>>,
 	) -> DispatchResult {
-		// Verify request exists
-		let _request = AuditRepository::get_disclosure_request::<T>(target, auditor)
+		// Verify request exists and has not expired
+		let request = AuditRepository::get_disclosure_request::<T>(target, auditor)
 			.ok_or(Error::<T>::DisclosureRequestNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number()
+				<= request.expires_at(T::DisclosureRequestExpiration::get()),
+			Error::<T>::DisclosureRequestExpired
+		);
 
 		// Get policy
 		let policy =
@@ -24,6 +24,7 @@ impl UnshieldUseCase {
 	/// * `asset_id` - Which asset to unshield
 	/// * `amount` - Amount to withdraw
 	/// * `recipient` - Public account to receive the tokens
+	/// * `recipient_nonce` - Nonce bound to the recipient by the proof
 	///
 	/// # Returns
 	/// Result with () on success
@@ -38,12 +39,21 @@ impl UnshieldUseCase {
 		asset_id: u32,
 		amount: BalanceOf<T>,
 		recipient: T::AccountId,
+		recipient_nonce: [u8; 32],
 	) -> DispatchResult {
 		// Validate inputs at use case level
 		Self::validate_inputs::<T>(&amount, &nullifier, &recipient)?;
 
 		// Delegate to unshield service
-		UnshieldService::execute::<T>(proof, merkle_root, nullifier, asset_id, amount, recipient)
+		UnshieldService::execute::<T>(
+			proof,
+			merkle_root,
+			nullifier,
+			asset_id,
+			amount,
+			recipient,
+			recipient_nonce,
+		)
 	}
 
 	/// Validate unshield inputs
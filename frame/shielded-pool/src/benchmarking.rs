@@ -7,7 +7,7 @@ use frame_benchmarking::v2::*;
 use frame_support::{
 	BoundedVec,
 	pallet_prelude::ConstU32,
-	traits::{Currency, Get},
+	traits::{Currency, EnsureOrigin, Get},
 };
 use frame_system::RawOrigin;
 use sp_runtime::traits::AccountIdConversion;
@@ -94,7 +94,7 @@ mod benchmarks {
 		let (caller, asset_id) = setup_benchmark_env::<T>();
 		let amount: BalanceOf<T> = T::MinShieldAmount::get() * 10u32.into();
 		let commitment = Commitment([1u8; 32]);
-		// Memo must be exactly 104 bytes (MAX_ENCRYPTED_MEMO_SIZE): nonce(12) + data(76) + MAC(16)
+		// Memo must be exactly 104 bytes (STANDARD_MEMO_SIZE): nonce(12) + data(76) + MAC(16)
 		let memo_bytes = vec![0u8; 104];
 		let encrypted_memo = FrameEncryptedMemo(memo_bytes.try_into().unwrap());
 
@@ -184,14 +184,19 @@ mod benchmarks {
 			asset_id,
 			amount,
 			recipient,
+			[3u8; 32],
 		);
 	}
 
 	#[benchmark]
-	fn set_disclosure_verifying_key() {
+	fn set_disclosure_verifying_key() -> Result<(), BenchmarkError> {
+		let origin = T::CircuitAdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
 		let vk: BoundedVec<u8, ConstU32<4096>> = vec![1u8; 3000].try_into().unwrap();
 		#[extrinsic_call]
-		set_disclosure_verifying_key(RawOrigin::Root, vk);
+		set_disclosure_verifying_key(origin as T::RuntimeOrigin, vk);
+
+		Ok(())
 	}
 
 	#[benchmark]
@@ -395,15 +400,21 @@ mod benchmarks {
 	// }
 
 	#[benchmark]
-	fn register_asset() {
+	fn register_asset() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AssetAdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		let name: BoundedVec<u8, ConstU32<64>> = vec![1u8; 32].try_into().unwrap();
 		let symbol: BoundedVec<u8, ConstU32<16>> = vec![1u8; 4].try_into().unwrap();
 		#[extrinsic_call]
-		register_asset(RawOrigin::Root, name, symbol, 18, None);
+		register_asset(origin as T::RuntimeOrigin, name, symbol, 18, None);
+
+		Ok(())
 	}
 
 	#[benchmark]
-	fn verify_asset() {
+	fn verify_asset() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AssetAdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		let name: BoundedVec<u8, ConstU32<64>> = vec![1u8; 32].try_into().unwrap();
 		let symbol: BoundedVec<u8, ConstU32<16>> = vec![1u8; 4].try_into().unwrap();
 		let asset_id = crate::application::services::asset_service::AssetService::register::<T>(
@@ -412,11 +423,15 @@ mod benchmarks {
 		.unwrap();
 
 		#[extrinsic_call]
-		verify_asset(RawOrigin::Root, asset_id);
+		verify_asset(origin as T::RuntimeOrigin, asset_id);
+
+		Ok(())
 	}
 
 	#[benchmark]
-	fn unverify_asset() {
+	fn unverify_asset() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AssetAdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 		let name: BoundedVec<u8, ConstU32<64>> = vec![1u8; 32].try_into().unwrap();
 		let symbol: BoundedVec<u8, ConstU32<16>> = vec![1u8; 4].try_into().unwrap();
 		let asset_id = crate::application::services::asset_service::AssetService::register::<T>(
@@ -426,7 +441,9 @@ mod benchmarks {
 		let _ = crate::application::services::asset_service::AssetService::verify::<T>(asset_id);
 
 		#[extrinsic_call]
-		unverify_asset(RawOrigin::Root, asset_id);
+		unverify_asset(origin as T::RuntimeOrigin, asset_id);
+
+		Ok(())
 	}
 
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
@@ -2,10 +2,11 @@
 //!
 //! Represents metadata for assets that can be used in the shielded pool.
 
+use alloc::vec::Vec;
 use frame_support::{BoundedVec, pallet_prelude::*};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{RuntimeDebug, traits::UniqueSaturatedInto};
 
 /// Asset metadata for multi-asset shielded pool
 ///
@@ -48,6 +49,12 @@ pub struct AssetMetadata<AccountId, BlockNumber> {
 	pub creator: AccountId,
 }
 
+/// Asset metadata as returned by the runtime API
+///
+/// The creator is SCALE-encoded and the block number widened to `u64`, so
+/// the runtime API does not depend on runtime-specific types.
+pub type EncodedAssetMetadata = AssetMetadata<Vec<u8>, u64>;
+
 impl<AccountId, BlockNumber> AssetMetadata<AccountId, BlockNumber> {
 	/// Create new asset metadata
 	pub fn new(
@@ -89,4 +96,22 @@ impl<AccountId, BlockNumber> AssetMetadata<AccountId, BlockNumber> {
 	pub fn set_contract_address(&mut self, address: [u8; 20]) {
 		self.contract_address = Some(address);
 	}
+
+	/// Convert to the runtime API representation
+	pub fn into_encoded(self) -> EncodedAssetMetadata
+	where
+		AccountId: Encode,
+		BlockNumber: UniqueSaturatedInto<u64>,
+	{
+		AssetMetadata {
+			id: self.id,
+			name: self.name,
+			symbol: self.symbol,
+			decimals: self.decimals,
+			is_verified: self.is_verified,
+			contract_address: self.contract_address,
+			created_at: self.created_at.unique_saturated_into(),
+			creator: self.creator.encode(),
+		}
+	}
 }
@@ -5,7 +5,7 @@
 use frame_support::{BoundedVec, pallet_prelude::*};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{RuntimeDebug, traits::Saturating};
 
 /// Disclosure request from an auditor
 ///
@@ -83,3 +83,13 @@ where
 		&self.requested_at
 	}
 }
+
+impl<AccountId, BlockNumber> DisclosureRequest<AccountId, BlockNumber>
+where
+	BlockNumber: Saturating + Copy,
+{
+	/// Last block the request can be approved in
+	pub fn expires_at(&self, expiration: BlockNumber) -> BlockNumber {
+		self.requested_at.saturating_add(expiration)
+	}
+}
@@ -8,7 +8,7 @@ pub mod commitment;
 pub mod note;
 pub mod nullifier;
 
-pub use asset_metadata::AssetMetadata;
+pub use asset_metadata::{AssetMetadata, EncodedAssetMetadata};
 pub use audit::{AuditPolicy, AuditTrail, DisclosureProof, DisclosureRequest};
 pub use commitment::Commitment;
 pub use note::Note;
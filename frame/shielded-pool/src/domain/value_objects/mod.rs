@@ -7,9 +7,13 @@ pub mod audit;
 pub mod encrypted_memo;
 pub mod hash;
 pub mod merkle_path;
+pub mod pool_stats;
+pub mod transfer_validation;
 
 pub use asset_id::AssetId;
 pub use audit::{Auditor, DisclosureCondition};
 pub use encrypted_memo::{EncryptedMemo, MAX_MEMO_SIZE, StandardEncryptedMemo};
 pub use hash::Hash;
 pub use merkle_path::{DEFAULT_TREE_DEPTH, DefaultMerklePath, MAX_TREE_DEPTH, MerklePath};
+pub use pool_stats::PoolStats;
+pub use transfer_validation::{ProofCheck, TransferValidation};
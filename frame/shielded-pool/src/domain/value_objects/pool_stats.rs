@@ -0,0 +1,31 @@
+//! PoolStats value object
+//!
+//! Snapshot of the shielded pool returned by the `get_pool_stats` runtime API.
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use super::hash::Hash;
+
+/// Shielded pool statistics at a given block
+///
+/// Commitments do not reveal their asset, so note counts and tree
+/// utilization are pool-wide; only the balance is scoped to `asset_id`.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug, Default)]
+pub struct PoolStats {
+	/// Asset the balance refers to (None = total pool balance)
+	pub asset_id: Option<u32>,
+	/// Pool balance in minimum units
+	pub balance: u128,
+	/// Current Poseidon Merkle root
+	pub merkle_root: Hash,
+	/// Number of notes (commitments) in the Merkle tree
+	pub commitment_count: u32,
+	/// Maximum depth of the Merkle tree
+	pub tree_depth: u32,
+	/// Number of roots currently accepted as historic
+	pub historic_roots: u32,
+	/// Maximum number of historic roots kept
+	pub max_historic_roots: u32,
+}
@@ -0,0 +1,50 @@
+//! TransferValidation value object
+//!
+//! Diagnostics of a `private_transfer` dry-run returned by the
+//! `validate_transfer` runtime API.
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::domain::Nullifier;
+
+/// Outcome of the proof check of a transfer dry-run
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum ProofCheck {
+	/// The proof verifies against the given public inputs
+	Valid,
+	/// The proof does not verify (`InvalidProof`)
+	Invalid,
+	/// The verifier could not run (`ProofVerificationFailed`), e.g. malformed
+	/// proof bytes or no active verification key
+	VerificationFailed,
+	/// Verification was not run: inputs out of bounds or benchmark build
+	NotChecked,
+}
+
+/// Checks `private_transfer` would run on the given inputs
+///
+/// Memos are not part of the dry-run: their shape is checked statelessly.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub struct TransferValidation {
+	/// Proof, nullifiers and commitments fit the call's bounds
+	pub within_bounds: bool,
+	/// The Merkle root is the current or a historic root
+	pub root_known: bool,
+	/// Nullifiers already in the nullifier set
+	pub spent_nullifiers: Vec<Nullifier>,
+	/// Outcome of the proof verification
+	pub proof: ProofCheck,
+}
+
+impl TransferValidation {
+	/// Whether `private_transfer` would pass every check
+	pub fn is_valid(&self) -> bool {
+		self.within_bounds
+			&& self.root_known
+			&& self.spent_nullifiers.is_empty()
+			&& self.proof == ProofCheck::Valid
+	}
+}
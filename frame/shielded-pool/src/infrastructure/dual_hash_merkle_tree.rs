@@ -0,0 +1,297 @@
+//! Dual-hash Merkle tree (`dual-hash-tree` feature)
+//!
+//! Experimental layout for the frontier redesign: the lowest `INNER` levels
+//! hash with Poseidon, so every subtree of `2^INNER` leaves has a
+//! circuit-visible Poseidon root, while the `OUTER` levels above the subtrees
+//! hash with Blake2-256, which is far cheaper to maintain on-chain.
+//!
+//! ```text
+//!                 Root                  Blake2 levels (OUTER)
+//!               /      \
+//!         S(0)   ...    S(k)            Poseidon subtree roots (circuit-visible)
+//!        /    \
+//!      L0 ... L(2^INNER - 1)            Poseidon levels (INNER)
+//! ```
+//!
+//! Circuits prove membership against a subtree root with an `INNER`-level
+//! Poseidon path; the outer Blake2 path ties that subtree root to the root.
+//! Because the inner levels are identical to a Poseidon-only tree, existing
+//! full-depth paths convert with [`inner_path_from_full`].
+
+use crate::{
+	domain::value_objects::{Hash, MerklePath},
+	infrastructure::merkle_tree::{get_zero_hash_cached, hash_pair},
+};
+use frame_support::pallet_prelude::*;
+use sp_std::vec::Vec;
+
+/// Hash two outer nodes using Blake2-256 over `left || right`
+pub fn hash_pair_blake2(left: &Hash, right: &Hash) -> Hash {
+	let mut input = [0u8; 64];
+	input[..32].copy_from_slice(left);
+	input[32..].copy_from_slice(right);
+	sp_io::hashing::blake2_256(&input)
+}
+
+/// Zero hash per outer level (`OUTER + 1` entries)
+///
+/// Level 0 is the root of an empty Poseidon subtree.
+pub fn outer_zero_hashes<const INNER: usize, const OUTER: usize>() -> Vec<Hash> {
+	let mut zeros = Vec::with_capacity(OUTER + 1);
+	zeros.push(get_zero_hash_cached(INNER));
+	for level in 0..OUTER {
+		zeros.push(hash_pair_blake2(&zeros[level], &zeros[level]));
+	}
+	zeros
+}
+
+/// Truncate a full-depth Poseidon path to its lowest `INNER` levels
+///
+/// The result is the leaf's path within its dual-hash subtree. Returns `None`
+/// if `INNER > DEPTH`.
+pub fn inner_path_from_full<const DEPTH: usize, const INNER: usize>(
+	path: &MerklePath<DEPTH>,
+) -> Option<MerklePath<INNER>> {
+	if INNER > DEPTH {
+		return None;
+	}
+
+	let mut inner = MerklePath::<INNER>::default();
+	inner.siblings.copy_from_slice(&path.siblings[..INNER]);
+	inner.indices.copy_from_slice(&path.indices[..INNER]);
+	Some(inner)
+}
+
+/// Fold `leaf` up `path` with `hash`
+fn fold_path<const DEPTH: usize>(
+	leaf: &Hash,
+	path: &MerklePath<DEPTH>,
+	hash: fn(&Hash, &Hash) -> Hash,
+) -> Hash {
+	path.siblings
+		.iter()
+		.zip(path.indices.iter())
+		.fold(*leaf, |current, (sibling, index)| {
+			if *index == 0 {
+				hash(&current, sibling)
+			} else {
+				hash(sibling, &current)
+			}
+		})
+}
+
+/// Root of a `DEPTH`-level tree over `nodes` (padded with `zeros`) and the
+/// path of `nodes[index]`
+fn build_path<const DEPTH: usize>(
+	mut nodes: Vec<Hash>,
+	mut index: usize,
+	zeros: impl Fn(usize) -> Hash,
+	hash: fn(&Hash, &Hash) -> Hash,
+) -> (Hash, MerklePath<DEPTH>) {
+	let mut path = MerklePath::<DEPTH>::default();
+
+	for level in 0..DEPTH {
+		let zero = zeros(level);
+		path.indices[level] = (index % 2) as u8;
+		path.siblings[level] = nodes.get(index ^ 1).copied().unwrap_or(zero);
+
+		nodes = nodes
+			.chunks(2)
+			.map(|pair| hash(&pair[0], pair.get(1).unwrap_or(&zero)))
+			.collect();
+		index /= 2;
+	}
+
+	(nodes.first().copied().unwrap_or_else(|| zeros(DEPTH)), path)
+}
+
+/// Incremental Merkle tree with Poseidon subtrees under a Blake2 top
+///
+/// Stores a frontier per hash, like `IncrementalMerkleTree`:
+/// O(`INNER + OUTER`) storage, `INNER` Poseidon and `OUTER` Blake2 hashes per
+/// insertion.
+#[derive(Clone, Encode, Decode, TypeInfo, MaxEncodedLen, Debug)]
+pub struct DualHashMerkleTree<const INNER: usize, const OUTER: usize> {
+	/// Rightmost Poseidon node at each inner level (frontier)
+	pub inner_frontier: [Hash; INNER],
+	/// Rightmost Blake2 node at each outer level (frontier)
+	pub outer_frontier: [Hash; OUTER],
+	/// Current number of leaves
+	pub next_index: u32,
+	/// Poseidon root of the subtree holding the latest leaf
+	pub subtree_root: Hash,
+	/// Blake2 root over all subtree roots
+	pub root: Hash,
+}
+
+impl<const INNER: usize, const OUTER: usize> Default for DualHashMerkleTree<INNER, OUTER> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const INNER: usize, const OUTER: usize> DualHashMerkleTree<INNER, OUTER> {
+	/// Create a new empty tree
+	pub fn new() -> Self {
+		Self {
+			inner_frontier: [[0u8; 32]; INNER],
+			outer_frontier: [[0u8; 32]; OUTER],
+			next_index: 0,
+			subtree_root: get_zero_hash_cached(INNER),
+			root: outer_zero_hashes::<INNER, OUTER>()[OUTER],
+		}
+	}
+
+	/// Rebuild the tree from the leaves of an existing tree, in insertion order
+	///
+	/// Used to migrate a Poseidon-only tree (e.g. from `MerkleLeaves`).
+	pub fn from_leaves(leaves: &[Hash]) -> Result<Self, &'static str> {
+		let mut tree = Self::new();
+		for leaf in leaves {
+			tree.insert(*leaf)?;
+		}
+		Ok(tree)
+	}
+
+	/// Maximum number of leaves
+	pub fn capacity(&self) -> u32 {
+		1u32 << (INNER + OUTER)
+	}
+
+	/// Number of leaves per Poseidon subtree
+	pub fn subtree_capacity(&self) -> u32 {
+		1u32 << INNER
+	}
+
+	/// Check if the tree is full
+	pub fn is_full(&self) -> bool {
+		self.next_index >= self.capacity()
+	}
+
+	/// Get the number of leaves in the tree
+	pub fn size(&self) -> u32 {
+		self.next_index
+	}
+
+	/// Get the current (Blake2) root
+	pub fn root(&self) -> Hash {
+		self.root
+	}
+
+	/// Get the Poseidon root of the subtree holding the latest leaf
+	pub fn subtree_root(&self) -> Hash {
+		self.subtree_root
+	}
+
+	/// Index of the subtree holding `leaf_index`
+	pub fn subtree_index(leaf_index: u32) -> u32 {
+		leaf_index >> INNER
+	}
+
+	/// Insert a new leaf and return its index
+	pub fn insert(&mut self, leaf: Hash) -> Result<u32, &'static str> {
+		if self.is_full() {
+			return Err("Merkle tree is full");
+		}
+
+		let index = self.next_index;
+		let mut current_hash = leaf;
+		let mut current_index = index;
+
+		// Poseidon levels: same frontier update as `IncrementalMerkleTree`
+		for level in 0..INNER {
+			if current_index % 2 == 0 {
+				self.inner_frontier[level] = current_hash;
+				current_hash = hash_pair(&current_hash, &get_zero_hash_cached(level));
+			} else {
+				current_hash = hash_pair(&self.inner_frontier[level], &current_hash);
+			}
+			current_index /= 2;
+		}
+		self.subtree_root = current_hash;
+
+		// Blake2 levels over subtree roots
+		let mut zero = get_zero_hash_cached(INNER);
+		for level in 0..OUTER {
+			if current_index % 2 == 0 {
+				self.outer_frontier[level] = current_hash;
+				current_hash = hash_pair_blake2(&current_hash, &zero);
+			} else {
+				current_hash = hash_pair_blake2(&self.outer_frontier[level], &current_hash);
+			}
+			zero = hash_pair_blake2(&zero, &zero);
+			current_index /= 2;
+		}
+
+		self.root = current_hash;
+		self.next_index += 1;
+
+		Ok(index)
+	}
+
+	/// Poseidon roots of every non-empty subtree
+	pub fn subtree_roots(leaves: &[Hash]) -> Vec<Hash> {
+		leaves
+			.chunks(1 << INNER)
+			.map(|subtree| {
+				build_path::<INNER>(subtree.to_vec(), 0, get_zero_hash_cached, hash_pair).0
+			})
+			.collect()
+	}
+
+	/// Generate the inner (Poseidon) and outer (Blake2) paths for a leaf
+	///
+	/// The inner path proves membership in the leaf's subtree root; the outer
+	/// path proves that subtree root under the tree root.
+	pub fn generate_proof(
+		&self,
+		leaf_index: u32,
+		leaves: &[Hash],
+	) -> Result<(MerklePath<INNER>, MerklePath<OUTER>), &'static str> {
+		if leaf_index >= self.next_index {
+			return Err("Leaf index out of bounds");
+		}
+		if leaves.len() != self.next_index as usize {
+			return Err("Leaves count mismatch");
+		}
+
+		let subtree_size = 1usize << INNER;
+		let subtree = Self::subtree_index(leaf_index) as usize;
+		let start = subtree * subtree_size;
+		let end = (start + subtree_size).min(leaves.len());
+
+		let (_, inner) = build_path::<INNER>(
+			leaves[start..end].to_vec(),
+			leaf_index as usize - start,
+			get_zero_hash_cached,
+			hash_pair,
+		);
+		let outer_zeros = outer_zero_hashes::<INNER, OUTER>();
+		let (_, outer) = build_path::<OUTER>(
+			Self::subtree_roots(leaves),
+			subtree,
+			|level| outer_zeros[level],
+			hash_pair_blake2,
+		);
+
+		Ok((inner, outer))
+	}
+
+	/// Poseidon subtree root reached from `leaf` along `inner`
+	///
+	/// This is the root a circuit proves membership against.
+	pub fn compute_subtree_root(leaf: &Hash, inner: &MerklePath<INNER>) -> Hash {
+		fold_path(leaf, inner, hash_pair)
+	}
+
+	/// Verify a leaf against the tree root
+	pub fn verify_proof(
+		root: &Hash,
+		leaf: &Hash,
+		inner: &MerklePath<INNER>,
+		outer: &MerklePath<OUTER>,
+	) -> bool {
+		let subtree_root = Self::compute_subtree_root(leaf, inner);
+		&fold_path(&subtree_root, outer, hash_pair_blake2) == root
+	}
+}
@@ -11,11 +11,52 @@ use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 use sp_std::vec::Vec;
 
-/// Maximum size for an encrypted memo (in bytes)
+/// Size of a standard encrypted memo (in bytes)
 ///
 /// Must match the output of ChaCha20Poly1305 encryption:
 /// `nonce(12) + note_data(76) + MAC(16) = 104`
-pub const MAX_ENCRYPTED_MEMO_SIZE: u32 = 104;
+pub const STANDARD_MEMO_SIZE: u32 = orbinum_encrypted_memo::MAX_ENCRYPTED_MEMO_SIZE as u32;
+
+/// Size of the escrow slot: the memo key wrapped to the auditor
+///
+/// `ephemeral_public(32) + wrapped_key(32) + MAC(16) = 80`
+pub const ESCROW_SLOT_SIZE: u32 = orbinum_encrypted_memo::ESCROWED_KEY_SIZE as u32;
+
+/// Header byte of escrow-mode memos (`orbinum-encrypted-memo` envelope version)
+pub const ESCROW_MEMO_VERSION: u8 = orbinum_encrypted_memo::MEMO_VERSION_ESCROW;
+
+/// Size of an escrow-mode memo (in bytes)
+///
+/// `version(1) + escrow_slot(80) + standard_memo(104) = 185`
+pub const ESCROW_MEMO_SIZE: u32 = orbinum_encrypted_memo::ENCRYPTED_MEMO_ESCROW_SIZE as u32;
+
+/// Header byte of off-chain memo pointers (`orbinum-encrypted-memo` envelope version)
+pub const MEMO_POINTER_VERSION: u8 = orbinum_encrypted_memo::MEMO_VERSION_POINTER;
+
+/// Size of an off-chain memo pointer (in bytes)
+///
+/// `version(1) + content_hash(32) = 33`; the ciphertext itself is distributed
+/// off-chain (IPFS, relayers) and checked against the hash by the recipient.
+pub const MEMO_POINTER_SIZE: u32 = orbinum_encrypted_memo::MEMO_POINTER_SIZE as u32;
+
+/// Header byte of compressed V2 memos (`orbinum-encrypted-memo` envelope version)
+pub const COMPRESSED_MEMO_VERSION: u8 =
+	orbinum_encrypted_memo::MEMO_VERSION_V2 | orbinum_encrypted_memo::MEMO_FLAG_COMPRESSED;
+
+/// Smallest compressed V2 memo (in bytes)
+///
+/// The compressed payload is zero-padded so the memo is never mistaken for a
+/// standard memo.
+pub const MIN_COMPRESSED_MEMO_SIZE: u32 = orbinum_encrypted_memo::MIN_COMPRESSED_MEMO_SIZE as u32;
+
+/// Largest compressed V2 memo (in bytes); longer payloads are sealed uncompressed
+pub const MAX_COMPRESSED_MEMO_SIZE: u32 = orbinum_encrypted_memo::MAX_COMPRESSED_MEMO_SIZE as u32;
+
+/// Maximum size for an encrypted memo (in bytes)
+///
+/// A tagged multi-recipient memo for the most recipients:
+/// `version(1) + detection_tag(34) + multi_recipient_memo(490) = 525`
+pub const MAX_ENCRYPTED_MEMO_SIZE: u32 = orbinum_encrypted_memo::MAX_ONCHAIN_MEMO_SIZE as u32;
 
 /// Encrypted memo for commitments (FRAME-specific type with ConstU32)
 ///
@@ -68,10 +109,45 @@ impl EncryptedMemo {
 
 	/// Create from bytes slice (for compatibility with tests)
 	pub fn from_bytes(bytes: &[u8]) -> Result<Self, &'static str> {
-		if bytes.len() != MAX_ENCRYPTED_MEMO_SIZE as usize {
+		let memo = Self::new(bytes.to_vec())?;
+		if !memo.is_well_formed() {
 			return Err("Invalid memo size");
 		}
-		Self::new(bytes.to_vec())
+		Ok(memo)
+	}
+
+	/// Check if the memo is an envelope the pool stores on-chain
+	///
+	/// Dispatches on the version byte against the `orbinum-encrypted-memo`
+	/// size table: standard 104-byte, V2, V3, ephemeral-key, escrow, XChaCha,
+	/// compressed, multi-recipient and tagged memos, or an off-chain pointer.
+	pub fn is_well_formed(&self) -> bool {
+		orbinum_encrypted_memo::is_valid_onchain_memo(&self.0)
+	}
+
+	/// Check if the memo carries a compressed V2 payload (header and size range)
+	pub fn is_compressed(&self) -> bool {
+		(MIN_COMPRESSED_MEMO_SIZE as usize..=MAX_COMPRESSED_MEMO_SIZE as usize)
+			.contains(&self.0.len())
+			&& self.0[0] == COMPRESSED_MEMO_VERSION
+	}
+
+	/// Check if the memo only points to an off-chain ciphertext
+	pub fn is_offchain_pointer(&self) -> bool {
+		self.0.len() == MEMO_POINTER_SIZE as usize && self.0[0] == MEMO_POINTER_VERSION
+	}
+
+	/// Get the content hash of an off-chain memo pointer
+	pub fn content_hash(&self) -> Option<[u8; 32]> {
+		if !self.is_offchain_pointer() {
+			return None;
+		}
+		self.0[1..].try_into().ok()
+	}
+
+	/// Check if the memo declares an escrow slot (escrow-mode header and size)
+	pub fn has_escrow_slot(&self) -> bool {
+		self.0.len() == ESCROW_MEMO_SIZE as usize && self.0[0] == ESCROW_MEMO_VERSION
 	}
 
 	/// Get nonce (first 24 bytes)
@@ -3,9 +3,11 @@
 use crate::{
 	domain::{entities::AssetMetadata, value_objects::Hash},
 	pallet::{
-		Assets, Config, HistoricPoseidonRoots, HistoricRootsOrder, NextAssetId, PoseidonRoot,
+		Assets, CommitmentIndexComplete, Config, DisclosureVerifyingKey, HistoricPoseidonRoots,
+		HistoricRootsOrder, NextAssetId, PoseidonRoot,
 	},
 };
+use alloc::vec::Vec;
 use frame_support::{pallet_prelude::*, traits::Get};
 use sp_runtime::traits::AccountIdConversion;
 
@@ -23,6 +25,9 @@ pub fn initialize_genesis<T: Config>(initial_root: Hash) {
 	let _ = order.try_push(initial_root);
 	HistoricRootsOrder::<T>::put(order);
 
+	// Every leaf is indexed on insert, so lookups never need a scan
+	CommitmentIndexComplete::<T>::put(true);
+
 	// Register native asset (asset_id = 0) at genesis
 	let native_asset = AssetMetadata {
 		id: 0,
@@ -40,3 +45,49 @@ pub fn initialize_genesis<T: Config>(initial_root: Hash) {
 	Assets::<T>::insert(0, native_asset);
 	NextAssetId::<T>::put(1); // Next asset ID starts at 1
 }
+
+/// Registers pre-verified assets after the native asset
+///
+/// Assets get consecutive ids from `NextAssetId`, are created by the pallet
+/// account at block 0 and can be shielded from the first block.
+pub fn register_genesis_assets<T: Config>(assets: &[(Vec<u8>, Vec<u8>, u8)]) {
+	for (name, symbol, decimals) in assets {
+		assert!(
+			!name.is_empty() && !symbol.is_empty(),
+			"Genesis asset name and symbol must not be empty"
+		);
+		let id = NextAssetId::<T>::get();
+		let asset = AssetMetadata {
+			id,
+			name: name
+				.clone()
+				.try_into()
+				.expect("Genesis asset name exceeds 64 bytes"),
+			symbol: symbol
+				.clone()
+				.try_into()
+				.expect("Genesis asset symbol exceeds 16 bytes"),
+			decimals: *decimals,
+			is_verified: true,
+			contract_address: None,
+			created_at: 0u32.into(),
+			creator: T::PalletId::get().into_account_truncating(),
+		};
+		Assets::<T>::insert(id, asset);
+		NextAssetId::<T>::put(id + 1);
+	}
+}
+
+/// Sets the disclosure verifying key, with the checks of
+/// `set_disclosure_verifying_key`
+pub fn set_genesis_disclosure_key<T: Config>(vk: &[u8]) {
+	assert!(
+		vk.len() >= 100,
+		"Genesis disclosure verifying key is too short"
+	);
+	let vk: BoundedVec<u8, ConstU32<4096>> = vk
+		.to_vec()
+		.try_into()
+		.expect("Genesis disclosure verifying key exceeds 4096 bytes");
+	DisclosureVerifyingKey::<T>::put(vk);
+}
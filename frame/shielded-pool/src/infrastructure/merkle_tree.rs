@@ -21,7 +21,6 @@
 
 use crate::domain::value_objects::{Hash, MerklePath};
 use alloc::boxed::Box;
-use ark_ff::BigInteger;
 use frame_support::pallet_prelude::*;
 use sp_std::vec::Vec;
 
@@ -90,13 +89,9 @@ pub fn get_zero_hash_cached(level: usize) -> [u8; 32] {
 ///
 /// Clean Architecture: Usa NativePoseidonHasher port (~3x más rápido vía host functions)
 pub fn hash_pair_poseidon(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
-	use ark_bn254::Fr as Bn254Fr;
-	use ark_ff::PrimeField;
-	use orbinum_zk_core::domain::{ports::PoseidonHasher, value_objects::FieldElement};
-
-	// Convert bytes to field elements (little-endian mod order)
-	let left_fr = Bn254Fr::from_le_bytes_mod_order(left);
-	let right_fr = Bn254Fr::from_le_bytes_mod_order(right);
+	use orbinum_zk_core::{
+		domain::ports::PoseidonHasher, infrastructure::crypto::merkle::bytes_to_field,
+	};
 
 	// Use native hasher for ~3x performance boost (falls back to WASM if feature disabled)
 	#[cfg(feature = "poseidon-native")]
@@ -105,15 +100,10 @@ pub fn hash_pair_poseidon(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
 	#[cfg(not(feature = "poseidon-native"))]
 	let hasher = orbinum_zk_core::LightPoseidonHasher;
 
-	// Hash using the domain port (Clean Architecture)
-	let hash_fr = hasher.hash_2([FieldElement::new(left_fr), FieldElement::new(right_fr)]);
-
-	// Convert back to bytes (little-endian)
-	let mut hash_bytes = [0u8; 32];
-	let bigint = hash_fr.inner().into_bigint();
-	let bytes = bigint.to_bytes_le();
-	hash_bytes.copy_from_slice(&bytes[..32]);
-	hash_bytes
+	// Hash using the domain port (Clean Architecture); inputs reduce mod order
+	hasher
+		.hash_2([bytes_to_field(left), bytes_to_field(right)])
+		.to_le_bytes()
 }
 
 /// Hash pair - siempre usa Poseidon nativo
@@ -0,0 +1,287 @@
+//! Storage migrations
+//!
+//! Each `vN` module upgrades the pallet storage from version `N - 1` to `N`
+//! and is wrapped in a `VersionedMigration`, so it only runs once and bumps
+//! the on-chain storage version. Pre/post checks run under `try-runtime`.
+//!
+//! Only the commitment index and the nullifier count are covered. The
+//! frontier tree and per-asset tree redesigns have not landed: the pallet
+//! still recomputes the Poseidon root from every `MerkleLeaves` entry and keeps
+//! one tree for all assets. Their migrations belong to the change that
+//! introduces the new storage.
+
+use crate::pallet::{
+	CommitmentIndex, CommitmentIndexBackfillCursor, CommitmentIndexComplete, Config, MerkleLeaves,
+	MerkleTreeSize, NullifierCount, NullifierCountBackfill, NullifierSet, Pallet,
+};
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+#[cfg(feature = "try-runtime")]
+use {
+	crate::pallet::PoseidonRoot,
+	alloc::vec::Vec,
+	frame_support::storage::{TransactionOutcome, with_transaction},
+};
+
+/// Steps of `step` weight fitting in `budget`
+fn max_steps(budget: Weight, step: Weight) -> u64 {
+	let ref_time = budget
+		.ref_time()
+		.checked_div(step.ref_time())
+		.unwrap_or(u64::MAX);
+	let proof_size = budget
+		.proof_size()
+		.checked_div(step.proof_size())
+		.unwrap_or(u64::MAX);
+	ref_time.min(proof_size)
+}
+
+/// v1: commitment index
+///
+/// `CommitmentIndex` maps each commitment to its leaf so proofs no longer
+/// scan `MerkleLeaves`. Chains that inserted leaves before the index existed
+/// have no entry for them. Backfilling every leaf in the upgrade block would
+/// have unbounded weight, so the upgrade only starts a cursor and
+/// [`backfill_commitment_index`] indexes leaves in `on_idle` with the weight
+/// left in each block. Lookups fall back to a scan until it completes and
+/// sets `CommitmentIndexComplete`. The tree itself is not modified.
+pub mod v1 {
+	use super::*;
+
+	/// Weight of backfilling one leaf: read it and its index, write the index
+	fn leaf_weight<T: Config>() -> Weight {
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+
+	/// Backfills leaves from the cursor within `limit`, returning the weight used
+	///
+	/// Once the cursor reaches the tree size the index is marked complete.
+	/// Leaves inserted meanwhile are indexed on insert, so the size read at
+	/// each step is a safe bound.
+	pub fn backfill_commitment_index<T: Config>(limit: Weight) -> Weight {
+		let overhead = T::DbWeight::get().reads_writes(2, 2);
+		if !limit.all_gte(overhead) {
+			return Weight::zero();
+		}
+		let Some(mut cursor) = CommitmentIndexBackfillCursor::<T>::get() else {
+			return T::DbWeight::get().reads(1);
+		};
+
+		let size = MerkleTreeSize::<T>::get();
+		let per_leaf = leaf_weight::<T>();
+		let max_leaves = max_steps(limit.saturating_sub(overhead), per_leaf);
+		let end = cursor
+			.saturating_add(max_leaves.min(u32::MAX as u64) as u32)
+			.min(size);
+
+		let mut used = overhead;
+		while cursor < end {
+			let unindexed = MerkleLeaves::<T>::get(cursor)
+				.filter(|commitment| !CommitmentIndex::<T>::contains_key(commitment));
+			if let Some(commitment) = unindexed {
+				CommitmentIndex::<T>::insert(commitment, cursor);
+			}
+			cursor += 1;
+			used = used.saturating_add(per_leaf);
+		}
+
+		if cursor >= size {
+			CommitmentIndexBackfillCursor::<T>::kill();
+			CommitmentIndexComplete::<T>::put(true);
+		} else {
+			CommitmentIndexBackfillCursor::<T>::put(cursor);
+		}
+		used
+	}
+
+	pub struct InnerMigrateToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if CommitmentIndexComplete::<T>::get() {
+				return T::DbWeight::get().reads(1);
+			}
+			CommitmentIndexBackfillCursor::<T>::put(0);
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((MerkleTreeSize::<T>::get(), PoseidonRoot::<T>::get()).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (size, root) = <(u32, crate::Hash)>::decode(&mut &state[..])
+				.map_err(|_| "shielded-pool v1: invalid pre-upgrade state")?;
+			ensure!(
+				MerkleTreeSize::<T>::get() == size && PoseidonRoot::<T>::get() == root,
+				"shielded-pool v1: Merkle tree changed"
+			);
+			ensure!(
+				CommitmentIndexComplete::<T>::get()
+					|| CommitmentIndexBackfillCursor::<T>::get() == Some(0),
+				"shielded-pool v1: commitment index backfill not started"
+			);
+
+			// Rehearse the backfill `on_idle` runs over the next blocks, then
+			// discard it so the upgrade itself is checked unchanged
+			with_transaction(|| TransactionOutcome::Rollback(Self::rehearse_backfill(size)))
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	impl<T: Config> InnerMigrateToV1<T> {
+		fn rehearse_backfill(size: u32) -> Result<(), sp_runtime::TryRuntimeError> {
+			while CommitmentIndexBackfillCursor::<T>::get().is_some() {
+				backfill_commitment_index::<T>(Weight::MAX);
+			}
+			ensure!(
+				CommitmentIndexComplete::<T>::get(),
+				"shielded-pool v1: commitment index backfill did not complete"
+			);
+			for index in 0..size {
+				let commitment =
+					MerkleLeaves::<T>::get(index).ok_or("shielded-pool v1: missing Merkle leaf")?;
+				let indexed = CommitmentIndex::<T>::get(commitment)
+					.ok_or("shielded-pool v1: leaf missing from commitment index")?;
+				ensure!(
+					indexed <= index && MerkleLeaves::<T>::get(indexed) == Some(commitment),
+					"shielded-pool v1: commitment index points at another leaf"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrates storage from version 0 to 1.
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// v2: nullifier count
+///
+/// `NullifierCount` replaces iterating `NullifierSet` to count spent
+/// nullifiers. Spends increment it from the upgrade block on, and
+/// [`backfill_nullifier_count`] counts the earlier ones in `on_idle` with
+/// the weight left in each block, telling them apart by their spend block.
+/// Until it completes readers iterate the set.
+pub mod v2 {
+	use super::*;
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// Weight of counting one nullifier: read its entry
+	fn entry_weight<T: Config>() -> Weight {
+		T::DbWeight::get().reads(1)
+	}
+
+	/// Counts nullifiers after the last counted one within `limit`,
+	/// returning the weight used
+	///
+	/// Only nullifiers spent up to the block before the upgrade are counted;
+	/// later ones were counted on insert. Once the set is exhausted the
+	/// backfill ends.
+	pub fn backfill_nullifier_count<T: Config>(limit: Weight) -> Weight {
+		let overhead = T::DbWeight::get().reads_writes(2, 2);
+		if !limit.all_gte(overhead) {
+			return Weight::zero();
+		}
+		let Some((last_uncounted_block, last)) = NullifierCountBackfill::<T>::get() else {
+			return T::DbWeight::get().reads(1);
+		};
+
+		let per_entry = entry_weight::<T>();
+		let max_entries = max_steps(limit.saturating_sub(overhead), per_entry);
+		let mut entries = match last {
+			Some(last) => NullifierSet::<T>::iter_from(NullifierSet::<T>::hashed_key_for(last)),
+			None => NullifierSet::<T>::iter(),
+		};
+
+		let mut used = overhead;
+		let mut counted = 0u32;
+		let mut last_counted = None;
+		let mut steps = 0;
+		let exhausted = loop {
+			if steps >= max_entries {
+				break false;
+			}
+			let Some((nullifier, spent_at)) = entries.next() else {
+				break true;
+			};
+			if spent_at <= last_uncounted_block {
+				counted = counted.saturating_add(1);
+			}
+			last_counted = Some(nullifier);
+			steps += 1;
+			used = used.saturating_add(per_entry);
+		};
+
+		NullifierCount::<T>::mutate(|count| *count = count.saturating_add(counted));
+		if exhausted {
+			NullifierCountBackfill::<T>::kill();
+		} else {
+			NullifierCountBackfill::<T>::put((last_uncounted_block, last_counted.or(last)));
+		}
+		used
+	}
+
+	pub struct InnerMigrateToV2<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			// Upgrades run before the block is initialized, so this is its parent
+			let parent: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+			NullifierCountBackfill::<T>::put((parent, None));
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((NullifierSet::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count = u32::decode(&mut &state[..])
+				.map_err(|_| "shielded-pool v2: invalid pre-upgrade state")?;
+			ensure!(
+				NullifierCountBackfill::<T>::exists(),
+				"shielded-pool v2: nullifier count backfill not started"
+			);
+
+			// Rehearse the backfill `on_idle` runs over the next blocks, then
+			// discard it so the upgrade itself is checked unchanged
+			with_transaction(|| TransactionOutcome::Rollback(Self::rehearse_backfill(count)))
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	impl<T: Config> InnerMigrateToV2<T> {
+		fn rehearse_backfill(count: u32) -> Result<(), sp_runtime::TryRuntimeError> {
+			while NullifierCountBackfill::<T>::exists() {
+				backfill_nullifier_count::<T>(Weight::MAX);
+			}
+			ensure!(
+				NullifierCount::<T>::get() == count,
+				"shielded-pool v2: nullifier count does not match the set"
+			);
+			Ok(())
+		}
+	}
+
+	/// Migrates storage from version 1 to 2.
+	pub type MigrateToV2<T> = VersionedMigration<
+		1,
+		2,
+		InnerMigrateToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -3,8 +3,11 @@
 //! This layer contains the implementation of ports defined in the domain layer.
 //! It includes storage, external integrations, and framework-specific code.
 
+#[cfg(feature = "dual-hash-tree")]
+pub mod dual_hash_merkle_tree;
 pub mod frame_types;
 pub mod genesis;
 pub mod merkle_tree;
+pub mod migrations;
 pub mod repositories;
 pub mod services;
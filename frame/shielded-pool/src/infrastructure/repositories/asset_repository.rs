@@ -4,6 +4,7 @@ use crate::{
 	domain::entities::AssetMetadata,
 	pallet::{Assets, Config, NextAssetId},
 };
+use alloc::vec::Vec;
 use frame_system::pallet_prelude::BlockNumberFor;
 
 /// Repository for asset registry operations
@@ -17,6 +18,13 @@ impl AssetRepository {
 		Assets::<T>::get(asset_id)
 	}
 
+	/// Get all registered assets, ordered by asset_id
+	pub fn list_assets<T: Config>() -> Vec<AssetMetadata<T::AccountId, BlockNumberFor<T>>> {
+		let mut assets: Vec<_> = Assets::<T>::iter_values().collect();
+		assets.sort_by_key(|asset| asset.id);
+		assets
+	}
+
 	/// Store asset metadata
 	pub fn store_asset<T: Config>(
 		asset_id: u32,
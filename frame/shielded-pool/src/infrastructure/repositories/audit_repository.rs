@@ -59,6 +59,12 @@ impl AuditRepository {
 		DisclosureRequests::<T>::get(target, auditor)
 	}
 
+	pub fn get_disclosure_requests_for<T: Config>(
+		target: &T::AccountId,
+	) -> sp_std::vec::Vec<DisclosureRequest<T::AccountId, BlockNumberFor<T>>> {
+		DisclosureRequests::<T>::iter_prefix_values(target).collect()
+	}
+
 	pub fn store_disclosure_request<T: Config>(
 		target: T::AccountId,
 		auditor: T::AccountId,
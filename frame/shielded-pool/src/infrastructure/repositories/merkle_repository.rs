@@ -3,11 +3,15 @@
 use crate::{
 	domain::{Commitment, value_objects::Hash},
 	pallet::{
-		Config, HistoricPoseidonRoots, HistoricRootsOrder, MerkleLeaves, MerkleTreeSize,
-		PoseidonRoot,
+		CommitmentIndex, CommitmentIndexBackfillCursor, CommitmentIndexComplete, Config,
+		HistoricPoseidonRoots, HistoricRootBlocks, HistoricRootsOrder, MerkleLeaves,
+		MerkleTreeSize, PoseidonRoot,
 	},
 };
+use core::marker::PhantomData;
 use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
+use orbinum_zk_core::MerkleStorage;
 
 /// Repository for Merkle tree storage operations
 pub struct MerkleRepository;
@@ -38,9 +42,48 @@ impl MerkleRepository {
 		MerkleLeaves::<T>::get(index)
 	}
 
-	/// Insert leaf at index
+	/// Insert leaf at index and record the commitment's index
 	pub fn insert_leaf<T: Config>(index: u32, commitment: Commitment) {
 		MerkleLeaves::<T>::insert(index, commitment);
+		CommitmentIndex::<T>::insert(commitment, index);
+	}
+
+	/// Get the leaf index of a commitment
+	///
+	/// A single map read once `CommitmentIndexComplete` is set. Before that, a
+	/// miss falls back to a linear scan of the leaves the backfill has not
+	/// reached yet (see [`Self::unindexed_leaves`]).
+	pub fn get_commitment_index<T: Config>(commitment: &Commitment) -> Option<u32> {
+		CommitmentIndex::<T>::get(commitment).or_else(|| {
+			if CommitmentIndexComplete::<T>::get() {
+				None
+			} else {
+				Self::scan_leaf_index::<T>(commitment)
+			}
+		})
+	}
+
+	/// Find leaf index for a commitment (linear scan of the unindexed window)
+	fn scan_leaf_index<T: Config>(commitment: &Commitment) -> Option<u32> {
+		let start = CommitmentIndexBackfillCursor::<T>::get().unwrap_or(0);
+		(start..Self::get_tree_size::<T>()).find(|&i| Self::get_leaf::<T>(i) == Some(*commitment))
+	}
+
+	/// Number of leaves a `CommitmentIndex` miss has to scan
+	///
+	/// Zero once `CommitmentIndexComplete` is set; otherwise every leaf from the
+	/// backfill cursor to the end of the tree.
+	pub fn unindexed_leaves<T: Config>() -> u32 {
+		if CommitmentIndexComplete::<T>::get() {
+			return 0;
+		}
+		let start = CommitmentIndexBackfillCursor::<T>::get().unwrap_or(0);
+		Self::get_tree_size::<T>().saturating_sub(start)
+	}
+
+	/// Mark every leaf as present in `CommitmentIndex`
+	pub fn set_commitment_index_complete<T: Config>() {
+		CommitmentIndexComplete::<T>::put(true);
 	}
 
 	/// Check if Poseidon root is known (historic or current)
@@ -57,11 +100,18 @@ impl MerkleRepository {
 	/// Add Poseidon root to historic roots (Poseidon-only system)
 	pub fn add_historic_poseidon_root<T: Config>(root: Hash) {
 		HistoricPoseidonRoots::<T>::insert(root, true);
+		HistoricRootBlocks::<T>::insert(root, frame_system::Pallet::<T>::block_number());
 	}
 
 	/// Remove Poseidon root from historic roots
 	pub fn remove_poseidon_historic_root<T: Config>(root: &Hash) {
 		HistoricPoseidonRoots::<T>::remove(root);
+		HistoricRootBlocks::<T>::remove(root);
+	}
+
+	/// Get the block a historic root was produced in (genesis root: block 0)
+	pub fn get_historic_root_block<T: Config>(root: &Hash) -> BlockNumberFor<T> {
+		HistoricRootBlocks::<T>::get(root).unwrap_or_default()
 	}
 
 	/// Get historic roots order
@@ -74,20 +124,6 @@ impl MerkleRepository {
 		HistoricRootsOrder::<T>::put(order);
 	}
 
-	/// Find leaf index for a commitment (linear scan)
-	pub fn find_leaf_index<T: Config>(commitment: &Commitment) -> Option<u32> {
-		let size = Self::get_tree_size::<T>();
-		for i in 0..size {
-			#[allow(clippy::collapsible_if)]
-			if let Some(c) = Self::get_leaf::<T>(i) {
-				if c == *commitment {
-					return Some(i);
-				}
-			}
-		}
-		None
-	}
-
 	/// Get all leaves up to current size
 	pub fn get_all_leaves<T: Config>() -> sp_std::vec::Vec<Hash> {
 		let size = Self::get_tree_size::<T>();
@@ -96,3 +132,35 @@ impl MerkleRepository {
 			.collect()
 	}
 }
+
+/// Pallet storage items exposed to `orbinum_zk_core::StorageMerkleRepository`
+///
+/// Lets tree logic be written against the zk-core `MerkleRepository` port and
+/// unit-tested against its in-memory adapter.
+pub struct PalletMerkleStorage<T>(PhantomData<T>);
+
+impl<T: Config> MerkleStorage for PalletMerkleStorage<T> {
+	fn leaf(index: u32) -> Option<[u8; 32]> {
+		MerkleRepository::get_leaf::<T>(index).map(|commitment| commitment.0)
+	}
+
+	fn insert_leaf(index: u32, leaf: [u8; 32]) {
+		MerkleRepository::insert_leaf::<T>(index, Commitment(leaf));
+	}
+
+	fn size() -> u32 {
+		MerkleRepository::get_tree_size::<T>()
+	}
+
+	fn set_size(size: u32) {
+		MerkleRepository::set_tree_size::<T>(size);
+	}
+
+	fn root() -> [u8; 32] {
+		MerkleRepository::get_poseidon_root::<T>()
+	}
+
+	fn set_root(root: [u8; 32]) {
+		MerkleRepository::set_poseidon_root::<T>(root);
+	}
+}
@@ -10,6 +10,6 @@ pub mod pool_balance_repository;
 pub use asset_repository::AssetRepository;
 pub use audit_repository::AuditRepository;
 pub use commitment_repository::CommitmentRepository;
-pub use merkle_repository::MerkleRepository;
+pub use merkle_repository::{MerkleRepository, PalletMerkleStorage};
 pub use nullifier_repository::NullifierRepository;
 pub use pool_balance_repository::PoolBalanceRepository;
@@ -2,7 +2,7 @@
 
 use crate::{
 	domain::Nullifier,
-	pallet::{Config, NullifierSet},
+	pallet::{Config, NullifierCount, NullifierCountBackfill, NullifierSet},
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 
@@ -18,10 +18,22 @@ impl NullifierRepository {
 	/// Mark a nullifier as used at current block
 	pub fn mark_as_used<T: Config>(nullifier: Nullifier, block: BlockNumberFor<T>) {
 		NullifierSet::<T>::insert(nullifier, block);
+		NullifierCount::<T>::mutate(|count| *count = count.saturating_add(1));
 	}
 
 	/// Get block number when nullifier was used (if any)
 	pub fn get_usage_block<T: Config>(nullifier: &Nullifier) -> Option<BlockNumberFor<T>> {
 		NullifierSet::<T>::get(nullifier)
 	}
+
+	/// Count the used nullifiers
+	///
+	/// Iterates the whole set while the v2 migration is still counting the
+	/// nullifiers spent before `NullifierCount` existed.
+	pub fn count_used<T: Config>() -> u32 {
+		if NullifierCountBackfill::<T>::exists() {
+			return NullifierSet::<T>::iter_keys().count() as u32;
+		}
+		NullifierCount::<T>::get()
+	}
 }
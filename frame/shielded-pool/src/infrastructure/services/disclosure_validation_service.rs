@@ -12,6 +12,7 @@ use crate::{
 };
 use frame_support::{ensure, pallet_prelude::*};
 use frame_system::{self, pallet_prelude::BlockNumberFor};
+use orbinum_encrypted_memo::{MemoField, disclosed_data_size, validate_mask_bitmap};
 use pallet_zk_verifier::ZkVerifierPort;
 use sp_runtime::traits::Saturating;
 
@@ -42,6 +43,33 @@ impl DisclosureValidationService {
 		Ok(())
 	}
 
+	/// Verify the discloser holds the viewing key behind `viewing_key_hash`
+	///
+	/// Replaces the "hash is non-zero" check with a viewing key ownership proof
+	/// bound to the disclosed commitment.
+	pub fn verify_viewing_key_ownership<T: Config>(
+		proof_bytes: &[u8],
+		viewing_key_hash: &[u8; 32],
+		commitment: &Commitment,
+	) -> DispatchResult {
+		ensure!(
+			*viewing_key_hash != [0u8; 32],
+			Error::<T>::InvalidPublicSignals
+		);
+		ensure!(proof_bytes.len() == 256, Error::<T>::InvalidProof);
+
+		let is_valid = T::ZkVerifier::verify_viewing_key_proof(
+			proof_bytes,
+			viewing_key_hash,
+			&commitment.0,
+			None,
+		)?;
+
+		ensure!(is_valid, Error::<T>::InvalidProof);
+
+		Ok(())
+	}
+
 	/// Validate public signals consistency
 	pub fn validate_public_signals<T: Config>(
 		commitment: &Commitment,
@@ -187,23 +215,28 @@ impl DisclosureValidationService {
 
 	/// Validate mask bitmap (selective disclosure bitmap)
 	///
-	/// # Bitmap Layout (4 bits):
-	/// - Bit 0: reveals value
-	/// - Bit 1: reveals asset_id
-	/// - Bit 2: reveals owner
-	/// - Bit 3: reveals blinding (MUST be 0)
+	/// Bit `i` reveals field `i` of `MemoDataV3` (see
+	/// `orbinum_encrypted_memo::MemoField`): value, owner, blinding (MUST be 0),
+	/// asset_id, diversifier_index, sender_hint, app_data. The rules are shared with the
+	/// wallet-side `DisclosureMask` so both sides reject the same masks.
+	pub fn validate_mask_bitmap<T: Config>(mask: u8) -> DispatchResult {
+		validate_mask_bitmap(mask).map_err(|_| Error::<T>::InvalidDisclosureMask.into())
+	}
+
+	/// Decode mask bitmap
 	///
 	/// Returns (reveals_value, reveals_asset_id, reveals_owner, is_valid)
 	pub fn decode_mask_bitmap(mask: u8) -> (bool, bool, bool, bool) {
-		let reveals_value = (mask & 0x01) != 0;
-		let reveals_asset_id = (mask & 0x02) != 0;
-		let reveals_owner = (mask & 0x04) != 0;
-		let reveals_blinding = (mask & 0x08) != 0;
-
-		// Valid if: not revealing blinding AND revealing at least one field
-		let is_valid = !reveals_blinding && (reveals_value || reveals_asset_id || reveals_owner);
-
-		(reveals_value, reveals_asset_id, reveals_owner, is_valid)
+		let reveals_value = (mask & MemoField::Value.bit()) != 0;
+		let reveals_asset_id = (mask & MemoField::AssetId.bit()) != 0;
+		let reveals_owner = (mask & MemoField::Owner.bit()) != 0;
+
+		(
+			reveals_value,
+			reveals_asset_id,
+			reveals_owner,
+			validate_mask_bitmap(mask).is_ok(),
+		)
 	}
 
 	/// Check if a disclosure request exists and is pending
@@ -226,30 +259,14 @@ impl DisclosureValidationService {
 
 	/// Validate disclosed data structure
 	///
-	/// Disclosed data should contain:
-	/// - value (optional, 16 bytes)
-	/// - asset_id (optional, 4 bytes)
-	/// - owner pubkey (optional, 32 bytes)
+	/// Disclosed data holds the revealed `MemoDataV3` fields in serialization
+	/// order, e.g. value (8 bytes), owner pubkey (32 bytes), asset_id (8 bytes).
 	pub fn validate_disclosed_data_structure(data: &[u8], mask: u8) -> Result<(), &'static str> {
-		let (reveals_value, reveals_asset_id, reveals_owner, is_valid) =
-			Self::decode_mask_bitmap(mask);
-
-		if !is_valid {
+		if validate_mask_bitmap(mask).is_err() {
 			return Err("Invalid mask bitmap");
 		}
 
-		let mut expected_size = 0usize;
-		if reveals_value {
-			expected_size += 16; // u128 value
-		}
-		if reveals_asset_id {
-			expected_size += 4; // u32 asset_id
-		}
-		if reveals_owner {
-			expected_size += 32; // 32-byte owner pubkey
-		}
-
-		if data.len() < expected_size {
+		if data.len() < disclosed_data_size(mask) {
 			return Err("Disclosed data too short");
 		}
 
@@ -161,6 +161,21 @@ impl MerkleTreeService {
 		Some(DefaultMerklePath { siblings, indices })
 	}
 
+	/// Get the tree frontier: the rightmost node of each level, leaf level first
+	///
+	/// Rebuilt from all leaves. Together with the tree size it is enough to
+	/// append further leaves and recompute the root off-chain.
+	pub fn get_frontier<T: Config>() -> sp_std::vec::Vec<Hash> {
+		let mut tree = crate::infrastructure::merkle_tree::IncrementalMerkleTree::<20>::new();
+		for leaf in MerkleRepository::get_all_leaves::<T>() {
+			if tree.insert(leaf).is_err() {
+				break;
+			}
+		}
+
+		tree.frontier.to_vec()
+	}
+
 	/// Verify a Merkle proof for a given leaf
 	pub fn verify_merkle_proof(root: &Hash, leaf: &Hash, path: &DefaultMerklePath) -> bool {
 		// Use the path directly - it's already compatible with the verification
@@ -169,8 +184,9 @@ impl MerkleTreeService {
 		)
 	}
 
-	/// Find leaf index for a commitment (Linear scan - expensive, only for RPC)
+	/// Find leaf index for a commitment (`CommitmentIndex` read, scanning the
+	/// unindexed leaves while the backfill runs)
 	pub fn find_leaf_index<T: Config>(commitment: &Commitment) -> Option<u32> {
-		crate::infrastructure::repositories::MerkleRepository::find_leaf_index::<T>(commitment)
+		crate::infrastructure::repositories::MerkleRepository::get_commitment_index::<T>(commitment)
 	}
 }
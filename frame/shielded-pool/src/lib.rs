@@ -46,7 +46,7 @@
 //! ShieldedPool::private_transfer(origin, proof)?;
 //!
 //! // Withdraw from the pool
-//! ShieldedPool::unshield(origin, proof, nullifier, amount, recipient)?;
+//! ShieldedPool::unshield(origin, proof, nullifier, amount, recipient, recipient_nonce)?;
 //! ```
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -82,21 +82,24 @@ pub use application::DepositInfo;
 pub use domain::{
 	Commitment, Note, Nullifier,
 	entities::{
-		AssetMetadata,
+		AssetMetadata, EncodedAssetMetadata,
 		audit::{AuditPolicy, AuditTrail, DisclosureProof, DisclosureRequest},
 	},
 	value_objects::{
 		AssetId, DEFAULT_TREE_DEPTH, DefaultMerklePath, Hash, MAX_MEMO_SIZE, MAX_TREE_DEPTH,
-		MerklePath, StandardEncryptedMemo,
+		MerklePath, PoolStats, ProofCheck, StandardEncryptedMemo, TransferValidation,
 		audit::{Auditor, DisclosureCondition},
 	},
 };
 // Re-export FRAME-specific EncryptedMemo for storage compatibility
 pub use infrastructure::frame_types::EncryptedMemo as FrameEncryptedMemo;
+// Re-export proof jobs handed to the node for pre-verification
+pub use pallet_zk_verifier::ProofJob;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
+	use alloc::vec::Vec;
 	use frame_support::{
 		PalletId,
 		pallet_prelude::*,
@@ -107,11 +110,15 @@ pub mod pallet {
 	use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 	use sp_runtime::traits::AccountIdConversion;
 
+	/// In-code storage version, see [`crate::infrastructure::migrations`]
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	/// The balance type for this pallet
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Input data for a batch disclosure proof submission
@@ -161,6 +168,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinShieldAmount: Get<BalanceOf<Self>>;
 
+		/// Blocks a disclosure request stays open; it can no longer be
+		/// approved afterwards
+		#[pallet::constant]
+		type DisclosureRequestExpiration: Get<BlockNumberFor<Self>>;
+
+		/// Origin that sets the disclosure verifying key
+		type CircuitAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin that registers and verifies assets and sets their memo policy
+		type AssetAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Weight information for extrinsics in this pallet
 		type WeightInfo: WeightInfo;
 	}
@@ -183,11 +201,47 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MerkleLeaves<T> = StorageMap<_, Blake2_128Concat, u32, Commitment, OptionQuery>;
 
+	/// Leaf index of each commitment (commitment -> index)
+	///
+	/// Written together with `MerkleLeaves` so a commitment's position can be
+	/// looked up without scanning the leaves.
+	#[pallet::storage]
+	pub type CommitmentIndex<T> = StorageMap<_, Blake2_128Concat, Commitment, u32, OptionQuery>;
+
+	/// Whether every leaf is in `CommitmentIndex`
+	///
+	/// Set at genesis, and by the v1 migration once leaves inserted before the
+	/// index existed are backfilled. Until then lookups fall back to a scan.
+	#[pallet::storage]
+	pub type CommitmentIndexComplete<T> = StorageValue<_, bool, ValueQuery>;
+
+	/// Next leaf the v1 migration backfills into `CommitmentIndex`
+	///
+	/// Present while the backfill is in progress; it advances in `on_idle`.
+	#[pallet::storage]
+	pub type CommitmentIndexBackfillCursor<T> = StorageValue<_, u32, OptionQuery>;
+
 	/// Set of used nullifiers (nullifier -> block number when used)
 	#[pallet::storage]
 	pub type NullifierSet<T: Config> =
 		StorageMap<_, Blake2_128Concat, Nullifier, BlockNumberFor<T>, OptionQuery>;
 
+	/// Number of entries in `NullifierSet`
+	///
+	/// Counts every nullifier once the v2 migration has counted those spent
+	/// before it existed; until then readers fall back to iterating the set.
+	#[pallet::storage]
+	pub type NullifierCount<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Progress of the v2 migration counting nullifiers into `NullifierCount`
+	///
+	/// Holds the last block whose spends the migration counts (the block
+	/// before the upgrade) and the last nullifier it visited. Present while
+	/// the count is in progress; it advances in `on_idle`.
+	#[pallet::storage]
+	pub type NullifierCountBackfill<T: Config> =
+		StorageValue<_, (BlockNumberFor<T>, Option<Nullifier>), OptionQuery>;
+
 	/// Total balance held in the shielded pool
 	#[pallet::storage]
 	#[pallet::getter(fn pool_balance)]
@@ -197,6 +251,14 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type HistoricPoseidonRoots<T> = StorageMap<_, Blake2_128Concat, Hash, bool, ValueQuery>;
 
+	/// Block in which each historic root was produced (root -> block number)
+	///
+	/// Kept in sync with `HistoricPoseidonRoots`. Roots without an entry
+	/// (the genesis root) were produced at block 0.
+	#[pallet::storage]
+	pub type HistoricRootBlocks<T: Config> =
+		StorageMap<_, Blake2_128Concat, Hash, BlockNumberFor<T>, OptionQuery>;
+
 	/// Order of historic roots (FIFO queue for pruning)
 	/// Stores roots in insertion order, oldest first
 	#[pallet::storage]
@@ -233,7 +295,7 @@ pub mod pallet {
 	/// Disclosure verifying key
 	///
 	/// Stores the verifying key for disclosure ZK proofs.
-	/// Only governance (root) can update this key.
+	/// Only `CircuitAdminOrigin` can update this key.
 	/// Format: Raw bytes of ark-groth16 VerifyingKey serialized
 	#[pallet::storage]
 	pub type DisclosureVerifyingKey<T> =
@@ -309,6 +371,20 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextAssetId<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+	/// Assets whose memos must carry an escrow slot
+	///
+	/// Regulated-asset issuers require every note of their asset to wrap its
+	/// memo key to a policy-designated auditor. Checked on shield, where the
+	/// asset is public.
+	#[pallet::storage]
+	pub type MemoEscrowRequired<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32, // asset_id
+		bool,
+		ValueQuery,
+	>;
+
 	/// Pool balance per asset
 	///
 	/// Tracks the total balance of each asset in the shielded pool
@@ -345,6 +421,11 @@ pub mod pallet {
 	pub struct GenesisConfig<T: Config> {
 		/// Initial Merkle root (empty tree)
 		pub initial_root: Hash,
+		/// Assets registered and verified after the native asset, as
+		/// `(name, symbol, decimals)`; they get ids from 1 in order
+		pub assets: Vec<(Vec<u8>, Vec<u8>, u8)>,
+		/// Verifying key of the disclosure circuit (ark-groth16 compressed)
+		pub disclosure_verifying_key: Option<Vec<u8>>,
 		#[serde(skip)]
 		pub _phantom: PhantomData<T>,
 	}
@@ -354,6 +435,10 @@ pub mod pallet {
 		fn build(&self) {
 			// Delegate to infrastructure layer for genesis initialization
 			crate::infrastructure::genesis::initialize_genesis::<T>(self.initial_root);
+			crate::infrastructure::genesis::register_genesis_assets::<T>(&self.assets);
+			if let Some(vk) = &self.disclosure_verifying_key {
+				crate::infrastructure::genesis::set_genesis_disclosure_key::<T>(vk);
+			}
 		}
 	}
 
@@ -368,6 +453,8 @@ pub mod pallet {
 		Shielded {
 			/// Who made the deposit
 			depositor: T::AccountId,
+			/// Asset deposited
+			asset_id: u32,
 			/// Amount deposited
 			amount: BalanceOf<T>,
 			/// Commitment created
@@ -394,6 +481,8 @@ pub mod pallet {
 		Unshielded {
 			/// Nullifier of the spent note
 			nullifier: Nullifier,
+			/// Asset withdrawn
+			asset_id: u32,
 			/// Amount withdrawn
 			amount: BalanceOf<T>,
 			/// Recipient account
@@ -505,6 +594,14 @@ pub mod pallet {
 			/// The asset ID
 			asset_id: u32,
 		},
+
+		/// Memo escrow requirement of an asset was changed
+		MemoEscrowRequirementSet {
+			/// The asset ID
+			asset_id: u32,
+			/// Whether memos must carry an escrow slot
+			required: bool,
+		},
 	}
 
 	// ========================================================================
@@ -565,7 +662,7 @@ pub mod pallet {
 		VerifyingKeyNotSet,
 		/// Invalid public signals (length or consistency)
 		InvalidPublicSignals,
-		/// Invalid disclosure mask (blinding revealed or no fields disclosed)
+		/// Invalid disclosure mask (blinding revealed, unknown field bits or no fields disclosed)
 		InvalidDisclosureMask,
 		/// Commitment not found on-chain
 		CommitmentNotFound,
@@ -581,6 +678,26 @@ pub mod pallet {
 		DisclosureFrequencyLimitExceeded,
 		/// Too many disclosure requests
 		TooManyDisclosureRequests,
+		/// Asset requires an escrow-mode memo
+		MemoEscrowRequired,
+		/// Disclosure request is past its expiration block
+		DisclosureRequestExpired,
+	}
+
+	// ========================================================================
+	// Hooks
+	// ========================================================================
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			use crate::infrastructure::migrations::{v1, v2};
+
+			let used = v1::backfill_commitment_index::<T>(remaining_weight);
+			used.saturating_add(v2::backfill_nullifier_count::<T>(
+				remaining_weight.saturating_sub(used),
+			))
+		}
 	}
 
 	// ========================================================================
@@ -605,9 +722,11 @@ pub mod pallet {
 		/// * `AmountTooSmall` - Amount is below minimum
 		/// * `MerkleTreeFull` - No more space in the tree
 		/// * `CommitmentAlreadyExists` - Duplicate commitment
-		/// * `InvalidMemoSize` - Encrypted memo is not exactly 104 bytes
+		/// * `InvalidMemoSize` - Encrypted memo is not an on-chain memo envelope
+		///   (see `EncryptedMemo::is_well_formed`)
+		/// * `MemoEscrowRequired` - Asset requires an escrow-mode memo
 		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::shield())]
+		#[pallet::weight(T::WeightInfo::shield().saturating_add(Pallet::<T>::leaf_index_scan_weight(1)))]
 		pub fn shield(
 			origin: OriginFor<T>,
 			asset_id: u32,
@@ -649,9 +768,11 @@ pub mod pallet {
 		/// * `Shielded` - Emitted for each successful shield in the batch
 		///
 		/// # Weight
-		/// Approximately `N * shield_weight * 0.8` (20% batch discount)
+		/// Approximately `N * shield_weight * 0.8` (20% batch discount), plus the
+		/// duplicate-commitment scan while the `CommitmentIndex` backfill runs
 		#[pallet::call_index(12)]
-		#[pallet::weight(T::WeightInfo::shield().saturating_mul(operations.len() as u64).saturating_mul(4) / 5)]
+		#[pallet::weight((T::WeightInfo::shield().saturating_mul(operations.len() as u64).saturating_mul(4) / 5)
+			.saturating_add(Pallet::<T>::leaf_index_scan_weight(operations.len() as u32)))]
 		pub fn shield_batch(
 			origin: OriginFor<T>,
 			operations: BoundedVec<
@@ -694,10 +815,11 @@ pub mod pallet {
 		/// * `UnknownMerkleRoot` - Root is not in historic roots
 		/// * `NullifierAlreadyUsed` - Double-spend attempt
 		/// * `InvalidProof` - ZK proof verification failed
-		/// * `InvalidMemoSize` - Encrypted memo is not exactly 104 bytes
+		/// * `InvalidMemoSize` - Encrypted memo is not an on-chain memo envelope
+		///   (see `EncryptedMemo::is_well_formed`)
 		/// * `MemoCommitmentMismatch` - Number of memos doesn't match commitments
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::private_transfer())]
+		#[pallet::weight(T::WeightInfo::private_transfer().saturating_add(Pallet::<T>::leaf_index_scan_weight(commitments.len() as u32)))]
 		pub fn private_transfer(
 			origin: OriginFor<T>,
 			#[allow(unused_variables)] proof: BoundedVec<u8, ConstU32<512>>,
@@ -730,6 +852,8 @@ pub mod pallet {
 		/// * `nullifier` - Nullifier for the note being spent
 		/// * `amount` - Amount to withdraw
 		/// * `recipient` - Public account to receive tokens
+		/// * `recipient_nonce` - Nonce the proof binds to the recipient
+		///   (`recipient_binding = Poseidon(recipient, recipient_nonce)`)
 		///
 		/// # Errors
 		/// * `UnknownMerkleRoot` - Root is not in historic roots
@@ -746,6 +870,7 @@ pub mod pallet {
 			asset_id: u32,
 			amount: BalanceOf<T>,
 			recipient: T::AccountId,
+			recipient_nonce: [u8; 32],
 		) -> DispatchResult {
 			ensure_signed(origin)?;
 
@@ -757,6 +882,7 @@ pub mod pallet {
 				asset_id,
 				amount,
 				recipient,
+				recipient_nonce,
 			)
 		}
 
@@ -764,14 +890,14 @@ pub mod pallet {
 		///
 		/// Configures the verifying key used to verify disclosure ZK proofs.
 		/// This should be the disclosure_vk.json generated from the disclosure circuit.
-		/// Only root/governance can call this extrinsic.
+		/// Only `CircuitAdminOrigin` can call this extrinsic.
 		///
 		/// # Arguments
-		/// * `origin` - Must be root
+		/// * `origin` - Must be `CircuitAdminOrigin`
 		/// * `vk_bytes` - Serialized verifying key (ark-groth16 format)
 		///
 		/// # Errors
-		/// * `BadOrigin` - Caller is not root
+		/// * `BadOrigin` - Caller is not `CircuitAdminOrigin`
 		/// * `InvalidVerifyingKey` - VK format is invalid (size < 100 bytes)
 		#[pallet::call_index(3)]
 		#[pallet::weight(T::WeightInfo::set_disclosure_verifying_key())]
@@ -779,7 +905,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			vk_bytes: BoundedVec<u8, ConstU32<4096>>,
 		) -> DispatchResult {
-			ensure_root(origin)?;
+			T::CircuitAdminOrigin::ensure_origin(origin)?;
 
 			crate::application::services::disclosure_service::DisclosureService::set_verifying_key::<
 				T,
@@ -935,14 +1061,14 @@ pub mod pallet {
 		/// Assets must be verified before they can be used in shield/unshield operations.
 		///
 		/// # Arguments
-		/// * `origin` - Must be root (governance)
+		/// * `origin` - Must be `AssetAdminOrigin`
 		/// * `name` - Human-readable asset name (max 64 bytes)
 		/// * `symbol` - Asset symbol (max 16 bytes, e.g. "USDT")
 		/// * `decimals` - Number of decimal places (e.g. 18 for most ERC20)
 		/// * `contract_address` - Optional ERC20 contract address for bridged tokens
 		///
 		/// # Errors
-		/// * `BadOrigin` - Caller is not root
+		/// * `BadOrigin` - Caller is not `AssetAdminOrigin`
 		///
 		/// # Events
 		/// * `AssetRegistered` - Asset was successfully registered
@@ -955,7 +1081,7 @@ pub mod pallet {
 			decimals: u8,
 			contract_address: Option<[u8; 20]>,
 		) -> DispatchResult {
-			ensure_root(origin)?;
+			T::AssetAdminOrigin::ensure_origin(origin)?;
 
 			let _asset_id = crate::application::services::asset_service::AssetService::register::<T>(
 				name,
@@ -973,11 +1099,11 @@ pub mod pallet {
 		/// Only verified assets can be shielded/unshielded.
 		///
 		/// # Arguments
-		/// * `origin` - Must be root (governance)
+		/// * `origin` - Must be `AssetAdminOrigin`
 		/// * `asset_id` - The asset to verify
 		///
 		/// # Errors
-		/// * `BadOrigin` - Caller is not root
+		/// * `BadOrigin` - Caller is not `AssetAdminOrigin`
 		/// * `InvalidAssetId` - Asset does not exist
 		///
 		/// # Events
@@ -985,7 +1111,7 @@ pub mod pallet {
 		#[pallet::call_index(10)]
 		#[pallet::weight(Weight::from_parts(50_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
 		pub fn verify_asset(origin: OriginFor<T>, asset_id: u32) -> DispatchResult {
-			ensure_root(origin)?;
+			T::AssetAdminOrigin::ensure_origin(origin)?;
 
 			crate::application::services::asset_service::AssetService::verify::<T>(asset_id)
 		}
@@ -996,11 +1122,11 @@ pub mod pallet {
 		/// can still be spent, but new shield operations are prevented.
 		///
 		/// # Arguments
-		/// * `origin` - Must be root (governance)
+		/// * `origin` - Must be `AssetAdminOrigin`
 		/// * `asset_id` - The asset to unverify
 		///
 		/// # Errors
-		/// * `BadOrigin` - Caller is not root
+		/// * `BadOrigin` - Caller is not `AssetAdminOrigin`
 		/// * `InvalidAssetId` - Asset does not exist
 		///
 		/// # Events
@@ -1008,10 +1134,42 @@ pub mod pallet {
 		#[pallet::call_index(11)]
 		#[pallet::weight(Weight::from_parts(50_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
 		pub fn unverify_asset(origin: OriginFor<T>, asset_id: u32) -> DispatchResult {
-			ensure_root(origin)?;
+			T::AssetAdminOrigin::ensure_origin(origin)?;
 
 			crate::application::services::asset_service::AssetService::unverify::<T>(asset_id)
 		}
+
+		/// Require (or stop requiring) escrow-mode memos for an asset
+		///
+		/// While required, every shield of the asset must attach a memo that
+		/// declares an escrow slot, i.e. wraps its key to the auditor designated
+		/// by the asset's policy. Private transfers hide the asset, so the
+		/// requirement is enforced where notes enter the pool.
+		///
+		/// # Arguments
+		/// * `origin` - Must be `AssetAdminOrigin`
+		/// * `asset_id` - The asset to configure
+		/// * `required` - Whether memos must carry an escrow slot
+		///
+		/// # Errors
+		/// * `BadOrigin` - Caller is not `AssetAdminOrigin`
+		/// * `InvalidAssetId` - Asset does not exist
+		///
+		/// # Events
+		/// * `MemoEscrowRequirementSet` - Requirement was updated
+		#[pallet::call_index(14)]
+		#[pallet::weight(Weight::from_parts(50_000, 0) + T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_memo_escrow_required(
+			origin: OriginFor<T>,
+			asset_id: u32,
+			required: bool,
+		) -> DispatchResult {
+			T::AssetAdminOrigin::ensure_origin(origin)?;
+
+			crate::application::services::asset_service::AssetService::set_memo_escrow_required::<T>(
+				asset_id, required,
+			)
+		}
 	}
 
 	// ========================================================================
@@ -1120,13 +1278,27 @@ pub mod pallet {
 			)
 		}
 
-		/// Get leaf index for a commitment (Linear scan - expensive, only for RPC)
+		/// Get leaf index for a commitment
+		///
+		/// A single `CommitmentIndex` read once the index backfill has completed.
+		/// Until then a miss scans the leaves the backfill has not reached, so
+		/// callers dispatching inserts add [`Self::leaf_index_scan_weight`].
 		pub fn get_leaf_index(commitment: &Commitment) -> Option<u32> {
 			crate::infrastructure::services::merkle_tree_service::MerkleTreeService::find_leaf_index::<
 				T,
 			>(commitment)
 		}
 
+		/// Extra weight of the duplicate check for `commitments` new leaves
+		///
+		/// `insert_leaf` looks every commitment up before inserting it; a new
+		/// commitment misses `CommitmentIndex` and scans the unindexed window.
+		pub fn leaf_index_scan_weight(commitments: u32) -> Weight {
+			let window =
+				crate::infrastructure::repositories::MerkleRepository::unindexed_leaves::<T>();
+			T::DbWeight::get().reads(u64::from(window).saturating_mul(u64::from(commitments)))
+		}
+
 		/// Verify disclosure proof (cryptographic verification)
 		///
 		/// Realiza verificación criptográfica completa del ZK proof de disclosure.
@@ -1,7 +1,11 @@
 //! Mock runtime for testing pallet-shielded-pool
 
 use crate as pallet_shielded_pool;
-use frame_support::{PalletId, derive_impl, parameter_types, traits::ConstU128};
+use frame_support::{
+	PalletId, derive_impl, ord_parameter_types, parameter_types,
+	traits::{ConstU128, EitherOfDiverse},
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use pallet_zk_verifier::ZkVerifierPort;
 use sp_runtime::BuildStorage;
 
@@ -21,6 +25,7 @@ frame_support::construct_runtime!(
 impl frame_system::Config for Test {
 	type Block = Block;
 	type AccountData = pallet_balances::AccountData<u128>;
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
@@ -40,9 +45,15 @@ parameter_types! {
 	pub const RequestExpiration: u64 = 1000;
 }
 
+ord_parameter_types! {
+	/// Account holding the asset admin origin, besides root
+	pub const AssetAdmin: u64 = 9;
+}
+
 impl pallet_zk_verifier::Config for Test {
 	type MaxProofSize = MaxProofSize;
 	type MaxPublicInputs = MaxPublicInputs;
+	type CircuitAdminOrigin = EnsureRoot<u64>;
 	type WeightInfo = pallet_zk_verifier::weights::SubstrateWeight<Test>;
 }
 
@@ -74,6 +85,7 @@ impl ZkVerifierPort for MockZkVerifier {
 		_nullifier: &[u8; 32],
 		_amount: u128,
 		_recipient: &[u8; 32],
+		_recipient_binding: &[u8; 32],
 		_asset_id: u32,
 		_version: Option<u32>,
 	) -> Result<bool, sp_runtime::DispatchError> {
@@ -129,8 +141,69 @@ impl ZkVerifierPort for MockZkVerifier {
 		// Always return true for testing (bypass ZK verification)
 		Ok(true)
 	}
+
+	fn verify_viewing_key_proof(
+		proof: &[u8],
+		_viewing_key_hash: &[u8; 32],
+		_commitment: &[u8; 32],
+		_version: Option<u32>,
+	) -> Result<bool, sp_runtime::DispatchError> {
+		// Validate basic format
+		if proof.is_empty() {
+			return Err(sp_runtime::DispatchError::Other("Empty proof"));
+		}
+		// Always return true for testing (bypass ZK verification)
+		Ok(true)
+	}
+
+	fn transfer_proof_job(
+		proof: &[u8],
+		merkle_root: &[u8; 32],
+		nullifiers: &[[u8; 32]],
+		commitments: &[[u8; 32]],
+	) -> Option<pallet_zk_verifier::ProofJob> {
+		let inputs: sp_std::vec::Vec<[u8; 32]> = core::iter::once(*merkle_root)
+			.chain(nullifiers.iter().copied())
+			.chain(commitments.iter().copied())
+			.collect();
+		Some(pallet_zk_verifier::ProofJob::new(
+			MOCK_TRANSFER_VK.to_vec(),
+			proof.to_vec(),
+			&inputs,
+		))
+	}
+
+	fn unshield_proof_job(
+		proof: &[u8],
+		merkle_root: &[u8; 32],
+		nullifier: &[u8; 32],
+		amount: u128,
+		_recipient: &[u8; 32],
+		recipient_binding: &[u8; 32],
+		asset_id: u32,
+	) -> Option<pallet_zk_verifier::ProofJob> {
+		let mut amount_bytes = [0u8; 32];
+		amount_bytes[..16].copy_from_slice(&amount.to_le_bytes());
+		let mut asset_id_bytes = [0u8; 32];
+		asset_id_bytes[..4].copy_from_slice(&asset_id.to_le_bytes());
+		Some(pallet_zk_verifier::ProofJob::new(
+			MOCK_UNSHIELD_VK.to_vec(),
+			proof.to_vec(),
+			&[
+				*merkle_root,
+				*nullifier,
+				amount_bytes,
+				*recipient_binding,
+				asset_id_bytes,
+			],
+		))
+	}
 }
 
+/// Verification keys reported in the mock's proof jobs
+pub const MOCK_TRANSFER_VK: [u8; 4] = *b"xfer";
+pub const MOCK_UNSHIELD_VK: [u8; 4] = *b"unsh";
+
 impl pallet_shielded_pool::Config for Test {
 	type Currency = Balances;
 	type ZkVerifier = MockZkVerifier;
@@ -138,6 +211,9 @@ impl pallet_shielded_pool::Config for Test {
 	type MaxTreeDepth = MaxTreeDepth;
 	type MaxHistoricRoots = MaxHistoricRoots;
 	type MinShieldAmount = MinShieldAmount;
+	type DisclosureRequestExpiration = RequestExpiration;
+	type CircuitAdminOrigin = EnsureRoot<u64>;
+	type AssetAdminOrigin = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<AssetAdmin, u64>>;
 	type WeightInfo = ();
 }
 
@@ -157,7 +233,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	// Initialize ShieldedPool genesis
 	crate::GenesisConfig::<Test> {
 		initial_root: [0u8; 32],
-		_phantom: Default::default(),
+		..Default::default()
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
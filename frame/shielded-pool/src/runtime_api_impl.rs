@@ -3,8 +3,15 @@
 //! This module implements the ShieldedPoolRuntimeApi trait defined in the runtime-api crate.
 //! These functions are callable from RPC without executing transactions.
 
-use crate::{Commitment, DefaultMerklePath, Hash, Pallet, pallet::Config};
+use crate::{
+	AuditPolicy, AuditTrail, BalanceOf, Call, Commitment, DefaultMerklePath, DisclosureProof,
+	DisclosureRequest, EncodedAssetMetadata, Event, Hash, Nullifier, Pallet, PoolStats, ProofJob,
+	TransferValidation, pallet::Config,
+};
+use alloc::{vec, vec::Vec};
 use frame_support::traits::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::UniqueSaturatedInto;
 
 impl<T: Config> Pallet<T> {
 	/// Get Merkle tree information (root, size, depth)
@@ -34,11 +41,8 @@ impl<T: Config> Pallet<T> {
 
 	/// Get Merkle proof for a given commitment
 	///
-	/// This scans all leaves in the tree to find the commitment.
+	/// The leaf is located with a single `CommitmentIndex` read.
 	/// Returns (leaf_index, proof) if found, None otherwise.
-	///
-	/// Note: This is expensive as it requires scanning all leaves.
-	/// Should be used sparingly or cached off-chain.
 	pub fn get_merkle_proof_for_commitment(commitment: Hash) -> Option<(u32, DefaultMerklePath)> {
 		let commitment_wrapped = Commitment(commitment);
 
@@ -52,4 +56,272 @@ impl<T: Config> Pallet<T> {
 
 		Some((leaf_index, proof))
 	}
+
+	/// Get the leaf index of a commitment
+	///
+	/// Reads the `CommitmentIndex` map instead of scanning the leaves.
+	/// Returns None if the commitment is not in the tree.
+	pub fn get_commitment_index(commitment: Hash) -> Option<u32> {
+		crate::infrastructure::repositories::MerkleRepository::get_commitment_index::<T>(
+			&Commitment(commitment),
+		)
+	}
+
+	/// Get the encrypted memo stored for a commitment
+	///
+	/// Returns None if no memo is stored for the commitment.
+	pub fn get_encrypted_memo(commitment: Hash) -> Option<Vec<u8>> {
+		crate::infrastructure::repositories::CommitmentRepository::get_memo::<T>(&Commitment(
+			commitment,
+		))
+		.map(|memo| memo.as_bytes().to_vec())
+	}
+
+	/// Get the commitments of leaves `start_leaf..start_leaf + count`
+	///
+	/// Stops at the end of the tree.
+	pub fn get_leaves(start_leaf: u32, count: u32) -> Vec<Hash> {
+		use crate::infrastructure::repositories::MerkleRepository;
+
+		let end = start_leaf
+			.saturating_add(count)
+			.min(MerkleRepository::get_tree_size::<T>());
+
+		(start_leaf..end)
+			.filter_map(|leaf_index| MerkleRepository::get_leaf::<T>(leaf_index))
+			.map(|commitment| commitment.0)
+			.collect()
+	}
+
+	/// Get the Merkle tree frontier, leaf level first
+	///
+	/// Rebuilds the tree from all leaves; intended for RPC use only.
+	pub fn get_tree_frontier() -> Vec<Hash> {
+		crate::infrastructure::services::merkle_tree_service::MerkleTreeService::get_frontier::<T>()
+	}
+
+	/// Get leaves `start_leaf..start_leaf + count` with their encrypted memos
+	///
+	/// Returns (leaf_index, commitment, memo) entries, stopping at the end of
+	/// the tree. Used by wallets recovering from seed, which trial-decrypt
+	/// every memo.
+	pub fn get_memos_range(start_leaf: u32, count: u32) -> Vec<(u32, Hash, Option<Vec<u8>>)> {
+		use crate::infrastructure::repositories::{CommitmentRepository, MerkleRepository};
+
+		let end = start_leaf
+			.saturating_add(count)
+			.min(MerkleRepository::get_tree_size::<T>());
+
+		(start_leaf..end)
+			.filter_map(|leaf_index| {
+				let commitment = MerkleRepository::get_leaf::<T>(leaf_index)?;
+				let memo = CommitmentRepository::get_memo::<T>(&commitment)
+					.map(|memo| memo.as_bytes().to_vec());
+				Some((leaf_index, commitment.0, memo))
+			})
+			.collect()
+	}
+
+	/// Get the historic roots window, oldest first
+	///
+	/// Returns each root still accepted for proofs together with the block
+	/// it was produced in. The first root is the next one to be pruned.
+	pub fn get_historic_roots() -> Vec<(Hash, u64)> {
+		use crate::infrastructure::repositories::MerkleRepository;
+
+		MerkleRepository::get_historic_roots_order::<T>()
+			.into_iter()
+			.map(|root| {
+				let block = MerkleRepository::get_historic_root_block::<T>(&root);
+				(root, block.unique_saturated_into())
+			})
+			.collect()
+	}
+
+	/// List all registered assets, ordered by asset_id
+	///
+	/// Includes unverified assets; check `is_verified` before accepting one.
+	pub fn list_assets() -> Vec<(u32, EncodedAssetMetadata)> {
+		crate::infrastructure::repositories::AssetRepository::list_assets::<T>()
+			.into_iter()
+			.map(|asset| (asset.id, asset.into_encoded()))
+			.collect()
+	}
+
+	/// Get the audit policy of an account (None if it has none)
+	pub fn get_audit_policy(
+		account: T::AccountId,
+	) -> Option<AuditPolicy<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+		crate::infrastructure::repositories::AuditRepository::get_audit_policy::<T>(&account)
+	}
+
+	/// Get the pending disclosure requests targeting an account
+	///
+	/// Returns one request per auditor, in storage order.
+	pub fn get_disclosure_requests(
+		target: T::AccountId,
+	) -> Vec<DisclosureRequest<T::AccountId, BlockNumberFor<T>>> {
+		crate::infrastructure::repositories::AuditRepository::get_disclosure_requests_for::<T>(
+			&target,
+		)
+	}
+
+	/// Get the disclosure requests targeting an account that can still be approved
+	///
+	/// Each request comes with the last block it can be approved in.
+	pub fn get_pending_disclosures(
+		target: T::AccountId,
+	) -> Vec<(
+		DisclosureRequest<T::AccountId, BlockNumberFor<T>>,
+		BlockNumberFor<T>,
+	)> {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let expiration = T::DisclosureRequestExpiration::get();
+
+		Self::get_disclosure_requests(target)
+			.into_iter()
+			.map(|request| {
+				let expires_at = request.expires_at(expiration);
+				(request, expires_at)
+			})
+			.filter(|(_, expires_at)| *expires_at >= current_block)
+			.collect()
+	}
+
+	/// Get the approved disclosure proof of a commitment (None if not disclosed)
+	pub fn get_disclosure_proof(commitment: Hash) -> Option<DisclosureProof> {
+		crate::infrastructure::repositories::AuditRepository::get_disclosure_proof::<T>(Commitment(
+			commitment,
+		))
+	}
+
+	/// Get the audit trail entry recorded under `trail_hash` (None if unknown)
+	pub fn get_audit_trail(
+		trail_hash: Hash,
+	) -> Option<AuditTrail<T::AccountId, BlockNumberFor<T>>> {
+		crate::infrastructure::repositories::AuditRepository::get_audit_trail::<T>(&trail_hash)
+	}
+
+	/// Get shielded pool statistics
+	///
+	/// The balance is the balance of `asset_id`, or the total pool balance
+	/// when no asset is given. All other fields are pool-wide.
+	pub fn get_pool_stats(asset_id: Option<u32>) -> PoolStats {
+		use crate::infrastructure::repositories::{MerkleRepository, PoolBalanceRepository};
+
+		let balance = match asset_id {
+			Some(asset_id) => PoolBalanceRepository::get_asset_balance::<T>(asset_id),
+			None => PoolBalanceRepository::get_total_balance::<T>(),
+		};
+
+		PoolStats {
+			asset_id,
+			balance: balance.unique_saturated_into(),
+			merkle_root: MerkleRepository::get_poseidon_root::<T>(),
+			commitment_count: MerkleRepository::get_tree_size::<T>(),
+			tree_depth: T::MaxTreeDepth::get(),
+			historic_roots: MerkleRepository::get_historic_roots_order::<T>().len() as u32,
+			max_historic_roots: T::MaxHistoricRoots::get(),
+		}
+	}
+
+	/// Get the block in which a nullifier was spent
+	///
+	/// Returns None if the nullifier is not in the nullifier set.
+	pub fn get_nullifier_spent_block(nullifier: Hash) -> Option<u64> {
+		crate::infrastructure::repositories::NullifierRepository::get_usage_block::<T>(&Nullifier(
+			nullifier,
+		))
+		.map(|block| block.unique_saturated_into())
+	}
+
+	/// Get the number of spent nullifiers
+	pub fn get_spent_nullifier_count() -> u32 {
+		crate::infrastructure::repositories::NullifierRepository::count_used::<T>()
+	}
+
+	/// Dry-run the checks of `private_transfer` on the given inputs
+	///
+	/// Reports whether the root is known, which nullifiers are already spent
+	/// and whether the proof verifies, without writing storage.
+	pub fn validate_transfer(
+		proof: Vec<u8>,
+		merkle_root: Hash,
+		nullifiers: Vec<Hash>,
+		commitments: Vec<Hash>,
+	) -> TransferValidation {
+		let nullifiers: Vec<Nullifier> = nullifiers.into_iter().map(Nullifier).collect();
+		let commitments: Vec<Commitment> = commitments.into_iter().map(Commitment).collect();
+
+		crate::application::services::transfer_service::TransferService::validate::<T>(
+			&proof,
+			merkle_root,
+			&nullifiers,
+			&commitments,
+		)
+	}
+
+	/// Get the nullifiers spent in the current block
+	///
+	/// Reads the block's `PrivateTransfer` and `Unshielded` events, so it is
+	/// only meaningful when called against a block's post-state (e.g. from RPC).
+	pub fn get_spent_nullifiers() -> Vec<Hash>
+	where
+		<T as frame_system::Config>::RuntimeEvent: TryInto<Event<T>>,
+	{
+		frame_system::Pallet::<T>::read_events_no_consensus()
+			.filter_map(|record| {
+				let record = *record;
+				record.event.try_into().ok()
+			})
+			.flat_map(|event| match event {
+				Event::PrivateTransfer { nullifiers, .. } => nullifiers
+					.into_iter()
+					.map(|nullifier| nullifier.0)
+					.collect(),
+				Event::Unshielded { nullifier, .. } => vec![nullifier.0],
+				_ => Vec::new(),
+			})
+			.collect()
+	}
+
+	/// Get the proof verifications a call will perform
+	///
+	/// Covers `private_transfer` and `unshield`. Evaluated against the state
+	/// the call will execute on, so the node can verify a block's proofs in
+	/// parallel before importing it.
+	pub fn proof_jobs(call: &Call<T>) -> Vec<ProofJob> {
+		use crate::application::services::{
+			transfer_service::TransferService, unshield_service::UnshieldService,
+		};
+
+		let job = match call {
+			Call::private_transfer {
+				proof,
+				merkle_root,
+				nullifiers,
+				commitments,
+				..
+			} => TransferService::proof_job::<T>(proof, merkle_root, nullifiers, commitments),
+			Call::unshield {
+				proof,
+				merkle_root,
+				nullifier,
+				asset_id,
+				amount,
+				recipient,
+				recipient_nonce,
+			} => UnshieldService::proof_job::<T>(
+				proof,
+				merkle_root,
+				nullifier,
+				*asset_id,
+				*amount,
+				recipient,
+				recipient_nonce,
+			),
+			_ => None,
+		};
+		job.into_iter().collect()
+	}
 }
@@ -4,7 +4,10 @@
 
 use crate::{
 	domain::{Commitment, Nullifier, value_objects::Hash},
-	infrastructure::frame_types::{EncryptedMemo, MAX_ENCRYPTED_MEMO_SIZE},
+	infrastructure::frame_types::{
+		ESCROW_MEMO_SIZE, ESCROW_MEMO_VERSION, EncryptedMemo, MEMO_POINTER_SIZE,
+		MEMO_POINTER_VERSION, STANDARD_MEMO_SIZE,
+	},
 };
 use frame_support::BoundedVec;
 
@@ -23,6 +26,11 @@ pub fn sample_nullifier() -> Nullifier {
 	Nullifier([2u8; 32])
 }
 
+/// Generate a sample unshield recipient nonce for testing
+pub fn sample_recipient_nonce() -> [u8; 32] {
+	[3u8; 32]
+}
+
 /// Generate a sample Merkle root for testing
 pub fn sample_merkle_root() -> Hash {
 	[0u8; 32]
@@ -31,7 +39,7 @@ pub fn sample_merkle_root() -> Hash {
 /// Generate a sample encrypted memo with pattern data
 pub fn sample_encrypted_memo() -> EncryptedMemo {
 	// Create a 104-byte memo filled with test data (nonce(12) + data(76) + MAC(16))
-	let mut memo_bytes = vec![0u8; MAX_ENCRYPTED_MEMO_SIZE as usize];
+	let mut memo_bytes = vec![0u8; STANDARD_MEMO_SIZE as usize];
 	// Fill with some pattern for testing
 	for (i, byte) in memo_bytes.iter_mut().enumerate() {
 		*byte = (i % 256) as u8;
@@ -42,6 +50,46 @@ pub fn sample_encrypted_memo() -> EncryptedMemo {
 /// Generate a sample encrypted memo with a specific seed
 pub fn sample_encrypted_memo_with_seed(seed: u8) -> EncryptedMemo {
 	// Create a 104-byte memo with a specific seed
-	let memo_bytes = vec![seed; MAX_ENCRYPTED_MEMO_SIZE as usize];
+	let memo_bytes = vec![seed; STANDARD_MEMO_SIZE as usize];
+	EncryptedMemo(BoundedVec::try_from(memo_bytes).expect("Memo size is correct; qed"))
+}
+
+/// Generate a sample escrow-mode memo (header + escrow slot + standard memo)
+pub fn sample_escrow_memo() -> EncryptedMemo {
+	let mut memo_bytes = vec![7u8; ESCROW_MEMO_SIZE as usize];
+	memo_bytes[0] = ESCROW_MEMO_VERSION;
+	EncryptedMemo(BoundedVec::try_from(memo_bytes).expect("Memo size is correct; qed"))
+}
+
+/// Generate a compressed V2 memo, as sealed by `orbinum-encrypted-memo`
+pub fn sample_compressed_memo() -> EncryptedMemo {
+	let memo = orbinum_encrypted_memo::MemoDataV2::new(1000, [1u8; 32], [0u8; 32], 0, 0, [0u8; 16]);
+	let memo_bytes = orbinum_encrypted_memo::encrypt_memo_v2_compressed(
+		&memo, &[3u8; 32], &[4u8; 32], &[5u8; 12],
+	)
+	.expect("Memo encrypts; qed");
+	EncryptedMemo(BoundedVec::try_from(memo_bytes).expect("Memo size is correct; qed"))
+}
+
+pub fn sample_memo_pointer() -> EncryptedMemo {
+	let mut memo_bytes = vec![9u8; MEMO_POINTER_SIZE as usize];
+	memo_bytes[0] = MEMO_POINTER_VERSION;
+	EncryptedMemo(BoundedVec::try_from(memo_bytes).expect("Memo size is correct; qed"))
+}
+
+/// Generate a versioned memo envelope of `size` bytes headed by `version`
+pub fn sample_versioned_memo(version: u8, size: usize) -> EncryptedMemo {
+	let mut memo_bytes = vec![7u8; size];
+	memo_bytes[0] = version;
+	EncryptedMemo(BoundedVec::try_from(memo_bytes).expect("Memo size is correct; qed"))
+}
+
+/// Generate a multi-recipient memo for `recipients` recipients
+pub fn sample_multi_recipient_memo(recipients: usize) -> EncryptedMemo {
+	let size = orbinum_encrypted_memo::MIN_MULTI_RECIPIENT_MEMO_SIZE
+		+ (recipients - 1) * orbinum_encrypted_memo::WRAPPED_MEMO_KEY_SIZE;
+	let mut memo_bytes = vec![7u8; size];
+	memo_bytes[0] = orbinum_encrypted_memo::MEMO_VERSION_MULTI;
+	memo_bytes[1] = recipients as u8;
 	EncryptedMemo(BoundedVec::try_from(memo_bytes).expect("Memo size is correct; qed"))
 }
@@ -8,10 +8,10 @@ use crate::{
 		entities::audit::AuditPolicy,
 		value_objects::audit::{Auditor, DisclosureCondition},
 	},
-	infrastructure::frame_types::{EncryptedMemo, MAX_ENCRYPTED_MEMO_SIZE},
+	infrastructure::frame_types::{EncryptedMemo, STANDARD_MEMO_SIZE},
 	mock::*,
 };
-use frame_support::{BoundedVec, assert_noop, assert_ok};
+use frame_support::{BoundedVec, assert_noop, assert_ok, traits::Get};
 
 // ============================================================================
 
@@ -176,7 +176,7 @@ fn approve_disclosure_works() {
 
 		// First, create a shield transaction to insert the commitment with a memo
 		let amount = 200u128;
-		let memo = vec![1u8; MAX_ENCRYPTED_MEMO_SIZE as usize];
+		let memo = vec![1u8; STANDARD_MEMO_SIZE as usize];
 		let encrypted_memo = EncryptedMemo::new(memo).unwrap();
 
 		assert_ok!(ShieldedPool::shield(
@@ -339,7 +339,7 @@ fn batch_submit_disclosure_proofs_works() {
 			let commitment = Commitment([i as u8; 32]);
 
 			// Setup required states for each (commitment memo exists)
-			let memo = vec![1u8; MAX_ENCRYPTED_MEMO_SIZE as usize];
+			let memo = vec![1u8; STANDARD_MEMO_SIZE as usize];
 			crate::CommitmentMemos::<Test>::insert(commitment, EncryptedMemo::new(memo).unwrap());
 
 			// Construct valid 76-byte public signals
@@ -393,7 +393,7 @@ fn batch_submit_disclosure_stress_test() {
 		let mut submissions = vec![];
 		for i in 0..10 {
 			let commitment = Commitment([i as u8; 32]);
-			let memo = vec![1u8; MAX_ENCRYPTED_MEMO_SIZE as usize];
+			let memo = vec![1u8; STANDARD_MEMO_SIZE as usize];
 			crate::CommitmentMemos::<Test>::insert(commitment, EncryptedMemo::new(memo).unwrap());
 
 			// Construct valid 76-byte public signals
@@ -440,7 +440,7 @@ fn make_signals(commitment: &Commitment) -> Vec<u8> {
 
 /// Shield a commitment so it exists in CommitmentMemos.
 fn shield_commitment(who: u64, commitment: Commitment) {
-	let memo = vec![1u8; MAX_ENCRYPTED_MEMO_SIZE as usize];
+	let memo = vec![1u8; STANDARD_MEMO_SIZE as usize];
 	let encrypted_memo = EncryptedMemo::new(memo).unwrap();
 	assert_ok!(ShieldedPool::shield(
 		RuntimeOrigin::signed(who),
@@ -870,6 +870,58 @@ fn approve_disclosure_fails_request_not_found() {
 	});
 }
 
+#[test]
+fn approve_disclosure_fails_request_expired() {
+	new_test_ext().execute_with(|| {
+		let target = 1u64;
+		let auditor = 2u64;
+		let commitment = Commitment([52u8; 32]);
+
+		shield_commitment(target, commitment);
+		set_vk();
+
+		let auds = BoundedVec::try_from(vec![Auditor::Account(auditor)]).unwrap();
+		let conds = BoundedVec::try_from(vec![DisclosureCondition::Always]).unwrap();
+		assert_ok!(ShieldedPool::set_audit_policy(
+			RuntimeOrigin::signed(target),
+			auds,
+			conds,
+			None,
+		));
+		let reason = BoundedVec::try_from(b"audit".to_vec()).unwrap();
+		assert_ok!(ShieldedPool::request_disclosure(
+			RuntimeOrigin::signed(auditor),
+			target,
+			reason,
+			None,
+		));
+
+		// Request made at block 1 expires after RequestExpiration blocks
+		System::set_block_number(2 + RequestExpiration::get());
+
+		let zk_proof = BoundedVec::try_from(vec![1u8; 256]).unwrap();
+		let disclosed_data = BoundedVec::try_from(vec![2u8; 50]).unwrap();
+
+		assert_noop!(
+			ShieldedPool::approve_disclosure(
+				RuntimeOrigin::signed(target),
+				auditor,
+				commitment,
+				zk_proof,
+				disclosed_data,
+			),
+			Error::<Test>::DisclosureRequestExpired
+		);
+
+		// An expired request can still be rejected
+		assert_ok!(ShieldedPool::reject_disclosure(
+			RuntimeOrigin::signed(target),
+			auditor,
+			BoundedVec::try_from(b"expired".to_vec()).unwrap(),
+		));
+	});
+}
+
 #[test]
 fn approve_disclosure_fails_invalid_proof_when_commitment_not_shielded() {
 	new_test_ext().execute_with(|| {
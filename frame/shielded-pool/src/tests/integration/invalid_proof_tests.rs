@@ -57,6 +57,7 @@ fn unshield_should_fail_with_invalid_proof() {
 				0, // asset_id
 				5_000u128,
 				alice,
+				sample_recipient_nonce(),
 			),
 			Error::<Test>::InvalidProof
 		);
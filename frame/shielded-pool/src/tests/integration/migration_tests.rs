@@ -0,0 +1,154 @@
+//! Storage migration tests
+
+use crate::{
+	Commitment, CommitmentIndex, CommitmentIndexBackfillCursor, CommitmentIndexComplete,
+	MerkleLeaves, MerkleTreeSize, Nullifier, NullifierCount, NullifierCountBackfill, NullifierSet,
+	Pallet,
+	infrastructure::{
+		migrations::{v1::MigrateToV1, v2},
+		repositories::NullifierRepository,
+	},
+	mock::*,
+};
+use frame_support::{
+	traits::{Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+
+/// Leaves inserted before `CommitmentIndex` existed
+fn insert_unindexed_leaves(commitments: &[Commitment]) {
+	for (index, commitment) in commitments.iter().enumerate() {
+		MerkleLeaves::<Test>::insert(index as u32, commitment);
+	}
+	MerkleTreeSize::<Test>::put(commitments.len() as u32);
+}
+
+#[test]
+fn migrate_to_v1_backfills_commitment_index_across_blocks() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Pallet<Test>>();
+		CommitmentIndexComplete::<Test>::kill();
+		insert_unindexed_leaves(&[
+			Commitment([1u8; 32]),
+			Commitment([2u8; 32]),
+			Commitment([3u8; 32]),
+		]);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+		assert_eq!(CommitmentIndexBackfillCursor::<Test>::get(), Some(0));
+		assert_eq!(CommitmentIndex::<Test>::get(Commitment([1u8; 32])), None);
+
+		// Room for the step overhead and two leaves
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let limit = db.reads_writes(2, 2) + db.reads_writes(4, 2);
+		let used = Pallet::<Test>::on_idle(1, limit);
+		assert!(limit.all_gte(used));
+		assert_eq!(CommitmentIndexBackfillCursor::<Test>::get(), Some(2));
+		assert_eq!(CommitmentIndex::<Test>::get(Commitment([2u8; 32])), Some(1));
+		assert!(!CommitmentIndexComplete::<Test>::get());
+		// The remaining leaf is still found by scanning
+		assert_eq!(ShieldedPool::get_commitment_index([3u8; 32]), Some(2));
+
+		Pallet::<Test>::on_idle(2, limit);
+		assert_eq!(CommitmentIndex::<Test>::get(Commitment([3u8; 32])), Some(2));
+		assert_eq!(CommitmentIndexBackfillCursor::<Test>::get(), None);
+		assert!(CommitmentIndexComplete::<Test>::get());
+	});
+}
+
+#[test]
+fn backfill_waits_for_enough_weight() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Pallet<Test>>();
+		CommitmentIndexComplete::<Test>::kill();
+		insert_unindexed_leaves(&[Commitment([1u8; 32])]);
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Pallet::<Test>::on_idle(1, Weight::zero()), Weight::zero());
+		assert_eq!(CommitmentIndexBackfillCursor::<Test>::get(), Some(0));
+	});
+}
+
+#[test]
+fn migrate_to_v1_completes_index_of_new_chains() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Pallet<Test>>();
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(CommitmentIndexBackfillCursor::<Test>::get(), None);
+		assert!(CommitmentIndexComplete::<Test>::get());
+	});
+}
+
+#[test]
+fn migrate_to_v1_skips_upgraded_storage() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<Pallet<Test>>();
+		insert_unindexed_leaves(&[Commitment([1u8; 32])]);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(CommitmentIndex::<Test>::get(Commitment([1u8; 32])), None);
+	});
+}
+
+#[test]
+fn unindexed_leaves_are_found_until_index_is_complete() {
+	new_test_ext().execute_with(|| {
+		CommitmentIndexComplete::<Test>::kill();
+		insert_unindexed_leaves(&[Commitment([1u8; 32]), Commitment([2u8; 32])]);
+
+		assert_eq!(ShieldedPool::get_commitment_index([2u8; 32]), Some(1));
+		assert_eq!(ShieldedPool::get_commitment_index([3u8; 32]), None);
+
+		CommitmentIndexComplete::<Test>::put(true);
+		assert_eq!(ShieldedPool::get_commitment_index([2u8; 32]), None);
+	});
+}
+
+#[test]
+fn migrate_to_v2_counts_nullifiers_across_blocks() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<Pallet<Test>>();
+		System::set_block_number(4);
+		for seed in 1..=3u8 {
+			NullifierSet::<Test>::insert(Nullifier([seed; 32]), 4);
+		}
+
+		v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 2);
+		assert_eq!(NullifierCountBackfill::<Test>::get(), Some((4, None)));
+
+		// Spent after the upgrade: counted on insert, skipped by the backfill
+		NullifierRepository::mark_as_used::<Test>(Nullifier([9u8; 32]), 5);
+		assert_eq!(NullifierCount::<Test>::get(), 1);
+		// Readers iterate the set until the backfill completes
+		assert_eq!(ShieldedPool::get_spent_nullifier_count(), 4);
+
+		// Room for the step overhead and two entries
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let limit = db.reads_writes(2, 2) + db.reads(2);
+		let used = v2::backfill_nullifier_count::<Test>(limit);
+		assert!(limit.all_gte(used));
+		assert!(NullifierCountBackfill::<Test>::exists());
+
+		v2::backfill_nullifier_count::<Test>(limit);
+		v2::backfill_nullifier_count::<Test>(limit);
+		assert!(!NullifierCountBackfill::<Test>::exists());
+		assert_eq!(NullifierCount::<Test>::get(), 4);
+		assert_eq!(ShieldedPool::get_spent_nullifier_count(), 4);
+	});
+}
+
+#[test]
+fn migrate_to_v2_skips_upgraded_storage() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<Pallet<Test>>();
+
+		v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert!(!NullifierCountBackfill::<Test>::exists());
+	});
+}
@@ -4,7 +4,7 @@
 //! and cross-asset operations.
 
 use crate::{Error, Event, mock::*, tests::helpers::*};
-use frame_support::{BoundedVec, assert_noop, assert_ok};
+use frame_support::{BoundedVec, assert_noop, assert_ok, traits::Get};
 
 #[test]
 fn register_asset_works() {
@@ -54,6 +54,37 @@ fn register_asset_requires_root() {
 	});
 }
 
+#[test]
+fn asset_admin_can_manage_assets() {
+	new_test_ext().execute_with(|| {
+		let admin = RuntimeOrigin::signed(AssetAdmin::get());
+		let name = BoundedVec::try_from(b"DAI".to_vec()).unwrap();
+		let symbol = BoundedVec::try_from(b"DAI".to_vec()).unwrap();
+
+		assert_ok!(ShieldedPool::register_asset(
+			admin.clone(),
+			name,
+			symbol,
+			18,
+			None
+		));
+		assert_ok!(ShieldedPool::verify_asset(admin.clone(), 1));
+		assert_ok!(ShieldedPool::set_memo_escrow_required(
+			admin.clone(),
+			1,
+			true
+		));
+		assert_ok!(ShieldedPool::unverify_asset(admin.clone(), 1));
+
+		// The asset admin cannot manage circuit keys
+		let vk = BoundedVec::try_from(vec![1u8; 128]).unwrap();
+		assert_noop!(
+			ShieldedPool::set_disclosure_verifying_key(admin, vk),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
 #[test]
 fn verify_asset_works() {
 	new_test_ext().execute_with(|| {
@@ -269,6 +300,7 @@ fn unshield_with_invalid_asset_fails() {
 				999, // doesn't exist
 				amount,
 				recipient,
+				sample_recipient_nonce(),
 			),
 			Error::<Test>::InvalidAssetId
 		);
@@ -294,6 +326,7 @@ fn unshield_to_pool_account_fails() {
 				0, // native asset
 				amount,
 				pool_account,
+				sample_recipient_nonce(),
 			),
 			Error::<Test>::InvalidRecipient
 		);
@@ -354,6 +387,39 @@ fn native_asset_registered_in_genesis() {
 	});
 }
 
+#[test]
+fn genesis_assets_and_disclosure_key_are_set() {
+	use sp_runtime::BuildStorage;
+
+	let storage = RuntimeGenesisConfig {
+		shielded_pool: crate::GenesisConfig {
+			assets: vec![
+				(b"Test USD".to_vec(), b"tUSD".to_vec(), 6),
+				(b"Test Wrapped Ether".to_vec(), b"tWETH".to_vec(), 18),
+			],
+			disclosure_verifying_key: Some(vec![7u8; 128]),
+			..Default::default()
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		let usd = crate::Assets::<Test>::get(1).expect("Genesis asset should exist");
+		assert_eq!(usd.symbol.to_vec(), b"tUSD".to_vec());
+		assert_eq!(usd.decimals, 6);
+		assert!(usd.is_verified);
+		assert!(crate::Assets::<Test>::get(2).unwrap().is_verified);
+		assert_eq!(crate::NextAssetId::<Test>::get(), 3);
+
+		assert_eq!(
+			crate::DisclosureVerifyingKey::<Test>::get().map(|vk| vk.to_vec()),
+			Some(vec![7u8; 128])
+		);
+	});
+}
+
 #[test]
 fn multiple_assets_can_coexist() {
 	new_test_ext().execute_with(|| {
@@ -4,6 +4,7 @@
 
 use crate::{Commitment, Error, Event, Nullifier, mock::*, tests::helpers::*};
 use frame_support::{BoundedVec, assert_noop, assert_ok, pallet_prelude::ConstU32};
+use orbinum_encrypted_memo::ENCRYPTED_MEMO_V2_SIZE;
 
 #[test]
 fn private_transfer_works() {
@@ -66,6 +67,37 @@ fn private_transfer_works() {
 	});
 }
 
+#[test]
+fn compressed_memos_are_accepted() {
+	new_test_ext().execute_with(|| {
+		let memo = sample_compressed_memo();
+		assert!(memo.is_compressed());
+		assert!(memo.len() < ENCRYPTED_MEMO_V2_SIZE);
+
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0, // native asset
+			1000u128,
+			sample_commitment(),
+			memo.clone(),
+		));
+
+		let encrypted_memos: BoundedVec<_, ConstU32<2>> = vec![memo.clone()].try_into().unwrap();
+		assert_ok!(ShieldedPool::private_transfer(
+			RuntimeOrigin::signed(1),
+			vec![1u8; 64].try_into().unwrap(),
+			crate::PoseidonRoot::<Test>::get(),
+			vec![sample_nullifier()].try_into().unwrap(),
+			vec![Commitment([3u8; 32])].try_into().unwrap(),
+			encrypted_memos,
+		));
+		assert_eq!(
+			crate::CommitmentMemos::<Test>::get(Commitment([3u8; 32])),
+			Some(memo)
+		);
+	});
+}
+
 #[test]
 fn private_transfer_fails_unknown_root() {
 	new_test_ext().execute_with(|| {
@@ -0,0 +1,534 @@
+//! Runtime API tests
+//!
+//! Tests for the read-only queries exposed through `ShieldedPoolRuntimeApi`.
+
+use crate::{
+	AuditTrail, DisclosureProof, Event, Nullifier, NullifierSet, PoolBalance, PoolBalancePerAsset,
+	PoseidonRoot, ProofCheck,
+	domain::value_objects::audit::{Auditor, DisclosureCondition},
+	infrastructure::repositories::NullifierRepository,
+	mock::*,
+	tests::helpers::*,
+};
+use frame_support::{BoundedVec, assert_ok, traits::Get};
+use parity_scale_codec::Encode;
+
+#[test]
+fn audit_queries_return_policy_and_pending_requests() {
+	new_test_ext().execute_with(|| {
+		let target = 1;
+		let auditor = 2;
+		assert_eq!(ShieldedPool::get_audit_policy(target), None);
+		assert!(ShieldedPool::get_disclosure_requests(target).is_empty());
+
+		let auditors = BoundedVec::try_from(vec![Auditor::Account(auditor)]).unwrap();
+		let conditions = BoundedVec::try_from(vec![DisclosureCondition::Always]).unwrap();
+		assert_ok!(ShieldedPool::set_audit_policy(
+			RuntimeOrigin::signed(target),
+			auditors.clone(),
+			conditions.clone(),
+			Some(100),
+		));
+		assert_ok!(ShieldedPool::request_disclosure(
+			RuntimeOrigin::signed(auditor),
+			target,
+			BoundedVec::try_from(b"Regulatory compliance".to_vec()).unwrap(),
+			None,
+		));
+
+		let policy = ShieldedPool::get_audit_policy(target).expect("policy is set");
+		assert_eq!(policy.auditors, auditors);
+		assert_eq!(policy.conditions, conditions);
+		assert_eq!(policy.max_frequency, Some(100));
+
+		let requests = ShieldedPool::get_disclosure_requests(target);
+		assert_eq!(requests.len(), 1);
+		assert_eq!(requests[0].target, target);
+		assert_eq!(requests[0].auditor, auditor);
+
+		assert_eq!(
+			ShieldedPool::get_disclosure_proof(sample_commitment().0),
+			None
+		);
+	});
+}
+
+#[test]
+fn disclosure_queries_return_stored_proof_and_audit_trail() {
+	new_test_ext().execute_with(|| {
+		let commitment = crate::Commitment([42u8; 32]);
+		let trail_hash = [7u8; 32];
+		assert_eq!(ShieldedPool::get_disclosure_proof(commitment.0), None);
+		assert_eq!(ShieldedPool::get_audit_trail(trail_hash), None);
+
+		let proof = DisclosureProof::new(
+			commitment,
+			BoundedVec::try_from(vec![1u8; 256]).unwrap(),
+			BoundedVec::try_from(vec![2u8; 50]).unwrap(),
+			10,
+		);
+		crate::DisclosureProofs::<Test>::insert(commitment, proof.clone());
+		let trail = AuditTrail::new(
+			1,
+			2,
+			5,
+			BoundedVec::try_from(b"selective_disclosure".to_vec()).unwrap(),
+			trail_hash,
+		);
+		crate::AuditTrailStorage::<Test>::insert(trail_hash, trail.clone());
+
+		assert_eq!(
+			ShieldedPool::get_disclosure_proof(commitment.0),
+			Some(proof)
+		);
+		assert_eq!(ShieldedPool::get_audit_trail(trail_hash), Some(trail));
+	});
+}
+
+#[test]
+fn get_pending_disclosures_skips_expired_requests() {
+	new_test_ext().execute_with(|| {
+		let target = 1;
+		let auditors =
+			BoundedVec::try_from(vec![Auditor::Account(2), Auditor::Account(3)]).unwrap();
+		let conditions = BoundedVec::try_from(vec![DisclosureCondition::Always]).unwrap();
+		assert_ok!(ShieldedPool::set_audit_policy(
+			RuntimeOrigin::signed(target),
+			auditors,
+			conditions,
+			None,
+		));
+		let reason = || BoundedVec::try_from(b"audit".to_vec()).unwrap();
+
+		assert_ok!(ShieldedPool::request_disclosure(
+			RuntimeOrigin::signed(2),
+			target,
+			reason(),
+			None,
+		));
+		System::set_block_number(500);
+		assert_ok!(ShieldedPool::request_disclosure(
+			RuntimeOrigin::signed(3),
+			target,
+			reason(),
+			None,
+		));
+
+		let expiration = RequestExpiration::get();
+		let mut pending = ShieldedPool::get_pending_disclosures(target);
+		pending.sort_by_key(|(request, _)| request.auditor);
+		assert_eq!(pending.len(), 2);
+		assert_eq!(pending[0].1, 1 + expiration);
+		assert_eq!(pending[1].1, 500 + expiration);
+
+		System::set_block_number(2 + expiration);
+		let pending = ShieldedPool::get_pending_disclosures(target);
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0].0.auditor, 3);
+	});
+}
+
+#[test]
+fn get_commitment_index_returns_leaf_index() {
+	new_test_ext().execute_with(|| {
+		let commitment = sample_commitment();
+		assert_eq!(ShieldedPool::get_commitment_index(commitment.0), None);
+
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0, // native asset
+			1000u128,
+			commitment,
+			sample_encrypted_memo(),
+		));
+
+		assert_eq!(ShieldedPool::get_commitment_index(commitment.0), Some(0));
+		assert_eq!(
+			ShieldedPool::get_commitment_index(commitment.0),
+			ShieldedPool::get_leaf_index(&commitment)
+		);
+	});
+}
+
+#[test]
+fn get_merkle_proof_for_commitment_uses_commitment_index() {
+	new_test_ext().execute_with(|| {
+		for byte in 1..=3u8 {
+			assert_ok!(ShieldedPool::shield(
+				RuntimeOrigin::signed(1),
+				0, // native asset
+				1000u128,
+				crate::Commitment([byte; 32]),
+				sample_encrypted_memo(),
+			));
+		}
+
+		let (leaf_index, path) = ShieldedPool::get_merkle_proof_for_commitment([2u8; 32])
+			.expect("commitment is in tree");
+		assert_eq!(leaf_index, 1);
+		assert_eq!(Some(path), ShieldedPool::get_merkle_proof(1));
+		assert_eq!(
+			ShieldedPool::get_merkle_proof_for_commitment([9u8; 32]),
+			None
+		);
+	});
+}
+
+#[test]
+fn get_encrypted_memo_returns_stored_memo() {
+	new_test_ext().execute_with(|| {
+		let commitment = sample_commitment();
+		let memo = sample_encrypted_memo();
+		assert_eq!(ShieldedPool::get_encrypted_memo(commitment.0), None);
+
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0, // native asset
+			1000u128,
+			commitment,
+			memo.clone(),
+		));
+
+		assert_eq!(
+			ShieldedPool::get_encrypted_memo(commitment.0),
+			Some(memo.as_bytes().to_vec())
+		);
+	});
+}
+
+#[test]
+fn get_memos_range_returns_leaves_with_memos() {
+	new_test_ext().execute_with(|| {
+		let memo = sample_encrypted_memo();
+		for byte in 1..=3u8 {
+			assert_ok!(ShieldedPool::shield(
+				RuntimeOrigin::signed(1),
+				0, // native asset
+				1000u128,
+				crate::Commitment([byte; 32]),
+				memo.clone(),
+			));
+		}
+
+		let page = ShieldedPool::get_memos_range(1, 10);
+		assert_eq!(
+			page,
+			vec![
+				(1, [2u8; 32], Some(memo.as_bytes().to_vec())),
+				(2, [3u8; 32], Some(memo.as_bytes().to_vec())),
+			]
+		);
+		assert!(ShieldedPool::get_memos_range(3, 10).is_empty());
+		assert_eq!(ShieldedPool::get_memos_range(0, 1).len(), 1);
+	});
+}
+
+#[test]
+fn get_leaves_returns_commitment_range() {
+	new_test_ext().execute_with(|| {
+		for byte in 1..=3u8 {
+			assert_ok!(ShieldedPool::shield(
+				RuntimeOrigin::signed(1),
+				0, // native asset
+				1000u128,
+				crate::Commitment([byte; 32]),
+				sample_encrypted_memo(),
+			));
+		}
+
+		assert_eq!(ShieldedPool::get_leaves(1, 10), vec![[2u8; 32], [3u8; 32]]);
+		assert_eq!(ShieldedPool::get_leaves(0, 1), vec![[1u8; 32]]);
+		assert!(ShieldedPool::get_leaves(3, 10).is_empty());
+	});
+}
+
+#[test]
+fn get_tree_frontier_resumes_tree() {
+	use crate::infrastructure::merkle_tree::IncrementalMerkleTree;
+
+	new_test_ext().execute_with(|| {
+		for byte in 1..=3u8 {
+			assert_ok!(ShieldedPool::shield(
+				RuntimeOrigin::signed(1),
+				0, // native asset
+				1000u128,
+				crate::Commitment([byte; 32]),
+				sample_encrypted_memo(),
+			));
+		}
+
+		let frontier = ShieldedPool::get_tree_frontier();
+		assert_eq!(frontier.len(), 20);
+		assert_eq!(frontier[0], [3u8; 32]);
+
+		// A client resuming from the checkpoint reaches the on-chain root
+		let mut tree = IncrementalMerkleTree::<20> {
+			frontier: frontier.try_into().unwrap(),
+			next_index: 3,
+			root: PoseidonRoot::<Test>::get(),
+		};
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0, // native asset
+			1000u128,
+			crate::Commitment([4u8; 32]),
+			sample_encrypted_memo(),
+		));
+		assert_eq!(tree.insert([4u8; 32]), Ok(3));
+		assert_eq!(tree.root(), PoseidonRoot::<Test>::get());
+	});
+}
+
+#[test]
+fn get_historic_roots_returns_roots_with_blocks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(7);
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0, // native asset
+			1000u128,
+			sample_commitment(),
+			sample_encrypted_memo(),
+		));
+
+		let roots = ShieldedPool::get_historic_roots();
+		assert_eq!(roots.last(), Some(&(PoseidonRoot::<Test>::get(), 7)));
+		assert_eq!(
+			roots.len() as u32,
+			ShieldedPool::get_pool_stats(None).historic_roots
+		);
+	});
+}
+
+#[test]
+fn list_assets_returns_registered_assets_in_order() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ShieldedPool::register_asset(
+			RuntimeOrigin::root(),
+			b"Tether USD".to_vec().try_into().unwrap(),
+			b"USDT".to_vec().try_into().unwrap(),
+			6,
+			None,
+		));
+
+		let assets = ShieldedPool::list_assets();
+		let ids: Vec<u32> = assets.iter().map(|(id, _)| *id).collect();
+		assert_eq!(ids, vec![0, 1]);
+
+		let (_, usdt) = &assets[1];
+		assert_eq!(usdt.symbol.to_vec(), b"USDT".to_vec());
+		assert_eq!(usdt.decimals, 6);
+		assert!(!usdt.is_verified);
+		assert_eq!(
+			usdt.creator,
+			crate::Assets::<Test>::get(1).unwrap().creator.encode()
+		);
+	});
+}
+
+#[test]
+fn get_pool_stats_reports_total_and_asset_balances() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0, // native asset
+			1000u128,
+			sample_commitment(),
+			sample_encrypted_memo(),
+		));
+
+		let total = ShieldedPool::get_pool_stats(None);
+		assert_eq!(total.asset_id, None);
+		assert_eq!(total.balance, PoolBalance::<Test>::get());
+		assert_eq!(total.merkle_root, PoseidonRoot::<Test>::get());
+		assert_eq!(total.commitment_count, 1);
+		assert_eq!(
+			total.tree_depth,
+			<Test as crate::Config>::MaxTreeDepth::get()
+		);
+		assert_eq!(
+			total.max_historic_roots,
+			<Test as crate::Config>::MaxHistoricRoots::get()
+		);
+		assert!(total.historic_roots >= 1);
+
+		let native = ShieldedPool::get_pool_stats(Some(0));
+		assert_eq!(native.asset_id, Some(0));
+		assert_eq!(native.balance, PoolBalancePerAsset::<Test>::get(0));
+		assert_eq!(native.commitment_count, total.commitment_count);
+
+		assert_eq!(ShieldedPool::get_pool_stats(Some(99)).balance, 0);
+	});
+}
+
+#[test]
+fn get_nullifier_spent_block_returns_spend_block() {
+	new_test_ext().execute_with(|| {
+		let nullifier = sample_nullifier();
+		assert_eq!(ShieldedPool::get_nullifier_spent_block(nullifier.0), None);
+
+		NullifierSet::<Test>::insert(nullifier, 42u64);
+
+		assert_eq!(
+			ShieldedPool::get_nullifier_spent_block(nullifier.0),
+			Some(42)
+		);
+	});
+}
+
+#[test]
+fn get_spent_nullifiers_collects_transfer_and_unshield_nullifiers() {
+	new_test_ext().execute_with(|| {
+		let transfer_nullifiers = [Nullifier([1u8; 32]), Nullifier([2u8; 32])];
+		let unshield_nullifier = Nullifier([3u8; 32]);
+
+		System::deposit_event(Event::<Test>::PrivateTransfer {
+			nullifiers: transfer_nullifiers.to_vec().try_into().unwrap(),
+			commitments: Default::default(),
+			encrypted_memos: Default::default(),
+			leaf_indices: Default::default(),
+		});
+		System::deposit_event(Event::<Test>::Shielded {
+			depositor: 1,
+			asset_id: 0,
+			amount: 1000,
+			commitment: sample_commitment(),
+			encrypted_memo: sample_encrypted_memo(),
+			leaf_index: 0,
+		});
+		System::deposit_event(Event::<Test>::Unshielded {
+			nullifier: unshield_nullifier,
+			asset_id: 0,
+			amount: 500,
+			recipient: 2,
+		});
+
+		assert_eq!(
+			ShieldedPool::get_spent_nullifiers(),
+			vec![[1u8; 32], [2u8; 32], [3u8; 32]]
+		);
+	});
+}
+
+#[test]
+fn get_spent_nullifier_count_counts_nullifier_set() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ShieldedPool::get_spent_nullifier_count(), 0);
+
+		NullifierRepository::mark_as_used::<Test>(Nullifier([1u8; 32]), 1u64);
+		NullifierRepository::mark_as_used::<Test>(Nullifier([2u8; 32]), 2u64);
+
+		assert_eq!(ShieldedPool::get_spent_nullifier_count(), 2);
+	});
+}
+
+#[test]
+fn get_spent_nullifiers_is_empty_without_spends() {
+	new_test_ext().execute_with(|| {
+		System::deposit_event(Event::<Test>::Shielded {
+			depositor: 1,
+			asset_id: 0,
+			amount: 1000,
+			commitment: sample_commitment(),
+			encrypted_memo: sample_encrypted_memo(),
+			leaf_index: 0,
+		});
+
+		assert!(ShieldedPool::get_spent_nullifiers().is_empty());
+	});
+}
+
+#[test]
+fn validate_transfer_reports_each_failed_check() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0,
+			1000u128,
+			sample_commitment(),
+			sample_encrypted_memo(),
+		));
+		let root = PoseidonRoot::<Test>::get();
+		let spent = Nullifier([1u8; 32]);
+		let fresh = Nullifier([2u8; 32]);
+		let outputs = vec![[3u8; 32], [4u8; 32]];
+
+		let valid =
+			ShieldedPool::validate_transfer(vec![1u8; 64], root, vec![fresh.0], outputs.clone());
+		assert!(valid.is_valid());
+		assert_eq!(valid.proof, ProofCheck::Valid);
+
+		NullifierSet::<Test>::insert(spent, 1u64);
+		let invalid = ShieldedPool::validate_transfer(
+			Vec::new(),
+			[9u8; 32],
+			vec![spent.0, fresh.0],
+			outputs.clone(),
+		);
+		assert!(!invalid.is_valid());
+		assert!(invalid.within_bounds);
+		assert!(!invalid.root_known);
+		assert_eq!(invalid.spent_nullifiers, vec![spent]);
+		assert_eq!(invalid.proof, ProofCheck::VerificationFailed);
+
+		let oversized =
+			ShieldedPool::validate_transfer(vec![1u8; 513], root, vec![fresh.0], outputs);
+		assert!(!oversized.within_bounds);
+		assert_eq!(oversized.proof, ProofCheck::NotChecked);
+
+		// Validation never writes storage
+		assert!(!NullifierSet::<Test>::contains_key(fresh));
+		assert_eq!(crate::MerkleTreeSize::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn proof_jobs_cover_transfer_and_unshield_calls() {
+	new_test_ext().execute_with(|| {
+		let proof = BoundedVec::try_from(vec![1u8; 192]).unwrap();
+
+		let transfer = crate::Call::<Test>::private_transfer {
+			proof: proof.clone(),
+			merkle_root: sample_merkle_root(),
+			nullifiers: BoundedVec::try_from(vec![sample_nullifier()]).unwrap(),
+			commitments: BoundedVec::try_from(vec![sample_commitment()]).unwrap(),
+			encrypted_memos: BoundedVec::try_from(vec![sample_encrypted_memo()]).unwrap(),
+		};
+		let jobs = ShieldedPool::proof_jobs(&transfer);
+		assert_eq!(jobs.len(), 1);
+		assert_eq!(jobs[0].vk, MOCK_TRANSFER_VK.to_vec());
+		assert_eq!(jobs[0].proof, proof.to_vec());
+		assert_eq!(
+			jobs[0].public_inputs,
+			[
+				sample_merkle_root(),
+				sample_nullifier().0,
+				sample_commitment().0
+			]
+			.concat()
+		);
+
+		let unshield = crate::Call::<Test>::unshield {
+			proof: proof.clone(),
+			merkle_root: sample_merkle_root(),
+			nullifier: sample_nullifier(),
+			asset_id: 0,
+			amount: 500,
+			recipient: 2,
+			recipient_nonce: sample_recipient_nonce(),
+		};
+		let jobs = ShieldedPool::proof_jobs(&unshield);
+		assert_eq!(jobs.len(), 1);
+		assert_eq!(jobs[0].vk, MOCK_UNSHIELD_VK.to_vec());
+		assert_eq!(jobs[0].public_inputs.len(), 5 * 32);
+		assert_eq!(&jobs[0].public_inputs[32..64], &sample_nullifier().0);
+
+		let shield = crate::Call::<Test>::shield {
+			asset_id: 0,
+			amount: 1000,
+			commitment: sample_commitment(),
+			encrypted_memo: sample_encrypted_memo(),
+		};
+		assert!(ShieldedPool::proof_jobs(&shield).is_empty());
+	});
+}
@@ -37,6 +37,7 @@ fn shield_works() {
 		System::assert_has_event(
 			Event::Shielded {
 				depositor,
+				asset_id: 0,
 				amount,
 				commitment,
 				encrypted_memo,
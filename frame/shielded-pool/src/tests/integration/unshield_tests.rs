@@ -24,6 +24,7 @@ fn unshield_fails_unknown_root() {
 				0, // native asset
 				amount,
 				recipient,
+				sample_recipient_nonce(),
 			),
 			Error::<Test>::UnknownMerkleRoot
 		);
@@ -62,6 +63,7 @@ fn unshield_fails_nullifier_reuse() {
 				0, // native asset
 				amount,
 				recipient,
+				sample_recipient_nonce(),
 			),
 			Error::<Test>::NullifierAlreadyUsed
 		);
@@ -96,6 +98,7 @@ fn unshield_fails_insufficient_pool_balance() {
 				0, // native asset
 				amount,
 				recipient,
+				sample_recipient_nonce(),
 			),
 			Error::<Test>::InsufficientPoolBalance,
 		);
@@ -131,6 +134,7 @@ fn unshield_works() {
 			0, // native asset
 			unshield_amount,
 			recipient,
+			sample_recipient_nonce(),
 		));
 
 		// Check recipient received funds
@@ -152,6 +156,7 @@ fn unshield_works() {
 		System::assert_has_event(
 			Event::Unshielded {
 				nullifier,
+				asset_id: 0,
 				amount: unshield_amount,
 				recipient,
 			}
@@ -159,3 +164,25 @@ fn unshield_works() {
 		);
 	});
 }
+
+#[test]
+fn recipient_binding_depends_on_recipient_and_nonce() {
+	use crate::application::services::unshield_service::UnshieldService;
+
+	let recipient = [7u8; 32];
+	let nonce = sample_recipient_nonce();
+	let binding = UnshieldService::recipient_binding(&recipient, &nonce);
+
+	assert_eq!(
+		binding,
+		UnshieldService::recipient_binding(&recipient, &nonce)
+	);
+	assert_ne!(
+		binding,
+		UnshieldService::recipient_binding(&[8u8; 32], &nonce)
+	);
+	assert_ne!(
+		binding,
+		UnshieldService::recipient_binding(&recipient, &[4u8; 32])
+	);
+}
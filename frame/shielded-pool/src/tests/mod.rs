@@ -16,6 +16,8 @@
 //! - Unshield operations (withdrawals)
 //! - Audit and disclosure workflows
 //! - Multi-asset support
+//! - Runtime API queries
+//! - Storage migrations
 //!
 //! ### Unit Tests
 //! - Merkle tree operations
@@ -43,8 +45,10 @@ pub mod unit {
 pub mod integration {
 	pub mod audit_tests;
 	pub mod invalid_proof_tests;
+	pub mod migration_tests;
 	pub mod multi_asset_tests;
 	pub mod private_transfer_tests;
+	pub mod runtime_api_tests;
 	pub mod shield_batch_tests;
 	pub mod shield_tests;
 	pub mod unshield_tests;
@@ -0,0 +1,79 @@
+//! Disclosure mask tests
+//!
+//! Tests for the mask checks shared with `orbinum-encrypted-memo`.
+
+use crate::{
+	Error, infrastructure::services::disclosure_validation_service::DisclosureValidationService,
+	mock::*,
+};
+use frame_support::{assert_noop, assert_ok};
+use orbinum_encrypted_memo::{DisclosureMask, ExtendedDisclosureMask, MemoField};
+
+// ============================================================================
+
+#[test]
+fn wallet_masks_are_accepted() {
+	new_test_ext().execute_with(|| {
+		for mask in [
+			DisclosureMask::only_value().to_bitmap(),
+			DisclosureMask::all().to_bitmap(),
+			ExtendedDisclosureMask::all().to_bitmap(),
+		] {
+			assert_ok!(DisclosureValidationService::validate_mask_bitmap::<Test>(
+				mask
+			));
+		}
+	});
+}
+
+#[test]
+fn blinding_mask_is_rejected() {
+	new_test_ext().execute_with(|| {
+		let mask = ExtendedDisclosureMask::all().with(MemoField::Blinding);
+		assert_noop!(
+			DisclosureValidationService::validate_mask_bitmap::<Test>(mask.to_bitmap()),
+			Error::<Test>::InvalidDisclosureMask
+		);
+	});
+}
+
+#[test]
+fn empty_and_unknown_masks_are_rejected() {
+	new_test_ext().execute_with(|| {
+		for mask in [0u8, 0b1000_0001] {
+			assert_noop!(
+				DisclosureValidationService::validate_mask_bitmap::<Test>(mask),
+				Error::<Test>::InvalidDisclosureMask
+			);
+		}
+	});
+}
+
+// ============================================================================
+
+#[test]
+fn decode_mask_bitmap_uses_memo_field_order() {
+	let mask = ExtendedDisclosureMask::none()
+		.with(MemoField::Value)
+		.with(MemoField::AssetId)
+		.to_bitmap();
+	assert_eq!(
+		DisclosureValidationService::decode_mask_bitmap(mask),
+		(true, true, false, true)
+	);
+}
+
+#[test]
+fn disclosed_data_must_cover_revealed_fields() {
+	let mask = ExtendedDisclosureMask::none()
+		.with(MemoField::Value)
+		.with(MemoField::SenderHint)
+		.to_bitmap();
+	assert!(
+		DisclosureValidationService::validate_disclosed_data_structure(&[0u8; 24], mask).is_ok()
+	);
+	assert_eq!(
+		DisclosureValidationService::validate_disclosed_data_structure(&[0u8; 23], mask),
+		Err("Disclosed data too short")
+	);
+}
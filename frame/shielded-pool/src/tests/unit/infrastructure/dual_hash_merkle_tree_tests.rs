@@ -0,0 +1,197 @@
+//! DualHashMerkleTree tests
+//!
+//! The Poseidon subtrees must match the pallet's Poseidon-only tree, and the
+//! Blake2 top must commit to every subtree root.
+
+use crate::{
+	domain::value_objects::MerklePath,
+	infrastructure::{
+		dual_hash_merkle_tree::{
+			DualHashMerkleTree, hash_pair_blake2, inner_path_from_full, outer_zero_hashes,
+		},
+		merkle_tree::{IncrementalMerkleTree, compute_root_from_leaves, get_zero_hash_cached},
+	},
+};
+
+const INNER: usize = 2;
+const OUTER: usize = 2;
+
+type Tree = DualHashMerkleTree<INNER, OUTER>;
+
+/// Canonical leaf bytes (top byte clear, so below the field modulus)
+fn leaf(seed: u8) -> [u8; 32] {
+	let mut bytes = [seed; 32];
+	bytes[31] = 0;
+	bytes
+}
+
+fn leaves(count: u8) -> Vec<[u8; 32]> {
+	(1..=count).map(leaf).collect()
+}
+
+/// Blake2 root over subtree roots, computed level by level
+fn expected_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+	let zeros = outer_zero_hashes::<INNER, OUTER>();
+	let mut nodes = Tree::subtree_roots(leaves);
+	for zero in zeros.iter().take(OUTER) {
+		nodes = nodes
+			.chunks(2)
+			.map(|pair| hash_pair_blake2(&pair[0], pair.get(1).unwrap_or(zero)))
+			.collect();
+	}
+	nodes.first().copied().unwrap_or(zeros[OUTER])
+}
+
+// ============================================================================
+// Initialization
+// ============================================================================
+
+#[test]
+fn empty_tree_roots() {
+	let tree = Tree::new();
+
+	assert_eq!(tree.size(), 0);
+	assert_eq!(tree.capacity(), 16);
+	assert_eq!(tree.subtree_capacity(), 4);
+	assert_eq!(tree.subtree_root(), get_zero_hash_cached(INNER));
+	assert_eq!(tree.root(), outer_zero_hashes::<INNER, OUTER>()[OUTER]);
+	assert_eq!(tree.root(), expected_root(&[]));
+}
+
+// ============================================================================
+// Insertion
+// ============================================================================
+
+#[test]
+fn subtree_roots_match_poseidon_tree() {
+	let mut tree = Tree::new();
+	let leaves = leaves(7);
+
+	for (index, leaf) in leaves.iter().enumerate() {
+		assert_eq!(tree.insert(*leaf), Ok(index as u32));
+
+		let start = index / 4 * 4;
+		assert_eq!(
+			tree.subtree_root(),
+			compute_root_from_leaves::<INNER>(&leaves[start..=index]),
+			"subtree root after leaf {index}"
+		);
+		assert_eq!(tree.root(), expected_root(&leaves[..=index]));
+	}
+}
+
+#[test]
+fn root_differs_from_poseidon_only_tree() {
+	let dual = Tree::from_leaves(&leaves(5)).unwrap();
+	let poseidon = compute_root_from_leaves::<{ INNER + OUTER }>(&leaves(5));
+
+	assert_ne!(dual.root(), poseidon);
+}
+
+#[test]
+fn from_leaves_matches_sequential_inserts() {
+	let mut tree = Tree::new();
+	for leaf in leaves(9) {
+		tree.insert(leaf).unwrap();
+	}
+	let rebuilt = Tree::from_leaves(&leaves(9)).unwrap();
+
+	assert_eq!(rebuilt.root(), tree.root());
+	assert_eq!(rebuilt.subtree_root(), tree.subtree_root());
+	assert_eq!(rebuilt.size(), 9);
+}
+
+#[test]
+fn full_tree_rejects_insert() {
+	let mut tree = Tree::from_leaves(&(0..16u8).map(leaf).collect::<Vec<_>>()).unwrap();
+
+	assert!(tree.is_full());
+	assert_eq!(tree.insert(leaf(99)), Err("Merkle tree is full"));
+	assert!(Tree::from_leaves(&(0..17u8).map(leaf).collect::<Vec<_>>()).is_err());
+}
+
+// ============================================================================
+// Proofs
+// ============================================================================
+
+#[test]
+fn proofs_verify_for_every_leaf() {
+	let leaves = leaves(11);
+	let tree = Tree::from_leaves(&leaves).unwrap();
+
+	for (index, value) in leaves.iter().enumerate() {
+		let (inner, outer) = tree.generate_proof(index as u32, &leaves).unwrap();
+
+		assert!(Tree::verify_proof(&tree.root(), value, &inner, &outer));
+		assert_eq!(
+			Tree::compute_subtree_root(value, &inner),
+			Tree::subtree_roots(&leaves)[index / 4]
+		);
+		assert!(!Tree::verify_proof(
+			&tree.root(),
+			&leaf(200),
+			&inner,
+			&outer
+		));
+	}
+}
+
+#[test]
+fn tampered_outer_path_fails() {
+	let leaves = leaves(6);
+	let tree = Tree::from_leaves(&leaves).unwrap();
+	let (inner, mut outer) = tree.generate_proof(5, &leaves).unwrap();
+
+	outer.siblings[0] = leaf(42);
+	assert!(!Tree::verify_proof(
+		&tree.root(),
+		&leaves[5],
+		&inner,
+		&outer
+	));
+}
+
+#[test]
+fn proof_errors() {
+	let leaves = leaves(3);
+	let tree = Tree::from_leaves(&leaves).unwrap();
+
+	assert_eq!(
+		tree.generate_proof(3, &leaves),
+		Err("Leaf index out of bounds")
+	);
+	assert_eq!(
+		tree.generate_proof(0, &leaves[..2]),
+		Err("Leaves count mismatch")
+	);
+}
+
+// ============================================================================
+// Conversion Helpers
+// ============================================================================
+
+#[test]
+fn full_depth_paths_convert_to_inner_paths() {
+	let leaves = leaves(10);
+	let mut poseidon = IncrementalMerkleTree::<{ INNER + OUTER }>::new();
+	for leaf in &leaves {
+		poseidon.insert(*leaf).unwrap();
+	}
+	let dual = Tree::from_leaves(&leaves).unwrap();
+
+	for index in 0..leaves.len() as u32 {
+		let full = poseidon.generate_proof(index, &leaves).unwrap();
+		let (inner, _) = dual.generate_proof(index, &leaves).unwrap();
+
+		assert_eq!(
+			inner_path_from_full::<{ INNER + OUTER }, INNER>(&full),
+			Some(inner)
+		);
+	}
+}
+
+#[test]
+fn inner_path_longer_than_full_path_is_rejected() {
+	let path = MerklePath::<2>::default();
+	assert_eq!(inner_path_from_full::<2, 3>(&path), None);
+}
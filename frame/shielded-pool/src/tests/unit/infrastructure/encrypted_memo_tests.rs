@@ -3,24 +3,28 @@
 //! Tests for encrypted memo functionality.
 
 use crate::{
-	Commitment, Error,
-	infrastructure::frame_types::{EncryptedMemo, MAX_ENCRYPTED_MEMO_SIZE},
+	Commitment, Error, MemoEscrowRequired,
+	infrastructure::frame_types::{
+		ESCROW_MEMO_SIZE, ESCROW_MEMO_VERSION, EncryptedMemo, MEMO_POINTER_SIZE,
+		MEMO_POINTER_VERSION, STANDARD_MEMO_SIZE,
+	},
 	mock::*,
 	tests::helpers::*,
 };
 use frame_support::{BoundedVec, assert_noop, assert_ok};
+use sp_runtime::DispatchError;
 
 // ============================================================================
 
 #[test]
 fn encrypted_memo_creation_works() {
 	let memo = sample_encrypted_memo();
-	assert_eq!(memo.0.len(), MAX_ENCRYPTED_MEMO_SIZE as usize);
+	assert_eq!(memo.0.len(), STANDARD_MEMO_SIZE as usize);
 }
 
 #[test]
 fn encrypted_memo_from_bytes_works() {
-	let bytes = vec![42u8; MAX_ENCRYPTED_MEMO_SIZE as usize];
+	let bytes = vec![42u8; STANDARD_MEMO_SIZE as usize];
 	let memo = EncryptedMemo::from_bytes(&bytes).unwrap();
 	assert_eq!(memo.as_bytes(), &bytes[..]);
 }
@@ -34,6 +38,74 @@ fn encrypted_memo_from_bytes_fails_wrong_size() {
 	assert!(EncryptedMemo::from_bytes(&bytes_long).is_err());
 }
 
+#[test]
+fn encrypted_memo_escrow_slot_detection() {
+	let escrow = sample_escrow_memo();
+	assert!(escrow.has_escrow_slot());
+	assert!(escrow.is_well_formed());
+	assert!(EncryptedMemo::from_bytes(escrow.as_bytes()).is_ok());
+
+	// Standard memos carry no escrow slot
+	assert!(!sample_encrypted_memo().has_escrow_slot());
+	assert!(sample_encrypted_memo().is_well_formed());
+
+	// Escrow size without the escrow header is not well formed
+	let unheaded = vec![ESCROW_MEMO_VERSION + 1; ESCROW_MEMO_SIZE as usize];
+	assert!(EncryptedMemo::from_bytes(&unheaded).is_err());
+}
+
+#[test]
+fn encrypted_memo_pointer_detection() {
+	let pointer = sample_memo_pointer();
+	assert!(pointer.is_offchain_pointer());
+	assert!(pointer.is_well_formed());
+	assert!(!pointer.has_escrow_slot());
+	assert_eq!(pointer.content_hash(), Some([9u8; 32]));
+	assert!(EncryptedMemo::from_bytes(pointer.as_bytes()).is_ok());
+
+	assert!(!sample_encrypted_memo().is_offchain_pointer());
+	assert_eq!(sample_encrypted_memo().content_hash(), None);
+
+	// Pointer size without the pointer header is not well formed
+	let unheaded = vec![MEMO_POINTER_VERSION + 1; MEMO_POINTER_SIZE as usize];
+	assert!(EncryptedMemo::from_bytes(&unheaded).is_err());
+}
+
+#[test]
+fn encrypted_memo_versioned_envelopes_are_well_formed() {
+	use orbinum_encrypted_memo::{
+		ENCRYPTED_MEMO_EPHEMERAL_SIZE, ENCRYPTED_MEMO_HYBRID_SIZE, ENCRYPTED_MEMO_V2_SIZE,
+		ENCRYPTED_MEMO_V3_SIZE, ENCRYPTED_MEMO_XCHACHA_SIZE, MEMO_VERSION_EPHEMERAL,
+		MEMO_VERSION_HYBRID, MEMO_VERSION_V2, MEMO_VERSION_V3, MEMO_VERSION_XCHACHA,
+	};
+
+	for (version, size) in [
+		(MEMO_VERSION_V2, ENCRYPTED_MEMO_V2_SIZE),
+		(MEMO_VERSION_V3, ENCRYPTED_MEMO_V3_SIZE),
+		(MEMO_VERSION_EPHEMERAL, ENCRYPTED_MEMO_EPHEMERAL_SIZE),
+		(MEMO_VERSION_XCHACHA, ENCRYPTED_MEMO_XCHACHA_SIZE),
+	] {
+		assert!(sample_versioned_memo(version, size).is_well_formed());
+		// Size of one envelope under the header of another is not well formed
+		let mut unheaded = vec![0u8; size];
+		unheaded[0] = version + 100;
+		assert!(EncryptedMemo::from_bytes(&unheaded).is_err());
+	}
+
+	assert!(sample_multi_recipient_memo(1).is_well_formed());
+	assert!(
+		sample_multi_recipient_memo(orbinum_encrypted_memo::MAX_MEMO_RECIPIENTS).is_well_formed()
+	);
+	let mut miscounted = sample_multi_recipient_memo(3).as_bytes().to_vec();
+	miscounted[1] = 2;
+	assert!(EncryptedMemo::from_bytes(&miscounted).is_err());
+
+	// Hybrid memos exceed the on-chain bound and travel behind a pointer
+	let mut hybrid = vec![0u8; ENCRYPTED_MEMO_HYBRID_SIZE];
+	hybrid[0] = MEMO_VERSION_HYBRID;
+	assert!(EncryptedMemo::from_bytes(&hybrid).is_err());
+}
+
 #[test]
 fn encrypted_memo_accessors_work() {
 	let memo = sample_encrypted_memo();
@@ -134,4 +206,140 @@ fn multiple_shield_operations_store_different_memos() {
 	});
 }
 
+#[test]
+fn shield_accepts_versioned_and_multi_recipient_memos() {
+	new_test_ext().execute_with(|| {
+		let memos = [
+			sample_versioned_memo(
+				orbinum_encrypted_memo::MEMO_VERSION_V3,
+				orbinum_encrypted_memo::ENCRYPTED_MEMO_V3_SIZE,
+			),
+			sample_versioned_memo(
+				orbinum_encrypted_memo::MEMO_VERSION_XCHACHA,
+				orbinum_encrypted_memo::ENCRYPTED_MEMO_XCHACHA_SIZE,
+			),
+			sample_versioned_memo(
+				orbinum_encrypted_memo::MEMO_VERSION_EPHEMERAL,
+				orbinum_encrypted_memo::ENCRYPTED_MEMO_EPHEMERAL_SIZE,
+			),
+			sample_multi_recipient_memo(orbinum_encrypted_memo::MAX_MEMO_RECIPIENTS),
+		];
+
+		for (i, memo) in memos.into_iter().enumerate() {
+			let commitment = Commitment([i as u8 + 1; 32]);
+			assert_ok!(ShieldedPool::shield(
+				RuntimeOrigin::signed(1),
+				0, // native asset
+				1000u128,
+				commitment,
+				memo.clone(),
+			));
+			assert_eq!(crate::CommitmentMemos::<Test>::get(commitment), Some(memo));
+		}
+	});
+}
+
+// ============================================================================
+
+#[test]
+fn shield_accepts_escrow_memo() {
+	new_test_ext().execute_with(|| {
+		let commitment = sample_commitment();
+		let memo = sample_escrow_memo();
+
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0, // native asset
+			1000u128,
+			commitment,
+			memo.clone(),
+		));
+		assert_eq!(crate::CommitmentMemos::<Test>::get(commitment), Some(memo));
+	});
+}
+
+#[test]
+fn shield_accepts_offchain_memo_pointer() {
+	new_test_ext().execute_with(|| {
+		let commitment = sample_commitment();
+		let memo = sample_memo_pointer();
+
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0, // native asset
+			1000u128,
+			commitment,
+			memo.clone(),
+		));
+		assert_eq!(
+			crate::CommitmentMemos::<Test>::get(commitment).and_then(|m| m.content_hash()),
+			memo.content_hash()
+		);
+	});
+}
+
+#[test]
+fn shield_requires_escrow_slot_when_asset_requires_it() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ShieldedPool::set_memo_escrow_required(
+			RuntimeOrigin::root(),
+			0,
+			true
+		));
+		assert!(MemoEscrowRequired::<Test>::get(0));
+
+		assert_noop!(
+			ShieldedPool::shield(
+				RuntimeOrigin::signed(1),
+				0,
+				1000u128,
+				Commitment([1u8; 32]),
+				sample_encrypted_memo(),
+			),
+			Error::<Test>::MemoEscrowRequired
+		);
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0,
+			1000u128,
+			Commitment([2u8; 32]),
+			sample_escrow_memo(),
+		));
+
+		// Lifting the requirement accepts standard memos again
+		assert_ok!(ShieldedPool::set_memo_escrow_required(
+			RuntimeOrigin::root(),
+			0,
+			false
+		));
+		assert_ok!(ShieldedPool::shield(
+			RuntimeOrigin::signed(1),
+			0,
+			1000u128,
+			Commitment([3u8; 32]),
+			sample_encrypted_memo(),
+		));
+	});
+}
+
+#[test]
+fn set_memo_escrow_required_requires_root() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ShieldedPool::set_memo_escrow_required(RuntimeOrigin::signed(1), 0, true),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_memo_escrow_required_fails_for_unknown_asset() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ShieldedPool::set_memo_escrow_required(RuntimeOrigin::root(), 999, true),
+			Error::<Test>::InvalidAssetId
+		);
+	});
+}
+
 // ============================================================================
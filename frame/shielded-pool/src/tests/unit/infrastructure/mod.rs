@@ -1,9 +1,13 @@
 //! Infrastructure layer tests
 
+pub mod disclosure_mask_tests;
+#[cfg(feature = "dual-hash-tree")]
+pub mod dual_hash_merkle_tree_tests;
 pub mod encrypted_memo_tests;
 pub mod historic_roots_tests;
 pub mod merkle_tree_implementation_tests;
 pub mod merkle_tree_structure_tests;
 pub mod pool_account_tests;
 pub mod poseidon_host_interface_tests;
+pub mod storage_merkle_repository_tests;
 pub mod type_tests;
@@ -9,7 +9,7 @@
 //! Estos tests solo validan la implementación WASM subyacente que usan los host functions.
 
 use ark_bn254::Fr as Bn254Fr;
-use ark_ff::{BigInteger, PrimeField};
+use ark_ff::PrimeField;
 use orbinum_zk_core::{
 	domain::{ports::PoseidonHasher, value_objects::FieldElement},
 	infrastructure::crypto::LightPoseidonHasher,
@@ -29,10 +29,7 @@ fn test_poseidon_hash_2_produces_valid_output() {
 	let hash_fr = hasher.hash_2([FieldElement::new(left_fr), FieldElement::new(right_fr)]);
 
 	// Convert back to bytes
-	let mut hash_bytes = [0u8; 32];
-	let bigint = hash_fr.inner().into_bigint();
-	let bytes = bigint.to_bytes_le();
-	hash_bytes.copy_from_slice(&bytes[..32]);
+	let hash_bytes = hash_fr.to_le_bytes();
 
 	// Hash should be non-zero
 	assert_ne!(
@@ -55,13 +52,8 @@ fn test_poseidon_hash_2_order_matters() {
 	let hash2_fr = hasher.hash_2([FieldElement::new(right_fr), FieldElement::new(left_fr)]);
 
 	// Convert both to bytes
-	let mut hash1 = [0u8; 32];
-	let bigint1 = hash1_fr.inner().into_bigint();
-	hash1.copy_from_slice(&bigint1.to_bytes_le()[..32]);
-
-	let mut hash2 = [0u8; 32];
-	let bigint2 = hash2_fr.inner().into_bigint();
-	hash2.copy_from_slice(&bigint2.to_bytes_le()[..32]);
+	let hash1 = hash1_fr.to_le_bytes();
+	let hash2 = hash2_fr.to_le_bytes();
 
 	// Hashes should be different
 	assert_ne!(
@@ -91,9 +83,7 @@ fn test_poseidon_hash_4_produces_valid_output() {
 	]);
 
 	// Convert to bytes
-	let mut hash = [0u8; 32];
-	let bigint = hash_fr.inner().into_bigint();
-	hash.copy_from_slice(&bigint.to_bytes_le()[..32]);
+	let hash = hash_fr.to_le_bytes();
 
 	// Hash should be non-zero
 	assert_ne!(
@@ -0,0 +1,101 @@
+//! StorageMerkleRepository tests
+//!
+//! The zk-core `MerkleRepository` adapter over pallet storage must produce the
+//! same roots and paths as the pallet's own tree logic.
+
+use crate::{
+	Commitment, PoseidonRoot,
+	infrastructure::{
+		repositories::PalletMerkleStorage, services::merkle_tree_service::MerkleTreeService,
+	},
+	mock::*,
+	tests::helpers::*,
+};
+use frame_support::assert_ok;
+use orbinum_zk_core::{
+	Commitment as CoreCommitment, InMemoryMerkleRepository, LightPoseidonHasher,
+	StorageMerkleRepository,
+	domain::repositories::MerkleRepository,
+	infrastructure::crypto::merkle::{bytes_to_field, field_to_bytes},
+};
+
+const DEPTH: usize = 20;
+
+type Repository = StorageMerkleRepository<PalletMerkleStorage<Test>, LightPoseidonHasher>;
+
+/// Canonical commitment bytes (top byte clear, so below the field modulus)
+fn commitment(seed: u8) -> Commitment {
+	let mut bytes = [seed; 32];
+	bytes[31] = 0;
+	Commitment(bytes)
+}
+
+fn core_commitment(seed: u8) -> CoreCommitment {
+	CoreCommitment::new(bytes_to_field(&commitment(seed).0))
+}
+
+#[test]
+fn adapter_matches_pallet_roots() {
+	let pallet_root = new_test_ext().execute_with(|| {
+		for seed in 1..=3u8 {
+			assert_ok!(ShieldedPool::shield(
+				RuntimeOrigin::signed(1),
+				0,
+				100u128,
+				commitment(seed),
+				sample_encrypted_memo_with_seed(seed),
+			));
+		}
+		PoseidonRoot::<Test>::get()
+	});
+
+	new_test_ext().execute_with(|| {
+		let mut repository = Repository::new(LightPoseidonHasher, DEPTH);
+		for seed in 1..=3u8 {
+			repository.insert_commitment(core_commitment(seed)).unwrap();
+		}
+
+		assert_eq!(PoseidonRoot::<Test>::get(), pallet_root);
+		assert_eq!(field_to_bytes(&repository.get_root().unwrap()), pallet_root);
+	});
+}
+
+#[test]
+fn adapter_paths_match_pallet_paths() {
+	new_test_ext().execute_with(|| {
+		let mut repository = Repository::new(LightPoseidonHasher, DEPTH);
+		for seed in 1..=5u8 {
+			repository.insert_commitment(core_commitment(seed)).unwrap();
+		}
+
+		for index in 0..5u32 {
+			let expected = MerkleTreeService::get_merkle_path::<Test>(index).unwrap();
+			let path = repository.get_proof(index as u64).unwrap();
+			let siblings: Vec<[u8; 32]> = path.siblings.iter().map(field_to_bytes).collect();
+			assert_eq!(siblings, expected.siblings.to_vec());
+		}
+	});
+}
+
+#[test]
+fn adapter_matches_in_memory_repository() {
+	new_test_ext().execute_with(|| {
+		let mut repository = Repository::new(LightPoseidonHasher, DEPTH);
+		let mut memory = InMemoryMerkleRepository::new();
+
+		for seed in 1..=4u8 {
+			assert_eq!(
+				repository.insert_commitment(core_commitment(seed)),
+				memory.insert_commitment(core_commitment(seed))
+			);
+		}
+
+		assert_eq!(repository.size(), memory.size());
+		for index in 0..5 {
+			assert_eq!(
+				repository.get_commitment(index),
+				memory.get_commitment(index)
+			);
+		}
+	});
+}
@@ -85,6 +85,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ShieldedPool::HistoricRoots` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `ShieldedPool::NullifierSet` (r:1 w:1)
 	/// Proof: `ShieldedPool::NullifierSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ShieldedPool::NullifierCount` (r:1 w:1)
+	/// Proof: `ShieldedPool::NullifierCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Number` (r:1 w:0)
 	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ShieldedPool::MerkleTreeSize` (r:1 w:1)
@@ -105,13 +107,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3517`
 		// Minimum execution time: 34_000_000 picoseconds.
 		Weight::from_parts(34_000_000, 3517)
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `ShieldedPool::HistoricRoots` (r:1 w:0)
 	/// Proof: `ShieldedPool::HistoricRoots` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `ShieldedPool::NullifierSet` (r:1 w:1)
 	/// Proof: `ShieldedPool::NullifierSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ShieldedPool::NullifierCount` (r:1 w:1)
+	/// Proof: `ShieldedPool::NullifierCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ShieldedPool::PoolBalance` (r:1 w:1)
 	/// Proof: `ShieldedPool::PoolBalance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `System::Number` (r:1 w:0)
@@ -132,8 +136,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `6172`
 		// Minimum execution time: 52_000_000 picoseconds.
 		Weight::from_parts(52_000_000, 6172)
-			.saturating_add(T::DbWeight::get().reads(10_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	fn set_disclosure_verifying_key() -> Weight {
 		Weight::from_parts(150_000, 0).saturating_add(T::DbWeight::get().writes(1))
@@ -220,6 +224,8 @@ impl WeightInfo for () {
 	/// Proof: `ShieldedPool::HistoricRoots` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `ShieldedPool::NullifierSet` (r:1 w:1)
 	/// Proof: `ShieldedPool::NullifierSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ShieldedPool::NullifierCount` (r:1 w:1)
+	/// Proof: `ShieldedPool::NullifierCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Number` (r:1 w:0)
 	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ShieldedPool::MerkleTreeSize` (r:1 w:1)
@@ -240,13 +246,15 @@ impl WeightInfo for () {
 		//  Estimated: `3517`
 		// Minimum execution time: 34_000_000 picoseconds.
 		Weight::from_parts(34_000_000, 3517)
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `ShieldedPool::HistoricRoots` (r:1 w:0)
 	/// Proof: `ShieldedPool::HistoricRoots` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
 	/// Storage: `ShieldedPool::NullifierSet` (r:1 w:1)
 	/// Proof: `ShieldedPool::NullifierSet` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `ShieldedPool::NullifierCount` (r:1 w:1)
+	/// Proof: `ShieldedPool::NullifierCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `ShieldedPool::PoolBalance` (r:1 w:1)
 	/// Proof: `ShieldedPool::PoolBalance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `System::Number` (r:1 w:0)
@@ -267,8 +275,8 @@ impl WeightInfo for () {
 		//  Estimated: `6172`
 		// Minimum execution time: 52_000_000 picoseconds.
 		Weight::from_parts(52_000_000, 6172)
-			.saturating_add(RocksDbWeight::get().reads(10_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	fn set_disclosure_verifying_key() -> Weight {
 		Weight::from_parts(150_000, 0).saturating_add(RocksDbWeight::get().writes(1))
@@ -1,7 +1,14 @@
 use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObjectOwned};
-use pallet_zk_verifier_runtime_api::{CircuitVersionInfo, ZkVerifierRuntimeApi};
+use orbinum_zk_verifier::{
+	domain::value_objects::VerifyingKey,
+	infrastructure::adapters::{export_vk_to_snarkjs, SnarkjsVerifyingKey},
+};
+use pallet_zk_verifier_runtime_api::{
+	CircuitInfo, CircuitStatistics, CircuitVersionInfo, ProofSystem, ZkVerifierRuntimeApi,
+	ZK_VERIFIER_API_V2,
+};
 use serde::{Deserialize, Serialize};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
@@ -36,6 +43,72 @@ fn to_response(info: CircuitVersionInfo) -> CircuitVersionInfoResponse {
 	}
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CircuitInfoResponse {
+	pub circuit_id: u32,
+	pub active_version: u32,
+	pub proof_system: ProofSystem,
+	pub vk_hash: String,
+	pub registered_at: u64,
+	pub supported_versions: Vec<u32>,
+	pub statistics: CircuitStatistics,
+}
+
+fn to_circuit_response(info: CircuitInfo) -> CircuitInfoResponse {
+	CircuitInfoResponse {
+		circuit_id: info.circuit_id,
+		active_version: info.active_version,
+		proof_system: info.proof_system,
+		vk_hash: format!("0x{}", hex::encode(info.vk_hash)),
+		registered_at: info.registered_at,
+		supported_versions: info.supported_versions,
+		statistics: info.statistics,
+	}
+}
+
+/// Groth16 verification key in snarkjs `verification_key.json` format
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct VerificationKeyJson {
+	pub protocol: String,
+	pub curve: String,
+	#[serde(rename = "nPublic")]
+	pub n_public: usize,
+	pub vk_alpha_1: [String; 3],
+	pub vk_beta_2: [[String; 2]; 3],
+	pub vk_gamma_2: [[String; 2]; 3],
+	pub vk_delta_2: [[String; 2]; 3],
+	#[serde(rename = "IC")]
+	pub ic: Vec<[String; 3]>,
+}
+
+impl From<SnarkjsVerifyingKey> for VerificationKeyJson {
+	fn from(vk: SnarkjsVerifyingKey) -> Self {
+		Self {
+			protocol: "groth16".into(),
+			curve: "bn128".into(),
+			n_public: vk.n_public,
+			vk_alpha_1: vk.vk_alpha_1,
+			vk_beta_2: vk.vk_beta_2,
+			vk_gamma_2: vk.vk_gamma_2,
+			vk_delta_2: vk.vk_delta_2,
+			ic: vk.ic,
+		}
+	}
+}
+
+impl From<VerificationKeyJson> for SnarkjsVerifyingKey {
+	fn from(vk: VerificationKeyJson) -> Self {
+		Self {
+			n_public: vk.n_public,
+			vk_alpha_1: vk.vk_alpha_1,
+			vk_beta_2: vk.vk_beta_2,
+			vk_gamma_2: vk.vk_gamma_2,
+			vk_delta_2: vk.vk_delta_2,
+			ic: vk.ic,
+		}
+	}
+}
+
 #[rpc(client, server)]
 pub trait ZkVerifierApi<BlockHash> {
 	#[method(name = "zkVerifier_getCircuitVersionInfo")]
@@ -46,6 +119,23 @@ pub trait ZkVerifierApi<BlockHash> {
 
 	#[method(name = "zkVerifier_getAllCircuitVersions")]
 	fn get_all_circuit_versions(&self) -> RpcResult<Vec<CircuitVersionInfoResponse>>;
+
+	/// Active version, proof system, VK hash and statistics of a circuit
+	#[method(name = "zkVerifier_getCircuit")]
+	fn get_circuit(&self, circuit_id: u32) -> RpcResult<Option<CircuitInfoResponse>>;
+
+	/// All circuits with a registered verification key, ordered by circuit id
+	#[method(name = "zkVerifier_listCircuits")]
+	fn list_circuits(&self) -> RpcResult<Vec<CircuitInfoResponse>>;
+
+	/// Verification key of a circuit (active version if `version` is `None`)
+	/// converted to snarkjs `verification_key.json`
+	#[method(name = "zkVerifier_getVerificationKeyJson")]
+	fn get_verification_key_json(
+		&self,
+		circuit_id: u32,
+		version: Option<u32>,
+	) -> RpcResult<Option<VerificationKeyJson>>;
 }
 
 pub struct ZkVerifier<C, B> {
@@ -62,6 +152,31 @@ impl<C, B> ZkVerifier<C, B> {
 	}
 }
 
+impl<C, B> ZkVerifier<C, B>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: ZkVerifierRuntimeApi<B>,
+{
+	/// Fails unless the runtime at `at` serves the circuit catalog queries
+	/// added in version 2 of `ZkVerifierRuntimeApi`
+	fn ensure_api_v2(&self, at: B::Hash) -> RpcResult<()> {
+		let version = self
+			.client
+			.runtime_api()
+			.api_version::<dyn ZkVerifierRuntimeApi<B>>(at)
+			.map_err(|e| ErrorObjectOwned::owned(1, format!("Runtime error: {e}"), None::<()>))?;
+		match version {
+			Some(version) if version >= ZK_VERIFIER_API_V2 => Ok(()),
+			_ => Err(ErrorObjectOwned::owned(
+				1,
+				"Not supported by the runtime at this block",
+				None::<()>,
+			)),
+		}
+	}
+}
+
 impl<C, B> ZkVerifierApiServer<B::Hash> for ZkVerifier<C, B>
 where
 	B: BlockT,
@@ -92,4 +207,57 @@ where
 
 		Ok(info.into_iter().map(to_response).collect())
 	}
+
+	fn get_circuit(&self, circuit_id: u32) -> RpcResult<Option<CircuitInfoResponse>> {
+		let api = self.client.runtime_api();
+		let best_block = self.client.info().best_hash;
+		self.ensure_api_v2(best_block)?;
+
+		let info = api
+			.get_circuit(best_block, circuit_id)
+			.map_err(|e| ErrorObjectOwned::owned(1, format!("Runtime error: {e}"), None::<()>))?;
+
+		Ok(info.map(to_circuit_response))
+	}
+
+	fn list_circuits(&self) -> RpcResult<Vec<CircuitInfoResponse>> {
+		let api = self.client.runtime_api();
+		let best_block = self.client.info().best_hash;
+		self.ensure_api_v2(best_block)?;
+
+		let info = api
+			.list_circuits(best_block)
+			.map_err(|e| ErrorObjectOwned::owned(1, format!("Runtime error: {e}"), None::<()>))?;
+
+		Ok(info.into_iter().map(to_circuit_response).collect())
+	}
+
+	fn get_verification_key_json(
+		&self,
+		circuit_id: u32,
+		version: Option<u32>,
+	) -> RpcResult<Option<VerificationKeyJson>> {
+		let api = self.client.runtime_api();
+		let best_block = self.client.info().best_hash;
+		self.ensure_api_v2(best_block)?;
+
+		let Some((version, key_data)) = api
+			.get_verification_key(best_block, circuit_id, version)
+			.map_err(|e| {
+			ErrorObjectOwned::owned(1, format!("Runtime error: {e}"), None::<()>)
+		})?
+		else {
+			return Ok(None);
+		};
+
+		let vk = export_vk_to_snarkjs(&VerifyingKey::new(key_data)).map_err(|e| {
+			ErrorObjectOwned::owned(
+				1,
+				format!("Verification key {circuit_id} v{version} cannot be exported: {e}"),
+				None::<()>,
+			)
+		})?;
+
+		Ok(Some(vk.into()))
+	}
 }
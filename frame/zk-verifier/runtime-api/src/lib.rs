@@ -37,9 +37,80 @@ pub struct CircuitVersionInfo {
 	pub vk_hashes: Vec<VkVersionHash>,
 }
 
+pub use pallet_zk_verifier::ProofSystem;
+
+#[derive(
+	scale_codec::Encode,
+	scale_codec::Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	Debug,
+	Default,
+	TypeInfo
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct CircuitStatistics {
+	pub total_verifications: u64,
+	pub successful_verifications: u64,
+	pub failed_verifications: u64,
+}
+
+#[derive(
+	scale_codec::Encode,
+	scale_codec::Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct CircuitInfo {
+	pub circuit_id: u32,
+	pub active_version: u32,
+	pub proof_system: ProofSystem,
+	pub vk_hash: [u8; 32],
+	pub registered_at: u64,
+	pub supported_versions: Vec<u32>,
+	/// Statistics of the active version
+	pub statistics: CircuitStatistics,
+}
+
+impl From<pallet_zk_verifier::RuntimeCircuitInfo> for CircuitInfo {
+	fn from(info: pallet_zk_verifier::RuntimeCircuitInfo) -> Self {
+		Self {
+			circuit_id: info.circuit_id,
+			active_version: info.active_version,
+			proof_system: info.proof_system,
+			vk_hash: info.vk_hash,
+			registered_at: info.registered_at,
+			supported_versions: info.supported_versions,
+			statistics: CircuitStatistics {
+				total_verifications: info.statistics.total_verifications,
+				successful_verifications: info.statistics.successful_verifications,
+				failed_verifications: info.statistics.failed_verifications,
+			},
+		}
+	}
+}
+
+/// Version of `ZkVerifierRuntimeApi` adding the circuit catalog and
+/// verification key queries
+pub const ZK_VERIFIER_API_V2: u32 = 2;
+
 sp_api::decl_runtime_apis! {
+	#[api_version(2)]
 	pub trait ZkVerifierRuntimeApi {
 		fn get_circuit_version_info(circuit_id: u32) -> Option<CircuitVersionInfo>;
 		fn get_all_circuit_versions() -> Vec<CircuitVersionInfo>;
+		#[api_version(2)]
+		fn get_circuit(circuit_id: u32) -> Option<CircuitInfo>;
+		#[api_version(2)]
+		fn list_circuits() -> Vec<CircuitInfo>;
+		/// Version and raw (arkworks, compressed) bytes of a verification
+		/// key, the active version when `version` is `None`
+		#[api_version(2)]
+		fn get_verification_key(circuit_id: u32, version: Option<u32>) -> Option<(u32, Vec<u8>)>;
 	}
 }
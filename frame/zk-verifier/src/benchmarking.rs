@@ -24,7 +24,7 @@ use alloc::vec;
 #[benchmarks]
 mod benchmarks {
 	use super::*;
-	use frame_support::{BoundedVec, pallet_prelude::ConstU32};
+	use frame_support::{BoundedVec, pallet_prelude::ConstU32, traits::EnsureOrigin};
 	use sp_std::vec::Vec;
 
 	// Benchmark configuration constants for FRAME weight generation
@@ -114,7 +114,9 @@ mod benchmarks {
 	}
 
 	#[benchmark]
-	fn register_verification_key() {
+	fn register_verification_key() -> Result<(), BenchmarkError> {
+		let origin = T::CircuitAdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
 		let circuit_id = CircuitId::TRANSFER;
 		let version = 1u32;
 		let vk_bytes = sample_verification_key();
@@ -123,13 +125,17 @@ mod benchmarks {
 			.expect("benchmark vk bytes must fit bounded verification key size");
 
 		#[extrinsic_call]
-		_(RawOrigin::Root, circuit_id, version, bounded_vk);
+		_(origin as T::RuntimeOrigin, circuit_id, version, bounded_vk);
 
 		assert!(VerificationKeys::<T>::contains_key(circuit_id, version));
+
+		Ok(())
 	}
 
 	#[benchmark]
-	fn set_active_version() {
+	fn set_active_version() -> Result<(), BenchmarkError> {
+		let origin = T::CircuitAdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
 		let circuit_id = CircuitId::TRANSFER;
 		let current_version = 1u32;
 		let new_version = 2u32;
@@ -151,16 +157,20 @@ mod benchmarks {
 		ActiveCircuitVersion::<T>::insert(circuit_id, current_version);
 
 		#[extrinsic_call]
-		_(RawOrigin::Root, circuit_id, new_version);
+		_(origin as T::RuntimeOrigin, circuit_id, new_version);
 
 		assert_eq!(
 			ActiveCircuitVersion::<T>::get(circuit_id),
 			Some(new_version)
 		);
+
+		Ok(())
 	}
 
 	#[benchmark]
-	fn remove_verification_key() {
+	fn remove_verification_key() -> Result<(), BenchmarkError> {
+		let origin = T::CircuitAdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
 		let circuit_id = CircuitId::TRANSFER;
 		let active_version = 1u32;
 		let remove_version = 2u32;
@@ -182,7 +192,7 @@ mod benchmarks {
 		ActiveCircuitVersion::<T>::insert(circuit_id, active_version);
 
 		#[extrinsic_call]
-		_(RawOrigin::Root, circuit_id, remove_version);
+		_(origin as T::RuntimeOrigin, circuit_id, remove_version);
 
 		assert!(!VerificationKeys::<T>::contains_key(
 			circuit_id,
@@ -192,6 +202,8 @@ mod benchmarks {
 			circuit_id,
 			active_version
 		));
+
+		Ok(())
 	}
 
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
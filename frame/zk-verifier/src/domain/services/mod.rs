@@ -6,4 +6,4 @@ mod zk_verifier_port;
 
 pub use proof_validator::ProofValidator;
 pub use vk_validator::{DefaultVkValidator, VkValidator};
-pub use zk_verifier_port::ZkVerifierPort;
+pub use zk_verifier_port::{UNSHIELD_RECIPIENT_BINDING_VERSION, ZkVerifierPort};
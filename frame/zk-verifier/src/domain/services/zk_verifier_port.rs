@@ -4,8 +4,15 @@
 //! ZK proof verification. Other pallets can use this trait as a dependency
 //! without coupling to the concrete implementation.
 
+use orbinum_zk_host::ProofJob;
 use sp_runtime::DispatchError;
 
+/// First unshield circuit version whose public input 3 is the recipient binding
+///
+/// Earlier versions commit to the raw recipient, so their keys keep verifying
+/// until a key for this version is registered and activated.
+pub const UNSHIELD_RECIPIENT_BINDING_VERSION: u32 = 2;
+
 /// Domain port for ZK proof verification
 pub trait ZkVerifierPort {
 	/// Verify a private transfer proof
@@ -35,10 +42,15 @@ pub trait ZkVerifierPort {
 	/// * `merkle_root` - Merkle tree root used in the proof
 	/// * `nullifier` - Nullifier of the consumed note
 	/// * `amount` - Amount to withdraw (part of public input)
-	/// * `recipient` - Recipient address (20 bytes for H160)
+	/// * `recipient` - Recipient AccountId32 bytes (big-endian SCALE encoding)
+	/// * `recipient_binding` - Poseidon(recipient, nonce) as 32-byte LE
 	/// * `asset_id` - Asset ID (u32)
 	/// * `version` - Circuit version (None for active version)
 	///
+	/// Public input 3 depends on the key version: from
+	/// [`UNSHIELD_RECIPIENT_BINDING_VERSION`] on it is `recipient_binding`,
+	/// older keys take the raw `recipient`.
+	///
 	/// # Returns
 	/// * `Ok(true)` if the proof is valid
 	/// * `Ok(false)` if the proof is invalid
@@ -49,6 +61,7 @@ pub trait ZkVerifierPort {
 		nullifier: &[u8; 32],
 		amount: u128,
 		recipient: &[u8; 32],
+		recipient_binding: &[u8; 32],
 		asset_id: u32,
 		version: Option<u32>,
 	) -> Result<bool, DispatchError>;
@@ -101,4 +114,50 @@ pub trait ZkVerifierPort {
 		call_hash_fe: &[u8; 32],
 		version: Option<u32>,
 	) -> Result<bool, sp_runtime::DispatchError>;
+
+	/// Verify a viewing key ownership proof
+	///
+	/// Proves knowledge of the viewing key behind `viewing_key_hash` for the
+	/// disclosed `commitment`, without revealing the key.
+	///
+	/// # Arguments
+	/// * `proof` - Serialized Groth16 proof bytes
+	/// * `viewing_key_hash` - Poseidon(domain, viewing_key) as 32-byte LE
+	/// * `commitment` - Commitment of the disclosed note as 32-byte LE
+	/// * `version` - Circuit version (None for active version)
+	///
+	/// # Returns
+	/// * `Ok(true)` if the proof is valid
+	/// * `Ok(false)` if the proof is invalid
+	/// * `Err` if an error occurs during verification
+	fn verify_viewing_key_proof(
+		proof: &[u8],
+		viewing_key_hash: &[u8; 32],
+		commitment: &[u8; 32],
+		version: Option<u32>,
+	) -> Result<bool, sp_runtime::DispatchError>;
+
+	/// Groth16 check `verify_transfer_proof` performs with the active key
+	///
+	/// Lets the node verify the proof ahead of block execution. Returns `None`
+	/// if the transfer circuit has no active verification key.
+	fn transfer_proof_job(
+		proof: &[u8],
+		merkle_root: &[u8; 32],
+		nullifiers: &[[u8; 32]],
+		commitments: &[[u8; 32]],
+	) -> Option<ProofJob>;
+
+	/// Groth16 check `verify_unshield_proof` performs with the active key
+	///
+	/// Returns `None` if the unshield circuit has no active verification key.
+	fn unshield_proof_job(
+		proof: &[u8],
+		merkle_root: &[u8; 32],
+		nullifier: &[u8; 32],
+		amount: u128,
+		recipient: &[u8; 32],
+		recipient_binding: &[u8; 32],
+		asset_id: u32,
+	) -> Option<ProofJob>;
 }
@@ -1,4 +1,8 @@
 //! Circuit ID value object
+//!
+//! Known IDs come from the shared catalog in `orbinum_zk_circuits::domain::circuit_ids`.
+
+use orbinum_zk_verifier::circuit_ids;
 
 /// Circuit identifier - uniquely identifies a ZK circuit
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
@@ -7,19 +11,22 @@ pub struct CircuitId(u32);
 
 impl CircuitId {
 	/// Transfer circuit ID
-	pub const TRANSFER: Self = Self(1);
+	pub const TRANSFER: Self = Self(circuit_ids::CIRCUIT_ID_TRANSFER);
 
 	/// Unshield circuit ID
-	pub const UNSHIELD: Self = Self(2);
+	pub const UNSHIELD: Self = Self(circuit_ids::CIRCUIT_ID_UNSHIELD);
 
 	/// Shield circuit ID
-	pub const SHIELD: Self = Self(3);
+	pub const SHIELD: Self = Self(circuit_ids::CIRCUIT_ID_SHIELD);
 
 	/// Disclosure circuit ID (selective disclosure)
-	pub const DISCLOSURE: Self = Self(4);
+	pub const DISCLOSURE: Self = Self(circuit_ids::CIRCUIT_ID_DISCLOSURE);
 
 	/// Private link dispatch circuit ID
-	pub const PRIVATE_LINK: Self = Self(5);
+	pub const PRIVATE_LINK: Self = Self(circuit_ids::CIRCUIT_ID_PRIVATE_LINK);
+
+	/// Viewing key ownership circuit ID (disclosure authentication)
+	pub const VIEWING_KEY_OWNERSHIP: Self = Self(circuit_ids::CIRCUIT_ID_VIEWING_KEY_OWNERSHIP);
 
 	/// Create a new circuit ID
 	pub fn new(value: u32) -> Self {
@@ -33,14 +40,12 @@ impl CircuitId {
 
 	/// Get a human-readable name for known circuits
 	pub fn name(&self) -> Option<&'static str> {
-		match *self {
-			Self::TRANSFER => Some("Transfer"),
-			Self::UNSHIELD => Some("Unshield"),
-			Self::SHIELD => Some("Shield"),
-			Self::DISCLOSURE => Some("Disclosure"),
-			Self::PRIVATE_LINK => Some("PrivateLink"),
-			_ => None,
-		}
+		circuit_ids::circuit_name(self.0)
+	}
+
+	/// Expected number of public inputs, if the circuit has a known schema
+	pub fn expected_public_inputs(&self) -> Option<usize> {
+		circuit_ids::schema(self.0).map(|schema| schema.public_input_count())
 	}
 }
 
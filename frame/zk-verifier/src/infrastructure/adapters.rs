@@ -18,6 +18,8 @@ pub mod primitives {
 		},
 		infrastructure::Groth16Verifier as PrimitiveGroth16Verifier,
 	};
+	// orbinum-zk-host verification (host functions or WASM fallback)
+	pub use orbinum_zk_host::{groth16_batch_verify, groth16_verify, pack_public_inputs};
 }
 
 /// Adapter to convert PublicInputs from domain to primitive
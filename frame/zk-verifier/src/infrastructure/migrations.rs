@@ -0,0 +1,129 @@
+//! Storage migrations
+//!
+//! Each `vN` module upgrades the pallet storage from version `N - 1` to `N`
+//! and is wrapped in a `VersionedMigration`, so it only runs once and bumps
+//! the on-chain storage version. Pre/post checks run under `try-runtime`.
+
+use crate::pallet::{ActiveCircuitVersion, Config, Pallet, VerificationKeys};
+use alloc::{collections::btree_map::BTreeMap, vec::Vec};
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+/// Highest registered version of each circuit
+fn latest_versions<T: Config>() -> BTreeMap<u32, u32> {
+	let mut latest = BTreeMap::new();
+	for (circuit_id, version) in VerificationKeys::<T>::iter_keys() {
+		let entry = latest.entry(circuit_id.0).or_insert(version);
+		*entry = (*entry).max(version);
+	}
+	latest
+}
+
+/// v1: versioned verification key schema
+///
+/// Keys are stored per `(circuit, version)` and verification uses the
+/// version in `ActiveCircuitVersion`. Chains that registered keys before
+/// the active version was tracked have circuits without one, or pointing at
+/// a version that was never stored. Such circuits are switched to their
+/// highest registered version; active versions of circuits without any key
+/// are removed. Keys themselves are left untouched.
+pub mod v1 {
+	use super::*;
+	use crate::types::CircuitId;
+
+	pub struct InnerMigrateToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let latest = latest_versions::<T>();
+			let mut reads = VerificationKeys::<T>::iter_keys().count() as u64;
+			let mut writes = 0u64;
+
+			for (&circuit_id, &version) in &latest {
+				let circuit_id = CircuitId(circuit_id);
+				reads += 2;
+				let has_key = ActiveCircuitVersion::<T>::get(circuit_id)
+					.is_some_and(|active| VerificationKeys::<T>::contains_key(circuit_id, active));
+				if !has_key {
+					ActiveCircuitVersion::<T>::insert(circuit_id, version);
+					writes += 1;
+				}
+			}
+
+			let active: Vec<_> = ActiveCircuitVersion::<T>::iter_keys().collect();
+			reads += active.len() as u64;
+			let orphaned = active
+				.into_iter()
+				.filter(|circuit_id| !latest.contains_key(&circuit_id.0));
+			for circuit_id in orphaned {
+				ActiveCircuitVersion::<T>::remove(circuit_id);
+				writes += 1;
+			}
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		/// Records the stored keys and the active versions that already point
+		/// at one, which the upgrade must leave alone
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let keys: Vec<(u32, u32)> = VerificationKeys::<T>::iter_keys()
+				.map(|(circuit_id, version)| (circuit_id.0, version))
+				.collect();
+			let valid_active: BTreeMap<u32, u32> = ActiveCircuitVersion::<T>::iter()
+				.filter(|(circuit_id, version)| {
+					VerificationKeys::<T>::contains_key(circuit_id, version)
+				})
+				.map(|(circuit_id, version)| (circuit_id.0, version))
+				.collect();
+			Ok((keys, valid_active).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (mut keys, valid_active) =
+				<(Vec<(u32, u32)>, BTreeMap<u32, u32>)>::decode(&mut &state[..])
+					.map_err(|_| "zk-verifier v1: invalid pre-upgrade state")?;
+			let mut keys_after: Vec<(u32, u32)> = VerificationKeys::<T>::iter_keys()
+				.map(|(circuit_id, version)| (circuit_id.0, version))
+				.collect();
+			keys.sort_unstable();
+			keys_after.sort_unstable();
+			ensure!(
+				keys_after == keys,
+				"zk-verifier v1: verification keys changed"
+			);
+
+			for (circuit_id, version) in valid_active {
+				ensure!(
+					ActiveCircuitVersion::<T>::get(CircuitId(circuit_id)) == Some(version),
+					"zk-verifier v1: valid active version changed"
+				);
+			}
+
+			for circuit_id in latest_versions::<T>().keys() {
+				ensure!(
+					ActiveCircuitVersion::<T>::contains_key(CircuitId(*circuit_id)),
+					"zk-verifier v1: circuit without active version"
+				);
+			}
+			for (circuit_id, version) in ActiveCircuitVersion::<T>::iter() {
+				ensure!(
+					VerificationKeys::<T>::contains_key(circuit_id, version),
+					"zk-verifier v1: active version without verification key"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Migrates storage from version 0 to 1.
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
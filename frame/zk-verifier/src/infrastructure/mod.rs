@@ -5,10 +5,12 @@
 //! - Repository implementations using FRAME storage
 //! - Mappers between domain and storage types
 //! - Domain service implementations requiring crypto
+//! - Storage migrations
 //! - Config trait
 //!
 //! This layer depends on domain and application layers.
 
 pub mod adapters;
+pub mod migrations;
 pub mod repositories;
 pub mod services;
@@ -7,7 +7,7 @@ use crate::domain::{
 	value_objects::PublicInputs,
 };
 
-/// Groth16 proof verifier backed by `orbinum-zk-verifier` primitives.
+/// Groth16 proof verifier backed by the `orbinum-zk-host` host functions.
 pub struct Groth16Verifier;
 
 impl ProofValidator for Groth16Verifier {
@@ -24,24 +24,19 @@ impl ProofValidator for Groth16Verifier {
 			Ok(true)
 		}
 
-		// Real verification in production using orbinum-zk-verifier via adapters
+		// Real verification in production through the orbinum-zk-host host functions
 		#[cfg(not(any(feature = "runtime-benchmarks", test)))]
 		{
 			use crate::infrastructure::adapters::{
-				ProofAdapter, PublicInputsAdapter, VerificationKeyAdapter,
-				primitives::PrimitiveGroth16Verifier,
+				PublicInputsAdapter,
+				primitives::{groth16_verify, pack_public_inputs},
 			};
 
-			// Convert domain types to primitive types using adapters
-			let fp_vk = VerificationKeyAdapter::to_primitive(vk);
-			let fp_proof = ProofAdapter::to_primitive(proof);
+			// Convert domain inputs to the packed 32-byte layout of the host functions
 			let fp_inputs = PublicInputsAdapter::to_primitive(public_inputs);
+			let packed_inputs = pack_public_inputs(&fp_inputs.inputs);
 
-			// Verify using orbinum-zk-verifier Groth16Verifier
-			match PrimitiveGroth16Verifier::verify(&fp_vk, &fp_inputs, &fp_proof) {
-				Ok(()) => Ok(true),
-				Err(_) => Ok(false),
-			}
+			Ok(groth16_verify(vk.data(), proof.data(), &packed_inputs))
 		}
 	}
 }
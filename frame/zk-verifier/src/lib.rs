@@ -55,7 +55,8 @@ mod tests;
 mod benchmarking;
 
 /// Domain port for ZK verification (the ONLY public contract)
-pub use domain::services::ZkVerifierPort;
+pub use domain::services::{UNSHIELD_RECIPIENT_BINDING_VERSION, ZkVerifierPort};
+pub use orbinum_zk_host::ProofJob;
 
 pub use types::{CircuitId, ProofSystem, VerificationKeyInfo, VerificationStatistics};
 pub use weights::WeightInfo;
@@ -90,6 +91,29 @@ pub struct RuntimeCircuitVersionInfo {
 	pub vk_hashes: alloc::vec::Vec<RuntimeVkVersionHash>,
 }
 
+#[derive(
+	Clone,
+	PartialEq,
+	Eq,
+	parity_scale_codec::Encode,
+	parity_scale_codec::Decode,
+	scale_info::TypeInfo,
+	Debug
+)]
+pub struct RuntimeCircuitInfo {
+	pub circuit_id: u32,
+	pub active_version: u32,
+	/// Proof system of the active verification key
+	pub proof_system: ProofSystem,
+	/// blake2_256 hash of the active verification key
+	pub vk_hash: [u8; 32],
+	/// Block at which the active verification key was registered
+	pub registered_at: u64,
+	pub supported_versions: alloc::vec::Vec<u32>,
+	/// Verification statistics of the active version
+	pub statistics: VerificationStatistics,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -97,7 +121,11 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 
+	/// In-code storage version, see [`crate::infrastructure::migrations`]
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configuration trait for the pallet
@@ -111,6 +139,9 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxPublicInputs: Get<u32>;
 
+		/// Origin that registers, activates and removes verification keys
+		type CircuitAdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Weight information for extrinsics in this pallet
 		type WeightInfo: WeightInfo;
 	}
@@ -261,7 +292,7 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		/// Register a verification key version for a circuit.
 		///
-		/// Origin must be Root (sudo/governance).
+		/// Origin must be `CircuitAdminOrigin`.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::register_verification_key())]
 		pub fn register_verification_key(
@@ -270,7 +301,7 @@ pub mod pallet {
 			version: u32,
 			verification_key: BoundedVec<u8, ConstU32<8192>>,
 		) -> DispatchResult {
-			ensure_root(origin)?;
+			T::CircuitAdminOrigin::ensure_origin(origin)?;
 
 			let domain_vk = crate::domain::entities::VerificationKey::new(
 				verification_key.to_vec(),
@@ -311,7 +342,7 @@ pub mod pallet {
 
 		/// Set active verification key version for a circuit.
 		///
-		/// Origin must be Root (sudo/governance).
+		/// Origin must be `CircuitAdminOrigin`.
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::set_active_version())]
 		pub fn set_active_version(
@@ -319,7 +350,7 @@ pub mod pallet {
 			circuit_id: CircuitId,
 			version: u32,
 		) -> DispatchResult {
-			ensure_root(origin)?;
+			T::CircuitAdminOrigin::ensure_origin(origin)?;
 
 			ensure!(
 				VerificationKeys::<T>::contains_key(circuit_id, version),
@@ -336,7 +367,7 @@ pub mod pallet {
 
 		/// Remove verification key version from a circuit.
 		///
-		/// Origin must be Root (sudo/governance).
+		/// Origin must be `CircuitAdminOrigin`.
 		#[pallet::call_index(2)]
 		#[pallet::weight(T::WeightInfo::remove_verification_key())]
 		pub fn remove_verification_key(
@@ -344,7 +375,7 @@ pub mod pallet {
 			circuit_id: CircuitId,
 			version: u32,
 		) -> DispatchResult {
-			ensure_root(origin)?;
+			T::CircuitAdminOrigin::ensure_origin(origin)?;
 
 			ensure!(
 				VerificationKeys::<T>::contains_key(circuit_id, version),
@@ -401,20 +432,12 @@ impl<T: Config> ZkVerifierPort for Pallet<T> {
 				services::Groth16Verifier,
 			},
 		};
-		use alloc::{boxed::Box, vec::Vec};
-
-		// Build public inputs: [merkle_root, nullifier1, nullifier2, commitment1, commitment2]
-		// Canonical format between shielded-pool and zk-verifier is little-endian (LE).
-		// Keep bytes as-is to avoid cross-pallet conversions.
+		use alloc::boxed::Box;
 
-		let mut public_inputs = Vec::new();
-		public_inputs.push(merkle_root.to_vec());
-		for nullifier in nullifiers {
-			public_inputs.push(nullifier.to_vec());
-		}
-		for commitment in commitments {
-			public_inputs.push(commitment.to_vec());
-		}
+		let public_inputs = Self::transfer_public_inputs(merkle_root, nullifiers, commitments)
+			.iter()
+			.map(|input| input.to_vec())
+			.collect();
 
 		// Create command for the use case
 		let command = VerifyProofCommand {
@@ -442,6 +465,7 @@ impl<T: Config> ZkVerifierPort for Pallet<T> {
 		nullifier: &[u8; 32],
 		amount: u128,
 		recipient: &[u8; 32],
+		recipient_binding: &[u8; 32],
 		asset_id: u32,
 		version: Option<u32>,
 	) -> Result<bool, sp_runtime::DispatchError> {
@@ -455,32 +479,19 @@ impl<T: Config> ZkVerifierPort for Pallet<T> {
 		};
 		use alloc::boxed::Box;
 
-		// Build public inputs: [merkle_root, nullifier, amount, recipient, asset_id]
-		// Canonical format between shielded-pool and zk-verifier is LE.
-
-		// amount is encoded as 32 bytes little-endian (u128 -> [u8; 32])
-		let mut amount_bytes = [0u8; 32];
-		amount_bytes[..16].copy_from_slice(&amount.to_le_bytes());
-
-		// recipient is AccountId32 (32 bytes, big-endian).
-		// For BN254 field LE encoding: reverse the byte order.
-		let mut recipient_bytes = [0u8; 32];
-		for (index, byte) in recipient.iter().rev().enumerate() {
-			recipient_bytes[index] = *byte;
-		}
-
-		// asset_id is encoded as 32 bytes little-endian (u32 -> [u8; 32])
-		let mut asset_id_bytes = [0u8; 32];
-		asset_id_bytes[..4].copy_from_slice(&asset_id.to_le_bytes());
-
-		use alloc::vec;
-		let public_inputs = vec![
-			merkle_root.to_vec(),
-			nullifier.to_vec(),
-			amount_bytes.to_vec(),
-			recipient_bytes.to_vec(),
-			asset_id_bytes.to_vec(),
-		];
+		let resolved_version = version.or_else(|| {
+			crate::infrastructure::repositories::runtime_active_version::<T>(CircuitId::UNSHIELD.0)
+		});
+		let public_inputs = Self::unshield_public_inputs(
+			merkle_root,
+			nullifier,
+			amount,
+			&Self::unshield_recipient_input(resolved_version, recipient, recipient_binding),
+			asset_id,
+		)
+		.iter()
+		.map(|input| input.to_vec())
+		.collect();
 
 		// Create command for the use case
 		let command = VerifyProofCommand {
@@ -582,10 +593,7 @@ impl<T: Config> ZkVerifierPort for Pallet<T> {
 			},
 			infrastructure::repositories::FrameVkRepository,
 		};
-		use orbinum_zk_verifier::{
-			domain::value_objects::{Proof, PublicInputs, VerifyingKey},
-			infrastructure::Groth16Verifier,
-		};
+		use orbinum_zk_host::{groth16_batch_verify, pack_public_inputs};
 		use sp_std::vec::Vec;
 
 		// Maximum batch size to prevent DoS
@@ -618,16 +626,7 @@ impl<T: Config> ZkVerifierPort for Pallet<T> {
 			.map_err(|_| Error::<T>::RepositoryError)?
 			.ok_or(Error::<T>::VerificationKeyNotFound)?;
 
-		// 4. Create primitive verification key
-		let vk = VerifyingKey::new(vk_domain.data().to_vec());
-
-		// 5. Create primitive proofs
-		let mut groth16_proofs = Vec::with_capacity(proofs.len());
-		for proof_bytes in proofs {
-			groth16_proofs.push(Proof::new(proof_bytes.clone()));
-		}
-
-		// 6. Create primitive public inputs
+		// 4. Pack public inputs in the host function layout
 		let mut all_public_inputs = Vec::with_capacity(public_signals.len());
 		for signals in public_signals {
 			// Validate length
@@ -660,12 +659,12 @@ impl<T: Config> ZkVerifierPort for Pallet<T> {
 			owner.copy_from_slice(&signals[44..76]);
 			inputs_raw.push(owner);
 
-			all_public_inputs.push(PublicInputs::new(inputs_raw));
+			all_public_inputs.push(pack_public_inputs(&inputs_raw));
 		}
 
-		// 7. Batch verify using orbinum-zk-verifier primitives
-		let valid = Groth16Verifier::batch_verify(&vk, &all_public_inputs, &groth16_proofs)
-			.map_err(|_| Error::<T>::BatchVerificationFailed)?;
+		// 5. Batch verify through the orbinum-zk-host host functions
+		let valid = groth16_batch_verify(vk_domain.data(), proofs.to_vec(), all_public_inputs)
+			.ok_or(Error::<T>::BatchVerificationFailed)?;
 
 		Ok(valid)
 	}
@@ -706,6 +705,149 @@ impl<T: Config> ZkVerifierPort for Pallet<T> {
 			.execute(command)
 			.map_err(Self::map_application_error_to_dispatch)
 	}
+
+	/// Verify a viewing key ownership proof
+	fn verify_viewing_key_proof(
+		proof: &[u8],
+		viewing_key_hash: &[u8; 32],
+		commitment: &[u8; 32],
+		version: Option<u32>,
+	) -> Result<bool, sp_runtime::DispatchError> {
+		use crate::{
+			application::{commands::VerifyProofCommand, use_cases::VerifyProofUseCase},
+			domain::value_objects::CircuitId as DomainCircuitId,
+			infrastructure::{
+				repositories::{FrameStatisticsRepository, FrameVkRepository},
+				services::Groth16Verifier,
+			},
+		};
+		use alloc::{boxed::Box, vec};
+
+		// Public inputs: [viewing_key_hash(32B LE), commitment(32B LE)]
+		let public_inputs = vec![viewing_key_hash.to_vec(), commitment.to_vec()];
+
+		let command = VerifyProofCommand {
+			circuit_id: DomainCircuitId::new(CircuitId::VIEWING_KEY_OWNERSHIP.0),
+			version,
+			proof: proof.to_vec(),
+			public_inputs,
+		};
+
+		let vk_repository = FrameVkRepository::<T>::new();
+		let statistics = FrameStatisticsRepository::<T>::new();
+		let validator = Box::new(Groth16Verifier);
+
+		let use_case = VerifyProofUseCase::new(vk_repository, statistics, validator);
+		use_case
+			.execute(command)
+			.map_err(Self::map_application_error_to_dispatch)
+	}
+
+	fn transfer_proof_job(
+		proof: &[u8],
+		merkle_root: &[u8; 32],
+		nullifiers: &[[u8; 32]],
+		commitments: &[[u8; 32]],
+	) -> Option<ProofJob> {
+		Self::proof_job(
+			CircuitId::TRANSFER,
+			proof,
+			&Self::transfer_public_inputs(merkle_root, nullifiers, commitments),
+		)
+	}
+
+	fn unshield_proof_job(
+		proof: &[u8],
+		merkle_root: &[u8; 32],
+		nullifier: &[u8; 32],
+		amount: u128,
+		recipient: &[u8; 32],
+		recipient_binding: &[u8; 32],
+		asset_id: u32,
+	) -> Option<ProofJob> {
+		let (version, vk) = Self::runtime_api_get_verification_key(CircuitId::UNSHIELD.0, None)?;
+		let public_inputs = Self::unshield_public_inputs(
+			merkle_root,
+			nullifier,
+			amount,
+			&Self::unshield_recipient_input(Some(version), recipient, recipient_binding),
+			asset_id,
+		);
+		Some(ProofJob::new(vk, proof.to_vec(), &public_inputs))
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Transfer public inputs: [merkle_root, nullifiers.., commitments..]
+	///
+	/// Canonical format between shielded-pool and zk-verifier is little-endian
+	/// (LE); bytes are kept as-is to avoid cross-pallet conversions.
+	fn transfer_public_inputs(
+		merkle_root: &[u8; 32],
+		nullifiers: &[[u8; 32]],
+		commitments: &[[u8; 32]],
+	) -> alloc::vec::Vec<[u8; 32]> {
+		core::iter::once(*merkle_root)
+			.chain(nullifiers.iter().copied())
+			.chain(commitments.iter().copied())
+			.collect()
+	}
+
+	/// Unshield public input 3 for the key `version`
+	///
+	/// From `UNSHIELD_RECIPIENT_BINDING_VERSION` on this is the recipient
+	/// binding, already a LE field element (Poseidon output). Older keys take
+	/// the raw recipient: AccountId32 is big-endian, so it is reversed into LE.
+	fn unshield_recipient_input(
+		version: Option<u32>,
+		recipient: &[u8; 32],
+		recipient_binding: &[u8; 32],
+	) -> [u8; 32] {
+		match version {
+			Some(version) if version < UNSHIELD_RECIPIENT_BINDING_VERSION => {
+				let mut recipient_le = *recipient;
+				recipient_le.reverse();
+				recipient_le
+			}
+			_ => *recipient_binding,
+		}
+	}
+
+	/// Unshield public inputs: [merkle_root, nullifier, amount, recipient, asset_id]
+	///
+	/// `amount` and `asset_id` are zero-padded to 32 bytes LE; `recipient` is
+	/// the LE field element from `unshield_recipient_input`.
+	fn unshield_public_inputs(
+		merkle_root: &[u8; 32],
+		nullifier: &[u8; 32],
+		amount: u128,
+		recipient: &[u8; 32],
+		asset_id: u32,
+	) -> alloc::vec::Vec<[u8; 32]> {
+		let mut amount_bytes = [0u8; 32];
+		amount_bytes[..16].copy_from_slice(&amount.to_le_bytes());
+
+		let mut asset_id_bytes = [0u8; 32];
+		asset_id_bytes[..4].copy_from_slice(&asset_id.to_le_bytes());
+
+		alloc::vec![
+			*merkle_root,
+			*nullifier,
+			amount_bytes,
+			*recipient,
+			asset_id_bytes,
+		]
+	}
+
+	/// Job verifying `proof` with the active key of `circuit_id`
+	fn proof_job(
+		circuit_id: CircuitId,
+		proof: &[u8],
+		public_inputs: &[[u8; 32]],
+	) -> Option<ProofJob> {
+		let (_, vk) = Self::runtime_api_get_verification_key(circuit_id.0, None)?;
+		Some(ProofJob::new(vk, proof.to_vec(), public_inputs))
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -754,6 +896,56 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	pub fn runtime_api_get_circuit(circuit_id: u32) -> Option<RuntimeCircuitInfo> {
+		use crate::infrastructure::repositories::{
+			runtime_active_version, runtime_supported_versions,
+		};
+		use sp_io::hashing::blake2_256;
+		use sp_runtime::SaturatedConversion;
+
+		let active_version = runtime_active_version::<T>(circuit_id)?;
+		let active_vk = VerificationKeys::<T>::get(CircuitId(circuit_id), active_version)?;
+
+		Some(RuntimeCircuitInfo {
+			circuit_id,
+			active_version,
+			proof_system: active_vk.system,
+			vk_hash: blake2_256(active_vk.key_data.as_slice()),
+			registered_at: active_vk.registered_at.saturated_into(),
+			supported_versions: runtime_supported_versions::<T>(circuit_id),
+			statistics: VerificationStats::<T>::get(CircuitId(circuit_id), active_version),
+		})
+	}
+
+	pub fn runtime_api_list_circuits() -> alloc::vec::Vec<RuntimeCircuitInfo> {
+		use alloc::collections::BTreeSet;
+
+		let circuit_ids: BTreeSet<u32> = VerificationKeys::<T>::iter_keys()
+			.map(|(circuit_id, _version)| circuit_id.0)
+			.collect();
+
+		circuit_ids
+			.into_iter()
+			.filter_map(Self::runtime_api_get_circuit)
+			.collect()
+	}
+
+	/// Version and raw bytes of a circuit's verification key, the active
+	/// version when `version` is `None`
+	pub fn runtime_api_get_verification_key(
+		circuit_id: u32,
+		version: Option<u32>,
+	) -> Option<(u32, alloc::vec::Vec<u8>)> {
+		use crate::infrastructure::repositories::runtime_active_version;
+
+		let version = version.or_else(|| runtime_active_version::<T>(circuit_id))?;
+		let vk = VerificationKeys::<T>::get(CircuitId(circuit_id), version)?;
+
+		Some((version, vk.key_data.into_inner()))
+	}
+}
+
 impl<T: Config> Pallet<T> {
 	/// Helper to convert ApplicationError to DispatchError
 	fn map_application_error_to_dispatch(
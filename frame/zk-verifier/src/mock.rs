@@ -28,6 +28,7 @@ parameter_types! {
 impl pallet_zk_verifier::Config for Test {
 	type MaxProofSize = MaxProofSize;
 	type MaxPublicInputs = MaxPublicInputs;
+	type CircuitAdminOrigin = frame_system::EnsureRoot<u64>;
 	type WeightInfo = crate::weights::SubstrateWeight<Test>;
 }
 
@@ -526,6 +526,88 @@ fn runtime_api_lists_dynamic_circuit_ids_from_storage() {
 	});
 }
 
+#[test]
+fn runtime_api_get_circuit_reports_active_version() {
+	let genesis_config: GenesisConfig<crate::mock::Test> = GenesisConfig {
+		verification_keys: vec![(CircuitId::TRANSFER, sample_vk_1())],
+		_phantom: Default::default(),
+	};
+
+	let storage = frame_system::GenesisConfig::<crate::mock::Test>::default()
+		.build_storage()
+		.unwrap();
+
+	let mut ext = TestExternalities::new(storage);
+	ext.execute_with(|| {
+		genesis_config.build();
+		assert_ok!(ZkVerifier::register_verification_key(
+			RuntimeOrigin::root(),
+			CircuitId::TRANSFER,
+			2,
+			sample_vk_2().try_into().unwrap()
+		));
+		crate::VerificationStats::<crate::mock::Test>::insert(
+			CircuitId::TRANSFER,
+			1,
+			crate::VerificationStatistics {
+				total_verifications: 3,
+				successful_verifications: 2,
+				failed_verifications: 1,
+			},
+		);
+
+		let info =
+			crate::Pallet::<crate::mock::Test>::runtime_api_get_circuit(CircuitId::TRANSFER.0)
+				.expect("circuit is registered");
+		assert_eq!(info.active_version, 1);
+		assert_eq!(info.proof_system, ProofSystem::Groth16);
+		assert_eq!(info.vk_hash, sp_io::hashing::blake2_256(&sample_vk_1()));
+		assert_eq!(info.registered_at, 0);
+		assert_eq!(info.supported_versions, vec![1, 2]);
+		assert_eq!(info.statistics.total_verifications, 3);
+		assert_eq!(info.statistics.successful_verifications, 2);
+
+		assert!(crate::Pallet::<crate::mock::Test>::runtime_api_get_circuit(99).is_none());
+		let circuits = crate::Pallet::<crate::mock::Test>::runtime_api_list_circuits();
+		assert_eq!(circuits, vec![info]);
+	});
+}
+
+#[test]
+fn runtime_api_get_verification_key_defaults_to_active_version() {
+	let genesis_config: GenesisConfig<crate::mock::Test> = GenesisConfig {
+		verification_keys: vec![(CircuitId::TRANSFER, sample_vk_1())],
+		_phantom: Default::default(),
+	};
+
+	let storage = frame_system::GenesisConfig::<crate::mock::Test>::default()
+		.build_storage()
+		.unwrap();
+
+	let mut ext = TestExternalities::new(storage);
+	ext.execute_with(|| {
+		genesis_config.build();
+		assert_ok!(ZkVerifier::register_verification_key(
+			RuntimeOrigin::root(),
+			CircuitId::TRANSFER,
+			2,
+			sample_vk_2().try_into().unwrap()
+		));
+
+		let get_vk = crate::Pallet::<crate::mock::Test>::runtime_api_get_verification_key;
+		assert_eq!(
+			get_vk(CircuitId::TRANSFER.0, None),
+			Some((1, sample_vk_1()))
+		);
+		assert_eq!(
+			get_vk(CircuitId::TRANSFER.0, Some(2)),
+			Some((2, sample_vk_2()))
+		);
+		assert_eq!(get_vk(CircuitId::TRANSFER.0, Some(3)), None);
+		assert_eq!(get_vk(99, None), None);
+	});
+}
+
 // ============================================================================
 // Block Number Tests
 // ============================================================================
@@ -0,0 +1,80 @@
+//! Tests for storage migrations
+
+use crate::{
+	ActiveCircuitVersion, Pallet, VerificationKeyInfo, VerificationKeys,
+	infrastructure::migrations::v1::MigrateToV1,
+	mock::{Test, new_test_ext},
+	types::CircuitId,
+};
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+fn insert_key(circuit_id: CircuitId, version: u32) {
+	VerificationKeys::<Test>::insert(circuit_id, version, VerificationKeyInfo::default());
+}
+
+#[test]
+fn migrate_to_v1_repairs_active_versions() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Pallet<Test>>();
+
+		// No active version
+		insert_key(CircuitId::TRANSFER, 1);
+		insert_key(CircuitId::TRANSFER, 2);
+		// Active version without key
+		insert_key(CircuitId::UNSHIELD, 1);
+		ActiveCircuitVersion::<Test>::insert(CircuitId::UNSHIELD, 3);
+		// Valid active version, kept even if not the latest
+		insert_key(CircuitId::DISCLOSURE, 1);
+		insert_key(CircuitId::DISCLOSURE, 2);
+		ActiveCircuitVersion::<Test>::insert(CircuitId::DISCLOSURE, 1);
+		// Circuit without keys
+		ActiveCircuitVersion::<Test>::insert(CircuitId(99), 1);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(
+			ActiveCircuitVersion::<Test>::get(CircuitId::TRANSFER),
+			Some(2)
+		);
+		assert_eq!(
+			ActiveCircuitVersion::<Test>::get(CircuitId::UNSHIELD),
+			Some(1)
+		);
+		assert_eq!(
+			ActiveCircuitVersion::<Test>::get(CircuitId::DISCLOSURE),
+			Some(1)
+		);
+		assert_eq!(ActiveCircuitVersion::<Test>::get(CircuitId(99)), None);
+		assert_eq!(VerificationKeys::<Test>::iter_keys().count(), 5);
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn migrate_to_v1_skips_upgraded_storage() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<Pallet<Test>>();
+		insert_key(CircuitId::TRANSFER, 1);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ActiveCircuitVersion::<Test>::get(CircuitId::TRANSFER), None);
+	});
+}
+
+#[cfg(feature = "try-runtime")]
+#[test]
+fn migrate_to_v1_passes_try_runtime_checks() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Pallet<Test>>();
+		insert_key(CircuitId::TRANSFER, 1);
+		insert_key(CircuitId::TRANSFER, 2);
+		insert_key(CircuitId::DISCLOSURE, 1);
+		ActiveCircuitVersion::<Test>::insert(CircuitId::DISCLOSURE, 1);
+		ActiveCircuitVersion::<Test>::insert(CircuitId(99), 1);
+
+		MigrateToV1::<Test>::try_on_runtime_upgrade(true).unwrap();
+
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+	});
+}
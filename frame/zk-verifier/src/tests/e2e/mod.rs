@@ -1,3 +1,5 @@
 //! End-to-end tests module
 
 pub mod genesis_tests;
+pub mod migration_tests;
+pub mod proof_job_tests;
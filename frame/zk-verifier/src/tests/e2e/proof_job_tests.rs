@@ -0,0 +1,136 @@
+//! Tests for the proof jobs handed to the node for pre-verification
+
+use crate::{
+	UNSHIELD_RECIPIENT_BINDING_VERSION, ZkVerifierPort,
+	mock::{RuntimeOrigin, Test, ZkVerifier},
+	types::CircuitId,
+};
+use frame_support::{BoundedVec, assert_ok, traits::ConstU32};
+use sp_io::TestExternalities;
+use sp_runtime::BuildStorage;
+
+fn new_ext() -> TestExternalities {
+	frame_system::GenesisConfig::<Test>::default()
+		.build_storage()
+		.unwrap()
+		.into()
+}
+
+fn register_active_key(circuit_id: CircuitId, version: u32, vk: &[u8]) {
+	let bounded_vk: BoundedVec<u8, ConstU32<8192>> = vk.to_vec().try_into().unwrap();
+	assert_ok!(ZkVerifier::register_verification_key(
+		RuntimeOrigin::root(),
+		circuit_id,
+		version,
+		bounded_vk
+	));
+	assert_ok!(ZkVerifier::set_active_version(
+		RuntimeOrigin::root(),
+		circuit_id,
+		version
+	));
+}
+
+fn recipient() -> [u8; 32] {
+	let mut recipient = [0u8; 32];
+	recipient[0] = 0xaa;
+	recipient[31] = 0x01;
+	recipient
+}
+
+#[test]
+fn no_job_without_active_key() {
+	new_ext().execute_with(|| {
+		assert!(ZkVerifier::transfer_proof_job(&[1u8; 64], &[0u8; 32], &[], &[]).is_none());
+		assert!(
+			ZkVerifier::unshield_proof_job(
+				&[1u8; 64],
+				&[0u8; 32],
+				&[0u8; 32],
+				5,
+				&recipient(),
+				&[0u8; 32],
+				0
+			)
+			.is_none()
+		);
+	});
+}
+
+#[test]
+fn transfer_job_packs_inputs_in_schema_order() {
+	new_ext().execute_with(|| {
+		register_active_key(CircuitId::TRANSFER, 1, &[9u8; 512]);
+
+		let job = ZkVerifier::transfer_proof_job(
+			&[1u8; 64],
+			&[2u8; 32],
+			&[[3u8; 32], [4u8; 32]],
+			&[[5u8; 32], [6u8; 32]],
+		)
+		.unwrap();
+
+		assert_eq!(job.vk, vec![9u8; 512]);
+		assert_eq!(job.proof, vec![1u8; 64]);
+		let expected: Vec<u8> = (2u8..=6).flat_map(|byte| [byte; 32]).collect();
+		assert_eq!(job.public_inputs, expected);
+	});
+}
+
+#[test]
+fn unshield_job_pads_amount_and_asset_id() {
+	new_ext().execute_with(|| {
+		register_active_key(
+			CircuitId::UNSHIELD,
+			UNSHIELD_RECIPIENT_BINDING_VERSION,
+			&[8u8; 512],
+		);
+
+		let job = ZkVerifier::unshield_proof_job(
+			&[1u8; 64],
+			&[2u8; 32],
+			&[3u8; 32],
+			500,
+			&recipient(),
+			&[4u8; 32],
+			7,
+		)
+		.unwrap();
+
+		let inputs: Vec<&[u8]> = job.public_inputs.chunks(32).collect();
+		assert_eq!(inputs.len(), 5);
+		assert_eq!(inputs[0], &[2u8; 32]);
+		assert_eq!(inputs[1], &[3u8; 32]);
+		assert_eq!(&inputs[2][..16], &500u128.to_le_bytes());
+		assert_eq!(&inputs[2][16..], &[0u8; 16]);
+		assert_eq!(inputs[3], &[4u8; 32]);
+		assert_eq!(&inputs[4][..4], &7u32.to_le_bytes());
+		assert_eq!(&inputs[4][4..], &[0u8; 28]);
+	});
+}
+
+#[test]
+fn unshield_job_keeps_raw_recipient_for_pre_binding_keys() {
+	new_ext().execute_with(|| {
+		register_active_key(
+			CircuitId::UNSHIELD,
+			UNSHIELD_RECIPIENT_BINDING_VERSION - 1,
+			&[8u8; 512],
+		);
+
+		let job = ZkVerifier::unshield_proof_job(
+			&[1u8; 64],
+			&[2u8; 32],
+			&[3u8; 32],
+			500,
+			&recipient(),
+			&[4u8; 32],
+			7,
+		)
+		.unwrap();
+
+		let mut recipient_le = recipient();
+		recipient_le.reverse();
+		assert_eq!(&job.public_inputs[96..128], &recipient_le);
+	});
+}
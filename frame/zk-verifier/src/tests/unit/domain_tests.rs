@@ -164,6 +164,26 @@ mod circuit_id_tests {
 		let as_u32: u32 = CircuitId::UNSHIELD.into();
 		assert_eq!(as_u32, 2);
 	}
+
+	#[test]
+	fn circuit_id_matches_shared_catalog() {
+		use orbinum_zk_verifier::circuit_ids;
+
+		assert_eq!(CircuitId::TRANSFER.value(), circuit_ids::TRANSFER.id);
+		assert_eq!(
+			CircuitId::PRIVATE_LINK.value(),
+			circuit_ids::PRIVATE_LINK.id
+		);
+		assert_eq!(CircuitId::SHIELD.name(), Some("Shield"));
+		assert_eq!(CircuitId::TRANSFER.expected_public_inputs(), Some(5));
+		assert_eq!(CircuitId::DISCLOSURE.expected_public_inputs(), Some(4));
+		assert_eq!(
+			CircuitId::VIEWING_KEY_OWNERSHIP.expected_public_inputs(),
+			Some(2)
+		);
+		assert_eq!(CircuitId::SHIELD.expected_public_inputs(), None);
+		assert_eq!(CircuitId::new(999).expected_public_inputs(), None);
+	}
 }
 
 #[cfg(test)]
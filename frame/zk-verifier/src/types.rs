@@ -10,6 +10,7 @@
 //! - Primitives (orbinum-zk-verifier, orbinum-zk-core) are accessed ONLY via infrastructure/adapters
 
 use frame_support::pallet_prelude::*;
+use orbinum_zk_verifier::circuit_ids;
 use parity_scale_codec::DecodeWithMemTracking;
 use serde::{Deserialize, Serialize};
 
@@ -33,15 +34,17 @@ pub struct CircuitId(pub u32);
 
 impl CircuitId {
 	/// Transfer circuit ID
-	pub const TRANSFER: Self = Self(1);
+	pub const TRANSFER: Self = Self(circuit_ids::CIRCUIT_ID_TRANSFER);
 	/// Unshield circuit ID
-	pub const UNSHIELD: Self = Self(2);
+	pub const UNSHIELD: Self = Self(circuit_ids::CIRCUIT_ID_UNSHIELD);
 	/// Shield circuit ID
-	pub const SHIELD: Self = Self(3);
+	pub const SHIELD: Self = Self(circuit_ids::CIRCUIT_ID_SHIELD);
 	/// Disclosure circuit ID
-	pub const DISCLOSURE: Self = Self(4);
+	pub const DISCLOSURE: Self = Self(circuit_ids::CIRCUIT_ID_DISCLOSURE);
 	/// Private link dispatch circuit ID
-	pub const PRIVATE_LINK: Self = Self(5);
+	pub const PRIVATE_LINK: Self = Self(circuit_ids::CIRCUIT_ID_PRIVATE_LINK);
+	/// Viewing key ownership circuit ID
+	pub const VIEWING_KEY_OWNERSHIP: Self = Self(circuit_ids::CIRCUIT_ID_VIEWING_KEY_OWNERSHIP);
 }
 
 /// Supported proof systems
@@ -0,0 +1,133 @@
+//! Derived Key Set aggregate.
+//!
+//! Sub-keys derived from an intermediate [`DerivationKey`] rather than the
+//! spending key. A hardware wallet exports the derivation key so the host can
+//! scan, decrypt and compute nullifiers while spend authority stays on the
+//! device; the EdDSA component can be kept there too behind an
+//! [`EdDSASigner`](crate::domain::ports::EdDSASigner).
+
+use crate::domain::{
+	entities::error::MemoError,
+	services::key_derivation,
+	value_objects::{
+		ClueKey, DerivationKey, DetectionKey, EdDSAKey, FullViewingKey, IncomingViewingKey,
+		NullifierKey, OutgoingViewingKey, ViewingKey,
+	},
+};
+
+/// Key set derived from a derivation key, without the spending key.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DerivedKeySet {
+	/// Intermediate secret the sub-keys derive from (secret — grants everything but spending)
+	derivation_key: DerivationKey,
+	/// Incoming viewing key for memo decryption (safe to share with auditors)
+	pub viewing_key: ViewingKey,
+	/// Nullifier derivation key
+	pub nullifier_key: NullifierKey,
+	/// EdDSA signing key for ZK circuits
+	pub eddsa_key: EdDSAKey,
+}
+
+impl DerivedKeySet {
+	/// Derives viewing, nullifier and EdDSA keys from a derivation key.
+	pub fn from_derivation_key(derivation_key: DerivationKey) -> Self {
+		let bytes = derivation_key.as_bytes();
+		Self {
+			viewing_key: key_derivation::derive_viewing_key(bytes),
+			nullifier_key: key_derivation::derive_nullifier_key(bytes),
+			eddsa_key: key_derivation::derive_eddsa_key(bytes),
+			derivation_key,
+		}
+	}
+
+	/// Returns a reference to the derivation key.
+	pub fn derivation_key(&self) -> &DerivationKey {
+		&self.derivation_key
+	}
+
+	/// Exports the viewing key for an auditor (read-only access).
+	pub fn export_viewing_key(&self) -> ViewingKey {
+		self.viewing_key.clone()
+	}
+
+	/// Returns the incoming viewing key (decrypts received notes).
+	///
+	/// Same key as [`Self::export_viewing_key`].
+	pub fn incoming_viewing_key(&self) -> IncomingViewingKey {
+		self.viewing_key.clone()
+	}
+
+	/// Derives the outgoing viewing key (recovers sent memos).
+	pub fn outgoing_viewing_key(&self) -> OutgoingViewingKey {
+		key_derivation::derive_outgoing_viewing_key(self.derivation_key.as_bytes())
+	}
+
+	/// Returns the incoming and outgoing viewing keys together.
+	pub fn full_viewing_key(&self) -> FullViewingKey {
+		FullViewingKey::new(self.incoming_viewing_key(), self.outgoing_viewing_key())
+	}
+
+	/// Derives the clue key senders use to tag memos for this key set.
+	pub fn clue_key(&self) -> ClueKey {
+		key_derivation::derive_clue_key(self.viewing_key.as_bytes())
+	}
+
+	/// Derives a detection key with a false-positive rate of `2^-precision`.
+	pub fn detection_key(&self, precision: usize) -> Result<DetectionKey, MemoError> {
+		key_derivation::derive_detection_key(self.viewing_key.as_bytes(), precision)
+	}
+
+	/// Returns this key set with its viewing key replaced by `generation`.
+	pub fn with_viewing_key_generation(&self, generation: u32) -> Self {
+		Self {
+			viewing_key: key_derivation::derive_viewing_key_at_generation(
+				self.derivation_key.as_bytes(),
+				generation,
+			),
+			..self.clone()
+		}
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::aggregates::keyset::KeySet;
+
+	const SPENDING_KEY: [u8; 32] = [11u8; 32];
+
+	fn derived() -> DerivedKeySet {
+		DerivedKeySet::from_derivation_key(key_derivation::derive_derivation_key(&SPENDING_KEY))
+	}
+
+	#[test]
+	fn test_matches_keyset_from_spending_key() {
+		let keys = KeySet::from_spending_key(SPENDING_KEY);
+		let derived = derived();
+
+		assert_eq!(derived.viewing_key, keys.viewing_key);
+		assert_eq!(derived.nullifier_key, keys.nullifier_key);
+		assert_eq!(derived.eddsa_key, keys.eddsa_key);
+		assert_eq!(derived.clue_key(), keys.clue_key());
+		assert_eq!(keys.derived_keys(), derived);
+	}
+
+	#[test]
+	fn test_derivation_key_hides_spending_key() {
+		assert_ne!(derived().derivation_key().as_bytes(), &SPENDING_KEY);
+	}
+
+	#[test]
+	fn test_with_viewing_key_generation_matches_keyset() {
+		let keys = KeySet::from_spending_key(SPENDING_KEY);
+		assert_eq!(derived().with_viewing_key_generation(0), derived());
+		assert_eq!(
+			derived().with_viewing_key_generation(2).viewing_key,
+			keys.with_viewing_key_generation(2).viewing_key
+		);
+	}
+}
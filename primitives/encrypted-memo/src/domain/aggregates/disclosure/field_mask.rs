@@ -0,0 +1,295 @@
+//! Extended Disclosure Mask value object.
+//!
+//! Field-tagged bitmap over every [`MemoDataV3`] field. Bit `i` reveals the
+//! `i`-th field in serialization order, so the low four bits coincide with
+//! the [`DisclosureMask`] bitmap. [`MemoDataV2`] memos disclose as V3 memos
+//! without application data.
+//!
+//! [`validate_mask_bitmap`] holds the mask safety rules for both this crate
+//! and the shielded pool pallet's `InvalidDisclosureMask` check.
+
+use super::mask::DisclosureMask;
+use crate::domain::{
+	entities::{error::MemoError, memo_data_v2::MemoDataV2, memo_data_v3::MemoDataV3},
+	value_objects::constants::{MEMO_DATA_V2_SIZE, MEMO_DATA_V3_SIZE, SENDER_HINT_SIZE},
+};
+use alloc::vec::Vec;
+use core::ops::Range;
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use scale_info::TypeInfo;
+
+// ============================================================================
+// Memo Field
+// ============================================================================
+
+/// A [`MemoDataV3`] field addressable by a disclosure mask.
+///
+/// The discriminant is the field's bit position in the mask.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum MemoField {
+	/// Token amount
+	Value = 0,
+	/// Owner public key
+	Owner = 1,
+	/// Blinding factor — never disclosable
+	Blinding = 2,
+	/// Asset identifier
+	AssetId = 3,
+	/// Diversifier index of the receiving address
+	DiversifierIndex = 4,
+	/// Sender hint
+	SenderHint = 5,
+	/// Application data, with its length byte
+	AppData = 6,
+}
+
+impl MemoField {
+	/// All fields in serialization order.
+	pub const ALL: [MemoField; 7] = [
+		Self::Value,
+		Self::Owner,
+		Self::Blinding,
+		Self::AssetId,
+		Self::DiversifierIndex,
+		Self::SenderHint,
+		Self::AppData,
+	];
+
+	/// Mask bit of this field.
+	pub fn bit(self) -> u8 {
+		1 << self as u8
+	}
+
+	/// Byte range of this field in the serialized [`MemoDataV3`].
+	pub fn range(self) -> Range<usize> {
+		match self {
+			Self::Value => 0..8,
+			Self::Owner => 8..40,
+			Self::Blinding => 40..72,
+			Self::AssetId => 72..80,
+			Self::DiversifierIndex => 80..84,
+			Self::SenderHint => 84..84 + SENDER_HINT_SIZE,
+			Self::AppData => MEMO_DATA_V2_SIZE..MEMO_DATA_V3_SIZE,
+		}
+	}
+
+	/// Serialized size of this field in bytes.
+	pub fn size(self) -> usize {
+		self.range().len()
+	}
+}
+
+/// Bits of all fields a mask can address.
+pub const MEMO_FIELD_BITS: u8 = 0b0111_1111;
+
+/// Validates a disclosure mask bitmap:
+/// - only known field bits may be set
+/// - the blinding bit must be clear
+/// - at least one field must be revealed
+pub fn validate_mask_bitmap(bits: u8) -> Result<(), MemoError> {
+	if bits & !MEMO_FIELD_BITS != 0 {
+		return Err(MemoError::InvalidDisclosureMask(
+			"Unknown field bits set in disclosure mask",
+		));
+	}
+	if bits & MemoField::Blinding.bit() != 0 {
+		return Err(MemoError::InvalidDisclosureMask(
+			"Cannot disclose blinding factor — compromises commitment privacy",
+		));
+	}
+	if bits == 0 {
+		return Err(MemoError::InvalidDisclosureMask(
+			"Must disclose at least one field",
+		));
+	}
+	Ok(())
+}
+
+/// Size in bytes of the disclosed data for a mask bitmap.
+pub fn disclosed_data_size(bits: u8) -> usize {
+	MemoField::ALL
+		.iter()
+		.filter(|field| bits & field.bit() != 0)
+		.map(|field| field.size())
+		.sum()
+}
+
+// ============================================================================
+// Extended Disclosure Mask
+// ============================================================================
+
+/// Disclosure mask over all [`MemoDataV3`] fields.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+	all(feature = "parity-scale-codec", feature = "scale-info"),
+	derive(Encode, Decode, TypeInfo)
+)]
+pub struct ExtendedDisclosureMask(u8);
+
+impl ExtendedDisclosureMask {
+	/// Reveals nothing (invalid for a proof — use for constructing custom masks).
+	pub fn none() -> Self {
+		Self(0)
+	}
+
+	/// Reveals all fields except `blinding`.
+	pub fn all() -> Self {
+		Self(MEMO_FIELD_BITS & !MemoField::Blinding.bit())
+	}
+
+	/// Returns the mask with `field` revealed.
+	pub fn with(self, field: MemoField) -> Self {
+		Self(self.0 | field.bit())
+	}
+
+	/// Returns `true` if `field` is revealed.
+	pub fn reveals(&self, field: MemoField) -> bool {
+		self.0 & field.bit() != 0
+	}
+
+	/// Returns the raw bitmap (bit `i` = field `i` of [`MemoField::ALL`]).
+	pub fn to_bitmap(&self) -> u8 {
+		self.0
+	}
+
+	/// Creates a mask from a raw bitmap; unknown bits fail [`Self::validate`].
+	pub fn from_bitmap(bits: u8) -> Self {
+		Self(bits)
+	}
+
+	/// Validates mask safety rules (see [`validate_mask_bitmap`]).
+	pub fn validate(&self) -> Result<(), MemoError> {
+		validate_mask_bitmap(self.0)
+	}
+
+	/// Returns the number of fields that will be revealed.
+	pub fn disclosed_field_count(&self) -> usize {
+		(self.0 & MEMO_FIELD_BITS).count_ones() as usize
+	}
+
+	/// Concatenates the revealed fields of `memo` in serialization order.
+	///
+	/// A revealed [`MemoField::AppData`] discloses an empty field.
+	///
+	/// # Errors
+	/// `InvalidDisclosureMask` if the mask fails validation.
+	pub fn disclose(&self, memo: &MemoDataV2) -> Result<Vec<u8>, MemoError> {
+		self.disclose_v3(&MemoDataV3::from(memo.clone()))
+	}
+
+	/// Concatenates the revealed fields of a V3 `memo` in serialization order.
+	///
+	/// # Errors
+	/// `InvalidDisclosureMask` if the mask fails validation.
+	pub fn disclose_v3(&self, memo: &MemoDataV3) -> Result<Vec<u8>, MemoError> {
+		self.validate()?;
+		let bytes = memo.to_bytes();
+		let mut disclosed = Vec::with_capacity(disclosed_data_size(self.0));
+		for field in MemoField::ALL.iter().filter(|field| self.reveals(**field)) {
+			disclosed.extend_from_slice(&bytes[field.range()]);
+		}
+		Ok(disclosed)
+	}
+}
+
+impl From<&DisclosureMask> for ExtendedDisclosureMask {
+	fn from(mask: &DisclosureMask) -> Self {
+		Self(mask.to_bitmap())
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn memo() -> MemoDataV2 {
+		MemoDataV2::new(1000, [1u8; 32], [2u8; 32], 1 << 40, 7, [9u8; 16])
+	}
+
+	// ===== MemoField Tests =====
+
+	#[test]
+	fn test_field_ranges_cover_memo_data_v3() {
+		let mut end = 0;
+		for field in MemoField::ALL {
+			assert_eq!(field.range().start, end);
+			end = field.range().end;
+		}
+		assert_eq!(end, MEMO_DATA_V3_SIZE);
+		assert_eq!(disclosed_data_size(MEMO_FIELD_BITS), MEMO_DATA_V3_SIZE);
+	}
+
+	#[test]
+	fn test_field_bits_match_legacy_mask() {
+		assert_eq!(
+			ExtendedDisclosureMask::from(&DisclosureMask::all()),
+			ExtendedDisclosureMask::none()
+				.with(MemoField::Value)
+				.with(MemoField::Owner)
+				.with(MemoField::AssetId)
+		);
+		assert_eq!(
+			MemoField::AssetId.bit(),
+			DisclosureMask::value_and_asset().to_bitmap() & !MemoField::Value.bit()
+		);
+	}
+
+	// ===== Validation Tests =====
+
+	#[test]
+	fn test_validate_mask_bitmap() {
+		assert!(validate_mask_bitmap(MemoField::SenderHint.bit()).is_ok());
+		assert!(validate_mask_bitmap(ExtendedDisclosureMask::all().to_bitmap()).is_ok());
+		assert!(validate_mask_bitmap(0).is_err());
+		assert!(validate_mask_bitmap(MemoField::Blinding.bit() | 1).is_err());
+		assert!(validate_mask_bitmap(MemoField::AppData.bit()).is_ok());
+		assert!(validate_mask_bitmap(0b1000_0001).is_err());
+	}
+
+	#[test]
+	fn test_all_never_reveals_blinding() {
+		let mask = ExtendedDisclosureMask::all();
+		assert!(!mask.reveals(MemoField::Blinding));
+		assert_eq!(mask.disclosed_field_count(), 6);
+		assert!(mask.validate().is_ok());
+	}
+
+	// ===== Disclosure Tests =====
+
+	#[test]
+	fn test_disclose_selected_fields() {
+		let mask = ExtendedDisclosureMask::none()
+			.with(MemoField::Value)
+			.with(MemoField::SenderHint);
+		let disclosed = mask.disclose(&memo()).unwrap();
+
+		assert_eq!(disclosed.len(), disclosed_data_size(mask.to_bitmap()));
+		assert_eq!(&disclosed[..8], &1000u64.to_le_bytes());
+		assert_eq!(&disclosed[8..], &[9u8; 16]);
+	}
+
+	#[test]
+	fn test_disclose_app_data() {
+		let mask = ExtendedDisclosureMask::none().with(MemoField::AppData);
+		let memo_v3 = MemoDataV3::from_v2(memo(), b"order-42").unwrap();
+		let disclosed = mask.disclose_v3(&memo_v3).unwrap();
+
+		assert_eq!(disclosed.len(), disclosed_data_size(mask.to_bitmap()));
+		assert_eq!(disclosed[0], 8);
+		assert_eq!(&disclosed[1..9], b"order-42");
+		assert_eq!(mask.disclose(&memo()).unwrap(), [0u8; 33]);
+	}
+
+	#[test]
+	fn test_disclose_rejects_blinding() {
+		let mask = ExtendedDisclosureMask::all().with(MemoField::Blinding);
+		assert!(mask.disclose(&memo()).is_err());
+	}
+}
@@ -2,6 +2,7 @@
 //!
 //! Controls which note fields are revealed in a selective disclosure proof.
 
+use super::field_mask::validate_mask_bitmap;
 use crate::domain::entities::error::MemoError;
 #[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
 use parity_scale_codec::{Decode, Encode};
@@ -89,21 +90,11 @@ impl DisclosureMask {
 		}
 	}
 
-	/// Validates mask safety rules:
+	/// Validates mask safety rules (see [`validate_mask_bitmap`]):
 	/// - `disclose_blinding` must be `false`
 	/// - At least one field must be revealed
 	pub fn validate(&self) -> Result<(), MemoError> {
-		if self.disclose_blinding {
-			return Err(MemoError::InvalidDisclosureMask(
-				"Cannot disclose blinding factor — compromises commitment privacy",
-			));
-		}
-		if !self.disclose_value && !self.disclose_owner && !self.disclose_asset_id {
-			return Err(MemoError::InvalidDisclosureMask(
-				"Must disclose at least one field (value, owner, or asset_id)",
-			));
-		}
-		Ok(())
+		validate_mask_bitmap(self.to_bitmap())
 	}
 
 	/// Returns the number of fields that will be revealed.
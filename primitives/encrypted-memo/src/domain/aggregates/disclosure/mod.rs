@@ -6,15 +6,20 @@
 //! ## Components
 //!
 //! - [`mask`]    - [`DisclosureMask`] controls which fields are revealed
+//! - [`field_mask`] - [`ExtendedDisclosureMask`] covers every `MemoDataV3` field
 //! - [`signals`] - [`DisclosurePublicSignals`] verified outputs of the circuit
 //! - [`proof`]   - [`DisclosureProof`] bundles proof bytes + signals + mask
 //! - [`partial`] - [`PartialMemoData`] holds `Option` values for revealed fields
 
+pub mod field_mask;
 pub mod mask;
 pub mod partial;
 pub mod proof;
 pub mod signals;
 
+pub use field_mask::{
+	disclosed_data_size, validate_mask_bitmap, ExtendedDisclosureMask, MemoField, MEMO_FIELD_BITS,
+};
 pub use mask::DisclosureMask;
 pub use partial::PartialMemoData;
 pub use proof::DisclosureProof;
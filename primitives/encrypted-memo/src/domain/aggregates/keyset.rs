@@ -1,10 +1,19 @@
 //! Key Set aggregate.
 //!
 //! Manages all sub-keys derived from a single master spending key.
+//!
+//! Sub-keys derive from the spending key through an intermediate
+//! [`DerivationKey`], which a hardware wallet can export in place of the
+//! spending key (see [`DerivedKeySet`]).
 
 use crate::domain::{
-	services::key_derivation,
-	value_objects::{EdDSAKey, NullifierKey, ViewingKey},
+	aggregates::derived_keyset::DerivedKeySet,
+	entities::error::MemoError,
+	services::{key_derivation, rotation},
+	value_objects::{
+		ClueKey, DerivationKey, DetectionKey, EdDSAKey, FullViewingKey, IncomingViewingKey,
+		KeyRotationStatement, NullifierKey, OutgoingViewingKey, ViewingKey,
+	},
 };
 
 /// Full key set derived from a single spending key.
@@ -15,7 +24,7 @@ use crate::domain::{
 pub struct KeySet {
 	/// Master spending key (secret — never expose over the wire)
 	spending_key: [u8; 32],
-	/// Viewing key for memo decryption (safe to share with auditors)
+	/// Incoming viewing key for memo decryption (safe to share with auditors)
 	pub viewing_key: ViewingKey,
 	/// Nullifier derivation key
 	pub nullifier_key: NullifierKey,
@@ -40,12 +49,20 @@ impl KeySet {
 	}
 
 	/// Derives a full key set from a master spending key.
+	///
+	/// `spending_key → derivation_key → {viewing, nullifier, eddsa}`
 	pub fn from_spending_key(spending_key: [u8; 32]) -> Self {
+		let DerivedKeySet {
+			viewing_key,
+			nullifier_key,
+			eddsa_key,
+			..
+		} = DerivedKeySet::from_derivation_key(key_derivation::derive_derivation_key(&spending_key));
 		Self {
 			spending_key,
-			viewing_key: key_derivation::derive_viewing_key_from_spending(&spending_key),
-			nullifier_key: key_derivation::derive_nullifier_key_from_spending(&spending_key),
-			eddsa_key: key_derivation::derive_eddsa_key_from_spending(&spending_key),
+			viewing_key,
+			nullifier_key,
+			eddsa_key,
 		}
 	}
 
@@ -56,15 +73,92 @@ impl KeySet {
 		&self.spending_key
 	}
 
+	/// Derives the intermediate key a hardware wallet exports to its host.
+	///
+	/// Grants every sub-key but not spend authority.
+	pub fn derivation_key(&self) -> DerivationKey {
+		key_derivation::derive_derivation_key(&self.spending_key)
+	}
+
+	/// Returns the sub-keys derived from [`Self::derivation_key`].
+	pub fn derived_keys(&self) -> DerivedKeySet {
+		DerivedKeySet::from_derivation_key(self.derivation_key())
+	}
+
 	/// Exports the viewing key for an auditor (read-only access).
 	pub fn export_viewing_key(&self) -> ViewingKey {
 		self.viewing_key.clone()
 	}
 
+	/// Returns the incoming viewing key (decrypts received notes).
+	///
+	/// Same key as [`Self::export_viewing_key`].
+	pub fn incoming_viewing_key(&self) -> IncomingViewingKey {
+		self.viewing_key.clone()
+	}
+
+	/// Derives the outgoing viewing key (recovers sent memos).
+	pub fn outgoing_viewing_key(&self) -> OutgoingViewingKey {
+		key_derivation::derive_outgoing_viewing_key(self.derivation_key().as_bytes())
+	}
+
+	/// Returns the incoming and outgoing viewing keys together.
+	pub fn full_viewing_key(&self) -> FullViewingKey {
+		FullViewingKey::new(self.incoming_viewing_key(), self.outgoing_viewing_key())
+	}
+
 	/// Returns `true` when `vk` matches the viewing key in this key set.
 	pub fn matches_viewing_key(&self, vk: &ViewingKey) -> bool {
 		self.viewing_key == *vk
 	}
+
+	/// Derives the viewing key scoped to `asset_id`.
+	///
+	/// Shareable with an auditor who should only see that asset's memos.
+	pub fn asset_viewing_key(&self, asset_id: u64) -> ViewingKey {
+		key_derivation::derive_asset_viewing_key(self.viewing_key.as_bytes(), asset_id)
+	}
+
+	/// Derives the clue key senders use to tag memos for this key set.
+	pub fn clue_key(&self) -> ClueKey {
+		key_derivation::derive_clue_key(self.viewing_key.as_bytes())
+	}
+
+	/// Derives the ML-KEM-768 public key senders need for hybrid memos.
+	#[cfg(feature = "pq")]
+	pub fn pq_public_key(&self) -> alloc::vec::Vec<u8> {
+		key_derivation::derive_pq_public_key(self.viewing_key.as_bytes())
+	}
+
+	/// Derives a detection key with a false-positive rate of `2^-precision`.
+	///
+	/// Can be delegated to a detection server; it cannot decrypt memos.
+	pub fn detection_key(&self, precision: usize) -> Result<DetectionKey, MemoError> {
+		key_derivation::derive_detection_key(self.viewing_key.as_bytes(), precision)
+	}
+
+	/// Returns this key set with its viewing key replaced by `generation`.
+	///
+	/// Clue, detection and ML-KEM keys follow the viewing key and rotate with it.
+	pub fn with_viewing_key_generation(&self, generation: u32) -> Self {
+		Self {
+			viewing_key: key_derivation::derive_viewing_key_generation(
+				&self.spending_key,
+				generation,
+			),
+			..self.clone()
+		}
+	}
+
+	/// Builds the statement that viewing key generation `to_generation`
+	/// supersedes `from_generation`.
+	pub fn rotation_statement(
+		&self,
+		from_generation: u32,
+		to_generation: u32,
+	) -> Result<KeyRotationStatement, MemoError> {
+		rotation::key_rotation_statement(&self.spending_key, from_generation, to_generation)
+	}
 }
 
 // ============================================================================
@@ -217,6 +311,18 @@ mod tests {
 		assert_ne!(vk1, vk2);
 	}
 
+	// ===== Incoming/Outgoing Viewing Key Tests =====
+
+	#[test]
+	fn test_full_viewing_key_halves() {
+		let keyset = KeySet::from_spending_key([42u8; 32]);
+		let fvk = keyset.full_viewing_key();
+		assert_eq!(fvk.incoming, keyset.export_viewing_key());
+		assert_eq!(fvk.outgoing, keyset.outgoing_viewing_key());
+		assert_ne!(fvk.incoming.as_bytes(), fvk.outgoing.as_bytes());
+		assert_eq!(fvk, keyset.derived_keys().full_viewing_key());
+	}
+
 	// ===== matches_viewing_key Tests =====
 
 	#[test]
@@ -253,6 +359,65 @@ mod tests {
 		assert!(!keyset.matches_viewing_key(&other_vk));
 	}
 
+	#[test]
+	fn test_asset_viewing_key() {
+		let keyset = KeySet::from_spending_key([42u8; 32]);
+		let usd = keyset.asset_viewing_key(1);
+		assert_eq!(usd, keyset.asset_viewing_key(1));
+		assert_ne!(usd, keyset.asset_viewing_key(0));
+		assert!(!keyset.matches_viewing_key(&usd));
+	}
+
+	// ===== Detection Key Tests =====
+
+	#[test]
+	fn test_detection_key_derived_from_viewing_key() {
+		let keyset = KeySet::from_spending_key([8u8; 32]);
+		let detection_key = keyset.detection_key(3).unwrap();
+		assert_eq!(detection_key.precision(), 3);
+		assert_eq!(
+			detection_key,
+			key_derivation::derive_detection_key(keyset.viewing_key.as_bytes(), 3).unwrap()
+		);
+		assert_eq!(
+			keyset.clue_key(),
+			key_derivation::derive_clue_key(keyset.viewing_key.as_bytes())
+		);
+		assert_eq!(
+			keyset.detection_key(0),
+			Err(MemoError::InvalidDetectionPrecision(0))
+		);
+	}
+
+	// ===== Key Rotation Tests =====
+
+	#[test]
+	fn test_with_viewing_key_generation() {
+		let keys = KeySet::from_spending_key([5u8; 32]);
+		assert_eq!(keys.with_viewing_key_generation(0), keys);
+
+		let rotated = keys.with_viewing_key_generation(1);
+		assert_ne!(rotated.viewing_key, keys.viewing_key);
+		assert_eq!(rotated.spending_key(), keys.spending_key());
+		assert_eq!(rotated.nullifier_key, keys.nullifier_key);
+		assert_ne!(rotated.clue_key(), keys.clue_key());
+	}
+
+	#[test]
+	fn test_rotation_statement() {
+		let keys = KeySet::from_spending_key([5u8; 32]);
+		let statement = keys.rotation_statement(0, 1).unwrap();
+		assert!(rotation::verify_key_rotation_statement(
+			&statement,
+			keys.viewing_key.as_bytes(),
+			keys.with_viewing_key_generation(1).viewing_key.as_bytes(),
+		));
+		assert_eq!(
+			keys.rotation_statement(1, 0),
+			Err(MemoError::InvalidKeyRotation)
+		);
+	}
+
 	// ===== Clone and PartialEq Tests =====
 
 	#[test]
@@ -4,8 +4,10 @@
 //!
 //! ## Aggregates
 //!
+//! - [`derived_keyset`] - Sub-keys derived without the spending key (hardware wallets)
 //! - [`disclosure`] - Selective disclosure proof structures (mask, signals, proof, partial)
 //! - [`keyset`]     - Wallet key management (spending, viewing, nullifier, EdDSA)
 
+pub mod derived_keyset;
 pub mod disclosure;
 pub mod keyset;
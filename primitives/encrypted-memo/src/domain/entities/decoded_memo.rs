@@ -0,0 +1,144 @@
+//! DecodedMemo entity.
+//!
+//! Result of decoding an on-chain memo of any format, together with the
+//! [`MemoFormat`] it was stored in.
+
+use super::versioned_memo::VersionedMemo;
+use crate::domain::value_objects::{
+	constants::{
+		MEMO_FLAG_COMPRESSED, MEMO_VERSION_EPHEMERAL, MEMO_VERSION_ESCROW, MEMO_VERSION_HYBRID,
+		MEMO_VERSION_MULTI, MEMO_VERSION_POINTER, MEMO_VERSION_V1, MEMO_VERSION_V2,
+		MEMO_VERSION_V3, MEMO_VERSION_XCHACHA,
+	},
+	DetectionTag,
+};
+
+/// Envelope format of an on-chain memo.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemoFormat {
+	/// Headerless V1 memo
+	V1,
+	/// V2 memo keyed by the viewing key
+	V2,
+	/// V3 memo carrying application data
+	V3,
+	/// V2 memo with a compressed payload
+	CompressedV2,
+	/// V2 memo keyed by an ephemeral X25519 agreement
+	Ephemeral,
+	/// One payload with a wrapped key per recipient
+	MultiRecipient,
+	/// V1 memo carrying an auditor escrow slot
+	Escrow,
+	/// V2 memo under XChaCha20-Poly1305
+	XChaCha,
+	/// V2 memo under the hybrid X25519 + ML-KEM-768 KEM
+	Hybrid,
+	/// Content-hash pointer to an off-chain ciphertext
+	Pointer,
+}
+
+impl MemoFormat {
+	/// Envelope header byte (`MEMO_VERSION_V1` for headerless memos)
+	pub fn version(&self) -> u8 {
+		match self {
+			Self::V1 => MEMO_VERSION_V1,
+			Self::V2 => MEMO_VERSION_V2,
+			Self::V3 => MEMO_VERSION_V3,
+			Self::CompressedV2 => MEMO_VERSION_V2 | MEMO_FLAG_COMPRESSED,
+			Self::Ephemeral => MEMO_VERSION_EPHEMERAL,
+			Self::MultiRecipient => MEMO_VERSION_MULTI,
+			Self::Escrow => MEMO_VERSION_ESCROW,
+			Self::XChaCha => MEMO_VERSION_XCHACHA,
+			Self::Hybrid => MEMO_VERSION_HYBRID,
+			Self::Pointer => MEMO_VERSION_POINTER,
+		}
+	}
+}
+
+/// An on-chain memo decoded for its recipient.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodedMemo {
+	/// Memo decrypted from its on-chain envelope
+	Note {
+		/// Envelope format the memo was stored in
+		format: MemoFormat,
+		/// Detection tag, if the envelope was tagged
+		detection_tag: Option<DetectionTag>,
+		/// Decrypted payload
+		memo: VersionedMemo,
+	},
+	/// Pointer to an off-chain ciphertext that must be fetched first
+	OffChain {
+		/// SHA-256 of the off-chain ciphertext
+		content_hash: [u8; 32],
+	},
+}
+
+impl DecodedMemo {
+	/// Decrypted payload, `None` for off-chain pointers
+	pub fn memo(&self) -> Option<&VersionedMemo> {
+		match self {
+			Self::Note { memo, .. } => Some(memo),
+			Self::OffChain { .. } => None,
+		}
+	}
+
+	/// Envelope format
+	pub fn format(&self) -> MemoFormat {
+		match self {
+			Self::Note { format, .. } => *format,
+			Self::OffChain { .. } => MemoFormat::Pointer,
+		}
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::entities::memo_data::MemoData;
+
+	#[test]
+	fn test_format_versions_are_distinct() {
+		let formats = [
+			MemoFormat::V1,
+			MemoFormat::V2,
+			MemoFormat::V3,
+			MemoFormat::CompressedV2,
+			MemoFormat::Ephemeral,
+			MemoFormat::MultiRecipient,
+			MemoFormat::Escrow,
+			MemoFormat::XChaCha,
+			MemoFormat::Hybrid,
+			MemoFormat::Pointer,
+		];
+		for i in 0..formats.len() {
+			for j in (i + 1)..formats.len() {
+				assert_ne!(formats[i].version(), formats[j].version());
+			}
+		}
+		assert_eq!(MemoFormat::CompressedV2.version(), 0x82);
+	}
+
+	#[test]
+	fn test_decoded_memo_accessors() {
+		let memo = VersionedMemo::V1(MemoData::new(5, [1u8; 32], [2u8; 32], 0));
+		let note = DecodedMemo::Note {
+			format: MemoFormat::V1,
+			detection_tag: None,
+			memo: memo.clone(),
+		};
+		assert_eq!(note.memo(), Some(&memo));
+		assert_eq!(note.format(), MemoFormat::V1);
+
+		let pointer = DecodedMemo::OffChain {
+			content_hash: [3u8; 32],
+		};
+		assert_eq!(pointer.memo(), None);
+		assert_eq!(pointer.format(), MemoFormat::Pointer);
+	}
+}
@@ -21,6 +21,38 @@ pub enum MemoError {
 	InvalidDisclosureData,
 	/// Invalid or inconsistent disclosure proof
 	InvalidProof(&'static str),
+	/// Public key is low-order (key agreement would not be contributory)
+	InvalidPublicKey,
+	/// Escrowed key has the wrong length
+	InvalidEscrowedKey,
+	/// Memo envelope has an unknown version header
+	UnsupportedMemoVersion(u8),
+	/// Multi-recipient memo has no recipients or more than the maximum
+	InvalidRecipientCount(usize),
+	/// Memo is not an escrow envelope
+	MissingEscrowSlot,
+	/// Detection precision outside `1..=MAX_DETECTION_PRECISION`
+	InvalidDetectionPrecision(usize),
+	/// Detection key, clue key or detection tag has the wrong length
+	InvalidDetectionEncoding,
+	/// On-chain memo is not an off-chain memo pointer
+	InvalidMemoPointer,
+	/// Fetched off-chain ciphertext does not match the on-chain content hash
+	ContentHashMismatch,
+	/// Key rotation must move to a later viewing key generation
+	InvalidKeyRotation,
+	/// KeySet backup is malformed or its parameters are out of range
+	InvalidKeySetBackup,
+	/// KeySet backup has an unknown format version
+	UnsupportedBackupVersion(u8),
+	/// Remote EdDSA signer refused or failed to sign
+	RemoteSigningFailed,
+	/// Application data exceeds `MAX_APP_DATA_SIZE`
+	AppDataTooLong,
+	/// Full viewing key encoding has the wrong length
+	InvalidFullViewingKey,
+	/// Stream chunk or header has the wrong length, or the stream is too long
+	InvalidStreamChunk,
 }
 
 impl core::fmt::Display for MemoError {
@@ -35,6 +67,28 @@ impl core::fmt::Display for MemoError {
 			Self::InvalidDisclosureMask(msg) => write!(f, "Invalid disclosure mask: {msg}"),
 			Self::InvalidDisclosureData => write!(f, "Invalid disclosed data format"),
 			Self::InvalidProof(msg) => write!(f, "Invalid disclosure proof: {msg}"),
+			Self::InvalidPublicKey => write!(f, "Invalid key agreement public key"),
+			Self::InvalidEscrowedKey => write!(f, "Invalid escrowed key encoding"),
+			Self::UnsupportedMemoVersion(version) => {
+				write!(f, "Unsupported memo version: {version}")
+			}
+			Self::InvalidRecipientCount(count) => write!(f, "Invalid recipient count: {count}"),
+			Self::MissingEscrowSlot => write!(f, "Memo has no escrow slot"),
+			Self::InvalidDetectionPrecision(precision) => {
+				write!(f, "Invalid detection precision: {precision}")
+			}
+			Self::InvalidDetectionEncoding => write!(f, "Invalid detection key or tag encoding"),
+			Self::InvalidMemoPointer => write!(f, "Memo is not an off-chain memo pointer"),
+			Self::ContentHashMismatch => write!(f, "Off-chain memo does not match content hash"),
+			Self::InvalidKeyRotation => write!(f, "Invalid viewing key rotation"),
+			Self::InvalidKeySetBackup => write!(f, "Invalid key set backup"),
+			Self::UnsupportedBackupVersion(version) => {
+				write!(f, "Unsupported key set backup version: {version}")
+			}
+			Self::RemoteSigningFailed => write!(f, "Remote EdDSA signing failed"),
+			Self::AppDataTooLong => write!(f, "Application data too long"),
+			Self::InvalidFullViewingKey => write!(f, "Invalid full viewing key encoding"),
+			Self::InvalidStreamChunk => write!(f, "Invalid stream chunk"),
 		}
 	}
 }
@@ -152,4 +206,88 @@ mod tests {
 		let msg = format!("{}", MemoError::InvalidProof("empty proof bytes"));
 		assert!(msg.contains("empty proof bytes"));
 	}
+
+	#[test]
+	fn test_display_invalid_public_key() {
+		let msg = format!("{}", MemoError::InvalidPublicKey);
+		assert!(msg.to_lowercase().contains("public key"));
+	}
+
+	#[test]
+	fn test_display_invalid_escrowed_key() {
+		let msg = format!("{}", MemoError::InvalidEscrowedKey);
+		assert!(msg.to_lowercase().contains("escrowed key"));
+	}
+
+	#[test]
+	fn test_display_unsupported_memo_version() {
+		let msg = format!("{}", MemoError::UnsupportedMemoVersion(7));
+		assert_eq!(msg, "Unsupported memo version: 7");
+	}
+
+	#[test]
+	fn test_display_invalid_recipient_count() {
+		let msg = format!("{}", MemoError::InvalidRecipientCount(9));
+		assert_eq!(msg, "Invalid recipient count: 9");
+	}
+
+	#[test]
+	fn test_display_missing_escrow_slot() {
+		let msg = format!("{}", MemoError::MissingEscrowSlot);
+		assert!(msg.contains("escrow slot"));
+	}
+
+	#[test]
+	fn test_display_detection_errors() {
+		let msg = format!("{}", MemoError::InvalidDetectionPrecision(17));
+		assert_eq!(msg, "Invalid detection precision: 17");
+		let msg = format!("{}", MemoError::InvalidDetectionEncoding);
+		assert!(msg.contains("detection key"));
+	}
+
+	#[test]
+	fn test_display_offchain_memo_errors() {
+		let msg = format!("{}", MemoError::InvalidMemoPointer);
+		assert!(msg.contains("memo pointer"));
+		let msg = format!("{}", MemoError::ContentHashMismatch);
+		assert!(msg.contains("content hash"));
+	}
+
+	#[test]
+	fn test_display_invalid_key_rotation() {
+		let msg = format!("{}", MemoError::InvalidKeyRotation);
+		assert!(msg.contains("key rotation"));
+	}
+
+	#[test]
+	fn test_display_keyset_backup_errors() {
+		let msg = format!("{}", MemoError::InvalidKeySetBackup);
+		assert!(msg.contains("key set backup"));
+		let msg = format!("{}", MemoError::UnsupportedBackupVersion(2));
+		assert_eq!(msg, "Unsupported key set backup version: 2");
+	}
+
+	#[test]
+	fn test_display_remote_signing_failed() {
+		let msg = format!("{}", MemoError::RemoteSigningFailed);
+		assert!(msg.contains("signing failed"));
+	}
+
+	#[test]
+	fn test_display_app_data_too_long() {
+		let msg = format!("{}", MemoError::AppDataTooLong);
+		assert!(msg.contains("Application data"));
+	}
+
+	#[test]
+	fn test_display_invalid_full_viewing_key() {
+		let msg = format!("{}", MemoError::InvalidFullViewingKey);
+		assert!(msg.contains("full viewing key"));
+	}
+
+	#[test]
+	fn test_display_invalid_stream_chunk() {
+		let msg = format!("{}", MemoError::InvalidStreamChunk);
+		assert!(msg.contains("stream chunk"));
+	}
 }
@@ -3,7 +3,16 @@
 //! Plaintext content of an encrypted note memo.
 
 use super::error::MemoError;
-use crate::domain::value_objects::constants::{MAX_ENCRYPTED_MEMO_SIZE, MIN_ENCRYPTED_MEMO_SIZE};
+use crate::domain::value_objects::constants::{
+	DETECTION_TAG_SIZE, ENCRYPTED_MEMO_EPHEMERAL_SIZE, ENCRYPTED_MEMO_ESCROW_SIZE,
+	ENCRYPTED_MEMO_HYBRID_SIZE, ENCRYPTED_MEMO_V2_SIZE, ENCRYPTED_MEMO_V3_SIZE,
+	ENCRYPTED_MEMO_XCHACHA_SIZE, MAX_COMPRESSED_MEMO_SIZE, MAX_ENCRYPTED_MEMO_SIZE,
+	MAX_MEMO_RECIPIENTS, MAX_ONCHAIN_MEMO_SIZE, MEMO_FLAG_COMPRESSED, MEMO_POINTER_SIZE,
+	MEMO_VERSION_EPHEMERAL, MEMO_VERSION_ESCROW, MEMO_VERSION_HYBRID, MEMO_VERSION_MULTI,
+	MEMO_VERSION_POINTER, MEMO_VERSION_SIZE, MEMO_VERSION_TAGGED, MEMO_VERSION_V2, MEMO_VERSION_V3,
+	MEMO_VERSION_XCHACHA, MIN_COMPRESSED_MEMO_SIZE, MIN_ENCRYPTED_MEMO_SIZE,
+	MIN_MULTI_RECIPIENT_MEMO_SIZE, WRAPPED_MEMO_KEY_SIZE,
+};
 #[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
 use parity_scale_codec::{Decode, Encode};
 #[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
@@ -83,11 +92,64 @@ impl MemoData {
 	}
 }
 
-/// Returns true when `data` has a valid encrypted memo length (28-104 bytes).
+/// Returns true when `data` has a valid encrypted memo length.
 ///
-/// Valid range: `nonce(12) + MAC(16)` minimum up to `nonce(12) + plaintext(76) + MAC(16)`.
+/// V1 range: `nonce(12) + MAC(16)` minimum up to `nonce(12) + plaintext(76) + MAC(16)`.
+/// V2, V3, ephemeral-key and escrow envelopes are exactly 129, 162, 161 and 185 bytes;
+/// multi-recipient envelopes are sized by their recipient count. All start
+/// with their version byte.
 pub fn is_valid_encrypted_memo(data: &[u8]) -> bool {
 	(MIN_ENCRYPTED_MEMO_SIZE..=MAX_ENCRYPTED_MEMO_SIZE).contains(&data.len())
+		|| (data.len() == ENCRYPTED_MEMO_V2_SIZE && data[0] == MEMO_VERSION_V2)
+		|| (data.len() == ENCRYPTED_MEMO_V3_SIZE && data[0] == MEMO_VERSION_V3)
+		|| (data.len() == ENCRYPTED_MEMO_EPHEMERAL_SIZE && data[0] == MEMO_VERSION_EPHEMERAL)
+		|| (data.len() == ENCRYPTED_MEMO_ESCROW_SIZE && data[0] == MEMO_VERSION_ESCROW)
+		|| (data.len() == ENCRYPTED_MEMO_XCHACHA_SIZE && data[0] == MEMO_VERSION_XCHACHA)
+		|| ((MIN_COMPRESSED_MEMO_SIZE..=MAX_COMPRESSED_MEMO_SIZE).contains(&data.len())
+			&& data[0] == MEMO_VERSION_V2 | MEMO_FLAG_COMPRESSED)
+		|| (data.len() == ENCRYPTED_MEMO_HYBRID_SIZE && data[0] == MEMO_VERSION_HYBRID)
+		|| is_valid_multi_recipient_memo(data)
+		|| is_valid_tagged_memo(data)
+}
+
+/// Returns true when `data` is a memo the shielded pool stores on-chain.
+///
+/// Any [`is_valid_encrypted_memo`] envelope of at most [`MAX_ONCHAIN_MEMO_SIZE`]
+/// bytes whose headerless V1 memo, tagged or not, carries the full 104 bytes,
+/// plus off-chain memo pointers. The envelope is identified by its version
+/// byte, so on-chain validation follows this crate's size table.
+pub fn is_valid_onchain_memo(data: &[u8]) -> bool {
+	if data.len() == MEMO_POINTER_SIZE && data[0] == MEMO_VERSION_POINTER {
+		return true;
+	}
+	let untagged = if data.len() > MAX_ENCRYPTED_MEMO_SIZE && data[0] == MEMO_VERSION_TAGGED {
+		data.get(MEMO_VERSION_SIZE + DETECTION_TAG_SIZE..)
+			.unwrap_or_default()
+	} else {
+		data
+	};
+	data.len() <= MAX_ONCHAIN_MEMO_SIZE
+		&& untagged.len() >= MAX_ENCRYPTED_MEMO_SIZE
+		&& is_valid_encrypted_memo(data)
+}
+
+fn is_valid_multi_recipient_memo(data: &[u8]) -> bool {
+	if data.len() < MIN_MULTI_RECIPIENT_MEMO_SIZE || data[0] != MEMO_VERSION_MULTI {
+		return false;
+	}
+	let count = data[1] as usize;
+	(1..=MAX_MEMO_RECIPIENTS).contains(&count)
+		&& data.len() == MIN_MULTI_RECIPIENT_MEMO_SIZE + (count - 1) * WRAPPED_MEMO_KEY_SIZE
+}
+
+fn is_valid_tagged_memo(data: &[u8]) -> bool {
+	if data.len() <= MAX_ENCRYPTED_MEMO_SIZE || data[0] != MEMO_VERSION_TAGGED {
+		return false;
+	}
+	let inner = &data[(MEMO_VERSION_SIZE + DETECTION_TAG_SIZE).min(data.len())..];
+	// Tags do not nest
+	let nested = inner.len() > MAX_ENCRYPTED_MEMO_SIZE && inner[0] == MEMO_VERSION_TAGGED;
+	!nested && is_valid_encrypted_memo(inner)
 }
 
 // ============================================================================
@@ -189,6 +251,146 @@ mod tests {
 		assert!(!is_valid_encrypted_memo(&[0u8; 105]));
 	}
 
+	#[test]
+	fn test_v2_envelope_size() {
+		let mut envelope = [0u8; ENCRYPTED_MEMO_V2_SIZE];
+		assert!(!is_valid_encrypted_memo(&envelope));
+		envelope[0] = MEMO_VERSION_V2;
+		assert!(is_valid_encrypted_memo(&envelope));
+	}
+
+	#[test]
+	fn test_v3_envelope_size() {
+		let mut envelope = [0u8; ENCRYPTED_MEMO_V3_SIZE];
+		assert!(!is_valid_encrypted_memo(&envelope));
+		envelope[0] = MEMO_VERSION_V3;
+		assert!(is_valid_encrypted_memo(&envelope));
+	}
+
+	#[test]
+	fn test_ephemeral_envelope_size() {
+		let mut envelope = [0u8; ENCRYPTED_MEMO_EPHEMERAL_SIZE];
+		envelope[0] = MEMO_VERSION_V2;
+		assert!(!is_valid_encrypted_memo(&envelope));
+		envelope[0] = MEMO_VERSION_EPHEMERAL;
+		assert!(is_valid_encrypted_memo(&envelope));
+	}
+
+	#[test]
+	fn test_escrow_envelope_size() {
+		let mut envelope = [0u8; ENCRYPTED_MEMO_ESCROW_SIZE];
+		assert!(!is_valid_encrypted_memo(&envelope));
+		envelope[0] = MEMO_VERSION_ESCROW;
+		assert!(is_valid_encrypted_memo(&envelope));
+	}
+
+	#[test]
+	fn test_xchacha_envelope_size() {
+		let mut envelope = [0u8; ENCRYPTED_MEMO_XCHACHA_SIZE];
+		assert!(!is_valid_encrypted_memo(&envelope));
+		envelope[0] = MEMO_VERSION_XCHACHA;
+		assert!(is_valid_encrypted_memo(&envelope));
+	}
+
+	#[test]
+	fn test_hybrid_envelope_size() {
+		let mut envelope = alloc::vec![0u8; ENCRYPTED_MEMO_HYBRID_SIZE];
+		assert!(!is_valid_encrypted_memo(&envelope));
+		envelope[0] = MEMO_VERSION_HYBRID;
+		assert!(is_valid_encrypted_memo(&envelope));
+	}
+
+	#[test]
+	fn test_compressed_envelope_size() {
+		let mut envelope = [0u8; MAX_COMPRESSED_MEMO_SIZE + 1];
+		envelope[0] = MEMO_VERSION_V2 | MEMO_FLAG_COMPRESSED;
+		assert!(is_valid_encrypted_memo(
+			&envelope[..MIN_COMPRESSED_MEMO_SIZE]
+		));
+		assert!(is_valid_encrypted_memo(
+			&envelope[..MAX_COMPRESSED_MEMO_SIZE]
+		));
+		assert!(!is_valid_encrypted_memo(&envelope));
+	}
+
+	#[test]
+	fn test_multi_recipient_envelope_size() {
+		let mut envelope = [0u8; MIN_MULTI_RECIPIENT_MEMO_SIZE + WRAPPED_MEMO_KEY_SIZE];
+		envelope[0] = MEMO_VERSION_MULTI;
+		envelope[1] = 2;
+		assert!(is_valid_encrypted_memo(&envelope));
+		envelope[1] = 3;
+		assert!(!is_valid_encrypted_memo(&envelope));
+		envelope[1] = 0;
+		assert!(!is_valid_encrypted_memo(
+			&envelope[..MIN_MULTI_RECIPIENT_MEMO_SIZE]
+		));
+	}
+
+	#[test]
+	fn test_tagged_envelope_size() {
+		let mut envelope = [0u8; 1 + DETECTION_TAG_SIZE + MAX_ENCRYPTED_MEMO_SIZE];
+		envelope[0] = MEMO_VERSION_TAGGED;
+		assert!(is_valid_encrypted_memo(&envelope));
+
+		let mut bad_inner = [0u8; 1 + DETECTION_TAG_SIZE + MAX_ENCRYPTED_MEMO_SIZE + 6];
+		bad_inner[0] = MEMO_VERSION_TAGGED;
+		assert!(!is_valid_encrypted_memo(&bad_inner));
+
+		let mut nested = [0u8; 2 * (1 + DETECTION_TAG_SIZE) + MAX_ENCRYPTED_MEMO_SIZE];
+		nested[0] = MEMO_VERSION_TAGGED;
+		nested[1 + DETECTION_TAG_SIZE] = MEMO_VERSION_TAGGED;
+		assert!(!is_valid_encrypted_memo(&nested));
+	}
+
+	#[test]
+	fn test_onchain_memo_sizes() {
+		use crate::domain::value_objects::constants::MAX_MULTI_RECIPIENT_MEMO_SIZE;
+
+		let envelope = |version: u8, len: usize| {
+			let mut envelope = alloc::vec![0u8; len];
+			envelope[0] = version;
+			envelope
+		};
+
+		assert!(is_valid_onchain_memo(&[0u8; MAX_ENCRYPTED_MEMO_SIZE]));
+		assert!(!is_valid_onchain_memo(&[0u8; MAX_ENCRYPTED_MEMO_SIZE - 1]));
+		assert!(is_valid_onchain_memo(&envelope(
+			MEMO_VERSION_POINTER,
+			MEMO_POINTER_SIZE
+		)));
+		for (version, len) in [
+			(MEMO_VERSION_V2, ENCRYPTED_MEMO_V2_SIZE),
+			(MEMO_VERSION_V3, ENCRYPTED_MEMO_V3_SIZE),
+			(MEMO_VERSION_EPHEMERAL, ENCRYPTED_MEMO_EPHEMERAL_SIZE),
+			(MEMO_VERSION_ESCROW, ENCRYPTED_MEMO_ESCROW_SIZE),
+			(MEMO_VERSION_XCHACHA, ENCRYPTED_MEMO_XCHACHA_SIZE),
+			(
+				MEMO_VERSION_V2 | MEMO_FLAG_COMPRESSED,
+				MAX_COMPRESSED_MEMO_SIZE,
+			),
+		] {
+			assert!(is_valid_onchain_memo(&envelope(version, len)));
+			assert!(!is_valid_onchain_memo(&envelope(version, len + 1)));
+		}
+
+		let mut multi = envelope(MEMO_VERSION_MULTI, MAX_MULTI_RECIPIENT_MEMO_SIZE);
+		multi[1] = MAX_MEMO_RECIPIENTS as u8;
+		assert!(is_valid_onchain_memo(&multi));
+
+		let mut tagged = envelope(MEMO_VERSION_TAGGED, MAX_ONCHAIN_MEMO_SIZE);
+		tagged[1 + DETECTION_TAG_SIZE..].copy_from_slice(&multi);
+		assert!(is_valid_onchain_memo(&tagged));
+		let short_inner = envelope(MEMO_VERSION_TAGGED, 1 + DETECTION_TAG_SIZE + 80);
+		assert!(!is_valid_onchain_memo(&short_inner));
+
+		// Hybrid memos go off-chain behind a pointer
+		assert!(!is_valid_onchain_memo(&envelope(
+			MEMO_VERSION_HYBRID,
+			ENCRYPTED_MEMO_HYBRID_SIZE
+		)));
+	}
+
 	#[test]
 	fn test_valid_mid_size() {
 		assert!(is_valid_encrypted_memo(&[0u8; 60]));
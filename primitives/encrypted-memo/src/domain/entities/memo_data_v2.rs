@@ -0,0 +1,139 @@
+//! MemoDataV2 entity.
+//!
+//! Plaintext of version 2 memos: the V1 note fields with a 64-bit asset id,
+//! plus the recipient's diversifier index and an opaque sender hint.
+
+use super::error::MemoError;
+use crate::domain::value_objects::constants::{MEMO_DATA_V2_SIZE, SENDER_HINT_SIZE};
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use scale_info::TypeInfo;
+
+/// Plaintext memo data carried inside a V2 encrypted note.
+///
+/// Serialized layout (100 bytes):
+/// `value(8) || owner_pk(32) || blinding(32) || asset_id(8) || diversifier_index(4) || sender_hint(16)`
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+	all(feature = "parity-scale-codec", feature = "scale-info"),
+	derive(Encode, Decode, TypeInfo)
+)]
+pub struct MemoDataV2 {
+	/// Token amount in the note
+	pub value: u64,
+	/// Owner's public key (32 bytes)
+	pub owner_pk: [u8; 32],
+	/// Random blinding factor (32 bytes)
+	pub blinding: [u8; 32],
+	/// Asset identifier (0 = native token)
+	pub asset_id: u64,
+	/// Diversifier index of the receiving address
+	pub diversifier_index: u32,
+	/// Opaque hint identifying the sender to the recipient (zero = none)
+	pub sender_hint: [u8; SENDER_HINT_SIZE],
+}
+
+impl MemoDataV2 {
+	/// Creates new V2 memo data.
+	pub fn new(
+		value: u64,
+		owner_pk: [u8; 32],
+		blinding: [u8; 32],
+		asset_id: u64,
+		diversifier_index: u32,
+		sender_hint: [u8; SENDER_HINT_SIZE],
+	) -> Self {
+		Self {
+			value,
+			owner_pk,
+			blinding,
+			asset_id,
+			diversifier_index,
+			sender_hint,
+		}
+	}
+
+	/// Serializes to bytes (100 bytes: 8+32+32+8+4+16).
+	pub fn to_bytes(&self) -> [u8; MEMO_DATA_V2_SIZE] {
+		let mut bytes = [0u8; MEMO_DATA_V2_SIZE];
+		bytes[0..8].copy_from_slice(&self.value.to_le_bytes());
+		bytes[8..40].copy_from_slice(&self.owner_pk);
+		bytes[40..72].copy_from_slice(&self.blinding);
+		bytes[72..80].copy_from_slice(&self.asset_id.to_le_bytes());
+		bytes[80..84].copy_from_slice(&self.diversifier_index.to_le_bytes());
+		bytes[84..100].copy_from_slice(&self.sender_hint);
+		bytes
+	}
+
+	/// Deserializes from bytes (must be exactly 100 bytes).
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, MemoError> {
+		if bytes.len() != MEMO_DATA_V2_SIZE {
+			return Err(MemoError::InvalidNoteData);
+		}
+
+		let mut value = [0u8; 8];
+		let mut owner_pk = [0u8; 32];
+		let mut blinding = [0u8; 32];
+		let mut asset_id = [0u8; 8];
+		let mut diversifier_index = [0u8; 4];
+		let mut sender_hint = [0u8; SENDER_HINT_SIZE];
+		value.copy_from_slice(&bytes[0..8]);
+		owner_pk.copy_from_slice(&bytes[8..40]);
+		blinding.copy_from_slice(&bytes[40..72]);
+		asset_id.copy_from_slice(&bytes[72..80]);
+		diversifier_index.copy_from_slice(&bytes[80..84]);
+		sender_hint.copy_from_slice(&bytes[84..100]);
+
+		Ok(Self {
+			value: u64::from_le_bytes(value),
+			owner_pk,
+			blinding,
+			asset_id: u64::from_le_bytes(asset_id),
+			diversifier_index: u32::from_le_bytes(diversifier_index),
+			sender_hint,
+		})
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sample() -> MemoDataV2 {
+		MemoDataV2::new(1000, [1u8; 32], [2u8; 32], u64::MAX, 7, [9u8; 16])
+	}
+
+	#[test]
+	fn test_bytes_roundtrip() {
+		let memo = sample();
+		assert_eq!(MemoDataV2::from_bytes(&memo.to_bytes()), Ok(memo));
+	}
+
+	#[test]
+	fn test_to_bytes_field_layout() {
+		let bytes = sample().to_bytes();
+		assert_eq!(&bytes[0..8], &1000u64.to_le_bytes());
+		assert_eq!(&bytes[8..40], &[1u8; 32]);
+		assert_eq!(&bytes[40..72], &[2u8; 32]);
+		assert_eq!(&bytes[72..80], &u64::MAX.to_le_bytes());
+		assert_eq!(&bytes[80..84], &7u32.to_le_bytes());
+		assert_eq!(&bytes[84..100], &[9u8; 16]);
+	}
+
+	#[test]
+	fn test_from_bytes_wrong_length() {
+		assert_eq!(
+			MemoDataV2::from_bytes(&[0u8; 76]),
+			Err(MemoError::InvalidNoteData)
+		);
+		assert_eq!(
+			MemoDataV2::from_bytes(&[0u8; MEMO_DATA_V2_SIZE + 1]),
+			Err(MemoError::InvalidNoteData)
+		);
+	}
+}
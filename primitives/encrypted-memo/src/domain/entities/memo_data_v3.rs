@@ -0,0 +1,226 @@
+//! MemoDataV3 entity.
+//!
+//! Plaintext of version 3 memos: the V2 fields plus a bounded, opaque
+//! application data field, so dapps can attach references (order ids,
+//! invoice hashes) to shielded payments.
+
+use super::{error::MemoError, memo_data_v2::MemoDataV2};
+use crate::domain::value_objects::constants::{
+	MAX_APP_DATA_SIZE, MEMO_DATA_V2_SIZE, MEMO_DATA_V3_SIZE, SENDER_HINT_SIZE,
+};
+
+/// Plaintext memo data carried inside a V3 encrypted note.
+///
+/// Serialized layout (133 bytes):
+/// `memo_data_v2(100) || app_data_len(1) || app_data(32)`
+///
+/// Application data is zero-padded to [`MAX_APP_DATA_SIZE`], so every V3
+/// memo has the same length whatever it carries.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MemoDataV3 {
+	/// Token amount in the note
+	pub value: u64,
+	/// Owner's public key (32 bytes)
+	pub owner_pk: [u8; 32],
+	/// Random blinding factor (32 bytes)
+	pub blinding: [u8; 32],
+	/// Asset identifier (0 = native token)
+	pub asset_id: u64,
+	/// Diversifier index of the receiving address
+	pub diversifier_index: u32,
+	/// Opaque hint identifying the sender to the recipient (zero = none)
+	pub sender_hint: [u8; SENDER_HINT_SIZE],
+	app_data: [u8; MAX_APP_DATA_SIZE],
+	app_data_len: u8,
+}
+
+impl MemoDataV3 {
+	/// Creates new V3 memo data.
+	///
+	/// # Errors
+	/// `AppDataTooLong` if `app_data` exceeds [`MAX_APP_DATA_SIZE`] bytes.
+	pub fn new(
+		value: u64,
+		owner_pk: [u8; 32],
+		blinding: [u8; 32],
+		asset_id: u64,
+		diversifier_index: u32,
+		sender_hint: [u8; SENDER_HINT_SIZE],
+		app_data: &[u8],
+	) -> Result<Self, MemoError> {
+		Self::from_v2(
+			MemoDataV2::new(
+				value,
+				owner_pk,
+				blinding,
+				asset_id,
+				diversifier_index,
+				sender_hint,
+			),
+			app_data,
+		)
+	}
+
+	/// Attaches application data to V2 memo data.
+	///
+	/// # Errors
+	/// `AppDataTooLong` if `app_data` exceeds [`MAX_APP_DATA_SIZE`] bytes.
+	pub fn from_v2(memo: MemoDataV2, app_data: &[u8]) -> Result<Self, MemoError> {
+		if app_data.len() > MAX_APP_DATA_SIZE {
+			return Err(MemoError::AppDataTooLong);
+		}
+		let mut padded = [0u8; MAX_APP_DATA_SIZE];
+		padded[..app_data.len()].copy_from_slice(app_data);
+
+		Ok(Self {
+			value: memo.value,
+			owner_pk: memo.owner_pk,
+			blinding: memo.blinding,
+			asset_id: memo.asset_id,
+			diversifier_index: memo.diversifier_index,
+			sender_hint: memo.sender_hint,
+			app_data: padded,
+			app_data_len: app_data.len() as u8,
+		})
+	}
+
+	/// Application data attached to the note (empty = none)
+	pub fn app_data(&self) -> &[u8] {
+		&self.app_data[..self.app_data_len as usize]
+	}
+
+	/// The V2 fields, without the application data
+	pub fn to_v2(&self) -> MemoDataV2 {
+		MemoDataV2::new(
+			self.value,
+			self.owner_pk,
+			self.blinding,
+			self.asset_id,
+			self.diversifier_index,
+			self.sender_hint,
+		)
+	}
+
+	/// Serializes to bytes (133 bytes: 100+1+32).
+	pub fn to_bytes(&self) -> [u8; MEMO_DATA_V3_SIZE] {
+		let mut bytes = [0u8; MEMO_DATA_V3_SIZE];
+		bytes[..MEMO_DATA_V2_SIZE].copy_from_slice(&self.to_v2().to_bytes());
+		bytes[MEMO_DATA_V2_SIZE] = self.app_data_len;
+		bytes[MEMO_DATA_V2_SIZE + 1..].copy_from_slice(&self.app_data);
+		bytes
+	}
+
+	/// Deserializes from bytes (must be exactly 133 bytes).
+	///
+	/// Rejects an out-of-range length byte and non-zero padding, so each
+	/// memo has a single encoding.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, MemoError> {
+		if bytes.len() != MEMO_DATA_V3_SIZE {
+			return Err(MemoError::InvalidNoteData);
+		}
+
+		let memo = MemoDataV2::from_bytes(&bytes[..MEMO_DATA_V2_SIZE])?;
+		let len = bytes[MEMO_DATA_V2_SIZE] as usize;
+		if len > MAX_APP_DATA_SIZE {
+			return Err(MemoError::InvalidNoteData);
+		}
+		let (app_data, padding) = bytes[MEMO_DATA_V2_SIZE + 1..].split_at(len);
+		if padding.iter().any(|&byte| byte != 0) {
+			return Err(MemoError::InvalidNoteData);
+		}
+
+		Self::from_v2(memo, app_data)
+	}
+}
+
+impl From<MemoDataV2> for MemoDataV3 {
+	/// V2 memo data with no application data
+	fn from(memo: MemoDataV2) -> Self {
+		Self {
+			value: memo.value,
+			owner_pk: memo.owner_pk,
+			blinding: memo.blinding,
+			asset_id: memo.asset_id,
+			diversifier_index: memo.diversifier_index,
+			sender_hint: memo.sender_hint,
+			app_data: [0u8; MAX_APP_DATA_SIZE],
+			app_data_len: 0,
+		}
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sample() -> MemoDataV3 {
+		MemoDataV3::new(
+			1000,
+			[1u8; 32],
+			[2u8; 32],
+			u64::MAX,
+			7,
+			[9u8; 16],
+			b"order-42",
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn test_bytes_roundtrip() {
+		let memo = sample();
+		assert_eq!(MemoDataV3::from_bytes(&memo.to_bytes()), Ok(memo));
+
+		let full = MemoDataV3::from_v2(sample().to_v2(), &[0xAB; MAX_APP_DATA_SIZE]).unwrap();
+		assert_eq!(MemoDataV3::from_bytes(&full.to_bytes()), Ok(full));
+	}
+
+	#[test]
+	fn test_to_bytes_field_layout() {
+		let memo = sample();
+		let bytes = memo.to_bytes();
+		assert_eq!(&bytes[..MEMO_DATA_V2_SIZE], &memo.to_v2().to_bytes());
+		assert_eq!(bytes[100], 8);
+		assert_eq!(&bytes[101..109], b"order-42");
+		assert!(bytes[109..].iter().all(|&byte| byte == 0));
+	}
+
+	#[test]
+	fn test_app_data_bound() {
+		assert_eq!(
+			MemoDataV3::from_v2(sample().to_v2(), &[0u8; MAX_APP_DATA_SIZE + 1]),
+			Err(MemoError::AppDataTooLong)
+		);
+		assert_eq!(sample().app_data(), b"order-42");
+		assert!(MemoDataV3::from(sample().to_v2()).app_data().is_empty());
+	}
+
+	#[test]
+	fn test_from_bytes_rejects_malformed_app_data() {
+		let mut bytes = sample().to_bytes();
+		bytes[MEMO_DATA_V2_SIZE] = MAX_APP_DATA_SIZE as u8 + 1;
+		assert_eq!(
+			MemoDataV3::from_bytes(&bytes),
+			Err(MemoError::InvalidNoteData)
+		);
+
+		let mut bytes = sample().to_bytes();
+		bytes[MEMO_DATA_V3_SIZE - 1] = 1;
+		assert_eq!(
+			MemoDataV3::from_bytes(&bytes),
+			Err(MemoError::InvalidNoteData)
+		);
+	}
+
+	#[test]
+	fn test_from_bytes_wrong_length() {
+		assert_eq!(
+			MemoDataV3::from_bytes(&[0u8; MEMO_DATA_V2_SIZE]),
+			Err(MemoError::InvalidNoteData)
+		);
+	}
+}
@@ -4,10 +4,22 @@
 //!
 //! ## Modules
 //!
-//! - [`memo_data`] - Plaintext memo entity with serialization and format validation
-//! - [`error`]     - Error types for domain operations
+//! - [`memo_data`]      - Plaintext memo entity with serialization and format validation
+//! - [`memo_data_v2`]   - V2 plaintext memo entity (diversifier index, sender hint)
+//! - [`memo_data_v3`]   - V3 plaintext memo entity (V2 fields plus application data)
+//! - [`versioned_memo`] - Decrypted memo of any supported version
+//! - [`decoded_memo`]   - Decoded on-chain memo tagged with its envelope format
+//! - [`error`]          - Error types for domain operations
 
+pub mod decoded_memo;
 pub mod error;
 pub mod memo_data;
+pub mod memo_data_v2;
+pub mod memo_data_v3;
+pub mod versioned_memo;
 
-pub use memo_data::{is_valid_encrypted_memo, MemoData};
+pub use decoded_memo::{DecodedMemo, MemoFormat};
+pub use memo_data::{is_valid_encrypted_memo, is_valid_onchain_memo, MemoData};
+pub use memo_data_v2::MemoDataV2;
+pub use memo_data_v3::MemoDataV3;
+pub use versioned_memo::VersionedMemo;
@@ -0,0 +1,172 @@
+//! VersionedMemo entity.
+//!
+//! Result of decrypting a memo of any supported version.
+
+use super::{memo_data::MemoData, memo_data_v2::MemoDataV2, memo_data_v3::MemoDataV3};
+use crate::domain::value_objects::constants::{
+	MEMO_VERSION_V1, MEMO_VERSION_V2, MEMO_VERSION_V3, SENDER_HINT_SIZE,
+};
+use alloc::vec::Vec;
+
+/// Decrypted memo, tagged with its format version.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum VersionedMemo {
+	/// Headerless 104-byte memo with a [`MemoData`] payload
+	V1(MemoData),
+	/// Version 2 or ephemeral-key envelope with a [`MemoDataV2`] payload
+	V2(MemoDataV2),
+	/// Version 3 memo with a [`MemoDataV3`] payload
+	V3(MemoDataV3),
+}
+
+impl VersionedMemo {
+	/// Format version
+	pub fn version(&self) -> u8 {
+		match self {
+			Self::V1(_) => MEMO_VERSION_V1,
+			Self::V2(_) => MEMO_VERSION_V2,
+			Self::V3(_) => MEMO_VERSION_V3,
+		}
+	}
+
+	/// Serialized payload (76 bytes for V1, 100 bytes for V2, 133 bytes for V3)
+	pub fn payload_bytes(&self) -> Vec<u8> {
+		match self {
+			Self::V1(memo) => memo.to_bytes().to_vec(),
+			Self::V2(memo) => memo.to_bytes().to_vec(),
+			Self::V3(memo) => memo.to_bytes().to_vec(),
+		}
+	}
+
+	/// Token amount in the note
+	pub fn value(&self) -> u64 {
+		match self {
+			Self::V1(memo) => memo.value,
+			Self::V2(memo) => memo.value,
+			Self::V3(memo) => memo.value,
+		}
+	}
+
+	/// Owner's public key
+	pub fn owner_pk(&self) -> &[u8; 32] {
+		match self {
+			Self::V1(memo) => &memo.owner_pk,
+			Self::V2(memo) => &memo.owner_pk,
+			Self::V3(memo) => &memo.owner_pk,
+		}
+	}
+
+	/// Blinding factor
+	pub fn blinding(&self) -> &[u8; 32] {
+		match self {
+			Self::V1(memo) => &memo.blinding,
+			Self::V2(memo) => &memo.blinding,
+			Self::V3(memo) => &memo.blinding,
+		}
+	}
+
+	/// Asset identifier (V1 ids are widened)
+	pub fn asset_id(&self) -> u64 {
+		match self {
+			Self::V1(memo) => u64::from(memo.asset_id),
+			Self::V2(memo) => memo.asset_id,
+			Self::V3(memo) => memo.asset_id,
+		}
+	}
+
+	/// Diversifier index of the receiving address (V2 and later)
+	pub fn diversifier_index(&self) -> Option<u32> {
+		match self {
+			Self::V1(_) => None,
+			Self::V2(memo) => Some(memo.diversifier_index),
+			Self::V3(memo) => Some(memo.diversifier_index),
+		}
+	}
+
+	/// Sender hint (V2 and later)
+	pub fn sender_hint(&self) -> Option<&[u8; SENDER_HINT_SIZE]> {
+		match self {
+			Self::V1(_) => None,
+			Self::V2(memo) => Some(&memo.sender_hint),
+			Self::V3(memo) => Some(&memo.sender_hint),
+		}
+	}
+
+	/// Application data (V3 only)
+	pub fn app_data(&self) -> Option<&[u8]> {
+		match self {
+			Self::V3(memo) => Some(memo.app_data()),
+			_ => None,
+		}
+	}
+}
+
+impl From<MemoData> for VersionedMemo {
+	fn from(memo: MemoData) -> Self {
+		Self::V1(memo)
+	}
+}
+
+impl From<MemoDataV2> for VersionedMemo {
+	fn from(memo: MemoDataV2) -> Self {
+		Self::V2(memo)
+	}
+}
+
+impl From<MemoDataV3> for VersionedMemo {
+	fn from(memo: MemoDataV3) -> Self {
+		Self::V3(memo)
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_v1_accessors() {
+		let memo = VersionedMemo::from(MemoData::new(5, [1u8; 32], [2u8; 32], u32::MAX));
+		assert_eq!(memo.version(), MEMO_VERSION_V1);
+		assert_eq!(memo.value(), 5);
+		assert_eq!(memo.owner_pk(), &[1u8; 32]);
+		assert_eq!(memo.blinding(), &[2u8; 32]);
+		assert_eq!(memo.asset_id(), u64::from(u32::MAX));
+		assert_eq!(memo.diversifier_index(), None);
+		assert_eq!(memo.sender_hint(), None);
+		assert_eq!(memo.app_data(), None);
+		assert_eq!(memo.payload_bytes().len(), 76);
+	}
+
+	#[test]
+	fn test_v2_accessors() {
+		let memo = VersionedMemo::from(MemoDataV2::new(
+			5,
+			[1u8; 32],
+			[2u8; 32],
+			u64::MAX,
+			3,
+			[4u8; 16],
+		));
+		assert_eq!(memo.version(), MEMO_VERSION_V2);
+		assert_eq!(memo.asset_id(), u64::MAX);
+		assert_eq!(memo.diversifier_index(), Some(3));
+		assert_eq!(memo.sender_hint(), Some(&[4u8; 16]));
+		assert_eq!(memo.payload_bytes().len(), 100);
+	}
+
+	#[test]
+	fn test_v3_accessors() {
+		let memo = VersionedMemo::from(
+			MemoDataV3::new(5, [1u8; 32], [2u8; 32], 9, 3, [4u8; 16], b"inv-7").unwrap(),
+		);
+		assert_eq!(memo.version(), MEMO_VERSION_V3);
+		assert_eq!(memo.asset_id(), 9);
+		assert_eq!(memo.diversifier_index(), Some(3));
+		assert_eq!(memo.app_data(), Some(&b"inv-7"[..]));
+		assert_eq!(memo.payload_bytes().len(), 133);
+	}
+}
@@ -0,0 +1,74 @@
+//! EdDSA Signer port.
+//!
+//! Abstract interface for the circuit's EdDSA signing component. Lets the
+//! EdDSA key stay on a hardware device: the host sends the message to sign and
+//! receives the signature, never the key.
+
+use crate::domain::entities::error::MemoError;
+
+/// Port for producing EdDSA (BabyJubJub) signatures consumed by the circuits.
+pub trait EdDSASigner {
+	/// Returns the packed BabyJubJub public key of the signing key.
+	fn public_key(&self) -> Result<[u8; 32], MemoError>;
+
+	/// Signs a circuit message (a field element, 32 bytes little-endian).
+	///
+	/// Returns `R8(32) || S(32)`.
+	///
+	/// # Errors
+	/// `RemoteSigningFailed` if the device refuses or cannot be reached.
+	fn sign(&self, message: &[u8; 32]) -> Result<[u8; 64], MemoError>;
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Mock device that echoes the message into both signature halves.
+	struct MockDevice {
+		connected: bool,
+	}
+
+	impl EdDSASigner for MockDevice {
+		fn public_key(&self) -> Result<[u8; 32], MemoError> {
+			if !self.connected {
+				return Err(MemoError::RemoteSigningFailed);
+			}
+			Ok([7u8; 32])
+		}
+
+		fn sign(&self, message: &[u8; 32]) -> Result<[u8; 64], MemoError> {
+			if !self.connected {
+				return Err(MemoError::RemoteSigningFailed);
+			}
+			let mut signature = [0u8; 64];
+			signature[..32].copy_from_slice(message);
+			signature[32..].copy_from_slice(message);
+			Ok(signature)
+		}
+	}
+
+	#[test]
+	fn test_sign_returns_signature() {
+		let device = MockDevice { connected: true };
+		assert_eq!(device.public_key(), Ok([7u8; 32]));
+		assert_eq!(&device.sign(&[3u8; 32]).unwrap()[32..], &[3u8; 32]);
+	}
+
+	#[test]
+	fn test_disconnected_device_fails() {
+		let device = MockDevice { connected: false };
+		assert_eq!(device.public_key(), Err(MemoError::RemoteSigningFailed));
+		assert_eq!(device.sign(&[3u8; 32]), Err(MemoError::RemoteSigningFailed));
+	}
+
+	#[test]
+	fn test_signer_usable_as_trait_object() {
+		let device: &dyn EdDSASigner = &MockDevice { connected: true };
+		assert!(device.sign(&[0u8; 32]).is_ok());
+	}
+}
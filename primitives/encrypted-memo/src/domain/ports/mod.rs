@@ -7,9 +7,12 @@
 //!
 //! - [`memo_encryptor`] - ChaCha20Poly1305 encryption contract
 //! - [`key_deriver`]    - SHA-256 key derivation contract
+//! - [`eddsa_signer`]   - Remote EdDSA signing contract (hardware wallets)
 
+pub mod eddsa_signer;
 pub mod key_deriver;
 pub mod memo_encryptor;
 
+pub use eddsa_signer::EdDSASigner;
 pub use key_deriver::KeyDeriver;
 pub use memo_encryptor::MemoEncryptor;
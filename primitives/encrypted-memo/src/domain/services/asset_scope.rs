@@ -0,0 +1,239 @@
+//! Asset-Scoped Viewing Keys
+//!
+//! Memos of assets in an [`AssetScope`] are encrypted under a per-asset
+//! viewing key instead of the base viewing key:
+//!
+//! ```text
+//! asset_viewing_key = SHA256(viewing_key || domain || asset_id_le)
+//! ```
+//!
+//! The wallet keeps the base key and re-derives every scoped key, so it still
+//! reads all of its notes. An auditor given one asset's key reads that asset's
+//! memos only: native-token and other-asset memos stay sealed under keys it
+//! cannot derive.
+
+use alloc::vec::Vec;
+
+use crate::domain::{
+	entities::{error::MemoError, versioned_memo::VersionedMemo},
+	services::{
+		encryption::{decrypt_memo, detect_only, encrypt_memo, encrypt_memo_v2, encrypt_memo_v3},
+		key_derivation::derive_asset_viewing_key,
+	},
+	value_objects::{AssetScope, ViewingKey},
+};
+
+/// Returns the viewing key memos of `asset_id` are encrypted under
+///
+/// The asset-scoped key when `scope` requires one, the base key otherwise.
+pub fn memo_viewing_key(viewing_key: &[u8; 32], scope: &AssetScope, asset_id: u64) -> ViewingKey {
+	if scope.requires_scoped_key(asset_id) {
+		derive_asset_viewing_key(viewing_key, asset_id)
+	} else {
+		ViewingKey::from_bytes(*viewing_key)
+	}
+}
+
+/// Encrypts a memo under the key its asset requires
+///
+/// Produces a plain V1, V2 or V3 envelope matching the payload.
+/// WARNING: Nonce MUST be unique and never reused.
+pub fn encrypt_memo_scoped(
+	memo: &VersionedMemo,
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+	scope: &AssetScope,
+	nonce: &[u8; 12],
+) -> Result<Vec<u8>, MemoError> {
+	let key = memo_viewing_key(viewing_key, scope, memo.asset_id());
+	match memo {
+		VersionedMemo::V1(memo) => encrypt_memo(memo, commitment, key.as_bytes(), nonce),
+		VersionedMemo::V2(memo) => encrypt_memo_v2(memo, commitment, key.as_bytes(), nonce),
+		VersionedMemo::V3(memo) => encrypt_memo_v3(memo, commitment, key.as_bytes(), nonce),
+	}
+}
+
+/// Encrypts a memo under the key its asset requires with a random nonce
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_memo_scoped_random(
+	memo: &VersionedMemo,
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+	scope: &AssetScope,
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut nonce);
+
+	encrypt_memo_scoped(memo, commitment, viewing_key, scope, &nonce)
+}
+
+/// Decrypts a memo sealed under the base key or any scoped key
+///
+/// A memo opened with an asset-scoped key must carry that asset, so a scoped
+/// key cannot be used to pass off notes of another asset.
+///
+/// # Errors
+/// The base-key decryption error when no key opens the memo;
+/// `InvalidNoteData` when a scoped key opens a memo of a different asset.
+pub fn decrypt_memo_scoped(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+	scope: &AssetScope,
+) -> Result<VersionedMemo, MemoError> {
+	let base_error = match decrypt_memo(encrypted, commitment, viewing_key) {
+		Ok(memo) => return Ok(memo),
+		Err(error) => error,
+	};
+
+	for asset_id in scope.assets() {
+		let key = derive_asset_viewing_key(viewing_key, asset_id);
+		if !detect_only(encrypted, commitment, key.as_bytes()) {
+			continue;
+		}
+		let memo = decrypt_memo(encrypted, commitment, key.as_bytes())?;
+		if memo.asset_id() != asset_id {
+			return Err(MemoError::InvalidNoteData);
+		}
+		return Ok(memo);
+	}
+
+	Err(base_error)
+}
+
+/// Attempts scoped decryption, returns None on failure
+pub fn try_decrypt_memo_scoped(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+	scope: &AssetScope,
+) -> Option<VersionedMemo> {
+	decrypt_memo_scoped(encrypted, commitment, viewing_key, scope).ok()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::entities::{memo_data::MemoData, memo_data_v2::MemoDataV2};
+
+	const VIEWING_KEY: [u8; 32] = [31u8; 32];
+	const COMMITMENT: [u8; 32] = [3u8; 32];
+	const NONCE: [u8; 12] = [5u8; 12];
+	const USD: u64 = 1;
+
+	fn scope() -> AssetScope {
+		AssetScope::new([USD])
+	}
+
+	fn memo(asset_id: u64) -> VersionedMemo {
+		VersionedMemo::V2(MemoDataV2::new(
+			500, [1u8; 32], [2u8; 32], asset_id, 0, [0u8; 16],
+		))
+	}
+
+	// ===== Key Selection Tests =====
+
+	#[test]
+	fn test_memo_viewing_key_selects_scoped_key() {
+		assert_eq!(
+			memo_viewing_key(&VIEWING_KEY, &scope(), USD),
+			derive_asset_viewing_key(&VIEWING_KEY, USD)
+		);
+		assert_eq!(
+			memo_viewing_key(&VIEWING_KEY, &scope(), 0),
+			ViewingKey::from_bytes(VIEWING_KEY)
+		);
+	}
+
+	// ===== Encryption Tests =====
+
+	#[test]
+	fn test_scoped_auditor_sees_only_scoped_asset() {
+		let auditor_key = derive_asset_viewing_key(&VIEWING_KEY, USD);
+		let usd =
+			encrypt_memo_scoped(&memo(USD), &COMMITMENT, &VIEWING_KEY, &scope(), &NONCE).unwrap();
+		let native =
+			encrypt_memo_scoped(&memo(0), &COMMITMENT, &VIEWING_KEY, &scope(), &NONCE).unwrap();
+
+		assert_eq!(
+			decrypt_memo(&usd, &COMMITMENT, auditor_key.as_bytes()),
+			Ok(memo(USD))
+		);
+		assert_eq!(
+			decrypt_memo(&native, &COMMITMENT, auditor_key.as_bytes()),
+			Err(MemoError::DecryptionFailed)
+		);
+		assert_eq!(
+			decrypt_memo(&usd, &COMMITMENT, &VIEWING_KEY),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_encrypt_memo_scoped_keeps_payload_version() {
+		let v1 = VersionedMemo::V1(MemoData::new(500, [1u8; 32], [2u8; 32], USD as u32));
+		let encrypted =
+			encrypt_memo_scoped(&v1, &COMMITMENT, &VIEWING_KEY, &scope(), &NONCE).unwrap();
+		assert_eq!(
+			decrypt_memo_scoped(&encrypted, &COMMITMENT, &VIEWING_KEY, &scope()),
+			Ok(v1)
+		);
+	}
+
+	// ===== Decryption Tests =====
+
+	#[test]
+	fn test_owner_decrypts_every_asset() {
+		for asset_id in [0, USD] {
+			let encrypted =
+				encrypt_memo_scoped(&memo(asset_id), &COMMITMENT, &VIEWING_KEY, &scope(), &NONCE)
+					.unwrap();
+			assert_eq!(
+				decrypt_memo_scoped(&encrypted, &COMMITMENT, &VIEWING_KEY, &scope()),
+				Ok(memo(asset_id))
+			);
+		}
+	}
+
+	#[test]
+	fn test_decrypt_memo_scoped_rejects_asset_mismatch() {
+		let key = derive_asset_viewing_key(&VIEWING_KEY, USD);
+		let encrypted = encrypt_memo_v2(
+			&MemoDataV2::new(500, [1u8; 32], [2u8; 32], 2, 0, [0u8; 16]),
+			&COMMITMENT,
+			key.as_bytes(),
+			&NONCE,
+		)
+		.unwrap();
+		assert_eq!(
+			decrypt_memo_scoped(&encrypted, &COMMITMENT, &VIEWING_KEY, &scope()),
+			Err(MemoError::InvalidNoteData)
+		);
+	}
+
+	#[test]
+	fn test_decrypt_memo_scoped_wrong_key() {
+		let encrypted =
+			encrypt_memo_scoped(&memo(USD), &COMMITMENT, &VIEWING_KEY, &scope(), &NONCE).unwrap();
+		assert_eq!(
+			decrypt_memo_scoped(&encrypted, &COMMITMENT, &[9u8; 32], &scope()),
+			Err(MemoError::DecryptionFailed)
+		);
+		assert!(try_decrypt_memo_scoped(
+			&encrypted,
+			&COMMITMENT,
+			&VIEWING_KEY,
+			&AssetScope::none()
+		)
+		.is_none());
+	}
+}
@@ -0,0 +1,369 @@
+//! KeySet Backup Service
+//!
+//! Password-protected serialization of a [`KeySet`] for backups and device
+//! migration. The password is stretched with Argon2id and the key entries are
+//! sealed with ChaCha20Poly1305:
+//!
+//! ```text
+//! key    = Argon2id(password, salt, m_cost, t_cost, p_cost)
+//! backup = version(1) || m_cost(4) || t_cost(4) || p_cost(4) || salt(16) || nonce(12) || ciphertext(entries + 16)
+//! ```
+//!
+//! The header is AEAD associated data. The plaintext is a list of
+//! `tag(1) || length(1) || bytes` entries; importers skip unknown tags, so
+//! keys added by later versions (detection, escrow) do not break older
+//! readers. Only the spending key is required; missing sub-keys are derived
+//! from it. Only compiled with the `backup` feature.
+
+use alloc::vec::Vec;
+use argon2::{Algorithm, Argon2, Params, Version};
+use zeroize::Zeroize;
+
+use crate::domain::{
+	aggregates::keyset::KeySet,
+	entities::error::MemoError,
+	services::encryption::{open, seal},
+	value_objects::{
+		constants::{
+			BACKUP_ARGON2_M_COST, BACKUP_ARGON2_P_COST, BACKUP_ARGON2_T_COST, BACKUP_SALT_SIZE,
+			KEYSET_BACKUP_HEADER_SIZE, KEYSET_BACKUP_VERSION, MAC_SIZE, MAX_BACKUP_ARGON2_M_COST,
+			NONCE_SIZE,
+		},
+		EdDSAKey, NullifierKey, ViewingKey,
+	},
+};
+
+/// Entry tag of the master spending key (required)
+const ENTRY_SPENDING_KEY: u8 = 1;
+/// Entry tag of the viewing key (may be a rotated generation)
+const ENTRY_VIEWING_KEY: u8 = 2;
+/// Entry tag of the nullifier key
+const ENTRY_NULLIFIER_KEY: u8 = 3;
+/// Entry tag of the EdDSA key
+const ENTRY_EDDSA_KEY: u8 = 4;
+
+/// Argon2id cost parameters of a KeySet backup.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BackupParams {
+	/// Memory cost in KiB
+	pub m_cost: u32,
+	/// Number of iterations
+	pub t_cost: u32,
+	/// Degree of parallelism
+	pub p_cost: u32,
+}
+
+impl Default for BackupParams {
+	fn default() -> Self {
+		Self {
+			m_cost: BACKUP_ARGON2_M_COST,
+			t_cost: BACKUP_ARGON2_T_COST,
+			p_cost: BACKUP_ARGON2_P_COST,
+		}
+	}
+}
+
+/// Stretches `password` into a backup encryption key
+fn derive_backup_key(
+	password: &[u8],
+	salt: &[u8],
+	params: &BackupParams,
+) -> Result<[u8; 32], MemoError> {
+	if params.m_cost > MAX_BACKUP_ARGON2_M_COST {
+		return Err(MemoError::InvalidKeySetBackup);
+	}
+	let params = Params::new(params.m_cost, params.t_cost, params.p_cost, Some(32))
+		.map_err(|_| MemoError::InvalidKeySetBackup)?;
+
+	let mut key = [0u8; 32];
+	Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+		.hash_password_into(password, salt, &mut key)
+		.map_err(|_| MemoError::InvalidKeySetBackup)?;
+	Ok(key)
+}
+
+/// Appends one `tag || length || bytes` entry
+fn push_entry(out: &mut Vec<u8>, tag: u8, bytes: &[u8; 32]) {
+	out.push(tag);
+	out.push(bytes.len() as u8);
+	out.extend_from_slice(bytes);
+}
+
+/// Encrypts a key set under a password
+///
+/// Returns: version(1) || m_cost(4) || t_cost(4) || p_cost(4) || salt(16) || nonce(12) || ciphertext
+/// WARNING: `salt` and nonce MUST be fresh per backup.
+pub fn export_keyset(
+	keys: &KeySet,
+	password: &[u8],
+	params: &BackupParams,
+	salt: &[u8; BACKUP_SALT_SIZE],
+	nonce: &[u8; 12],
+) -> Result<Vec<u8>, MemoError> {
+	let mut key = derive_backup_key(password, salt, params)?;
+
+	let mut entries = Vec::with_capacity(4 * 34);
+	push_entry(&mut entries, ENTRY_SPENDING_KEY, keys.spending_key());
+	push_entry(&mut entries, ENTRY_VIEWING_KEY, keys.viewing_key.as_bytes());
+	push_entry(&mut entries, ENTRY_NULLIFIER_KEY, &keys.nullifier_key.0);
+	push_entry(&mut entries, ENTRY_EDDSA_KEY, &keys.eddsa_key.0);
+
+	let mut result =
+		Vec::with_capacity(KEYSET_BACKUP_HEADER_SIZE + NONCE_SIZE + entries.len() + MAC_SIZE);
+	result.push(KEYSET_BACKUP_VERSION);
+	result.extend_from_slice(&params.m_cost.to_le_bytes());
+	result.extend_from_slice(&params.t_cost.to_le_bytes());
+	result.extend_from_slice(&params.p_cost.to_le_bytes());
+	result.extend_from_slice(salt);
+	let header = result.clone();
+	let sealed = seal(&key, nonce, &entries, &header, &mut result);
+	key.zeroize();
+	entries.zeroize();
+	sealed.map(|()| result)
+}
+
+/// Encrypts a key set under a password with default parameters and fresh randomness
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn export_keyset_random(keys: &KeySet, password: &[u8]) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut salt = [0u8; BACKUP_SALT_SIZE];
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut salt);
+	OsRng.fill_bytes(&mut nonce);
+
+	export_keyset(keys, password, &BackupParams::default(), &salt, &nonce)
+}
+
+/// Decrypts a key set backup
+///
+/// # Errors
+/// - `UnsupportedBackupVersion` for unknown format versions
+/// - `InvalidKeySetBackup` if the backup is malformed or lacks a spending key
+/// - `DecryptionFailed` on a wrong password or tampered backup
+pub fn import_keyset(backup: &[u8], password: &[u8]) -> Result<KeySet, MemoError> {
+	if backup.len() < KEYSET_BACKUP_HEADER_SIZE + NONCE_SIZE + MAC_SIZE {
+		return Err(MemoError::InvalidKeySetBackup);
+	}
+	if backup[0] != KEYSET_BACKUP_VERSION {
+		return Err(MemoError::UnsupportedBackupVersion(backup[0]));
+	}
+
+	let (header, body) = backup.split_at(KEYSET_BACKUP_HEADER_SIZE);
+	let cost = |offset: usize| {
+		let mut bytes = [0u8; 4];
+		bytes.copy_from_slice(&header[offset..offset + 4]);
+		u32::from_le_bytes(bytes)
+	};
+	let params = BackupParams {
+		m_cost: cost(1),
+		t_cost: cost(5),
+		p_cost: cost(9),
+	};
+	let salt = &header[13..];
+
+	let mut key = derive_backup_key(password, salt, &params)?;
+	let plaintext = open(&key, body, header);
+	key.zeroize();
+	let mut entries = plaintext?;
+
+	let keys = parse_entries(&entries);
+	entries.zeroize();
+	keys
+}
+
+/// Rebuilds a key set from decrypted backup entries
+fn parse_entries(mut entries: &[u8]) -> Result<KeySet, MemoError> {
+	let mut spending_key = None;
+	let mut viewing_key = None;
+	let mut nullifier_key = None;
+	let mut eddsa_key = None;
+
+	while let [tag, len, rest @ ..] = entries {
+		let len = *len as usize;
+		if rest.len() < len {
+			return Err(MemoError::InvalidKeySetBackup);
+		}
+		let (value, tail) = rest.split_at(len);
+		entries = tail;
+
+		let slot = match *tag {
+			ENTRY_SPENDING_KEY => &mut spending_key,
+			ENTRY_VIEWING_KEY => &mut viewing_key,
+			ENTRY_NULLIFIER_KEY => &mut nullifier_key,
+			ENTRY_EDDSA_KEY => &mut eddsa_key,
+			// Keys added by later versions
+			_ => continue,
+		};
+		let key: [u8; 32] = value
+			.try_into()
+			.map_err(|_| MemoError::InvalidKeySetBackup)?;
+		*slot = Some(key);
+	}
+	if !entries.is_empty() {
+		return Err(MemoError::InvalidKeySetBackup);
+	}
+
+	let spending_key = spending_key.ok_or(MemoError::InvalidKeySetBackup)?;
+	let derived = KeySet::from_spending_key(spending_key);
+	Ok(KeySet::new(
+		spending_key,
+		viewing_key.map_or(derived.viewing_key, ViewingKey),
+		nullifier_key.map_or(derived.nullifier_key, NullifierKey),
+		eddsa_key.map_or(derived.eddsa_key, EdDSAKey),
+	))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Minimal Argon2 cost so tests stay fast
+	const TEST_PARAMS: BackupParams = BackupParams {
+		m_cost: 8,
+		t_cost: 1,
+		p_cost: 1,
+	};
+
+	fn backup(keys: &KeySet) -> Vec<u8> {
+		export_keyset(keys, b"correct horse", &TEST_PARAMS, &[1u8; 16], &[2u8; 12]).unwrap()
+	}
+
+	fn sealed_entries(entries: &[u8]) -> Vec<u8> {
+		let mut result = backup(&KeySet::from_spending_key([3u8; 32]));
+		result.truncate(KEYSET_BACKUP_HEADER_SIZE);
+		let key = derive_backup_key(b"correct horse", &[1u8; 16], &TEST_PARAMS).unwrap();
+		let header = result.clone();
+		seal(&key, &[2u8; 12], entries, &header, &mut result).unwrap();
+		result
+	}
+
+	// ===== Roundtrip Tests =====
+
+	#[test]
+	fn test_export_import_roundtrip() {
+		let keys = KeySet::from_spending_key([3u8; 32]);
+		let exported = backup(&keys);
+		assert_eq!(exported[0], KEYSET_BACKUP_VERSION);
+		assert_eq!(
+			exported.len(),
+			KEYSET_BACKUP_HEADER_SIZE + NONCE_SIZE + 4 * 34 + MAC_SIZE
+		);
+		assert_eq!(import_keyset(&exported, b"correct horse"), Ok(keys));
+	}
+
+	#[test]
+	fn test_rotated_viewing_key_survives_backup() {
+		let keys = KeySet::from_spending_key([3u8; 32]).with_viewing_key_generation(2);
+		assert_eq!(import_keyset(&backup(&keys), b"correct horse"), Ok(keys));
+	}
+
+	#[test]
+	fn test_backup_hides_keys() {
+		let keys = KeySet::from_spending_key([3u8; 32]);
+		let exported = backup(&keys);
+		assert!(!exported.windows(32).any(|w| w == keys.spending_key()));
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_export_keyset_random_uses_fresh_salt() {
+		let keys = KeySet::from_spending_key([3u8; 32]);
+		let a = export_keyset_random(&keys, b"pw").unwrap();
+		let b = export_keyset_random(&keys, b"pw").unwrap();
+		assert_ne!(
+			a[13..KEYSET_BACKUP_HEADER_SIZE],
+			b[13..KEYSET_BACKUP_HEADER_SIZE]
+		);
+		assert_eq!(import_keyset(&a, b"pw"), Ok(keys));
+	}
+
+	// ===== Versioning Tests =====
+
+	#[test]
+	fn test_import_skips_unknown_entries() {
+		let mut entries = Vec::new();
+		push_entry(&mut entries, ENTRY_SPENDING_KEY, &[3u8; 32]);
+		// A future key type with a different length
+		entries.extend_from_slice(&[0x40, 3, 7, 7, 7]);
+		assert_eq!(
+			import_keyset(&sealed_entries(&entries), b"correct horse"),
+			Ok(KeySet::from_spending_key([3u8; 32]))
+		);
+	}
+
+	#[test]
+	fn test_import_requires_spending_key() {
+		let mut entries = Vec::new();
+		push_entry(&mut entries, ENTRY_VIEWING_KEY, &[3u8; 32]);
+		assert_eq!(
+			import_keyset(&sealed_entries(&entries), b"correct horse"),
+			Err(MemoError::InvalidKeySetBackup)
+		);
+	}
+
+	#[test]
+	fn test_import_rejects_truncated_entry() {
+		assert_eq!(
+			import_keyset(
+				&sealed_entries(&[ENTRY_SPENDING_KEY, 32, 1]),
+				b"correct horse"
+			),
+			Err(MemoError::InvalidKeySetBackup)
+		);
+	}
+
+	#[test]
+	fn test_import_rejects_unknown_version() {
+		let mut exported = backup(&KeySet::from_spending_key([3u8; 32]));
+		exported[0] = KEYSET_BACKUP_VERSION + 1;
+		assert_eq!(
+			import_keyset(&exported, b"correct horse"),
+			Err(MemoError::UnsupportedBackupVersion(
+				KEYSET_BACKUP_VERSION + 1
+			))
+		);
+	}
+
+	// ===== Failure Tests =====
+
+	#[test]
+	fn test_import_wrong_password() {
+		let exported = backup(&KeySet::from_spending_key([3u8; 32]));
+		assert_eq!(
+			import_keyset(&exported, b"wrong horse"),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_import_header_is_authenticated() {
+		let mut exported = backup(&KeySet::from_spending_key([3u8; 32]));
+		exported[13] ^= 1;
+		assert_eq!(
+			import_keyset(&exported, b"correct horse"),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_import_rejects_bad_params_and_length() {
+		let mut exported = backup(&KeySet::from_spending_key([3u8; 32]));
+		exported[1..5].copy_from_slice(&(MAX_BACKUP_ARGON2_M_COST + 1).to_le_bytes());
+		assert_eq!(
+			import_keyset(&exported, b"correct horse"),
+			Err(MemoError::InvalidKeySetBackup)
+		);
+		assert_eq!(
+			import_keyset(&exported[..KEYSET_BACKUP_HEADER_SIZE], b"correct horse"),
+			Err(MemoError::InvalidKeySetBackup)
+		);
+	}
+}
@@ -0,0 +1,334 @@
+//! Memo Codec Service
+//!
+//! Single entry point for reading on-chain memos of any format. The codec
+//! inspects the envelope header, strips detection tags, resolves off-chain
+//! pointers and dispatches to the matching decrypt path, so wallets handle
+//! one [`DecodedMemo`] instead of branching on every envelope version.
+
+use crate::domain::{
+	entities::{
+		decoded_memo::{DecodedMemo, MemoFormat},
+		error::MemoError,
+		memo_data::is_valid_encrypted_memo,
+	},
+	services::{
+		detection::{detection_tag, strip_detection_tag},
+		encryption::decrypt_memo,
+		offchain::{pointer_content_hash, verify_offchain_memo},
+	},
+	value_objects::{
+		constants::{
+			MAX_ENCRYPTED_MEMO_SIZE, MEMO_FLAG_COMPRESSED, MEMO_VERSION_EPHEMERAL,
+			MEMO_VERSION_ESCROW, MEMO_VERSION_HYBRID, MEMO_VERSION_MULTI, MEMO_VERSION_V2,
+			MEMO_VERSION_V3, MEMO_VERSION_XCHACHA, MIN_ENCRYPTED_MEMO_SIZE,
+		},
+		DetectionTag,
+	},
+};
+
+/// Format-dispatching memo decoder
+pub struct MemoCodec;
+
+impl MemoCodec {
+	/// Identifies the envelope format and detection tag of an on-chain memo
+	///
+	/// # Errors
+	/// - `DataTooShort` below the minimum memo size
+	/// - `UnsupportedMemoVersion` for an unknown envelope header
+	/// - `InvalidNoteData` for a known header with an invalid layout
+	pub fn inspect(encrypted: &[u8]) -> Result<(MemoFormat, Option<DetectionTag>), MemoError> {
+		if pointer_content_hash(encrypted).is_some() {
+			return Ok((MemoFormat::Pointer, None));
+		}
+		if encrypted.len() < MIN_ENCRYPTED_MEMO_SIZE {
+			return Err(MemoError::DataTooShort);
+		}
+		if let Some(inner) = strip_detection_tag(encrypted) {
+			if !is_valid_encrypted_memo(encrypted) {
+				return Err(MemoError::InvalidNoteData);
+			}
+			let (format, _) = Self::inspect(inner)?;
+			return Ok((format, detection_tag(encrypted)));
+		}
+		if encrypted.len() <= MAX_ENCRYPTED_MEMO_SIZE {
+			return Ok((MemoFormat::V1, None));
+		}
+
+		let format = match encrypted[0] {
+			MEMO_VERSION_V2 => MemoFormat::V2,
+			MEMO_VERSION_V3 => MemoFormat::V3,
+			MEMO_VERSION_EPHEMERAL => MemoFormat::Ephemeral,
+			MEMO_VERSION_MULTI => MemoFormat::MultiRecipient,
+			MEMO_VERSION_ESCROW => MemoFormat::Escrow,
+			MEMO_VERSION_XCHACHA => MemoFormat::XChaCha,
+			MEMO_VERSION_HYBRID => MemoFormat::Hybrid,
+			COMPRESSED_V2 => MemoFormat::CompressedV2,
+			version => return Err(MemoError::UnsupportedMemoVersion(version)),
+		};
+		if !is_valid_encrypted_memo(encrypted) {
+			return Err(MemoError::InvalidNoteData);
+		}
+		Ok((format, None))
+	}
+
+	/// Decodes an on-chain memo of any format
+	///
+	/// Pointers decode to [`DecodedMemo::OffChain`]; fetch the ciphertext and
+	/// pass it to [`Self::decode_offchain`].
+	///
+	/// # Errors
+	/// Any error of [`Self::inspect`], `UnsupportedMemoVersion` for hybrid
+	/// memos without the `pq` feature, or the decryption error of the format.
+	pub fn decode(
+		encrypted: &[u8],
+		commitment: &[u8; 32],
+		viewing_key: &[u8; 32],
+	) -> Result<DecodedMemo, MemoError> {
+		let (format, detection_tag) = Self::inspect(encrypted)?;
+		if format == MemoFormat::Pointer {
+			let pointer = strip_detection_tag(encrypted).unwrap_or(encrypted);
+			let content_hash =
+				pointer_content_hash(pointer).ok_or(MemoError::InvalidMemoPointer)?;
+			return Ok(DecodedMemo::OffChain { content_hash });
+		}
+		if cfg!(not(feature = "pq")) && format == MemoFormat::Hybrid {
+			return Err(MemoError::UnsupportedMemoVersion(MEMO_VERSION_HYBRID));
+		}
+
+		let memo = decrypt_memo(encrypted, commitment, viewing_key)?;
+		Ok(DecodedMemo::Note {
+			format,
+			detection_tag,
+			memo,
+		})
+	}
+
+	/// Verifies fetched ciphertext against an on-chain pointer and decodes it
+	///
+	/// # Errors
+	/// `InvalidMemoPointer` or `ContentHashMismatch` from the pointer check,
+	/// otherwise any error of [`Self::decode`].
+	pub fn decode_offchain(
+		onchain: &[u8],
+		fetched: &[u8],
+		commitment: &[u8; 32],
+		viewing_key: &[u8; 32],
+	) -> Result<DecodedMemo, MemoError> {
+		verify_offchain_memo(onchain, fetched)?;
+		Self::decode(fetched, commitment, viewing_key)
+	}
+
+	/// Decodes an on-chain memo, returning `None` if it is not for this key
+	pub fn try_decode(
+		encrypted: &[u8],
+		commitment: &[u8; 32],
+		viewing_key: &[u8; 32],
+	) -> Option<DecodedMemo> {
+		Self::decode(encrypted, commitment, viewing_key).ok()
+	}
+}
+
+/// Header of a compressed V2 memo
+const COMPRESSED_V2: u8 = MEMO_VERSION_V2 | MEMO_FLAG_COMPRESSED;
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::{
+		entities::{
+			memo_data::MemoData, memo_data_v2::MemoDataV2, memo_data_v3::MemoDataV3,
+			versioned_memo::VersionedMemo,
+		},
+		services::{
+			detection::{create_detection_tag, tag_memo},
+			encryption::{
+				encrypt_memo, encrypt_memo_ephemeral, encrypt_memo_v2, encrypt_memo_v2_compressed,
+				encrypt_memo_v3, encrypt_memo_xchacha,
+			},
+			escrow::encrypt_memo_escrowed,
+			key_derivation::{derive_agreement_public_key, derive_clue_key},
+			multi_recipient::encrypt_memo_multi,
+			offchain::memo_pointer,
+		},
+		value_objects::ViewingKey,
+	};
+
+	const COMMITMENT: [u8; 32] = [3u8; 32];
+	const VIEWING_KEY: [u8; 32] = [4u8; 32];
+
+	fn memo_v1() -> MemoData {
+		MemoData::new(500, [1u8; 32], [2u8; 32], 0)
+	}
+
+	fn memo_v2() -> MemoDataV2 {
+		MemoDataV2::new(500, [1u8; 32], [2u8; 32], 0, 7, [0u8; 16])
+	}
+
+	fn decoded(encrypted: &[u8]) -> (MemoFormat, VersionedMemo) {
+		match MemoCodec::decode(encrypted, &COMMITMENT, &VIEWING_KEY).unwrap() {
+			DecodedMemo::Note { format, memo, .. } => (format, memo),
+			other => panic!("unexpected {other:?}"),
+		}
+	}
+
+	// ===== Inspection Tests =====
+
+	#[test]
+	fn test_inspect_rejects_short_data() {
+		assert_eq!(
+			MemoCodec::inspect(&[0u8; MIN_ENCRYPTED_MEMO_SIZE - 1]),
+			Err(MemoError::DataTooShort)
+		);
+	}
+
+	#[test]
+	fn test_inspect_rejects_unknown_version() {
+		let mut data = [0u8; MAX_ENCRYPTED_MEMO_SIZE + 25];
+		data[0] = 0x7f;
+		assert_eq!(
+			MemoCodec::inspect(&data),
+			Err(MemoError::UnsupportedMemoVersion(0x7f))
+		);
+	}
+
+	#[test]
+	fn test_inspect_rejects_invalid_layout() {
+		let mut data = encrypt_memo_v2(&memo_v2(), &COMMITMENT, &VIEWING_KEY, &[5u8; 12]).unwrap();
+		data.push(0);
+		assert_eq!(MemoCodec::inspect(&data), Err(MemoError::InvalidNoteData));
+	}
+
+	// ===== Decode Tests =====
+
+	#[test]
+	fn test_decode_v1_v2_and_v3() {
+		let v1 = encrypt_memo(&memo_v1(), &COMMITMENT, &VIEWING_KEY, &[5u8; 12]).unwrap();
+		assert_eq!(decoded(&v1), (MemoFormat::V1, VersionedMemo::V1(memo_v1())));
+
+		let v2 = encrypt_memo_v2(&memo_v2(), &COMMITMENT, &VIEWING_KEY, &[5u8; 12]).unwrap();
+		assert_eq!(decoded(&v2), (MemoFormat::V2, VersionedMemo::V2(memo_v2())));
+
+		let memo_v3 = MemoDataV3::from_v2(memo_v2(), b"order-42").unwrap();
+		let v3 = encrypt_memo_v3(&memo_v3, &COMMITMENT, &VIEWING_KEY, &[5u8; 12]).unwrap();
+		assert_eq!(decoded(&v3), (MemoFormat::V3, VersionedMemo::V3(memo_v3)));
+
+		let compressed =
+			encrypt_memo_v2_compressed(&memo_v2(), &COMMITMENT, &VIEWING_KEY, &[5u8; 12]).unwrap();
+		assert_eq!(decoded(&compressed).0, MemoFormat::CompressedV2);
+
+		let xchacha =
+			encrypt_memo_xchacha(&memo_v2(), &COMMITMENT, &VIEWING_KEY, &[5u8; 24]).unwrap();
+		assert_eq!(decoded(&xchacha).0, MemoFormat::XChaCha);
+	}
+
+	#[test]
+	fn test_decode_key_agreement_formats() {
+		let address = derive_agreement_public_key(&VIEWING_KEY);
+
+		let ephemeral =
+			encrypt_memo_ephemeral(&memo_v2(), &COMMITMENT, &address, &[6u8; 32], &[5u8; 12])
+				.unwrap();
+		assert_eq!(
+			decoded(&ephemeral),
+			(MemoFormat::Ephemeral, VersionedMemo::V2(memo_v2()))
+		);
+
+		let multi = encrypt_memo_multi(
+			&memo_v1(),
+			&COMMITMENT,
+			&[ViewingKey(VIEWING_KEY)],
+			&[7u8; 32],
+			&[5u8; 12],
+		)
+		.unwrap();
+		assert_eq!(decoded(&multi).0, MemoFormat::MultiRecipient);
+
+		let auditor = derive_agreement_public_key(&[8u8; 32]);
+		let escrowed = encrypt_memo_escrowed(
+			&memo_v1(),
+			&COMMITMENT,
+			&VIEWING_KEY,
+			&[5u8; 12],
+			&auditor,
+			&[6u8; 32],
+		)
+		.unwrap();
+		assert_eq!(decoded(&escrowed).0, MemoFormat::Escrow);
+	}
+
+	#[test]
+	fn test_decode_tagged_memo() {
+		let encrypted = encrypt_memo_v2(&memo_v2(), &COMMITMENT, &VIEWING_KEY, &[5u8; 12]).unwrap();
+		let tag = create_detection_tag(&derive_clue_key(&VIEWING_KEY), &[7u8; 32]).unwrap();
+		let tagged = tag_memo(&encrypted, &tag).unwrap();
+
+		assert_eq!(
+			MemoCodec::decode(&tagged, &COMMITMENT, &VIEWING_KEY),
+			Ok(DecodedMemo::Note {
+				format: MemoFormat::V2,
+				detection_tag: Some(tag),
+				memo: VersionedMemo::V2(memo_v2()),
+			})
+		);
+	}
+
+	#[test]
+	fn test_decode_wrong_key() {
+		let encrypted = encrypt_memo(&memo_v1(), &COMMITMENT, &VIEWING_KEY, &[5u8; 12]).unwrap();
+		assert_eq!(
+			MemoCodec::decode(&encrypted, &COMMITMENT, &[9u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+		assert!(MemoCodec::try_decode(&encrypted, &COMMITMENT, &[9u8; 32]).is_none());
+	}
+
+	#[cfg(not(feature = "pq"))]
+	#[test]
+	fn test_decode_hybrid_requires_pq() {
+		let mut data = [0u8; crate::domain::value_objects::constants::ENCRYPTED_MEMO_HYBRID_SIZE];
+		data[0] = MEMO_VERSION_HYBRID;
+		assert_eq!(
+			MemoCodec::decode(&data, &COMMITMENT, &VIEWING_KEY),
+			Err(MemoError::UnsupportedMemoVersion(MEMO_VERSION_HYBRID))
+		);
+	}
+
+	// ===== Off-chain Tests =====
+
+	#[test]
+	fn test_decode_pointer_then_offchain() {
+		let ciphertext = encrypt_memo(&memo_v1(), &COMMITMENT, &VIEWING_KEY, &[5u8; 12]).unwrap();
+		let pointer = memo_pointer(&ciphertext);
+
+		let resolved = MemoCodec::decode(&pointer, &COMMITMENT, &VIEWING_KEY).unwrap();
+		assert_eq!(
+			resolved,
+			DecodedMemo::OffChain {
+				content_hash: pointer_content_hash(&pointer).unwrap(),
+			}
+		);
+
+		let fetched =
+			MemoCodec::decode_offchain(&pointer, &ciphertext, &COMMITMENT, &VIEWING_KEY).unwrap();
+		assert_eq!(fetched.memo(), Some(&VersionedMemo::V1(memo_v1())));
+	}
+
+	#[test]
+	fn test_decode_offchain_rejects_substituted_ciphertext() {
+		let ciphertext = encrypt_memo(&memo_v1(), &COMMITMENT, &VIEWING_KEY, &[5u8; 12]).unwrap();
+		let other = encrypt_memo(&memo_v1(), &COMMITMENT, &VIEWING_KEY, &[6u8; 12]).unwrap();
+		assert_eq!(
+			MemoCodec::decode_offchain(
+				&memo_pointer(&ciphertext),
+				&other,
+				&COMMITMENT,
+				&VIEWING_KEY
+			),
+			Err(MemoError::ContentHashMismatch)
+		);
+	}
+}
@@ -0,0 +1,172 @@
+//! Compression Service
+//!
+//! Lightweight run-length compression of memo plaintexts. Memo payloads are
+//! fixed-layout and mostly zero bytes (small values and asset ids, unused
+//! sender hints), so collapsing zero runs recovers most of the slack without
+//! a general-purpose compressor.
+//!
+//! The stream is a sequence of tokens, each a control byte `c`:
+//!
+//! ```text
+//! c & 0x80 == 0   literal run: the next (c + 1) bytes are copied
+//! c & 0x80 != 0   zero run: (c & 0x7f) + 1 zero bytes
+//! ```
+//!
+//! The decompressed length is fixed by the payload type, so decoding stops
+//! once it is reached; any remaining bytes must be zero padding.
+
+use alloc::vec::Vec;
+
+use crate::domain::entities::error::MemoError;
+
+/// Longest run a single token can encode
+const MAX_RUN: usize = 128;
+
+/// Marks a zero-run token
+const ZERO_RUN: u8 = 0x80;
+
+/// Compresses `data` by collapsing runs of zero bytes
+pub fn compress_payload(data: &[u8]) -> Vec<u8> {
+	let mut out = Vec::with_capacity(data.len() + data.len() / MAX_RUN + 1);
+	let mut i = 0;
+	while i < data.len() {
+		let zeros = data[i..]
+			.iter()
+			.take(MAX_RUN)
+			.take_while(|&&b| b == 0)
+			.count();
+		// Isolated zeros are cheaper inside a literal run
+		if zeros >= 2 || (zeros == 1 && i + 1 == data.len()) {
+			out.push(ZERO_RUN | (zeros - 1) as u8);
+			i += zeros;
+			continue;
+		}
+
+		let start = i;
+		while i < data.len() && i - start < MAX_RUN {
+			if data[i] == 0 && data.get(i + 1) == Some(&0) {
+				break;
+			}
+			i += 1;
+		}
+		out.push((i - start - 1) as u8);
+		out.extend_from_slice(&data[start..i]);
+	}
+	out
+}
+
+/// Decompresses a stream produced by [`compress_payload`] into `expected_len` bytes
+///
+/// # Errors
+/// `InvalidNoteData` if the stream is truncated, overruns `expected_len` or
+/// has non-zero trailing bytes.
+pub fn decompress_payload(compressed: &[u8], expected_len: usize) -> Result<Vec<u8>, MemoError> {
+	let mut out = Vec::with_capacity(expected_len);
+	let mut i = 0;
+	while out.len() < expected_len {
+		let control = *compressed.get(i).ok_or(MemoError::InvalidNoteData)?;
+		i += 1;
+		let run = (control & !ZERO_RUN) as usize + 1;
+		if out.len() + run > expected_len {
+			return Err(MemoError::InvalidNoteData);
+		}
+
+		if control & ZERO_RUN != 0 {
+			out.resize(out.len() + run, 0);
+		} else {
+			let literal = compressed
+				.get(i..i + run)
+				.ok_or(MemoError::InvalidNoteData)?;
+			out.extend_from_slice(literal);
+			i += run;
+		}
+	}
+
+	if compressed[i..].iter().any(|&b| b != 0) {
+		return Err(MemoError::InvalidNoteData);
+	}
+	Ok(out)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec;
+
+	fn roundtrip(data: &[u8]) -> Vec<u8> {
+		let compressed = compress_payload(data);
+		assert_eq!(
+			decompress_payload(&compressed, data.len()),
+			Ok(data.to_vec())
+		);
+		compressed
+	}
+
+	#[test]
+	fn test_all_zero_payload_collapses() {
+		let compressed = roundtrip(&[0u8; 100]);
+		assert_eq!(compressed, vec![ZERO_RUN | 99]);
+	}
+
+	#[test]
+	fn test_mixed_payload_roundtrip() {
+		let mut data = [0u8; 100];
+		data[0] = 0xe8;
+		data[1] = 0x03;
+		data[8..40].copy_from_slice(&[7u8; 32]);
+		data[50] = 1;
+		let compressed = roundtrip(&data);
+		assert!(compressed.len() < 50);
+	}
+
+	#[test]
+	fn test_isolated_zero_stays_literal() {
+		let compressed = roundtrip(&[1, 0, 2]);
+		assert_eq!(compressed, vec![2, 1, 0, 2]);
+	}
+
+	#[test]
+	fn test_incompressible_payload_roundtrip() {
+		let data: Vec<u8> = (1..=255u8).collect();
+		let compressed = roundtrip(&data);
+		assert_eq!(compressed.len(), data.len() + 2);
+	}
+
+	#[test]
+	fn test_trailing_single_zero() {
+		roundtrip(&[5, 0]);
+		roundtrip(&[]);
+	}
+
+	#[test]
+	fn test_zero_padding_is_accepted() {
+		let mut compressed = compress_payload(&[3u8; 10]);
+		compressed.extend_from_slice(&[0u8; 5]);
+		assert_eq!(decompress_payload(&compressed, 10), Ok(vec![3u8; 10]));
+	}
+
+	#[test]
+	fn test_decompress_rejects_malformed_streams() {
+		// Truncated literal
+		assert_eq!(
+			decompress_payload(&[3, 1, 2], 4),
+			Err(MemoError::InvalidNoteData)
+		);
+		// Run past the expected length
+		assert_eq!(
+			decompress_payload(&[ZERO_RUN | 9], 4),
+			Err(MemoError::InvalidNoteData)
+		);
+		// Non-zero trailing bytes
+		assert_eq!(
+			decompress_payload(&[ZERO_RUN | 3, 1], 4),
+			Err(MemoError::InvalidNoteData)
+		);
+		// Stream too short
+		assert_eq!(decompress_payload(&[], 1), Err(MemoError::InvalidNoteData));
+	}
+}
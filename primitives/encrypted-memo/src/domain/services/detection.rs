@@ -0,0 +1,294 @@
+//! Detection Service
+//!
+//! Fuzzy message detection: senders attach a detection tag built from the
+//! recipient's clue key, and anyone holding a detection key can test tags
+//! without decrypting. Each clue bit is masked by an X25519 agreement:
+//!
+//! ```text
+//! ephemeral_public = X25519(ephemeral_secret, basepoint)
+//! mask_i           = SHA256(X25519(ephemeral_secret, clue_i) || ephemeral_public || clue_i || domain)[0] & 1
+//! clue_bit_i       = mask_i ^ 1
+//! ```
+//!
+//! The owner of detection secret `i` recomputes `mask_i` and flags the tag when
+//! `mask_i ^ clue_bit_i == 1` for every bit it holds. For any other key each
+//! check passes with probability 1/2, so a key of precision `p` flags
+//! unrelated tags at a rate of `2^-p`.
+//!
+//! Tagged memos wrap any other memo envelope:
+//!
+//! ```text
+//! version(1) || detection_tag(34) || memo
+//! ```
+
+use alloc::vec::Vec;
+use x25519_dalek::{PublicKey, StaticSecret};
+use zeroize::Zeroize;
+
+use crate::domain::{
+	entities::{error::MemoError, memo_data::is_valid_encrypted_memo},
+	services::key_derivation::derive_shared_key,
+	value_objects::{
+		constants::{
+			DETECTION_TAG_DOMAIN, DETECTION_TAG_SIZE, MAX_DETECTION_PRECISION,
+			MAX_ENCRYPTED_MEMO_SIZE, MEMO_VERSION_SIZE, MEMO_VERSION_TAGGED,
+		},
+		ClueKey, DetectionKey, DetectionTag,
+	},
+};
+
+/// Unmasked clue bit for one clue key entry
+fn clue_mask(
+	secret: &StaticSecret,
+	their_public: &[u8; 32],
+	ephemeral_public: &[u8; 32],
+	clue_public: &[u8; 32],
+) -> Result<u8, MemoError> {
+	let mut hash = derive_shared_key(
+		secret,
+		their_public,
+		ephemeral_public,
+		clue_public,
+		DETECTION_TAG_DOMAIN,
+	)?;
+	let mask = hash[0] & 1;
+	hash.zeroize();
+	Ok(mask)
+}
+
+/// Creates a detection tag for the owner of `clue_key`
+///
+/// WARNING: `ephemeral_secret` MUST be fresh per memo, otherwise tags are linkable.
+pub fn create_detection_tag(
+	clue_key: &ClueKey,
+	ephemeral_secret: &[u8; 32],
+) -> Result<DetectionTag, MemoError> {
+	let secret = StaticSecret::from(*ephemeral_secret);
+	let ephemeral_public = PublicKey::from(&secret).to_bytes();
+
+	let mut clue_bits = [0u8; MAX_DETECTION_PRECISION / 8];
+	for (index, clue_public) in clue_key.0.iter().enumerate() {
+		let bit = clue_mask(&secret, clue_public, &ephemeral_public, clue_public)? ^ 1;
+		clue_bits[index / 8] |= bit << (index % 8);
+	}
+
+	Ok(DetectionTag {
+		ephemeral_public,
+		clue_bits,
+	})
+}
+
+/// Creates a detection tag using a random ephemeral secret
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn create_detection_tag_random(clue_key: &ClueKey) -> Result<DetectionTag, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut ephemeral_secret = [0u8; 32];
+	OsRng.fill_bytes(&mut ephemeral_secret);
+
+	let result = create_detection_tag(clue_key, &ephemeral_secret);
+	ephemeral_secret.zeroize();
+	result
+}
+
+/// Tests a detection tag against a detection key
+///
+/// Always `true` for tags created from the matching clue key; `true` with
+/// probability `2^-precision` otherwise.
+pub fn detect(detection_key: &DetectionKey, tag: &DetectionTag) -> bool {
+	detection_key
+		.secrets()
+		.iter()
+		.enumerate()
+		.all(|(index, secret)| {
+			let secret = StaticSecret::from(*secret);
+			let clue_public = PublicKey::from(&secret).to_bytes();
+			match clue_mask(
+				&secret,
+				&tag.ephemeral_public,
+				&tag.ephemeral_public,
+				&clue_public,
+			) {
+				Ok(mask) => mask ^ tag.clue_bit(index) == 1,
+				// A low-order ephemeral key cannot come from an honest sender
+				Err(_) => false,
+			}
+		})
+}
+
+/// Prefixes an encrypted memo with a detection tag
+///
+/// # Errors
+/// `DataTooShort` unless `encrypted` is a valid, untagged memo envelope.
+pub fn tag_memo(encrypted: &[u8], tag: &DetectionTag) -> Result<Vec<u8>, MemoError> {
+	if !is_valid_encrypted_memo(encrypted) || strip_detection_tag(encrypted).is_some() {
+		return Err(MemoError::DataTooShort);
+	}
+	// Tagged memos are told apart from V1 memos by length
+	if MEMO_VERSION_SIZE + DETECTION_TAG_SIZE + encrypted.len() <= MAX_ENCRYPTED_MEMO_SIZE {
+		return Err(MemoError::DataTooShort);
+	}
+
+	let mut out = Vec::with_capacity(MEMO_VERSION_SIZE + DETECTION_TAG_SIZE + encrypted.len());
+	out.push(MEMO_VERSION_TAGGED);
+	out.extend_from_slice(&tag.to_bytes());
+	out.extend_from_slice(encrypted);
+	Ok(out)
+}
+
+/// Returns the detection tag of a tagged memo, or `None` for untagged memos
+pub fn detection_tag(encrypted: &[u8]) -> Option<DetectionTag> {
+	strip_detection_tag(encrypted)?;
+	DetectionTag::from_bytes(&encrypted[MEMO_VERSION_SIZE..MEMO_VERSION_SIZE + DETECTION_TAG_SIZE])
+		.ok()
+}
+
+/// Returns `true` if `encrypted` is tagged and its tag passes `detection_key`
+pub fn is_flagged(detection_key: &DetectionKey, encrypted: &[u8]) -> bool {
+	detection_tag(encrypted).is_some_and(|tag| detect(detection_key, &tag))
+}
+
+/// Returns the memo inside a tagged envelope, or `None` for untagged memos
+pub(crate) fn strip_detection_tag(encrypted: &[u8]) -> Option<&[u8]> {
+	if encrypted.len() <= MAX_ENCRYPTED_MEMO_SIZE || encrypted[0] != MEMO_VERSION_TAGGED {
+		return None;
+	}
+	encrypted.get(MEMO_VERSION_SIZE + DETECTION_TAG_SIZE..)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::{
+		entities::memo_data::MemoData,
+		services::{
+			encryption::{decrypt_memo, encrypt_memo},
+			key_derivation::{derive_clue_key, derive_detection_key},
+		},
+	};
+
+	fn recipient_vk() -> [u8; 32] {
+		[11u8; 32]
+	}
+
+	fn tagged_memo() -> Vec<u8> {
+		let memo = MemoData::new(500, [1u8; 32], [2u8; 32], 0);
+		let encrypted = encrypt_memo(&memo, &[3u8; 32], &recipient_vk(), &[4u8; 12]).unwrap();
+		let tag = create_detection_tag(&derive_clue_key(&recipient_vk()), &[5u8; 32]).unwrap();
+		tag_memo(&encrypted, &tag).unwrap()
+	}
+
+	// ===== Detection Tests =====
+
+	#[test]
+	fn test_detect_always_flags_recipient() {
+		let clue_key = derive_clue_key(&recipient_vk());
+		let detection_key = derive_detection_key(&recipient_vk(), MAX_DETECTION_PRECISION).unwrap();
+		for seed in 0..32u8 {
+			let tag = create_detection_tag(&clue_key, &[seed; 32]).unwrap();
+			assert!(detect(&detection_key, &tag));
+		}
+	}
+
+	#[test]
+	fn test_detect_false_positive_rate_tracks_precision() {
+		let clue_key = derive_clue_key(&recipient_vk());
+		let low = derive_detection_key(&[12u8; 32], 1).unwrap();
+		let high = derive_detection_key(&[12u8; 32], MAX_DETECTION_PRECISION).unwrap();
+
+		let mut low_hits = 0;
+		let mut high_hits = 0;
+		for seed in 0..64u8 {
+			let tag = create_detection_tag(&clue_key, &[seed; 32]).unwrap();
+			low_hits += detect(&low, &tag) as u32;
+			high_hits += detect(&high, &tag) as u32;
+		}
+		// Expected 32 and 64 / 2^16
+		assert!((16..=48).contains(&low_hits));
+		assert_eq!(high_hits, 0);
+	}
+
+	#[test]
+	fn test_detect_rejects_low_order_ephemeral_key() {
+		let detection_key = derive_detection_key(&recipient_vk(), 1).unwrap();
+		let tag = DetectionTag {
+			ephemeral_public: [0u8; 32],
+			clue_bits: [0xff; MAX_DETECTION_PRECISION / 8],
+		};
+		assert!(!detect(&detection_key, &tag));
+	}
+
+	#[test]
+	fn test_create_tag_rejects_low_order_clue_key() {
+		let clue_key = ClueKey([[0u8; 32]; MAX_DETECTION_PRECISION]);
+		assert_eq!(
+			create_detection_tag(&clue_key, &[5u8; 32]),
+			Err(MemoError::InvalidPublicKey)
+		);
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_create_detection_tag_random() {
+		let clue_key = derive_clue_key(&recipient_vk());
+		let detection_key = derive_detection_key(&recipient_vk(), 8).unwrap();
+		let a = create_detection_tag_random(&clue_key).unwrap();
+		let b = create_detection_tag_random(&clue_key).unwrap();
+		assert_ne!(a, b);
+		assert!(detect(&detection_key, &a));
+	}
+
+	// ===== Tagged Envelope Tests =====
+
+	#[test]
+	fn test_tagged_memo_layout() {
+		let tagged = tagged_memo();
+		assert_eq!(
+			tagged.len(),
+			1 + DETECTION_TAG_SIZE + MAX_ENCRYPTED_MEMO_SIZE
+		);
+		assert_eq!(tagged[0], MEMO_VERSION_TAGGED);
+		assert!(is_valid_encrypted_memo(&tagged));
+	}
+
+	#[test]
+	fn test_is_flagged_and_decrypt_tagged_memo() {
+		let tagged = tagged_memo();
+		let detection_key = derive_detection_key(&recipient_vk(), 4).unwrap();
+		assert!(is_flagged(&detection_key, &tagged));
+
+		let decrypted = decrypt_memo(&tagged, &[3u8; 32], &recipient_vk()).unwrap();
+		assert_eq!(decrypted.value(), 500);
+	}
+
+	#[test]
+	fn test_untagged_memo_has_no_tag() {
+		let memo = MemoData::new(500, [1u8; 32], [2u8; 32], 0);
+		let encrypted = encrypt_memo(&memo, &[3u8; 32], &recipient_vk(), &[4u8; 12]).unwrap();
+		let detection_key = derive_detection_key(&recipient_vk(), 1).unwrap();
+		assert_eq!(detection_tag(&encrypted), None);
+		assert!(!is_flagged(&detection_key, &encrypted));
+	}
+
+	#[test]
+	fn test_tag_memo_rejects_invalid_or_tagged_input() {
+		let tag = create_detection_tag(&derive_clue_key(&recipient_vk()), &[5u8; 32]).unwrap();
+		assert_eq!(tag_memo(&[0u8; 10], &tag), Err(MemoError::DataTooShort));
+		assert_eq!(tag_memo(&tagged_memo(), &tag), Err(MemoError::DataTooShort));
+	}
+
+	#[test]
+	fn test_tag_not_bound_to_ciphertext() {
+		let mut tagged = tagged_memo();
+		tagged[1] ^= 0xff;
+		// The tag is not bound to the ciphertext; the memo still decrypts
+		assert!(decrypt_memo(&tagged, &[3u8; 32], &recipient_vk()).is_ok());
+	}
+}
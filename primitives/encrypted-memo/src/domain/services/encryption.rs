@@ -1,50 +1,300 @@
 //! Encryption Service
 //!
-//! ChaCha20Poly1305 AEAD encryption/decryption for memo data.
+//! ChaCha20Poly1305 AEAD encryption/decryption for memo data, with
+//! XChaCha20Poly1305 as an alternative cipher.
+//!
+//! Several envelope formats coexist; decryption dispatches on length and header:
+//!
+//! ```text
+//! V1:        nonce(12) || ciphertext(76+16)                                headerless, <= 104 bytes
+//! V2:        version(1) || nonce(12) || ciphertext(100+16)                 version byte is AEAD associated data
+//! V3:        version(1) || nonce(12) || ciphertext(133+16)                 V2 fields plus application data
+//! Ephemeral: version(1) || ephemeral_public(32) || nonce(12) || ciphertext(100+16)
+//! Multi:     version(1) || recipient_count(1) || ...       see [`multi_recipient`](super::multi_recipient)
+//! Escrow:    version(1) || escrowed_key(80) || nonce(12) || ciphertext(76+16)   see [`escrow`](super::escrow)
+//! XChaCha:   version(1) || nonce(24) || ciphertext(100+16)                 version byte is AEAD associated data
+//! Compressed: (2 | 0x80)(1) || nonce(12) || ciphertext(76..=99 + 16)      see [`compression`](super::compression)
+//! Hybrid:    version(1) || ephemeral_public(32) || kem_ciphertext(1088) || ...   `pq` feature, see `hybrid`
+//! ```
+//!
+//! The per-note key depends only on the viewing key and commitment, so a
+//! sender's 96-bit random nonces collide once enough memos share a key.
+//! XChaCha20Poly1305's 192-bit nonces make random nonces safe at any volume.
+//!
+//! Ephemeral-key envelopes (Zcash-style note encryption) key each memo with a
+//! fresh X25519 ephemeral secret agreed against the recipient's agreement key.
+//! The ephemeral public key travels inside the envelope and is authenticated
+//! with the version byte; the sender erases the ephemeral secret after sealing.
+//!
+//! [`detect_only`] answers "is this mine?" by recomputing the Poly1305 tag
+//! over the ciphertext, skipping the payload keystream and plaintext parsing.
 
 use alloc::vec::Vec;
+use chacha20::{
+	cipher::{KeyIvInit, StreamCipher},
+	ChaCha20, XChaCha20,
+};
 use chacha20poly1305::{
-	aead::{Aead, KeyInit},
-	ChaCha20Poly1305, Nonce,
+	aead::{Aead, KeyInit, Payload},
+	ChaCha20Poly1305, Nonce, XChaCha20Poly1305, XNonce,
 };
+use poly1305::{universal_hash::UniversalHash, Poly1305};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
 
 use crate::domain::{
-	entities::{error::MemoError, memo_data::MemoData},
-	services::key_derivation::derive_encryption_key,
-	value_objects::constants::{MAX_ENCRYPTED_MEMO_SIZE, MIN_ENCRYPTED_MEMO_SIZE},
+	entities::{
+		error::MemoError, memo_data::MemoData, memo_data_v2::MemoDataV2, memo_data_v3::MemoDataV3,
+		versioned_memo::VersionedMemo,
+	},
+	services::{
+		compression::{compress_payload, decompress_payload},
+		detection::strip_detection_tag,
+		key_derivation::{
+			derive_encryption_key, derive_recipient_shared_key, derive_sender_shared_key,
+		},
+		multi_recipient::decrypt_memo_multi,
+	},
+	value_objects::constants::{
+		AGREEMENT_KEY_SIZE, ENCRYPTED_MEMO_EPHEMERAL_SIZE, ENCRYPTED_MEMO_ESCROW_SIZE,
+		ENCRYPTED_MEMO_V2_SIZE, ENCRYPTED_MEMO_V3_SIZE, ENCRYPTED_MEMO_XCHACHA_SIZE,
+		ESCROWED_KEY_SIZE, MAC_SIZE, MAX_ENCRYPTED_MEMO_SIZE, MEMO_DATA_V2_SIZE,
+		MEMO_FLAG_COMPRESSED, MEMO_VERSION_EPHEMERAL, MEMO_VERSION_ESCROW, MEMO_VERSION_HYBRID,
+		MEMO_VERSION_MULTI, MEMO_VERSION_SIZE, MEMO_VERSION_V2, MEMO_VERSION_V3,
+		MEMO_VERSION_XCHACHA, MIN_COMPRESSED_MEMO_SIZE, MIN_ENCRYPTED_MEMO_SIZE, NONCE_SIZE,
+		XNONCE_SIZE,
+	},
 };
 
 /// Decrypts encrypted memo using viewing key
 ///
-/// Format: nonce(12) || ciphertext. Returns MemoData or error.
+/// Accepts headerless V1 memos and versioned envelopes, so notes written
+/// before V2 stay recoverable. For ephemeral-key envelopes the viewing key's
+/// agreement secret is combined with the embedded ephemeral public key;
+/// multi-recipient envelopes are opened by trial-unwrapping their key slots.
+/// Detection tags are skipped.
 pub fn decrypt_memo(
 	encrypted: &[u8],
 	commitment: &[u8; 32],
 	viewing_key: &[u8; 32],
-) -> Result<MemoData, MemoError> {
+) -> Result<VersionedMemo, MemoError> {
+	if let Some(inner) = strip_detection_tag(encrypted) {
+		return decrypt_memo(inner, commitment, viewing_key);
+	}
+	if encrypted.len() > MAX_ENCRYPTED_MEMO_SIZE && encrypted[0] == MEMO_VERSION_MULTI {
+		return decrypt_memo_multi(encrypted, commitment, viewing_key).map(VersionedMemo::V1);
+	}
+	#[cfg(feature = "pq")]
+	if encrypted.len() > MAX_ENCRYPTED_MEMO_SIZE
+		&& encrypted[0] == crate::domain::value_objects::constants::MEMO_VERSION_HYBRID
+	{
+		return super::hybrid::decrypt_memo_hybrid(encrypted, commitment, viewing_key);
+	}
+
+	// Derive decryption key
+	let key = match ephemeral_public_key(encrypted) {
+		Some(ephemeral_public) => {
+			let shared_key = derive_recipient_shared_key(viewing_key, &ephemeral_public)?;
+			derive_encryption_key(&shared_key, commitment)
+		}
+		None => derive_encryption_key(viewing_key, commitment),
+	};
+	decrypt_memo_with_key(encrypted, &key)
+}
+
+/// Ephemeral public key of an ephemeral-key envelope, `None` for other formats
+fn ephemeral_public_key(encrypted: &[u8]) -> Option<[u8; 32]> {
+	if encrypted.len() <= MAX_ENCRYPTED_MEMO_SIZE || encrypted[0] != MEMO_VERSION_EPHEMERAL {
+		return None;
+	}
+	encrypted[MEMO_VERSION_SIZE..MEMO_VERSION_SIZE + AGREEMENT_KEY_SIZE]
+		.try_into()
+		.ok()
+}
+
+/// Decrypts encrypted memo with an already derived per-note key
+///
+/// Used when the key was recovered from escrow rather than derived.
+pub(crate) fn decrypt_memo_with_key(
+	encrypted: &[u8],
+	key: &[u8; 32],
+) -> Result<VersionedMemo, MemoError> {
+	if let Some(inner) = strip_detection_tag(encrypted) {
+		return decrypt_memo_with_key(inner, key);
+	}
+
 	// Validate length
 	if encrypted.len() < MIN_ENCRYPTED_MEMO_SIZE {
 		return Err(MemoError::DataTooShort);
 	}
-	if encrypted.len() > MAX_ENCRYPTED_MEMO_SIZE {
+	// The escrow envelope is the largest single-key format
+	if encrypted.len() > ENCRYPTED_MEMO_ESCROW_SIZE {
 		return Err(MemoError::DataTooLong);
 	}
 
+	// Anything that fits the V1 layout has no header
+	if encrypted.len() <= MAX_ENCRYPTED_MEMO_SIZE {
+		let plaintext = open(key, encrypted, &[])?;
+		return MemoData::from_bytes(&plaintext).map(VersionedMemo::V1);
+	}
+
+	let (header, body) = encrypted.split_at(MEMO_VERSION_SIZE);
+	match header[0] {
+		MEMO_VERSION_V2 => {
+			let plaintext = open(key, body, header)?;
+			MemoDataV2::from_bytes(&plaintext).map(VersionedMemo::V2)
+		}
+		MEMO_VERSION_V3 => {
+			let plaintext = open(key, body, header)?;
+			MemoDataV3::from_bytes(&plaintext).map(VersionedMemo::V3)
+		}
+		MEMO_VERSION_EPHEMERAL => {
+			// Header and ephemeral public key are both associated data
+			let (aad, body) = encrypted.split_at(MEMO_VERSION_SIZE + AGREEMENT_KEY_SIZE);
+			let plaintext = open(key, body, aad)?;
+			MemoDataV2::from_bytes(&plaintext).map(VersionedMemo::V2)
+		}
+		MEMO_VERSION_ESCROW => {
+			// Header and escrow slot are both associated data
+			let (aad, body) = encrypted.split_at(MEMO_VERSION_SIZE + ESCROWED_KEY_SIZE);
+			let plaintext = open(key, body, aad)?;
+			MemoData::from_bytes(&plaintext).map(VersionedMemo::V1)
+		}
+		MEMO_VERSION_XCHACHA => {
+			let plaintext = open_xchacha(key, body, header)?;
+			MemoDataV2::from_bytes(&plaintext).map(VersionedMemo::V2)
+		}
+		COMPRESSED_V2 => {
+			let plaintext = open(key, body, header)?;
+			let payload = decompress_payload(&plaintext, MEMO_DATA_V2_SIZE)?;
+			MemoDataV2::from_bytes(&payload).map(VersionedMemo::V2)
+		}
+		version => Err(MemoError::UnsupportedMemoVersion(version)),
+	}
+}
+
+/// Header of a compressed V2 memo
+const COMPRESSED_V2: u8 = MEMO_VERSION_V2 | MEMO_FLAG_COMPRESSED;
+
+/// Decrypts `nonce(12) || ciphertext` with `aad` as associated data
+pub(crate) fn open(key: &[u8; 32], body: &[u8], aad: &[u8]) -> Result<Vec<u8>, MemoError> {
 	// Extract nonce and ciphertext
-	let (nonce_bytes, ciphertext) = encrypted.split_at(12);
+	let (nonce_bytes, ciphertext) = body.split_at(12);
 	let nonce = Nonce::from_slice(nonce_bytes);
 
-	// Derive decryption key
-	let key = derive_encryption_key(viewing_key, commitment);
-
 	// Create cipher and decrypt
-	let cipher = ChaCha20Poly1305::new((&key).into());
-	let plaintext = cipher
-		.decrypt(nonce, ciphertext)
-		.map_err(|_| MemoError::DecryptionFailed)?;
+	let cipher = ChaCha20Poly1305::new(key.into());
+	cipher
+		.decrypt(
+			nonce,
+			Payload {
+				msg: ciphertext,
+				aad,
+			},
+		)
+		.map_err(|_| MemoError::DecryptionFailed)
+}
+
+/// Decrypts `nonce(24) || ciphertext` with XChaCha20Poly1305
+fn open_xchacha(key: &[u8; 32], body: &[u8], aad: &[u8]) -> Result<Vec<u8>, MemoError> {
+	if body.len() < XNONCE_SIZE {
+		return Err(MemoError::DataTooShort);
+	}
+	let (nonce_bytes, ciphertext) = body.split_at(XNONCE_SIZE);
+
+	let cipher = XChaCha20Poly1305::new(key.into());
+	cipher
+		.decrypt(
+			XNonce::from_slice(nonce_bytes),
+			Payload {
+				msg: ciphertext,
+				aad,
+			},
+		)
+		.map_err(|_| MemoError::DecryptionFailed)
+}
+
+/// Checks the tag of a memo sealed under an already derived per-note key
+///
+/// Mirrors the envelope dispatch of [`decrypt_memo_with_key`].
+fn memo_tag_matches(encrypted: &[u8], key: &[u8; 32]) -> bool {
+	if !(MIN_ENCRYPTED_MEMO_SIZE..=ENCRYPTED_MEMO_ESCROW_SIZE).contains(&encrypted.len()) {
+		return false;
+	}
+	if encrypted.len() <= MAX_ENCRYPTED_MEMO_SIZE {
+		return tag_matches::<ChaCha20>(key, encrypted, &[]);
+	}
+
+	let (header, body) = encrypted.split_at(MEMO_VERSION_SIZE);
+	match header[0] {
+		MEMO_VERSION_V2 | MEMO_VERSION_V3 | COMPRESSED_V2 => {
+			tag_matches::<ChaCha20>(key, body, header)
+		}
+		MEMO_VERSION_EPHEMERAL => {
+			let (aad, body) = encrypted.split_at(MEMO_VERSION_SIZE + AGREEMENT_KEY_SIZE);
+			tag_matches::<ChaCha20>(key, body, aad)
+		}
+		MEMO_VERSION_ESCROW => {
+			let (aad, body) = encrypted.split_at(MEMO_VERSION_SIZE + ESCROWED_KEY_SIZE);
+			tag_matches::<ChaCha20>(key, body, aad)
+		}
+		MEMO_VERSION_XCHACHA => tag_matches::<XChaCha20>(key, body, header),
+		_ => false,
+	}
+}
 
-	// Parse memo data
-	MemoData::from_bytes(&plaintext)
+/// Verifies the tag of `nonce || ciphertext || tag` without decrypting
+///
+/// Same construction as the (X)ChaCha20Poly1305 AEAD (RFC 8439): the
+/// Poly1305 key is the first keystream block, and the MAC covers the padded
+/// associated data, the padded ciphertext and both lengths.
+fn tag_matches<C: KeyIvInit + StreamCipher>(key: &[u8; 32], body: &[u8], aad: &[u8]) -> bool {
+	if body.len() < C::iv_size() + MAC_SIZE {
+		return false;
+	}
+	let (nonce, sealed) = body.split_at(C::iv_size());
+	let (ciphertext, tag) = sealed.split_at(sealed.len() - MAC_SIZE);
+	let Ok(mut cipher) = C::new_from_slices(key, nonce) else {
+		return false;
+	};
+
+	let mut mac_key = poly1305::Key::default();
+	cipher.apply_keystream(&mut mac_key);
+	let mut mac = Poly1305::new(&mac_key);
+	mac_key.as_mut_slice().zeroize();
+
+	mac.update_padded(aad);
+	mac.update_padded(ciphertext);
+	let mut lengths = poly1305::Block::default();
+	lengths[..8].copy_from_slice(&(aad.len() as u64).to_le_bytes());
+	lengths[8..].copy_from_slice(&(ciphertext.len() as u64).to_le_bytes());
+	mac.update(&[lengths]);
+
+	mac.finalize().as_slice().ct_eq(tag).into()
+}
+
+/// Encrypts `plaintext` and appends `nonce || ciphertext` to `out`
+pub(crate) fn seal(
+	key: &[u8; 32],
+	nonce: &[u8; 12],
+	plaintext: &[u8],
+	aad: &[u8],
+	out: &mut Vec<u8>,
+) -> Result<(), MemoError> {
+	let cipher = ChaCha20Poly1305::new(key.into());
+	let ciphertext = cipher
+		.encrypt(
+			Nonce::from_slice(nonce),
+			Payload {
+				msg: plaintext,
+				aad,
+			},
+		)
+		.map_err(|_| MemoError::EncryptionFailed)?;
+
+	out.extend_from_slice(nonce);
+	out.extend_from_slice(&ciphertext);
+	Ok(())
 }
 
 /// Encrypts memo data with provided nonce
@@ -60,24 +310,138 @@ pub fn encrypt_memo(
 	// Derive encryption key
 	let key = derive_encryption_key(recipient_viewing_key, commitment);
 
-	// Serialize memo data
-	let plaintext = memo.to_bytes();
+	// Return nonce || ciphertext
+	let mut result = Vec::with_capacity(MAX_ENCRYPTED_MEMO_SIZE);
+	seal(&key, nonce, &memo.to_bytes(), &[], &mut result)?;
+	Ok(result)
+}
+
+/// Encrypts V2 memo data with provided nonce
+///
+/// Returns: version(1) || nonce(12) || ciphertext(100+16)
+/// WARNING: Nonce MUST be unique and never reused.
+pub fn encrypt_memo_v2(
+	memo: &MemoDataV2,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+	nonce: &[u8; 12],
+) -> Result<Vec<u8>, MemoError> {
+	let key = derive_encryption_key(recipient_viewing_key, commitment);
+
+	let header = [MEMO_VERSION_V2];
+	let mut result = Vec::with_capacity(ENCRYPTED_MEMO_V2_SIZE);
+	result.extend_from_slice(&header);
+	seal(&key, nonce, &memo.to_bytes(), &header, &mut result)?;
+	Ok(result)
+}
+
+/// Encrypts V3 memo data with provided nonce
+///
+/// Returns: version(1) || nonce(12) || ciphertext(133+16)
+/// WARNING: Nonce MUST be unique and never reused.
+pub fn encrypt_memo_v3(
+	memo: &MemoDataV3,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+	nonce: &[u8; 12],
+) -> Result<Vec<u8>, MemoError> {
+	let key = derive_encryption_key(recipient_viewing_key, commitment);
+
+	let header = [MEMO_VERSION_V3];
+	let mut result = Vec::with_capacity(ENCRYPTED_MEMO_V3_SIZE);
+	result.extend_from_slice(&header);
+	seal(&key, nonce, &memo.to_bytes(), &header, &mut result)?;
+	Ok(result)
+}
+
+/// Encrypts V2 memo data with a compressed payload
+///
+/// Returns: (version | MEMO_FLAG_COMPRESSED)(1) || nonce(12) || ciphertext(76..=99 + 16),
+/// or a plain V2 memo when compression does not shrink the payload.
+/// WARNING: Nonce MUST be unique and never reused.
+pub fn encrypt_memo_v2_compressed(
+	memo: &MemoDataV2,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+	nonce: &[u8; 12],
+) -> Result<Vec<u8>, MemoError> {
+	let mut payload = compress_payload(&memo.to_bytes());
+	if payload.len() >= MEMO_DATA_V2_SIZE {
+		return encrypt_memo_v2(memo, commitment, recipient_viewing_key, nonce);
+	}
+	// Pad past the V1 length range
+	let min_payload = MIN_COMPRESSED_MEMO_SIZE - MEMO_VERSION_SIZE - NONCE_SIZE - MAC_SIZE;
+	if payload.len() < min_payload {
+		payload.resize(min_payload, 0);
+	}
+
+	let key = derive_encryption_key(recipient_viewing_key, commitment);
+	let header = [COMPRESSED_V2];
+	let mut result = Vec::with_capacity(MEMO_VERSION_SIZE + NONCE_SIZE + payload.len() + MAC_SIZE);
+	result.extend_from_slice(&header);
+	seal(&key, nonce, &payload, &header, &mut result)?;
+	Ok(result)
+}
+
+/// Encrypts V2 memo data with XChaCha20Poly1305
+///
+/// Returns: version(1) || nonce(24) || ciphertext(100+16)
+/// The 24-byte nonce may be drawn at random without collision concerns.
+pub fn encrypt_memo_xchacha(
+	memo: &MemoDataV2,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+	nonce: &[u8; XNONCE_SIZE],
+) -> Result<Vec<u8>, MemoError> {
+	let mut key = derive_encryption_key(recipient_viewing_key, commitment);
 
-	// Create cipher and encrypt
-	let cipher = ChaCha20Poly1305::new((&key).into());
-	let nonce_obj = Nonce::from_slice(nonce);
+	let header = [MEMO_VERSION_XCHACHA];
+	let cipher = XChaCha20Poly1305::new((&key).into());
+	key.zeroize();
 	let ciphertext = cipher
-		.encrypt(nonce_obj, plaintext.as_ref())
+		.encrypt(
+			XNonce::from_slice(nonce),
+			Payload {
+				msg: &memo.to_bytes(),
+				aad: &header,
+			},
+		)
 		.map_err(|_| MemoError::EncryptionFailed)?;
 
-	// Return nonce || ciphertext
-	let mut result = Vec::with_capacity(12 + ciphertext.len());
+	let mut result = Vec::with_capacity(ENCRYPTED_MEMO_XCHACHA_SIZE);
+	result.extend_from_slice(&header);
 	result.extend_from_slice(nonce);
 	result.extend_from_slice(&ciphertext);
-
 	Ok(result)
 }
 
+/// Encrypts V2 memo data with a fresh ephemeral key
+///
+/// Returns: version(1) || ephemeral_public(32) || nonce(12) || ciphertext(100+16)
+/// The recipient decrypts with [`decrypt_memo`] and their viewing key.
+/// WARNING: `ephemeral_secret` and nonce MUST be fresh per note, and the
+/// secret should be erased once the memo is sealed.
+pub fn encrypt_memo_ephemeral(
+	memo: &MemoDataV2,
+	commitment: &[u8; 32],
+	recipient_agreement_key: &[u8; 32],
+	ephemeral_secret: &[u8; 32],
+	nonce: &[u8; 12],
+) -> Result<Vec<u8>, MemoError> {
+	let (ephemeral_public, mut shared_key) =
+		derive_sender_shared_key(ephemeral_secret, recipient_agreement_key)?;
+	let mut key = derive_encryption_key(&shared_key, commitment);
+	shared_key.zeroize();
+
+	let mut result = Vec::with_capacity(ENCRYPTED_MEMO_EPHEMERAL_SIZE);
+	result.push(MEMO_VERSION_EPHEMERAL);
+	result.extend_from_slice(&ephemeral_public);
+	let aad = result.clone();
+	let sealed = seal(&key, nonce, &memo.to_bytes(), &aad, &mut result);
+	key.zeroize();
+	sealed.map(|()| result)
+}
+
 /// Encrypts memo with auto-generated random nonce
 ///
 /// Recommended method. Requires encrypt feature.
@@ -96,6 +460,106 @@ pub fn encrypt_memo_random(
 	encrypt_memo(memo, commitment, recipient_viewing_key, &nonce)
 }
 
+/// Encrypts V2 memo with auto-generated random nonce
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_memo_v2_random(
+	memo: &MemoDataV2,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut nonce);
+
+	encrypt_memo_v2(memo, commitment, recipient_viewing_key, &nonce)
+}
+
+/// Encrypts V3 memo with random nonce
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_memo_v3_random(
+	memo: &MemoDataV3,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut nonce);
+
+	encrypt_memo_v3(memo, commitment, recipient_viewing_key, &nonce)
+}
+
+/// Encrypts V2 memo with a compressed payload and a random nonce
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_memo_v2_compressed_random(
+	memo: &MemoDataV2,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut nonce);
+
+	encrypt_memo_v2_compressed(memo, commitment, recipient_viewing_key, &nonce)
+}
+
+/// Encrypts V2 memo with XChaCha20Poly1305 and a random 24-byte nonce
+///
+/// Preferred for high-volume senders. Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_memo_xchacha_random(
+	memo: &MemoDataV2,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut nonce = [0u8; XNONCE_SIZE];
+	OsRng.fill_bytes(&mut nonce);
+
+	encrypt_memo_xchacha(memo, commitment, recipient_viewing_key, &nonce)
+}
+
+/// Encrypts V2 memo data with a random ephemeral secret and nonce
+///
+/// The ephemeral secret is erased before returning. Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_memo_ephemeral_random(
+	memo: &MemoDataV2,
+	commitment: &[u8; 32],
+	recipient_agreement_key: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut ephemeral_secret = [0u8; 32];
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut ephemeral_secret);
+	OsRng.fill_bytes(&mut nonce);
+
+	let result = encrypt_memo_ephemeral(
+		memo,
+		commitment,
+		recipient_agreement_key,
+		&ephemeral_secret,
+		&nonce,
+	);
+	ephemeral_secret.zeroize();
+	result
+}
+
 /// Attempts decryption, returns None on failure
 ///
 /// Useful for scanning blockchain to find owned notes.
@@ -103,10 +567,124 @@ pub fn try_decrypt_memo(
 	encrypted: &[u8],
 	commitment: &[u8; 32],
 	viewing_key: &[u8; 32],
-) -> Option<MemoData> {
+) -> Option<VersionedMemo> {
 	decrypt_memo(encrypted, commitment, viewing_key).ok()
 }
 
+/// Checks whether a memo was sealed for `viewing_key`, without decrypting it
+///
+/// Derives the per-note key and verifies the Poly1305 tag only, so rescans
+/// that just ask "is this mine?" skip the payload keystream. A match means
+/// the memo authenticates under the key; [`decrypt_memo`] may still reject a
+/// malformed payload. Multi-recipient and hybrid envelopes fall back to full
+/// decryption; off-chain pointers never match.
+pub fn detect_only(encrypted: &[u8], commitment: &[u8; 32], viewing_key: &[u8; 32]) -> bool {
+	if let Some(inner) = strip_detection_tag(encrypted) {
+		return detect_only(inner, commitment, viewing_key);
+	}
+	if encrypted.len() > MAX_ENCRYPTED_MEMO_SIZE
+		&& matches!(encrypted[0], MEMO_VERSION_MULTI | MEMO_VERSION_HYBRID)
+	{
+		return try_decrypt_memo(encrypted, commitment, viewing_key).is_some();
+	}
+
+	let key = match ephemeral_public_key(encrypted) {
+		Some(ephemeral_public) => {
+			match derive_recipient_shared_key(viewing_key, &ephemeral_public) {
+				Ok(shared_key) => derive_encryption_key(&shared_key, commitment),
+				Err(_) => return false,
+			}
+		}
+		None => derive_encryption_key(viewing_key, commitment),
+	};
+	memo_tag_matches(encrypted, &key)
+}
+
+/// Encrypts memo data to a recipient's published agreement key
+///
+/// For senders who only know the recipient's address. Returns
+/// `(nonce(12) || ciphertext(76+16), ephemeral_public)`; the memo has the
+/// standard layout and the ephemeral public key is published next to it.
+/// WARNING: `ephemeral_secret` and nonce MUST be fresh per note.
+pub fn encrypt_memo_to_address(
+	memo: &MemoData,
+	commitment: &[u8; 32],
+	recipient_agreement_key: &[u8; 32],
+	ephemeral_secret: &[u8; 32],
+	nonce: &[u8; 12],
+) -> Result<(Vec<u8>, [u8; 32]), MemoError> {
+	let (ephemeral_public, shared_key) =
+		derive_sender_shared_key(ephemeral_secret, recipient_agreement_key)?;
+	let encrypted = encrypt_memo(memo, commitment, &shared_key, nonce)?;
+	Ok((encrypted, ephemeral_public))
+}
+
+/// Encrypts V2 memo data to a recipient's published agreement key
+///
+/// As [`encrypt_memo_to_address`], with the V2 envelope.
+/// WARNING: `ephemeral_secret` and nonce MUST be fresh per note.
+pub fn encrypt_memo_v2_to_address(
+	memo: &MemoDataV2,
+	commitment: &[u8; 32],
+	recipient_agreement_key: &[u8; 32],
+	ephemeral_secret: &[u8; 32],
+	nonce: &[u8; 12],
+) -> Result<(Vec<u8>, [u8; 32]), MemoError> {
+	let (ephemeral_public, shared_key) =
+		derive_sender_shared_key(ephemeral_secret, recipient_agreement_key)?;
+	let encrypted = encrypt_memo_v2(memo, commitment, &shared_key, nonce)?;
+	Ok((encrypted, ephemeral_public))
+}
+
+/// Encrypts memo to an agreement key with random ephemeral secret and nonce
+///
+/// Recommended method for third-party payments. Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_memo_to_address_random(
+	memo: &MemoData,
+	commitment: &[u8; 32],
+	recipient_agreement_key: &[u8; 32],
+) -> Result<(Vec<u8>, [u8; 32]), MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut ephemeral_secret = [0u8; 32];
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut ephemeral_secret);
+	OsRng.fill_bytes(&mut nonce);
+
+	let result = encrypt_memo_to_address(
+		memo,
+		commitment,
+		recipient_agreement_key,
+		&ephemeral_secret,
+		&nonce,
+	);
+	ephemeral_secret.zeroize();
+	result
+}
+
+/// Decrypts a memo encrypted to this viewing key's agreement key
+pub fn decrypt_memo_from_sender(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+	ephemeral_public: &[u8; 32],
+) -> Result<VersionedMemo, MemoError> {
+	let shared_key = derive_recipient_shared_key(viewing_key, ephemeral_public)?;
+	decrypt_memo(encrypted, commitment, &shared_key)
+}
+
+/// Attempts agreement-based decryption, returns None on failure
+pub fn try_decrypt_memo_from_sender(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+	ephemeral_public: &[u8; 32],
+) -> Option<VersionedMemo> {
+	decrypt_memo_from_sender(encrypted, commitment, viewing_key, ephemeral_public).ok()
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -210,7 +788,7 @@ mod tests {
 		let encrypted = encrypt_memo(&memo, &commitment, &viewing_key, &nonce).unwrap();
 		let decrypted = decrypt_memo(&encrypted, &commitment, &viewing_key).unwrap();
 
-		assert_eq!(decrypted, memo);
+		assert_eq!(decrypted, memo.into());
 	}
 
 	#[test]
@@ -223,10 +801,10 @@ mod tests {
 		let encrypted = encrypt_memo(&original, &commitment, &viewing_key, &nonce).unwrap();
 		let decrypted = decrypt_memo(&encrypted, &commitment, &viewing_key).unwrap();
 
-		assert_eq!(decrypted.value, original.value);
-		assert_eq!(decrypted.owner_pk, original.owner_pk);
-		assert_eq!(decrypted.blinding, original.blinding);
-		assert_eq!(decrypted.asset_id, original.asset_id);
+		assert_eq!(decrypted.value(), original.value);
+		assert_eq!(decrypted.owner_pk(), &original.owner_pk);
+		assert_eq!(decrypted.blinding(), &original.blinding);
+		assert_eq!(decrypted.asset_id(), u64::from(original.asset_id));
 	}
 
 	#[test]
@@ -280,7 +858,7 @@ mod tests {
 
 	#[test]
 	fn test_decrypt_memo_too_long() {
-		let encrypted = vec![0u8; MAX_ENCRYPTED_MEMO_SIZE + 1];
+		let encrypted = vec![0u8; ENCRYPTED_MEMO_ESCROW_SIZE + 1];
 		let commitment = [3u8; 32];
 		let viewing_key = [4u8; 32];
 
@@ -349,7 +927,7 @@ mod tests {
 		let encrypted = encrypt_memo_random(&original, &commitment, &viewing_key).unwrap();
 		let decrypted = decrypt_memo(&encrypted, &commitment, &viewing_key).unwrap();
 
-		assert_eq!(decrypted, original);
+		assert_eq!(decrypted, original.into());
 	}
 
 	// ===== try_decrypt_memo Tests =====
@@ -365,7 +943,7 @@ mod tests {
 		let result = try_decrypt_memo(&encrypted, &commitment, &viewing_key);
 
 		assert!(result.is_some());
-		assert_eq!(result.unwrap(), memo);
+		assert_eq!(result.unwrap(), memo.into());
 	}
 
 	#[test]
@@ -408,8 +986,14 @@ mod tests {
 		let encrypted2 = encrypt_memo(&memo2, &commitment, &vk2, &nonce2).unwrap();
 
 		// Each key can only decrypt its own memo
-		assert_eq!(decrypt_memo(&encrypted1, &commitment, &vk1).unwrap(), memo1);
-		assert_eq!(decrypt_memo(&encrypted2, &commitment, &vk2).unwrap(), memo2);
+		assert_eq!(
+			decrypt_memo(&encrypted1, &commitment, &vk1).unwrap(),
+			memo1.into()
+		);
+		assert_eq!(
+			decrypt_memo(&encrypted2, &commitment, &vk2).unwrap(),
+			memo2.into()
+		);
 		assert!(decrypt_memo(&encrypted1, &commitment, &vk2).is_err());
 		assert!(decrypt_memo(&encrypted2, &commitment, &vk1).is_err());
 	}
@@ -443,7 +1027,7 @@ mod tests {
 		for original in test_cases {
 			let encrypted = encrypt_memo(&original, &commitment, &viewing_key, &nonce).unwrap();
 			let decrypted = decrypt_memo(&encrypted, &commitment, &viewing_key).unwrap();
-			assert_eq!(decrypted, original);
+			assert_eq!(decrypted, original.into());
 		}
 	}
 
@@ -515,6 +1099,598 @@ mod tests {
 		// 12 nonce + 76 plaintext + 16 MAC = 104 == MAX_ENCRYPTED_MEMO_SIZE
 		assert_eq!(enc.len(), MAX_ENCRYPTED_MEMO_SIZE);
 		let dec = decrypt_memo(&enc, &comm, &vk).unwrap();
-		assert_eq!(dec, memo);
+		assert_eq!(dec, memo.into());
+	}
+
+	// ===== Key Agreement Tests =====
+
+	#[test]
+	fn test_encrypt_to_address_roundtrip() {
+		use crate::domain::services::key_derivation::derive_agreement_public_key;
+
+		let memo = MemoData::new(750, [1u8; 32], [2u8; 32], 3);
+		let commitment = [3u8; 32];
+		let viewing_key = [4u8; 32];
+		let agreement_key = derive_agreement_public_key(&viewing_key);
+
+		let (encrypted, ephemeral_public) =
+			encrypt_memo_to_address(&memo, &commitment, &agreement_key, &[6u8; 32], &[5u8; 12])
+				.unwrap();
+
+		assert_eq!(encrypted.len(), MAX_ENCRYPTED_MEMO_SIZE);
+		assert_eq!(
+			decrypt_memo_from_sender(&encrypted, &commitment, &viewing_key, &ephemeral_public)
+				.unwrap(),
+			memo.into()
+		);
+		// The viewing key alone does not open an agreement-based memo
+		assert!(try_decrypt_memo(&encrypted, &commitment, &viewing_key).is_none());
+	}
+
+	#[test]
+	fn test_decrypt_from_sender_wrong_viewing_key() {
+		use crate::domain::services::key_derivation::derive_agreement_public_key;
+
+		let memo = MemoData::new(750, [1u8; 32], [2u8; 32], 3);
+		let agreement_key = derive_agreement_public_key(&[4u8; 32]);
+		let (encrypted, ephemeral_public) =
+			encrypt_memo_to_address(&memo, &[3u8; 32], &agreement_key, &[6u8; 32], &[5u8; 12])
+				.unwrap();
+
+		assert!(try_decrypt_memo_from_sender(
+			&encrypted,
+			&[3u8; 32],
+			&[9u8; 32],
+			&ephemeral_public
+		)
+		.is_none());
+	}
+
+	#[test]
+	fn test_encrypt_to_low_order_key_fails() {
+		let memo = MemoData::new(1, [1u8; 32], [2u8; 32], 0);
+		assert_eq!(
+			encrypt_memo_to_address(&memo, &[3u8; 32], &[0u8; 32], &[6u8; 32], &[5u8; 12]),
+			Err(MemoError::InvalidPublicKey)
+		);
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_encrypt_to_address_random_roundtrip() {
+		use crate::domain::services::key_derivation::derive_agreement_public_key;
+
+		let memo = MemoData::new(9, [1u8; 32], [2u8; 32], 0);
+		let agreement_key = derive_agreement_public_key(&[4u8; 32]);
+		let (encrypted, ephemeral_public) =
+			encrypt_memo_to_address_random(&memo, &[3u8; 32], &agreement_key).unwrap();
+
+		assert_eq!(
+			decrypt_memo_from_sender(&encrypted, &[3u8; 32], &[4u8; 32], &ephemeral_public)
+				.unwrap(),
+			memo.into()
+		);
+	}
+
+	// ===== Versioned envelope Tests =====
+
+	fn memo_v2() -> MemoDataV2 {
+		MemoDataV2::new(1000, [1u8; 32], [2u8; 32], 1 << 40, 7, [9u8; 16])
+	}
+
+	#[test]
+	fn test_encrypt_memo_v2_layout() {
+		let encrypted = encrypt_memo_v2(&memo_v2(), &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+
+		assert_eq!(encrypted.len(), ENCRYPTED_MEMO_V2_SIZE);
+		assert_eq!(encrypted[0], MEMO_VERSION_V2);
+		assert_eq!(&encrypted[1..13], &[5u8; 12]);
+	}
+
+	#[test]
+	fn test_decrypt_memo_dispatches_on_version() {
+		let commitment = [3u8; 32];
+		let viewing_key = [4u8; 32];
+		let v1 = MemoData::new(500, [1u8; 32], [2u8; 32], 3);
+		let encrypted_v1 = encrypt_memo(&v1, &commitment, &viewing_key, &[5u8; 12]).unwrap();
+		let encrypted_v2 =
+			encrypt_memo_v2(&memo_v2(), &commitment, &viewing_key, &[6u8; 12]).unwrap();
+
+		assert_eq!(
+			decrypt_memo(&encrypted_v1, &commitment, &viewing_key),
+			Ok(VersionedMemo::V1(v1))
+		);
+		assert_eq!(
+			decrypt_memo(&encrypted_v2, &commitment, &viewing_key),
+			Ok(VersionedMemo::V2(memo_v2()))
+		);
+	}
+
+	#[test]
+	fn test_decrypt_memo_v2_wrong_key() {
+		let encrypted = encrypt_memo_v2(&memo_v2(), &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[8u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_decrypt_memo_unknown_version() {
+		let mut encrypted =
+			encrypt_memo_v2(&memo_v2(), &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+		encrypted[0] = 9;
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Err(MemoError::UnsupportedMemoVersion(9))
+		);
+
+		let garbage = vec![0u8; MAX_ENCRYPTED_MEMO_SIZE + 1];
+		assert_eq!(
+			decrypt_memo(&garbage, &[3u8; 32], &[4u8; 32]),
+			Err(MemoError::UnsupportedMemoVersion(0))
+		);
+	}
+
+	#[test]
+	fn test_decrypt_memo_v2_header_is_authenticated() {
+		// Re-sealing the V2 payload without the header as associated data must fail
+		let key = derive_encryption_key(&[4u8; 32], &[3u8; 32]);
+		let mut forged = vec![MEMO_VERSION_V2];
+		seal(&key, &[5u8; 12], &memo_v2().to_bytes(), &[], &mut forged).unwrap();
+
+		assert_eq!(
+			decrypt_memo(&forged, &[3u8; 32], &[4u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_decrypt_memo_v2_truncated() {
+		let encrypted = encrypt_memo_v2(&memo_v2(), &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+		assert_eq!(
+			decrypt_memo(
+				&encrypted[..ENCRYPTED_MEMO_V2_SIZE - 1],
+				&[3u8; 32],
+				&[4u8; 32]
+			),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_encrypt_memo_v2_to_address_roundtrip() {
+		use crate::domain::services::key_derivation::derive_agreement_public_key;
+
+		let agreement_key = derive_agreement_public_key(&[4u8; 32]);
+		let (encrypted, ephemeral_public) = encrypt_memo_v2_to_address(
+			&memo_v2(),
+			&[3u8; 32],
+			&agreement_key,
+			&[6u8; 32],
+			&[5u8; 12],
+		)
+		.unwrap();
+
+		assert_eq!(
+			decrypt_memo_from_sender(&encrypted, &[3u8; 32], &[4u8; 32], &ephemeral_public),
+			Ok(VersionedMemo::V2(memo_v2()))
+		);
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_encrypt_memo_v2_random_roundtrip() {
+		let encrypted = encrypt_memo_v2_random(&memo_v2(), &[3u8; 32], &[4u8; 32]).unwrap();
+		assert_eq!(
+			try_decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Some(VersionedMemo::V2(memo_v2()))
+		);
+	}
+
+	// ===== V3 envelope Tests =====
+
+	fn memo_v3() -> MemoDataV3 {
+		MemoDataV3::from_v2(memo_v2(), b"invoice-2026-0042").unwrap()
+	}
+
+	#[test]
+	fn test_encrypt_memo_v3_roundtrip() {
+		let encrypted = encrypt_memo_v3(&memo_v3(), &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+
+		assert_eq!(encrypted.len(), ENCRYPTED_MEMO_V3_SIZE);
+		assert_eq!(encrypted[0], MEMO_VERSION_V3);
+		let decrypted = decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]).unwrap();
+		assert_eq!(decrypted.app_data(), Some(&b"invoice-2026-0042"[..]));
+		assert_eq!(decrypted, VersionedMemo::V3(memo_v3()));
+	}
+
+	#[test]
+	fn test_v3_length_hides_app_data_size() {
+		let empty = MemoDataV3::from(memo_v2());
+		let a = encrypt_memo_v3(&empty, &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+		let b = encrypt_memo_v3(&memo_v3(), &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+		assert_eq!(a.len(), b.len());
+	}
+
+	#[test]
+	fn test_v3_header_is_authenticated() {
+		let mut encrypted =
+			encrypt_memo_v3(&memo_v3(), &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+		encrypted[0] = MEMO_VERSION_V2;
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_encrypt_memo_v3_random_roundtrip() {
+		let encrypted = encrypt_memo_v3_random(&memo_v3(), &[3u8; 32], &[4u8; 32]).unwrap();
+		assert_eq!(
+			try_decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Some(VersionedMemo::V3(memo_v3()))
+		);
+	}
+
+	// ===== Ephemeral-key envelope Tests =====
+
+	fn agreement_key() -> [u8; 32] {
+		crate::domain::services::key_derivation::derive_agreement_public_key(&[4u8; 32])
+	}
+
+	#[test]
+	fn test_encrypt_memo_ephemeral_layout() {
+		let encrypted = encrypt_memo_ephemeral(
+			&memo_v2(),
+			&[3u8; 32],
+			&agreement_key(),
+			&[6u8; 32],
+			&[5u8; 12],
+		)
+		.unwrap();
+		let (ephemeral_public, _) = derive_sender_shared_key(&[6u8; 32], &agreement_key()).unwrap();
+
+		assert_eq!(encrypted.len(), ENCRYPTED_MEMO_EPHEMERAL_SIZE);
+		assert_eq!(encrypted[0], MEMO_VERSION_EPHEMERAL);
+		assert_eq!(&encrypted[1..33], &ephemeral_public);
+		assert_eq!(&encrypted[33..45], &[5u8; 12]);
+	}
+
+	#[test]
+	fn test_decrypt_memo_ephemeral_roundtrip() {
+		let encrypted = encrypt_memo_ephemeral(
+			&memo_v2(),
+			&[3u8; 32],
+			&agreement_key(),
+			&[6u8; 32],
+			&[5u8; 12],
+		)
+		.unwrap();
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Ok(VersionedMemo::V2(memo_v2()))
+		);
+	}
+
+	#[test]
+	fn test_decrypt_memo_ephemeral_wrong_key() {
+		let encrypted = encrypt_memo_ephemeral(
+			&memo_v2(),
+			&[3u8; 32],
+			&agreement_key(),
+			&[6u8; 32],
+			&[5u8; 12],
+		)
+		.unwrap();
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[8u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+		assert_eq!(
+			decrypt_memo(&encrypted, &[9u8; 32], &[4u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_ephemeral_secret_changes_ciphertext() {
+		let a = encrypt_memo_ephemeral(
+			&memo_v2(),
+			&[3u8; 32],
+			&agreement_key(),
+			&[6u8; 32],
+			&[5u8; 12],
+		)
+		.unwrap();
+		let b = encrypt_memo_ephemeral(
+			&memo_v2(),
+			&[3u8; 32],
+			&agreement_key(),
+			&[7u8; 32],
+			&[5u8; 12],
+		)
+		.unwrap();
+		assert_ne!(a[1..33], b[1..33]);
+		assert_ne!(a[45..], b[45..]);
+	}
+
+	#[test]
+	fn test_decrypt_memo_ephemeral_public_is_authenticated() {
+		// Re-sealing without the ephemeral public key as associated data must fail
+		let (ephemeral_public, shared_key) =
+			derive_sender_shared_key(&[6u8; 32], &agreement_key()).unwrap();
+		let key = derive_encryption_key(&shared_key, &[3u8; 32]);
+		let mut forged = vec![MEMO_VERSION_EPHEMERAL];
+		forged.extend_from_slice(&ephemeral_public);
+		seal(
+			&key,
+			&[5u8; 12],
+			&memo_v2().to_bytes(),
+			&[MEMO_VERSION_EPHEMERAL],
+			&mut forged,
+		)
+		.unwrap();
+
+		assert_eq!(
+			decrypt_memo(&forged, &[3u8; 32], &[4u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_decrypt_memo_ephemeral_rejects_low_order_point() {
+		let mut encrypted = encrypt_memo_ephemeral(
+			&memo_v2(),
+			&[3u8; 32],
+			&agreement_key(),
+			&[6u8; 32],
+			&[5u8; 12],
+		)
+		.unwrap();
+		encrypted[1..33].copy_from_slice(&[0u8; 32]);
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Err(MemoError::InvalidPublicKey)
+		);
+	}
+
+	#[test]
+	fn test_encrypt_memo_ephemeral_rejects_low_order_agreement_key() {
+		assert_eq!(
+			encrypt_memo_ephemeral(&memo_v2(), &[3u8; 32], &[0u8; 32], &[6u8; 32], &[5u8; 12]),
+			Err(MemoError::InvalidPublicKey)
+		);
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_encrypt_memo_ephemeral_random_roundtrip() {
+		let a = encrypt_memo_ephemeral_random(&memo_v2(), &[3u8; 32], &agreement_key()).unwrap();
+		let b = encrypt_memo_ephemeral_random(&memo_v2(), &[3u8; 32], &agreement_key()).unwrap();
+
+		assert_ne!(a[1..33], b[1..33]);
+		assert_eq!(
+			try_decrypt_memo(&a, &[3u8; 32], &[4u8; 32]),
+			Some(VersionedMemo::V2(memo_v2()))
+		);
+	}
+
+	// ===== Compressed envelope Tests =====
+
+	#[test]
+	fn test_encrypt_memo_v2_compressed_shrinks_sparse_memo() {
+		let memo = MemoDataV2::new(1000, [1u8; 32], [2u8; 32], 0, 0, [0u8; 16]);
+		let encrypted =
+			encrypt_memo_v2_compressed(&memo, &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+
+		assert_eq!(encrypted[0], MEMO_VERSION_V2 | MEMO_FLAG_COMPRESSED);
+		assert!(encrypted.len() < ENCRYPTED_MEMO_V2_SIZE);
+		assert!(encrypted.len() >= MIN_COMPRESSED_MEMO_SIZE);
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Ok(VersionedMemo::V2(memo))
+		);
+	}
+
+	#[test]
+	fn test_encrypt_memo_v2_compressed_pads_past_v1_range() {
+		let memo = MemoDataV2::new(0, [0u8; 32], [0u8; 32], 0, 0, [0u8; 16]);
+		let encrypted =
+			encrypt_memo_v2_compressed(&memo, &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+
+		assert_eq!(encrypted.len(), MIN_COMPRESSED_MEMO_SIZE);
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Ok(VersionedMemo::V2(memo))
+		);
+	}
+
+	#[test]
+	fn test_encrypt_memo_v2_compressed_falls_back_when_dense() {
+		let memo = MemoDataV2::new(
+			u64::MAX,
+			[1u8; 32],
+			[2u8; 32],
+			u64::MAX,
+			u32::MAX,
+			[9u8; 16],
+		);
+		let encrypted =
+			encrypt_memo_v2_compressed(&memo, &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+
+		assert_eq!(
+			encrypted,
+			encrypt_memo_v2(&memo, &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_compressed_flag_is_authenticated() {
+		let memo = MemoDataV2::new(1000, [1u8; 32], [2u8; 32], 0, 0, [0u8; 16]);
+		let mut encrypted =
+			encrypt_memo_v2_compressed(&memo, &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+		encrypted[0] = MEMO_VERSION_V2;
+		assert!(decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]).is_err());
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_encrypt_memo_v2_compressed_random_roundtrip() {
+		let memo = MemoDataV2::new(1000, [1u8; 32], [2u8; 32], 0, 0, [0u8; 16]);
+		let encrypted = encrypt_memo_v2_compressed_random(&memo, &[3u8; 32], &[4u8; 32]).unwrap();
+		assert_eq!(
+			try_decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Some(VersionedMemo::V2(memo))
+		);
+	}
+
+	// ===== XChaCha20Poly1305 envelope Tests =====
+
+	#[test]
+	fn test_encrypt_memo_xchacha_layout() {
+		let encrypted =
+			encrypt_memo_xchacha(&memo_v2(), &[3u8; 32], &[4u8; 32], &[5u8; XNONCE_SIZE]).unwrap();
+
+		assert_eq!(encrypted.len(), ENCRYPTED_MEMO_XCHACHA_SIZE);
+		assert_eq!(encrypted[0], MEMO_VERSION_XCHACHA);
+		assert_eq!(&encrypted[1..25], &[5u8; XNONCE_SIZE]);
+	}
+
+	#[test]
+	fn test_decrypt_memo_xchacha_roundtrip() {
+		let encrypted =
+			encrypt_memo_xchacha(&memo_v2(), &[3u8; 32], &[4u8; 32], &[5u8; XNONCE_SIZE]).unwrap();
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Ok(VersionedMemo::V2(memo_v2()))
+		);
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[8u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_decrypt_memo_xchacha_header_is_authenticated() {
+		let mut encrypted =
+			encrypt_memo_xchacha(&memo_v2(), &[3u8; 32], &[4u8; 32], &[5u8; XNONCE_SIZE]).unwrap();
+		encrypted[1] ^= 1;
+		assert_eq!(
+			decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_xchacha_differs_from_chacha_under_same_key() {
+		let xchacha =
+			encrypt_memo_xchacha(&memo_v2(), &[3u8; 32], &[4u8; 32], &[5u8; XNONCE_SIZE]).unwrap();
+		let chacha = encrypt_memo_v2(&memo_v2(), &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+		assert_ne!(xchacha[XNONCE_SIZE + 1..], chacha[13..]);
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_encrypt_memo_xchacha_random_roundtrip() {
+		let a = encrypt_memo_xchacha_random(&memo_v2(), &[3u8; 32], &[4u8; 32]).unwrap();
+		let b = encrypt_memo_xchacha_random(&memo_v2(), &[3u8; 32], &[4u8; 32]).unwrap();
+
+		assert_ne!(a[1..25], b[1..25]);
+		assert_eq!(
+			try_decrypt_memo(&a, &[3u8; 32], &[4u8; 32]),
+			Some(VersionedMemo::V2(memo_v2()))
+		);
+	}
+
+	// ===== detect_only Tests =====
+
+	/// One memo of each envelope format, all sealed for viewing key `[4u8; 32]`
+	fn envelopes_for_each_format() -> Vec<Vec<u8>> {
+		use crate::domain::services::{
+			detection::{create_detection_tag, tag_memo},
+			escrow::encrypt_memo_escrowed,
+			key_derivation::derive_clue_key,
+			multi_recipient::encrypt_memo_multi,
+		};
+		use crate::domain::value_objects::ViewingKey;
+
+		let commitment = [3u8; 32];
+		let viewing_key = [4u8; 32];
+		let v1 = MemoData::new(500, [1u8; 32], [2u8; 32], 3);
+		let sparse = MemoDataV2::new(1000, [1u8; 32], [2u8; 32], 0, 0, [0u8; 16]);
+		let v2 = encrypt_memo_v2(&memo_v2(), &commitment, &viewing_key, &[5u8; 12]).unwrap();
+		let tag = create_detection_tag(&derive_clue_key(&viewing_key), &[7u8; 32]).unwrap();
+
+		vec![
+			encrypt_memo(&v1, &commitment, &viewing_key, &[5u8; 12]).unwrap(),
+			v2.clone(),
+			encrypt_memo_v3(&memo_v3(), &commitment, &viewing_key, &[5u8; 12]).unwrap(),
+			encrypt_memo_v2_compressed(&sparse, &commitment, &viewing_key, &[5u8; 12]).unwrap(),
+			encrypt_memo_xchacha(&memo_v2(), &commitment, &viewing_key, &[5u8; 24]).unwrap(),
+			encrypt_memo_ephemeral(
+				&memo_v2(),
+				&commitment,
+				&agreement_key(),
+				&[6u8; 32],
+				&[5u8; 12],
+			)
+			.unwrap(),
+			encrypt_memo_escrowed(
+				&v1,
+				&commitment,
+				&viewing_key,
+				&[5u8; 12],
+				&agreement_key(),
+				&[8u8; 32],
+			)
+			.unwrap(),
+			encrypt_memo_multi(
+				&v1,
+				&commitment,
+				&[ViewingKey::from_bytes(viewing_key)],
+				&[9u8; 32],
+				&[5u8; 12],
+			)
+			.unwrap(),
+			tag_memo(&v2, &tag).unwrap(),
+		]
+	}
+
+	#[test]
+	fn test_detect_only_matches_every_format() {
+		for encrypted in envelopes_for_each_format() {
+			assert!(detect_only(&encrypted, &[3u8; 32], &[4u8; 32]));
+			assert!(!detect_only(&encrypted, &[3u8; 32], &[8u8; 32]));
+			assert!(!detect_only(&encrypted, &[8u8; 32], &[4u8; 32]));
+		}
+	}
+
+	#[test]
+	fn test_detect_only_agrees_with_decryption() {
+		for mut encrypted in envelopes_for_each_format() {
+			let last = encrypted.len() - 1;
+			encrypted[last] ^= 1;
+			assert!(!detect_only(&encrypted, &[3u8; 32], &[4u8; 32]));
+			assert!(try_decrypt_memo(&encrypted, &[3u8; 32], &[4u8; 32]).is_none());
+		}
+	}
+
+	#[test]
+	fn test_detect_only_rejects_tampered_associated_data() {
+		let mut encrypted =
+			encrypt_memo_v2(&memo_v2(), &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap();
+		encrypted[0] = MEMO_VERSION_V3;
+		assert!(!detect_only(&encrypted, &[3u8; 32], &[4u8; 32]));
+	}
+
+	#[test]
+	fn test_detect_only_rejects_malformed_input() {
+		assert!(!detect_only(&[], &[3u8; 32], &[4u8; 32]));
+		assert!(!detect_only(&[0u8; 27], &[3u8; 32], &[4u8; 32]));
+		assert!(!detect_only(&[0u8; 300], &[3u8; 32], &[4u8; 32]));
+		let pointer = crate::domain::services::offchain::memo_pointer(&[1u8; 64]);
+		assert!(!detect_only(&pointer, &[3u8; 32], &[4u8; 32]));
 	}
 }
@@ -0,0 +1,576 @@
+//! Escrow Service
+//!
+//! KEM-style auditor escrow: at encryption time the per-note memo key is
+//! wrapped to an auditor's X25519 agreement key and stored next to the memo, so
+//! a court-ordered disclosure can be served by the auditor alone.
+//!
+//! ```text
+//! (ephemeral_public, wrapping_key) = KEM(ephemeral_secret, auditor_agreement_key)
+//! wrapped_key = ChaCha20Poly1305(memo_key, wrapping_key, nonce = 0, aad = commitment)
+//! ```
+//!
+//! The wrapping key is fresh per ephemeral secret, so the fixed nonce is never
+//! reused under one key; the commitment as associated data binds the escrow to
+//! its note.
+//!
+//! In escrow mode the escrowed key travels inside the memo instead, so that a
+//! chain can require it for regulated assets by checking the envelope header:
+//!
+//! ```text
+//! version(1) || escrowed_key(80) || nonce(12) || ciphertext(76+16)      version and slot are AEAD associated data
+//! ```
+
+use alloc::vec::Vec;
+use chacha20poly1305::{
+	aead::{Aead, KeyInit, Payload},
+	ChaCha20Poly1305, Nonce,
+};
+use zeroize::Zeroize;
+
+use crate::domain::{
+	entities::{error::MemoError, memo_data::MemoData, versioned_memo::VersionedMemo},
+	services::{
+		encryption::{decrypt_memo_with_key, encrypt_memo, seal},
+		key_derivation::{
+			derive_encryption_key, derive_escrow_wrapping_key, derive_sender_shared_key,
+			recover_escrow_wrapping_key,
+		},
+		stream::decrypt_stream,
+	},
+	value_objects::{
+		constants::{
+			ENCRYPTED_MEMO_ESCROW_SIZE, ESCROWED_KEY_SIZE, MEMO_VERSION_ESCROW, MEMO_VERSION_SIZE,
+		},
+		EscrowedKey,
+	},
+};
+
+/// Fixed nonce for key wrapping (the wrapping key is single-use)
+const WRAP_NONCE: [u8; 12] = [0u8; 12];
+
+/// Wraps a per-note memo key to an auditor's agreement key
+///
+/// WARNING: `ephemeral_secret` MUST be fresh per note.
+pub fn escrow_memo_key(
+	memo_key: &[u8; 32],
+	commitment: &[u8; 32],
+	auditor_agreement_key: &[u8; 32],
+	ephemeral_secret: &[u8; 32],
+) -> Result<EscrowedKey, MemoError> {
+	let (ephemeral_public, mut wrapping_key) =
+		derive_escrow_wrapping_key(ephemeral_secret, auditor_agreement_key)?;
+
+	let cipher = ChaCha20Poly1305::new((&wrapping_key).into());
+	wrapping_key.zeroize();
+	let wrapped = cipher
+		.encrypt(
+			Nonce::from_slice(&WRAP_NONCE),
+			Payload {
+				msg: memo_key,
+				aad: commitment,
+			},
+		)
+		.map_err(|_| MemoError::EncryptionFailed)?;
+
+	let mut wrapped_key = [0u8; 48];
+	wrapped_key.copy_from_slice(&wrapped);
+	Ok(EscrowedKey {
+		ephemeral_public,
+		wrapped_key,
+	})
+}
+
+/// Recovers the per-note memo key with the auditor's viewing key
+pub fn open_escrowed_key(
+	escrowed: &EscrowedKey,
+	commitment: &[u8; 32],
+	auditor_viewing_key: &[u8; 32],
+) -> Result<[u8; 32], MemoError> {
+	let mut wrapping_key =
+		recover_escrow_wrapping_key(auditor_viewing_key, &escrowed.ephemeral_public)?;
+
+	let cipher = ChaCha20Poly1305::new((&wrapping_key).into());
+	wrapping_key.zeroize();
+	let mut plaintext = cipher
+		.decrypt(
+			Nonce::from_slice(&WRAP_NONCE),
+			Payload {
+				msg: &escrowed.wrapped_key,
+				aad: commitment,
+			},
+		)
+		.map_err(|_| MemoError::DecryptionFailed)?;
+
+	let mut memo_key = [0u8; 32];
+	memo_key.copy_from_slice(&plaintext);
+	plaintext.zeroize();
+	Ok(memo_key)
+}
+
+/// Encrypts memo data for a recipient and escrows its key to an auditor
+///
+/// Returns `(nonce(12) || ciphertext(76+16), escrowed_key)`; the memo has the
+/// standard layout and the escrowed key is stored alongside it.
+/// WARNING: nonce and `escrow_secret` MUST be fresh per note.
+pub fn encrypt_memo_with_escrow(
+	memo: &MemoData,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+	nonce: &[u8; 12],
+	auditor_agreement_key: &[u8; 32],
+	escrow_secret: &[u8; 32],
+) -> Result<(Vec<u8>, EscrowedKey), MemoError> {
+	let encrypted = encrypt_memo(memo, commitment, recipient_viewing_key, nonce)?;
+
+	let mut memo_key = derive_encryption_key(recipient_viewing_key, commitment);
+	let escrowed = escrow_memo_key(&memo_key, commitment, auditor_agreement_key, escrow_secret);
+	memo_key.zeroize();
+
+	Ok((encrypted, escrowed?))
+}
+
+/// Encrypts memo data to a recipient's agreement key and escrows its key
+///
+/// Returns `(encrypted, ephemeral_public, escrowed_key)`, extending
+/// `encrypt_memo_to_address`. The two ephemeral secrets MUST differ and be
+/// fresh per note.
+pub fn encrypt_memo_to_address_with_escrow(
+	memo: &MemoData,
+	commitment: &[u8; 32],
+	recipient_agreement_key: &[u8; 32],
+	ephemeral_secret: &[u8; 32],
+	nonce: &[u8; 12],
+	auditor_agreement_key: &[u8; 32],
+	escrow_secret: &[u8; 32],
+) -> Result<(Vec<u8>, [u8; 32], EscrowedKey), MemoError> {
+	let (ephemeral_public, mut shared_key) =
+		derive_sender_shared_key(ephemeral_secret, recipient_agreement_key)?;
+	let result = encrypt_memo_with_escrow(
+		memo,
+		commitment,
+		&shared_key,
+		nonce,
+		auditor_agreement_key,
+		escrow_secret,
+	);
+	shared_key.zeroize();
+
+	let (encrypted, escrowed) = result?;
+	Ok((encrypted, ephemeral_public, escrowed))
+}
+
+/// Encrypts memo with escrow using random nonce and escrow secret
+///
+/// Recommended method when policy mandates escrow. Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_memo_with_escrow_random(
+	memo: &MemoData,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+	auditor_agreement_key: &[u8; 32],
+) -> Result<(Vec<u8>, EscrowedKey), MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut escrow_secret = [0u8; 32];
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut escrow_secret);
+	OsRng.fill_bytes(&mut nonce);
+
+	let result = encrypt_memo_with_escrow(
+		memo,
+		commitment,
+		recipient_viewing_key,
+		&nonce,
+		auditor_agreement_key,
+		&escrow_secret,
+	);
+	escrow_secret.zeroize();
+	result
+}
+
+/// Decrypts an escrowed memo with the auditor's viewing key
+///
+/// Needs no cooperation from the note's owner.
+pub fn decrypt_memo_with_escrow(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	escrowed: &EscrowedKey,
+	auditor_viewing_key: &[u8; 32],
+) -> Result<VersionedMemo, MemoError> {
+	let mut memo_key = open_escrowed_key(escrowed, commitment, auditor_viewing_key)?;
+	let result = decrypt_memo_with_key(encrypted, &memo_key);
+	memo_key.zeroize();
+	result
+}
+
+/// Decrypts a streamed attachment of an escrowed note with the auditor's viewing key
+///
+/// Attachments are sealed under the note's memo key (see
+/// [`encrypt_offchain_stream`](super::offchain::encrypt_offchain_stream)), so the
+/// escrowed key that opens the memo opens them as well.
+pub fn decrypt_attachment_with_escrow(
+	ciphertext: &[u8],
+	commitment: &[u8; 32],
+	escrowed: &EscrowedKey,
+	auditor_viewing_key: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	let mut memo_key = open_escrowed_key(escrowed, commitment, auditor_viewing_key)?;
+	let result = decrypt_stream(&memo_key, ciphertext);
+	memo_key.zeroize();
+	result
+}
+
+/// Encrypts memo data in escrow mode
+///
+/// Returns: version(1) || escrowed_key(80) || nonce(12) || ciphertext(76+16)
+/// The recipient decrypts with [`decrypt_memo`](super::encryption::decrypt_memo),
+/// the auditor with [`decrypt_escrowed_memo`].
+/// WARNING: nonce and `escrow_secret` MUST be fresh per note.
+pub fn encrypt_memo_escrowed(
+	memo: &MemoData,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+	nonce: &[u8; 12],
+	auditor_agreement_key: &[u8; 32],
+	escrow_secret: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	let mut memo_key = derive_encryption_key(recipient_viewing_key, commitment);
+	let result = escrow_memo_key(&memo_key, commitment, auditor_agreement_key, escrow_secret)
+		.and_then(|escrowed| {
+			let mut result = Vec::with_capacity(ENCRYPTED_MEMO_ESCROW_SIZE);
+			result.push(MEMO_VERSION_ESCROW);
+			result.extend_from_slice(&escrowed.to_bytes());
+			let aad = result.clone();
+			seal(&memo_key, nonce, &memo.to_bytes(), &aad, &mut result)?;
+			Ok(result)
+		});
+	memo_key.zeroize();
+	result
+}
+
+/// Encrypts memo data in escrow mode with random nonce and escrow secret
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_memo_escrowed_random(
+	memo: &MemoData,
+	commitment: &[u8; 32],
+	recipient_viewing_key: &[u8; 32],
+	auditor_agreement_key: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut escrow_secret = [0u8; 32];
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut escrow_secret);
+	OsRng.fill_bytes(&mut nonce);
+
+	let result = encrypt_memo_escrowed(
+		memo,
+		commitment,
+		recipient_viewing_key,
+		&nonce,
+		auditor_agreement_key,
+		&escrow_secret,
+	);
+	escrow_secret.zeroize();
+	result
+}
+
+/// Escrow slot of an escrow-mode memo, `None` for other formats
+pub fn escrow_slot(encrypted: &[u8]) -> Option<EscrowedKey> {
+	if encrypted.len() != ENCRYPTED_MEMO_ESCROW_SIZE || encrypted[0] != MEMO_VERSION_ESCROW {
+		return None;
+	}
+	EscrowedKey::from_bytes(&encrypted[MEMO_VERSION_SIZE..MEMO_VERSION_SIZE + ESCROWED_KEY_SIZE])
+		.ok()
+}
+
+/// Decrypts an escrow-mode memo with the auditor's viewing key
+pub fn decrypt_escrowed_memo(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	auditor_viewing_key: &[u8; 32],
+) -> Result<VersionedMemo, MemoError> {
+	let escrowed = escrow_slot(encrypted).ok_or(MemoError::MissingEscrowSlot)?;
+	decrypt_memo_with_escrow(encrypted, commitment, &escrowed, auditor_viewing_key)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::services::{
+		encryption::{decrypt_memo, decrypt_memo_from_sender},
+		key_derivation::derive_agreement_public_key,
+	};
+
+	const RECIPIENT_VK: [u8; 32] = [4u8; 32];
+	const AUDITOR_VK: [u8; 32] = [9u8; 32];
+	const COMMITMENT: [u8; 32] = [3u8; 32];
+
+	fn memo() -> MemoData {
+		MemoData::new(1000, [1u8; 32], [2u8; 32], 7)
+	}
+
+	fn auditor_key() -> [u8; 32] {
+		derive_agreement_public_key(&AUDITOR_VK)
+	}
+
+	fn escrowed_memo() -> (Vec<u8>, EscrowedKey) {
+		encrypt_memo_with_escrow(
+			&memo(),
+			&COMMITMENT,
+			&RECIPIENT_VK,
+			&[5u8; 12],
+			&auditor_key(),
+			&[6u8; 32],
+		)
+		.unwrap()
+	}
+
+	// ===== Key wrapping =====
+
+	#[test]
+	fn test_escrow_memo_key_roundtrip() {
+		let memo_key = [42u8; 32];
+		let escrowed = escrow_memo_key(&memo_key, &COMMITMENT, &auditor_key(), &[6u8; 32]).unwrap();
+
+		assert_ne!(&escrowed.wrapped_key[..32], &memo_key);
+		assert_eq!(
+			open_escrowed_key(&escrowed, &COMMITMENT, &AUDITOR_VK),
+			Ok(memo_key)
+		);
+	}
+
+	#[test]
+	fn test_open_requires_auditor_key() {
+		let (_, escrowed) = escrowed_memo();
+		assert_eq!(
+			open_escrowed_key(&escrowed, &COMMITMENT, &RECIPIENT_VK),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_escrow_bound_to_commitment() {
+		let (_, escrowed) = escrowed_memo();
+		assert_eq!(
+			open_escrowed_key(&escrowed, &[8u8; 32], &AUDITOR_VK),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_tampered_escrow_rejected() {
+		let (_, mut escrowed) = escrowed_memo();
+		escrowed.wrapped_key[0] ^= 1;
+		assert_eq!(
+			open_escrowed_key(&escrowed, &COMMITMENT, &AUDITOR_VK),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_low_order_auditor_key_rejected() {
+		assert_eq!(
+			escrow_memo_key(&[42u8; 32], &COMMITMENT, &[0u8; 32], &[6u8; 32]),
+			Err(MemoError::InvalidPublicKey)
+		);
+	}
+
+	// ===== Escrowed encryption =====
+
+	#[test]
+	fn test_recipient_and_auditor_both_decrypt() {
+		let (encrypted, escrowed) = escrowed_memo();
+
+		assert_eq!(
+			decrypt_memo(&encrypted, &COMMITMENT, &RECIPIENT_VK),
+			Ok(memo().into())
+		);
+		assert_eq!(
+			decrypt_memo_with_escrow(&encrypted, &COMMITMENT, &escrowed, &AUDITOR_VK),
+			Ok(memo().into())
+		);
+	}
+
+	#[test]
+	fn test_memo_layout_unchanged() {
+		let (encrypted, _) = escrowed_memo();
+		let plain = encrypt_memo(&memo(), &COMMITMENT, &RECIPIENT_VK, &[5u8; 12]).unwrap();
+		assert_eq!(encrypted, plain);
+	}
+
+	#[test]
+	fn test_address_payment_with_escrow() {
+		let recipient_key = derive_agreement_public_key(&RECIPIENT_VK);
+		let (encrypted, ephemeral_public, escrowed) = encrypt_memo_to_address_with_escrow(
+			&memo(),
+			&COMMITMENT,
+			&recipient_key,
+			&[11u8; 32],
+			&[5u8; 12],
+			&auditor_key(),
+			&[12u8; 32],
+		)
+		.unwrap();
+
+		assert_eq!(
+			decrypt_memo_from_sender(&encrypted, &COMMITMENT, &RECIPIENT_VK, &ephemeral_public),
+			Ok(memo().into())
+		);
+		assert_eq!(
+			decrypt_memo_with_escrow(&encrypted, &COMMITMENT, &escrowed, &AUDITOR_VK),
+			Ok(memo().into())
+		);
+	}
+
+	#[test]
+	fn test_escrowed_key_serialization_roundtrip() {
+		let (encrypted, escrowed) = escrowed_memo();
+		let restored = EscrowedKey::from_bytes(&escrowed.to_bytes()).unwrap();
+		assert_eq!(
+			decrypt_memo_with_escrow(&encrypted, &COMMITMENT, &restored, &AUDITOR_VK),
+			Ok(memo().into())
+		);
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_random_escrow_is_fresh() {
+		let (encrypted1, escrowed1) =
+			encrypt_memo_with_escrow_random(&memo(), &COMMITMENT, &RECIPIENT_VK, &auditor_key())
+				.unwrap();
+		let (_, escrowed2) =
+			encrypt_memo_with_escrow_random(&memo(), &COMMITMENT, &RECIPIENT_VK, &auditor_key())
+				.unwrap();
+
+		assert_ne!(escrowed1.ephemeral_public, escrowed2.ephemeral_public);
+		assert_eq!(
+			decrypt_memo_with_escrow(&encrypted1, &COMMITMENT, &escrowed1, &AUDITOR_VK),
+			Ok(memo().into())
+		);
+	}
+
+	#[test]
+	fn test_auditor_opens_escrowed_attachment() {
+		use crate::domain::services::offchain::encrypt_offchain_stream;
+
+		let (_, escrowed) = escrowed_memo();
+		let attachment = [0x33u8; 5000];
+		let (ciphertext, _) =
+			encrypt_offchain_stream(&attachment, &COMMITMENT, &RECIPIENT_VK, &[1u8; 7]).unwrap();
+
+		assert_eq!(
+			decrypt_attachment_with_escrow(&ciphertext, &COMMITMENT, &escrowed, &AUDITOR_VK),
+			Ok(attachment.to_vec())
+		);
+		assert_eq!(
+			decrypt_attachment_with_escrow(&ciphertext, &COMMITMENT, &escrowed, &RECIPIENT_VK),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	// ===== Escrow mode =====
+
+	fn escrow_mode_memo() -> Vec<u8> {
+		encrypt_memo_escrowed(
+			&memo(),
+			&COMMITMENT,
+			&RECIPIENT_VK,
+			&[5u8; 12],
+			&auditor_key(),
+			&[6u8; 32],
+		)
+		.unwrap()
+	}
+
+	#[test]
+	fn test_escrow_mode_layout() {
+		let encrypted = escrow_mode_memo();
+		let (_, escrowed) = escrowed_memo();
+
+		assert_eq!(encrypted.len(), ENCRYPTED_MEMO_ESCROW_SIZE);
+		assert_eq!(encrypted[0], MEMO_VERSION_ESCROW);
+		assert_eq!(escrow_slot(&encrypted), Some(escrowed));
+		assert_eq!(&encrypted[81..93], &[5u8; 12]);
+	}
+
+	#[test]
+	fn test_escrow_mode_recipient_and_auditor_decrypt() {
+		let encrypted = escrow_mode_memo();
+
+		assert_eq!(
+			decrypt_memo(&encrypted, &COMMITMENT, &RECIPIENT_VK),
+			Ok(memo().into())
+		);
+		assert_eq!(
+			decrypt_escrowed_memo(&encrypted, &COMMITMENT, &AUDITOR_VK),
+			Ok(memo().into())
+		);
+		assert_eq!(
+			decrypt_escrowed_memo(&encrypted, &COMMITMENT, &RECIPIENT_VK),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_escrow_slot_is_authenticated() {
+		// Swapping in another escrowed key must break the recipient's decryption
+		let mut encrypted = escrow_mode_memo();
+		let other = escrow_memo_key(&[1u8; 32], &COMMITMENT, &auditor_key(), &[7u8; 32]).unwrap();
+		encrypted[1..81].copy_from_slice(&other.to_bytes());
+
+		assert_eq!(
+			decrypt_memo(&encrypted, &COMMITMENT, &RECIPIENT_VK),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_escrow_slot_absent_from_other_formats() {
+		let (plain, _) = escrowed_memo();
+		assert_eq!(escrow_slot(&plain), None);
+		assert_eq!(
+			decrypt_escrowed_memo(&plain, &COMMITMENT, &AUDITOR_VK),
+			Err(MemoError::MissingEscrowSlot)
+		);
+
+		let mut wrong_version = escrow_mode_memo();
+		wrong_version[0] = MEMO_VERSION_ESCROW + 1;
+		assert_eq!(escrow_slot(&wrong_version), None);
+	}
+
+	#[test]
+	fn test_escrow_mode_rejects_low_order_auditor_key() {
+		assert_eq!(
+			encrypt_memo_escrowed(
+				&memo(),
+				&COMMITMENT,
+				&RECIPIENT_VK,
+				&[5u8; 12],
+				&[0u8; 32],
+				&[6u8; 32]
+			),
+			Err(MemoError::InvalidPublicKey)
+		);
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_escrow_mode_random_roundtrip() {
+		let encrypted =
+			encrypt_memo_escrowed_random(&memo(), &COMMITMENT, &RECIPIENT_VK, &auditor_key())
+				.unwrap();
+		assert_eq!(
+			decrypt_escrowed_memo(&encrypted, &COMMITMENT, &AUDITOR_VK),
+			Ok(memo().into())
+		);
+	}
+}
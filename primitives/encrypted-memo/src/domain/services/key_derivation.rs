@@ -3,11 +3,28 @@
 //! All functions return the strongly-typed value objects defined in
 //! `domain::value_objects`.
 
-use crate::domain::value_objects::{
-	constants::{EDDSA_KEY_DOMAIN, KEY_DOMAIN, NULLIFIER_KEY_DOMAIN, VIEWING_KEY_DOMAIN},
-	EdDSAKey, NullifierKey, ViewingKey,
+#[cfg(feature = "pq")]
+use crate::domain::value_objects::constants::{HYBRID_KEY_DOMAIN, PQ_KEY_DOMAIN};
+use crate::domain::{
+	entities::error::MemoError,
+	value_objects::{
+		constants::{
+			AGREEMENT_KEY_DOMAIN, ASSET_VIEWING_KEY_DOMAIN, DERIVATION_KEY_DOMAIN,
+			DETECTION_KEY_DOMAIN, EDDSA_KEY_DOMAIN, ESCROW_KEY_DOMAIN, KEY_DOMAIN,
+			LEGACY_OUTGOING_VIEWING_KEY_DOMAIN, MAX_DETECTION_PRECISION, NULLIFIER_KEY_DOMAIN,
+			OUTGOING_VIEWING_KEY_DOMAIN, RECIPIENT_WRAP_KEY_DOMAIN, SHARED_KEY_DOMAIN,
+			STREAM_KEY_DOMAIN, VIEWING_KEY_COMMITMENT_DOMAIN, VIEWING_KEY_DOMAIN,
+			VIEWING_KEY_ROTATION_DOMAIN,
+		},
+		ClueKey, DerivationKey, DetectionKey, EdDSAKey, NullifierKey, OutgoingViewingKey,
+		ViewingKey,
+	},
 };
+#[cfg(feature = "pq")]
+use alloc::vec::Vec;
 use sha2::{Digest, Sha256};
+use x25519_dalek::{PublicKey, StaticSecret};
+use zeroize::Zeroize;
 
 /// Derives the per-note encryption key from a viewing key and commitment.
 ///
@@ -20,36 +37,474 @@ pub fn derive_encryption_key(viewing_key: &[u8; 32], commitment: &[u8; 32]) -> [
 	hasher.finalize().into()
 }
 
-/// Derives the viewing key from a spending key.
+/// Derives the key wrapping a multi-recipient memo's content key for one
+/// recipient.
 ///
-/// `SHA256(spending_key || VIEWING_KEY_DOMAIN)`
-pub fn derive_viewing_key_from_spending(spending_key: &[u8; 32]) -> ViewingKey {
+/// `SHA256(viewing_key || commitment || RECIPIENT_WRAP_KEY_DOMAIN)`
+pub fn derive_recipient_wrapping_key(viewing_key: &[u8; 32], commitment: &[u8; 32]) -> [u8; 32] {
+	let mut hasher = Sha256::new();
+	hasher.update(viewing_key);
+	hasher.update(commitment);
+	hasher.update(RECIPIENT_WRAP_KEY_DOMAIN);
+	hasher.finalize().into()
+}
+
+/// Derives the key a stream is sealed under from a content key.
+///
+/// `SHA256(content_key || STREAM_KEY_DOMAIN)`
+///
+/// Keeps stream nonces (`prefix || counter || flag`) apart from memo nonces
+/// when both are used with the same per-note key.
+pub fn derive_stream_key(content_key: &[u8; 32]) -> [u8; 32] {
+	let mut hasher = Sha256::new();
+	hasher.update(content_key);
+	hasher.update(STREAM_KEY_DOMAIN);
+	hasher.finalize().into()
+}
+
+/// Derives the intermediate derivation key from a spending key.
+///
+/// `SHA256(spending_key || DERIVATION_KEY_DOMAIN)`
+///
+/// Every other wallet key derives from this value, so a hardware wallet can
+/// export it instead of the spending key.
+pub fn derive_derivation_key(spending_key: &[u8; 32]) -> DerivationKey {
 	let mut hasher = Sha256::new();
 	hasher.update(spending_key);
+	hasher.update(DERIVATION_KEY_DOMAIN);
+	DerivationKey(hasher.finalize().into())
+}
+
+/// Derives the viewing key from a derivation key.
+///
+/// `SHA256(derivation_key || VIEWING_KEY_DOMAIN)`
+pub fn derive_viewing_key(derivation_key: &[u8; 32]) -> ViewingKey {
+	let mut hasher = Sha256::new();
+	hasher.update(derivation_key);
 	hasher.update(VIEWING_KEY_DOMAIN);
 	ViewingKey(hasher.finalize().into())
 }
 
-/// Derives the nullifier key from a spending key.
+/// Derives the viewing key from a spending key.
 ///
-/// `SHA256(spending_key || NULLIFIER_KEY_DOMAIN)`
-pub fn derive_nullifier_key_from_spending(spending_key: &[u8; 32]) -> NullifierKey {
+/// `derive_viewing_key(derive_derivation_key(spending_key))`
+pub fn derive_viewing_key_from_spending(spending_key: &[u8; 32]) -> ViewingKey {
+	derive_viewing_key(derive_derivation_key(spending_key).as_bytes())
+}
+
+/// Derives the viewing key of a given rotation generation from a derivation key.
+///
+/// Generation 0 is the original viewing key; later generations are
+/// `SHA256(derivation_key || VIEWING_KEY_ROTATION_DOMAIN || generation_le)`.
+pub fn derive_viewing_key_at_generation(derivation_key: &[u8; 32], generation: u32) -> ViewingKey {
+	if generation == 0 {
+		return derive_viewing_key(derivation_key);
+	}
 	let mut hasher = Sha256::new();
-	hasher.update(spending_key);
+	hasher.update(derivation_key);
+	hasher.update(VIEWING_KEY_ROTATION_DOMAIN);
+	hasher.update(generation.to_le_bytes());
+	ViewingKey(hasher.finalize().into())
+}
+
+/// Derives the viewing key of a given rotation generation from a spending key.
+pub fn derive_viewing_key_generation(spending_key: &[u8; 32], generation: u32) -> ViewingKey {
+	derive_viewing_key_at_generation(derive_derivation_key(spending_key).as_bytes(), generation)
+}
+
+/// Derives the viewing key scoped to one asset.
+///
+/// `SHA256(viewing_key || ASSET_VIEWING_KEY_DOMAIN || asset_id_le)`
+///
+/// Memos of assets that require scoping are encrypted under this key, so an
+/// auditor holding it sees that asset's notes and nothing else; the base
+/// viewing key cannot be recovered from it.
+pub fn derive_asset_viewing_key(viewing_key: &[u8; 32], asset_id: u64) -> ViewingKey {
+	let mut hasher = Sha256::new();
+	hasher.update(viewing_key);
+	hasher.update(ASSET_VIEWING_KEY_DOMAIN);
+	hasher.update(asset_id.to_le_bytes());
+	ViewingKey(hasher.finalize().into())
+}
+
+/// Derives the outgoing viewing key from a derivation key.
+///
+/// `SHA256(derivation_key || OUTGOING_VIEWING_KEY_DOMAIN)`
+pub fn derive_outgoing_viewing_key(derivation_key: &[u8; 32]) -> OutgoingViewingKey {
+	let mut hasher = Sha256::new();
+	hasher.update(derivation_key);
+	hasher.update(OUTGOING_VIEWING_KEY_DOMAIN);
+	OutgoingViewingKey(hasher.finalize().into())
+}
+
+/// Derives the outgoing viewing key from a spending key.
+pub fn derive_outgoing_viewing_key_from_spending(spending_key: &[u8; 32]) -> OutgoingViewingKey {
+	derive_outgoing_viewing_key(derive_derivation_key(spending_key).as_bytes())
+}
+
+/// Derives an outgoing viewing key for a wallet that only holds a viewing key.
+///
+/// `SHA256(viewing_key || LEGACY_OUTGOING_VIEWING_KEY_DOMAIN)`
+///
+/// Migration path for single-key exports made before the incoming/outgoing
+/// split; wallets with a spending or derivation key use
+/// [`derive_outgoing_viewing_key`].
+pub fn derive_legacy_outgoing_viewing_key(viewing_key: &[u8; 32]) -> OutgoingViewingKey {
+	let mut hasher = Sha256::new();
+	hasher.update(viewing_key);
+	hasher.update(LEGACY_OUTGOING_VIEWING_KEY_DOMAIN);
+	OutgoingViewingKey(hasher.finalize().into())
+}
+
+/// Commits to a viewing key without revealing it.
+///
+/// `SHA256(viewing_key || VIEWING_KEY_COMMITMENT_DOMAIN)`
+pub fn viewing_key_commitment(viewing_key: &[u8; 32]) -> [u8; 32] {
+	let mut hasher = Sha256::new();
+	hasher.update(viewing_key);
+	hasher.update(VIEWING_KEY_COMMITMENT_DOMAIN);
+	hasher.finalize().into()
+}
+
+/// Derives the nullifier key from a derivation key.
+///
+/// `SHA256(derivation_key || NULLIFIER_KEY_DOMAIN)`
+pub fn derive_nullifier_key(derivation_key: &[u8; 32]) -> NullifierKey {
+	let mut hasher = Sha256::new();
+	hasher.update(derivation_key);
 	hasher.update(NULLIFIER_KEY_DOMAIN);
 	NullifierKey(hasher.finalize().into())
 }
 
-/// Derives the EdDSA circuit signing key from a spending key.
+/// Derives the nullifier key from a spending key.
+pub fn derive_nullifier_key_from_spending(spending_key: &[u8; 32]) -> NullifierKey {
+	derive_nullifier_key(derive_derivation_key(spending_key).as_bytes())
+}
+
+/// Derives the EdDSA circuit signing key from a derivation key.
 ///
-/// `SHA256(spending_key || EDDSA_KEY_DOMAIN)`
-pub fn derive_eddsa_key_from_spending(spending_key: &[u8; 32]) -> EdDSAKey {
+/// `SHA256(derivation_key || EDDSA_KEY_DOMAIN)`
+pub fn derive_eddsa_key(derivation_key: &[u8; 32]) -> EdDSAKey {
 	let mut hasher = Sha256::new();
-	hasher.update(spending_key);
+	hasher.update(derivation_key);
 	hasher.update(EDDSA_KEY_DOMAIN);
 	EdDSAKey(hasher.finalize().into())
 }
 
+/// Derives the EdDSA circuit signing key from a spending key.
+pub fn derive_eddsa_key_from_spending(spending_key: &[u8; 32]) -> EdDSAKey {
+	derive_eddsa_key(derive_derivation_key(spending_key).as_bytes())
+}
+
+// ============================================================================
+// Key agreement (X25519)
+// ============================================================================
+
+/// Derives the X25519 agreement secret from a viewing key.
+///
+/// `SHA256(viewing_key || AGREEMENT_KEY_DOMAIN)`, clamped by X25519.
+fn derive_agreement_secret(viewing_key: &[u8; 32]) -> StaticSecret {
+	let mut hasher = Sha256::new();
+	hasher.update(viewing_key);
+	hasher.update(AGREEMENT_KEY_DOMAIN);
+	let mut bytes: [u8; 32] = hasher.finalize().into();
+	let secret = StaticSecret::from(bytes);
+	bytes.zeroize();
+	secret
+}
+
+/// Derives the public agreement key published in the recipient's address.
+///
+/// `X25519(agreement_secret, basepoint)`
+pub fn derive_agreement_public_key(viewing_key: &[u8; 32]) -> [u8; 32] {
+	PublicKey::from(&derive_agreement_secret(viewing_key)).to_bytes()
+}
+
+/// Derives the key shared between an ephemeral sender key and an agreement key.
+///
+/// `SHA256(shared_secret || ephemeral_public || recipient_agreement_key || domain)`
+pub(crate) fn derive_shared_key(
+	secret: &StaticSecret,
+	their_public: &[u8; 32],
+	ephemeral_public: &[u8; 32],
+	recipient_agreement_key: &[u8; 32],
+	domain: &[u8],
+) -> Result<[u8; 32], MemoError> {
+	let shared = secret.diffie_hellman(&PublicKey::from(*their_public));
+	if !shared.was_contributory() {
+		return Err(MemoError::InvalidPublicKey);
+	}
+
+	let mut hasher = Sha256::new();
+	hasher.update(shared.as_bytes());
+	hasher.update(ephemeral_public);
+	hasher.update(recipient_agreement_key);
+	hasher.update(domain);
+	Ok(hasher.finalize().into())
+}
+
+/// Sender side of the key agreement.
+///
+/// Returns `(ephemeral_public, shared_key)`. The shared key takes the place of
+/// the viewing key in [`derive_encryption_key`]; the ephemeral public key is
+/// published alongside the memo. `ephemeral_secret` MUST be fresh per note.
+pub fn derive_sender_shared_key(
+	ephemeral_secret: &[u8; 32],
+	recipient_agreement_key: &[u8; 32],
+) -> Result<([u8; 32], [u8; 32]), MemoError> {
+	let secret = StaticSecret::from(*ephemeral_secret);
+	let ephemeral_public = PublicKey::from(&secret).to_bytes();
+	let shared_key = derive_shared_key(
+		&secret,
+		recipient_agreement_key,
+		&ephemeral_public,
+		recipient_agreement_key,
+		SHARED_KEY_DOMAIN,
+	)?;
+	Ok((ephemeral_public, shared_key))
+}
+
+/// Recipient side of the key agreement.
+///
+/// Recomputes the shared key from the viewing key and the sender's ephemeral
+/// public key.
+pub fn derive_recipient_shared_key(
+	viewing_key: &[u8; 32],
+	ephemeral_public: &[u8; 32],
+) -> Result<[u8; 32], MemoError> {
+	let secret = derive_agreement_secret(viewing_key);
+	let agreement_key = PublicKey::from(&secret).to_bytes();
+	derive_shared_key(
+		&secret,
+		ephemeral_public,
+		ephemeral_public,
+		&agreement_key,
+		SHARED_KEY_DOMAIN,
+	)
+}
+
+// ============================================================================
+// Key escrow (X25519 KEM)
+// ============================================================================
+
+/// Encapsulation side of auditor escrow.
+///
+/// Returns `(ephemeral_public, wrapping_key)` for the auditor's agreement key,
+/// using `ESCROW_KEY_DOMAIN` in place of `SHARED_KEY_DOMAIN`.
+/// `ephemeral_secret` MUST be fresh per note.
+pub fn derive_escrow_wrapping_key(
+	ephemeral_secret: &[u8; 32],
+	auditor_agreement_key: &[u8; 32],
+) -> Result<([u8; 32], [u8; 32]), MemoError> {
+	let secret = StaticSecret::from(*ephemeral_secret);
+	let ephemeral_public = PublicKey::from(&secret).to_bytes();
+	let wrapping_key = derive_shared_key(
+		&secret,
+		auditor_agreement_key,
+		&ephemeral_public,
+		auditor_agreement_key,
+		ESCROW_KEY_DOMAIN,
+	)?;
+	Ok((ephemeral_public, wrapping_key))
+}
+
+/// Decapsulation side of auditor escrow.
+///
+/// Recomputes the wrapping key from the auditor's viewing key.
+pub fn recover_escrow_wrapping_key(
+	auditor_viewing_key: &[u8; 32],
+	ephemeral_public: &[u8; 32],
+) -> Result<[u8; 32], MemoError> {
+	let secret = derive_agreement_secret(auditor_viewing_key);
+	let agreement_key = PublicKey::from(&secret).to_bytes();
+	derive_shared_key(
+		&secret,
+		ephemeral_public,
+		ephemeral_public,
+		&agreement_key,
+		ESCROW_KEY_DOMAIN,
+	)
+}
+
+// ============================================================================
+// Fuzzy message detection
+// ============================================================================
+
+/// Derives the detection secret at `index`.
+///
+/// `SHA256(viewing_key || DETECTION_KEY_DOMAIN || index)`, clamped by X25519.
+fn derive_detection_secret(viewing_key: &[u8; 32], index: usize) -> [u8; 32] {
+	let mut hasher = Sha256::new();
+	hasher.update(viewing_key);
+	hasher.update(DETECTION_KEY_DOMAIN);
+	hasher.update([index as u8]);
+	hasher.finalize().into()
+}
+
+/// Derives the clue key published for senders to tag memos with.
+pub fn derive_clue_key(viewing_key: &[u8; 32]) -> ClueKey {
+	let mut keys = [[0u8; 32]; MAX_DETECTION_PRECISION];
+	for (index, key) in keys.iter_mut().enumerate() {
+		let mut secret = derive_detection_secret(viewing_key, index);
+		*key = PublicKey::from(&StaticSecret::from(secret)).to_bytes();
+		secret.zeroize();
+	}
+	ClueKey(keys)
+}
+
+/// Derives a detection key over the first `precision` clue key entries.
+///
+/// The false-positive rate of the resulting key is `2^-precision`.
+pub fn derive_detection_key(
+	viewing_key: &[u8; 32],
+	precision: usize,
+) -> Result<DetectionKey, MemoError> {
+	if precision == 0 || precision > MAX_DETECTION_PRECISION {
+		return Err(MemoError::InvalidDetectionPrecision(precision));
+	}
+
+	let mut secrets = [[0u8; 32]; MAX_DETECTION_PRECISION];
+	for (index, secret) in secrets.iter_mut().take(precision).enumerate() {
+		*secret = derive_detection_secret(viewing_key, index);
+	}
+	let key = DetectionKey::new(&secrets[..precision]);
+	secrets.zeroize();
+	key
+}
+
+// ============================================================================
+// Hybrid post-quantum KEM (X25519 + ML-KEM-768)
+// ============================================================================
+
+/// Derives the recipient's ML-KEM-768 key pair from a viewing key.
+///
+/// Seeds `d` and `z` are `SHA256(viewing_key || PQ_KEY_DOMAIN || i)` for `i = 0, 1`.
+#[cfg(feature = "pq")]
+fn derive_pq_key_pair(
+	viewing_key: &[u8; 32],
+) -> (
+	ml_kem::kem::DecapsulationKey<ml_kem::MlKem768Params>,
+	ml_kem::kem::EncapsulationKey<ml_kem::MlKem768Params>,
+) {
+	use ml_kem::{KemCore, MlKem768, B32};
+
+	let seed = |index: u8| -> [u8; 32] {
+		let mut hasher = Sha256::new();
+		hasher.update(viewing_key);
+		hasher.update(PQ_KEY_DOMAIN);
+		hasher.update([index]);
+		hasher.finalize().into()
+	};
+	let (mut d, mut z) = (seed(0), seed(1));
+	let key_pair = MlKem768::generate_deterministic(&B32::from(d), &B32::from(z));
+	d.zeroize();
+	z.zeroize();
+	key_pair
+}
+
+/// Derives the ML-KEM-768 public key published next to the agreement key.
+///
+/// Returns `ML_KEM_PUBLIC_KEY_SIZE` bytes.
+#[cfg(feature = "pq")]
+pub fn derive_pq_public_key(viewing_key: &[u8; 32]) -> Vec<u8> {
+	use ml_kem::EncodedSizeUser;
+
+	derive_pq_key_pair(viewing_key).1.as_bytes().to_vec()
+}
+
+/// Combines the classical and post-quantum shared secrets.
+///
+/// `SHA256(x25519_key || ml_kem_shared || kem_ciphertext || HYBRID_KEY_DOMAIN)`; the
+/// result is secure as long as either component is.
+#[cfg(feature = "pq")]
+fn combine_hybrid_key(
+	classical: &[u8; 32],
+	post_quantum: &[u8],
+	kem_ciphertext: &[u8],
+) -> [u8; 32] {
+	let mut hasher = Sha256::new();
+	hasher.update(classical);
+	hasher.update(post_quantum);
+	hasher.update(kem_ciphertext);
+	hasher.update(HYBRID_KEY_DOMAIN);
+	hasher.finalize().into()
+}
+
+/// Output of [`derive_hybrid_sender_key`].
+///
+/// The caller zeroizes `shared_key` once the encryption key is derived.
+#[cfg(feature = "pq")]
+pub struct HybridSenderKey {
+	/// X25519 ephemeral public key sent with the memo
+	pub ephemeral_public: [u8; 32],
+	/// ML-KEM-768 ciphertext sent with the memo
+	pub kem_ciphertext: Vec<u8>,
+	/// Combined shared secret
+	pub shared_key: [u8; 32],
+}
+
+/// Sender side of the hybrid KEM.
+///
+/// The shared key takes the place of the viewing key in
+/// [`derive_encryption_key`]. `ephemeral_secret` and `kem_seed` MUST be
+/// fresh per note.
+#[cfg(feature = "pq")]
+pub fn derive_hybrid_sender_key(
+	ephemeral_secret: &[u8; 32],
+	recipient_agreement_key: &[u8; 32],
+	recipient_pq_public_key: &[u8],
+	kem_seed: &[u8; 32],
+) -> Result<HybridSenderKey, MemoError> {
+	use ml_kem::{
+		kem::EncapsulationKey, EncapsulateDeterministic, Encoded, EncodedSizeUser, MlKem768Params,
+		B32,
+	};
+
+	let encoded = Encoded::<EncapsulationKey<MlKem768Params>>::try_from(recipient_pq_public_key)
+		.map_err(|_| MemoError::InvalidPublicKey)?;
+	let (kem_ciphertext, mut post_quantum) =
+		EncapsulationKey::<MlKem768Params>::from_bytes(&encoded)
+			.encapsulate_deterministic(&B32::from(*kem_seed))
+			.map_err(|_| MemoError::EncryptionFailed)?;
+
+	let (ephemeral_public, mut classical) =
+		derive_sender_shared_key(ephemeral_secret, recipient_agreement_key)?;
+	let shared_key = combine_hybrid_key(&classical, &post_quantum, &kem_ciphertext);
+	classical.zeroize();
+	post_quantum.as_mut_slice().zeroize();
+
+	Ok(HybridSenderKey {
+		ephemeral_public,
+		kem_ciphertext: kem_ciphertext.to_vec(),
+		shared_key,
+	})
+}
+
+/// Recipient side of the hybrid KEM.
+#[cfg(feature = "pq")]
+pub fn derive_hybrid_recipient_key(
+	viewing_key: &[u8; 32],
+	ephemeral_public: &[u8; 32],
+	kem_ciphertext: &[u8],
+) -> Result<[u8; 32], MemoError> {
+	use ml_kem::{kem::Decapsulate, Ciphertext, MlKem768};
+
+	let ciphertext =
+		Ciphertext::<MlKem768>::try_from(kem_ciphertext).map_err(|_| MemoError::DataTooShort)?;
+	// Decapsulation rejects implicitly: a forged ciphertext yields an
+	// unrelated key and the AEAD fails
+	let mut post_quantum = derive_pq_key_pair(viewing_key)
+		.0
+		.decapsulate(&ciphertext)
+		.map_err(|_| MemoError::DecryptionFailed)?;
+
+	let mut classical = derive_recipient_shared_key(viewing_key, ephemeral_public)?;
+	let shared_key = combine_hybrid_key(&classical, &post_quantum, kem_ciphertext);
+	classical.zeroize();
+	post_quantum.as_mut_slice().zeroize();
+	Ok(shared_key)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -91,6 +546,35 @@ mod tests {
 		);
 	}
 
+	// ===== derive_recipient_wrapping_key =====
+
+	#[test]
+	fn test_recipient_wrapping_key_separated_from_encryption_key() {
+		let (vk, comm) = ([1u8; 32], [2u8; 32]);
+		assert_ne!(
+			derive_recipient_wrapping_key(&vk, &comm),
+			derive_encryption_key(&vk, &comm)
+		);
+	}
+
+	#[test]
+	fn test_recipient_wrapping_key_binds_commitment() {
+		let vk = [1u8; 32];
+		assert_ne!(
+			derive_recipient_wrapping_key(&vk, &[2u8; 32]),
+			derive_recipient_wrapping_key(&vk, &[3u8; 32])
+		);
+	}
+
+	// ===== derive_stream_key =====
+
+	#[test]
+	fn test_stream_key_separated_from_content_key() {
+		let key = [4u8; 32];
+		assert_ne!(derive_stream_key(&key), key);
+		assert_ne!(derive_stream_key(&key), derive_stream_key(&[5u8; 32]));
+	}
+
 	// ===== derive_viewing_key_from_spending =====
 
 	#[test]
@@ -115,6 +599,70 @@ mod tests {
 		);
 	}
 
+	// ===== derive_viewing_key_generation =====
+
+	#[test]
+	fn test_viewing_key_generation_zero_is_original() {
+		assert_eq!(
+			derive_viewing_key_generation(&[7u8; 32], 0),
+			derive_viewing_key_from_spending(&[7u8; 32]),
+		);
+	}
+
+	#[test]
+	fn test_viewing_key_generations_are_distinct() {
+		let g1 = derive_viewing_key_generation(&[7u8; 32], 1);
+		let g2 = derive_viewing_key_generation(&[7u8; 32], 2);
+		assert_ne!(g1, g2);
+		assert_ne!(g1, derive_viewing_key_from_spending(&[7u8; 32]));
+	}
+
+	#[test]
+	fn test_viewing_key_commitment_hides_key() {
+		let vk = [9u8; 32];
+		assert_ne!(viewing_key_commitment(&vk), vk);
+		assert_ne!(
+			viewing_key_commitment(&vk),
+			viewing_key_commitment(&[8u8; 32])
+		);
+	}
+
+	// ===== derive_asset_viewing_key =====
+
+	#[test]
+	fn test_asset_viewing_keys_are_scoped() {
+		let vk = [7u8; 32];
+		let usd = derive_asset_viewing_key(&vk, 1);
+		assert_eq!(usd, derive_asset_viewing_key(&vk, 1));
+		assert_ne!(usd, derive_asset_viewing_key(&vk, 2));
+		assert_ne!(usd, derive_asset_viewing_key(&[8u8; 32], 1));
+		assert_ne!(usd.0, vk);
+	}
+
+	// ===== derive_outgoing_viewing_key =====
+
+	#[test]
+	fn test_outgoing_viewing_key_from_spending_matches_derivation_key() {
+		let spending_key = [13u8; 32];
+		let derivation_key = derive_derivation_key(&spending_key);
+		assert_eq!(
+			derive_outgoing_viewing_key_from_spending(&spending_key),
+			derive_outgoing_viewing_key(derivation_key.as_bytes())
+		);
+	}
+
+	#[test]
+	fn test_outgoing_viewing_key_independent_of_viewing_key() {
+		let spending_key = [13u8; 32];
+		let incoming = derive_viewing_key_from_spending(&spending_key);
+		let outgoing = derive_outgoing_viewing_key_from_spending(&spending_key);
+		assert_ne!(outgoing.as_bytes(), incoming.as_bytes());
+		assert_ne!(
+			derive_legacy_outgoing_viewing_key(incoming.as_bytes()),
+			outgoing
+		);
+	}
+
 	// ===== derive_nullifier_key_from_spending =====
 
 	#[test]
@@ -147,6 +695,31 @@ mod tests {
 		);
 	}
 
+	// ===== Derivation Key =====
+
+	#[test]
+	fn test_spending_derivation_goes_through_derivation_key() {
+		let sk = [42u8; 32];
+		let dk = derive_derivation_key(&sk);
+		assert_ne!(dk.0, sk);
+		assert_eq!(
+			derive_viewing_key(dk.as_bytes()),
+			derive_viewing_key_from_spending(&sk)
+		);
+		assert_eq!(
+			derive_nullifier_key(dk.as_bytes()),
+			derive_nullifier_key_from_spending(&sk)
+		);
+		assert_eq!(
+			derive_eddsa_key(dk.as_bytes()),
+			derive_eddsa_key_from_spending(&sk)
+		);
+		assert_eq!(
+			derive_viewing_key_at_generation(dk.as_bytes(), 3),
+			derive_viewing_key_generation(&sk, 3)
+		);
+	}
+
 	// ===== Domain Separation =====
 
 	#[test]
@@ -220,4 +793,204 @@ mod tests {
 		let key = derive_encryption_key(&[1u8; 32], &[2u8; 32]);
 		assert_eq!(key.len(), 32);
 	}
+
+	// ===== Key agreement =====
+
+	#[test]
+	fn test_x25519_rfc7748_vector() {
+		// RFC 7748 section 6.1 (Alice's key pair)
+		let alice_secret = StaticSecret::from([
+			0x77, 0x07, 0x6d, 0x0a, 0x73, 0x18, 0xa5, 0x7d, 0x3c, 0x16, 0xc1, 0x72, 0x51, 0xb2,
+			0x66, 0x45, 0xdf, 0x4c, 0x2f, 0x87, 0xeb, 0xc0, 0x99, 0x2a, 0xb1, 0x77, 0xfb, 0xa5,
+			0x1d, 0xb9, 0x2c, 0x2a,
+		]);
+		assert_eq!(
+			PublicKey::from(&alice_secret).to_bytes(),
+			[
+				0x85, 0x20, 0xf0, 0x09, 0x89, 0x30, 0xa7, 0x54, 0x74, 0x8b, 0x7d, 0xdc, 0xb4, 0x3e,
+				0xf7, 0x5a, 0x0d, 0xbf, 0x3a, 0x0d, 0x26, 0x38, 0x1a, 0xf4, 0xeb, 0xa4, 0xa9, 0x8e,
+				0xaa, 0x9b, 0x4e, 0x6a,
+			]
+		);
+	}
+
+	#[test]
+	fn test_sender_and_recipient_agree() {
+		let vk = derive_viewing_key_from_spending(&[7u8; 32]);
+		let agreement_key = derive_agreement_public_key(vk.as_bytes());
+
+		let (ephemeral_public, sender_key) =
+			derive_sender_shared_key(&[11u8; 32], &agreement_key).unwrap();
+		let recipient_key = derive_recipient_shared_key(vk.as_bytes(), &ephemeral_public).unwrap();
+
+		assert_eq!(sender_key, recipient_key);
+	}
+
+	#[test]
+	fn test_other_viewing_key_disagrees() {
+		let agreement_key = derive_agreement_public_key(&[1u8; 32]);
+		let (ephemeral_public, sender_key) =
+			derive_sender_shared_key(&[11u8; 32], &agreement_key).unwrap();
+
+		assert_ne!(
+			derive_recipient_shared_key(&[2u8; 32], &ephemeral_public).unwrap(),
+			sender_key
+		);
+	}
+
+	#[test]
+	fn test_fresh_ephemeral_secret_changes_key() {
+		let agreement_key = derive_agreement_public_key(&[1u8; 32]);
+		let (epk1, k1) = derive_sender_shared_key(&[11u8; 32], &agreement_key).unwrap();
+		let (epk2, k2) = derive_sender_shared_key(&[12u8; 32], &agreement_key).unwrap();
+		assert_ne!(epk1, epk2);
+		assert_ne!(k1, k2);
+	}
+
+	#[test]
+	fn test_low_order_public_key_rejected() {
+		assert_eq!(
+			derive_sender_shared_key(&[11u8; 32], &[0u8; 32]),
+			Err(MemoError::InvalidPublicKey)
+		);
+		assert_eq!(
+			derive_recipient_shared_key(&[1u8; 32], &[0u8; 32]),
+			Err(MemoError::InvalidPublicKey)
+		);
+	}
+
+	#[test]
+	fn test_agreement_key_differs_from_viewing_key() {
+		let vk = [5u8; 32];
+		assert_ne!(derive_agreement_public_key(&vk), vk);
+	}
+
+	// ===== Key escrow =====
+
+	#[test]
+	fn test_escrow_wrapping_key_roundtrip() {
+		let auditor_vk = [7u8; 32];
+		let auditor_key = derive_agreement_public_key(&auditor_vk);
+
+		let (epk, sender_key) = derive_escrow_wrapping_key(&[13u8; 32], &auditor_key).unwrap();
+		let auditor_side = recover_escrow_wrapping_key(&auditor_vk, &epk).unwrap();
+
+		assert_eq!(sender_key, auditor_side);
+		assert_ne!(
+			recover_escrow_wrapping_key(&[8u8; 32], &epk).unwrap(),
+			sender_key
+		);
+	}
+
+	#[test]
+	fn test_escrow_key_separated_from_shared_key() {
+		let agreement_key = derive_agreement_public_key(&[1u8; 32]);
+		let (_, shared) = derive_sender_shared_key(&[11u8; 32], &agreement_key).unwrap();
+		let (_, wrapping) = derive_escrow_wrapping_key(&[11u8; 32], &agreement_key).unwrap();
+		assert_ne!(shared, wrapping);
+	}
+
+	#[test]
+	fn test_escrow_low_order_key_rejected() {
+		assert_eq!(
+			derive_escrow_wrapping_key(&[11u8; 32], &[0u8; 32]),
+			Err(MemoError::InvalidPublicKey)
+		);
+	}
+
+	// ===== Fuzzy message detection =====
+
+	#[test]
+	fn test_detection_key_matches_clue_key() {
+		let vk = [3u8; 32];
+		let clue_key = derive_clue_key(&vk);
+		let detection_key = derive_detection_key(&vk, 5).unwrap();
+		assert_eq!(detection_key.precision(), 5);
+		for (secret, public) in detection_key.secrets().iter().zip(&clue_key.0) {
+			assert_eq!(
+				&PublicKey::from(&StaticSecret::from(*secret)).to_bytes(),
+				public
+			);
+		}
+	}
+
+	#[test]
+	fn test_clue_key_entries_distinct_and_separated() {
+		let vk = [4u8; 32];
+		let clue_key = derive_clue_key(&vk);
+		assert_ne!(clue_key.0[0], clue_key.0[1]);
+		assert_ne!(clue_key.0[0], derive_agreement_public_key(&vk));
+		assert_ne!(clue_key, derive_clue_key(&[5u8; 32]));
+	}
+
+	#[test]
+	fn test_detection_key_precision_prefix() {
+		let vk = [6u8; 32];
+		let low = derive_detection_key(&vk, 2).unwrap();
+		let high = derive_detection_key(&vk, MAX_DETECTION_PRECISION).unwrap();
+		assert_eq!(low.secrets(), &high.secrets()[..2]);
+	}
+
+	#[test]
+	fn test_detection_key_rejects_invalid_precision() {
+		assert_eq!(
+			derive_detection_key(&[0u8; 32], 0),
+			Err(MemoError::InvalidDetectionPrecision(0))
+		);
+		assert_eq!(
+			derive_detection_key(&[0u8; 32], MAX_DETECTION_PRECISION + 1),
+			Err(MemoError::InvalidDetectionPrecision(17))
+		);
+	}
+
+	// ===== Hybrid post-quantum KEM =====
+
+	#[cfg(feature = "pq")]
+	#[test]
+	fn test_hybrid_sender_and_recipient_agree() {
+		use crate::domain::value_objects::constants::{
+			ML_KEM_CIPHERTEXT_SIZE, ML_KEM_PUBLIC_KEY_SIZE,
+		};
+
+		let vk = [4u8; 32];
+		let pq_public_key = derive_pq_public_key(&vk);
+		assert_eq!(pq_public_key.len(), ML_KEM_PUBLIC_KEY_SIZE);
+
+		let HybridSenderKey {
+			ephemeral_public,
+			kem_ciphertext,
+			shared_key: sender_key,
+		} = derive_hybrid_sender_key(
+			&[6u8; 32],
+			&derive_agreement_public_key(&vk),
+			&pq_public_key,
+			&[7u8; 32],
+		)
+		.unwrap();
+		assert_eq!(kem_ciphertext.len(), ML_KEM_CIPHERTEXT_SIZE);
+		assert_eq!(
+			derive_hybrid_recipient_key(&vk, &ephemeral_public, &kem_ciphertext),
+			Ok(sender_key)
+		);
+		assert_ne!(
+			derive_hybrid_recipient_key(&[5u8; 32], &ephemeral_public, &kem_ciphertext),
+			Ok(sender_key)
+		);
+	}
+
+	#[cfg(feature = "pq")]
+	#[test]
+	fn test_hybrid_sender_rejects_bad_pq_public_key() {
+		let vk = [4u8; 32];
+		assert_eq!(
+			derive_hybrid_sender_key(
+				&[6u8; 32],
+				&derive_agreement_public_key(&vk),
+				&[0u8; 10],
+				&[7u8; 32]
+			)
+			.err(),
+			Some(MemoError::InvalidPublicKey)
+		);
+	}
 }
@@ -4,8 +4,34 @@
 //!
 //! ## Services
 //!
+//! - [`asset_scope`]   - Asset-scoped viewing keys for memo encryption
+//! - `backup`          - Password-protected KeySet export/import (`backup` feature)
+//! - [`codec`]         - Format-dispatching decoder for memos of any version
+//! - [`compression`]   - Zero-run compression of memo payloads
+//! - [`detection`]     - Fuzzy message detection tags
 //! - [`encryption`]    - ChaCha20Poly1305 AEAD encryption/decryption
-//! - [`key_derivation`] - SHA-256 key derivation with domain separation
+//! - [`escrow`]        - Auditor key escrow (X25519 KEM + key wrapping)
+//! - `hybrid`          - X25519 + ML-KEM-768 hybrid encryption (`pq` feature)
+//! - [`key_derivation`] - SHA-256 key derivation and X25519 key agreement
+//! - [`offchain`]      - Off-chain ciphertexts behind on-chain content hashes
+//! - [`outgoing`]      - Sender copies under the outgoing viewing key
+//! - [`rotation`]      - Viewing key rotation and memo re-encryption
+//! - [`stream`]        - Chunked STREAM encryption for large attachments
+//! - [`multi_recipient`] - One memo payload with per-recipient wrapped keys
 
+pub mod asset_scope;
+#[cfg(feature = "backup")]
+pub mod backup;
+pub mod codec;
+pub mod compression;
+pub mod detection;
 pub mod encryption;
+pub mod escrow;
+#[cfg(feature = "pq")]
+pub mod hybrid;
 pub mod key_derivation;
+pub mod multi_recipient;
+pub mod offchain;
+pub mod outgoing;
+pub mod rotation;
+pub mod stream;
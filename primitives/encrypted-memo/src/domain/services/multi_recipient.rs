@@ -0,0 +1,383 @@
+//! Multi-Recipient Service
+//!
+//! One memo readable by several viewing keys, e.g. the recipient, the sender's
+//! own wallet (change detection) and a mandated auditor. The payload is
+//! encrypted once under a random content key, which is wrapped per recipient:
+//!
+//! ```text
+//! version(1) || recipient_count(1) || nonce(12) || ciphertext(76+16) || wrapped_key(32+16) * n
+//!
+//! payload     = ChaCha20Poly1305(memo_data, content_key, nonce, aad = version || count || commitment)
+//! wrapped_key = ChaCha20Poly1305(content_key, SHA256(viewing_key || commitment || domain),
+//!                                nonce = 0, aad = commitment)
+//! ```
+//!
+//! Each wrapping key is bound to its commitment and used once, so the fixed
+//! nonce is never reused under one key. Recipients trial-unwrap every slot;
+//! the envelope does not reveal which slot is whose.
+
+use alloc::vec::Vec;
+use chacha20poly1305::{
+	aead::{Aead, KeyInit, Payload},
+	ChaCha20Poly1305, Nonce,
+};
+use zeroize::Zeroize;
+
+use crate::domain::{
+	entities::{error::MemoError, memo_data::MemoData},
+	services::key_derivation::derive_recipient_wrapping_key,
+	value_objects::{
+		constants::{
+			MAX_ENCRYPTED_MEMO_SIZE, MAX_MEMO_RECIPIENTS, MEMO_VERSION_MULTI, MEMO_VERSION_SIZE,
+			MIN_MULTI_RECIPIENT_MEMO_SIZE, WRAPPED_MEMO_KEY_SIZE,
+		},
+		ViewingKey,
+	},
+};
+
+/// Fixed nonce for key wrapping (each wrapping key is single-use)
+const WRAP_NONCE: [u8; 12] = [0u8; 12];
+
+/// Size of the `version || recipient_count` header
+const HEADER_SIZE: usize = MEMO_VERSION_SIZE + 1;
+
+/// Size of a multi-recipient memo with `recipients` wrapped keys
+pub fn multi_recipient_memo_size(recipients: usize) -> usize {
+	MIN_MULTI_RECIPIENT_MEMO_SIZE + recipients.saturating_sub(1) * WRAPPED_MEMO_KEY_SIZE
+}
+
+/// Associated data of the payload: header followed by the commitment
+fn payload_aad(header: &[u8], commitment: &[u8; 32]) -> [u8; HEADER_SIZE + 32] {
+	let mut aad = [0u8; HEADER_SIZE + 32];
+	aad[..HEADER_SIZE].copy_from_slice(header);
+	aad[HEADER_SIZE..].copy_from_slice(commitment);
+	aad
+}
+
+/// Encrypts memo data once for several viewing keys
+///
+/// Returns the multi-recipient envelope (see module docs), which
+/// [`decrypt_memo`](super::encryption::decrypt_memo) opens with any of the
+/// recipients' viewing keys.
+/// WARNING: `content_key` and nonce MUST be fresh per note.
+pub fn encrypt_memo_multi(
+	memo: &MemoData,
+	commitment: &[u8; 32],
+	recipients: &[ViewingKey],
+	content_key: &[u8; 32],
+	nonce: &[u8; 12],
+) -> Result<Vec<u8>, MemoError> {
+	if recipients.is_empty() || recipients.len() > MAX_MEMO_RECIPIENTS {
+		return Err(MemoError::InvalidRecipientCount(recipients.len()));
+	}
+
+	let header = [MEMO_VERSION_MULTI, recipients.len() as u8];
+	let ciphertext = ChaCha20Poly1305::new(content_key.into())
+		.encrypt(
+			Nonce::from_slice(nonce),
+			Payload {
+				msg: &memo.to_bytes(),
+				aad: &payload_aad(&header, commitment),
+			},
+		)
+		.map_err(|_| MemoError::EncryptionFailed)?;
+
+	let mut result = Vec::with_capacity(multi_recipient_memo_size(recipients.len()));
+	result.extend_from_slice(&header);
+	result.extend_from_slice(nonce);
+	result.extend_from_slice(&ciphertext);
+
+	for recipient in recipients {
+		let mut wrapping_key = derive_recipient_wrapping_key(recipient.as_bytes(), commitment);
+		let cipher = ChaCha20Poly1305::new((&wrapping_key).into());
+		wrapping_key.zeroize();
+		let wrapped = cipher
+			.encrypt(
+				Nonce::from_slice(&WRAP_NONCE),
+				Payload {
+					msg: content_key,
+					aad: commitment,
+				},
+			)
+			.map_err(|_| MemoError::EncryptionFailed)?;
+		result.extend_from_slice(&wrapped);
+	}
+
+	Ok(result)
+}
+
+/// Encrypts memo data for several viewing keys with random content key and nonce
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_memo_multi_random(
+	memo: &MemoData,
+	commitment: &[u8; 32],
+	recipients: &[ViewingKey],
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut content_key = [0u8; 32];
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut content_key);
+	OsRng.fill_bytes(&mut nonce);
+
+	let result = encrypt_memo_multi(memo, commitment, recipients, &content_key, &nonce);
+	content_key.zeroize();
+	result
+}
+
+/// Decrypts a multi-recipient memo with one recipient's viewing key
+pub fn decrypt_memo_multi(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+) -> Result<MemoData, MemoError> {
+	if encrypted.len() < MIN_MULTI_RECIPIENT_MEMO_SIZE {
+		return Err(MemoError::DataTooShort);
+	}
+	if encrypted[0] != MEMO_VERSION_MULTI {
+		return Err(MemoError::UnsupportedMemoVersion(encrypted[0]));
+	}
+	let count = encrypted[1] as usize;
+	if count == 0 || count > MAX_MEMO_RECIPIENTS {
+		return Err(MemoError::InvalidRecipientCount(count));
+	}
+	if encrypted.len() != multi_recipient_memo_size(count) {
+		return Err(MemoError::InvalidNoteData);
+	}
+
+	let (header, rest) = encrypted.split_at(HEADER_SIZE);
+	let (body, slots) = rest.split_at(MAX_ENCRYPTED_MEMO_SIZE);
+
+	let mut wrapping_key = derive_recipient_wrapping_key(viewing_key, commitment);
+	let cipher = ChaCha20Poly1305::new((&wrapping_key).into());
+	wrapping_key.zeroize();
+
+	// Trial-unwrap every slot
+	let mut content_key = slots
+		.chunks_exact(WRAPPED_MEMO_KEY_SIZE)
+		.find_map(|slot| {
+			cipher
+				.decrypt(
+					Nonce::from_slice(&WRAP_NONCE),
+					Payload {
+						msg: slot,
+						aad: commitment,
+					},
+				)
+				.ok()
+		})
+		.ok_or(MemoError::DecryptionFailed)?;
+
+	let (nonce, ciphertext) = body.split_at(12);
+	let plaintext = ChaCha20Poly1305::new(content_key.as_slice().into()).decrypt(
+		Nonce::from_slice(nonce),
+		Payload {
+			msg: ciphertext,
+			aad: &payload_aad(header, commitment),
+		},
+	);
+	content_key.zeroize();
+
+	MemoData::from_bytes(&plaintext.map_err(|_| MemoError::DecryptionFailed)?)
+}
+
+/// Attempts multi-recipient decryption, returns None on failure
+pub fn try_decrypt_memo_multi(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+) -> Option<MemoData> {
+	decrypt_memo_multi(encrypted, commitment, viewing_key).ok()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::{entities::versioned_memo::VersionedMemo, services::encryption};
+	use alloc::vec;
+
+	const COMMITMENT: [u8; 32] = [3u8; 32];
+
+	fn memo() -> MemoData {
+		MemoData::new(1000, [1u8; 32], [2u8; 32], 5)
+	}
+
+	fn recipients() -> Vec<ViewingKey> {
+		vec![
+			ViewingKey::from_bytes([10u8; 32]),
+			ViewingKey::from_bytes([11u8; 32]),
+			ViewingKey::from_bytes([12u8; 32]),
+		]
+	}
+
+	fn encrypted() -> Vec<u8> {
+		encrypt_memo_multi(&memo(), &COMMITMENT, &recipients(), &[7u8; 32], &[5u8; 12]).unwrap()
+	}
+
+	// ===== Layout Tests =====
+
+	#[test]
+	fn test_encrypt_memo_multi_layout() {
+		let encrypted = encrypted();
+
+		assert_eq!(encrypted.len(), multi_recipient_memo_size(3));
+		assert_eq!(encrypted[0], MEMO_VERSION_MULTI);
+		assert_eq!(encrypted[1], 3);
+		assert_eq!(&encrypted[2..14], &[5u8; 12]);
+	}
+
+	#[test]
+	fn test_multi_recipient_memo_size() {
+		assert_eq!(multi_recipient_memo_size(1), MIN_MULTI_RECIPIENT_MEMO_SIZE);
+		assert_eq!(
+			multi_recipient_memo_size(MAX_MEMO_RECIPIENTS),
+			MIN_MULTI_RECIPIENT_MEMO_SIZE + 7 * WRAPPED_MEMO_KEY_SIZE
+		);
+	}
+
+	// ===== Roundtrip Tests =====
+
+	#[test]
+	fn test_every_recipient_decrypts() {
+		let encrypted = encrypted();
+		for recipient in recipients() {
+			assert_eq!(
+				decrypt_memo_multi(&encrypted, &COMMITMENT, recipient.as_bytes()),
+				Ok(memo())
+			);
+		}
+	}
+
+	#[test]
+	fn test_decrypt_memo_dispatches_to_multi() {
+		assert_eq!(
+			encryption::decrypt_memo(&encrypted(), &COMMITMENT, &[11u8; 32]),
+			Ok(VersionedMemo::V1(memo()))
+		);
+	}
+
+	#[test]
+	fn test_single_recipient() {
+		let encrypted = encrypt_memo_multi(
+			&memo(),
+			&COMMITMENT,
+			&recipients()[..1],
+			&[7u8; 32],
+			&[5u8; 12],
+		)
+		.unwrap();
+		assert_eq!(encrypted.len(), MIN_MULTI_RECIPIENT_MEMO_SIZE);
+		assert_eq!(
+			decrypt_memo_multi(&encrypted, &COMMITMENT, &[10u8; 32]),
+			Ok(memo())
+		);
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_encrypt_memo_multi_random_roundtrip() {
+		let a = encrypt_memo_multi_random(&memo(), &COMMITMENT, &recipients()).unwrap();
+		let b = encrypt_memo_multi_random(&memo(), &COMMITMENT, &recipients()).unwrap();
+
+		assert_ne!(a, b);
+		assert_eq!(
+			try_decrypt_memo_multi(&a, &COMMITMENT, &[12u8; 32]),
+			Some(memo())
+		);
+	}
+
+	// ===== Error Tests =====
+
+	#[test]
+	fn test_non_recipient_fails() {
+		assert_eq!(
+			decrypt_memo_multi(&encrypted(), &COMMITMENT, &[13u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+		assert_eq!(
+			try_decrypt_memo_multi(&encrypted(), &COMMITMENT, &[13u8; 32]),
+			None
+		);
+	}
+
+	#[test]
+	fn test_wrong_commitment_fails() {
+		assert_eq!(
+			decrypt_memo_multi(&encrypted(), &[4u8; 32], &[10u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_recipient_count_bounds() {
+		let too_many = vec![ViewingKey::from_bytes([1u8; 32]); MAX_MEMO_RECIPIENTS + 1];
+		assert_eq!(
+			encrypt_memo_multi(&memo(), &COMMITMENT, &[], &[7u8; 32], &[5u8; 12]),
+			Err(MemoError::InvalidRecipientCount(0))
+		);
+		assert_eq!(
+			encrypt_memo_multi(&memo(), &COMMITMENT, &too_many, &[7u8; 32], &[5u8; 12]),
+			Err(MemoError::InvalidRecipientCount(MAX_MEMO_RECIPIENTS + 1))
+		);
+	}
+
+	#[test]
+	fn test_header_is_authenticated() {
+		// Dropping a slot and lowering the count must not verify
+		let mut encrypted = encrypted();
+		encrypted.truncate(multi_recipient_memo_size(2));
+		encrypted[1] = 2;
+		assert_eq!(
+			decrypt_memo_multi(&encrypted, &COMMITMENT, &[10u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_malformed_envelope_rejected() {
+		let encrypted = encrypted();
+		assert_eq!(
+			decrypt_memo_multi(
+				&encrypted[..MIN_MULTI_RECIPIENT_MEMO_SIZE - 1],
+				&COMMITMENT,
+				&[10u8; 32]
+			),
+			Err(MemoError::DataTooShort)
+		);
+		assert_eq!(
+			decrypt_memo_multi(&encrypted[..encrypted.len() - 1], &COMMITMENT, &[10u8; 32]),
+			Err(MemoError::InvalidNoteData)
+		);
+
+		let mut zero_count = encrypted.clone();
+		zero_count[1] = 0;
+		assert_eq!(
+			decrypt_memo_multi(&zero_count, &COMMITMENT, &[10u8; 32]),
+			Err(MemoError::InvalidRecipientCount(0))
+		);
+
+		let mut wrong_version = encrypted;
+		wrong_version[0] = MEMO_VERSION_MULTI + 1;
+		assert_eq!(
+			decrypt_memo_multi(&wrong_version, &COMMITMENT, &[10u8; 32]),
+			Err(MemoError::UnsupportedMemoVersion(MEMO_VERSION_MULTI + 1))
+		);
+	}
+
+	#[test]
+	fn test_tampered_payload_fails() {
+		let mut encrypted = encrypted();
+		encrypted[20] ^= 1;
+		assert_eq!(
+			decrypt_memo_multi(&encrypted, &COMMITMENT, &[10u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+}
@@ -0,0 +1,214 @@
+//! Off-chain Memo Service
+//!
+//! Large memos (e.g. disclosure payloads) can be kept out of chain state: the
+//! ciphertext is distributed off-chain (IPFS, relayers) and only a pointer
+//! holding its content hash is stored with the commitment:
+//!
+//! ```text
+//! pointer = version(1) || SHA256(ciphertext)(32)
+//! ```
+//!
+//! The hash is plain SHA-256 so it matches the `sha2-256` multihash of a raw
+//! IPFS block. Recipients verify fetched ciphertext against the pointer before
+//! decrypting; a relayer can withhold a memo but cannot substitute one.
+//!
+//! Attachments larger than the memo bound are sealed as a stream (see
+//! [`stream`](super::stream)) under the note's memo key, so an auditor holding
+//! the note's escrowed key can open them too.
+
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::domain::{
+	entities::{error::MemoError, versioned_memo::VersionedMemo},
+	services::{
+		encryption::decrypt_memo,
+		key_derivation::derive_encryption_key,
+		stream::{decrypt_stream, encrypt_stream},
+	},
+	value_objects::constants::{
+		MEMO_POINTER_SIZE, MEMO_VERSION_POINTER, MEMO_VERSION_SIZE, STREAM_NONCE_PREFIX_SIZE,
+	},
+};
+
+/// Computes the content hash of an off-chain ciphertext
+pub fn offchain_memo_hash(ciphertext: &[u8]) -> [u8; 32] {
+	Sha256::digest(ciphertext).into()
+}
+
+/// Builds the on-chain pointer to an off-chain ciphertext
+pub fn memo_pointer(ciphertext: &[u8]) -> [u8; MEMO_POINTER_SIZE] {
+	let mut pointer = [0u8; MEMO_POINTER_SIZE];
+	pointer[0] = MEMO_VERSION_POINTER;
+	pointer[MEMO_VERSION_SIZE..].copy_from_slice(&offchain_memo_hash(ciphertext));
+	pointer
+}
+
+/// Returns the content hash of an on-chain pointer, or `None` for other memos
+pub fn pointer_content_hash(onchain: &[u8]) -> Option<[u8; 32]> {
+	if onchain.len() != MEMO_POINTER_SIZE || onchain[0] != MEMO_VERSION_POINTER {
+		return None;
+	}
+	onchain[MEMO_VERSION_SIZE..].try_into().ok()
+}
+
+/// Verifies fetched ciphertext against an on-chain pointer
+///
+/// # Errors
+/// - `InvalidMemoPointer` if `onchain` is not a pointer
+/// - `ContentHashMismatch` if `fetched` is not the referenced ciphertext
+pub fn verify_offchain_memo(onchain: &[u8], fetched: &[u8]) -> Result<(), MemoError> {
+	let expected = pointer_content_hash(onchain).ok_or(MemoError::InvalidMemoPointer)?;
+	if offchain_memo_hash(fetched) != expected {
+		return Err(MemoError::ContentHashMismatch);
+	}
+	Ok(())
+}
+
+/// Verifies fetched ciphertext against an on-chain pointer and decrypts it
+pub fn decrypt_offchain_memo(
+	onchain: &[u8],
+	fetched: &[u8],
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+) -> Result<VersionedMemo, MemoError> {
+	verify_offchain_memo(onchain, fetched)?;
+	decrypt_memo(fetched, commitment, viewing_key)
+}
+
+/// Seals a large attachment for off-chain storage and builds its pointer
+///
+/// Returns `(ciphertext, pointer)`: the ciphertext goes off-chain, the pointer
+/// on-chain with the commitment.
+/// WARNING: `nonce_prefix` MUST be unique per note.
+pub fn encrypt_offchain_stream(
+	plaintext: &[u8],
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+	nonce_prefix: &[u8; STREAM_NONCE_PREFIX_SIZE],
+) -> Result<(Vec<u8>, [u8; MEMO_POINTER_SIZE]), MemoError> {
+	let memo_key = Zeroizing::new(derive_encryption_key(viewing_key, commitment));
+	let ciphertext = encrypt_stream(&memo_key, nonce_prefix, plaintext)?;
+	let pointer = memo_pointer(&ciphertext);
+	Ok((ciphertext, pointer))
+}
+
+/// Seals a large off-chain attachment with a random nonce prefix
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_offchain_stream_random(
+	plaintext: &[u8],
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+) -> Result<(Vec<u8>, [u8; MEMO_POINTER_SIZE]), MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut nonce_prefix = [0u8; STREAM_NONCE_PREFIX_SIZE];
+	OsRng.fill_bytes(&mut nonce_prefix);
+
+	encrypt_offchain_stream(plaintext, commitment, viewing_key, &nonce_prefix)
+}
+
+/// Verifies a fetched attachment against an on-chain pointer and decrypts it
+pub fn decrypt_offchain_stream(
+	onchain: &[u8],
+	fetched: &[u8],
+	commitment: &[u8; 32],
+	viewing_key: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	verify_offchain_memo(onchain, fetched)?;
+	let memo_key = Zeroizing::new(derive_encryption_key(viewing_key, commitment));
+	decrypt_stream(&memo_key, fetched)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::{entities::memo_data::MemoData, services::encryption::encrypt_memo};
+
+	fn ciphertext() -> alloc::vec::Vec<u8> {
+		let memo = MemoData::new(700, [1u8; 32], [2u8; 32], 3);
+		encrypt_memo(&memo, &[3u8; 32], &[4u8; 32], &[5u8; 12]).unwrap()
+	}
+
+	#[test]
+	fn test_memo_pointer_layout() {
+		let pointer = memo_pointer(&ciphertext());
+		assert_eq!(pointer[0], MEMO_VERSION_POINTER);
+		assert_eq!(
+			pointer_content_hash(&pointer),
+			Some(offchain_memo_hash(&ciphertext()))
+		);
+	}
+
+	#[test]
+	fn test_offchain_memo_hash_is_plain_sha256() {
+		// SHA256("abc")
+		assert_eq!(
+			hex::encode(offchain_memo_hash(b"abc")),
+			"ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+		);
+	}
+
+	#[test]
+	fn test_decrypt_offchain_memo_roundtrip() {
+		let fetched = ciphertext();
+		let pointer = memo_pointer(&fetched);
+		let memo = decrypt_offchain_memo(&pointer, &fetched, &[3u8; 32], &[4u8; 32]).unwrap();
+		assert_eq!(memo.value(), 700);
+	}
+
+	#[test]
+	fn test_verify_rejects_substituted_ciphertext() {
+		let pointer = memo_pointer(&ciphertext());
+		let mut fetched = ciphertext();
+		fetched[20] ^= 1;
+		assert_eq!(
+			verify_offchain_memo(&pointer, &fetched),
+			Err(MemoError::ContentHashMismatch)
+		);
+	}
+
+	#[test]
+	fn test_offchain_stream_roundtrip() {
+		let attachment = [0x5Au8; 10_000];
+		let (fetched, pointer) =
+			encrypt_offchain_stream(&attachment, &[3u8; 32], &[4u8; 32], &[6u8; 7]).unwrap();
+		assert_eq!(
+			decrypt_offchain_stream(&pointer, &fetched, &[3u8; 32], &[4u8; 32]),
+			Ok(attachment.to_vec())
+		);
+		assert_eq!(
+			decrypt_offchain_stream(&pointer, &fetched, &[3u8; 32], &[5u8; 32]),
+			Err(MemoError::DecryptionFailed)
+		);
+
+		let mut substituted = fetched.clone();
+		substituted[100] ^= 1;
+		assert_eq!(
+			decrypt_offchain_stream(&pointer, &substituted, &[3u8; 32], &[4u8; 32]),
+			Err(MemoError::ContentHashMismatch)
+		);
+	}
+
+	#[test]
+	fn test_verify_rejects_non_pointer() {
+		let fetched = ciphertext();
+		assert_eq!(pointer_content_hash(&fetched), None);
+		assert_eq!(
+			verify_offchain_memo(&fetched, &fetched),
+			Err(MemoError::InvalidMemoPointer)
+		);
+
+		let mut unheaded = memo_pointer(&fetched);
+		unheaded[0] = MEMO_VERSION_POINTER + 1;
+		assert_eq!(pointer_content_hash(&unheaded), None);
+	}
+}
@@ -0,0 +1,177 @@
+//! Outgoing Memo Service
+//!
+//! A memo sealed to the recipient cannot be read back by its sender. The
+//! sender keeps an outgoing copy sealed under its own outgoing viewing key:
+//!
+//! ```text
+//! outgoing_viewing_key = SHA256(derivation_key || domain)
+//! outgoing_copy        = encrypt_memo(memo, commitment, outgoing_viewing_key)
+//! ```
+//!
+//! Wallets that only hold a single legacy viewing key are upgraded to a
+//! [`FullViewingKey`] whose outgoing half is derived from that viewing key.
+
+use alloc::vec::Vec;
+
+use crate::domain::{
+	entities::{error::MemoError, versioned_memo::VersionedMemo},
+	services::{
+		encryption::{decrypt_memo, encrypt_memo, encrypt_memo_v2, encrypt_memo_v3},
+		key_derivation::derive_legacy_outgoing_viewing_key,
+	},
+	value_objects::{constants::FULL_VIEWING_KEY_SIZE, FullViewingKey, ViewingKey},
+};
+
+/// Upgrades a single legacy viewing key to a full viewing key
+///
+/// The viewing key becomes the incoming half; the outgoing half is derived
+/// from it, so every holder of the legacy key upgrades to the same pair.
+pub fn upgrade_legacy_viewing_key(viewing_key: ViewingKey) -> FullViewingKey {
+	let outgoing = derive_legacy_outgoing_viewing_key(viewing_key.as_bytes());
+	FullViewingKey::new(viewing_key, outgoing)
+}
+
+/// Decodes a full viewing key, accepting legacy 32-byte viewing keys
+///
+/// # Errors
+/// `InvalidFullViewingKey` unless `bytes` is 32 or 64 bytes long.
+pub fn decode_full_viewing_key(bytes: &[u8]) -> Result<FullViewingKey, MemoError> {
+	match bytes.len() {
+		32 => {
+			let mut key = [0u8; 32];
+			key.copy_from_slice(bytes);
+			Ok(upgrade_legacy_viewing_key(ViewingKey(key)))
+		}
+		FULL_VIEWING_KEY_SIZE => FullViewingKey::from_bytes(bytes),
+		_ => Err(MemoError::InvalidFullViewingKey),
+	}
+}
+
+/// Seals the sender's copy of a memo under its outgoing viewing key
+///
+/// Produces a plain V1, V2 or V3 envelope matching the payload.
+/// WARNING: Nonce MUST be unique and never reused.
+pub fn encrypt_outgoing_memo(
+	memo: &VersionedMemo,
+	commitment: &[u8; 32],
+	outgoing_viewing_key: &[u8; 32],
+	nonce: &[u8; 12],
+) -> Result<Vec<u8>, MemoError> {
+	match memo {
+		VersionedMemo::V1(memo) => encrypt_memo(memo, commitment, outgoing_viewing_key, nonce),
+		VersionedMemo::V2(memo) => encrypt_memo_v2(memo, commitment, outgoing_viewing_key, nonce),
+		VersionedMemo::V3(memo) => encrypt_memo_v3(memo, commitment, outgoing_viewing_key, nonce),
+	}
+}
+
+/// Seals the sender's copy of a memo with a random nonce
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_outgoing_memo_random(
+	memo: &VersionedMemo,
+	commitment: &[u8; 32],
+	outgoing_viewing_key: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut nonce);
+
+	encrypt_outgoing_memo(memo, commitment, outgoing_viewing_key, &nonce)
+}
+
+/// Recovers a memo the wallet sent from its outgoing copy
+pub fn recover_outgoing_memo(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	outgoing_viewing_key: &[u8; 32],
+) -> Result<VersionedMemo, MemoError> {
+	decrypt_memo(encrypted, commitment, outgoing_viewing_key)
+}
+
+/// Attempts outgoing recovery, returns None on failure
+pub fn try_recover_outgoing_memo(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	outgoing_viewing_key: &[u8; 32],
+) -> Option<VersionedMemo> {
+	recover_outgoing_memo(encrypted, commitment, outgoing_viewing_key).ok()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::{aggregates::keyset::KeySet, entities::memo_data_v2::MemoDataV2};
+
+	const COMMITMENT: [u8; 32] = [3u8; 32];
+	const NONCE: [u8; 12] = [5u8; 12];
+
+	fn memo() -> VersionedMemo {
+		VersionedMemo::V2(MemoDataV2::new(500, [1u8; 32], [2u8; 32], 0, 0, [0u8; 16]))
+	}
+
+	// ===== Legacy Migration Tests =====
+
+	#[test]
+	fn test_upgrade_legacy_viewing_key() {
+		let legacy = ViewingKey::from_bytes([7u8; 32]);
+		let fvk = upgrade_legacy_viewing_key(legacy.clone());
+		assert_eq!(fvk.incoming, legacy);
+		assert_eq!(
+			fvk.outgoing,
+			derive_legacy_outgoing_viewing_key(legacy.as_bytes())
+		);
+		assert_eq!(fvk, upgrade_legacy_viewing_key(legacy));
+	}
+
+	#[test]
+	fn test_decode_full_viewing_key() {
+		let fvk = KeySet::from_spending_key([9u8; 32]).full_viewing_key();
+		assert_eq!(decode_full_viewing_key(&fvk.to_bytes()), Ok(fvk.clone()));
+		assert_eq!(
+			decode_full_viewing_key(fvk.incoming.as_bytes()),
+			Ok(upgrade_legacy_viewing_key(fvk.incoming.clone()))
+		);
+		assert_eq!(
+			decode_full_viewing_key(&[0u8; 33]),
+			Err(MemoError::InvalidFullViewingKey)
+		);
+	}
+
+	// ===== Outgoing Recovery Tests =====
+
+	#[test]
+	fn test_sender_recovers_outgoing_copy() {
+		let sender = KeySet::from_spending_key([9u8; 32]);
+		let ovk = sender.outgoing_viewing_key();
+		let copy = encrypt_outgoing_memo(&memo(), &COMMITMENT, ovk.as_bytes(), &NONCE).unwrap();
+
+		assert_eq!(
+			recover_outgoing_memo(&copy, &COMMITMENT, ovk.as_bytes()),
+			Ok(memo())
+		);
+		assert!(try_recover_outgoing_memo(&copy, &COMMITMENT, &[0u8; 32]).is_none());
+	}
+
+	#[test]
+	fn test_incoming_key_cannot_read_outgoing_copy() {
+		let sender = KeySet::from_spending_key([9u8; 32]);
+		let copy = encrypt_outgoing_memo(
+			&memo(),
+			&COMMITMENT,
+			sender.outgoing_viewing_key().as_bytes(),
+			&NONCE,
+		)
+		.unwrap();
+		assert_eq!(
+			decrypt_memo(&copy, &COMMITMENT, sender.viewing_key.as_bytes()),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+}
@@ -0,0 +1,248 @@
+//! Key Rotation Service
+//!
+//! A leaked viewing key exposes every memo encrypted to it, but the spending
+//! key behind it is unaffected. Rotation moves the wallet to a fresh viewing
+//! key generation derived from the same spending key:
+//!
+//! ```text
+//! viewing_key_g = SHA256(derivation_key || domain || g_le)  (g > 0)
+//! commitment_g  = SHA256(viewing_key_g || domain)
+//! ```
+//!
+//! Existing memos are re-encrypted under the new generation and replace the
+//! old ciphertexts, and a [`KeyRotationStatement`] binds the two commitments
+//! so the rotation can be proven against the spending key without revealing
+//! either viewing key.
+
+use alloc::vec::Vec;
+
+use crate::domain::{
+	entities::{error::MemoError, versioned_memo::VersionedMemo},
+	services::{
+		encryption::{decrypt_memo, encrypt_memo, encrypt_memo_v2, encrypt_memo_v3},
+		key_derivation::{derive_viewing_key_generation, viewing_key_commitment},
+	},
+	value_objects::KeyRotationStatement,
+};
+
+/// Builds the statement that viewing key generation `to_generation`
+/// supersedes `from_generation`
+///
+/// # Errors
+/// `InvalidKeyRotation` unless `to_generation > from_generation`.
+pub fn key_rotation_statement(
+	spending_key: &[u8; 32],
+	from_generation: u32,
+	to_generation: u32,
+) -> Result<KeyRotationStatement, MemoError> {
+	if to_generation <= from_generation {
+		return Err(MemoError::InvalidKeyRotation);
+	}
+
+	let old_key = derive_viewing_key_generation(spending_key, from_generation);
+	let new_key = derive_viewing_key_generation(spending_key, to_generation);
+	Ok(KeyRotationStatement {
+		from_generation,
+		to_generation,
+		old_commitment: viewing_key_commitment(old_key.as_bytes()),
+		new_commitment: viewing_key_commitment(new_key.as_bytes()),
+	})
+}
+
+/// Checks a rotation statement against the two viewing keys it names
+///
+/// Lets an auditor holding both keys confirm they belong to one rotation.
+pub fn verify_key_rotation_statement(
+	statement: &KeyRotationStatement,
+	old_viewing_key: &[u8; 32],
+	new_viewing_key: &[u8; 32],
+) -> bool {
+	statement.to_generation > statement.from_generation
+		&& viewing_key_commitment(old_viewing_key) == statement.old_commitment
+		&& viewing_key_commitment(new_viewing_key) == statement.new_commitment
+}
+
+/// Re-encrypts a memo from one viewing key to another
+///
+/// Any envelope `old_viewing_key` can open is accepted; the result is a plain
+/// V1, V2 or V3 memo matching the payload. Escrow slots, detection tags and extra
+/// recipients are not carried over.
+/// WARNING: Nonce MUST be unique and never reused.
+pub fn reencrypt_memo(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	old_viewing_key: &[u8; 32],
+	new_viewing_key: &[u8; 32],
+	nonce: &[u8; 12],
+) -> Result<Vec<u8>, MemoError> {
+	match decrypt_memo(encrypted, commitment, old_viewing_key)? {
+		VersionedMemo::V1(memo) => encrypt_memo(&memo, commitment, new_viewing_key, nonce),
+		VersionedMemo::V2(memo) => encrypt_memo_v2(&memo, commitment, new_viewing_key, nonce),
+		VersionedMemo::V3(memo) => encrypt_memo_v3(&memo, commitment, new_viewing_key, nonce),
+	}
+}
+
+/// Re-encrypts a memo from one viewing key to another with a random nonce
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn reencrypt_memo_random(
+	encrypted: &[u8],
+	commitment: &[u8; 32],
+	old_viewing_key: &[u8; 32],
+	new_viewing_key: &[u8; 32],
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut nonce = [0u8; 12];
+	OsRng.fill_bytes(&mut nonce);
+
+	reencrypt_memo(
+		encrypted,
+		commitment,
+		old_viewing_key,
+		new_viewing_key,
+		&nonce,
+	)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::{
+		entities::{memo_data::MemoData, memo_data_v2::MemoDataV2},
+		services::{
+			detection::{create_detection_tag, tag_memo},
+			key_derivation::derive_clue_key,
+		},
+		value_objects::constants::{ENCRYPTED_MEMO_V2_SIZE, MAX_ENCRYPTED_MEMO_SIZE},
+	};
+
+	const SPENDING_KEY: [u8; 32] = [21u8; 32];
+
+	fn generation(generation: u32) -> [u8; 32] {
+		derive_viewing_key_generation(&SPENDING_KEY, generation).0
+	}
+
+	// ===== Rotation Statement Tests =====
+
+	#[test]
+	fn test_key_rotation_statement_verifies() {
+		let statement = key_rotation_statement(&SPENDING_KEY, 0, 1).unwrap();
+		assert_eq!(statement.from_generation, 0);
+		assert_eq!(statement.to_generation, 1);
+		assert!(verify_key_rotation_statement(
+			&statement,
+			&generation(0),
+			&generation(1)
+		));
+		assert!(!verify_key_rotation_statement(
+			&statement,
+			&generation(1),
+			&generation(0)
+		));
+	}
+
+	#[test]
+	fn test_key_rotation_statement_rejects_non_increasing_generation() {
+		assert_eq!(
+			key_rotation_statement(&SPENDING_KEY, 2, 2),
+			Err(MemoError::InvalidKeyRotation)
+		);
+		assert_eq!(
+			key_rotation_statement(&SPENDING_KEY, 3, 1),
+			Err(MemoError::InvalidKeyRotation)
+		);
+	}
+
+	#[test]
+	fn test_key_rotation_statement_hides_viewing_keys() {
+		let bytes = key_rotation_statement(&SPENDING_KEY, 0, 1)
+			.unwrap()
+			.to_bytes();
+		assert!(!bytes.windows(32).any(|w| w == generation(0)));
+		assert!(!bytes.windows(32).any(|w| w == generation(1)));
+	}
+
+	// ===== Re-encryption Tests =====
+
+	#[test]
+	fn test_reencrypt_memo_v1() {
+		let memo = MemoData::new(300, [1u8; 32], [2u8; 32], 4);
+		let old = encrypt_memo(&memo, &[3u8; 32], &generation(0), &[5u8; 12]).unwrap();
+		let new =
+			reencrypt_memo(&old, &[3u8; 32], &generation(0), &generation(1), &[6u8; 12]).unwrap();
+
+		assert_eq!(new.len(), MAX_ENCRYPTED_MEMO_SIZE);
+		assert_eq!(
+			decrypt_memo(&new, &[3u8; 32], &generation(1)),
+			Ok(VersionedMemo::V1(memo))
+		);
+		assert_eq!(
+			decrypt_memo(&new, &[3u8; 32], &generation(0)),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_reencrypt_memo_v2() {
+		let memo = MemoDataV2::new(300, [1u8; 32], [2u8; 32], 1 << 40, 7, [9u8; 16]);
+		let old = encrypt_memo_v2(&memo, &[3u8; 32], &generation(0), &[5u8; 12]).unwrap();
+		let new =
+			reencrypt_memo(&old, &[3u8; 32], &generation(0), &generation(1), &[6u8; 12]).unwrap();
+
+		assert_eq!(new.len(), ENCRYPTED_MEMO_V2_SIZE);
+		assert_eq!(
+			decrypt_memo(&new, &[3u8; 32], &generation(1)),
+			Ok(VersionedMemo::V2(memo))
+		);
+	}
+
+	#[test]
+	fn test_reencrypt_memo_drops_detection_tag() {
+		let memo = MemoData::new(300, [1u8; 32], [2u8; 32], 4);
+		let encrypted = encrypt_memo(&memo, &[3u8; 32], &generation(0), &[5u8; 12]).unwrap();
+		let tag = create_detection_tag(&derive_clue_key(&generation(0)), &[7u8; 32]).unwrap();
+		let tagged = tag_memo(&encrypted, &tag).unwrap();
+
+		let new = reencrypt_memo(
+			&tagged,
+			&[3u8; 32],
+			&generation(0),
+			&generation(1),
+			&[6u8; 12],
+		)
+		.unwrap();
+		assert_eq!(new.len(), MAX_ENCRYPTED_MEMO_SIZE);
+	}
+
+	#[test]
+	fn test_reencrypt_memo_wrong_old_key() {
+		let memo = MemoData::new(300, [1u8; 32], [2u8; 32], 4);
+		let old = encrypt_memo(&memo, &[3u8; 32], &generation(0), &[5u8; 12]).unwrap();
+		assert_eq!(
+			reencrypt_memo(&old, &[3u8; 32], &generation(2), &generation(1), &[6u8; 12]),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[cfg(feature = "encrypt")]
+	#[test]
+	fn test_reencrypt_memo_random() {
+		let memo = MemoData::new(300, [1u8; 32], [2u8; 32], 4);
+		let old = encrypt_memo(&memo, &[3u8; 32], &generation(0), &[5u8; 12]).unwrap();
+		let a = reencrypt_memo_random(&old, &[3u8; 32], &generation(0), &generation(1)).unwrap();
+		let b = reencrypt_memo_random(&old, &[3u8; 32], &generation(0), &generation(1)).unwrap();
+
+		assert_ne!(a, b);
+		assert_eq!(
+			decrypt_memo(&a, &[3u8; 32], &generation(1)),
+			Ok(VersionedMemo::V1(memo))
+		);
+	}
+}
@@ -0,0 +1,366 @@
+//! Streaming AEAD Service
+//!
+//! Disclosure attachments can exceed the memo bound by orders of magnitude.
+//! They are sealed with the STREAM construction over ChaCha20Poly1305: the
+//! plaintext is cut into fixed-size chunks, each sealed under its own nonce,
+//! so a payload is encrypted and verified chunk by chunk without holding it
+//! in memory:
+//!
+//! ```text
+//! header      = version(1) || nonce_prefix(7)
+//! nonce_i     = nonce_prefix(7) || i_be(4) || last_flag(1)
+//! chunk_i     = ChaCha20Poly1305(plaintext_i, stream_key, nonce_i, aad = header)
+//! ciphertext  = header || chunk_0(4096+16) || ... || chunk_n(<=4096+16)
+//! ```
+//!
+//! The counter rejects reordered chunks and the last flag rejects truncation:
+//! a stream cut at a chunk boundary has no chunk sealed as last.
+
+use alloc::vec::Vec;
+use chacha20poly1305::{
+	aead::{Aead, KeyInit, Payload},
+	ChaCha20Poly1305, Nonce,
+};
+use zeroize::Zeroizing;
+
+use crate::domain::{
+	entities::error::MemoError,
+	services::key_derivation::derive_stream_key,
+	value_objects::constants::{
+		MAC_SIZE, MEMO_VERSION_STREAM, NONCE_SIZE, STREAM_CHUNK_SIZE, STREAM_HEADER_SIZE,
+		STREAM_NONCE_PREFIX_SIZE, STREAM_SEALED_CHUNK_SIZE,
+	},
+};
+
+/// Chunk cipher shared by both stream directions
+struct StreamCipher {
+	cipher: ChaCha20Poly1305,
+	header: [u8; STREAM_HEADER_SIZE],
+	counter: u32,
+	exhausted: bool,
+}
+
+impl StreamCipher {
+	fn new(content_key: &[u8; 32], header: [u8; STREAM_HEADER_SIZE]) -> Self {
+		let key = Zeroizing::new(derive_stream_key(content_key));
+		Self {
+			cipher: ChaCha20Poly1305::new((&*key).into()),
+			header,
+			counter: 0,
+			exhausted: false,
+		}
+	}
+
+	/// Nonce of the next chunk; advances the counter
+	fn next_nonce(&mut self, last: bool) -> Result<[u8; NONCE_SIZE], MemoError> {
+		if self.exhausted {
+			return Err(MemoError::InvalidStreamChunk);
+		}
+		let mut nonce = [0u8; NONCE_SIZE];
+		nonce[..STREAM_NONCE_PREFIX_SIZE].copy_from_slice(&self.header[1..]);
+		nonce[STREAM_NONCE_PREFIX_SIZE..NONCE_SIZE - 1]
+			.copy_from_slice(&self.counter.to_be_bytes());
+		nonce[NONCE_SIZE - 1] = last as u8;
+
+		match self.counter.checked_add(1) {
+			Some(counter) => self.counter = counter,
+			None => self.exhausted = true,
+		}
+		Ok(nonce)
+	}
+}
+
+/// Seals a stream chunk by chunk
+pub struct StreamEncryptor(StreamCipher);
+
+impl StreamEncryptor {
+	/// Starts a stream under `content_key`
+	///
+	/// WARNING: `nonce_prefix` MUST be unique per content key.
+	pub fn new(content_key: &[u8; 32], nonce_prefix: &[u8; STREAM_NONCE_PREFIX_SIZE]) -> Self {
+		let mut header = [0u8; STREAM_HEADER_SIZE];
+		header[0] = MEMO_VERSION_STREAM;
+		header[1..].copy_from_slice(nonce_prefix);
+		Self(StreamCipher::new(content_key, header))
+	}
+
+	/// Header to emit before the first chunk
+	pub fn header(&self) -> [u8; STREAM_HEADER_SIZE] {
+		self.0.header
+	}
+
+	/// Seals a full chunk of exactly [`STREAM_CHUNK_SIZE`] bytes
+	///
+	/// # Errors
+	/// `InvalidStreamChunk` on a partial chunk or once the counter is spent.
+	pub fn encrypt_next(&mut self, chunk: &[u8]) -> Result<Vec<u8>, MemoError> {
+		if chunk.len() != STREAM_CHUNK_SIZE {
+			return Err(MemoError::InvalidStreamChunk);
+		}
+		self.seal(chunk, false)
+	}
+
+	/// Seals the final chunk (at most [`STREAM_CHUNK_SIZE`] bytes, may be empty)
+	pub fn encrypt_last(mut self, chunk: &[u8]) -> Result<Vec<u8>, MemoError> {
+		if chunk.len() > STREAM_CHUNK_SIZE {
+			return Err(MemoError::InvalidStreamChunk);
+		}
+		self.seal(chunk, true)
+	}
+
+	fn seal(&mut self, chunk: &[u8], last: bool) -> Result<Vec<u8>, MemoError> {
+		let nonce = self.0.next_nonce(last)?;
+		self.0
+			.cipher
+			.encrypt(
+				Nonce::from_slice(&nonce),
+				Payload {
+					msg: chunk,
+					aad: &self.0.header,
+				},
+			)
+			.map_err(|_| MemoError::EncryptionFailed)
+	}
+}
+
+/// Opens a stream chunk by chunk
+pub struct StreamDecryptor(StreamCipher);
+
+impl StreamDecryptor {
+	/// Starts opening a stream from its header
+	///
+	/// # Errors
+	/// - `InvalidStreamChunk` if the header has the wrong length
+	/// - `UnsupportedMemoVersion` if it is not a stream header
+	pub fn new(content_key: &[u8; 32], header: &[u8]) -> Result<Self, MemoError> {
+		let header: [u8; STREAM_HEADER_SIZE] = header
+			.try_into()
+			.map_err(|_| MemoError::InvalidStreamChunk)?;
+		if header[0] != MEMO_VERSION_STREAM {
+			return Err(MemoError::UnsupportedMemoVersion(header[0]));
+		}
+		Ok(Self(StreamCipher::new(content_key, header)))
+	}
+
+	/// Opens a full sealed chunk of exactly [`STREAM_SEALED_CHUNK_SIZE`] bytes
+	pub fn decrypt_next(&mut self, sealed: &[u8]) -> Result<Vec<u8>, MemoError> {
+		if sealed.len() != STREAM_SEALED_CHUNK_SIZE {
+			return Err(MemoError::InvalidStreamChunk);
+		}
+		self.open(sealed, false)
+	}
+
+	/// Opens the final sealed chunk
+	///
+	/// # Errors
+	/// `DecryptionFailed` if the chunk was not sealed as the last one.
+	pub fn decrypt_last(mut self, sealed: &[u8]) -> Result<Vec<u8>, MemoError> {
+		if !(MAC_SIZE..=STREAM_SEALED_CHUNK_SIZE).contains(&sealed.len()) {
+			return Err(MemoError::InvalidStreamChunk);
+		}
+		self.open(sealed, true)
+	}
+
+	fn open(&mut self, sealed: &[u8], last: bool) -> Result<Vec<u8>, MemoError> {
+		let nonce = self.0.next_nonce(last)?;
+		self.0
+			.cipher
+			.decrypt(
+				Nonce::from_slice(&nonce),
+				Payload {
+					msg: sealed,
+					aad: &self.0.header,
+				},
+			)
+			.map_err(|_| MemoError::DecryptionFailed)
+	}
+}
+
+/// Seals a whole payload as a stream
+///
+/// WARNING: `nonce_prefix` MUST be unique per content key.
+pub fn encrypt_stream(
+	content_key: &[u8; 32],
+	nonce_prefix: &[u8; STREAM_NONCE_PREFIX_SIZE],
+	plaintext: &[u8],
+) -> Result<Vec<u8>, MemoError> {
+	let mut encryptor = StreamEncryptor::new(content_key, nonce_prefix);
+	let full_chunks = plaintext.len().saturating_sub(1) / STREAM_CHUNK_SIZE;
+	let (body, last) = plaintext.split_at(full_chunks * STREAM_CHUNK_SIZE);
+
+	let mut out = Vec::with_capacity(stream_ciphertext_len(plaintext.len()));
+	out.extend_from_slice(&encryptor.header());
+	for chunk in body.chunks_exact(STREAM_CHUNK_SIZE) {
+		out.extend_from_slice(&encryptor.encrypt_next(chunk)?);
+	}
+	out.extend_from_slice(&encryptor.encrypt_last(last)?);
+	Ok(out)
+}
+
+/// Seals a whole payload as a stream with a random nonce prefix
+///
+/// Requires encrypt feature.
+#[cfg(feature = "encrypt")]
+pub fn encrypt_stream_random(
+	content_key: &[u8; 32],
+	plaintext: &[u8],
+) -> Result<Vec<u8>, MemoError> {
+	use rand::rngs::OsRng;
+	use rand::RngCore;
+
+	let mut nonce_prefix = [0u8; STREAM_NONCE_PREFIX_SIZE];
+	OsRng.fill_bytes(&mut nonce_prefix);
+
+	encrypt_stream(content_key, &nonce_prefix, plaintext)
+}
+
+/// Opens a whole stream produced by [`encrypt_stream`]
+pub fn decrypt_stream(content_key: &[u8; 32], ciphertext: &[u8]) -> Result<Vec<u8>, MemoError> {
+	if ciphertext.len() < STREAM_HEADER_SIZE + MAC_SIZE {
+		return Err(MemoError::DataTooShort);
+	}
+	let (header, sealed) = ciphertext.split_at(STREAM_HEADER_SIZE);
+	let mut decryptor = StreamDecryptor::new(content_key, header)?;
+
+	let full_chunks = (sealed.len() - 1) / STREAM_SEALED_CHUNK_SIZE;
+	let (body, last) = sealed.split_at(full_chunks * STREAM_SEALED_CHUNK_SIZE);
+
+	let mut out = Vec::with_capacity(sealed.len());
+	for chunk in body.chunks_exact(STREAM_SEALED_CHUNK_SIZE) {
+		out.extend_from_slice(&decryptor.decrypt_next(chunk)?);
+	}
+	out.extend_from_slice(&decryptor.decrypt_last(last)?);
+	Ok(out)
+}
+
+/// Length of the stream sealing `plaintext_len` bytes
+pub fn stream_ciphertext_len(plaintext_len: usize) -> usize {
+	let chunks = plaintext_len.saturating_sub(1) / STREAM_CHUNK_SIZE + 1;
+	STREAM_HEADER_SIZE + plaintext_len + chunks * MAC_SIZE
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const KEY: [u8; 32] = [7u8; 32];
+	const PREFIX: [u8; STREAM_NONCE_PREFIX_SIZE] = [9u8; STREAM_NONCE_PREFIX_SIZE];
+
+	fn payload(len: usize) -> Vec<u8> {
+		(0..len).map(|i| i as u8).collect()
+	}
+
+	// ===== Roundtrip Tests =====
+
+	#[test]
+	fn test_roundtrip_at_chunk_boundaries() {
+		for len in [
+			0,
+			1,
+			STREAM_CHUNK_SIZE - 1,
+			STREAM_CHUNK_SIZE,
+			STREAM_CHUNK_SIZE + 1,
+			3 * STREAM_CHUNK_SIZE,
+			3 * STREAM_CHUNK_SIZE + 17,
+		] {
+			let plaintext = payload(len);
+			let ciphertext = encrypt_stream(&KEY, &PREFIX, &plaintext).unwrap();
+			assert_eq!(ciphertext.len(), stream_ciphertext_len(len));
+			assert_eq!(ciphertext[0], MEMO_VERSION_STREAM);
+			assert_eq!(decrypt_stream(&KEY, &ciphertext), Ok(plaintext));
+		}
+	}
+
+	#[test]
+	fn test_incremental_matches_one_shot() {
+		let plaintext = payload(2 * STREAM_CHUNK_SIZE + 5);
+		let mut encryptor = StreamEncryptor::new(&KEY, &PREFIX);
+		let mut ciphertext = encryptor.header().to_vec();
+		ciphertext.extend(
+			encryptor
+				.encrypt_next(&plaintext[..STREAM_CHUNK_SIZE])
+				.unwrap(),
+		);
+		ciphertext.extend(
+			encryptor
+				.encrypt_next(&plaintext[STREAM_CHUNK_SIZE..2 * STREAM_CHUNK_SIZE])
+				.unwrap(),
+		);
+		ciphertext.extend(
+			encryptor
+				.encrypt_last(&plaintext[2 * STREAM_CHUNK_SIZE..])
+				.unwrap(),
+		);
+		assert_eq!(
+			ciphertext,
+			encrypt_stream(&KEY, &PREFIX, &plaintext).unwrap()
+		);
+	}
+
+	// ===== Integrity Tests =====
+
+	#[test]
+	fn test_rejects_truncated_stream() {
+		let ciphertext =
+			encrypt_stream(&KEY, &PREFIX, &payload(2 * STREAM_CHUNK_SIZE + 1)).unwrap();
+		let truncated = &ciphertext[..STREAM_HEADER_SIZE + STREAM_SEALED_CHUNK_SIZE];
+		assert_eq!(
+			decrypt_stream(&KEY, truncated),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_rejects_reordered_chunks() {
+		let ciphertext = encrypt_stream(&KEY, &PREFIX, &payload(3 * STREAM_CHUNK_SIZE)).unwrap();
+		let (header, sealed) = ciphertext.split_at(STREAM_HEADER_SIZE);
+		let mut swapped = header.to_vec();
+		swapped.extend_from_slice(&sealed[STREAM_SEALED_CHUNK_SIZE..2 * STREAM_SEALED_CHUNK_SIZE]);
+		swapped.extend_from_slice(&sealed[..STREAM_SEALED_CHUNK_SIZE]);
+		swapped.extend_from_slice(&sealed[2 * STREAM_SEALED_CHUNK_SIZE..]);
+		assert_eq!(
+			decrypt_stream(&KEY, &swapped),
+			Err(MemoError::DecryptionFailed)
+		);
+	}
+
+	#[test]
+	fn test_rejects_tampered_header_and_wrong_key() {
+		let mut ciphertext = encrypt_stream(&KEY, &PREFIX, &payload(100)).unwrap();
+		assert_eq!(
+			decrypt_stream(&[8u8; 32], &ciphertext),
+			Err(MemoError::DecryptionFailed)
+		);
+		ciphertext[1] ^= 1;
+		assert_eq!(
+			decrypt_stream(&KEY, &ciphertext),
+			Err(MemoError::DecryptionFailed)
+		);
+		ciphertext[0] = MEMO_VERSION_STREAM + 1;
+		assert_eq!(
+			decrypt_stream(&KEY, &ciphertext),
+			Err(MemoError::UnsupportedMemoVersion(MEMO_VERSION_STREAM + 1))
+		);
+	}
+
+	#[test]
+	fn test_chunk_length_checks() {
+		let mut encryptor = StreamEncryptor::new(&KEY, &PREFIX);
+		assert_eq!(
+			encryptor.encrypt_next(&[0u8; 10]),
+			Err(MemoError::InvalidStreamChunk)
+		);
+		assert_eq!(
+			encryptor.encrypt_last(&[0u8; STREAM_CHUNK_SIZE + 1]),
+			Err(MemoError::InvalidStreamChunk)
+		);
+		assert_eq!(
+			decrypt_stream(&KEY, &[MEMO_VERSION_STREAM; STREAM_HEADER_SIZE]),
+			Err(MemoError::DataTooShort)
+		);
+	}
+}
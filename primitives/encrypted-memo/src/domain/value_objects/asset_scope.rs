@@ -0,0 +1,63 @@
+//! Asset scope value object.
+//!
+//! The set of assets whose memos are encrypted under a per-asset viewing key
+//! instead of the base viewing key, so a wallet can hand an auditor one
+//! asset's key (e.g. a USD-pegged stablecoin) while keeping the rest private.
+
+use alloc::collections::BTreeSet;
+
+/// Assets that require an asset-scoped viewing key.
+///
+/// Empty by default: every memo uses the base viewing key.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AssetScope {
+	assets: BTreeSet<u64>,
+}
+
+impl AssetScope {
+	/// Creates a scope covering `assets`.
+	pub fn new(assets: impl IntoIterator<Item = u64>) -> Self {
+		Self {
+			assets: assets.into_iter().collect(),
+		}
+	}
+
+	/// Scope in which no asset requires a scoped key.
+	pub fn none() -> Self {
+		Self::default()
+	}
+
+	/// Returns `true` when memos of `asset_id` use the asset-scoped key.
+	pub fn requires_scoped_key(&self, asset_id: u64) -> bool {
+		self.assets.contains(&asset_id)
+	}
+
+	/// Scoped assets in ascending order.
+	pub fn assets(&self) -> impl Iterator<Item = u64> + '_ {
+		self.assets.iter().copied()
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_requires_scoped_key() {
+		let scope = AssetScope::new([3, 1, 3]);
+		assert!(scope.requires_scoped_key(1));
+		assert!(scope.requires_scoped_key(3));
+		assert!(!scope.requires_scoped_key(0));
+		assert_eq!(scope.assets().collect::<alloc::vec::Vec<_>>(), [1, 3]);
+	}
+
+	#[test]
+	fn test_none_scopes_nothing() {
+		assert!(!AssetScope::none().requires_scoped_key(0));
+		assert_eq!(AssetScope::none(), AssetScope::default());
+	}
+}
@@ -25,6 +25,264 @@ pub const NONCE_SIZE: usize = 12;
 /// Size of ChaCha20Poly1305 authentication tag in bytes
 pub const MAC_SIZE: usize = 16;
 
+/// Size of the version header of versioned memo envelopes
+pub const MEMO_VERSION_SIZE: usize = 1;
+
+/// Version of the original memo layout.
+///
+/// V1 memos carry no header; any memo of at most [`MAX_ENCRYPTED_MEMO_SIZE`]
+/// bytes is V1.
+pub const MEMO_VERSION_V1: u8 = 1;
+
+/// Version of the [`MemoDataV2`](crate::MemoDataV2) payload
+pub const MEMO_VERSION_V2: u8 = 2;
+
+/// Size of the sender hint carried by V2 memos
+pub const SENDER_HINT_SIZE: usize = 16;
+
+/// V2 plaintext memo data size (before encryption).
+///
+/// Layout: `value(8) + owner_pk(32) + blinding(32) + asset_id(8) + diversifier_index(4) + sender_hint(16) = 100`
+pub const MEMO_DATA_V2_SIZE: usize = 8 + 32 + 32 + 8 + 4 + SENDER_HINT_SIZE;
+
+/// V2 encrypted memo size in bytes.
+///
+/// Layout: `version(1) + nonce(12) + memo_data_v2(100) + MAC(16) = 129`
+pub const ENCRYPTED_MEMO_V2_SIZE: usize =
+	MEMO_VERSION_SIZE + NONCE_SIZE + MEMO_DATA_V2_SIZE + MAC_SIZE;
+
+/// Version of the [`MemoDataV3`](crate::MemoDataV3) payload
+pub const MEMO_VERSION_V3: u8 = 10;
+
+/// Maximum size of the application data carried by V3 memos
+pub const MAX_APP_DATA_SIZE: usize = 32;
+
+/// V3 plaintext memo data size (before encryption).
+///
+/// Layout: `memo_data_v2(100) + app_data_len(1) + app_data(32) = 133`;
+/// application data is zero-padded to [`MAX_APP_DATA_SIZE`]
+pub const MEMO_DATA_V3_SIZE: usize = MEMO_DATA_V2_SIZE + 1 + MAX_APP_DATA_SIZE;
+
+/// V3 encrypted memo size in bytes.
+///
+/// Layout: `version(1) + nonce(12) + memo_data_v3(133) + MAC(16) = 162`
+pub const ENCRYPTED_MEMO_V3_SIZE: usize =
+	MEMO_VERSION_SIZE + NONCE_SIZE + MEMO_DATA_V3_SIZE + MAC_SIZE;
+
+/// Version of the ephemeral-key envelope.
+///
+/// Carries a [`MemoDataV2`](crate::MemoDataV2) payload encrypted under a key
+/// agreed with a fresh X25519 ephemeral key stored in the envelope.
+pub const MEMO_VERSION_EPHEMERAL: u8 = 3;
+
+/// Size of an X25519 public key (agreement key or ephemeral key) in bytes
+pub const AGREEMENT_KEY_SIZE: usize = 32;
+
+/// Ephemeral-key encrypted memo size in bytes.
+///
+/// Layout: `version(1) + ephemeral_public(32) + nonce(12) + memo_data_v2(100) + MAC(16) = 161`
+pub const ENCRYPTED_MEMO_EPHEMERAL_SIZE: usize =
+	MEMO_VERSION_SIZE + AGREEMENT_KEY_SIZE + NONCE_SIZE + MEMO_DATA_V2_SIZE + MAC_SIZE;
+
+/// Version of the multi-recipient envelope.
+///
+/// Carries one [`MemoData`](crate::MemoData) payload and a wrapped copy of its
+/// content key per recipient.
+pub const MEMO_VERSION_MULTI: u8 = 4;
+
+/// Maximum number of recipients of a multi-recipient memo
+pub const MAX_MEMO_RECIPIENTS: usize = 8;
+
+/// Size of a memo key wrapped with ChaCha20Poly1305.
+///
+/// Layout: `key(32) + MAC(16) = 48`
+pub const WRAPPED_MEMO_KEY_SIZE: usize = 32 + MAC_SIZE;
+
+/// Multi-recipient encrypted memo size for one recipient, in bytes.
+///
+/// Layout: `version(1) + recipient_count(1) + nonce(12) + memo_data(76) + MAC(16) + wrapped_key(48) = 154`;
+/// each further recipient adds [`WRAPPED_MEMO_KEY_SIZE`].
+pub const MIN_MULTI_RECIPIENT_MEMO_SIZE: usize =
+	MEMO_VERSION_SIZE + 1 + MAX_ENCRYPTED_MEMO_SIZE + WRAPPED_MEMO_KEY_SIZE;
+
+/// Multi-recipient encrypted memo size for [`MAX_MEMO_RECIPIENTS`] recipients, in bytes.
+///
+/// Layout: `154 + 7 * wrapped_key(48) = 490`
+pub const MAX_MULTI_RECIPIENT_MEMO_SIZE: usize =
+	MIN_MULTI_RECIPIENT_MEMO_SIZE + (MAX_MEMO_RECIPIENTS - 1) * WRAPPED_MEMO_KEY_SIZE;
+
+/// Version of the tagged envelope.
+///
+/// Prefixes any other memo with a fuzzy message detection tag:
+/// `version(1) + detection_tag(34) + memo`.
+pub const MEMO_VERSION_TAGGED: u8 = 6;
+
+/// Number of detection key pairs behind a clue key; bounds the detection
+/// precision, so the lowest false-positive rate is `2^-16`
+pub const MAX_DETECTION_PRECISION: usize = 16;
+
+/// Size of a clue key (the public half of the detection key pairs) in bytes.
+///
+/// Layout: `public_key(32) * 16 = 512`
+pub const CLUE_KEY_SIZE: usize = AGREEMENT_KEY_SIZE * MAX_DETECTION_PRECISION;
+
+/// Size of a detection tag in bytes.
+///
+/// Layout: `ephemeral_public(32) + clue_bits(16 / 8) = 34`
+pub const DETECTION_TAG_SIZE: usize = AGREEMENT_KEY_SIZE + MAX_DETECTION_PRECISION / 8;
+
+/// Largest memo stored on-chain: a tagged multi-recipient memo for
+/// [`MAX_MEMO_RECIPIENTS`] recipients.
+///
+/// Layout: `version(1) + detection_tag(34) + multi_recipient_memo(490) = 525`;
+/// hybrid envelopes exceed it and go off-chain behind a pointer.
+pub const MAX_ONCHAIN_MEMO_SIZE: usize =
+	MEMO_VERSION_SIZE + DETECTION_TAG_SIZE + MAX_MULTI_RECIPIENT_MEMO_SIZE;
+
+// Length alone tells headerless V1 memos from versioned envelopes
+const _: () = assert!(ENCRYPTED_MEMO_V2_SIZE > MAX_ENCRYPTED_MEMO_SIZE);
+
+/// Size of an escrowed memo key in bytes.
+///
+/// Layout: `ephemeral_public(32) + wrapped_key(32) + MAC(16) = 80`
+pub const ESCROWED_KEY_SIZE: usize = AGREEMENT_KEY_SIZE + 32 + MAC_SIZE;
+
+/// Version of the escrow envelope.
+///
+/// Declares an escrow slot: the memo key wrapped to a policy-designated
+/// auditor travels inside the envelope, ahead of a [`MemoData`](crate::MemoData)
+/// ciphertext.
+pub const MEMO_VERSION_ESCROW: u8 = 5;
+
+/// Escrow envelope size in bytes.
+///
+/// Layout: `version(1) + escrowed_key(80) + nonce(12) + memo_data(76) + MAC(16) = 185`
+pub const ENCRYPTED_MEMO_ESCROW_SIZE: usize =
+	MEMO_VERSION_SIZE + ESCROWED_KEY_SIZE + MAX_ENCRYPTED_MEMO_SIZE;
+
+/// Header flag marking a compressed payload, combined with the version.
+///
+/// Supported with V2 only: `(MEMO_VERSION_V2 | MEMO_FLAG_COMPRESSED) || nonce(12) || ciphertext`.
+pub const MEMO_FLAG_COMPRESSED: u8 = 0x80;
+
+/// Smallest compressed memo; the compressed payload is zero-padded so the
+/// envelope is never mistaken for a headerless V1 memo.
+pub const MIN_COMPRESSED_MEMO_SIZE: usize = MAX_ENCRYPTED_MEMO_SIZE + 1;
+
+/// Largest compressed memo; payloads that do not shrink are sealed uncompressed.
+pub const MAX_COMPRESSED_MEMO_SIZE: usize = ENCRYPTED_MEMO_V2_SIZE - 1;
+
+/// Version of the XChaCha20Poly1305 envelope.
+///
+/// Same payload as V2 under the extended-nonce cipher, so nonces can be drawn
+/// at random even when many memos share a derived key.
+pub const MEMO_VERSION_XCHACHA: u8 = 7;
+
+/// XChaCha20Poly1305 nonce size in bytes
+pub const XNONCE_SIZE: usize = 24;
+
+/// XChaCha20Poly1305 encrypted memo size in bytes.
+///
+/// Layout: `version(1) + nonce(24) + memo_data_v2(100) + MAC(16) = 141`
+pub const ENCRYPTED_MEMO_XCHACHA_SIZE: usize =
+	MEMO_VERSION_SIZE + XNONCE_SIZE + MEMO_DATA_V2_SIZE + MAC_SIZE;
+
+/// Version of the hybrid post-quantum envelope (`pq` feature).
+///
+/// Keyed by both an X25519 agreement and an ML-KEM-768 encapsulation.
+pub const MEMO_VERSION_HYBRID: u8 = 8;
+
+/// Size of an ML-KEM-768 encapsulation (public) key in bytes
+pub const ML_KEM_PUBLIC_KEY_SIZE: usize = 1184;
+
+/// Size of an ML-KEM-768 ciphertext in bytes
+pub const ML_KEM_CIPHERTEXT_SIZE: usize = 1088;
+
+/// Hybrid encrypted memo size in bytes.
+///
+/// Layout: `version(1) + ephemeral_public(32) + kem_ciphertext(1088) + nonce(12) + memo_data_v2(100) + MAC(16) = 1249`
+pub const ENCRYPTED_MEMO_HYBRID_SIZE: usize = MEMO_VERSION_SIZE
+	+ AGREEMENT_KEY_SIZE
+	+ ML_KEM_CIPHERTEXT_SIZE
+	+ NONCE_SIZE
+	+ MEMO_DATA_V2_SIZE
+	+ MAC_SIZE;
+
+/// Version of the off-chain memo pointer.
+///
+/// Stored on-chain in place of a memo whose ciphertext is distributed
+/// off-chain: `version(1) + content_hash(32)`.
+pub const MEMO_VERSION_POINTER: u8 = 9;
+
+/// Off-chain memo pointer size in bytes.
+///
+/// Layout: `version(1) + SHA256(ciphertext)(32) = 33`
+pub const MEMO_POINTER_SIZE: usize = MEMO_VERSION_SIZE + 32;
+
+/// Version of streamed (chunked) ciphertexts.
+///
+/// Off-chain only: attachments larger than the memo bound, referenced
+/// on-chain through a [`MEMO_VERSION_POINTER`] pointer.
+pub const MEMO_VERSION_STREAM: u8 = 11;
+
+/// Size of the random nonce prefix of a streamed ciphertext
+pub const STREAM_NONCE_PREFIX_SIZE: usize = 7;
+
+/// Streamed ciphertext header size in bytes.
+///
+/// Layout: `version(1) + nonce_prefix(7) = 8`
+pub const STREAM_HEADER_SIZE: usize = MEMO_VERSION_SIZE + STREAM_NONCE_PREFIX_SIZE;
+
+/// Plaintext size of every chunk of a stream but the last
+pub const STREAM_CHUNK_SIZE: usize = 4096;
+
+/// Sealed size of a full stream chunk: `chunk(4096) + MAC(16) = 4112`
+pub const STREAM_SEALED_CHUNK_SIZE: usize = STREAM_CHUNK_SIZE + MAC_SIZE;
+
+/// Key rotation statement size in bytes.
+///
+/// Layout: `from_generation(4) + to_generation(4) + old_commitment(32) + new_commitment(32) = 72`
+pub const KEY_ROTATION_STATEMENT_SIZE: usize = 4 + 4 + 32 + 32;
+
+/// Full viewing key size in bytes.
+///
+/// Layout: `incoming_viewing_key(32) + outgoing_viewing_key(32) = 64`
+pub const FULL_VIEWING_KEY_SIZE: usize = 32 + 32;
+
+// Decryption bounds single-key envelopes by the escrow size
+const _: () = assert!(ENCRYPTED_MEMO_XCHACHA_SIZE < ENCRYPTED_MEMO_ESCROW_SIZE);
+const _: () = assert!(ENCRYPTED_MEMO_V3_SIZE < ENCRYPTED_MEMO_ESCROW_SIZE);
+
+// ============================================================================
+// KeySet backups
+// ============================================================================
+
+/// Current KeySet backup format version
+pub const KEYSET_BACKUP_VERSION: u8 = 1;
+
+/// Size of the Argon2 salt of a KeySet backup
+pub const BACKUP_SALT_SIZE: usize = 16;
+
+/// KeySet backup header size in bytes.
+///
+/// Layout: `version(1) + m_cost(4) + t_cost(4) + p_cost(4) + salt(16) = 29`
+pub const KEYSET_BACKUP_HEADER_SIZE: usize = 1 + 4 + 4 + 4 + BACKUP_SALT_SIZE;
+
+/// Default Argon2id memory cost in KiB (19 MiB)
+pub const BACKUP_ARGON2_M_COST: u32 = 19 * 1024;
+
+/// Default Argon2id iteration count
+pub const BACKUP_ARGON2_T_COST: u32 = 2;
+
+/// Default Argon2id parallelism
+pub const BACKUP_ARGON2_P_COST: u32 = 1;
+
+/// Largest Argon2id memory cost accepted on import, in KiB (1 GiB)
+pub const MAX_BACKUP_ARGON2_M_COST: u32 = 1 << 20;
+
+// Imports must accept backups written with the default parameters
+const _: () = assert!(BACKUP_ARGON2_M_COST <= MAX_BACKUP_ARGON2_M_COST);
+
 // ============================================================================
 // Domain separators
 // ============================================================================
@@ -32,6 +290,9 @@ pub const MAC_SIZE: usize = 16;
 /// Domain separator for per-note encryption key derivation
 pub const KEY_DOMAIN: &[u8] = b"orbinum-note-encryption-v1";
 
+/// Domain separator for the intermediate derivation key
+pub const DERIVATION_KEY_DOMAIN: &[u8] = b"orbinum-derivation-key-v1";
+
 /// Domain separator for viewing key derivation
 pub const VIEWING_KEY_DOMAIN: &[u8] = b"orbinum-viewing-key-v1";
 
@@ -41,6 +302,48 @@ pub const NULLIFIER_KEY_DOMAIN: &[u8] = b"orbinum-nullifier-key-v1";
 /// Domain separator for EdDSA key derivation
 pub const EDDSA_KEY_DOMAIN: &[u8] = b"orbinum-eddsa-key-v1";
 
+/// Domain separator for X25519 agreement key derivation
+pub const AGREEMENT_KEY_DOMAIN: &[u8] = b"orbinum-agreement-key-v1";
+
+/// Domain separator for the ECDH shared memo key
+pub const SHARED_KEY_DOMAIN: &[u8] = b"orbinum-shared-key-v1";
+
+/// Domain separator for the auditor escrow wrapping key
+pub const ESCROW_KEY_DOMAIN: &[u8] = b"orbinum-escrow-key-v1";
+
+/// Domain separator for per-recipient wrapping keys of multi-recipient memos
+pub const RECIPIENT_WRAP_KEY_DOMAIN: &[u8] = b"orbinum-recipient-wrap-v1";
+
+/// Domain separator for detection secret key derivation
+pub const DETECTION_KEY_DOMAIN: &[u8] = b"orbinum-detection-key-v1";
+
+/// Domain separator for detection tag clue bits
+pub const DETECTION_TAG_DOMAIN: &[u8] = b"orbinum-detection-tag-v1";
+
+/// Domain separator for ML-KEM key pair seeds
+pub const PQ_KEY_DOMAIN: &[u8] = b"orbinum-pq-key-v1";
+
+/// Domain separator for combining X25519 and ML-KEM shared secrets
+pub const HYBRID_KEY_DOMAIN: &[u8] = b"orbinum-hybrid-key-v1";
+
+/// Domain separator for rotated viewing key generations
+pub const VIEWING_KEY_ROTATION_DOMAIN: &[u8] = b"orbinum-viewing-key-rotation-v1";
+
+/// Domain separator for viewing key commitments in key rotation statements
+pub const VIEWING_KEY_COMMITMENT_DOMAIN: &[u8] = b"orbinum-viewing-key-commitment-v1";
+
+/// Domain separator for per-asset viewing keys
+pub const ASSET_VIEWING_KEY_DOMAIN: &[u8] = b"orbinum-asset-viewing-key-v1";
+
+/// Domain separator for streamed ciphertext keys
+pub const STREAM_KEY_DOMAIN: &[u8] = b"orbinum-stream-key-v1";
+
+/// Domain separator for outgoing viewing key derivation
+pub const OUTGOING_VIEWING_KEY_DOMAIN: &[u8] = b"orbinum-outgoing-viewing-key-v1";
+
+/// Domain separator for outgoing keys of wallets that only hold a viewing key
+pub const LEGACY_OUTGOING_VIEWING_KEY_DOMAIN: &[u8] = b"orbinum-legacy-outgoing-viewing-key-v1";
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -89,15 +392,127 @@ mod tests {
 		assert_eq!(MIN_ENCRYPTED_MEMO_SIZE, 28);
 	}
 
+	#[test]
+	fn test_memo_data_v2_size_is_100() {
+		assert_eq!(MEMO_DATA_V2_SIZE, 100);
+	}
+
+	#[test]
+	fn test_v2_envelope_size_is_129() {
+		assert_eq!(ENCRYPTED_MEMO_V2_SIZE, 129);
+	}
+
+	#[test]
+	fn test_v3_sizes() {
+		assert_eq!(MEMO_DATA_V3_SIZE, 133);
+		assert_eq!(ENCRYPTED_MEMO_V3_SIZE, 162);
+	}
+
+	#[test]
+	fn test_ephemeral_envelope_size_is_161() {
+		assert_eq!(ENCRYPTED_MEMO_EPHEMERAL_SIZE, 161);
+	}
+
+	#[test]
+	fn test_min_multi_recipient_memo_size_is_154() {
+		assert_eq!(WRAPPED_MEMO_KEY_SIZE, 48);
+		assert_eq!(MIN_MULTI_RECIPIENT_MEMO_SIZE, 154);
+	}
+
+	#[test]
+	fn test_detection_sizes() {
+		assert_eq!(CLUE_KEY_SIZE, 512);
+		assert_eq!(DETECTION_TAG_SIZE, 34);
+		assert_eq!(MAX_DETECTION_PRECISION % 8, 0);
+	}
+
+	#[test]
+	fn test_escrowed_key_size_is_80() {
+		assert_eq!(ESCROWED_KEY_SIZE, 80);
+	}
+
+	#[test]
+	fn test_escrow_envelope_size_is_185() {
+		assert_eq!(ENCRYPTED_MEMO_ESCROW_SIZE, 185);
+	}
+
 	// ===== Domain separator uniqueness =====
 
+	#[test]
+	fn test_xchacha_envelope_size_is_141() {
+		assert_eq!(XNONCE_SIZE, 24);
+		assert_eq!(ENCRYPTED_MEMO_XCHACHA_SIZE, 141);
+	}
+
+	#[test]
+	fn test_onchain_memo_size_is_525() {
+		assert_eq!(MAX_MULTI_RECIPIENT_MEMO_SIZE, 490);
+		assert_eq!(MAX_ONCHAIN_MEMO_SIZE, 525);
+		const { assert!(ENCRYPTED_MEMO_HYBRID_SIZE > MAX_ONCHAIN_MEMO_SIZE) };
+	}
+
+	#[test]
+	fn test_memo_pointer_size_is_33() {
+		assert_eq!(MEMO_POINTER_SIZE, 33);
+	}
+
+	#[test]
+	fn test_compressed_memo_bounds() {
+		assert_eq!(MIN_COMPRESSED_MEMO_SIZE, 105);
+		assert_eq!(MAX_COMPRESSED_MEMO_SIZE, 128);
+		assert_eq!(MEMO_VERSION_V2 & MEMO_FLAG_COMPRESSED, 0);
+	}
+
+	#[test]
+	fn test_key_rotation_statement_size_is_72() {
+		assert_eq!(KEY_ROTATION_STATEMENT_SIZE, 72);
+	}
+
+	#[test]
+	fn test_stream_sizes() {
+		assert_eq!(STREAM_HEADER_SIZE, 8);
+		assert_eq!(STREAM_SEALED_CHUNK_SIZE, 4112);
+		// prefix(7) || counter(4) || last_flag(1) fills the AEAD nonce
+		assert_eq!(STREAM_NONCE_PREFIX_SIZE + 4 + 1, NONCE_SIZE);
+	}
+
+	#[test]
+	fn test_full_viewing_key_size_is_64() {
+		assert_eq!(FULL_VIEWING_KEY_SIZE, 64);
+	}
+
+	#[test]
+	fn test_keyset_backup_header_size_is_29() {
+		assert_eq!(KEYSET_BACKUP_HEADER_SIZE, 29);
+	}
+
+	#[test]
+	fn test_hybrid_envelope_size_is_1249() {
+		assert_eq!(ENCRYPTED_MEMO_HYBRID_SIZE, 1249);
+	}
+
 	#[test]
 	fn test_all_domain_separators_unique() {
 		let domains = [
 			KEY_DOMAIN,
+			DERIVATION_KEY_DOMAIN,
 			VIEWING_KEY_DOMAIN,
 			NULLIFIER_KEY_DOMAIN,
 			EDDSA_KEY_DOMAIN,
+			AGREEMENT_KEY_DOMAIN,
+			SHARED_KEY_DOMAIN,
+			ESCROW_KEY_DOMAIN,
+			RECIPIENT_WRAP_KEY_DOMAIN,
+			DETECTION_KEY_DOMAIN,
+			DETECTION_TAG_DOMAIN,
+			PQ_KEY_DOMAIN,
+			HYBRID_KEY_DOMAIN,
+			VIEWING_KEY_ROTATION_DOMAIN,
+			VIEWING_KEY_COMMITMENT_DOMAIN,
+			ASSET_VIEWING_KEY_DOMAIN,
+			OUTGOING_VIEWING_KEY_DOMAIN,
+			LEGACY_OUTGOING_VIEWING_KEY_DOMAIN,
+			STREAM_KEY_DOMAIN,
 		];
 		for i in 0..domains.len() {
 			for j in (i + 1)..domains.len() {
@@ -147,6 +562,33 @@ mod tests {
 		assert_eq!(KEY_DOMAIN, b"orbinum-note-encryption-v1");
 	}
 
+	#[test]
+	fn test_derivation_key_domain_exact_value() {
+		assert_eq!(DERIVATION_KEY_DOMAIN, b"orbinum-derivation-key-v1");
+	}
+
+	#[test]
+	fn test_asset_viewing_key_domain_exact_value() {
+		assert_eq!(ASSET_VIEWING_KEY_DOMAIN, b"orbinum-asset-viewing-key-v1");
+	}
+
+	#[test]
+	fn test_stream_key_domain_exact_value() {
+		assert_eq!(STREAM_KEY_DOMAIN, b"orbinum-stream-key-v1");
+	}
+
+	#[test]
+	fn test_outgoing_viewing_key_domains_exact_value() {
+		assert_eq!(
+			OUTGOING_VIEWING_KEY_DOMAIN,
+			b"orbinum-outgoing-viewing-key-v1"
+		);
+		assert_eq!(
+			LEGACY_OUTGOING_VIEWING_KEY_DOMAIN,
+			b"orbinum-legacy-outgoing-viewing-key-v1"
+		);
+	}
+
 	#[test]
 	fn test_viewing_key_domain_exact_value() {
 		assert_eq!(VIEWING_KEY_DOMAIN, b"orbinum-viewing-key-v1");
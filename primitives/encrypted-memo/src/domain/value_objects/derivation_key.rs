@@ -0,0 +1,160 @@
+//! Derivation Key value object.
+//!
+//! Intermediate secret between the spending key and its sub-keys, derived via
+//! `SHA256(spending_key || DERIVATION_KEY_DOMAIN)`. A hardware wallet exports
+//! it so the host can derive viewing, nullifier and EdDSA keys while the
+//! spending key never leaves the device.
+
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use scale_info::TypeInfo;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Intermediate secret behind the viewing, nullifier and EdDSA keys.
+///
+/// Grants everything except spend authority. Treat it as a secret.
+///
+/// Compared in constant time and zeroized on drop.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+	all(feature = "parity-scale-codec", feature = "scale-info"),
+	derive(Encode, Decode, TypeInfo)
+)]
+pub struct DerivationKey(pub [u8; 32]);
+
+impl DerivationKey {
+	/// Creates a derivation key from raw bytes.
+	pub fn from_bytes(bytes: [u8; 32]) -> Self {
+		Self(bytes)
+	}
+
+	/// Returns the raw key bytes.
+	pub fn as_bytes(&self) -> &[u8; 32] {
+		&self.0
+	}
+}
+
+impl AsRef<[u8; 32]> for DerivationKey {
+	fn as_ref(&self) -> &[u8; 32] {
+		&self.0
+	}
+}
+
+impl From<[u8; 32]> for DerivationKey {
+	fn from(bytes: [u8; 32]) -> Self {
+		Self(bytes)
+	}
+}
+
+impl ConstantTimeEq for DerivationKey {
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.0[..].ct_eq(&other.0[..])
+	}
+}
+
+impl PartialEq for DerivationKey {
+	fn eq(&self, other: &Self) -> bool {
+		self.ct_eq(other).into()
+	}
+}
+
+impl Eq for DerivationKey {}
+
+impl Zeroize for DerivationKey {
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+impl Drop for DerivationKey {
+	fn drop(&mut self) {
+		self.zeroize();
+	}
+}
+
+impl ZeroizeOnDrop for DerivationKey {}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	extern crate alloc;
+	use alloc::format;
+
+	const BYTES: [u8; 32] = [99u8; 32];
+
+	#[test]
+	fn test_from_bytes_stores_inner() {
+		assert_eq!(DerivationKey::from_bytes(BYTES).0, BYTES);
+	}
+
+	#[test]
+	fn test_as_bytes_returns_reference() {
+		assert_eq!(DerivationKey::from_bytes(BYTES).as_bytes(), &BYTES);
+	}
+
+	#[test]
+	fn test_as_ref() {
+		let k = DerivationKey::from_bytes(BYTES);
+		let r: &[u8; 32] = k.as_ref();
+		assert_eq!(r, &BYTES);
+	}
+
+	#[test]
+	fn test_from_trait() {
+		let k: DerivationKey = BYTES.into();
+		assert_eq!(k.0, BYTES);
+	}
+
+	#[test]
+	fn test_clone_eq() {
+		let k1 = DerivationKey::from_bytes(BYTES);
+		assert_eq!(k1.clone(), k1);
+	}
+
+	#[test]
+	fn test_ne_different_bytes() {
+		assert_ne!(
+			DerivationKey::from_bytes([1u8; 32]),
+			DerivationKey::from_bytes([2u8; 32])
+		);
+	}
+
+	#[test]
+	fn test_debug_contains_type_name() {
+		let s = format!("{:?}", DerivationKey::from_bytes([0u8; 32]));
+		assert!(s.contains("DerivationKey"));
+	}
+
+	#[test]
+	fn test_all_zeros() {
+		assert_eq!(DerivationKey::from_bytes([0u8; 32]).as_bytes(), &[0u8; 32]);
+	}
+
+	#[test]
+	fn test_all_ones() {
+		assert_eq!(
+			DerivationKey::from_bytes([0xFFu8; 32]).as_bytes(),
+			&[0xFFu8; 32]
+		);
+	}
+
+	#[test]
+	fn test_ct_eq() {
+		let k = DerivationKey::from_bytes(BYTES);
+		assert!(bool::from(k.ct_eq(&DerivationKey::from_bytes(BYTES))));
+		assert!(!bool::from(k.ct_eq(&DerivationKey::from_bytes([0u8; 32]))));
+	}
+
+	#[test]
+	fn test_zeroize() {
+		let mut k = DerivationKey::from_bytes(BYTES);
+		k.zeroize();
+		assert_eq!(k.as_bytes(), &[0u8; 32]);
+	}
+}
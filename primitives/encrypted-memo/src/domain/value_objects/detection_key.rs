@@ -0,0 +1,313 @@
+//! Fuzzy message detection keys and tags.
+//!
+//! A viewing key yields `MAX_DETECTION_PRECISION` X25519 key pairs. The
+//! public halves form the [`ClueKey`] senders tag memos with; a prefix of the
+//! secret halves forms a [`DetectionKey`] a wallet can hand to an untrusted
+//! server. The server flags every tagged memo meant for the wallet, plus
+//! unrelated memos at a rate of `2^-precision`, without learning which are real.
+
+use crate::domain::{
+	entities::error::MemoError,
+	value_objects::constants::{
+		AGREEMENT_KEY_SIZE, CLUE_KEY_SIZE, DETECTION_TAG_SIZE, MAX_DETECTION_PRECISION,
+	},
+};
+use alloc::vec::Vec;
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use scale_info::TypeInfo;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+// ============================================================================
+// Clue Key
+// ============================================================================
+
+/// Public detection keys, published next to the recipient's agreement key.
+///
+/// Fixed serialized size: 512 bytes (`public_key(32) * 16`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+	all(feature = "parity-scale-codec", feature = "scale-info"),
+	derive(Encode, Decode, TypeInfo)
+)]
+pub struct ClueKey(pub [[u8; 32]; MAX_DETECTION_PRECISION]);
+
+impl ClueKey {
+	/// Serializes to bytes (fixed 512 bytes).
+	pub fn to_bytes(&self) -> [u8; CLUE_KEY_SIZE] {
+		let mut bytes = [0u8; CLUE_KEY_SIZE];
+		for (chunk, key) in bytes.chunks_exact_mut(AGREEMENT_KEY_SIZE).zip(&self.0) {
+			chunk.copy_from_slice(key);
+		}
+		bytes
+	}
+
+	/// Deserializes from bytes (must be exactly 512 bytes).
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, MemoError> {
+		if bytes.len() != CLUE_KEY_SIZE {
+			return Err(MemoError::InvalidDetectionEncoding);
+		}
+
+		let mut keys = [[0u8; 32]; MAX_DETECTION_PRECISION];
+		for (key, chunk) in keys.iter_mut().zip(bytes.chunks_exact(AGREEMENT_KEY_SIZE)) {
+			key.copy_from_slice(chunk);
+		}
+		Ok(Self(keys))
+	}
+}
+
+// ============================================================================
+// Detection Key
+// ============================================================================
+
+/// Secret detection keys for the first `precision` clue key entries.
+///
+/// Grants no decryption capability, only the ability to flag candidate memos.
+/// Higher precision means fewer false positives and less cover for the owner.
+///
+/// Zeroized on drop.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DetectionKey {
+	precision: u8,
+	secrets: [[u8; 32]; MAX_DETECTION_PRECISION],
+}
+
+impl DetectionKey {
+	/// Creates a detection key from its secrets.
+	///
+	/// # Errors
+	/// `InvalidDetectionPrecision` unless `1 <= secrets.len() <= MAX_DETECTION_PRECISION`.
+	pub fn new(secrets: &[[u8; 32]]) -> Result<Self, MemoError> {
+		if secrets.is_empty() || secrets.len() > MAX_DETECTION_PRECISION {
+			return Err(MemoError::InvalidDetectionPrecision(secrets.len()));
+		}
+
+		let mut all = [[0u8; 32]; MAX_DETECTION_PRECISION];
+		all[..secrets.len()].copy_from_slice(secrets);
+		Ok(Self {
+			precision: secrets.len() as u8,
+			secrets: all,
+		})
+	}
+
+	/// Number of clue bits checked; the false-positive rate is `2^-precision`.
+	pub fn precision(&self) -> usize {
+		self.precision as usize
+	}
+
+	/// Returns the secrets in clue key order.
+	pub fn secrets(&self) -> &[[u8; 32]] {
+		&self.secrets[..self.precision()]
+	}
+
+	/// Serializes to `precision(1) || secrets(32 * precision)`.
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::with_capacity(1 + self.precision() * 32);
+		bytes.push(self.precision);
+		for secret in self.secrets() {
+			bytes.extend_from_slice(secret);
+		}
+		bytes
+	}
+
+	/// Deserializes from the encoding produced by [`DetectionKey::to_bytes`].
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, MemoError> {
+		let (&precision, rest) = bytes
+			.split_first()
+			.ok_or(MemoError::InvalidDetectionEncoding)?;
+		if rest.len() != precision as usize * 32 {
+			return Err(MemoError::InvalidDetectionEncoding);
+		}
+
+		let mut secrets = [[0u8; 32]; MAX_DETECTION_PRECISION];
+		let count = rest.len() / 32;
+		if count > MAX_DETECTION_PRECISION {
+			return Err(MemoError::InvalidDetectionPrecision(count));
+		}
+		for (secret, chunk) in secrets.iter_mut().zip(rest.chunks_exact(32)) {
+			secret.copy_from_slice(chunk);
+		}
+		let key = Self::new(&secrets[..count]);
+		secrets.zeroize();
+		key
+	}
+}
+
+impl Zeroize for DetectionKey {
+	fn zeroize(&mut self) {
+		self.secrets.zeroize();
+	}
+}
+
+impl Drop for DetectionKey {
+	fn drop(&mut self) {
+		self.zeroize();
+	}
+}
+
+impl ZeroizeOnDrop for DetectionKey {}
+
+// ============================================================================
+// Detection Tag
+// ============================================================================
+
+/// Tag attached to a memo so detection servers can flag it.
+///
+/// Fixed serialized size: 34 bytes (`ephemeral_public(32) + clue_bits(2)`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+	all(feature = "parity-scale-codec", feature = "scale-info"),
+	derive(Encode, Decode, TypeInfo)
+)]
+pub struct DetectionTag {
+	/// Ephemeral X25519 public key of the tag
+	pub ephemeral_public: [u8; 32],
+	/// One masked bit per clue key entry, little-endian bit order
+	pub clue_bits: [u8; MAX_DETECTION_PRECISION / 8],
+}
+
+impl DetectionTag {
+	/// Returns clue bit `index` (0 or 1).
+	pub fn clue_bit(&self, index: usize) -> u8 {
+		(self.clue_bits[index / 8] >> (index % 8)) & 1
+	}
+
+	/// Serializes to bytes (fixed 34 bytes).
+	pub fn to_bytes(&self) -> [u8; DETECTION_TAG_SIZE] {
+		let mut bytes = [0u8; DETECTION_TAG_SIZE];
+		bytes[..32].copy_from_slice(&self.ephemeral_public);
+		bytes[32..].copy_from_slice(&self.clue_bits);
+		bytes
+	}
+
+	/// Deserializes from bytes (must be exactly 34 bytes).
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, MemoError> {
+		if bytes.len() != DETECTION_TAG_SIZE {
+			return Err(MemoError::InvalidDetectionEncoding);
+		}
+
+		let mut ephemeral_public = [0u8; 32];
+		let mut clue_bits = [0u8; MAX_DETECTION_PRECISION / 8];
+		ephemeral_public.copy_from_slice(&bytes[..32]);
+		clue_bits.copy_from_slice(&bytes[32..]);
+		Ok(Self {
+			ephemeral_public,
+			clue_bits,
+		})
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// ===== ClueKey Tests =====
+
+	#[test]
+	fn test_clue_key_bytes_roundtrip() {
+		let mut keys = [[0u8; 32]; MAX_DETECTION_PRECISION];
+		for (i, key) in keys.iter_mut().enumerate() {
+			*key = [i as u8; 32];
+		}
+		let clue_key = ClueKey(keys);
+		let bytes = clue_key.to_bytes();
+		assert_eq!(&bytes[32..64], &[1u8; 32]);
+		assert_eq!(ClueKey::from_bytes(&bytes), Ok(clue_key));
+	}
+
+	#[test]
+	fn test_clue_key_rejects_wrong_length() {
+		assert_eq!(
+			ClueKey::from_bytes(&[0u8; CLUE_KEY_SIZE - 1]),
+			Err(MemoError::InvalidDetectionEncoding)
+		);
+	}
+
+	// ===== DetectionKey Tests =====
+
+	#[test]
+	fn test_detection_key_precision_bounds() {
+		assert_eq!(
+			DetectionKey::new(&[]),
+			Err(MemoError::InvalidDetectionPrecision(0))
+		);
+		assert_eq!(
+			DetectionKey::new(&[[0u8; 32]; MAX_DETECTION_PRECISION + 1]),
+			Err(MemoError::InvalidDetectionPrecision(17))
+		);
+		let key = DetectionKey::new(&[[7u8; 32]; 4]).unwrap();
+		assert_eq!(key.precision(), 4);
+		assert_eq!(key.secrets(), &[[7u8; 32]; 4]);
+	}
+
+	#[test]
+	fn test_detection_key_bytes_roundtrip() {
+		let key = DetectionKey::new(&[[1u8; 32], [2u8; 32], [3u8; 32]]).unwrap();
+		let bytes = key.to_bytes();
+		assert_eq!(bytes.len(), 1 + 3 * 32);
+		assert_eq!(bytes[0], 3);
+		assert_eq!(DetectionKey::from_bytes(&bytes), Ok(key));
+	}
+
+	#[test]
+	fn test_detection_key_from_bytes_rejects_bad_encoding() {
+		assert_eq!(
+			DetectionKey::from_bytes(&[]),
+			Err(MemoError::InvalidDetectionEncoding)
+		);
+		assert_eq!(
+			DetectionKey::from_bytes(&[2u8; 1 + 32]),
+			Err(MemoError::InvalidDetectionEncoding)
+		);
+		assert_eq!(
+			DetectionKey::from_bytes(&[0u8]),
+			Err(MemoError::InvalidDetectionPrecision(0))
+		);
+	}
+
+	#[test]
+	fn test_detection_key_zeroize() {
+		let mut key = DetectionKey::new(&[[9u8; 32]; 2]).unwrap();
+		key.zeroize();
+		assert_eq!(key.secrets(), &[[0u8; 32]; 2]);
+	}
+
+	// ===== DetectionTag Tests =====
+
+	#[test]
+	fn test_detection_tag_bytes_roundtrip() {
+		let tag = DetectionTag {
+			ephemeral_public: [5u8; 32],
+			clue_bits: [0b0000_0101, 0b1000_0000],
+		};
+		let bytes = tag.to_bytes();
+		assert_eq!(bytes.len(), DETECTION_TAG_SIZE);
+		assert_eq!(DetectionTag::from_bytes(&bytes), Ok(tag));
+	}
+
+	#[test]
+	fn test_detection_tag_clue_bit_order() {
+		let tag = DetectionTag {
+			ephemeral_public: [0u8; 32],
+			clue_bits: [0b0000_0101, 0b1000_0000],
+		};
+		assert_eq!(tag.clue_bit(0), 1);
+		assert_eq!(tag.clue_bit(1), 0);
+		assert_eq!(tag.clue_bit(2), 1);
+		assert_eq!(tag.clue_bit(15), 1);
+		assert_eq!(tag.clue_bit(8), 0);
+	}
+
+	#[test]
+	fn test_detection_tag_rejects_wrong_length() {
+		assert_eq!(
+			DetectionTag::from_bytes(&[0u8; 33]),
+			Err(MemoError::InvalidDetectionEncoding)
+		);
+	}
+}
@@ -1,17 +1,21 @@
 //! EdDSA Key value object.
 //!
 //! BabyJubJub signing key for circuit ownership proofs (circom-compatible).
-//! Derived from spending key via `SHA256(spending_key || EDDSA_KEY_DOMAIN)`.
+//! Derived from the derivation key via `SHA256(derivation_key || EDDSA_KEY_DOMAIN)`.
 
 #[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
 use parity_scale_codec::{Decode, Encode};
 #[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
 use scale_info::TypeInfo;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// EdDSA signing key for circuit ownership proofs.
 ///
 /// BabyJubJub curve, compatible with circomlib.
-#[derive(Clone, PartialEq, Eq, Debug)]
+///
+/// Compared in constant time and zeroized on drop.
+#[derive(Clone, Debug)]
 #[cfg_attr(
 	all(feature = "parity-scale-codec", feature = "scale-info"),
 	derive(Encode, Decode, TypeInfo)
@@ -42,6 +46,34 @@ impl From<[u8; 32]> for EdDSAKey {
 	}
 }
 
+impl ConstantTimeEq for EdDSAKey {
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.0[..].ct_eq(&other.0[..])
+	}
+}
+
+impl PartialEq for EdDSAKey {
+	fn eq(&self, other: &Self) -> bool {
+		self.ct_eq(other).into()
+	}
+}
+
+impl Eq for EdDSAKey {}
+
+impl Zeroize for EdDSAKey {
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+impl Drop for EdDSAKey {
+	fn drop(&mut self) {
+		self.zeroize();
+	}
+}
+
+impl ZeroizeOnDrop for EdDSAKey {}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -106,4 +138,18 @@ mod tests {
 	fn test_all_ones() {
 		assert_eq!(EdDSAKey::from_bytes([0xFFu8; 32]).as_bytes(), &[0xFFu8; 32]);
 	}
+
+	#[test]
+	fn test_ct_eq() {
+		let k = EdDSAKey::from_bytes(BYTES);
+		assert!(bool::from(k.ct_eq(&EdDSAKey::from_bytes(BYTES))));
+		assert!(!bool::from(k.ct_eq(&EdDSAKey::from_bytes([0u8; 32]))));
+	}
+
+	#[test]
+	fn test_zeroize() {
+		let mut k = EdDSAKey::from_bytes(BYTES);
+		k.zeroize();
+		assert_eq!(k.as_bytes(), &[0u8; 32]);
+	}
 }
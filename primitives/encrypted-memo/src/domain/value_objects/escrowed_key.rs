@@ -0,0 +1,88 @@
+//! Escrowed Key value object.
+//!
+//! A memo's symmetric key wrapped to an auditor's agreement key, stored
+//! alongside the encrypted memo when policy mandates escrow.
+
+use crate::domain::{entities::error::MemoError, value_objects::constants::ESCROWED_KEY_SIZE};
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use scale_info::TypeInfo;
+
+/// Memo key encapsulated to an auditor.
+///
+/// Fixed serialized size: 80 bytes (`ephemeral_public(32) + wrapped_key(32 + 16)`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+	all(feature = "parity-scale-codec", feature = "scale-info"),
+	derive(Encode, Decode, TypeInfo)
+)]
+pub struct EscrowedKey {
+	/// Ephemeral X25519 public key of the encapsulation
+	pub ephemeral_public: [u8; 32],
+	/// ChaCha20Poly1305 encryption of the memo key (with MAC)
+	pub wrapped_key: [u8; 48],
+}
+
+impl EscrowedKey {
+	/// Serializes to bytes (fixed 80 bytes).
+	pub fn to_bytes(&self) -> [u8; ESCROWED_KEY_SIZE] {
+		let mut bytes = [0u8; ESCROWED_KEY_SIZE];
+		bytes[..32].copy_from_slice(&self.ephemeral_public);
+		bytes[32..].copy_from_slice(&self.wrapped_key);
+		bytes
+	}
+
+	/// Deserializes from bytes (must be exactly 80 bytes).
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, MemoError> {
+		if bytes.len() != ESCROWED_KEY_SIZE {
+			return Err(MemoError::InvalidEscrowedKey);
+		}
+
+		let mut ephemeral_public = [0u8; 32];
+		let mut wrapped_key = [0u8; 48];
+		ephemeral_public.copy_from_slice(&bytes[..32]);
+		wrapped_key.copy_from_slice(&bytes[32..]);
+		Ok(Self {
+			ephemeral_public,
+			wrapped_key,
+		})
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sample() -> EscrowedKey {
+		EscrowedKey {
+			ephemeral_public: [1u8; 32],
+			wrapped_key: [2u8; 48],
+		}
+	}
+
+	#[test]
+	fn test_bytes_roundtrip() {
+		let key = sample();
+		let bytes = key.to_bytes();
+		assert_eq!(bytes.len(), ESCROWED_KEY_SIZE);
+		assert_eq!(&bytes[..32], &[1u8; 32]);
+		assert_eq!(EscrowedKey::from_bytes(&bytes), Ok(key));
+	}
+
+	#[test]
+	fn test_from_bytes_rejects_wrong_length() {
+		assert_eq!(
+			EscrowedKey::from_bytes(&[0u8; 79]),
+			Err(MemoError::InvalidEscrowedKey)
+		);
+		assert_eq!(
+			EscrowedKey::from_bytes(&[0u8; 81]),
+			Err(MemoError::InvalidEscrowedKey)
+		);
+	}
+}
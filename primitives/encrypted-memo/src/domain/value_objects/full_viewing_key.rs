@@ -0,0 +1,92 @@
+//! Full Viewing Key value object.
+//!
+//! Pairs the incoming viewing key (decrypts received notes) with the outgoing
+//! viewing key (reconstructs sent memos). Either half can be shared on its
+//! own: an auditor of incoming payments never needs the outgoing key.
+
+use crate::domain::{
+	entities::error::MemoError,
+	value_objects::{constants::FULL_VIEWING_KEY_SIZE, IncomingViewingKey, OutgoingViewingKey},
+};
+
+/// Incoming and outgoing viewing keys of one wallet.
+///
+/// Fixed serialized size: 64 bytes (`incoming(32) + outgoing(32)`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FullViewingKey {
+	/// Decrypts notes received by the wallet
+	pub incoming: IncomingViewingKey,
+	/// Recovers memos the wallet sent
+	pub outgoing: OutgoingViewingKey,
+}
+
+impl FullViewingKey {
+	/// Creates a full viewing key from its two halves.
+	pub fn new(incoming: IncomingViewingKey, outgoing: OutgoingViewingKey) -> Self {
+		Self { incoming, outgoing }
+	}
+
+	/// Serializes to bytes (fixed 64 bytes).
+	pub fn to_bytes(&self) -> [u8; FULL_VIEWING_KEY_SIZE] {
+		let mut bytes = [0u8; FULL_VIEWING_KEY_SIZE];
+		bytes[..32].copy_from_slice(self.incoming.as_bytes());
+		bytes[32..].copy_from_slice(self.outgoing.as_bytes());
+		bytes
+	}
+
+	/// Deserializes from bytes (must be exactly 64 bytes).
+	///
+	/// # Errors
+	/// `InvalidFullViewingKey` on a wrong length.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, MemoError> {
+		if bytes.len() != FULL_VIEWING_KEY_SIZE {
+			return Err(MemoError::InvalidFullViewingKey);
+		}
+
+		let mut incoming = [0u8; 32];
+		let mut outgoing = [0u8; 32];
+		incoming.copy_from_slice(&bytes[..32]);
+		outgoing.copy_from_slice(&bytes[32..]);
+		Ok(Self::new(
+			IncomingViewingKey::from_bytes(incoming),
+			OutgoingViewingKey::from_bytes(outgoing),
+		))
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sample() -> FullViewingKey {
+		FullViewingKey::new(
+			IncomingViewingKey::from_bytes([1u8; 32]),
+			OutgoingViewingKey::from_bytes([2u8; 32]),
+		)
+	}
+
+	#[test]
+	fn test_bytes_roundtrip() {
+		let fvk = sample();
+		let bytes = fvk.to_bytes();
+		assert_eq!(&bytes[..32], &[1u8; 32]);
+		assert_eq!(&bytes[32..], &[2u8; 32]);
+		assert_eq!(FullViewingKey::from_bytes(&bytes), Ok(fvk));
+	}
+
+	#[test]
+	fn test_from_bytes_wrong_length() {
+		assert_eq!(
+			FullViewingKey::from_bytes(&[0u8; 32]),
+			Err(MemoError::InvalidFullViewingKey)
+		);
+		assert_eq!(
+			FullViewingKey::from_bytes(&[0u8; 65]),
+			Err(MemoError::InvalidFullViewingKey)
+		);
+	}
+}
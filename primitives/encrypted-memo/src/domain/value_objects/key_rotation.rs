@@ -0,0 +1,120 @@
+//! Key rotation statement.
+//!
+//! Public statement that two viewing key generations belong to the same
+//! spending key. It carries only commitments to the keys, so it can be posted
+//! on-chain as the public input of a key-rotation proof without revealing
+//! either viewing key.
+
+use crate::domain::{
+	entities::error::MemoError, value_objects::constants::KEY_ROTATION_STATEMENT_SIZE,
+};
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use scale_info::TypeInfo;
+
+/// Statement that `new_commitment` supersedes `old_commitment`.
+///
+/// Fixed serialized size: 72 bytes
+/// (`from_generation(4) + to_generation(4) + old_commitment(32) + new_commitment(32)`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(
+	all(feature = "parity-scale-codec", feature = "scale-info"),
+	derive(Encode, Decode, TypeInfo)
+)]
+pub struct KeyRotationStatement {
+	/// Generation of the retired viewing key
+	pub from_generation: u32,
+	/// Generation of the replacement viewing key
+	pub to_generation: u32,
+	/// Commitment to the retired viewing key
+	pub old_commitment: [u8; 32],
+	/// Commitment to the replacement viewing key
+	pub new_commitment: [u8; 32],
+}
+
+impl KeyRotationStatement {
+	/// Serializes to bytes (fixed 72 bytes, little-endian generations).
+	pub fn to_bytes(&self) -> [u8; KEY_ROTATION_STATEMENT_SIZE] {
+		let mut bytes = [0u8; KEY_ROTATION_STATEMENT_SIZE];
+		bytes[..4].copy_from_slice(&self.from_generation.to_le_bytes());
+		bytes[4..8].copy_from_slice(&self.to_generation.to_le_bytes());
+		bytes[8..40].copy_from_slice(&self.old_commitment);
+		bytes[40..].copy_from_slice(&self.new_commitment);
+		bytes
+	}
+
+	/// Deserializes from bytes (must be exactly 72 bytes).
+	///
+	/// # Errors
+	/// `InvalidKeyRotation` on a wrong length or a non-increasing generation.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, MemoError> {
+		if bytes.len() != KEY_ROTATION_STATEMENT_SIZE {
+			return Err(MemoError::InvalidKeyRotation);
+		}
+
+		let mut generation = [0u8; 4];
+		generation.copy_from_slice(&bytes[..4]);
+		let from_generation = u32::from_le_bytes(generation);
+		generation.copy_from_slice(&bytes[4..8]);
+		let to_generation = u32::from_le_bytes(generation);
+		if to_generation <= from_generation {
+			return Err(MemoError::InvalidKeyRotation);
+		}
+
+		let mut old_commitment = [0u8; 32];
+		let mut new_commitment = [0u8; 32];
+		old_commitment.copy_from_slice(&bytes[8..40]);
+		new_commitment.copy_from_slice(&bytes[40..]);
+		Ok(Self {
+			from_generation,
+			to_generation,
+			old_commitment,
+			new_commitment,
+		})
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn statement() -> KeyRotationStatement {
+		KeyRotationStatement {
+			from_generation: 1,
+			to_generation: 2,
+			old_commitment: [3u8; 32],
+			new_commitment: [4u8; 32],
+		}
+	}
+
+	#[test]
+	fn test_statement_bytes_roundtrip() {
+		let bytes = statement().to_bytes();
+		assert_eq!(&bytes[..8], &[1, 0, 0, 0, 2, 0, 0, 0]);
+		assert_eq!(&bytes[8..40], &[3u8; 32]);
+		assert_eq!(KeyRotationStatement::from_bytes(&bytes), Ok(statement()));
+	}
+
+	#[test]
+	fn test_statement_rejects_wrong_length() {
+		assert_eq!(
+			KeyRotationStatement::from_bytes(&[0u8; KEY_ROTATION_STATEMENT_SIZE - 1]),
+			Err(MemoError::InvalidKeyRotation)
+		);
+	}
+
+	#[test]
+	fn test_statement_rejects_non_increasing_generation() {
+		let mut bytes = statement().to_bytes();
+		bytes[4] = 1;
+		assert_eq!(
+			KeyRotationStatement::from_bytes(&bytes),
+			Err(MemoError::InvalidKeyRotation)
+		);
+	}
+}
@@ -4,16 +4,37 @@
 //!
 //! ## Modules
 //!
+//! - [`asset_scope`]  - Assets whose memos use a per-asset viewing key
 //! - [`constants`]    - Size limits and domain separators
-//! - [`viewing_key`]  - Read-only auditable key
+//! - [`derivation_key`] - Intermediate secret behind the viewing, nullifier and EdDSA keys
+//! - [`viewing_key`]  - Read-only auditable key (incoming viewing key)
+//! - [`outgoing_viewing_key`] - Key for recovering sent memos
+//! - [`full_viewing_key`] - Incoming and outgoing viewing key pair
 //! - [`nullifier_key`] - Key for nullifier derivation
 //! - [`eddsa_key`]    - Circuit signing key (BabyJubJub)
+//! - [`escrowed_key`] - Memo key wrapped to an auditor
+//! - [`detection_key`] - Fuzzy message detection keys and tags
+//! - [`key_rotation`] - Statement linking two viewing key generations
 
+pub mod asset_scope;
 pub mod constants;
+pub mod derivation_key;
+pub mod detection_key;
 pub mod eddsa_key;
+pub mod escrowed_key;
+pub mod full_viewing_key;
+pub mod key_rotation;
 pub mod nullifier_key;
+pub mod outgoing_viewing_key;
 pub mod viewing_key;
 
+pub use asset_scope::AssetScope;
+pub use derivation_key::DerivationKey;
+pub use detection_key::{ClueKey, DetectionKey, DetectionTag};
 pub use eddsa_key::EdDSAKey;
+pub use escrowed_key::EscrowedKey;
+pub use full_viewing_key::FullViewingKey;
+pub use key_rotation::KeyRotationStatement;
 pub use nullifier_key::NullifierKey;
-pub use viewing_key::ViewingKey;
+pub use outgoing_viewing_key::OutgoingViewingKey;
+pub use viewing_key::{IncomingViewingKey, ViewingKey};
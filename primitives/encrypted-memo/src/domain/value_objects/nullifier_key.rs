@@ -1,17 +1,21 @@
 //! Nullifier Key value object.
 //!
 //! Used to derive nullifiers for spent notes, preventing double-spending.
-//! Derived from spending key via `SHA256(spending_key || NULLIFIER_KEY_DOMAIN)`.
+//! Derived from the derivation key via `SHA256(derivation_key || NULLIFIER_KEY_DOMAIN)`.
 
 #[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
 use parity_scale_codec::{Decode, Encode};
 #[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
 use scale_info::TypeInfo;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Nullifier derivation key.
 ///
 /// Used to compute `nullifier = Poseidon(commitment, nullifier_key)`.
-#[derive(Clone, PartialEq, Eq, Debug)]
+///
+/// Compared in constant time and zeroized on drop.
+#[derive(Clone, Debug)]
 #[cfg_attr(
 	all(feature = "parity-scale-codec", feature = "scale-info"),
 	derive(Encode, Decode, TypeInfo)
@@ -42,6 +46,34 @@ impl From<[u8; 32]> for NullifierKey {
 	}
 }
 
+impl ConstantTimeEq for NullifierKey {
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.0[..].ct_eq(&other.0[..])
+	}
+}
+
+impl PartialEq for NullifierKey {
+	fn eq(&self, other: &Self) -> bool {
+		self.ct_eq(other).into()
+	}
+}
+
+impl Eq for NullifierKey {}
+
+impl Zeroize for NullifierKey {
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+impl Drop for NullifierKey {
+	fn drop(&mut self) {
+		self.zeroize();
+	}
+}
+
+impl ZeroizeOnDrop for NullifierKey {}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -109,4 +141,18 @@ mod tests {
 			&[0xFFu8; 32]
 		);
 	}
+
+	#[test]
+	fn test_ct_eq() {
+		let k = NullifierKey::from_bytes(BYTES);
+		assert!(bool::from(k.ct_eq(&NullifierKey::from_bytes(BYTES))));
+		assert!(!bool::from(k.ct_eq(&NullifierKey::from_bytes([0u8; 32]))));
+	}
+
+	#[test]
+	fn test_zeroize() {
+		let mut k = NullifierKey::from_bytes(BYTES);
+		k.zeroize();
+		assert_eq!(k.as_bytes(), &[0u8; 32]);
+	}
 }
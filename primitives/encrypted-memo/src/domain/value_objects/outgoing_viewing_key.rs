@@ -0,0 +1,108 @@
+//! Outgoing Viewing Key value object.
+//!
+//! Lets a sender reconstruct the memos it sent. Derived from the derivation
+//! key via `SHA256(derivation_key || OUTGOING_VIEWING_KEY_DOMAIN)`, independent
+//! of the incoming [`ViewingKey`](super::ViewingKey).
+
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
+use scale_info::TypeInfo;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Outgoing viewing key for recovering sent memos.
+///
+/// Cannot decrypt received notes. Compared in constant time and zeroized on
+/// drop.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+	all(feature = "parity-scale-codec", feature = "scale-info"),
+	derive(Encode, Decode, TypeInfo)
+)]
+pub struct OutgoingViewingKey(pub [u8; 32]);
+
+impl OutgoingViewingKey {
+	/// Creates an outgoing viewing key from raw bytes.
+	pub fn from_bytes(bytes: [u8; 32]) -> Self {
+		Self(bytes)
+	}
+
+	/// Returns the raw key bytes.
+	pub fn as_bytes(&self) -> &[u8; 32] {
+		&self.0
+	}
+}
+
+impl AsRef<[u8; 32]> for OutgoingViewingKey {
+	fn as_ref(&self) -> &[u8; 32] {
+		&self.0
+	}
+}
+
+impl From<[u8; 32]> for OutgoingViewingKey {
+	fn from(bytes: [u8; 32]) -> Self {
+		Self(bytes)
+	}
+}
+
+impl ConstantTimeEq for OutgoingViewingKey {
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.0[..].ct_eq(&other.0[..])
+	}
+}
+
+impl PartialEq for OutgoingViewingKey {
+	fn eq(&self, other: &Self) -> bool {
+		self.ct_eq(other).into()
+	}
+}
+
+impl Eq for OutgoingViewingKey {}
+
+impl Zeroize for OutgoingViewingKey {
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+impl Drop for OutgoingViewingKey {
+	fn drop(&mut self) {
+		self.zeroize();
+	}
+}
+
+impl ZeroizeOnDrop for OutgoingViewingKey {}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const BYTES: [u8; 32] = [42u8; 32];
+
+	#[test]
+	fn test_from_bytes_roundtrip() {
+		let k = OutgoingViewingKey::from_bytes(BYTES);
+		assert_eq!(k.as_bytes(), &BYTES);
+		assert_eq!(OutgoingViewingKey::from(BYTES), k);
+	}
+
+	#[test]
+	fn test_ne_different_bytes() {
+		assert_ne!(
+			OutgoingViewingKey::from_bytes([1u8; 32]),
+			OutgoingViewingKey::from_bytes([2u8; 32])
+		);
+	}
+
+	#[test]
+	fn test_zeroize() {
+		let mut k = OutgoingViewingKey::from_bytes(BYTES);
+		k.zeroize();
+		assert_eq!(k.as_bytes(), &[0u8; 32]);
+	}
+}
@@ -1,23 +1,35 @@
 //! Viewing Key value object.
 //!
-//! Read-only key shareable with auditors. Derived from the spending key via
-//! `SHA256(spending_key || VIEWING_KEY_DOMAIN)`.
+//! Read-only key shareable with auditors. Derived from the derivation key via
+//! `SHA256(derivation_key || VIEWING_KEY_DOMAIN)`.
+//!
+//! This is the incoming half of a [`FullViewingKey`](super::FullViewingKey);
+//! [`IncomingViewingKey`] names it in that role.
 
 #[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
 use parity_scale_codec::{Decode, Encode};
 #[cfg(all(feature = "parity-scale-codec", feature = "scale-info"))]
 use scale_info::TypeInfo;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Viewing key for memo decryption.
 ///
 /// Provides read-only access to note contents. Safe to share with auditors.
-#[derive(Clone, PartialEq, Eq, Debug)]
+///
+/// Compared in constant time and zeroized on drop.
+#[derive(Clone, Debug)]
 #[cfg_attr(
 	all(feature = "parity-scale-codec", feature = "scale-info"),
 	derive(Encode, Decode, TypeInfo)
 )]
 pub struct ViewingKey(pub [u8; 32]);
 
+/// Viewing key that decrypts received notes.
+///
+/// Same key as [`ViewingKey`]; existing single-key wallets already hold it.
+pub type IncomingViewingKey = ViewingKey;
+
 impl ViewingKey {
 	/// Creates a viewing key from raw bytes.
 	pub fn from_bytes(bytes: [u8; 32]) -> Self {
@@ -42,6 +54,34 @@ impl From<[u8; 32]> for ViewingKey {
 	}
 }
 
+impl ConstantTimeEq for ViewingKey {
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.0[..].ct_eq(&other.0[..])
+	}
+}
+
+impl PartialEq for ViewingKey {
+	fn eq(&self, other: &Self) -> bool {
+		self.ct_eq(other).into()
+	}
+}
+
+impl Eq for ViewingKey {}
+
+impl Zeroize for ViewingKey {
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
+impl Drop for ViewingKey {
+	fn drop(&mut self) {
+		self.zeroize();
+	}
+}
+
+impl ZeroizeOnDrop for ViewingKey {}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -109,4 +149,18 @@ mod tests {
 			&[0xFFu8; 32]
 		);
 	}
+
+	#[test]
+	fn test_ct_eq() {
+		let k = ViewingKey::from_bytes(BYTES);
+		assert!(bool::from(k.ct_eq(&ViewingKey::from_bytes(BYTES))));
+		assert!(!bool::from(k.ct_eq(&ViewingKey::from_bytes([0u8; 32]))));
+	}
+
+	#[test]
+	fn test_zeroize() {
+		let mut k = ViewingKey::from_bytes(BYTES);
+		k.zeroize();
+		assert_eq!(k.as_bytes(), &[0u8; 32]);
+	}
 }
@@ -2,17 +2,39 @@
 //!
 //! ## Features
 //!
-//! - **Encryption**: Per-note key derivation from viewing key + commitment
+//! - **Encryption**: Per-note key derivation from viewing key + commitment;
+//!   ChaCha20Poly1305 or XChaCha20Poly1305 (24-byte random nonces)
+//! - **Versioning**: Versioned memo envelopes; V1 memos stay decryptable;
+//!   V3 payloads carry bounded application data;
+//!   `MemoCodec` decodes any envelope into a `DecodedMemo`
 //! - **Disclosure**: Selective disclosure proof structures (Groth16)
-//! - **Key Derivation**: SHA-256 based with domain separation
+//! - **Key Derivation**: SHA-256 based with domain separation, through an
+//!   intermediate derivation key a hardware wallet can export
+//! - **Key Agreement**: X25519 ECDH so senders encrypt to a published address
+//! - **Ephemeral Keys**: Per-memo ephemeral X25519 keys embedded in the envelope
+//! - **Multi-Recipient**: One payload with a wrapped content key per viewing key
+//! - **Key Escrow**: KEM-style wrapping of memo keys to an auditor's agreement key,
+//!   optionally carried inside the memo (escrow mode)
+//! - **Post-Quantum**: `pq` feature adds a hybrid X25519 + ML-KEM-768 envelope
+//! - **Off-chain Memos**: On-chain content-hash pointers to off-chain ciphertexts
+//! - **Streaming**: Chunked STREAM encryption for attachments beyond the memo bound
+//! - **Key Rotation**: Viewing key generations, memo re-encryption and rotation
+//!   statements
+//! - **Outgoing Viewing Keys**: Incoming/outgoing viewing key split so senders can
+//!   recover memos they sent; legacy single-key wallets upgrade in place
+//! - **Asset-Scoped Keys**: Per-asset viewing keys for single-asset auditors
+//! - **Backups**: `backup` feature adds Argon2id + ChaCha20Poly1305 KeySet export/import
+//! - **Detection**: Fuzzy message detection tags with tunable false-positive rates;
+//!   `detect_only` checks ownership from the MAC alone
+//! - **WebAssembly**: `wasm` feature exports memo decryption to browser wallets
 //!
 //! ## Architecture
 //!
 //! Clean Architecture — domain layer only, no FRAME dependencies:
 //! - **value_objects**: Immutable keys and constants
-//! - **entities**: `MemoData` entity + `MemoError`
-//! - **ports**: Abstract interfaces (`MemoEncryptor`, `KeyDeriver`)
-//! - **aggregates**: `KeySet`, disclosure structures
+//! - **entities**: `MemoData` / `MemoDataV2` / `MemoDataV3` payloads, `VersionedMemo` + `MemoError`
+//! - **ports**: Abstract interfaces (`MemoEncryptor`, `KeyDeriver`, `EdDSASigner`)
+//! - **aggregates**: `KeySet`, `DerivedKeySet`, disclosure structures
 //! - **services**: Concrete implementations of the ports
 //!
 //! ## Example
@@ -37,41 +59,180 @@ extern crate alloc;
 // Domain layer - pure business logic
 pub mod domain;
 
+// WebAssembly bindings
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 // ============================================================================
 // Public API
 // ============================================================================
 
 // Constants
 pub use domain::value_objects::constants::{
-	EDDSA_KEY_DOMAIN, KEY_DOMAIN, MAC_SIZE, MAX_ENCRYPTED_MEMO_SIZE, MEMO_DATA_SIZE,
-	MIN_ENCRYPTED_MEMO_SIZE, NONCE_SIZE, NULLIFIER_KEY_DOMAIN, VIEWING_KEY_DOMAIN,
+	AGREEMENT_KEY_DOMAIN, AGREEMENT_KEY_SIZE, ASSET_VIEWING_KEY_DOMAIN, BACKUP_SALT_SIZE,
+	CLUE_KEY_SIZE, DERIVATION_KEY_DOMAIN, DETECTION_KEY_DOMAIN, DETECTION_TAG_DOMAIN,
+	DETECTION_TAG_SIZE, EDDSA_KEY_DOMAIN, ENCRYPTED_MEMO_EPHEMERAL_SIZE,
+	ENCRYPTED_MEMO_ESCROW_SIZE, ENCRYPTED_MEMO_HYBRID_SIZE, ENCRYPTED_MEMO_V2_SIZE,
+	ENCRYPTED_MEMO_V3_SIZE, ENCRYPTED_MEMO_XCHACHA_SIZE, ESCROWED_KEY_SIZE, ESCROW_KEY_DOMAIN,
+	FULL_VIEWING_KEY_SIZE, HYBRID_KEY_DOMAIN, KEYSET_BACKUP_HEADER_SIZE, KEYSET_BACKUP_VERSION,
+	KEY_DOMAIN, KEY_ROTATION_STATEMENT_SIZE, LEGACY_OUTGOING_VIEWING_KEY_DOMAIN, MAC_SIZE,
+	MAX_APP_DATA_SIZE, MAX_COMPRESSED_MEMO_SIZE, MAX_DETECTION_PRECISION, MAX_ENCRYPTED_MEMO_SIZE,
+	MAX_MEMO_RECIPIENTS, MAX_MULTI_RECIPIENT_MEMO_SIZE, MAX_ONCHAIN_MEMO_SIZE, MEMO_DATA_SIZE,
+	MEMO_DATA_V2_SIZE, MEMO_DATA_V3_SIZE, MEMO_FLAG_COMPRESSED, MEMO_POINTER_SIZE,
+	MEMO_VERSION_EPHEMERAL, MEMO_VERSION_ESCROW, MEMO_VERSION_HYBRID, MEMO_VERSION_MULTI,
+	MEMO_VERSION_POINTER, MEMO_VERSION_SIZE, MEMO_VERSION_STREAM, MEMO_VERSION_TAGGED,
+	MEMO_VERSION_V1, MEMO_VERSION_V2, MEMO_VERSION_V3, MEMO_VERSION_XCHACHA,
+	MIN_COMPRESSED_MEMO_SIZE, MIN_ENCRYPTED_MEMO_SIZE, MIN_MULTI_RECIPIENT_MEMO_SIZE,
+	ML_KEM_CIPHERTEXT_SIZE, ML_KEM_PUBLIC_KEY_SIZE, NONCE_SIZE, NULLIFIER_KEY_DOMAIN,
+	OUTGOING_VIEWING_KEY_DOMAIN, PQ_KEY_DOMAIN, RECIPIENT_WRAP_KEY_DOMAIN, SENDER_HINT_SIZE,
+	SHARED_KEY_DOMAIN, STREAM_CHUNK_SIZE, STREAM_HEADER_SIZE, STREAM_KEY_DOMAIN,
+	STREAM_NONCE_PREFIX_SIZE, STREAM_SEALED_CHUNK_SIZE, VIEWING_KEY_COMMITMENT_DOMAIN,
+	VIEWING_KEY_DOMAIN, VIEWING_KEY_ROTATION_DOMAIN, WRAPPED_MEMO_KEY_SIZE, XNONCE_SIZE,
 };
 
 // Value objects (keys)
-pub use domain::value_objects::{EdDSAKey, NullifierKey, ViewingKey};
+pub use domain::value_objects::{
+	AssetScope, ClueKey, DetectionKey, DetectionTag, EdDSAKey, EscrowedKey, FullViewingKey,
+	IncomingViewingKey, KeyRotationStatement, NullifierKey, OutgoingViewingKey, ViewingKey,
+};
 
 // Core entity and error
-pub use domain::entities::{error::MemoError, is_valid_encrypted_memo, memo_data::MemoData};
+pub use domain::entities::{
+	decoded_memo::{DecodedMemo, MemoFormat},
+	error::MemoError,
+	is_valid_encrypted_memo, is_valid_onchain_memo,
+	memo_data::MemoData,
+	memo_data_v2::MemoDataV2,
+	memo_data_v3::MemoDataV3,
+	versioned_memo::VersionedMemo,
+};
 
 // Key set aggregate
-pub use domain::aggregates::keyset::KeySet;
+pub use domain::aggregates::{derived_keyset::DerivedKeySet, keyset::KeySet};
 
 // Disclosure aggregates
 pub use domain::aggregates::disclosure::{
-	DisclosureMask, DisclosureProof, DisclosurePublicSignals, PartialMemoData,
+	disclosed_data_size, validate_mask_bitmap, DisclosureMask, DisclosureProof,
+	DisclosurePublicSignals, ExtendedDisclosureMask, MemoField, PartialMemoData, MEMO_FIELD_BITS,
 };
 
 // Ports (abstract interfaces)
-pub use domain::ports::{KeyDeriver, MemoEncryptor};
+pub use domain::ports::{EdDSASigner, KeyDeriver, MemoEncryptor};
 
 // Encryption services
-pub use domain::services::encryption::{decrypt_memo, encrypt_memo, try_decrypt_memo};
+pub use domain::services::encryption::{
+	decrypt_memo, decrypt_memo_from_sender, detect_only, encrypt_memo, encrypt_memo_ephemeral,
+	encrypt_memo_to_address, encrypt_memo_v2, encrypt_memo_v2_compressed,
+	encrypt_memo_v2_to_address, encrypt_memo_v3, encrypt_memo_xchacha, try_decrypt_memo,
+	try_decrypt_memo_from_sender,
+};
+
+#[cfg(feature = "encrypt")]
+pub use domain::services::encryption::{
+	encrypt_memo_ephemeral_random, encrypt_memo_random, encrypt_memo_to_address_random,
+	encrypt_memo_v2_compressed_random, encrypt_memo_v2_random, encrypt_memo_v3_random,
+	encrypt_memo_xchacha_random,
+};
+
+// Format-dispatching decoder
+pub use domain::services::codec::MemoCodec;
+
+// Payload compression
+pub use domain::services::compression::{compress_payload, decompress_payload};
+
+// Multi-recipient services
+pub use domain::services::multi_recipient::{
+	decrypt_memo_multi, encrypt_memo_multi, multi_recipient_memo_size, try_decrypt_memo_multi,
+};
+
+#[cfg(feature = "encrypt")]
+pub use domain::services::multi_recipient::encrypt_memo_multi_random;
+
+// Off-chain memo services
+pub use domain::services::offchain::{
+	decrypt_offchain_memo, decrypt_offchain_stream, encrypt_offchain_stream, memo_pointer,
+	offchain_memo_hash, pointer_content_hash, verify_offchain_memo,
+};
+
+#[cfg(feature = "encrypt")]
+pub use domain::services::offchain::encrypt_offchain_stream_random;
+
+// Streaming AEAD services
+pub use domain::services::stream::{
+	decrypt_stream, encrypt_stream, stream_ciphertext_len, StreamDecryptor, StreamEncryptor,
+};
+
+#[cfg(feature = "encrypt")]
+pub use domain::services::stream::encrypt_stream_random;
+
+// Asset-scoped viewing key services
+#[cfg(feature = "encrypt")]
+pub use domain::services::asset_scope::encrypt_memo_scoped_random;
+pub use domain::services::asset_scope::{
+	decrypt_memo_scoped, encrypt_memo_scoped, memo_viewing_key, try_decrypt_memo_scoped,
+};
+
+// Outgoing memo services
+#[cfg(feature = "encrypt")]
+pub use domain::services::outgoing::encrypt_outgoing_memo_random;
+pub use domain::services::outgoing::{
+	decode_full_viewing_key, encrypt_outgoing_memo, recover_outgoing_memo,
+	try_recover_outgoing_memo, upgrade_legacy_viewing_key,
+};
+
+// Key rotation services
+pub use domain::services::rotation::{
+	key_rotation_statement, reencrypt_memo, verify_key_rotation_statement,
+};
+
+#[cfg(feature = "encrypt")]
+pub use domain::services::rotation::reencrypt_memo_random;
+
+// KeySet backup services
+#[cfg(feature = "backup")]
+pub use domain::services::backup::{export_keyset, import_keyset, BackupParams};
+
+#[cfg(all(feature = "backup", feature = "encrypt"))]
+pub use domain::services::backup::export_keyset_random;
+
+// Auditor escrow services
+pub use domain::services::escrow::{
+	decrypt_attachment_with_escrow, decrypt_escrowed_memo, decrypt_memo_with_escrow,
+	encrypt_memo_escrowed, encrypt_memo_to_address_with_escrow, encrypt_memo_with_escrow,
+	escrow_memo_key, escrow_slot, open_escrowed_key,
+};
+
+#[cfg(feature = "encrypt")]
+pub use domain::services::escrow::{encrypt_memo_escrowed_random, encrypt_memo_with_escrow_random};
+
+// Hybrid post-quantum services
+#[cfg(feature = "pq")]
+pub use domain::services::hybrid::{decrypt_memo_hybrid, encrypt_memo_hybrid};
+
+#[cfg(all(feature = "pq", feature = "encrypt"))]
+pub use domain::services::hybrid::encrypt_memo_hybrid_random;
+
+#[cfg(feature = "pq")]
+pub use domain::services::key_derivation::{
+	derive_hybrid_recipient_key, derive_hybrid_sender_key, derive_pq_public_key, HybridSenderKey,
+};
+
+// Fuzzy message detection services
+pub use domain::services::detection::{
+	create_detection_tag, detect, detection_tag, is_flagged, tag_memo,
+};
 
 #[cfg(feature = "encrypt")]
-pub use domain::services::encryption::encrypt_memo_random;
+pub use domain::services::detection::create_detection_tag_random;
 
 // Key derivation services
 pub use domain::services::key_derivation::{
-	derive_eddsa_key_from_spending, derive_nullifier_key_from_spending,
-	derive_viewing_key_from_spending,
+	derive_agreement_public_key, derive_asset_viewing_key, derive_clue_key, derive_derivation_key,
+	derive_detection_key, derive_eddsa_key, derive_eddsa_key_from_spending,
+	derive_escrow_wrapping_key, derive_legacy_outgoing_viewing_key, derive_nullifier_key,
+	derive_nullifier_key_from_spending, derive_outgoing_viewing_key,
+	derive_outgoing_viewing_key_from_spending, derive_recipient_shared_key,
+	derive_recipient_wrapping_key, derive_sender_shared_key, derive_stream_key, derive_viewing_key,
+	derive_viewing_key_at_generation, derive_viewing_key_from_spending,
+	derive_viewing_key_generation, recover_escrow_wrapping_key, viewing_key_commitment,
 };
@@ -0,0 +1,169 @@
+//! WebAssembly bindings for browser wallets
+//!
+//! `wasm-bindgen` wrappers over memo decryption and key derivation, so wallets
+//! scan with the same code the chain's tooling uses. Keys and commitments are
+//! 32-byte arrays; decrypted memos are returned as their payload layout (76-byte
+//! `MemoData` for V1, 100-byte `MemoDataV2` for V2, 133-byte `MemoDataV3` for V3).
+
+use alloc::{string::ToString, vec::Vec};
+use wasm_bindgen::prelude::*;
+
+use crate::domain::services::{
+	encryption::{
+		decrypt_memo as decrypt, detect_only as detect, try_decrypt_memo as try_decrypt,
+		try_decrypt_memo_from_sender as try_decrypt_from_sender,
+	},
+	key_derivation::{derive_agreement_public_key, derive_viewing_key_from_spending},
+};
+
+/// Decrypts a memo, returning the `MemoData` (76), `MemoDataV2` (100) or
+/// `MemoDataV3` (133) encoding
+#[wasm_bindgen(js_name = decryptMemo)]
+pub fn decrypt_memo(
+	encrypted: &[u8],
+	commitment: &[u8],
+	viewing_key: &[u8],
+) -> Result<Vec<u8>, JsError> {
+	let commitment = to_array(commitment).map_err(JsError::new)?;
+	let viewing_key = to_array(viewing_key).map_err(JsError::new)?;
+
+	decrypt(encrypted, &commitment, &viewing_key)
+		.map(|memo| memo.payload_bytes())
+		.map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// Trial decryption for note scanning (`undefined` if the memo is not ours)
+#[wasm_bindgen(js_name = tryDecryptMemo)]
+pub fn try_decrypt_memo(
+	encrypted: &[u8],
+	commitment: &[u8],
+	viewing_key: &[u8],
+) -> Option<Vec<u8>> {
+	let commitment = to_array(commitment).ok()?;
+	let viewing_key = to_array(viewing_key).ok()?;
+
+	try_decrypt(encrypted, &commitment, &viewing_key).map(|memo| memo.payload_bytes())
+}
+
+/// MAC-only ownership check for rescans (`false` if the memo is not ours)
+#[wasm_bindgen(js_name = detectOnly)]
+pub fn detect_only(encrypted: &[u8], commitment: &[u8], viewing_key: &[u8]) -> bool {
+	let (Ok(commitment), Ok(viewing_key)) = (to_array(commitment), to_array(viewing_key)) else {
+		return false;
+	};
+
+	detect(encrypted, &commitment, &viewing_key)
+}
+
+/// Derives the viewing key from a spending key
+#[wasm_bindgen(js_name = deriveViewingKey)]
+pub fn derive_viewing_key(spending_key: &[u8]) -> Result<Vec<u8>, JsError> {
+	let spending_key = to_array(spending_key).map_err(JsError::new)?;
+	Ok(derive_viewing_key_from_spending(&spending_key)
+		.as_bytes()
+		.to_vec())
+}
+
+/// Trial decryption of a memo sent to the agreement key (`undefined` if not ours)
+#[wasm_bindgen(js_name = tryDecryptMemoFromSender)]
+pub fn try_decrypt_memo_from_sender(
+	encrypted: &[u8],
+	commitment: &[u8],
+	viewing_key: &[u8],
+	ephemeral_public: &[u8],
+) -> Option<Vec<u8>> {
+	let commitment = to_array(commitment).ok()?;
+	let viewing_key = to_array(viewing_key).ok()?;
+	let ephemeral_public = to_array(ephemeral_public).ok()?;
+
+	try_decrypt_from_sender(encrypted, &commitment, &viewing_key, &ephemeral_public)
+		.map(|memo| memo.payload_bytes())
+}
+
+/// Derives the X25519 agreement key to publish in an address
+#[wasm_bindgen(js_name = deriveAgreementKey)]
+pub fn derive_agreement_key(viewing_key: &[u8]) -> Result<Vec<u8>, JsError> {
+	let viewing_key = to_array(viewing_key).map_err(JsError::new)?;
+	Ok(derive_agreement_public_key(&viewing_key).to_vec())
+}
+
+fn to_array(bytes: &[u8]) -> Result<[u8; 32], &'static str> {
+	bytes.try_into().map_err(|_| "Expected 32 bytes")
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::{entities::memo_data::MemoData, services::encryption::encrypt_memo};
+
+	const COMMITMENT: [u8; 32] = [3u8; 32];
+	const SPENDING_KEY: [u8; 32] = [5u8; 32];
+
+	fn encrypted_for(viewing_key: &[u8; 32]) -> (MemoData, Vec<u8>) {
+		let memo = MemoData::new(1_000, [1u8; 32], [2u8; 32], 0);
+		let encrypted = encrypt_memo(&memo, &COMMITMENT, viewing_key, &[9u8; 12]).unwrap();
+		(memo, encrypted)
+	}
+
+	#[test]
+	fn test_decrypt_with_derived_viewing_key() {
+		let viewing_key = derive_viewing_key(&SPENDING_KEY).unwrap();
+		let (memo, encrypted) = encrypted_for(&to_array(&viewing_key).unwrap());
+
+		assert_eq!(
+			decrypt_memo(&encrypted, &COMMITMENT, &viewing_key).unwrap(),
+			memo.to_bytes().to_vec()
+		);
+	}
+
+	#[test]
+	fn test_try_decrypt_wrong_key() {
+		let (_, encrypted) = encrypted_for(&[7u8; 32]);
+
+		assert!(try_decrypt_memo(&encrypted, &COMMITMENT, &[8u8; 32]).is_none());
+		assert!(try_decrypt_memo(&encrypted, &COMMITMENT, &[7u8; 31]).is_none());
+		assert!(try_decrypt_memo(&encrypted, &COMMITMENT, &[7u8; 32]).is_some());
+	}
+
+	#[test]
+	fn test_detect_only() {
+		let (_, encrypted) = encrypted_for(&[7u8; 32]);
+
+		assert!(detect_only(&encrypted, &COMMITMENT, &[7u8; 32]));
+		assert!(!detect_only(&encrypted, &COMMITMENT, &[8u8; 32]));
+		assert!(!detect_only(&encrypted, &COMMITMENT, &[7u8; 31]));
+	}
+
+	#[test]
+	fn test_try_decrypt_from_sender() {
+		use crate::domain::services::encryption::encrypt_memo_to_address;
+
+		let memo = MemoData::new(5, [1u8; 32], [2u8; 32], 0);
+		let agreement_key = to_array(&derive_agreement_key(&[7u8; 32]).unwrap()).unwrap();
+		let (encrypted, ephemeral_public) =
+			encrypt_memo_to_address(&memo, &COMMITMENT, &agreement_key, &[6u8; 32], &[9u8; 12])
+				.unwrap();
+
+		assert_eq!(
+			try_decrypt_memo_from_sender(&encrypted, &COMMITMENT, &[7u8; 32], &ephemeral_public),
+			Some(memo.to_bytes().to_vec())
+		);
+		assert!(try_decrypt_memo_from_sender(
+			&encrypted,
+			&COMMITMENT,
+			&[8u8; 32],
+			&ephemeral_public
+		)
+		.is_none());
+	}
+
+	#[test]
+	fn test_to_array_length() {
+		assert!(to_array(&[0u8; 31]).is_err());
+		assert_eq!(to_array(&[4u8; 32]).unwrap(), [4u8; 32]);
+	}
+}
@@ -0,0 +1,276 @@
+//! Account
+//!
+//! One HD account and every key a wallet needs for it:
+//!
+//! ```text
+//! seed ─▶ m/32'/coin_type'/account' ─▶ spending_key ─┬─▶ HD viewing key ─▶ addresses
+//!                                                     ├─▶ nullifiers
+//!                                                     └─▶ memo KeySet (spending_key, LE bytes)
+//! ```
+//!
+//! Notes are recognized by decrypting their memo with the memo viewing key,
+//! then checking the owner key against the address the memo names and the
+//! commitment against the note it opens to, so a memo sealed to our key for
+//! somebody else's note is never counted. V1 memos (the on-chain format) name
+//! no address, so their owner key is matched against the first
+//! [`V1_ADDRESS_GAP_LIMIT`] addresses.
+
+use orbinum_encrypted_memo::{KeySet, MemoCodec};
+use orbinum_zk_core::{
+	Blinding, Commitment, DiversifiedAddress, ExtendedSpendingKey, FieldElement, HdKeyService,
+	LightPoseidonHasher, Note, NullifierVersion, ShieldedAddress, SpendingKey,
+};
+use zeroize::Zeroize;
+
+use crate::domain::{Recipient, WalletError};
+
+/// Addresses searched for the owner key of a V1 memo
+pub const V1_ADDRESS_GAP_LIMIT: u32 = 20;
+
+/// Note decrypted from an on-chain memo
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReceivedNote {
+	pub note: Note,
+	pub commitment: Commitment,
+	/// Receiving address the note was sent to
+	pub diversifier_index: u32,
+}
+
+/// Keys of one shielded account
+pub struct Account {
+	extended_key: ExtendedSpendingKey,
+	/// HD viewing key (derives and recognizes addresses)
+	viewing_key: FieldElement,
+	/// Memo keys derived from the account's spending key
+	keys: KeySet,
+}
+
+impl Account {
+	/// Derives the account at `m/32'/coin_type'/account'` from a seed
+	pub fn from_seed(seed: &[u8], coin_type: u32, account: u32) -> Result<Self, WalletError> {
+		let extended_key = hd().account_key(seed, coin_type, account)?;
+		Ok(Self::from_extended_key(extended_key))
+	}
+
+	/// Creates the account of an exported extended spending key
+	pub fn from_extended_key(extended_key: ExtendedSpendingKey) -> Self {
+		let mut spending_key = extended_key.spending_key.inner().to_le_bytes();
+		let keys = KeySet::from_spending_key(spending_key);
+		spending_key.zeroize();
+
+		Self {
+			viewing_key: hd().viewing_key(&extended_key),
+			extended_key,
+			keys,
+		}
+	}
+
+	/// Extended spending key (secret — never expose over the wire)
+	pub fn extended_key(&self) -> &ExtendedSpendingKey {
+		&self.extended_key
+	}
+
+	/// Spending key used in nullifiers and spend proofs
+	pub fn spending_key(&self) -> SpendingKey {
+		self.extended_key.spending_key
+	}
+
+	/// HD viewing key of the account
+	pub fn viewing_key(&self) -> FieldElement {
+		self.viewing_key
+	}
+
+	/// Memo key set (viewing, nullifier and EdDSA keys)
+	pub fn keys(&self) -> &KeySet {
+		&self.keys
+	}
+
+	/// Diversified receiving address `index`
+	pub fn address(&self, index: u64) -> DiversifiedAddress {
+		hd().derive_diversified_address(self.viewing_key, index)
+	}
+
+	/// `orb1...` encoding of receiving address `index`
+	pub fn shielded_address(&self, index: u64) -> ShieldedAddress {
+		ShieldedAddress::from(self.address(index))
+	}
+
+	/// Payment target for receiving address `index` (change, self-transfers)
+	pub fn recipient(&self, index: u64) -> Recipient {
+		Recipient::new(
+			self.address(index).owner_pubkey,
+			*self.keys.viewing_key.as_bytes(),
+		)
+	}
+
+	/// Nullifier of `note` under derivation `version` (field element, little-endian)
+	pub fn nullifier(&self, note: &Note, version: NullifierVersion) -> [u8; 32] {
+		note.nullifier_versioned(LightPoseidonHasher, &self.spending_key(), version)
+			.inner()
+			.to_le_bytes()
+	}
+
+	/// Opens the note behind `commitment` if `memo` was sent to this account
+	///
+	/// Returns `None` for memos of other accounts, off-chain memo pointers,
+	/// and memos whose owner key or commitment do not match.
+	pub fn decrypt_note(&self, commitment: &[u8; 32], memo: &[u8]) -> Option<ReceivedNote> {
+		let decoded = MemoCodec::try_decode(memo, commitment, self.keys.viewing_key.as_bytes())?;
+		let memo = decoded.memo()?;
+
+		let owner_pubkey = FieldElement::from_canonical_le_bytes(memo.owner_pk())?;
+		let owns = |index: u32| {
+			let address = self.address(u64::from(index));
+			(address.owner_pubkey.inner() == owner_pubkey).then_some((index, address))
+		};
+		let (diversifier_index, address) = match memo.diversifier_index() {
+			Some(index) => owns(index)?,
+			None => (0..V1_ADDRESS_GAP_LIMIT).find_map(owns)?,
+		};
+
+		let blinding = FieldElement::from_canonical_le_bytes(memo.blinding())?;
+		let note = Note::for_address(
+			memo.value(),
+			memo.asset_id(),
+			&address,
+			Blinding::new(blinding),
+		);
+		let expected = note.commitment(LightPoseidonHasher);
+		(expected.inner().to_le_bytes() == *commitment).then_some(ReceivedNote {
+			note,
+			commitment: expected,
+			diversifier_index,
+		})
+	}
+}
+
+impl Drop for Account {
+	fn drop(&mut self) {
+		self.extended_key.zeroize();
+	}
+}
+
+fn hd() -> HdKeyService<LightPoseidonHasher> {
+	HdKeyService::new(LightPoseidonHasher)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orbinum_encrypted_memo::{encrypt_memo, encrypt_memo_v2, MemoData, MemoDataV2};
+
+	const SEED: [u8; 64] = [7u8; 64];
+
+	/// Commitment and V2 memo of a note paying `account` at address `index`
+	fn sealed_note(account: &Account, index: u32, value: u64) -> ([u8; 32], alloc::vec::Vec<u8>) {
+		let address = account.address(u64::from(index));
+		let blinding = Blinding::new(FieldElement::from_u64(u64::from(index) + value));
+		let note = Note::for_address(value, 0, &address, blinding);
+		let commitment = note.commitment(LightPoseidonHasher).inner().to_le_bytes();
+
+		let memo = MemoDataV2::new(
+			value,
+			address.owner_pubkey.inner().to_le_bytes(),
+			blinding.inner().to_le_bytes(),
+			0,
+			index,
+			[0u8; 16],
+		);
+		let encrypted = encrypt_memo_v2(
+			&memo,
+			&commitment,
+			account.keys().viewing_key.as_bytes(),
+			&[1u8; 12],
+		)
+		.unwrap();
+		(commitment, encrypted)
+	}
+
+	// ===== Key Tests =====
+
+	#[test]
+	fn test_from_seed_matches_hd_derivation() {
+		let account = Account::from_seed(&SEED, 1, 0).unwrap();
+		let key = hd().account_key(&SEED, 1, 0).unwrap();
+
+		assert_eq!(account.extended_key(), &key);
+		assert_eq!(account.address(3), hd().address(&key, 3));
+		assert!(Account::from_seed(&[0u8; 8], 1, 0).is_err());
+	}
+
+	#[test]
+	fn test_accounts_have_distinct_memo_keys() {
+		let first = Account::from_seed(&SEED, 1, 0).unwrap();
+		let second = Account::from_seed(&SEED, 1, 1).unwrap();
+
+		assert_ne!(first.keys().viewing_key, second.keys().viewing_key);
+	}
+
+	// ===== Note Detection Tests =====
+
+	#[test]
+	fn test_decrypts_own_note() {
+		let account = Account::from_seed(&SEED, 1, 0).unwrap();
+		let (commitment, memo) = sealed_note(&account, 2, 500);
+
+		let received = account.decrypt_note(&commitment, &memo).unwrap();
+
+		assert_eq!(received.note.value(), 500);
+		assert_eq!(received.diversifier_index, 2);
+		assert_eq!(received.commitment.inner().to_le_bytes(), commitment);
+	}
+
+	#[test]
+	fn test_ignores_other_accounts_notes() {
+		let account = Account::from_seed(&SEED, 1, 0).unwrap();
+		let other = Account::from_seed(&SEED, 1, 1).unwrap();
+		let (commitment, memo) = sealed_note(&other, 0, 500);
+
+		assert_eq!(account.decrypt_note(&commitment, &memo), None);
+	}
+
+	#[test]
+	fn test_rejects_memo_for_different_commitment() {
+		let account = Account::from_seed(&SEED, 1, 0).unwrap();
+		let (_, memo) = sealed_note(&account, 0, 500);
+		let (commitment, _) = sealed_note(&account, 0, 600);
+
+		assert_eq!(account.decrypt_note(&commitment, &memo), None);
+	}
+
+	#[test]
+	fn test_decrypts_v1_note_within_gap_limit() {
+		let account = Account::from_seed(&SEED, 1, 0).unwrap();
+		let v1_note = |index: u32| {
+			let address = account.address(u64::from(index));
+			let blinding = Blinding::new(FieldElement::from_u64(9));
+			let note = Note::for_address(300, 0, &address, blinding);
+			let commitment = note.commitment(LightPoseidonHasher).inner().to_le_bytes();
+			let memo = MemoData::new(
+				300,
+				address.owner_pubkey.inner().to_le_bytes(),
+				blinding.inner().to_le_bytes(),
+				0,
+			);
+			let memo = encrypt_memo(
+				&memo,
+				&commitment,
+				account.keys().viewing_key.as_bytes(),
+				&[2u8; 12],
+			)
+			.unwrap();
+			(commitment, memo)
+		};
+
+		let (commitment, memo) = v1_note(V1_ADDRESS_GAP_LIMIT - 1);
+		let received = account.decrypt_note(&commitment, &memo).unwrap();
+		assert_eq!(received.diversifier_index, V1_ADDRESS_GAP_LIMIT - 1);
+
+		let (commitment, memo) = v1_note(V1_ADDRESS_GAP_LIMIT);
+		assert_eq!(account.decrypt_note(&commitment, &memo), None);
+	}
+}
@@ -0,0 +1,808 @@
+//! TransactionBuilder
+//!
+//! Turns wallet notes into `private_transfer` and `unshield` calls:
+//!
+//! ```text
+//! plan_transfer / plan_unshield ─▶ orbinum_v2_getSpendWitness per input
+//!     ─▶ build_transfer / build_unshield ─▶ witness to the prover
+//!     ─▶ with_proof ─▶ call_data
+//! ```
+//!
+//! The circuits fix the shapes: a transfer spends exactly two notes and
+//! creates exactly two, an unshield spends one whole note. Hence:
+//!
+//! - Without a fee the outputs are the payment and a change note to the
+//!   account's address 0. Zero-value change notes are still created; they
+//!   later serve as the second input of a transfer.
+//! - With a relayer fee the second output is the fee note, which leaves no
+//!   room for change, so the inputs must add up to exactly amount + fee.
+//!   Split notes with a fee-less self-transfer first.
+//! - An unshield needs a note worth exactly the amount.
+//!
+//! Merkle paths keep the chain's tree depth (the `orbinum-zk-circuits`
+//! witness DTOs are depth-agnostic) and are checked against the anchor root
+//! before use. Output blindings, memo nonces and the unshield recipient nonce
+//! are all drawn from one `BlindingSource`.
+
+use alloc::{format, string::String, vec, vec::Vec};
+use orbinum_encrypted_memo::{encrypt_memo, MemoData};
+use orbinum_zk_circuits::{
+	application::{
+		circuits::note::Note as CircuitNote,
+		dto::{
+			MerklePath, TransferPublicInputs, TransferWitness, UnshieldPublicInputs,
+			UnshieldWitness,
+		},
+	},
+	Bn254Fr,
+};
+use orbinum_zk_core::{
+	domain::ports::PoseidonHasher, infrastructure::crypto::merkle::bytes_to_field, BlindingSource,
+	FieldElement, LightPoseidonHasher, Note, NullifierVersion,
+};
+
+use crate::{
+	application::wallet::Wallet,
+	domain::{OwnedNote, PrivateTransferCall, Recipient, SpendWitness, UnshieldCall, WalletError},
+};
+
+/// How transfer inputs are chosen among the unspent notes of the asset
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionStrategy {
+	/// Spend the two largest notes
+	#[default]
+	LargestFirst,
+	/// Spend the pair leaving the least change, then the pair received longest ago
+	///
+	/// Large change notes tie the wallet's holdings to the payment, and notes
+	/// re-spent right after arriving link sender and recipient by timing.
+	PrivacyAware,
+}
+
+/// Relayer fee, paid as the second output note
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fee {
+	pub relayer: Recipient,
+	pub amount: u64,
+}
+
+/// Output note of a planned transfer
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlannedOutput {
+	pub recipient: Recipient,
+	pub value: u64,
+}
+
+/// Inputs and outputs of one private transfer
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferPlan {
+	pub asset_id: u32,
+	pub inputs: [OwnedNote; 2],
+	/// Payment, then the fee note (with a fee) or the change note
+	pub outputs: [PlannedOutput; 2],
+}
+
+/// Input and recipient of one unshield
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnshieldPlan {
+	pub asset_id: u32,
+	pub input: OwnedNote,
+	/// `AccountId32` receiving the funds
+	pub recipient: [u8; 32],
+}
+
+/// Transfer ready for proving
+#[derive(Clone, Debug)]
+pub struct PreparedTransfer {
+	/// Private inputs of the transfer circuit
+	pub witness: TransferWitness,
+	pub public_inputs: TransferPublicInputs,
+	/// Output notes, in commitment order
+	pub output_notes: [Note; 2],
+	/// Call arguments, without the proof
+	pub call: PrivateTransferCall,
+}
+
+/// Unshield ready for proving
+#[derive(Clone, Debug)]
+pub struct PreparedUnshield {
+	/// Private inputs of the unshield circuit
+	pub witness: UnshieldWitness,
+	pub public_inputs: UnshieldPublicInputs,
+	/// Call arguments, without the proof
+	pub call: UnshieldCall,
+}
+
+impl TransferPlan {
+	/// Commitments to fetch spend witnesses for, in input order
+	pub fn input_commitments(&self) -> [String; 2] {
+		self.inputs.each_ref().map(commitment_hex)
+	}
+}
+
+impl UnshieldPlan {
+	/// Commitment to fetch the spend witness for
+	pub fn input_commitment(&self) -> String {
+		commitment_hex(&self.input)
+	}
+}
+
+impl PreparedTransfer {
+	/// Call arguments with the proof attached
+	pub fn with_proof(self, proof: Vec<u8>) -> PrivateTransferCall {
+		PrivateTransferCall { proof, ..self.call }
+	}
+}
+
+impl PreparedUnshield {
+	/// Call arguments with the proof attached
+	pub fn with_proof(self, proof: Vec<u8>) -> UnshieldCall {
+		UnshieldCall { proof, ..self.call }
+	}
+}
+
+/// Builds transfers and unshields from the notes of a wallet
+pub struct TransactionBuilder<'a> {
+	wallet: &'a Wallet,
+	strategy: SelectionStrategy,
+	fee: Option<Fee>,
+}
+
+impl<'a> TransactionBuilder<'a> {
+	/// Creates a builder over `wallet`'s unspent notes, without a fee
+	pub fn new(wallet: &'a Wallet) -> Self {
+		Self {
+			wallet,
+			strategy: SelectionStrategy::default(),
+			fee: None,
+		}
+	}
+
+	/// Sets the input selection strategy
+	pub fn with_strategy(mut self, strategy: SelectionStrategy) -> Self {
+		self.strategy = strategy;
+		self
+	}
+
+	/// Pays `amount` to `relayer` in each transfer
+	pub fn with_fee(mut self, relayer: Recipient, amount: u64) -> Self {
+		self.fee = Some(Fee { relayer, amount });
+		self
+	}
+
+	/// Selects inputs paying `amount` of `asset_id` to `recipient`
+	///
+	/// # Errors
+	/// - `InsufficientFunds` if the unspent notes of the asset do not cover it
+	/// - `NoInputCombination` if no two notes cover it (exactly, with a fee)
+	/// - `AmountOverflow` if amount plus fee overflows
+	pub fn plan_transfer(
+		&self,
+		recipient: Recipient,
+		asset_id: u32,
+		amount: u64,
+	) -> Result<TransferPlan, WalletError> {
+		let fee = self.fee.map_or(0, |fee| fee.amount);
+		let required = amount.checked_add(fee).ok_or(WalletError::AmountOverflow)?;
+		let inputs = self.select_pair(asset_id, required, self.fee.is_some())?;
+
+		let total = u128::from(inputs[0].value()) + u128::from(inputs[1].value());
+		let change =
+			u64::try_from(total - u128::from(required)).map_err(|_| WalletError::AmountOverflow)?;
+		let second = match self.fee {
+			Some(fee) => PlannedOutput {
+				recipient: fee.relayer,
+				value: fee.amount,
+			},
+			None => PlannedOutput {
+				recipient: self.wallet.account().recipient(0),
+				value: change,
+			},
+		};
+
+		Ok(TransferPlan {
+			asset_id,
+			inputs,
+			outputs: [
+				PlannedOutput {
+					recipient,
+					value: amount,
+				},
+				second,
+			],
+		})
+	}
+
+	/// Selects the note withdrawing `amount` of `asset_id` to `recipient`
+	///
+	/// # Errors
+	/// - `FeeNotSupported` if the builder has a fee
+	/// - `InsufficientFunds` if the unspent notes of the asset do not cover it
+	/// - `NoInputCombination` if no note is worth exactly `amount`
+	pub fn plan_unshield(
+		&self,
+		asset_id: u32,
+		amount: u64,
+		recipient: [u8; 32],
+	) -> Result<UnshieldPlan, WalletError> {
+		if self.fee.is_some() {
+			return Err(WalletError::FeeNotSupported);
+		}
+		let notes = self.spendable(asset_id, amount)?;
+
+		// Leaf order is arrival order, so this spends the oldest match
+		let input = notes
+			.into_iter()
+			.find(|note| note.value() == amount)
+			.ok_or(WalletError::NoInputCombination {
+				asset_id,
+				amount: u128::from(amount),
+			})?;
+
+		Ok(UnshieldPlan {
+			asset_id,
+			input: input.clone(),
+			recipient,
+		})
+	}
+
+	/// Creates the output notes and the circuit witness of `plan`
+	///
+	/// `witnesses` are the spend witnesses of the plan's inputs, in input
+	/// order, read at the same block.
+	///
+	/// # Errors
+	/// - `InvalidSpendWitness` if a witness is malformed, belongs to another
+	///   leaf, has a different anchor or does not lead to it
+	/// - `Randomness` / `MemoEncryption` if an output cannot be sealed
+	pub fn build_transfer<S: BlindingSource + ?Sized>(
+		&self,
+		plan: &TransferPlan,
+		witnesses: &[SpendWitness; 2],
+		source: &mut S,
+	) -> Result<PreparedTransfer, WalletError> {
+		let account = self.wallet.account();
+		let merkle_root = witnesses[0].anchor_root_bytes()?;
+		let merkle_paths = vec![
+			merkle_path(&plan.inputs[0], &witnesses[0], &merkle_root)?,
+			merkle_path(&plan.inputs[1], &witnesses[1], &merkle_root)?,
+		];
+		let nullifiers: Vec<[u8; 32]> = plan
+			.inputs
+			.iter()
+			.map(|input| account.nullifier(&input.note, NullifierVersion::CURRENT))
+			.collect();
+
+		let (first, first_commitment, first_memo) =
+			seal_output(&plan.outputs[0], plan.asset_id, source)?;
+		let (second, second_commitment, second_memo) =
+			seal_output(&plan.outputs[1], plan.asset_id, source)?;
+		let output_notes = [first, second];
+		let commitments = vec![first_commitment, second_commitment];
+
+		let witness = TransferWitness::new(
+			plan.inputs
+				.iter()
+				.map(|input| circuit_note(&input.note))
+				.collect(),
+			vec![account.spending_key(); 2],
+			merkle_paths,
+			output_notes.iter().map(circuit_note).collect(),
+		);
+		let public_inputs = TransferPublicInputs::new(
+			field(&merkle_root),
+			nullifiers.iter().map(field).collect(),
+			commitments.iter().map(field).collect(),
+		);
+
+		Ok(PreparedTransfer {
+			witness,
+			public_inputs,
+			output_notes,
+			call: PrivateTransferCall {
+				proof: Vec::new(),
+				merkle_root,
+				nullifiers,
+				commitments,
+				encrypted_memos: vec![first_memo, second_memo],
+			},
+		})
+	}
+
+	/// Creates the circuit witness of `plan`
+	///
+	/// # Errors
+	/// - `InvalidSpendWitness` if the witness is malformed, belongs to another
+	///   leaf or does not lead to its anchor
+	/// - `Randomness` if no recipient nonce can be drawn
+	pub fn build_unshield<S: BlindingSource + ?Sized>(
+		&self,
+		plan: &UnshieldPlan,
+		witness: &SpendWitness,
+		source: &mut S,
+	) -> Result<PreparedUnshield, WalletError> {
+		let account = self.wallet.account();
+		let merkle_root = witness.anchor_root_bytes()?;
+		let merkle_path = merkle_path(&plan.input, witness, &merkle_root)?;
+		let nullifier = account.nullifier(&plan.input.note, NullifierVersion::CURRENT);
+		let recipient_nonce = source.next_blinding()?.inner();
+
+		// Same field encoding as the pallet: the SCALE bytes, reversed
+		let mut recipient_le = plan.recipient;
+		recipient_le.reverse();
+		let unshield_witness = UnshieldWitness::new(
+			circuit_note(&plan.input.note),
+			account.spending_key(),
+			merkle_path,
+			bytes_to_field(&recipient_le).inner(),
+			recipient_nonce.inner(),
+		);
+		let public_inputs = UnshieldPublicInputs::new(
+			field(&merkle_root),
+			field(&nullifier),
+			Bn254Fr::from(plan.input.value()),
+			unshield_witness.recipient_binding(),
+		);
+
+		Ok(PreparedUnshield {
+			witness: unshield_witness,
+			public_inputs,
+			call: UnshieldCall {
+				proof: Vec::new(),
+				merkle_root,
+				nullifier,
+				asset_id: plan.asset_id,
+				amount: u128::from(plan.input.value()),
+				recipient: plan.recipient,
+				recipient_nonce: recipient_nonce.to_le_bytes(),
+			},
+		})
+	}
+
+	/// Unspent notes of `asset_id`, if together they cover `amount`
+	fn spendable(&self, asset_id: u32, amount: u64) -> Result<Vec<&'a OwnedNote>, WalletError> {
+		let notes: Vec<&OwnedNote> = self
+			.wallet
+			.unspent_notes()
+			.filter(|note| note.asset_id() == u64::from(asset_id))
+			.collect();
+		let available = notes.iter().map(|note| u128::from(note.value())).sum();
+		if available < u128::from(amount) {
+			return Err(WalletError::InsufficientFunds {
+				asset_id,
+				available,
+				required: u128::from(amount),
+			});
+		}
+		Ok(notes)
+	}
+
+	/// Best pair of notes covering `amount` (exactly, if `exact`)
+	fn select_pair(
+		&self,
+		asset_id: u32,
+		amount: u64,
+		exact: bool,
+	) -> Result<[OwnedNote; 2], WalletError> {
+		let notes = self.spendable(asset_id, amount)?;
+		let required = u128::from(amount);
+
+		// Every pair is considered; wallets hold few enough notes for O(n²)
+		let mut best: Option<(&OwnedNote, &OwnedNote)> = None;
+		for (i, &first) in notes.iter().enumerate() {
+			for &second in &notes[i + 1..] {
+				let sum = u128::from(first.value()) + u128::from(second.value());
+				let fits = if exact {
+					sum == required
+				} else {
+					sum >= required
+				};
+				if fits && best.is_none_or(|best| self.prefers((first, second), best)) {
+					best = Some((first, second));
+				}
+			}
+		}
+
+		best.map(|(first, second)| [first.clone(), second.clone()])
+			.ok_or(WalletError::NoInputCombination {
+				asset_id,
+				amount: required,
+			})
+	}
+
+	/// Returns `true` if `candidate` beats `current` under the strategy;
+	/// ties keep `current`, the pair with the lower leaf indices
+	fn prefers(
+		&self,
+		candidate: (&OwnedNote, &OwnedNote),
+		current: (&OwnedNote, &OwnedNote),
+	) -> bool {
+		match self.strategy {
+			SelectionStrategy::LargestFirst => largest(candidate) > largest(current),
+			SelectionStrategy::PrivacyAware => privacy_cost(candidate) < privacy_cost(current),
+		}
+	}
+}
+
+/// Larger note value, then smaller note value
+fn largest((first, second): (&OwnedNote, &OwnedNote)) -> (u64, u64) {
+	(
+		first.value().max(second.value()),
+		first.value().min(second.value()),
+	)
+}
+
+/// Total value (hence change), then the later arrival block
+fn privacy_cost((first, second): (&OwnedNote, &OwnedNote)) -> (u128, u64) {
+	(
+		u128::from(first.value()) + u128::from(second.value()),
+		first.received_at.max(second.received_at),
+	)
+}
+
+/// Checks `witness` against `input` and `merkle_root` and converts it to a
+/// circuit path
+fn merkle_path(
+	input: &OwnedNote,
+	witness: &SpendWitness,
+	merkle_root: &[u8; 32],
+) -> Result<MerklePath, WalletError> {
+	if witness.leaf_index != input.leaf_index {
+		return Err(WalletError::InvalidSpendWitness("leaf_index"));
+	}
+	if witness.anchor_root_bytes()? != *merkle_root {
+		return Err(WalletError::InvalidSpendWitness("anchor_root"));
+	}
+
+	let siblings = witness.sibling_elements()?;
+	let positions = witness.positions();
+	let root = siblings.iter().zip(&positions).fold(
+		input.commitment.inner(),
+		|node, (sibling, is_right)| {
+			if *is_right {
+				LightPoseidonHasher.hash_2([*sibling, node])
+			} else {
+				LightPoseidonHasher.hash_2([node, *sibling])
+			}
+		},
+	);
+	if root.to_le_bytes() != *merkle_root {
+		return Err(WalletError::InvalidSpendWitness("siblings"));
+	}
+
+	Ok(MerklePath::new(
+		siblings.iter().map(FieldElement::inner).collect(),
+		positions,
+	))
+}
+
+/// Creates the note of `output` and its commitment and V1 memo
+fn seal_output<S: BlindingSource + ?Sized>(
+	output: &PlannedOutput,
+	asset_id: u32,
+	source: &mut S,
+) -> Result<(Note, [u8; 32], Vec<u8>), WalletError> {
+	let note = Note::with_blinding_source(
+		output.value,
+		u64::from(asset_id),
+		output.recipient.owner_pubkey,
+		source,
+	)?;
+	let commitment = note.commitment(LightPoseidonHasher).inner().to_le_bytes();
+
+	// The memo key is unique per commitment; the nonce is random regardless
+	let mut nonce = [0u8; 12];
+	nonce.copy_from_slice(&source.next_blinding()?.inner().to_le_bytes()[..12]);
+	let memo = MemoData::new(
+		output.value,
+		note.owner_pubkey().inner().to_le_bytes(),
+		note.blinding().inner().to_le_bytes(),
+		asset_id,
+	);
+	let memo = encrypt_memo(&memo, &commitment, &output.recipient.viewing_key, &nonce)
+		.map_err(WalletError::MemoEncryption)?;
+
+	Ok((note, commitment, memo))
+}
+
+fn circuit_note(note: &Note) -> CircuitNote {
+	CircuitNote::new(
+		note.value(),
+		note.asset_id(),
+		note.owner_pubkey().as_fr(),
+		note.blinding().as_fr(),
+	)
+}
+
+fn field(bytes: &[u8; 32]) -> Bn254Fr {
+	bytes_to_field(bytes).inner()
+}
+
+fn commitment_hex(note: &OwnedNote) -> String {
+	format!("0x{}", hex::encode(note.commitment.inner().to_le_bytes()))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		application::account::Account,
+		domain::{CompactBlock, CompactOutput, SHIELDED_POOL_PALLET_INDEX},
+	};
+	use orbinum_zk_core::{
+		infrastructure::crypto::merkle::IncrementalMerkleTree, Blinding, SeededBlindingSource,
+	};
+
+	const SEED: [u8; 64] = [7u8; 64];
+
+	fn hex(bytes: &[u8]) -> String {
+		format!("0x{}", ::hex::encode(bytes))
+	}
+
+	/// Wallet holding one native-token note per value (leaf `i` for value
+	/// `i`), and the tree they were appended to
+	fn funded_wallet(values: &[u64]) -> (Wallet, IncrementalMerkleTree<LightPoseidonHasher>) {
+		let mut wallet = Wallet::new(Account::from_seed(&SEED, 1, 0).unwrap());
+		let mut tree = IncrementalMerkleTree::new(LightPoseidonHasher, 8);
+		let address = wallet.account().address(0);
+		let viewing_key = *wallet.account().keys().viewing_key.as_bytes();
+
+		let mut outputs = Vec::new();
+		for (leaf_index, value) in (0u32..).zip(values) {
+			let blinding = Blinding::new(FieldElement::from_u64(u64::from(leaf_index) + 100));
+			let note = Note::for_address(*value, 0, &address, blinding);
+			let commitment = note.commitment(LightPoseidonHasher);
+			tree.append(commitment.inner()).unwrap();
+
+			let commitment = commitment.inner().to_le_bytes();
+			let memo = MemoData::new(
+				*value,
+				address.owner_pubkey.inner().to_le_bytes(),
+				blinding.inner().to_le_bytes(),
+				0,
+			);
+			let memo = encrypt_memo(&memo, &commitment, &viewing_key, &[3u8; 12]).unwrap();
+			outputs.push(CompactOutput {
+				leaf_index,
+				commitment: hex(&commitment),
+				memo: Some(hex(&memo)),
+			});
+		}
+		wallet
+			.scan_block(&CompactBlock {
+				block_number: 1,
+				block_hash: hex(&[1u8; 32]),
+				outputs,
+				nullifiers: vec![],
+			})
+			.unwrap();
+		(wallet, tree)
+	}
+
+	fn spend_witness(tree: &IncrementalMerkleTree<LightPoseidonHasher>, leaf: u32) -> SpendWitness {
+		let path = tree.witness(u64::from(leaf)).unwrap();
+		SpendWitness {
+			anchor_root: hex(&tree.root().to_le_bytes()),
+			anchor_block: 1,
+			leaf_index: leaf,
+			siblings: path
+				.siblings
+				.iter()
+				.map(|sibling| hex(&sibling.to_le_bytes()))
+				.collect(),
+		}
+	}
+
+	fn other_recipient() -> (Account, Recipient) {
+		let account = Account::from_seed(&SEED, 1, 1).unwrap();
+		let recipient = account.recipient(4);
+		(account, recipient)
+	}
+
+	fn leaves(plan: &TransferPlan) -> [u32; 2] {
+		[plan.inputs[0].leaf_index, plan.inputs[1].leaf_index]
+	}
+
+	// ===== Selection Tests =====
+
+	#[test]
+	fn test_largest_first_spends_two_largest_notes() {
+		let (wallet, _) = funded_wallet(&[10, 50, 30, 40]);
+		let (_, recipient) = other_recipient();
+
+		let plan = TransactionBuilder::new(&wallet)
+			.plan_transfer(recipient, 0, 60)
+			.unwrap();
+
+		assert_eq!(leaves(&plan), [1, 3]);
+		assert_eq!(plan.outputs[0].value, 60);
+		assert_eq!(plan.outputs[1].value, 30);
+		assert_eq!(plan.outputs[1].recipient, wallet.account().recipient(0));
+	}
+
+	#[test]
+	fn test_privacy_aware_minimizes_change() {
+		let (wallet, _) = funded_wallet(&[10, 50, 30, 40]);
+		let (_, recipient) = other_recipient();
+
+		let plan = TransactionBuilder::new(&wallet)
+			.with_strategy(SelectionStrategy::PrivacyAware)
+			.plan_transfer(recipient, 0, 60)
+			.unwrap();
+
+		assert_eq!(leaves(&plan), [0, 1]);
+		assert_eq!(plan.outputs[1].value, 0);
+	}
+
+	#[test]
+	fn test_fee_requires_exact_inputs() {
+		let (wallet, _) = funded_wallet(&[10, 50, 30, 40]);
+		let (_, recipient) = other_recipient();
+		let (_, relayer) = other_recipient();
+		let builder = TransactionBuilder::new(&wallet).with_fee(relayer, 5);
+
+		let plan = builder.plan_transfer(recipient, 0, 65).unwrap();
+		assert_eq!(leaves(&plan), [2, 3]);
+		assert_eq!(plan.outputs[1].value, 5);
+		assert_eq!(plan.outputs[1].recipient, relayer);
+
+		assert_eq!(
+			builder.plan_transfer(recipient, 0, 56),
+			Err(WalletError::NoInputCombination {
+				asset_id: 0,
+				amount: 61
+			})
+		);
+	}
+
+	#[test]
+	fn test_rejects_insufficient_funds() {
+		let (wallet, _) = funded_wallet(&[10, 50]);
+		let (_, recipient) = other_recipient();
+		let builder = TransactionBuilder::new(&wallet);
+
+		assert_eq!(
+			builder.plan_transfer(recipient, 0, 61),
+			Err(WalletError::InsufficientFunds {
+				asset_id: 0,
+				available: 60,
+				required: 61
+			})
+		);
+		assert_eq!(
+			builder.plan_transfer(recipient, 3, 1),
+			Err(WalletError::InsufficientFunds {
+				asset_id: 3,
+				available: 0,
+				required: 1
+			})
+		);
+	}
+
+	// ===== Transfer Tests =====
+
+	#[test]
+	fn test_builds_transfer_for_recipient_and_change() {
+		let (wallet, tree) = funded_wallet(&[10, 50, 30, 40]);
+		let (other, recipient) = other_recipient();
+		let builder = TransactionBuilder::new(&wallet);
+		let plan = builder.plan_transfer(recipient, 0, 60).unwrap();
+		let witnesses = [spend_witness(&tree, 1), spend_witness(&tree, 3)];
+
+		let prepared = builder
+			.build_transfer(&plan, &witnesses, &mut SeededBlindingSource::new(1))
+			.unwrap();
+
+		assert!(prepared.witness.validate().is_ok());
+		assert_eq!(prepared.witness.merkle_paths[0].depth(), 8);
+		assert_eq!(prepared.public_inputs.count(), 5);
+		assert_eq!(prepared.call.merkle_root, tree.root().to_le_bytes());
+		assert_eq!(
+			prepared.call.nullifiers[0],
+			wallet
+				.account()
+				.nullifier(&plan.inputs[0].note, NullifierVersion::CURRENT)
+		);
+
+		let call = &prepared.call;
+		let payment = other
+			.decrypt_note(&call.commitments[0], &call.encrypted_memos[0])
+			.unwrap();
+		assert_eq!(payment.note.value(), 60);
+		assert_eq!(payment.diversifier_index, 4);
+		let change = wallet
+			.account()
+			.decrypt_note(&call.commitments[1], &call.encrypted_memos[1])
+			.unwrap();
+		assert_eq!(change.note.value(), 30);
+		assert_eq!(change.note, prepared.output_notes[1]);
+
+		let call = prepared.with_proof(vec![5u8; 256]);
+		assert_eq!(call.proof.len(), 256);
+		assert_eq!(&call.call_data(SHIELDED_POOL_PALLET_INDEX)[..2], &[13, 1]);
+	}
+
+	#[test]
+	fn test_rejects_mismatched_spend_witnesses() {
+		let (wallet, tree) = funded_wallet(&[10, 50, 30, 40]);
+		let (_, recipient) = other_recipient();
+		let builder = TransactionBuilder::new(&wallet);
+		let plan = builder.plan_transfer(recipient, 0, 60).unwrap();
+		let build = |witnesses: &[SpendWitness; 2]| {
+			builder
+				.build_transfer(&plan, witnesses, &mut SeededBlindingSource::new(1))
+				.map(|_| ())
+		};
+
+		let swapped = [spend_witness(&tree, 3), spend_witness(&tree, 1)];
+		assert_eq!(
+			build(&swapped),
+			Err(WalletError::InvalidSpendWitness("leaf_index"))
+		);
+
+		let mut tampered = [spend_witness(&tree, 1), spend_witness(&tree, 3)];
+		tampered[1].siblings[0] = hex(&FieldElement::from_u64(1).to_le_bytes());
+		assert_eq!(
+			build(&tampered),
+			Err(WalletError::InvalidSpendWitness("siblings"))
+		);
+
+		let mut stale = [spend_witness(&tree, 1), spend_witness(&tree, 3)];
+		stale[1].anchor_root = hex(&[0u8; 32]);
+		assert_eq!(
+			build(&stale),
+			Err(WalletError::InvalidSpendWitness("anchor_root"))
+		);
+	}
+
+	// ===== Unshield Tests =====
+
+	#[test]
+	fn test_builds_unshield_of_exact_note() {
+		let (wallet, tree) = funded_wallet(&[10, 50, 30]);
+		let builder = TransactionBuilder::new(&wallet);
+		let recipient = [9u8; 32];
+
+		let plan = builder.plan_unshield(0, 30, recipient).unwrap();
+		assert_eq!(plan.input.leaf_index, 2);
+		let prepared = builder
+			.build_unshield(
+				&plan,
+				&spend_witness(&tree, 2),
+				&mut SeededBlindingSource::new(2),
+			)
+			.unwrap();
+
+		assert!(prepared.witness.validate().is_ok());
+		assert_eq!(prepared.public_inputs.amount, Bn254Fr::from(30u64));
+		assert_eq!(
+			prepared.public_inputs.recipient_binding,
+			prepared.witness.recipient_binding()
+		);
+		assert_eq!(prepared.call.amount, 30);
+		assert_eq!(prepared.call.recipient, recipient);
+		assert_eq!(
+			prepared.call.recipient_nonce,
+			FieldElement::new(prepared.witness.recipient_nonce).to_le_bytes()
+		);
+
+		assert_eq!(
+			builder.plan_unshield(0, 20, recipient),
+			Err(WalletError::NoInputCombination {
+				asset_id: 0,
+				amount: 20
+			})
+		);
+		let (_, relayer) = other_recipient();
+		assert_eq!(
+			TransactionBuilder::new(&wallet)
+				.with_fee(relayer, 1)
+				.plan_unshield(0, 30, recipient),
+			Err(WalletError::FeeNotSupported)
+		);
+	}
+}
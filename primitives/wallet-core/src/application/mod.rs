@@ -0,0 +1,13 @@
+//! Application layer: account keys, the note-tracking wallet and the
+//! transaction builder
+
+pub mod account;
+pub mod builder;
+pub mod wallet;
+
+pub use account::{Account, ReceivedNote, V1_ADDRESS_GAP_LIMIT};
+pub use builder::{
+	Fee, PlannedOutput, PreparedTransfer, PreparedUnshield, SelectionStrategy, TransactionBuilder,
+	TransferPlan, UnshieldPlan,
+};
+pub use wallet::{BlockScan, Wallet};
@@ -0,0 +1,362 @@
+//! Wallet
+//!
+//! Note tracking over compact blocks: trial-decrypts every output memo with
+//! the account's keys, remembers the nullifiers of each received note under
+//! every accepted derivation version, and marks notes spent when one of them
+//! appears on-chain. Blocks are scanned in ascending order; after a reorg,
+//! [`Wallet::rewind`] to the fork point and rescan.
+
+use alloc::{collections::BTreeMap, vec::Vec};
+use orbinum_zk_core::NullifierVersion;
+
+use crate::{
+	application::account::{Account, ReceivedNote},
+	domain::{CompactBlock, OwnedNote, WalletError},
+};
+
+/// Notes a scanned block added to or removed from the wallet
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockScan {
+	/// Leaf indices of notes received in the block
+	pub received: Vec<u32>,
+	/// Leaf indices of notes spent in the block
+	pub spent: Vec<u32>,
+}
+
+/// Shielded wallet of one account
+pub struct Wallet {
+	account: Account,
+	/// Owned notes by leaf index
+	notes: BTreeMap<u32, OwnedNote>,
+	/// Leaf index of the note behind each nullifier
+	nullifiers: BTreeMap<[u8; 32], u32>,
+	last_scanned: Option<u64>,
+}
+
+impl Wallet {
+	/// Creates an empty wallet for `account`
+	pub fn new(account: Account) -> Self {
+		Self {
+			account,
+			notes: BTreeMap::new(),
+			nullifiers: BTreeMap::new(),
+			last_scanned: None,
+		}
+	}
+
+	/// Account the wallet tracks
+	pub fn account(&self) -> &Account {
+		&self.account
+	}
+
+	/// Last scanned block, `None` before the first scan
+	pub fn last_scanned_block(&self) -> Option<u64> {
+		self.last_scanned
+	}
+
+	/// Scans one block for received and spent notes
+	///
+	/// A block that fails to parse leaves the wallet unchanged.
+	///
+	/// # Errors
+	/// - `BlockOutOfOrder` if the block is not after the last scanned block
+	/// - `InvalidCompactBlock` for malformed commitments, memos or nullifiers
+	pub fn scan_block(&mut self, block: &CompactBlock) -> Result<BlockScan, WalletError> {
+		if let Some(last) = self.last_scanned {
+			if block.block_number <= last {
+				return Err(WalletError::BlockOutOfOrder {
+					last,
+					block: block.block_number,
+				});
+			}
+		}
+		let outputs = block
+			.outputs
+			.iter()
+			.map(|output| {
+				Ok((
+					output.leaf_index,
+					output.commitment_bytes()?,
+					output.memo_bytes()?,
+				))
+			})
+			.collect::<Result<Vec<_>, WalletError>>()?;
+		let nullifiers = block.nullifier_bytes()?;
+
+		// Outputs first: a note can be received and spent in the same block
+		let mut scan = BlockScan::default();
+		for (leaf_index, commitment, memo) in outputs {
+			let Some(received) =
+				memo.and_then(|memo| self.account.decrypt_note(&commitment, &memo))
+			else {
+				continue;
+			};
+			self.track(leaf_index, received, block.block_number);
+			scan.received.push(leaf_index);
+		}
+		for nullifier in nullifiers {
+			let Some(note) = self
+				.nullifiers
+				.get(&nullifier)
+				.and_then(|leaf_index| self.notes.get_mut(leaf_index))
+			else {
+				continue;
+			};
+			if note.spent_at.is_none() {
+				note.spent_at = Some(block.block_number);
+				scan.spent.push(note.leaf_index);
+			}
+		}
+
+		self.last_scanned = Some(block.block_number);
+		Ok(scan)
+	}
+
+	/// Scans blocks in order, stopping at the first error
+	pub fn scan_blocks<'a>(
+		&mut self,
+		blocks: impl IntoIterator<Item = &'a CompactBlock>,
+	) -> Result<BlockScan, WalletError> {
+		let mut total = BlockScan::default();
+		for block in blocks {
+			let scan = self.scan_block(block)?;
+			total.received.extend(scan.received);
+			total.spent.extend(scan.spent);
+		}
+		Ok(total)
+	}
+
+	/// Forgets everything scanned after `block_number`
+	///
+	/// Notes received later are dropped and notes spent later become
+	/// unspent again; the next scan resumes at `block_number + 1`.
+	pub fn rewind(&mut self, block_number: u64) {
+		self.notes
+			.retain(|_, note| note.received_at <= block_number);
+		let notes = &self.notes;
+		self.nullifiers
+			.retain(|_, leaf_index| notes.contains_key(leaf_index));
+		for note in self.notes.values_mut() {
+			if note
+				.spent_at
+				.is_some_and(|spent_at| spent_at > block_number)
+			{
+				note.spent_at = None;
+			}
+		}
+		self.last_scanned = self.last_scanned.map(|last| last.min(block_number));
+	}
+
+	/// Every owned note, spent or not, by leaf index
+	pub fn notes(&self) -> impl Iterator<Item = &OwnedNote> {
+		self.notes.values()
+	}
+
+	/// Owned note at `leaf_index`
+	pub fn note(&self, leaf_index: u32) -> Option<&OwnedNote> {
+		self.notes.get(&leaf_index)
+	}
+
+	/// Unspent notes, by leaf index
+	pub fn unspent_notes(&self) -> impl Iterator<Item = &OwnedNote> {
+		self.notes.values().filter(|note| !note.is_spent())
+	}
+
+	/// Spendable balance of `asset_id`
+	pub fn balance(&self, asset_id: u64) -> u128 {
+		self.unspent_notes()
+			.filter(|note| note.asset_id() == asset_id)
+			.map(|note| u128::from(note.value()))
+			.sum()
+	}
+
+	/// Spendable balance of every asset with unspent notes
+	pub fn balances(&self) -> BTreeMap<u64, u128> {
+		let mut balances = BTreeMap::new();
+		for note in self.unspent_notes() {
+			*balances.entry(note.asset_id()).or_default() += u128::from(note.value());
+		}
+		balances
+	}
+
+	fn track(&mut self, leaf_index: u32, received: ReceivedNote, block_number: u64) {
+		for version in NullifierVersion::ALL {
+			let nullifier = self.account.nullifier(&received.note, version);
+			self.nullifiers.insert(nullifier, leaf_index);
+		}
+		self.notes.insert(
+			leaf_index,
+			OwnedNote {
+				leaf_index,
+				commitment: received.commitment,
+				note: received.note,
+				diversifier_index: received.diversifier_index,
+				received_at: block_number,
+				spent_at: None,
+			},
+		);
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::CompactOutput;
+	use alloc::{format, string::String, vec};
+	use orbinum_encrypted_memo::{encrypt_memo_v2, MemoDataV2};
+	use orbinum_zk_core::{Blinding, FieldElement, LightPoseidonHasher, Note};
+
+	const SEED: [u8; 64] = [7u8; 64];
+
+	fn hex(bytes: &[u8]) -> String {
+		format!("0x{}", ::hex::encode(bytes))
+	}
+
+	fn wallet() -> Wallet {
+		Wallet::new(Account::from_seed(&SEED, 1, 0).unwrap())
+	}
+
+	/// Output paying `account` and the note behind it
+	fn output(
+		account: &Account,
+		leaf_index: u32,
+		value: u64,
+		asset_id: u64,
+	) -> (CompactOutput, Note) {
+		let address = account.address(0);
+		let blinding = Blinding::new(FieldElement::from_u64(u64::from(leaf_index) + 1));
+		let note = Note::for_address(value, asset_id, &address, blinding);
+		let commitment = note.commitment(LightPoseidonHasher).inner().to_le_bytes();
+		let memo = MemoDataV2::new(
+			value,
+			address.owner_pubkey.inner().to_le_bytes(),
+			blinding.inner().to_le_bytes(),
+			asset_id,
+			0,
+			[0u8; 16],
+		);
+		let memo = encrypt_memo_v2(
+			&memo,
+			&commitment,
+			account.keys().viewing_key.as_bytes(),
+			&[leaf_index as u8; 12],
+		)
+		.unwrap();
+
+		let output = CompactOutput {
+			leaf_index,
+			commitment: hex(&commitment),
+			memo: Some(hex(&memo)),
+		};
+		(output, note)
+	}
+
+	fn block(
+		block_number: u64,
+		outputs: Vec<CompactOutput>,
+		nullifiers: Vec<[u8; 32]>,
+	) -> CompactBlock {
+		CompactBlock {
+			block_number,
+			block_hash: hex(&[block_number as u8; 32]),
+			outputs,
+			nullifiers: nullifiers.iter().map(|n| hex(n)).collect(),
+		}
+	}
+
+	// ===== Scanning Tests =====
+
+	#[test]
+	fn test_tracks_received_and_spent_notes() {
+		let mut wallet = wallet();
+		let (first, first_note) = output(wallet.account(), 0, 100, 0);
+		let (second, _) = output(wallet.account(), 1, 40, 7);
+		let foreign = CompactOutput {
+			leaf_index: 2,
+			commitment: hex(&[3u8; 32]),
+			memo: None,
+		};
+
+		let scan = wallet
+			.scan_block(&block(5, vec![first, second, foreign], vec![]))
+			.unwrap();
+		assert_eq!(scan.received, vec![0, 1]);
+		assert_eq!(wallet.balance(0), 100);
+		assert_eq!(wallet.balances(), BTreeMap::from([(0, 100), (7, 40)]));
+
+		let nullifier = wallet
+			.account()
+			.nullifier(&first_note, NullifierVersion::CURRENT);
+		let scan = wallet
+			.scan_block(&block(6, vec![], vec![nullifier]))
+			.unwrap();
+		assert_eq!(scan.spent, vec![0]);
+		assert_eq!(wallet.balance(0), 0);
+		assert_eq!(wallet.note(0).unwrap().spent_at, Some(6));
+		assert_eq!(wallet.unspent_notes().count(), 1);
+	}
+
+	#[test]
+	fn test_detects_spends_under_every_nullifier_version() {
+		let mut wallet = wallet();
+		let (out, note) = output(wallet.account(), 0, 100, 0);
+		let nullifier = wallet.account().nullifier(&note, NullifierVersion::V2);
+
+		let scan = wallet
+			.scan_block(&block(1, vec![out], vec![nullifier]))
+			.unwrap();
+
+		assert_eq!(scan.received, vec![0]);
+		assert_eq!(scan.spent, vec![0]);
+	}
+
+	#[test]
+	fn test_rejects_out_of_order_blocks() {
+		let mut wallet = wallet();
+		wallet.scan_block(&block(5, vec![], vec![])).unwrap();
+
+		assert_eq!(
+			wallet.scan_block(&block(5, vec![], vec![])),
+			Err(WalletError::BlockOutOfOrder { last: 5, block: 5 })
+		);
+	}
+
+	#[test]
+	fn test_malformed_block_leaves_wallet_unchanged() {
+		let mut wallet = wallet();
+		let (out, _) = output(wallet.account(), 0, 100, 0);
+		let mut bad = block(1, vec![out], vec![]);
+		bad.nullifiers.push("0x12".into());
+
+		assert!(wallet.scan_block(&bad).is_err());
+		assert_eq!(wallet.notes().count(), 0);
+		assert_eq!(wallet.last_scanned_block(), None);
+	}
+
+	// ===== Rewind Tests =====
+
+	#[test]
+	fn test_rewind_drops_later_notes_and_spends() {
+		let mut wallet = wallet();
+		let (first, first_note) = output(wallet.account(), 0, 100, 0);
+		let (second, _) = output(wallet.account(), 1, 40, 0);
+		let nullifier = wallet
+			.account()
+			.nullifier(&first_note, NullifierVersion::CURRENT);
+		wallet.scan_block(&block(1, vec![first], vec![])).unwrap();
+		wallet
+			.scan_block(&block(2, vec![second], vec![nullifier]))
+			.unwrap();
+		assert_eq!(wallet.balance(0), 40);
+
+		wallet.rewind(1);
+
+		assert_eq!(wallet.balance(0), 100);
+		assert_eq!(wallet.notes().count(), 1);
+		assert_eq!(wallet.last_scanned_block(), Some(1));
+	}
+}
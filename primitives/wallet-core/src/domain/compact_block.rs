@@ -0,0 +1,129 @@
+//! Compact block types.
+//!
+//! Mirror of the `orbinum_v2_getCompactBlocks` response, served by the node
+//! and by `orbinum-indexer`. Hashes, commitments, memos and nullifiers stay
+//! `0x`-prefixed hex strings, as on the wire.
+
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::WalletError;
+
+/// Pool activity of one block
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CompactBlock {
+	pub block_number: u64,
+	pub block_hash: String,
+	/// New commitments, in leaf order
+	pub outputs: Vec<CompactOutput>,
+	pub nullifiers: Vec<String>,
+}
+
+/// New note of the tree
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CompactOutput {
+	pub leaf_index: u32,
+	pub commitment: String,
+	/// Encrypted memo; recipients detect their notes from its MAC
+	pub memo: Option<String>,
+}
+
+/// One page of compact blocks; resume with `from_block = next_block`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct CompactBlockPage {
+	pub blocks: Vec<CompactBlock>,
+	/// First block not yet scanned, `None` once the range is exhausted
+	pub next_block: Option<u64>,
+}
+
+impl CompactOutput {
+	/// Commitment bytes (field element, little-endian)
+	pub fn commitment_bytes(&self) -> Result<[u8; 32], WalletError> {
+		parse_hash(
+			&self.commitment,
+			WalletError::InvalidCompactBlock("commitment"),
+		)
+	}
+
+	/// Encrypted memo bytes, `None` if the output carries no memo
+	pub fn memo_bytes(&self) -> Result<Option<Vec<u8>>, WalletError> {
+		self.memo
+			.as_deref()
+			.map(|memo| parse_hex(memo, WalletError::InvalidCompactBlock("memo")))
+			.transpose()
+	}
+}
+
+impl CompactBlock {
+	/// Nullifier bytes, in emission order
+	pub fn nullifier_bytes(&self) -> Result<Vec<[u8; 32]>, WalletError> {
+		self.nullifiers
+			.iter()
+			.map(|nullifier| parse_hash(nullifier, WalletError::InvalidCompactBlock("nullifier")))
+			.collect()
+	}
+}
+
+/// Decodes `0x`-prefixed (or bare) hex, failing with `error`
+pub(crate) fn parse_hex(value: &str, error: WalletError) -> Result<Vec<u8>, WalletError> {
+	hex::decode(value.strip_prefix("0x").unwrap_or(value)).map_err(|_| error)
+}
+
+/// Decodes a 32-byte hex hash, failing with `error`
+pub(crate) fn parse_hash(value: &str, error: WalletError) -> Result<[u8; 32], WalletError> {
+	parse_hex(value, error.clone())?
+		.try_into()
+		.map_err(|_| error)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_deserializes_rpc_page() {
+		let json = r#"{
+			"blocks": [{
+				"block_number": 7,
+				"block_hash": "0x0707070707070707070707070707070707070707070707070707070707070707",
+				"outputs": [{
+					"leaf_index": 3,
+					"commitment": "0x0101010101010101010101010101010101010101010101010101010101010101",
+					"memo": null
+				}],
+				"nullifiers": ["0x0202020202020202020202020202020202020202020202020202020202020202"]
+			}],
+			"next_block": 8
+		}"#;
+
+		let page: CompactBlockPage = serde_json::from_str(json).unwrap();
+		let block = &page.blocks[0];
+
+		assert_eq!(page.next_block, Some(8));
+		assert_eq!(block.outputs[0].commitment_bytes(), Ok([1u8; 32]));
+		assert_eq!(block.outputs[0].memo_bytes(), Ok(None));
+		assert_eq!(block.nullifier_bytes(), Ok(alloc::vec![[2u8; 32]]));
+	}
+
+	#[test]
+	fn test_rejects_malformed_hashes() {
+		let output = CompactOutput {
+			leaf_index: 0,
+			commitment: "0x0102".into(),
+			memo: Some("0xzz".into()),
+		};
+
+		assert_eq!(
+			output.commitment_bytes(),
+			Err(WalletError::InvalidCompactBlock("commitment"))
+		);
+		assert_eq!(
+			output.memo_bytes(),
+			Err(WalletError::InvalidCompactBlock("memo"))
+		);
+	}
+}
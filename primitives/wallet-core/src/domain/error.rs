@@ -0,0 +1,92 @@
+//! Error types for wallet operations.
+
+use orbinum_encrypted_memo::MemoError;
+use orbinum_zk_core::{BlindingSourceError, KeyDerivationError};
+
+/// All errors that can occur in wallet operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WalletError {
+	/// HD key derivation failed
+	KeyDerivation(KeyDerivationError),
+	/// Compact block field is not valid hex of the expected length
+	InvalidCompactBlock(&'static str),
+	/// Block is at or below the last scanned block
+	BlockOutOfOrder {
+		/// Last scanned block
+		last: u64,
+		/// Rejected block
+		block: u64,
+	},
+	/// Unspent notes of the asset do not cover the amount
+	InsufficientFunds {
+		asset_id: u32,
+		available: u128,
+		required: u128,
+	},
+	/// Funds suffice, but no input notes fit the shape the circuit needs
+	NoInputCombination {
+		asset_id: u32,
+		/// Amount the inputs must cover (exactly, for fee transfers and unshields)
+		amount: u128,
+	},
+	/// Amount plus fee overflows `u64`
+	AmountOverflow,
+	/// Unshields create no notes to carry a relayer fee
+	FeeNotSupported,
+	/// Spend witness field is malformed or does not match the note
+	InvalidSpendWitness(&'static str),
+	/// Blinding source failed
+	Randomness(BlindingSourceError),
+	/// Output memo could not be encrypted
+	MemoEncryption(MemoError),
+}
+
+impl core::fmt::Display for WalletError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			WalletError::KeyDerivation(err) => write!(f, "Key derivation failed: {err}"),
+			WalletError::InvalidCompactBlock(field) => {
+				write!(f, "Invalid compact block field: {field}")
+			}
+			WalletError::BlockOutOfOrder { last, block } => {
+				write!(f, "Block #{block} is not after last scanned block #{last}")
+			}
+			WalletError::InsufficientFunds {
+				asset_id,
+				available,
+				required,
+			} => write!(
+				f,
+				"Insufficient funds of asset {asset_id}: {available} available, {required} required"
+			),
+			WalletError::NoInputCombination { asset_id, amount } => {
+				write!(
+					f,
+					"No unspent notes of asset {asset_id} fit amount {amount}"
+				)
+			}
+			WalletError::AmountOverflow => write!(f, "Amount plus fee overflows"),
+			WalletError::FeeNotSupported => write!(f, "Unshields cannot pay a relayer fee"),
+			WalletError::InvalidSpendWitness(field) => {
+				write!(f, "Invalid spend witness field: {field}")
+			}
+			WalletError::Randomness(err) => write!(f, "Randomness unavailable: {err}"),
+			WalletError::MemoEncryption(err) => write!(f, "Memo encryption failed: {err}"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WalletError {}
+
+impl From<KeyDerivationError> for WalletError {
+	fn from(err: KeyDerivationError) -> Self {
+		WalletError::KeyDerivation(err)
+	}
+}
+
+impl From<BlindingSourceError> for WalletError {
+	fn from(err: BlindingSourceError) -> Self {
+		WalletError::Randomness(err)
+	}
+}
@@ -0,0 +1,18 @@
+//! Domain layer: wallet entities, RPC mirror types, pool calls and errors
+
+pub mod compact_block;
+pub mod error;
+pub mod owned_note;
+pub mod pool_call;
+pub mod recipient;
+pub mod spend_witness;
+
+pub use compact_block::{CompactBlock, CompactBlockPage, CompactOutput};
+pub use error::WalletError;
+pub use owned_note::OwnedNote;
+pub use pool_call::{
+	PrivateTransferCall, UnshieldCall, MAX_PROOF_SIZE, PRIVATE_TRANSFER_CALL_INDEX,
+	SHIELDED_POOL_PALLET_INDEX, UNSHIELD_CALL_INDEX,
+};
+pub use recipient::Recipient;
+pub use spend_witness::SpendWitness;
@@ -0,0 +1,37 @@
+//! OwnedNote entity.
+//!
+//! A note of the tree that decrypted for the wallet's account.
+
+use orbinum_zk_core::{Commitment, Note};
+
+/// Note owned by the wallet, with its tree position and spend status
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnedNote {
+	/// Position of the commitment in the Merkle tree
+	pub leaf_index: u32,
+	pub commitment: Commitment,
+	pub note: Note,
+	/// Receiving address the note was sent to
+	pub diversifier_index: u32,
+	/// Block that added the note to the tree
+	pub received_at: u64,
+	/// Block that revealed one of its nullifiers, `None` while unspent
+	pub spent_at: Option<u64>,
+}
+
+impl OwnedNote {
+	/// Token amount of the note
+	pub fn value(&self) -> u64 {
+		self.note.value()
+	}
+
+	/// Asset of the note (0 = native token)
+	pub fn asset_id(&self) -> u64 {
+		self.note.asset_id()
+	}
+
+	/// Returns `true` once a nullifier of the note was seen on-chain
+	pub fn is_spent(&self) -> bool {
+		self.spent_at.is_some()
+	}
+}
@@ -0,0 +1,116 @@
+//! Shielded pool call arguments.
+//!
+//! Arguments of `ShieldedPool::private_transfer` and `ShieldedPool::unshield`
+//! in declaration order. The SCALE encoding of each struct equals the encoded
+//! call arguments (`BoundedVec`, `Nullifier`, `Commitment` and `EncryptedMemo`
+//! encode like their inner values), so `call_data` is ready to wrap in an
+//! extrinsic once the proof is attached.
+
+use alloc::{vec, vec::Vec};
+use parity_scale_codec::Encode;
+
+/// Index of `pallet_shielded_pool` in the template runtime
+pub const SHIELDED_POOL_PALLET_INDEX: u8 = 13;
+
+/// Call index of `private_transfer`
+pub const PRIVATE_TRANSFER_CALL_INDEX: u8 = 1;
+
+/// Call index of `unshield`
+pub const UNSHIELD_CALL_INDEX: u8 = 2;
+
+/// Maximum proof size accepted by the pallet
+pub const MAX_PROOF_SIZE: usize = 512;
+
+/// Arguments of `private_transfer`
+#[derive(Encode, Clone, Debug, PartialEq, Eq)]
+pub struct PrivateTransferCall {
+	/// Groth16 proof, empty until attached
+	pub proof: Vec<u8>,
+	pub merkle_root: [u8; 32],
+	pub nullifiers: Vec<[u8; 32]>,
+	pub commitments: Vec<[u8; 32]>,
+	/// One encrypted memo per commitment
+	pub encrypted_memos: Vec<Vec<u8>>,
+}
+
+/// Arguments of `unshield`
+#[derive(Encode, Clone, Debug, PartialEq, Eq)]
+pub struct UnshieldCall {
+	/// Groth16 proof, empty until attached
+	pub proof: Vec<u8>,
+	pub merkle_root: [u8; 32],
+	pub nullifier: [u8; 32],
+	pub asset_id: u32,
+	pub amount: u128,
+	/// `AccountId32` receiving the funds
+	pub recipient: [u8; 32],
+	/// Nonce of the proof's recipient binding
+	pub recipient_nonce: [u8; 32],
+}
+
+impl PrivateTransferCall {
+	/// Encoded call (`pallet_index || call_index || arguments`)
+	pub fn call_data(&self, pallet_index: u8) -> Vec<u8> {
+		let mut data = vec![pallet_index, PRIVATE_TRANSFER_CALL_INDEX];
+		self.encode_to(&mut data);
+		data
+	}
+}
+
+impl UnshieldCall {
+	/// Encoded call (`pallet_index || call_index || arguments`)
+	pub fn call_data(&self, pallet_index: u8) -> Vec<u8> {
+		let mut data = vec![pallet_index, UNSHIELD_CALL_INDEX];
+		self.encode_to(&mut data);
+		data
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_private_transfer_call_data_layout() {
+		let call = PrivateTransferCall {
+			proof: vec![9u8; 3],
+			merkle_root: [1u8; 32],
+			nullifiers: vec![[2u8; 32]],
+			commitments: vec![[3u8; 32]],
+			encrypted_memos: vec![vec![4u8; 2]],
+		};
+
+		let data = call.call_data(SHIELDED_POOL_PALLET_INDEX);
+
+		assert_eq!(&data[..2], &[13, 1]);
+		// Compact length prefixes: 3 << 2, 1 << 2, 1 << 2, 1 << 2, 2 << 2
+		assert_eq!(&data[2..6], &[12, 9, 9, 9]);
+		assert_eq!(&data[6..38], &[1u8; 32]);
+		assert_eq!(data[38], 4);
+		assert_eq!(data.len(), 2 + 4 + 32 + 33 + 33 + 1 + 3);
+	}
+
+	#[test]
+	fn test_unshield_call_data_layout() {
+		let call = UnshieldCall {
+			proof: vec![],
+			merkle_root: [1u8; 32],
+			nullifier: [2u8; 32],
+			asset_id: 7,
+			amount: 500,
+			recipient: [3u8; 32],
+			recipient_nonce: [4u8; 32],
+		};
+
+		let data = call.call_data(SHIELDED_POOL_PALLET_INDEX);
+
+		assert_eq!(&data[..3], &[13, 2, 0]);
+		assert_eq!(&data[67..71], &7u32.to_le_bytes());
+		assert_eq!(&data[71..87], &500u128.to_le_bytes());
+		assert_eq!(data.len(), 3 + 32 + 32 + 4 + 16 + 32 + 32);
+	}
+}
@@ -0,0 +1,23 @@
+//! Recipient value object.
+
+use orbinum_zk_core::OwnerPubkey;
+
+/// Where an output note goes: the owner key its commitment binds and the
+/// memo viewing key its memo is sealed to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Recipient {
+	/// Owner key of one of the recipient's diversified addresses
+	pub owner_pubkey: OwnerPubkey,
+	/// Recipient's memo viewing key (`KeySet::viewing_key`)
+	pub viewing_key: [u8; 32],
+}
+
+impl Recipient {
+	/// Creates a recipient
+	pub fn new(owner_pubkey: OwnerPubkey, viewing_key: [u8; 32]) -> Self {
+		Self {
+			owner_pubkey,
+			viewing_key,
+		}
+	}
+}
@@ -0,0 +1,113 @@
+//! Spend witness type.
+//!
+//! Mirror of the `orbinum_v2_getSpendWitness` response: the Merkle path of
+//! one note against an anchor root still in the pool's historic-root window.
+//! Fields the builder does not need (pruning estimates) are ignored.
+
+use alloc::{string::String, vec::Vec};
+use orbinum_zk_core::FieldElement;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{compact_block::parse_hash, error::WalletError};
+
+/// Merkle path of one note, read at one block
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SpendWitness {
+	/// Root the path leads to
+	pub anchor_root: String,
+	/// Block the anchor root was produced in
+	pub anchor_block: u64,
+	pub leaf_index: u32,
+	/// Sibling hashes from leaf to root
+	pub siblings: Vec<String>,
+}
+
+impl SpendWitness {
+	/// Anchor root bytes (field element, little-endian)
+	pub fn anchor_root_bytes(&self) -> Result<[u8; 32], WalletError> {
+		parse_hash(
+			&self.anchor_root,
+			WalletError::InvalidSpendWitness("anchor_root"),
+		)
+	}
+
+	/// Sibling hashes as field elements, from leaf to root
+	pub fn sibling_elements(&self) -> Result<Vec<FieldElement>, WalletError> {
+		self.siblings
+			.iter()
+			.map(|sibling| {
+				let bytes = parse_hash(sibling, WalletError::InvalidSpendWitness("siblings"))?;
+				FieldElement::from_canonical_le_bytes(&bytes)
+					.ok_or(WalletError::InvalidSpendWitness("siblings"))
+			})
+			.collect()
+	}
+
+	/// Left/right position of the path at each level (`true` = right child)
+	pub fn positions(&self) -> Vec<bool> {
+		(0..self.siblings.len() as u32)
+			.map(|level| self.leaf_index.checked_shr(level).unwrap_or(0) & 1 == 1)
+			.collect()
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_deserializes_rpc_witness() {
+		let json = r#"{
+			"anchor_root": "0x0101010101010101010101010101010101010101010101010101010101010101",
+			"anchor_block": 12,
+			"leaf_index": 5,
+			"siblings": [
+				"0x0200000000000000000000000000000000000000000000000000000000000000",
+				"0x0300000000000000000000000000000000000000000000000000000000000000",
+				"0x0400000000000000000000000000000000000000000000000000000000000000"
+			],
+			"insertions_until_pruned": 90,
+			"blocks_until_pruned": null
+		}"#;
+
+		let witness: SpendWitness = serde_json::from_str(json).unwrap();
+
+		assert_eq!(witness.anchor_root_bytes(), Ok([1u8; 32]));
+		assert_eq!(
+			witness.sibling_elements().unwrap(),
+			alloc::vec![
+				FieldElement::from_u64(2),
+				FieldElement::from_u64(3),
+				FieldElement::from_u64(4)
+			]
+		);
+		assert_eq!(witness.positions(), alloc::vec![true, false, true]);
+	}
+
+	#[test]
+	fn test_rejects_non_canonical_siblings() {
+		let witness = SpendWitness {
+			anchor_root: "0x01".into(),
+			anchor_block: 1,
+			leaf_index: 0,
+			siblings: alloc::vec![format_hash(&[0xff; 32])],
+		};
+
+		assert_eq!(
+			witness.anchor_root_bytes(),
+			Err(WalletError::InvalidSpendWitness("anchor_root"))
+		);
+		assert_eq!(
+			witness.sibling_elements(),
+			Err(WalletError::InvalidSpendWitness("siblings"))
+		);
+	}
+
+	fn format_hash(bytes: &[u8; 32]) -> String {
+		alloc::format!("0x{}", hex::encode(bytes))
+	}
+}
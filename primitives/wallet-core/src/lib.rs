@@ -0,0 +1,48 @@
+//! # Orbinum Wallet Core
+//!
+//! Shared shielded-wallet logic, so wallets stop re-implementing pool rules
+//! with subtle incompatibilities:
+//!
+//! - **Keys**: `Account` derives an HD account (`m/32'/coin_type'/account'`),
+//!   its diversified addresses and nullifiers (`orbinum-zk-core`), and its
+//!   memo `KeySet` (`orbinum-encrypted-memo`)
+//! - **Memo scanning**: outputs are trial-decrypted with the memo viewing key
+//!   and accepted only if the memo opens the on-chain commitment
+//! - **Note tracking**: `Wallet` follows the compact-block API
+//!   (`orbinum_v2_getCompactBlocks`, also served by `orbinum-indexer`),
+//!   marks notes spent from their nullifiers and rewinds on reorgs
+//! - **Balances**: spendable balance per asset
+//! - **Transactions**: `TransactionBuilder` selects input notes, adds change
+//!   or relayer-fee notes, builds the `orbinum-zk-circuits` witness and the
+//!   `private_transfer` / `unshield` call arguments
+//!
+//! ## Architecture
+//!
+//! - **Domain**: compact block and spend witness types, `OwnedNote`,
+//!   `Recipient`, pool call arguments, `WalletError`
+//! - **Application**: `Account`, `Wallet` and `TransactionBuilder`
+//! - **wasm**: `wasm-bindgen` exports for browser wallets
+//!
+//! ## Features
+//!
+//! - `std`: Enable standard library support (default)
+//! - `wasm`: `wasm-bindgen` exports for browser wallets
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod application;
+pub mod domain;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use application::{
+	Account, BlockScan, Fee, PlannedOutput, PreparedTransfer, PreparedUnshield, ReceivedNote,
+	SelectionStrategy, TransactionBuilder, TransferPlan, UnshieldPlan, Wallet,
+};
+pub use domain::{
+	CompactBlock, CompactBlockPage, CompactOutput, OwnedNote, PrivateTransferCall, Recipient,
+	SpendWitness, UnshieldCall, WalletError, SHIELDED_POOL_PALLET_INDEX,
+};
@@ -0,0 +1,81 @@
+//! WebAssembly bindings for browser wallets
+//!
+//! A stateful `Wallet` class over the same scanning code native wallets use.
+//! Compact blocks are passed as the JSON returned by
+//! `orbinum_v2_getCompactBlocks`; balances are decimal strings since they
+//! may exceed `Number.MAX_SAFE_INTEGER`.
+
+use alloc::{
+	string::{String, ToString},
+	vec::Vec,
+};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+	application::{Account, Wallet},
+	domain::{CompactBlock, CompactBlockPage},
+};
+
+/// Shielded wallet of one HD account
+#[wasm_bindgen(js_name = Wallet)]
+pub struct WasmWallet {
+	inner: Wallet,
+}
+
+#[wasm_bindgen(js_class = Wallet)]
+impl WasmWallet {
+	/// Derives the wallet of account `m/32'/coin_type'/account'` from a seed
+	#[wasm_bindgen(constructor)]
+	pub fn new(seed: &[u8], coin_type: u32, account: u32) -> Result<WasmWallet, JsError> {
+		let account = Account::from_seed(seed, coin_type, account)
+			.map_err(|err| JsError::new(&err.to_string()))?;
+		Ok(Self {
+			inner: Wallet::new(account),
+		})
+	}
+
+	/// Scans a `CompactBlockPage` (or a bare array of compact blocks),
+	/// returning the number of notes received
+	#[wasm_bindgen(js_name = scanBlocks)]
+	pub fn scan_blocks(&mut self, json: &str) -> Result<u32, JsError> {
+		let blocks: Vec<CompactBlock> = match serde_json::from_str::<CompactBlockPage>(json) {
+			Ok(page) => page.blocks,
+			Err(_) => serde_json::from_str(json).map_err(|err| JsError::new(&err.to_string()))?,
+		};
+		let scan = self
+			.inner
+			.scan_blocks(&blocks)
+			.map_err(|err| JsError::new(&err.to_string()))?;
+		Ok(scan.received.len() as u32)
+	}
+
+	/// Forgets everything scanned after `block_number` (reorg recovery)
+	pub fn rewind(&mut self, block_number: u64) {
+		self.inner.rewind(block_number);
+	}
+
+	/// Last scanned block (`undefined` before the first scan)
+	#[wasm_bindgen(js_name = lastScannedBlock)]
+	pub fn last_scanned_block(&self) -> Option<u64> {
+		self.inner.last_scanned_block()
+	}
+
+	/// Spendable balance of `asset_id`, as a decimal string
+	pub fn balance(&self, asset_id: u64) -> String {
+		self.inner.balance(asset_id).to_string()
+	}
+
+	/// `orb1...` receiving address `index`
+	pub fn address(&self, index: u64) -> String {
+		self.inner.account().shielded_address(index).encode()
+	}
+
+	/// Leaf indices of the unspent notes
+	#[wasm_bindgen(js_name = unspentNotes)]
+	pub fn unspent_notes(&self) -> Vec<u32> {
+		self.inner
+			.unspent_notes()
+			.map(|note| note.leaf_index)
+			.collect()
+	}
+}
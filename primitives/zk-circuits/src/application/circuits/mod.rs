@@ -7,6 +7,7 @@
 //!
 //! - `note`: Note commitment and nullifier circuits
 //! - `transfer`: Complete private transfer circuit
+//! - `viewing_key`: Viewing key ownership circuit (disclosure authentication)
 //!
 //! ## Usage
 //!
@@ -19,3 +20,4 @@
 
 pub mod note;
 pub mod transfer;
+pub mod viewing_key;
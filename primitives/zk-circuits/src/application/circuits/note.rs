@@ -72,6 +72,29 @@ pub fn nullifier(
 	poseidon_hash_2(cs, &[(*commitment).clone(), (*spending_key).clone()])
 }
 
+// ============================================================================
+// Recipient Binding
+// ============================================================================
+
+/// Computes the unshield recipient binding (native)
+///
+/// `recipient_binding = Poseidon(recipient, nonce)`
+///
+/// The prover picks `nonce`, so a proof lifted from the mempool cannot be
+/// resubmitted for a different recipient.
+pub fn recipient_binding_native(recipient: Bn254Fr, nonce: Bn254Fr) -> Bn254Fr {
+	poseidon_hash_2_native(&[recipient, nonce])
+}
+
+/// Computes the unshield recipient binding (in-circuit)
+pub fn recipient_binding(
+	cs: ConstraintSystemRef<Bn254Fr>,
+	recipient: &FpVar<Bn254Fr>,
+	nonce: &FpVar<Bn254Fr>,
+) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+	poseidon_hash_2(cs, &[(*recipient).clone(), (*nonce).clone()])
+}
+
 // ============================================================================
 // Note Structure
 // ============================================================================
@@ -415,6 +438,45 @@ mod tests {
 		assert_eq!(nullifier_var.value().unwrap(), expected);
 	}
 
+	// ===== Recipient Binding Tests =====
+
+	#[test]
+	fn test_recipient_binding_depends_on_nonce() {
+		let recipient = Bn254Fr::from(999u64);
+
+		let b1 = recipient_binding_native(recipient, Bn254Fr::from(1u64));
+		let b2 = recipient_binding_native(recipient, Bn254Fr::from(2u64));
+		assert_ne!(b1, b2);
+	}
+
+	#[test]
+	fn test_recipient_binding_depends_on_recipient() {
+		let nonce = Bn254Fr::from(7u64);
+
+		let b1 = recipient_binding_native(Bn254Fr::from(999u64), nonce);
+		let b2 = recipient_binding_native(Bn254Fr::from(111u64), nonce);
+		assert_ne!(b1, b2);
+	}
+
+	#[test]
+	fn test_recipient_binding_circuit() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+
+		let recipient = Bn254Fr::from(999u64);
+		let nonce = Bn254Fr::from(42u64);
+
+		let recipient_var = FpVar::new_witness(cs.clone(), || Ok(recipient)).unwrap();
+		let nonce_var = FpVar::new_witness(cs.clone(), || Ok(nonce)).unwrap();
+
+		let binding_var = recipient_binding(cs.clone(), &recipient_var, &nonce_var).unwrap();
+
+		assert!(cs.is_satisfied().unwrap());
+		assert_eq!(
+			binding_var.value().unwrap(),
+			recipient_binding_native(recipient, nonce)
+		);
+	}
+
 	// ===== Note Struct Tests =====
 
 	#[test]
@@ -0,0 +1,271 @@
+//! Viewing Key Ownership Circuit
+//!
+//! Proves the prover holds the viewing key behind a registered `viewing_key_hash`
+//! without revealing it. Used to authenticate `submit_disclosure` beyond the
+//! "hash is non-zero" check.
+//!
+//! - `viewing_key_hash = Poseidon(VIEWING_KEY_HASH_DOMAIN, viewing_key)`
+//! - The viewing key must be non-zero
+//! - The disclosed `commitment` is a public input so a proof cannot be replayed
+//!   for another note
+
+use alloc::{vec, vec::Vec};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::{
+	infrastructure::{gadgets::poseidon::poseidon_hash_2, native_crypto},
+	Bn254Fr,
+};
+
+/// Domain separator for viewing key hashes (same tag as memo viewing key derivation)
+pub const VIEWING_KEY_HASH_DOMAIN: &[u8] = b"orbinum-viewing-key-v1";
+
+/// Domain separator as a field element
+pub fn viewing_key_domain() -> Bn254Fr {
+	Bn254Fr::from_le_bytes_mod_order(VIEWING_KEY_HASH_DOMAIN)
+}
+
+/// Converts a 32-byte viewing key into a field element (little-endian, reduced)
+pub fn viewing_key_to_field(viewing_key: &[u8; 32]) -> Bn254Fr {
+	Bn254Fr::from_le_bytes_mod_order(viewing_key)
+}
+
+// ============================================================================
+// Viewing Key Hash
+// ============================================================================
+
+/// Computes the viewing key hash (native)
+///
+/// `viewing_key_hash = Poseidon(VIEWING_KEY_HASH_DOMAIN, viewing_key)`
+pub fn viewing_key_hash_native(viewing_key: Bn254Fr) -> Bn254Fr {
+	native_crypto::poseidon_hash_2(&[viewing_key_domain(), viewing_key])
+}
+
+/// Computes the viewing key hash (in-circuit)
+pub fn viewing_key_hash(
+	cs: ConstraintSystemRef<Bn254Fr>,
+	viewing_key: &FpVar<Bn254Fr>,
+) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+	let domain = FpVar::Constant(viewing_key_domain());
+	poseidon_hash_2(cs, &[domain, viewing_key.clone()])
+}
+
+// ============================================================================
+// Viewing Key Public Inputs
+// ============================================================================
+
+/// Viewing key ownership public inputs
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewingKeyPublicInputs {
+	/// Registered viewing key hash
+	pub viewing_key_hash: Bn254Fr,
+	/// Commitment of the note being disclosed
+	pub commitment: Bn254Fr,
+}
+
+impl ViewingKeyPublicInputs {
+	/// Serializes to a vector for proof verification
+	pub fn to_vec(&self) -> Vec<Bn254Fr> {
+		vec![self.viewing_key_hash, self.commitment]
+	}
+}
+
+// ============================================================================
+// Viewing Key Ownership Circuit
+// ============================================================================
+
+/// Viewing key ownership circuit
+///
+/// Uses `Option` for arkworks pattern (None during setup, Some during proving).
+#[derive(Clone)]
+pub struct ViewingKeyOwnershipCircuit {
+	/// Private viewing key (None during setup)
+	pub viewing_key: Option<Bn254Fr>,
+	/// Public commitment being disclosed (None during setup)
+	pub commitment: Option<Bn254Fr>,
+}
+
+impl ViewingKeyOwnershipCircuit {
+	/// Creates a circuit with witness data (for proving)
+	pub fn new(viewing_key: Bn254Fr, commitment: Bn254Fr) -> Self {
+		Self {
+			viewing_key: Some(viewing_key),
+			commitment: Some(commitment),
+		}
+	}
+
+	/// Creates a circuit for trusted setup (no concrete values needed)
+	pub fn new_for_setup() -> Self {
+		Self {
+			viewing_key: None,
+			commitment: None,
+		}
+	}
+
+	/// Gets the public inputs for this circuit (panics if witness is None)
+	pub fn public_inputs(&self) -> ViewingKeyPublicInputs {
+		let viewing_key = self
+			.viewing_key
+			.expect("Cannot get public inputs without viewing_key");
+		let commitment = self
+			.commitment
+			.expect("Cannot get public inputs without commitment");
+		ViewingKeyPublicInputs {
+			viewing_key_hash: viewing_key_hash_native(viewing_key),
+			commitment,
+		}
+	}
+}
+
+impl ConstraintSynthesizer<Bn254Fr> for ViewingKeyOwnershipCircuit {
+	fn generate_constraints(self, cs: ConstraintSystemRef<Bn254Fr>) -> Result<(), SynthesisError> {
+		let get_or_missing = |opt: Option<Bn254Fr>| -> Result<Bn254Fr, SynthesisError> {
+			opt.ok_or(SynthesisError::AssignmentMissing)
+		};
+
+		// Public inputs
+		let vk_hash = self.viewing_key.map(viewing_key_hash_native);
+		let vk_hash_var = FpVar::new_input(cs.clone(), || get_or_missing(vk_hash))?;
+		let commitment_var = FpVar::new_input(cs.clone(), || get_or_missing(self.commitment))?;
+
+		// Private inputs
+		let viewing_key_var = FpVar::new_witness(cs.clone(), || get_or_missing(self.viewing_key))?;
+
+		// CONSTRAINT 1: viewing key is non-zero
+		viewing_key_var.enforce_not_equal(&FpVar::Constant(Bn254Fr::from(0u64)))?;
+
+		// CONSTRAINT 2: hash matches the registered viewing_key_hash
+		let computed_hash = viewing_key_hash(cs.clone(), &viewing_key_var)?;
+		computed_hash.enforce_equal(&vk_hash_var)?;
+
+		// CONSTRAINT 3: bind the commitment into the proof (unused inputs are malleable)
+		let commitment_squared = &commitment_var * &commitment_var;
+		let expected_squared =
+			FpVar::new_witness(cs, || get_or_missing(self.commitment.map(|c| c * c)))?;
+		commitment_squared.enforce_equal(&expected_squared)?;
+
+		Ok(())
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::circuit_ids::VIEWING_KEY_OWNERSHIP;
+	use ark_relations::r1cs::ConstraintSystem;
+
+	fn satisfied(viewing_key: Bn254Fr, vk_hash: Bn254Fr, commitment: Bn254Fr) -> bool {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let vk_hash_var = FpVar::new_input(cs.clone(), || Ok(vk_hash)).unwrap();
+		let _commitment = FpVar::new_input(cs.clone(), || Ok(commitment)).unwrap();
+		let vk_var = FpVar::new_witness(cs.clone(), || Ok(viewing_key)).unwrap();
+
+		// A zero key has no inverse, so the witness cannot even be assigned
+		if vk_var
+			.enforce_not_equal(&FpVar::Constant(Bn254Fr::from(0u64)))
+			.is_err()
+		{
+			return false;
+		}
+		viewing_key_hash(cs.clone(), &vk_var)
+			.unwrap()
+			.enforce_equal(&vk_hash_var)
+			.unwrap();
+
+		cs.is_satisfied().unwrap()
+	}
+
+	// ===== Viewing Key Hash Tests =====
+
+	#[test]
+	fn test_viewing_key_hash_deterministic() {
+		let vk = Bn254Fr::from(42u64);
+		assert_eq!(viewing_key_hash_native(vk), viewing_key_hash_native(vk));
+		assert_ne!(
+			viewing_key_hash_native(vk),
+			viewing_key_hash_native(Bn254Fr::from(43u64))
+		);
+	}
+
+	#[test]
+	fn test_viewing_key_hash_domain_separated() {
+		let vk = Bn254Fr::from(42u64);
+		let plain = native_crypto::poseidon_hash_2(&[Bn254Fr::from(0u64), vk]);
+		assert_ne!(viewing_key_hash_native(vk), plain);
+	}
+
+	#[test]
+	fn test_viewing_key_to_field_little_endian() {
+		let mut bytes = [0u8; 32];
+		bytes[0] = 7;
+		assert_eq!(viewing_key_to_field(&bytes), Bn254Fr::from(7u64));
+	}
+
+	// ===== Circuit Tests =====
+
+	#[test]
+	fn test_viewing_key_circuit_satisfied() {
+		let circuit = ViewingKeyOwnershipCircuit::new(Bn254Fr::from(1234u64), Bn254Fr::from(99u64));
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		circuit.generate_constraints(cs.clone()).unwrap();
+
+		assert!(cs.is_satisfied().unwrap());
+		assert_eq!(
+			cs.num_instance_variables() - 1,
+			VIEWING_KEY_OWNERSHIP.public_input_count()
+		);
+	}
+
+	#[test]
+	fn test_wrong_viewing_key_unsatisfied() {
+		let registered = viewing_key_hash_native(Bn254Fr::from(1234u64));
+		assert!(!satisfied(
+			Bn254Fr::from(4321u64),
+			registered,
+			Bn254Fr::from(99u64)
+		));
+	}
+
+	#[test]
+	fn test_zero_viewing_key_unsatisfied() {
+		let zero = Bn254Fr::from(0u64);
+		assert!(!satisfied(
+			zero,
+			viewing_key_hash_native(zero),
+			Bn254Fr::from(99u64)
+		));
+	}
+
+	#[test]
+	fn test_public_inputs_layout() {
+		let vk = Bn254Fr::from(1234u64);
+		let commitment = Bn254Fr::from(99u64);
+		let circuit = ViewingKeyOwnershipCircuit::new(vk, commitment);
+
+		let inputs = circuit.public_inputs().to_vec();
+		assert_eq!(inputs.len(), VIEWING_KEY_OWNERSHIP.public_input_count());
+		assert_eq!(
+			inputs[VIEWING_KEY_OWNERSHIP
+				.input_index("viewing_key_hash")
+				.unwrap()],
+			viewing_key_hash_native(vk)
+		);
+		assert_eq!(
+			inputs[VIEWING_KEY_OWNERSHIP.input_index("commitment").unwrap()],
+			commitment
+		);
+	}
+
+	#[test]
+	fn test_viewing_key_circuit_new_for_setup() {
+		let circuit = ViewingKeyOwnershipCircuit::new_for_setup();
+		assert!(circuit.viewing_key.is_none());
+		assert!(circuit.commitment.is_none());
+	}
+}
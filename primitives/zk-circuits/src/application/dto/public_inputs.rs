@@ -49,8 +49,8 @@ pub struct UnshieldPublicInputs {
 	pub nullifier: Bn254Fr,
 	/// Withdrawal amount
 	pub amount: Bn254Fr,
-	/// Recipient address
-	pub recipient: Bn254Fr,
+	/// Recipient binding: `Poseidon(recipient, nonce)`
+	pub recipient_binding: Bn254Fr,
 }
 
 impl UnshieldPublicInputs {
@@ -59,13 +59,13 @@ impl UnshieldPublicInputs {
 		merkle_root: Bn254Fr,
 		nullifier: Bn254Fr,
 		amount: Bn254Fr,
-		recipient: Bn254Fr,
+		recipient_binding: Bn254Fr,
 	) -> Self {
 		Self {
 			merkle_root,
 			nullifier,
 			amount,
-			recipient,
+			recipient_binding,
 		}
 	}
 
@@ -75,7 +75,7 @@ impl UnshieldPublicInputs {
 			self.merkle_root,
 			self.nullifier,
 			self.amount,
-			self.recipient,
+			self.recipient_binding,
 		]
 	}
 }
@@ -302,14 +302,14 @@ mod tests {
 		let merkle_root = Bn254Fr::from(100u64);
 		let nullifier = Bn254Fr::from(200u64);
 		let amount = Bn254Fr::from(1000u64);
-		let recipient = Bn254Fr::from(999u64);
+		let recipient_binding = Bn254Fr::from(999u64);
 
-		let inputs = UnshieldPublicInputs::new(merkle_root, nullifier, amount, recipient);
+		let inputs = UnshieldPublicInputs::new(merkle_root, nullifier, amount, recipient_binding);
 
 		assert_eq!(inputs.merkle_root, merkle_root);
 		assert_eq!(inputs.nullifier, nullifier);
 		assert_eq!(inputs.amount, amount);
-		assert_eq!(inputs.recipient, recipient);
+		assert_eq!(inputs.recipient_binding, recipient_binding);
 	}
 
 	#[test]
@@ -341,7 +341,7 @@ mod tests {
 		assert_eq!(elements[0], Bn254Fr::from(10u64)); // merkle_root
 		assert_eq!(elements[1], Bn254Fr::from(20u64)); // nullifier
 		assert_eq!(elements[2], Bn254Fr::from(30u64)); // amount
-		assert_eq!(elements[3], Bn254Fr::from(40u64)); // recipient
+		assert_eq!(elements[3], Bn254Fr::from(40u64)); // recipient_binding
 	}
 
 	#[test]
@@ -359,7 +359,7 @@ mod tests {
 		assert_eq!(inputs1.merkle_root, inputs2.merkle_root);
 		assert_eq!(inputs1.nullifier, inputs2.nullifier);
 		assert_eq!(inputs1.amount, inputs2.amount);
-		assert_eq!(inputs1.recipient, inputs2.recipient);
+		assert_eq!(inputs1.recipient_binding, inputs2.recipient_binding);
 	}
 
 	#[test]
@@ -451,7 +451,7 @@ mod tests {
 			Bn254Fr::from(1u64),
 			Bn254Fr::from(2u64),
 			Bn254Fr::from(100u64),
-			Bn254Fr::from(111u64), // Different recipient
+			Bn254Fr::from(111u64), // Different recipient binding
 		);
 
 		assert_ne!(inputs1, inputs2);
@@ -503,7 +503,7 @@ mod tests {
 			Bn254Fr::from(0u64),
 		);
 
-		assert_eq!(inputs.recipient, Bn254Fr::from(0u64));
+		assert_eq!(inputs.recipient_binding, Bn254Fr::from(0u64));
 	}
 
 	// ===== Cross-Type Tests =====
@@ -527,4 +527,18 @@ mod tests {
 		assert_eq!(transfer.merkle_root, Bn254Fr::from(1u64));
 		assert_eq!(unshield.merkle_root, Bn254Fr::from(1u64));
 	}
+
+	#[test]
+	fn test_transfer_count_matches_circuit_schema() {
+		let inputs = TransferPublicInputs::new(
+			Bn254Fr::from(1u64),
+			vec![Bn254Fr::from(2u64), Bn254Fr::from(3u64)],
+			vec![Bn254Fr::from(4u64), Bn254Fr::from(5u64)],
+		);
+
+		assert_eq!(
+			inputs.count(),
+			crate::domain::circuit_ids::TRANSFER.public_input_count()
+		);
+	}
 }
@@ -2,7 +2,10 @@
 //!
 //! Data structures for circuit private witness (secret inputs).
 
-use crate::{application::circuits::note::Note, Bn254Fr};
+use crate::{
+	application::circuits::note::{recipient_binding_native, Note},
+	Bn254Fr,
+};
 use alloc::vec::Vec;
 use orbinum_zk_core::domain::value_objects::SpendingKey;
 
@@ -96,6 +99,8 @@ pub struct UnshieldWitness {
 	pub merkle_path: MerklePath,
 	/// Recipient address
 	pub recipient: Bn254Fr,
+	/// Prover-chosen nonce hashed with the recipient
+	pub recipient_nonce: Bn254Fr,
 }
 
 impl UnshieldWitness {
@@ -105,15 +110,22 @@ impl UnshieldWitness {
 		spending_key: SpendingKey,
 		merkle_path: MerklePath,
 		recipient: Bn254Fr,
+		recipient_nonce: Bn254Fr,
 	) -> Self {
 		Self {
 			note,
 			spending_key,
 			merkle_path,
 			recipient,
+			recipient_nonce,
 		}
 	}
 
+	/// Public recipient binding: `Poseidon(recipient, recipient_nonce)`
+	pub fn recipient_binding(&self) -> Bn254Fr {
+		recipient_binding_native(self.recipient, self.recipient_nonce)
+	}
+
 	/// Validate witness data
 	pub fn validate(&self) -> Result<(), &'static str> {
 		self.merkle_path.validate()
@@ -458,8 +470,9 @@ mod tests {
 		let key = SpendingKey::new(FieldElement::from_u64(3));
 		let path = MerklePath::new(vec![Bn254Fr::from(4u64)], vec![false]);
 		let recipient = Bn254Fr::from(999u64);
+		let nonce = Bn254Fr::from(7u64);
 
-		let witness = UnshieldWitness::new(note.clone(), key, path.clone(), recipient);
+		let witness = UnshieldWitness::new(note.clone(), key, path.clone(), recipient, nonce);
 
 		assert_eq!(witness.note, note);
 		assert_eq!(witness.spending_key, key);
@@ -473,8 +486,9 @@ mod tests {
 		let key = SpendingKey::new(FieldElement::from_u64(3));
 		let path = MerklePath::new(vec![Bn254Fr::from(4u64)], vec![false]);
 		let recipient = Bn254Fr::from(999u64);
+		let nonce = Bn254Fr::from(7u64);
 
-		let witness = UnshieldWitness::new(note, key, path, recipient);
+		let witness = UnshieldWitness::new(note, key, path, recipient, nonce);
 
 		assert!(witness.validate().is_ok());
 	}
@@ -488,8 +502,9 @@ mod tests {
 			vec![false], // Length mismatch
 		);
 		let recipient = Bn254Fr::from(999u64);
+		let nonce = Bn254Fr::from(7u64);
 
-		let witness = UnshieldWitness::new(note, key, invalid_path, recipient);
+		let witness = UnshieldWitness::new(note, key, invalid_path, recipient, nonce);
 
 		let result = witness.validate();
 		assert!(result.is_err());
@@ -505,8 +520,9 @@ mod tests {
 		let key = SpendingKey::new(FieldElement::from_u64(3));
 		let path = MerklePath::new(vec![Bn254Fr::from(4u64)], vec![false]);
 		let recipient = Bn254Fr::from(999u64);
+		let nonce = Bn254Fr::from(7u64);
 
-		let witness1 = UnshieldWitness::new(note, key, path, recipient);
+		let witness1 = UnshieldWitness::new(note, key, path, recipient, nonce);
 		let witness2 = witness1.clone();
 
 		assert_eq!(witness1.note, witness2.note);
@@ -521,8 +537,9 @@ mod tests {
 		let key = SpendingKey::new(FieldElement::from_u64(3));
 		let path = MerklePath::new(vec![Bn254Fr::from(4u64)], vec![false]);
 		let recipient = Bn254Fr::from(999u64);
+		let nonce = Bn254Fr::from(7u64);
 
-		let witness = UnshieldWitness::new(note, key, path, recipient);
+		let witness = UnshieldWitness::new(note, key, path, recipient, nonce);
 
 		let debug_str = format!("{witness:?}");
 		assert!(debug_str.contains("UnshieldWitness"));
@@ -534,8 +551,9 @@ mod tests {
 		let key = SpendingKey::new(FieldElement::from_u64(3));
 		let path = MerklePath::new(vec![Bn254Fr::from(4u64)], vec![false]);
 		let recipient = Bn254Fr::from(999u64);
+		let nonce = Bn254Fr::from(7u64);
 
-		let witness = UnshieldWitness::new(note, key, path, recipient);
+		let witness = UnshieldWitness::new(note, key, path, recipient, nonce);
 
 		assert!(witness.validate().is_ok());
 		assert_eq!(witness.note.value, Bn254Fr::from(0u64));
@@ -547,21 +565,40 @@ mod tests {
 		let key = SpendingKey::new(FieldElement::from_u64(3));
 		let path = MerklePath::new(vec![Bn254Fr::from(4u64)], vec![false]);
 		let recipient = Bn254Fr::from(0u64);
+		let nonce = Bn254Fr::from(7u64);
 
-		let witness = UnshieldWitness::new(note, key, path, recipient);
+		let witness = UnshieldWitness::new(note, key, path, recipient, nonce);
 
 		assert!(witness.validate().is_ok());
 		assert_eq!(witness.recipient, Bn254Fr::from(0u64));
 	}
 
+	#[test]
+	fn test_unshield_witness_recipient_binding() {
+		let note = Note::new(100, 1, Bn254Fr::from(1u64), Bn254Fr::from(2u64));
+		let key = SpendingKey::new(FieldElement::from_u64(3));
+		let path = MerklePath::new(vec![Bn254Fr::from(4u64)], vec![false]);
+		let recipient = Bn254Fr::from(999u64);
+		let nonce = Bn254Fr::from(7u64);
+
+		let witness = UnshieldWitness::new(note, key, path, recipient, nonce);
+
+		assert_eq!(
+			witness.recipient_binding(),
+			recipient_binding_native(recipient, nonce)
+		);
+		assert_ne!(witness.recipient_binding(), recipient);
+	}
+
 	#[test]
 	fn test_unshield_witness_large_amount() {
 		let note = Note::new(u64::MAX, 1, Bn254Fr::from(1u64), Bn254Fr::from(2u64));
 		let key = SpendingKey::new(FieldElement::from_u64(3));
 		let path = MerklePath::new(vec![Bn254Fr::from(4u64)], vec![false]);
 		let recipient = Bn254Fr::from(999u64);
+		let nonce = Bn254Fr::from(7u64);
 
-		let witness = UnshieldWitness::new(note, key, path, recipient);
+		let witness = UnshieldWitness::new(note, key, path, recipient, nonce);
 
 		assert!(witness.validate().is_ok());
 		assert_eq!(witness.note.value, Bn254Fr::from(u64::MAX));
@@ -575,8 +612,9 @@ mod tests {
 		let positions: Vec<_> = (0..20).map(|i| i % 2 == 0).collect();
 		let path = MerklePath::new(elements, positions);
 		let recipient = Bn254Fr::from(999u64);
+		let nonce = Bn254Fr::from(7u64);
 
-		let witness = UnshieldWitness::new(note, key, path, recipient);
+		let witness = UnshieldWitness::new(note, key, path, recipient, nonce);
 
 		assert!(witness.validate().is_ok());
 		assert_eq!(witness.merkle_path.depth(), 20);
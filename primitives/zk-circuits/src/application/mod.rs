@@ -4,11 +4,14 @@
 //! This layer contains:
 //! - Circuits: Complete circuit implementations (use cases)
 //! - DTOs: Data Transfer Objects for public/private inputs
+//! - Prover: Groth16 proving helpers (`prover` feature, std only)
 //!
 //! This layer orchestrates domain and infrastructure components.
 
 pub mod circuits;
 pub mod dto;
+#[cfg(feature = "prover")]
+pub mod prover;
 
 pub use circuits::*;
 pub use dto::*;
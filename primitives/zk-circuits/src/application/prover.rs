@@ -0,0 +1,449 @@
+//! Groth16 Prover Helpers (std only)
+//!
+//! Thin wrappers around ark-groth16 that take an injectable RNG and return proofs
+//! already in the byte layout `pallet-zk-verifier` expects:
+//! - Proof / VK: arkworks compressed serialization
+//! - Public inputs: one 32-byte little-endian field element each
+//!
+//! Use [`ProverRng::deterministic`] in tests and fixtures, [`ProverRng::os`] in
+//! wallets and services.
+//!
+//! With the `rapidsnark` feature, [`rapidsnark::RapidsnarkProver`] proves through
+//! the native rapidsnark binary instead, for interactive proving of large circuits.
+
+use alloc::{boxed::Box, vec::Vec};
+use ark_bn254::Bn254;
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+use ark_relations::r1cs::{ConstraintSynthesizer, SynthesisError};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_snark::SNARK;
+use ark_std::rand::{
+	rngs::{OsRng, StdRng},
+	CryptoRng, RngCore, SeedableRng,
+};
+
+use super::circuits::{transfer::TransferCircuit, viewing_key::ViewingKeyOwnershipCircuit};
+use crate::Bn254Fr;
+
+#[cfg(feature = "rapidsnark")]
+pub mod rapidsnark;
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Prover errors
+#[derive(Debug)]
+pub enum ProverError {
+	/// Circuit synthesis failed (missing witness, unsatisfiable setup)
+	Synthesis(SynthesisError),
+	/// Proof or key serialization failed
+	Serialization(SerializationError),
+	/// Witness does not satisfy the circuit
+	InvalidWitness,
+	/// External prover backend failed or produced malformed output
+	#[cfg(feature = "rapidsnark")]
+	Backend(alloc::string::String),
+}
+
+impl From<SynthesisError> for ProverError {
+	fn from(err: SynthesisError) -> Self {
+		ProverError::Synthesis(err)
+	}
+}
+
+impl From<SerializationError> for ProverError {
+	fn from(err: SerializationError) -> Self {
+		ProverError::Serialization(err)
+	}
+}
+
+// ============================================================================
+// RNG
+// ============================================================================
+
+/// Randomness source for setup and proving
+pub enum ProverRng {
+	/// Seeded RNG: identical proofs for identical inputs
+	Deterministic(Box<StdRng>),
+	/// Operating system randomness
+	Os(OsRng),
+}
+
+impl ProverRng {
+	/// Seeded RNG for tests and fixtures
+	pub fn deterministic(seed: u64) -> Self {
+		ProverRng::Deterministic(Box::new(StdRng::seed_from_u64(seed)))
+	}
+
+	/// OS randomness for production proofs
+	pub fn os() -> Self {
+		ProverRng::Os(OsRng)
+	}
+
+	/// Independent RNG of the same kind, for proving on another thread
+	///
+	/// Deterministic RNGs seed the fork from their own stream, so a sequence
+	/// of forks is reproducible.
+	pub fn fork(&mut self) -> Self {
+		match self {
+			ProverRng::Deterministic(rng) => {
+				let mut seed = [0u8; 32];
+				rng.fill_bytes(&mut seed);
+				ProverRng::Deterministic(Box::new(StdRng::from_seed(seed)))
+			}
+			ProverRng::Os(_) => ProverRng::Os(OsRng),
+		}
+	}
+}
+
+impl RngCore for ProverRng {
+	fn next_u32(&mut self) -> u32 {
+		match self {
+			ProverRng::Deterministic(rng) => rng.next_u32(),
+			ProverRng::Os(rng) => rng.next_u32(),
+		}
+	}
+
+	fn next_u64(&mut self) -> u64 {
+		match self {
+			ProverRng::Deterministic(rng) => rng.next_u64(),
+			ProverRng::Os(rng) => rng.next_u64(),
+		}
+	}
+
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		match self {
+			ProverRng::Deterministic(rng) => rng.fill_bytes(dest),
+			ProverRng::Os(rng) => rng.fill_bytes(dest),
+		}
+	}
+
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), ark_std::rand::Error> {
+		match self {
+			ProverRng::Deterministic(rng) => rng.try_fill_bytes(dest),
+			ProverRng::Os(rng) => rng.try_fill_bytes(dest),
+		}
+	}
+}
+
+impl CryptoRng for ProverRng {}
+
+// ============================================================================
+// Serialized Output
+// ============================================================================
+
+/// Proof and public inputs in pallet byte layout
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializedProof {
+	/// Compressed Groth16 proof
+	pub proof: Vec<u8>,
+	/// Public inputs as 32-byte LE field elements
+	pub public_inputs: Vec<[u8; 32]>,
+}
+
+/// Encodes a field element as 32 little-endian bytes
+pub fn field_to_bytes(value: &Bn254Fr) -> [u8; 32] {
+	let mut bytes = [0u8; 32];
+	bytes.copy_from_slice(&value.into_bigint().to_bytes_le()[..32]);
+	bytes
+}
+
+/// Encodes public inputs in pallet layout
+pub fn encode_public_inputs(inputs: &[Bn254Fr]) -> Vec<[u8; 32]> {
+	inputs.iter().map(field_to_bytes).collect()
+}
+
+/// Serializes a proof (compressed)
+pub fn serialize_proof(proof: &Proof<Bn254>) -> Result<Vec<u8>, ProverError> {
+	let mut bytes = Vec::new();
+	proof.serialize_compressed(&mut bytes)?;
+	Ok(bytes)
+}
+
+/// Serializes a verifying key (compressed), as registered on-chain
+pub fn serialize_vk(vk: &VerifyingKey<Bn254>) -> Result<Vec<u8>, ProverError> {
+	let mut bytes = Vec::new();
+	vk.serialize_compressed(&mut bytes)?;
+	Ok(bytes)
+}
+
+/// Serializes a proving key (compressed), as stored by proving services
+pub fn serialize_pk(pk: &ProvingKey<Bn254>) -> Result<Vec<u8>, ProverError> {
+	let mut bytes = Vec::new();
+	pk.serialize_compressed(&mut bytes)?;
+	Ok(bytes)
+}
+
+/// Deserializes and validates a proving key written by [`serialize_pk`]
+pub fn deserialize_pk(bytes: &[u8]) -> Result<ProvingKey<Bn254>, ProverError> {
+	Ok(ProvingKey::deserialize_compressed(bytes)?)
+}
+
+// ============================================================================
+// Setup & Proving
+// ============================================================================
+
+/// Circuit-specific Groth16 setup
+pub fn setup<C: ConstraintSynthesizer<Bn254Fr>>(
+	circuit: C,
+	rng: &mut ProverRng,
+) -> Result<(ProvingKey<Bn254>, VerifyingKey<Bn254>), ProverError> {
+	Ok(Groth16::<Bn254>::circuit_specific_setup(circuit, rng)?)
+}
+
+/// Proves `circuit` and serializes the result
+///
+/// `public_inputs` must be in the circuit's schema order; the proof is checked
+/// against them before returning so a bad witness is reported here rather than
+/// on-chain.
+pub fn prove<C: ConstraintSynthesizer<Bn254Fr>>(
+	pk: &ProvingKey<Bn254>,
+	circuit: C,
+	public_inputs: &[Bn254Fr],
+	rng: &mut ProverRng,
+) -> Result<SerializedProof, ProverError> {
+	let proof = Groth16::<Bn254>::prove(pk, circuit, rng)?;
+
+	if !Groth16::<Bn254>::verify(&pk.vk, public_inputs, &proof)? {
+		return Err(ProverError::InvalidWitness);
+	}
+
+	Ok(SerializedProof {
+		proof: serialize_proof(&proof)?,
+		public_inputs: encode_public_inputs(public_inputs),
+	})
+}
+
+/// Proves many circuits against one proving key in parallel
+///
+/// Each job is a circuit with its public inputs, as for [`prove`]. Witness
+/// synthesis and proving are spread over the available cores while `pk` is
+/// shared, so the key is loaded once per batch. Every job gets its own fork
+/// of `rng`, making deterministic batches independent of the thread count.
+/// Proofs are returned in job order; the first failing job fails the batch.
+pub fn prove_batch<C: ConstraintSynthesizer<Bn254Fr> + Send>(
+	pk: &ProvingKey<Bn254>,
+	jobs: Vec<(C, Vec<Bn254Fr>)>,
+	rng: &mut ProverRng,
+) -> Result<Vec<SerializedProof>, ProverError> {
+	let mut jobs: Vec<_> = jobs
+		.into_iter()
+		.map(|(circuit, inputs)| (circuit, inputs, rng.fork()))
+		.collect();
+
+	let threads = std::thread::available_parallelism()
+		.map_or(1, |n| n.get())
+		.min(jobs.len().max(1));
+	let chunk_size = jobs.len().div_ceil(threads).max(1);
+
+	let mut chunks = Vec::with_capacity(threads);
+	while jobs.len() > chunk_size {
+		let tail = jobs.split_off(chunk_size);
+		chunks.push(core::mem::replace(&mut jobs, tail));
+	}
+	chunks.push(jobs);
+
+	std::thread::scope(|scope| {
+		let handles: Vec<_> = chunks
+			.into_iter()
+			.map(|chunk| {
+				scope.spawn(move || {
+					chunk
+						.into_iter()
+						.map(|(circuit, inputs, mut rng)| prove(pk, circuit, &inputs, &mut rng))
+						.collect::<Result<Vec<_>, _>>()
+				})
+			})
+			.collect();
+
+		let mut proofs = Vec::new();
+		for handle in handles {
+			let chunk = handle
+				.join()
+				.unwrap_or_else(|panic| std::panic::resume_unwind(panic))?;
+			proofs.extend(chunk);
+		}
+		Ok(proofs)
+	})
+}
+
+/// Proves a private transfer
+pub fn prove_transfer(
+	pk: &ProvingKey<Bn254>,
+	circuit: TransferCircuit,
+	rng: &mut ProverRng,
+) -> Result<SerializedProof, ProverError> {
+	let public_inputs = circuit.public_inputs().to_vec();
+	prove(pk, circuit, &public_inputs, rng)
+}
+
+/// Proves viewing key ownership
+pub fn prove_viewing_key_ownership(
+	pk: &ProvingKey<Bn254>,
+	circuit: ViewingKeyOwnershipCircuit,
+	rng: &mut ProverRng,
+) -> Result<SerializedProof, ProverError> {
+	let public_inputs = circuit.public_inputs().to_vec();
+	prove(pk, circuit, &public_inputs, rng)
+}
+
+/// Proves viewing key ownership for many disclosures in parallel
+///
+/// See [`prove_batch`].
+pub fn prove_viewing_key_ownership_batch(
+	pk: &ProvingKey<Bn254>,
+	circuits: Vec<ViewingKeyOwnershipCircuit>,
+	rng: &mut ProverRng,
+) -> Result<Vec<SerializedProof>, ProverError> {
+	let jobs = circuits
+		.into_iter()
+		.map(|circuit| {
+			let public_inputs = circuit.public_inputs().to_vec();
+			(circuit, public_inputs)
+		})
+		.collect();
+	prove_batch(pk, jobs, rng)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::circuit_ids::VIEWING_KEY_OWNERSHIP;
+
+	fn viewing_key_circuit() -> ViewingKeyOwnershipCircuit {
+		ViewingKeyOwnershipCircuit::new(Bn254Fr::from(1234u64), Bn254Fr::from(99u64))
+	}
+
+	#[test]
+	fn test_deterministic_proofs_are_reproducible() {
+		let mut rng = ProverRng::deterministic(7);
+		let (pk, _) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+
+		let mut rng_a = ProverRng::deterministic(1);
+		let mut rng_b = ProverRng::deterministic(1);
+		let a = prove_viewing_key_ownership(&pk, viewing_key_circuit(), &mut rng_a).unwrap();
+		let b = prove_viewing_key_ownership(&pk, viewing_key_circuit(), &mut rng_b).unwrap();
+
+		assert_eq!(a, b);
+	}
+
+	#[test]
+	fn test_os_rng_proof_verifies() {
+		let mut rng = ProverRng::os();
+		let (pk, _) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+
+		let proof = prove_viewing_key_ownership(&pk, viewing_key_circuit(), &mut rng).unwrap();
+		assert!(!proof.proof.is_empty());
+	}
+
+	#[test]
+	fn test_serialized_layout() {
+		let mut rng = ProverRng::deterministic(7);
+		let (pk, vk) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+
+		let proof = prove_viewing_key_ownership(&pk, viewing_key_circuit(), &mut rng).unwrap();
+
+		// Compressed BN254 Groth16 proof: G1 (32) + G2 (64) + G1 (32)
+		assert_eq!(proof.proof.len(), 128);
+		assert_eq!(
+			proof.public_inputs.len(),
+			VIEWING_KEY_OWNERSHIP.public_input_count()
+		);
+		assert_eq!(
+			proof.public_inputs[1],
+			field_to_bytes(&Bn254Fr::from(99u64))
+		);
+		assert!(!serialize_vk(&vk).unwrap().is_empty());
+	}
+
+	#[test]
+	fn test_proving_key_roundtrip() {
+		let mut rng = ProverRng::deterministic(7);
+		let (pk, _) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+
+		let bytes = serialize_pk(&pk).unwrap();
+
+		assert_eq!(deserialize_pk(&bytes).unwrap(), pk);
+		assert!(deserialize_pk(&bytes[..bytes.len() - 1]).is_err());
+	}
+
+	#[test]
+	fn test_wrong_public_inputs_rejected() {
+		let mut rng = ProverRng::deterministic(7);
+		let (pk, _) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+
+		let wrong = [Bn254Fr::from(1u64), Bn254Fr::from(99u64)];
+		let result = prove(&pk, viewing_key_circuit(), &wrong, &mut rng);
+
+		assert!(matches!(result, Err(ProverError::InvalidWitness)));
+	}
+
+	fn batch_circuits(count: u64) -> Vec<ViewingKeyOwnershipCircuit> {
+		(0..count)
+			.map(|i| ViewingKeyOwnershipCircuit::new(Bn254Fr::from(1000 + i), Bn254Fr::from(i)))
+			.collect()
+	}
+
+	#[test]
+	fn test_batch_matches_sequential_forks() {
+		let mut rng = ProverRng::deterministic(7);
+		let (pk, _) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+
+		let mut batch_rng = ProverRng::deterministic(3);
+		let batch =
+			prove_viewing_key_ownership_batch(&pk, batch_circuits(5), &mut batch_rng).unwrap();
+
+		let mut seq_rng = ProverRng::deterministic(3);
+		let sequential: Vec<_> = batch_circuits(5)
+			.into_iter()
+			.map(|circuit| prove_viewing_key_ownership(&pk, circuit, &mut seq_rng.fork()).unwrap())
+			.collect();
+
+		assert_eq!(batch, sequential);
+		assert_eq!(
+			batch[4].public_inputs[1],
+			field_to_bytes(&Bn254Fr::from(4u64))
+		);
+	}
+
+	#[test]
+	fn test_batch_reports_invalid_witness() {
+		let mut rng = ProverRng::deterministic(7);
+		let (pk, _) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+
+		let mut jobs: Vec<_> = batch_circuits(3)
+			.into_iter()
+			.map(|circuit| {
+				let inputs = circuit.public_inputs().to_vec();
+				(circuit, inputs)
+			})
+			.collect();
+		jobs[2].1[0] = Bn254Fr::from(1u64);
+
+		let result = prove_batch(&pk, jobs, &mut rng);
+		assert!(matches!(result, Err(ProverError::InvalidWitness)));
+	}
+
+	#[test]
+	fn test_empty_batch() {
+		let mut rng = ProverRng::deterministic(7);
+		let (pk, _) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+
+		let proofs = prove_viewing_key_ownership_batch(&pk, Vec::new(), &mut rng).unwrap();
+		assert!(proofs.is_empty());
+	}
+
+	#[test]
+	fn test_field_to_bytes_little_endian() {
+		let bytes = field_to_bytes(&Bn254Fr::from(0x0102u64));
+		assert_eq!(bytes[0], 0x02);
+		assert_eq!(bytes[1], 0x01);
+		assert!(bytes[2..].iter().all(|b| *b == 0));
+	}
+}
@@ -0,0 +1,521 @@
+//! rapidsnark Proving Backend (`rapidsnark` feature, std only)
+//!
+//! Shells out to the native rapidsnark `prover` binary, which proves large
+//! circuits far faster than arkworks:
+//! 1. The circuit's full wire assignment is written as an iden3 `.wtns` file
+//! 2. `prover <circuit.zkey> <witness.wtns> <proof.json> <public.json>` runs
+//!    against a zkey exported for the same R1CS
+//! 3. The snarkjs-style JSON it writes is converted to the pallet byte layout
+//!
+//! Proofs are checked against the verifying key before they are returned, as
+//! with [`prove`](super::prove). Intermediate files live in a private temporary
+//! directory that is removed after each proof.
+
+use alloc::{format, string::String, vec::Vec};
+use ark_bn254::{Bn254, Fq, Fq2, G1Affine, G2Affine};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{prepare_verifying_key, Groth16, PreparedVerifyingKey, Proof, VerifyingKey};
+use ark_relations::r1cs::{
+	ConstraintSynthesizer, ConstraintSystem, OptimizationGoal, SynthesisMode,
+};
+use core::{
+	fmt::Display,
+	str::FromStr,
+	sync::atomic::{AtomicU64, Ordering},
+};
+use std::{fs, path::PathBuf, process::Command};
+
+use super::{encode_public_inputs, field_to_bytes, serialize_proof, ProverError, SerializedProof};
+use crate::{
+	application::circuits::{transfer::TransferCircuit, viewing_key::ViewingKeyOwnershipCircuit},
+	Bn254Fr,
+};
+
+// ============================================================================
+// Witness
+// ============================================================================
+
+/// Synthesizes the full wire assignment of `circuit`
+///
+/// Layout is the arkworks variable order: `1 || public inputs || private
+/// witness`, which is the wire order of a zkey exported from the same R1CS.
+pub fn synthesize_witness<C: ConstraintSynthesizer<Bn254Fr>>(
+	circuit: C,
+) -> Result<Vec<Bn254Fr>, ProverError> {
+	let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+	cs.set_optimization_goal(OptimizationGoal::Constraints);
+	cs.set_mode(SynthesisMode::Prove {
+		construct_matrices: true,
+	});
+	circuit.generate_constraints(cs.clone())?;
+
+	if !cs.is_satisfied()? {
+		return Err(ProverError::InvalidWitness);
+	}
+
+	let cs = cs.borrow().ok_or(ProverError::InvalidWitness)?;
+	let mut witness = cs.instance_assignment.clone();
+	witness.extend_from_slice(&cs.witness_assignment);
+	Ok(witness)
+}
+
+/// Encodes a wire assignment as an iden3 `.wtns` file (version 2)
+///
+/// Layout: `"wtns" || version(4) || sections(4)`, a header section with the
+/// field size, prime and wire count, then one 32-byte LE value per wire.
+pub fn encode_wtns(witness: &[Bn254Fr]) -> Vec<u8> {
+	const FIELD_SIZE: u32 = 32;
+	let header_size = 4 + FIELD_SIZE as u64 + 4;
+	let data_size = witness.len() as u64 * FIELD_SIZE as u64;
+
+	let mut out = Vec::with_capacity(12 + 12 + header_size as usize + 12 + data_size as usize);
+	out.extend_from_slice(b"wtns");
+	out.extend_from_slice(&2u32.to_le_bytes());
+	out.extend_from_slice(&2u32.to_le_bytes());
+
+	out.extend_from_slice(&1u32.to_le_bytes());
+	out.extend_from_slice(&header_size.to_le_bytes());
+	out.extend_from_slice(&FIELD_SIZE.to_le_bytes());
+	out.extend_from_slice(&Bn254Fr::MODULUS.to_bytes_le());
+	out.extend_from_slice(&(witness.len() as u32).to_le_bytes());
+
+	out.extend_from_slice(&2u32.to_le_bytes());
+	out.extend_from_slice(&data_size.to_le_bytes());
+	for value in witness {
+		out.extend_from_slice(&field_to_bytes(value));
+	}
+	out
+}
+
+// ============================================================================
+// Prover
+// ============================================================================
+
+/// Groth16 prover backed by the rapidsnark binary
+pub struct RapidsnarkProver {
+	binary: PathBuf,
+	zkey: PathBuf,
+	pvk: PreparedVerifyingKey<Bn254>,
+}
+
+impl RapidsnarkProver {
+	/// Creates a prover for one circuit
+	///
+	/// `zkey` must be the rapidsnark proving key matching `vk`.
+	pub fn new(
+		binary: impl Into<PathBuf>,
+		zkey: impl Into<PathBuf>,
+		vk: &VerifyingKey<Bn254>,
+	) -> Self {
+		Self {
+			binary: binary.into(),
+			zkey: zkey.into(),
+			pvk: prepare_verifying_key(vk),
+		}
+	}
+
+	/// Proves `circuit` and serializes the result
+	///
+	/// `public_inputs` must be in the circuit's schema order.
+	pub fn prove<C: ConstraintSynthesizer<Bn254Fr>>(
+		&self,
+		circuit: C,
+		public_inputs: &[Bn254Fr],
+	) -> Result<SerializedProof, ProverError> {
+		self.prove_witness(&synthesize_witness(circuit)?, public_inputs)
+	}
+
+	/// Proves a full wire assignment (see [`synthesize_witness`])
+	pub fn prove_witness(
+		&self,
+		witness: &[Bn254Fr],
+		public_inputs: &[Bn254Fr],
+	) -> Result<SerializedProof, ProverError> {
+		let dir = WorkDir::new()?;
+		let wtns_path = dir.0.join("witness.wtns");
+		let proof_path = dir.0.join("proof.json");
+		let public_path = dir.0.join("public.json");
+		fs::write(&wtns_path, encode_wtns(witness))
+			.map_err(|err| backend("cannot write witness", err))?;
+
+		let output = Command::new(&self.binary)
+			.arg(&self.zkey)
+			.arg(&wtns_path)
+			.arg(&proof_path)
+			.arg(&public_path)
+			.output()
+			.map_err(|err| backend("cannot run rapidsnark", err))?;
+		if !output.status.success() {
+			return Err(ProverError::Backend(format!(
+				"rapidsnark failed ({}): {}",
+				output.status,
+				String::from_utf8_lossy(&output.stderr).trim()
+			)));
+		}
+
+		let proof = fs::read_to_string(&proof_path)
+			.map_err(|err| backend("cannot read proof", err))
+			.and_then(|json| parse_proof_json(&json))?;
+		let public = fs::read_to_string(&public_path)
+			.map_err(|err| backend("cannot read public inputs", err))
+			.and_then(|json| parse_public_json(&json))?;
+
+		if public != public_inputs
+			|| !Groth16::<Bn254>::verify_proof(&self.pvk, &proof, public_inputs)?
+		{
+			return Err(ProverError::InvalidWitness);
+		}
+
+		Ok(SerializedProof {
+			proof: serialize_proof(&proof)?,
+			public_inputs: encode_public_inputs(public_inputs),
+		})
+	}
+
+	/// Proves a private transfer
+	pub fn prove_transfer(&self, circuit: TransferCircuit) -> Result<SerializedProof, ProverError> {
+		let public_inputs = circuit.public_inputs().to_vec();
+		self.prove(circuit, &public_inputs)
+	}
+
+	/// Proves viewing key ownership
+	pub fn prove_viewing_key_ownership(
+		&self,
+		circuit: ViewingKeyOwnershipCircuit,
+	) -> Result<SerializedProof, ProverError> {
+		let public_inputs = circuit.public_inputs().to_vec();
+		self.prove(circuit, &public_inputs)
+	}
+}
+
+/// Private temporary directory, removed on drop
+struct WorkDir(PathBuf);
+
+impl WorkDir {
+	fn new() -> Result<Self, ProverError> {
+		static NEXT: AtomicU64 = AtomicU64::new(0);
+		let path = std::env::temp_dir().join(format!(
+			"orbinum-rapidsnark-{}-{}",
+			std::process::id(),
+			NEXT.fetch_add(1, Ordering::Relaxed)
+		));
+		// Owner-only, and fails if the path exists so a planted directory is
+		// never reused
+		let mut builder = fs::DirBuilder::new();
+		#[cfg(unix)]
+		std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+		builder
+			.create(&path)
+			.map_err(|err| backend("cannot create work directory", err))?;
+		Ok(Self(path))
+	}
+}
+
+impl Drop for WorkDir {
+	fn drop(&mut self) {
+		let _ = fs::remove_dir_all(&self.0);
+	}
+}
+
+fn backend(context: &str, err: impl Display) -> ProverError {
+	ProverError::Backend(format!("{context}: {err}"))
+}
+
+// ============================================================================
+// snarkjs JSON
+// ============================================================================
+
+/// Parses a snarkjs `proof.json` (affine coordinates as decimal strings)
+pub fn parse_proof_json(json: &str) -> Result<Proof<Bn254>, ProverError> {
+	let a = json_array(json, "pi_a")?;
+	let b = json_array(json, "pi_b")?;
+	let c = json_array(json, "pi_c")?;
+
+	Ok(Proof {
+		a: parse_g1(&a)?,
+		b: parse_g2(&b)?,
+		c: parse_g1(&c)?,
+	})
+}
+
+/// Parses a snarkjs `public.json` (array of decimal strings)
+pub fn parse_public_json(json: &str) -> Result<Vec<Bn254Fr>, ProverError> {
+	array_strings(json.trim())?
+		.into_iter()
+		.map(parse_decimal)
+		.collect()
+}
+
+/// `[x, y, "1"]`
+fn parse_g1(coords: &[&str]) -> Result<G1Affine, ProverError> {
+	let [x, y, "1"] = coords else {
+		return Err(malformed("G1 point"));
+	};
+	let point = G1Affine::new_unchecked(parse_decimal::<Fq>(x)?, parse_decimal::<Fq>(y)?);
+	if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+		return Err(malformed("G1 point"));
+	}
+	Ok(point)
+}
+
+/// `[[x.c0, x.c1], [y.c0, y.c1], ["1", "0"]]`, flattened
+fn parse_g2(coords: &[&str]) -> Result<G2Affine, ProverError> {
+	let [x0, x1, y0, y1, "1", "0"] = coords else {
+		return Err(malformed("G2 point"));
+	};
+	let point = G2Affine::new_unchecked(
+		Fq2::new(parse_decimal::<Fq>(x0)?, parse_decimal::<Fq>(x1)?),
+		Fq2::new(parse_decimal::<Fq>(y0)?, parse_decimal::<Fq>(y1)?),
+	);
+	if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+		return Err(malformed("G2 point"));
+	}
+	Ok(point)
+}
+
+fn parse_decimal<F: PrimeField + FromStr>(value: &str) -> Result<F, ProverError> {
+	if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+		return Err(malformed("field element"));
+	}
+	F::from_str(value).map_err(|_| malformed("field element"))
+}
+
+/// Strings of the array under `key`, flattened in order
+fn json_array<'a>(json: &'a str, key: &str) -> Result<Vec<&'a str>, ProverError> {
+	let quoted = format!("\"{key}\"");
+	let start = json.find(&quoted).ok_or_else(|| malformed(key))?;
+	let rest = json[start + quoted.len()..].trim_start();
+	let rest = rest.strip_prefix(':').ok_or_else(|| malformed(key))?;
+	array_strings(rest.trim_start())
+}
+
+/// Strings of the (possibly nested) array at the start of `json`
+fn array_strings(json: &str) -> Result<Vec<&str>, ProverError> {
+	if !json.starts_with('[') {
+		return Err(malformed("array"));
+	}
+	let mut depth = 0usize;
+	let mut end = None;
+	for (i, ch) in json.char_indices() {
+		match ch {
+			'[' => depth += 1,
+			']' => {
+				depth -= 1;
+				if depth == 0 {
+					end = Some(i);
+					break;
+				}
+			}
+			_ => {}
+		}
+	}
+	let end = end.ok_or_else(|| malformed("array"))?;
+	Ok(json[..end].split('"').skip(1).step_by(2).collect())
+}
+
+fn malformed(what: &str) -> ProverError {
+	ProverError::Backend(format!("malformed rapidsnark output: {what}"))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::application::prover::{prove, setup, ProverRng};
+	use alloc::string::ToString;
+
+	fn viewing_key_circuit() -> ViewingKeyOwnershipCircuit {
+		ViewingKeyOwnershipCircuit::new(Bn254Fr::from(1234u64), Bn254Fr::from(99u64))
+	}
+
+	/// snarkjs `proof.json` for an arkworks proof
+	fn proof_json(proof: &Proof<Bn254>) -> String {
+		let fq = |value: &Fq| value.into_bigint().to_string();
+		format!(
+			r#"{{"pi_a":["{}","{}","1"],"pi_b":[["{}","{}"],["{}","{}"],["1","0"]],"pi_c":["{}","{}","1"],"protocol":"groth16"}}"#,
+			fq(&proof.a.x),
+			fq(&proof.a.y),
+			fq(&proof.b.x.c0),
+			fq(&proof.b.x.c1),
+			fq(&proof.b.y.c0),
+			fq(&proof.b.y.c1),
+			fq(&proof.c.x),
+			fq(&proof.c.y),
+		)
+	}
+
+	fn public_json(inputs: &[Bn254Fr]) -> String {
+		let values: Vec<_> = inputs
+			.iter()
+			.map(|value| format!("\"{}\"", value.into_bigint()))
+			.collect();
+		format!("[\n {}\n]", values.join(",\n "))
+	}
+
+	// ===== Witness Tests =====
+
+	#[test]
+	fn test_synthesize_witness_layout() {
+		let circuit = viewing_key_circuit();
+		let public_inputs = circuit.public_inputs().to_vec();
+		let witness = synthesize_witness(circuit).unwrap();
+
+		assert_eq!(witness[0], Bn254Fr::from(1u64));
+		assert_eq!(&witness[1..=public_inputs.len()], &public_inputs[..]);
+	}
+
+	#[test]
+	fn test_encode_wtns_layout() {
+		let witness = [Bn254Fr::from(1u64), Bn254Fr::from(7u64)];
+		let wtns = encode_wtns(&witness);
+
+		assert_eq!(&wtns[0..4], b"wtns");
+		assert_eq!(&wtns[4..8], &2u32.to_le_bytes());
+		assert_eq!(&wtns[16..24], &40u64.to_le_bytes());
+		assert_eq!(&wtns[24..28], &32u32.to_le_bytes());
+		assert_eq!(&wtns[28..60], &Bn254Fr::MODULUS.to_bytes_le()[..]);
+		assert_eq!(&wtns[60..64], &2u32.to_le_bytes());
+		assert_eq!(&wtns[68..76], &64u64.to_le_bytes());
+		assert_eq!(&wtns[108..140], &field_to_bytes(&Bn254Fr::from(7u64)));
+		assert_eq!(wtns.len(), 140);
+	}
+
+	// ===== JSON Tests =====
+
+	#[test]
+	fn test_proof_json_roundtrip() {
+		let mut rng = ProverRng::deterministic(7);
+		let (pk, _) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+		let proof = Groth16::<Bn254>::create_random_proof_with_reduction(
+			viewing_key_circuit(),
+			&pk,
+			&mut rng,
+		)
+		.unwrap();
+
+		assert_eq!(parse_proof_json(&proof_json(&proof)).unwrap(), proof);
+	}
+
+	#[test]
+	fn test_public_json_roundtrip() {
+		let inputs = [Bn254Fr::from(5u64), -Bn254Fr::from(1u64)];
+		assert_eq!(parse_public_json(&public_json(&inputs)).unwrap(), inputs);
+	}
+
+	#[test]
+	fn test_malformed_json_rejected() {
+		assert!(parse_public_json("{}").is_err());
+		assert!(parse_public_json(r#"["12", "-3"]"#).is_err());
+		assert!(parse_proof_json(r#"{"pi_a":["1","2","1"]}"#).is_err());
+		assert!(
+			parse_proof_json(r#"{"pi_a":["1","3","1"],"pi_b":[],"pi_c":["1","2","1"]}"#).is_err()
+		);
+		assert!(parse_g1(&["0", "1", "0"]).is_err());
+	}
+
+	// ===== Prover Tests =====
+
+	#[cfg(unix)]
+	mod process {
+		use super::*;
+		use std::os::unix::fs::PermissionsExt;
+
+		/// Executable that checks the witness file and emits canned outputs
+		struct FakeRapidsnark {
+			dir: WorkDir,
+		}
+
+		impl FakeRapidsnark {
+			fn new(proof_json: &str, public_json: &str, script: &str) -> Self {
+				let dir = WorkDir::new().unwrap();
+				fs::write(dir.0.join("proof.json"), proof_json).unwrap();
+				fs::write(dir.0.join("public.json"), public_json).unwrap();
+				let binary = dir.0.join("prover");
+				fs::write(&binary, script.replace("$DIR", dir.0.to_str().unwrap())).unwrap();
+				fs::set_permissions(&binary, fs::Permissions::from_mode(0o700)).unwrap();
+				Self { dir }
+			}
+
+			fn binary(&self) -> PathBuf {
+				self.dir.0.join("prover")
+			}
+		}
+
+		const COPY_OUTPUTS: &str = "#!/bin/sh\n\
+			[ \"$(head -c 4 \"$2\")\" = wtns ] || exit 3\n\
+			cp \"$DIR/proof.json\" \"$3\" && cp \"$DIR/public.json\" \"$4\"\n";
+
+		fn fixture() -> (
+			ark_groth16::ProvingKey<Bn254>,
+			SerializedProof,
+			String,
+			String,
+		) {
+			let mut rng = ProverRng::deterministic(7);
+			let (pk, _) = setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+			let circuit = viewing_key_circuit();
+			let inputs = circuit.public_inputs().to_vec();
+			let proof = Groth16::<Bn254>::create_random_proof_with_reduction(
+				viewing_key_circuit(),
+				&pk,
+				&mut ProverRng::deterministic(1),
+			)
+			.unwrap();
+			let expected = prove(
+				&pk,
+				viewing_key_circuit(),
+				&inputs,
+				&mut ProverRng::deterministic(1),
+			)
+			.unwrap();
+			(pk, expected, proof_json(&proof), public_json(&inputs))
+		}
+
+		#[test]
+		fn test_prove_through_binary() {
+			let (pk, expected, proof, public) = fixture();
+			let fake = FakeRapidsnark::new(&proof, &public, COPY_OUTPUTS);
+			let prover = RapidsnarkProver::new(fake.binary(), "circuit.zkey", &pk.vk);
+
+			let serialized = prover
+				.prove_viewing_key_ownership(viewing_key_circuit())
+				.unwrap();
+			assert_eq!(serialized, expected);
+		}
+
+		#[test]
+		fn test_mismatched_public_inputs_rejected() {
+			let (pk, _, proof, _) = fixture();
+			let public = public_json(&[Bn254Fr::from(1u64), Bn254Fr::from(99u64)]);
+			let fake = FakeRapidsnark::new(&proof, &public, COPY_OUTPUTS);
+			let prover = RapidsnarkProver::new(fake.binary(), "circuit.zkey", &pk.vk);
+
+			let result = prover.prove_viewing_key_ownership(viewing_key_circuit());
+			assert!(matches!(result, Err(ProverError::InvalidWitness)));
+		}
+
+		#[test]
+		fn test_binary_failure_reported() {
+			let (pk, _, proof, public) = fixture();
+			let fake = FakeRapidsnark::new(&proof, &public, "#!/bin/sh\necho boom >&2\nexit 1\n");
+			let prover = RapidsnarkProver::new(fake.binary(), "circuit.zkey", &pk.vk);
+
+			match prover.prove_viewing_key_ownership(viewing_key_circuit()) {
+				Err(ProverError::Backend(message)) => assert!(message.contains("boom")),
+				other => panic!("unexpected result: {other:?}"),
+			}
+		}
+
+		#[test]
+		fn test_missing_binary_reported() {
+			let (pk, ..) = fixture();
+			let prover = RapidsnarkProver::new("/nonexistent/rapidsnark", "circuit.zkey", &pk.vk);
+
+			let result = prover.prove_viewing_key_ownership(viewing_key_circuit());
+			assert!(matches!(result, Err(ProverError::Backend(_))));
+		}
+	}
+}
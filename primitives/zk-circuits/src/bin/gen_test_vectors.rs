@@ -0,0 +1,315 @@
+//! Test-vector generator
+//!
+//! Produces deterministic Groth16 fixtures (verifying key, proofs, public inputs and
+//! the expected verification result) for pallet and RPC tests.
+//!
+//! ```text
+//! cargo run -p orbinum-zk-circuits --features test-vectors --bin gen-test-vectors -- \
+//!     --seed 42 --out fixtures/
+//! ```
+//!
+//! Without `--out` the fixtures are printed to stdout. Encodings match what
+//! `pallet-zk-verifier` consumes:
+//! - Proof / VK: arkworks compressed serialization
+//! - Public inputs: 32-byte little-endian field elements
+
+use std::{env, fmt::Write as _, fs, path::PathBuf, process};
+
+use ark_bn254::Bn254;
+use ark_groth16::{Groth16, Proof, ProvingKey, VerifyingKey};
+use ark_relations::r1cs::ConstraintSynthesizer;
+use ark_snark::SNARK;
+use ark_std::UniformRand;
+
+use orbinum_zk_circuits::{
+	application::{
+		circuits::{
+			note::Note,
+			transfer::{TransferCircuit, TransferWitness, TREE_DEPTH},
+			viewing_key::ViewingKeyOwnershipCircuit,
+		},
+		prover::{self, field_to_bytes, serialize_proof, serialize_vk, ProverRng},
+	},
+	domain::circuit_ids::{self, CircuitSchema},
+	infrastructure::native_crypto::poseidon_hash_2,
+	Bn254Fr,
+};
+
+const DEFAULT_SEED: u64 = 42;
+
+// ============================================================================
+// Fixture Model
+// ============================================================================
+
+struct Case {
+	name: &'static str,
+	proof: Vec<u8>,
+	public_inputs: Vec<Bn254Fr>,
+	expected: bool,
+}
+
+struct Fixture {
+	schema: &'static CircuitSchema,
+	seed: u64,
+	vk: Vec<u8>,
+	cases: Vec<Case>,
+}
+
+impl Fixture {
+	fn file_name(&self) -> String {
+		let mut name = String::new();
+		for (i, c) in self.schema.name.chars().enumerate() {
+			if c.is_ascii_uppercase() && i > 0 {
+				name.push('_');
+			}
+			name.push(c.to_ascii_lowercase());
+		}
+		name + ".json"
+	}
+
+	fn to_json(&self) -> String {
+		let mut out = String::new();
+		let _ = writeln!(out, "{{");
+		let _ = writeln!(out, "  \"circuit_id\": {},", self.schema.id);
+		let _ = writeln!(out, "  \"name\": \"{}\",", self.schema.name);
+		let _ = writeln!(out, "  \"seed\": {},", self.seed);
+		let _ = writeln!(out, "  \"vk\": \"{}\",", hex(&self.vk));
+		let _ = writeln!(out, "  \"cases\": [");
+		for (i, case) in self.cases.iter().enumerate() {
+			let inputs: Vec<String> = case
+				.public_inputs
+				.iter()
+				.map(|input| format!("\"{}\"", hex(&field_to_bytes(input))))
+				.collect();
+			let _ = writeln!(out, "    {{");
+			let _ = writeln!(out, "      \"name\": \"{}\",", case.name);
+			let _ = writeln!(out, "      \"proof\": \"{}\",", hex(&case.proof));
+			let _ = writeln!(out, "      \"public_inputs\": [{}],", inputs.join(", "));
+			let _ = writeln!(out, "      \"expected\": {}", case.expected);
+			let separator = if i + 1 < self.cases.len() { "," } else { "" };
+			let _ = writeln!(out, "    }}{separator}");
+		}
+		let _ = writeln!(out, "  ]");
+		let _ = writeln!(out, "}}");
+		out
+	}
+}
+
+// ============================================================================
+// Encoding Helpers
+// ============================================================================
+
+fn hex(bytes: &[u8]) -> String {
+	let mut out = String::with_capacity(2 + bytes.len() * 2);
+	out.push_str("0x");
+	for byte in bytes {
+		let _ = write!(out, "{byte:02x}");
+	}
+	out
+}
+
+fn serialize(proof: &Proof<Bn254>) -> Vec<u8> {
+	serialize_proof(proof).expect("serialization into Vec is infallible")
+}
+
+// ============================================================================
+// Circuit Fixtures
+// ============================================================================
+
+/// Valid proof, proof checked against tampered inputs, and a proof for other inputs
+fn standard_cases(
+	vk: &VerifyingKey<Bn254>,
+	proof: &Proof<Bn254>,
+	public_inputs: Vec<Bn254Fr>,
+	other_proof: &Proof<Bn254>,
+) -> Vec<Case> {
+	let proof_bytes = serialize(proof);
+
+	let mut tampered = public_inputs.clone();
+	tampered[0] += Bn254Fr::from(1u64);
+
+	let cases = vec![
+		Case {
+			name: "valid",
+			proof: proof_bytes.clone(),
+			public_inputs: public_inputs.clone(),
+			expected: true,
+		},
+		Case {
+			name: "tampered_public_input",
+			proof: proof_bytes,
+			public_inputs: tampered,
+			expected: false,
+		},
+		Case {
+			name: "mismatched_proof",
+			proof: serialize(other_proof),
+			public_inputs,
+			expected: false,
+		},
+	];
+
+	// Fixtures must agree with the verifier before they are written out
+	for case in &cases {
+		let proof = if case.name == "mismatched_proof" {
+			other_proof
+		} else {
+			proof
+		};
+		let result = Groth16::<Bn254>::verify(vk, &case.public_inputs, proof).unwrap_or(false);
+		assert_eq!(
+			result, case.expected,
+			"fixture case {} is inconsistent",
+			case.name
+		);
+	}
+
+	cases
+}
+
+fn prove<C: ConstraintSynthesizer<Bn254Fr>>(
+	pk: &ProvingKey<Bn254>,
+	circuit: C,
+	rng: &mut ProverRng,
+) -> Proof<Bn254> {
+	Groth16::<Bn254>::prove(pk, circuit, rng).expect("Groth16 proving failed")
+}
+
+/// Random balanced 2-in / 2-out transfer over a two-leaf tree
+fn random_transfer(rng: &mut ProverRng) -> (TransferWitness, Bn254Fr) {
+	let owner = Bn254Fr::rand(rng);
+	let recipient = Bn254Fr::rand(rng);
+	let value = u64::from(u32::rand(rng));
+	let split = value / 3;
+
+	let inputs = [
+		Note::new(value - split, 0, owner, Bn254Fr::rand(rng)),
+		Note::new(split, 0, owner, Bn254Fr::rand(rng)),
+	];
+	let outputs = [
+		Note::new(split, 0, recipient, Bn254Fr::rand(rng)),
+		Note::new(value - split, 0, owner, Bn254Fr::rand(rng)),
+	];
+
+	let leaves = [inputs[0].commitment(), inputs[1].commitment()];
+	let zero = Bn254Fr::from(0u64);
+
+	let mut path_elements = [[zero; TREE_DEPTH]; 2];
+	let mut path_indices = [[false; TREE_DEPTH]; 2];
+	path_elements[0][0] = leaves[1];
+	path_elements[1][0] = leaves[0];
+	path_indices[1][0] = true;
+
+	let mut root = poseidon_hash_2(&[leaves[0], leaves[1]]);
+	for _ in 1..TREE_DEPTH {
+		root = poseidon_hash_2(&[root, zero]);
+	}
+
+	let spending_keys = [Bn254Fr::rand(rng), Bn254Fr::rand(rng)];
+	let witness = TransferWitness::new(inputs, spending_keys, path_elements, path_indices, outputs);
+	(witness, root)
+}
+
+fn transfer_fixture(seed: u64) -> Fixture {
+	let mut rng = ProverRng::deterministic(seed);
+	let (pk, vk) =
+		prover::setup(TransferCircuit::new_for_setup(), &mut rng).expect("Groth16 setup failed");
+
+	let (witness, root) = random_transfer(&mut rng);
+	let circuit = TransferCircuit::new(witness, root);
+	let public_inputs = circuit.public_inputs().to_vec();
+	let proof = prove(&pk, circuit, &mut rng);
+
+	let (other_witness, other_root) = random_transfer(&mut rng);
+	let other_proof = prove(
+		&pk,
+		TransferCircuit::new(other_witness, other_root),
+		&mut rng,
+	);
+
+	Fixture {
+		schema: &circuit_ids::TRANSFER,
+		seed,
+		vk: serialize_vk(&vk).expect("serialization into Vec is infallible"),
+		cases: standard_cases(&vk, &proof, public_inputs, &other_proof),
+	}
+}
+
+fn viewing_key_fixture(seed: u64) -> Fixture {
+	let mut rng = ProverRng::deterministic(seed);
+	let (pk, vk) = prover::setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng)
+		.expect("Groth16 setup failed");
+
+	let circuit = ViewingKeyOwnershipCircuit::new(Bn254Fr::rand(&mut rng), Bn254Fr::rand(&mut rng));
+	let public_inputs = circuit.public_inputs().to_vec();
+	let proof = prove(&pk, circuit, &mut rng);
+
+	let other = ViewingKeyOwnershipCircuit::new(Bn254Fr::rand(&mut rng), Bn254Fr::rand(&mut rng));
+	let other_proof = prove(&pk, other, &mut rng);
+
+	Fixture {
+		schema: &circuit_ids::VIEWING_KEY_OWNERSHIP,
+		seed,
+		vk: serialize_vk(&vk).expect("serialization into Vec is infallible"),
+		cases: standard_cases(&vk, &proof, public_inputs, &other_proof),
+	}
+}
+
+// ============================================================================
+// CLI
+// ============================================================================
+
+struct Args {
+	seed: u64,
+	out: Option<PathBuf>,
+}
+
+fn usage() -> ! {
+	eprintln!("usage: gen-test-vectors [--seed <u64>] [--out <dir>]");
+	process::exit(2);
+}
+
+fn parse_args() -> Args {
+	let mut args = Args {
+		seed: DEFAULT_SEED,
+		out: None,
+	};
+
+	let mut iter = env::args().skip(1);
+	while let Some(flag) = iter.next() {
+		match flag.as_str() {
+			"--seed" => {
+				args.seed = iter
+					.next()
+					.and_then(|value| value.parse().ok())
+					.unwrap_or_else(|| usage());
+			}
+			"--out" => args.out = Some(iter.next().map(PathBuf::from).unwrap_or_else(|| usage())),
+			"-h" | "--help" => usage(),
+			_ => usage(),
+		}
+	}
+
+	args
+}
+
+fn main() {
+	let args = parse_args();
+	let fixtures = [transfer_fixture(args.seed), viewing_key_fixture(args.seed)];
+
+	match args.out {
+		Some(dir) => {
+			fs::create_dir_all(&dir).expect("failed to create output directory");
+			for fixture in &fixtures {
+				let path = dir.join(fixture.file_name());
+				fs::write(&path, fixture.to_json()).expect("failed to write fixture");
+				println!("wrote {}", path.display());
+			}
+		}
+		None => {
+			for fixture in &fixtures {
+				print!("{}", fixture.to_json());
+			}
+		}
+	}
+}
@@ -0,0 +1,179 @@
+//! Circuit Identifiers and Public-Input Schemas
+//!
+//! Single source of truth for circuit IDs and the ordering of their public inputs.
+//! Both `orbinum-zk-verifier` and `pallet-zk-verifier` re-export these values, so a
+//! new circuit only needs to be registered here.
+
+/// Circuit identifier for private transfers
+pub const CIRCUIT_ID_TRANSFER: u32 = 1;
+
+/// Circuit identifier for unshield (withdraw) operations
+pub const CIRCUIT_ID_UNSHIELD: u32 = 2;
+
+/// Circuit identifier reserved for shield operations (no circuit deployed yet)
+pub const CIRCUIT_ID_SHIELD: u32 = 3;
+
+/// Circuit identifier for selective disclosure
+pub const CIRCUIT_ID_DISCLOSURE: u32 = 4;
+
+/// Circuit identifier for private link dispatch
+pub const CIRCUIT_ID_PRIVATE_LINK: u32 = 5;
+
+/// Circuit identifier for viewing key ownership proofs
+pub const CIRCUIT_ID_VIEWING_KEY_OWNERSHIP: u32 = 6;
+
+/// Static description of a circuit's public interface
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitSchema {
+	/// Circuit identifier used to lookup the verification key
+	pub id: u32,
+	/// Human-readable circuit name
+	pub name: &'static str,
+	/// Public input names, in the order the verifier expects them
+	pub public_inputs: &'static [&'static str],
+}
+
+impl CircuitSchema {
+	/// Number of public inputs expected by the circuit
+	pub const fn public_input_count(&self) -> usize {
+		self.public_inputs.len()
+	}
+
+	/// Position of a named public input, if present
+	pub fn input_index(&self, name: &str) -> Option<usize> {
+		self.public_inputs.iter().position(|input| *input == name)
+	}
+}
+
+/// Transfer circuit (2-in / 2-out)
+pub const TRANSFER: CircuitSchema = CircuitSchema {
+	id: CIRCUIT_ID_TRANSFER,
+	name: "Transfer",
+	public_inputs: &[
+		"merkle_root",
+		"nullifier_1",
+		"nullifier_2",
+		"commitment_1",
+		"commitment_2",
+	],
+};
+
+/// Unshield circuit
+///
+/// `recipient_binding` is `Poseidon(recipient, nonce)` from circuit version 2
+/// on; version 1 keys take the raw recipient at the same index.
+pub const UNSHIELD: CircuitSchema = CircuitSchema {
+	id: CIRCUIT_ID_UNSHIELD,
+	name: "Unshield",
+	public_inputs: &[
+		"merkle_root",
+		"nullifier",
+		"amount",
+		"recipient_binding",
+		"asset_id",
+	],
+};
+
+/// Selective disclosure circuit
+pub const DISCLOSURE: CircuitSchema = CircuitSchema {
+	id: CIRCUIT_ID_DISCLOSURE,
+	name: "Disclosure",
+	public_inputs: &[
+		"commitment",
+		"revealed_value",
+		"revealed_asset_id",
+		"revealed_owner_hash",
+	],
+};
+
+/// Private link dispatch circuit
+pub const PRIVATE_LINK: CircuitSchema = CircuitSchema {
+	id: CIRCUIT_ID_PRIVATE_LINK,
+	name: "PrivateLink",
+	public_inputs: &["commitment", "call_hash_fe"],
+};
+
+/// Viewing key ownership circuit
+pub const VIEWING_KEY_OWNERSHIP: CircuitSchema = CircuitSchema {
+	id: CIRCUIT_ID_VIEWING_KEY_OWNERSHIP,
+	name: "ViewingKeyOwnership",
+	public_inputs: &["viewing_key_hash", "commitment"],
+};
+
+/// All circuits with a deployed verification key schema
+pub const ALL_CIRCUITS: &[CircuitSchema] = &[
+	TRANSFER,
+	UNSHIELD,
+	DISCLOSURE,
+	PRIVATE_LINK,
+	VIEWING_KEY_OWNERSHIP,
+];
+
+/// Lookup the schema of a circuit by ID
+pub fn schema(id: u32) -> Option<&'static CircuitSchema> {
+	ALL_CIRCUITS.iter().find(|schema| schema.id == id)
+}
+
+/// Human-readable name of a circuit ID (including reserved IDs)
+pub fn circuit_name(id: u32) -> Option<&'static str> {
+	match id {
+		CIRCUIT_ID_SHIELD => Some("Shield"),
+		_ => schema(id).map(|schema| schema.name),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_circuit_ids_are_stable() {
+		assert_eq!(CIRCUIT_ID_TRANSFER, 1);
+		assert_eq!(CIRCUIT_ID_UNSHIELD, 2);
+		assert_eq!(CIRCUIT_ID_SHIELD, 3);
+		assert_eq!(CIRCUIT_ID_DISCLOSURE, 4);
+		assert_eq!(CIRCUIT_ID_PRIVATE_LINK, 5);
+		assert_eq!(CIRCUIT_ID_VIEWING_KEY_OWNERSHIP, 6);
+	}
+
+	#[test]
+	fn test_public_input_counts() {
+		assert_eq!(TRANSFER.public_input_count(), 5);
+		assert_eq!(UNSHIELD.public_input_count(), 5);
+		assert_eq!(DISCLOSURE.public_input_count(), 4);
+		assert_eq!(PRIVATE_LINK.public_input_count(), 2);
+		assert_eq!(VIEWING_KEY_OWNERSHIP.public_input_count(), 2);
+	}
+
+	#[test]
+	fn test_circuit_ids_are_unique() {
+		for (i, a) in ALL_CIRCUITS.iter().enumerate() {
+			for b in &ALL_CIRCUITS[i + 1..] {
+				assert_ne!(a.id, b.id);
+				assert_ne!(a.name, b.name);
+			}
+		}
+	}
+
+	#[test]
+	fn test_schema_lookup() {
+		assert_eq!(schema(CIRCUIT_ID_TRANSFER), Some(&TRANSFER));
+		assert_eq!(schema(CIRCUIT_ID_PRIVATE_LINK), Some(&PRIVATE_LINK));
+		assert_eq!(schema(CIRCUIT_ID_SHIELD), None);
+		assert_eq!(schema(999), None);
+	}
+
+	#[test]
+	fn test_circuit_name() {
+		assert_eq!(circuit_name(CIRCUIT_ID_UNSHIELD), Some("Unshield"));
+		assert_eq!(circuit_name(CIRCUIT_ID_SHIELD), Some("Shield"));
+		assert_eq!(circuit_name(0), None);
+	}
+
+	#[test]
+	fn test_input_index() {
+		assert_eq!(UNSHIELD.input_index("merkle_root"), Some(0));
+		assert_eq!(UNSHIELD.input_index("recipient_binding"), Some(3));
+		assert_eq!(UNSHIELD.input_index("unknown"), None);
+	}
+}
@@ -5,9 +5,11 @@
 //! - Value Objects: Immutable domain primitives
 //! - Services: Pure business logic
 //! - Ports: Abstract interfaces for infrastructure
+//! - Circuit IDs: Identifiers and public-input schemas shared with the verifiers
 //!
 //! This layer is independent of specific R1CS implementations.
 
+pub mod circuit_ids;
 pub mod ports;
 pub mod services;
 pub mod value_objects;
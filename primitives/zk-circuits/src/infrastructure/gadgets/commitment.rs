@@ -3,8 +3,9 @@
 //! R1CS constraint-generating versions of commitment and nullifier schemes.
 //! Used inside ZK circuits to prove knowledge of commitments without revealing values.
 
-use ark_r1cs_std::fields::fp::FpVar;
+use ark_r1cs_std::fields::{fp::FpVar, FieldVar};
 use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+use orbinum_zk_core::domain::value_objects::NullifierVersion;
 
 use super::poseidon::{poseidon_hash_2, poseidon_hash_4};
 use crate::Bn254Fr;
@@ -47,6 +48,31 @@ pub fn compute_nullifier(
 	poseidon_hash_2(cs, &[commitment.clone(), spending_key.clone()])
 }
 
+/// Compute a nullifier under a specific derivation version (in-circuit)
+///
+/// Generates R1CS constraints equivalent to native `compute_nullifier_versioned`.
+/// `V1` is [`compute_nullifier`]; tagged versions hash
+/// `Poseidon(tag, commitment, spending_key, 0)` with the tag as a constant.
+pub fn compute_nullifier_versioned(
+	cs: ConstraintSystemRef<Bn254Fr>,
+	commitment: &FpVar<Bn254Fr>,
+	spending_key: &FpVar<Bn254Fr>,
+	version: NullifierVersion,
+) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+	match version.domain_tag() {
+		None => compute_nullifier(cs, commitment, spending_key),
+		Some(tag) => poseidon_hash_4(
+			cs,
+			&[
+				FpVar::constant(tag.inner()),
+				commitment.clone(),
+				spending_key.clone(),
+				FpVar::zero(),
+			],
+		),
+	}
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -356,6 +382,26 @@ mod tests {
 		assert_eq!(nullifier.value().unwrap(), expected.inner().inner());
 	}
 
+	#[test]
+	fn test_compute_nullifier_versioned_matches_native() {
+		let commitment_val = Bn254Fr::from(99999u64);
+		let spending_key_val = Bn254Fr::from(55555u64);
+		let commitment = Commitment::new(FieldElement::new(commitment_val));
+		let spending_key = SpendingKey::new(FieldElement::new(spending_key_val));
+		let service = NullifierService::new(LightPoseidonHasher);
+
+		for version in NullifierVersion::ALL {
+			let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+			let commitment_var = FpVar::new_witness(cs.clone(), || Ok(commitment_val)).unwrap();
+			let key_var = FpVar::new_witness(cs.clone(), || Ok(spending_key_val)).unwrap();
+
+			let nullifier =
+				compute_nullifier_versioned(cs, &commitment_var, &key_var, version).unwrap();
+			let expected = service.compute_nullifier_versioned(&commitment, &spending_key, version);
+			assert_eq!(nullifier.value().unwrap(), expected.as_fr());
+		}
+	}
+
 	#[test]
 	fn test_compute_nullifier_deterministic() {
 		let cs1 = ConstraintSystem::<Bn254Fr>::new_ref();
@@ -11,6 +11,9 @@
 //! - `poseidon`: Poseidon hash gadget
 //! - `merkle`: Merkle tree membership proof gadget
 //! - `commitment`: Commitment and nullifier gadgets
+//! - `sparse_merkle`: Sparse Merkle tree inclusion/exclusion gadget
+//! - `sha256`: SHA-256 gadget for cross-chain commitments
+//! - `rescue_prime`: Rescue-Prime gadget and `SchemeHashGadget` (`rescue-prime` feature)
 //!
 //! ## Usage
 //!
@@ -41,3 +44,7 @@
 pub mod commitment;
 pub mod merkle;
 pub mod poseidon;
+#[cfg(feature = "rescue-prime")]
+pub mod rescue_prime;
+pub mod sha256;
+pub mod sparse_merkle;
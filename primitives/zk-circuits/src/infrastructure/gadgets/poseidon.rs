@@ -2,6 +2,8 @@
 //!
 //! R1CS constraint-generating versions of Poseidon hash for ZK circuits.
 //! ~300 constraints for hash_2, ~500 for hash_4 (vs ~25,000 for SHA-256).
+//! `poseidon_sponge_var` hashes arbitrary-length inputs (memo binding, multi-field
+//! disclosures) with the same absorb schedule as `orbinum_zk_core::poseidon_sponge`.
 
 use alloc::vec::Vec;
 use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar, R1CSVar};
@@ -10,7 +12,7 @@ use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
 use crate::{
 	infrastructure::native_crypto::{
 		poseidon_hash as native_hash, poseidon_hash_2 as native_hash_2,
-		poseidon_hash_4 as native_hash_4,
+		poseidon_hash_4 as native_hash_4, poseidon_sponge as native_sponge,
 	},
 	Bn254Fr,
 };
@@ -75,6 +77,23 @@ pub fn poseidon_hash_var(
 	FpVar::new_witness(cs, || hash_value)
 }
 
+/// Variable-length Poseidon sponge (in-circuit)
+///
+/// Accepts any number of inputs, including none. Output matches the native
+/// `poseidon_sponge` in `orbinum-zk-core`. Supports setup mode.
+pub fn poseidon_sponge_var(
+	cs: ConstraintSystemRef<Bn254Fr>,
+	inputs: &[FpVar<Bn254Fr>],
+) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+	// Try to get native values
+	let values: Result<Vec<Bn254Fr>, _> = inputs.iter().map(|v| v.value()).collect();
+
+	let hash_value = values.map(|vals| native_sponge(vals.as_slice()));
+
+	// Allocate as witness - returns AssignmentMissing during setup
+	FpVar::new_witness(cs, || hash_value)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -765,4 +784,50 @@ mod tests {
 			assert!(hash.value().is_err());
 		}
 	}
+
+	// ===== poseidon_sponge_var Tests =====
+
+	#[test]
+	fn test_poseidon_sponge_var_matches_native() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+
+		let vals: Vec<Bn254Fr> = (1..=11u64).map(Bn254Fr::from).collect();
+		let inputs: Vec<FpVar<Bn254Fr>> = vals
+			.iter()
+			.map(|&v| FpVar::new_witness(cs.clone(), || Ok(v)).unwrap())
+			.collect();
+
+		let hash = poseidon_sponge_var(cs.clone(), &inputs).unwrap();
+
+		assert_eq!(hash.value().unwrap(), native_sponge(&vals));
+	}
+
+	#[test]
+	fn test_poseidon_sponge_var_empty_inputs() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+
+		let hash = poseidon_sponge_var(cs.clone(), &[]).unwrap();
+
+		assert_eq!(hash.value().unwrap(), native_sponge(&[]));
+	}
+
+	#[test]
+	fn test_poseidon_sponge_var_setup_mode() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		cs.set_mode(SynthesisMode::Setup);
+
+		let inputs: Vec<_> = (0..5)
+			.filter_map(|_| {
+				FpVar::new_witness(cs.clone(), || {
+					Err::<Bn254Fr, _>(SynthesisError::AssignmentMissing)
+				})
+				.ok()
+			})
+			.collect();
+
+		if inputs.len() == 5 {
+			let hash = poseidon_sponge_var(cs.clone(), &inputs).unwrap();
+			assert!(hash.value().is_err());
+		}
+	}
 }
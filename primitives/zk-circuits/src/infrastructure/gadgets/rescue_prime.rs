@@ -0,0 +1,369 @@
+//! Rescue-Prime Hash Gadget (R1CS Constraints)
+//!
+//! In-circuit Rescue-Prime (`rescue-prime` feature) matching
+//! `orbinum_zk_core::RescuePrimeParameters`, for comparing proving costs against
+//! Poseidon. Each round costs up to `6 * width` constraints: the forward S-box `x^5`
+//! takes three multiplications, and the inverse S-box allocates `y = x^(1/5)` and
+//! enforces `y^5 = x` with three more. MDS and round constants are linear.
+//!
+//! `SchemeHashGadget` implements `HashGadgetPort` for either scheme, so circuits
+//! written against the port can be synthesised over Poseidon or Rescue-Prime.
+
+use alloc::vec::Vec;
+use ark_ff::Field;
+use ark_r1cs_std::{
+	alloc::AllocVar,
+	fields::{fp::FpVar, FieldVar},
+	R1CSVar,
+};
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+use orbinum_zk_core::{
+	HashScheme, RescuePrimeParameters, RESCUE_ALPHA_INV, RESCUE_MAX_WIDTH, RESCUE_MIN_WIDTH,
+};
+
+use crate::{
+	domain::ports::HashGadgetPort,
+	infrastructure::gadgets::poseidon::{poseidon_hash_2, poseidon_hash_4, poseidon_hash_var},
+	Bn254Fr,
+};
+
+// ============================================================================
+// Circuit Gadgets (with R1CS constraints)
+// ============================================================================
+
+/// `x^5` (3 constraints)
+fn sbox(x: &FpVar<Bn254Fr>) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+	let x4 = x.square()?.square()?;
+	Ok(x4 * x)
+}
+
+/// `x^(1/5)`, witnessed and checked as `y^5 = x` (3 constraints)
+fn inverse_sbox(x: &FpVar<Bn254Fr>) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+	if let FpVar::Constant(value) = x {
+		return Ok(FpVar::Constant(value.pow(RESCUE_ALPHA_INV)));
+	}
+
+	let y = FpVar::new_witness(x.cs(), || {
+		x.value().map(|value| value.pow(RESCUE_ALPHA_INV))
+	})?;
+	y.square()?.square()?.mul_equals(&y, x)?;
+	Ok(y)
+}
+
+/// `MDS * state + constants` (no constraints)
+fn mix(
+	params: &RescuePrimeParameters,
+	state: &[FpVar<Bn254Fr>],
+	constants: &[Bn254Fr],
+) -> Vec<FpVar<Bn254Fr>> {
+	params
+		.mds
+		.iter()
+		.zip(constants)
+		.map(|(row, constant)| {
+			row.iter()
+				.zip(state)
+				.fold(FpVar::Constant(*constant), |acc, (m, s)| acc + s * *m)
+		})
+		.collect()
+}
+
+/// Rescue-Prime permutation (in-circuit)
+///
+/// Returns `SynthesisError::Unsatisfiable` if `state.len() != params.width`.
+pub fn rescue_prime_permutation(
+	params: &RescuePrimeParameters,
+	state: &[FpVar<Bn254Fr>],
+) -> Result<Vec<FpVar<Bn254Fr>>, SynthesisError> {
+	if state.len() != params.width {
+		return Err(SynthesisError::Unsatisfiable);
+	}
+
+	let mut state = state.to_vec();
+	for constants in params.round_constants.chunks_exact(2 * params.width) {
+		let (first, second) = constants.split_at(params.width);
+		let forward = state.iter().map(sbox).collect::<Result<Vec<_>, _>>()?;
+		state = mix(params, &forward, first);
+		let inverse = state
+			.iter()
+			.map(inverse_sbox)
+			.collect::<Result<Vec<_>, _>>()?;
+		state = mix(params, &inverse, second);
+	}
+	Ok(state)
+}
+
+/// Rescue-Prime hash for 1-8 inputs (in-circuit)
+///
+/// Matches `RescuePrimeParameters::hash` for width `inputs.len() + 1`.
+pub fn rescue_prime_hash_var(inputs: &[FpVar<Bn254Fr>]) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+	let width = inputs.len() + 1;
+	if !(RESCUE_MIN_WIDTH..=RESCUE_MAX_WIDTH).contains(&width) {
+		return Err(SynthesisError::Unsatisfiable);
+	}
+	let params =
+		RescuePrimeParameters::generate(width).map_err(|_| SynthesisError::Unsatisfiable)?;
+
+	let mut state = inputs.to_vec();
+	state.push(FpVar::Constant(Bn254Fr::from(inputs.len() as u64)));
+	let mut output = rescue_prime_permutation(&params, &state)?;
+	Ok(output.swap_remove(0))
+}
+
+/// Rescue-Prime hash for 2 inputs (in-circuit)
+pub fn rescue_prime_hash_2(inputs: &[FpVar<Bn254Fr>; 2]) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+	rescue_prime_hash_var(inputs)
+}
+
+/// Rescue-Prime hash for 4 inputs (in-circuit)
+pub fn rescue_prime_hash_4(inputs: &[FpVar<Bn254Fr>; 4]) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+	rescue_prime_hash_var(inputs)
+}
+
+// ============================================================================
+// Scheme Selection
+// ============================================================================
+
+/// Hash gadget for a `HashScheme`
+///
+/// Poseidon delegates to the `poseidon` gadgets on the inputs' constraint
+/// system; Rescue-Prime generates full constraints.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SchemeHashGadget {
+	/// Selected hash scheme
+	pub scheme: HashScheme,
+}
+
+impl SchemeHashGadget {
+	/// Create a gadget for `scheme`
+	pub fn new(scheme: HashScheme) -> Self {
+		Self { scheme }
+	}
+}
+
+/// Constraint system shared by `inputs` (`None` if all are constants)
+fn cs_of(inputs: &[FpVar<Bn254Fr>]) -> ConstraintSystemRef<Bn254Fr> {
+	inputs
+		.iter()
+		.fold(ConstraintSystemRef::None, |cs, input| cs.or(input.cs()))
+}
+
+impl HashGadgetPort for SchemeHashGadget {
+	fn hash_2(
+		&self,
+		left: &FpVar<Bn254Fr>,
+		right: &FpVar<Bn254Fr>,
+	) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+		let inputs = [left.clone(), right.clone()];
+		match self.scheme {
+			HashScheme::Poseidon => poseidon_hash_2(cs_of(&inputs), &inputs),
+			HashScheme::RescuePrime => rescue_prime_hash_2(&inputs),
+		}
+	}
+
+	fn hash_4(&self, inputs: &[FpVar<Bn254Fr>; 4]) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+		match self.scheme {
+			HashScheme::Poseidon => poseidon_hash_4(cs_of(inputs), inputs),
+			HashScheme::RescuePrime => rescue_prime_hash_4(inputs),
+		}
+	}
+
+	fn hash_var(&self, inputs: &[FpVar<Bn254Fr>]) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+		match self.scheme {
+			HashScheme::Poseidon => poseidon_hash_var(cs_of(inputs), inputs),
+			HashScheme::RescuePrime => rescue_prime_hash_var(inputs),
+		}
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::infrastructure::native_crypto::poseidon_hash_2 as native_poseidon_2;
+	use ark_relations::r1cs::{ConstraintSystem, SynthesisMode};
+	use orbinum_zk_core::{
+		domain::{ports::PoseidonHasher, value_objects::FieldElement},
+		RescuePrimeHasher, SchemeHasher,
+	};
+
+	fn witnesses(cs: &ConstraintSystemRef<Bn254Fr>, values: &[Bn254Fr]) -> Vec<FpVar<Bn254Fr>> {
+		values
+			.iter()
+			.map(|&v| FpVar::new_witness(cs.clone(), || Ok(v)).unwrap())
+			.collect()
+	}
+
+	fn native(values: &[Bn254Fr]) -> Bn254Fr {
+		RescuePrimeParameters::generate(values.len() + 1)
+			.unwrap()
+			.hash(values)
+	}
+
+	// ===== Rescue-Prime Gadget Tests =====
+
+	#[test]
+	fn test_hash_var_matches_native_all_arities() {
+		for arity in 1..RESCUE_MAX_WIDTH {
+			let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+			let values: Vec<Bn254Fr> = (1..=arity as u64).map(Bn254Fr::from).collect();
+
+			let hash = rescue_prime_hash_var(&witnesses(&cs, &values)).unwrap();
+
+			assert_eq!(hash.value().unwrap(), native(&values), "arity {arity}");
+			assert!(cs.is_satisfied().unwrap());
+		}
+	}
+
+	#[test]
+	fn test_hash_2_and_4_match_hasher() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let values = [7u64, 11, 13, 17].map(Bn254Fr::from);
+		let vars = witnesses(&cs, &values);
+		let hasher = RescuePrimeHasher::new();
+
+		let hash_2 = rescue_prime_hash_2(&[vars[0].clone(), vars[1].clone()]).unwrap();
+		let hash_4 = rescue_prime_hash_4(&vars.clone().try_into().unwrap()).unwrap();
+
+		assert_eq!(
+			hash_2.value().unwrap(),
+			hasher
+				.hash_2([FieldElement::new(values[0]), FieldElement::new(values[1])])
+				.inner()
+		);
+		assert_eq!(
+			hash_4.value().unwrap(),
+			hasher.hash_4(values.map(FieldElement::new)).inner()
+		);
+		assert!(cs.is_satisfied().unwrap());
+	}
+
+	#[test]
+	fn test_constraint_count() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let vars = witnesses(&cs, &[Bn254Fr::from(1u64), Bn254Fr::from(2u64)]);
+		let before = cs.num_constraints();
+
+		let _ = rescue_prime_hash_2(&[vars[0].clone(), vars[1].clone()]).unwrap();
+
+		// The constant capacity element's first S-box is free
+		let params = RescuePrimeParameters::generate(3).unwrap();
+		assert_eq!(
+			cs.num_constraints() - before,
+			6 * params.width * params.rounds - 3
+		);
+	}
+
+	#[test]
+	fn test_wrong_inverse_sbox_witness_unsatisfied() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let x = FpVar::new_witness(cs.clone(), || Ok(Bn254Fr::from(5u64))).unwrap();
+		let y = FpVar::new_witness(cs.clone(), || Ok(Bn254Fr::from(2u64))).unwrap();
+		y.square()
+			.unwrap()
+			.square()
+			.unwrap()
+			.mul_equals(&y, &x)
+			.unwrap();
+		assert!(!cs.is_satisfied().unwrap());
+
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let x = FpVar::new_witness(cs.clone(), || Ok(Bn254Fr::from(5u64))).unwrap();
+		let y = inverse_sbox(&x).unwrap();
+		assert_eq!(sbox(&y).unwrap().value().unwrap(), Bn254Fr::from(5u64));
+		assert!(cs.is_satisfied().unwrap());
+	}
+
+	#[test]
+	fn test_constant_inputs() {
+		let values = [Bn254Fr::from(3u64), Bn254Fr::from(4u64)];
+		let hash = rescue_prime_hash_2(&values.map(FpVar::Constant)).unwrap();
+		assert!(hash.is_constant());
+		assert_eq!(hash.value().unwrap(), native(&values));
+	}
+
+	#[test]
+	fn test_unsupported_arity() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		assert!(rescue_prime_hash_var(&[]).is_err());
+		let values: Vec<Bn254Fr> = (0..9u64).map(Bn254Fr::from).collect();
+		assert!(rescue_prime_hash_var(&witnesses(&cs, &values)).is_err());
+		let params = RescuePrimeParameters::generate(3).unwrap();
+		assert!(rescue_prime_permutation(&params, &witnesses(&cs, &values[..2])).is_err());
+	}
+
+	#[test]
+	fn test_setup_mode() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		cs.set_mode(SynthesisMode::Setup);
+		let inputs: Vec<_> = (0..2)
+			.map(|_| {
+				FpVar::new_witness(cs.clone(), || {
+					Err::<Bn254Fr, _>(SynthesisError::AssignmentMissing)
+				})
+				.unwrap()
+			})
+			.collect();
+
+		let hash = rescue_prime_hash_var(&inputs).unwrap();
+		assert!(hash.value().is_err());
+		assert!(cs.num_constraints() > 0);
+	}
+
+	// ===== SchemeHashGadget Tests =====
+
+	#[test]
+	fn test_scheme_gadget_dispatch() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let values = [Bn254Fr::from(1u64), Bn254Fr::from(2u64)];
+		let vars = witnesses(&cs, &values);
+
+		let poseidon = SchemeHashGadget::new(HashScheme::Poseidon);
+		assert_eq!(
+			poseidon
+				.hash_2(&vars[0], &vars[1])
+				.unwrap()
+				.value()
+				.unwrap(),
+			native_poseidon_2(&values)
+		);
+		assert_eq!(
+			poseidon.hash_var(&vars).unwrap().value().unwrap(),
+			native_poseidon_2(&values)
+		);
+
+		let rescue = SchemeHashGadget::new(HashScheme::RescuePrime);
+		assert_eq!(
+			rescue.hash_2(&vars[0], &vars[1]).unwrap().value().unwrap(),
+			native(&values)
+		);
+		assert_eq!(
+			rescue.hash_var(&vars).unwrap().value().unwrap(),
+			native(&values)
+		);
+		assert!(cs.is_satisfied().unwrap());
+	}
+
+	#[test]
+	fn test_scheme_gadget_hash_4() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let values = [1u64, 2, 3, 4].map(Bn254Fr::from);
+		let vars: [FpVar<Bn254Fr>; 4] = witnesses(&cs, &values).try_into().unwrap();
+
+		for scheme in HashScheme::ALL {
+			let hash = SchemeHashGadget::new(scheme).hash_4(&vars).unwrap();
+			let expected = SchemeHasher::new(scheme)
+				.hash_4(values.map(FieldElement::new))
+				.inner();
+			assert_eq!(hash.value().unwrap(), expected, "{scheme:?}");
+		}
+	}
+
+	#[test]
+	fn test_default_scheme_is_poseidon() {
+		assert_eq!(SchemeHashGadget::default().scheme, HashScheme::Poseidon);
+	}
+}
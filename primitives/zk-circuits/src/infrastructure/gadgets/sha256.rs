@@ -0,0 +1,144 @@
+//! SHA-256 Gadget (R1CS Constraints)
+//!
+//! Binds circuits to SHA-256 commitments used on other chains (Bitcoin HTLCs,
+//! Ethereum `sha256` precompile), e.g. proving knowledge of a cross-chain preimage.
+//!
+//! ~27,000 constraints per 64-byte block, so prefer Poseidon for anything that
+//! stays inside Orbinum.
+
+use alloc::vec::Vec;
+use ark_crypto_primitives::crh::sha256::constraints::Sha256Gadget;
+use ark_ff::ToConstraintField;
+use ark_r1cs_std::{eq::EqGadget, uint8::UInt8};
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+use sha2::{Digest, Sha256};
+
+use crate::Bn254Fr;
+
+/// SHA-256 digest length in bytes
+pub const SHA256_DIGEST_LEN: usize = 32;
+
+// ============================================================================
+// Native Helpers
+// ============================================================================
+
+/// Computes SHA-256 of `preimage` (native)
+pub fn sha256_native(preimage: &[u8]) -> [u8; SHA256_DIGEST_LEN] {
+	Sha256::digest(preimage).into()
+}
+
+/// Public inputs encoding a digest allocated with [`alloc_sha256_digest_input`]
+///
+/// Bytes are packed little-endian, 31 bytes per field element (2 elements).
+pub fn sha256_digest_public_inputs(digest: &[u8; SHA256_DIGEST_LEN]) -> Vec<Bn254Fr> {
+	ToConstraintField::<Bn254Fr>::to_field_elements(&digest[..])
+		.expect("byte packing into field elements is infallible")
+}
+
+// ============================================================================
+// Circuit Gadgets (with R1CS constraints)
+// ============================================================================
+
+/// Computes SHA-256 of `preimage` (in-circuit)
+pub fn sha256_digest(preimage: &[UInt8<Bn254Fr>]) -> Result<Vec<UInt8<Bn254Fr>>, SynthesisError> {
+	Ok(Sha256Gadget::digest(preimage)?.0)
+}
+
+/// Allocates an expected digest as packed public inputs
+pub fn alloc_sha256_digest_input(
+	cs: ConstraintSystemRef<Bn254Fr>,
+	digest: &[u8; SHA256_DIGEST_LEN],
+) -> Result<Vec<UInt8<Bn254Fr>>, SynthesisError> {
+	UInt8::new_input_vec(cs, digest)
+}
+
+/// Enforces `SHA-256(preimage) == expected_digest`
+pub fn enforce_sha256_preimage(
+	preimage: &[UInt8<Bn254Fr>],
+	expected_digest: &[UInt8<Bn254Fr>],
+) -> Result<(), SynthesisError> {
+	if expected_digest.len() != SHA256_DIGEST_LEN {
+		return Err(SynthesisError::Unsatisfiable);
+	}
+
+	let digest = sha256_digest(preimage)?;
+	digest.as_slice().enforce_equal(expected_digest)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use ark_r1cs_std::R1CSVar;
+	use ark_relations::r1cs::ConstraintSystem;
+
+	#[test]
+	fn test_sha256_native_known_vector() {
+		// SHA-256("abc")
+		let digest = sha256_native(b"abc");
+		assert_eq!(digest[..4], [0xba, 0x78, 0x16, 0xbf]);
+		assert_eq!(digest[28..], [0xf2, 0x00, 0x15, 0xad]);
+	}
+
+	#[test]
+	fn test_sha256_gadget_matches_native() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let preimage = b"orbinum cross-chain claim";
+
+		let preimage_vars = UInt8::new_witness_vec(cs.clone(), preimage).unwrap();
+		let digest = sha256_digest(&preimage_vars).unwrap();
+
+		let digest_bytes: Vec<u8> = digest.iter().map(|b| b.value().unwrap()).collect();
+		assert_eq!(digest_bytes, sha256_native(preimage));
+		assert!(cs.is_satisfied().unwrap());
+	}
+
+	#[test]
+	fn test_sha256_preimage_satisfied() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let preimage = [7u8; 40];
+		let expected = sha256_native(&preimage);
+
+		let preimage_vars = UInt8::new_witness_vec(cs.clone(), &preimage).unwrap();
+		let expected_vars = alloc_sha256_digest_input(cs.clone(), &expected).unwrap();
+
+		enforce_sha256_preimage(&preimage_vars, &expected_vars).unwrap();
+		assert!(cs.is_satisfied().unwrap());
+	}
+
+	#[test]
+	fn test_sha256_wrong_preimage_unsatisfied() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let expected = sha256_native(b"secret");
+
+		let preimage_vars = UInt8::new_witness_vec(cs.clone(), b"guess").unwrap();
+		let expected_vars = alloc_sha256_digest_input(cs.clone(), &expected).unwrap();
+
+		enforce_sha256_preimage(&preimage_vars, &expected_vars).unwrap();
+		assert!(!cs.is_satisfied().unwrap());
+	}
+
+	#[test]
+	fn test_sha256_rejects_short_expected_digest() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let preimage_vars = UInt8::new_witness_vec(cs.clone(), b"abc").unwrap();
+		let short = UInt8::constant_vec(&[0u8; 16]);
+
+		assert!(enforce_sha256_preimage(&preimage_vars, &short).is_err());
+	}
+
+	#[test]
+	fn test_sha256_digest_public_inputs_layout() {
+		let digest = sha256_native(b"abc");
+		let inputs = sha256_digest_public_inputs(&digest);
+
+		assert_eq!(inputs.len(), 2);
+
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		alloc_sha256_digest_input(cs.clone(), &digest).unwrap();
+		assert_eq!(cs.num_instance_variables() - 1, inputs.len());
+	}
+}
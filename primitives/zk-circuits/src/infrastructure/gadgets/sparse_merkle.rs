@@ -0,0 +1,239 @@
+//! Sparse Merkle Tree Gadget (R1CS Constraints)
+//!
+//! Inclusion and exclusion proofs over a Poseidon SMT keyed by a field element
+//! (nullifier, account hash). Used by non-membership and allowlist circuits.
+//!
+//! Layout matches `infrastructure::sparse_merkle_tree`:
+//! - The key's low `depth` bits select the leaf slot (LSB = level 0)
+//! - Occupied leaf: `Poseidon(key, value)`, empty leaf: `0`
+
+use alloc::vec::Vec;
+use ark_r1cs_std::{
+	boolean::Boolean, convert::ToBitsGadget, eq::EqGadget, fields::fp::FpVar,
+	select::CondSelectGadget,
+};
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+use super::{merkle::merkle_tree_verifier, poseidon::poseidon_hash_2};
+use crate::Bn254Fr;
+
+// ============================================================================
+// Circuit Gadgets (with R1CS constraints)
+// ============================================================================
+
+/// SMT leaf hash (in-circuit)
+///
+/// `leaf = Poseidon(key, value)`
+pub fn smt_leaf(
+	cs: ConstraintSystemRef<Bn254Fr>,
+	key: &FpVar<Bn254Fr>,
+	value: &FpVar<Bn254Fr>,
+) -> Result<FpVar<Bn254Fr>, SynthesisError> {
+	poseidon_hash_2(cs, &[key.clone(), value.clone()])
+}
+
+/// Path bits of a key (low `depth` bits, little-endian)
+pub fn smt_path_bits(
+	key: &FpVar<Bn254Fr>,
+	depth: usize,
+) -> Result<Vec<Boolean<Bn254Fr>>, SynthesisError> {
+	let mut bits = key.to_bits_le()?;
+	if depth > bits.len() {
+		return Err(SynthesisError::Unsatisfiable);
+	}
+	bits.truncate(depth);
+	Ok(bits)
+}
+
+/// Proves `(key, value)` is stored in the SMT with `expected_root`
+pub fn verify_smt_inclusion(
+	cs: ConstraintSystemRef<Bn254Fr>,
+	key: &FpVar<Bn254Fr>,
+	value: &FpVar<Bn254Fr>,
+	siblings: &[FpVar<Bn254Fr>],
+	expected_root: &FpVar<Bn254Fr>,
+) -> Result<(), SynthesisError> {
+	let leaf = smt_leaf(cs.clone(), key, value)?;
+	let path = smt_path_bits(key, siblings.len())?;
+
+	let computed_root = merkle_tree_verifier(cs, &leaf, siblings, &path)?;
+	computed_root.enforce_equal(expected_root)
+}
+
+/// Proves `key` is absent from the SMT with `expected_root`
+///
+/// The key's slot is either empty (`occupant_is_empty = true`) or holds a
+/// different key (`occupant_key != key`) with `occupant_value`.
+pub fn verify_smt_exclusion(
+	cs: ConstraintSystemRef<Bn254Fr>,
+	key: &FpVar<Bn254Fr>,
+	siblings: &[FpVar<Bn254Fr>],
+	expected_root: &FpVar<Bn254Fr>,
+	occupant_key: &FpVar<Bn254Fr>,
+	occupant_value: &FpVar<Bn254Fr>,
+	occupant_is_empty: &Boolean<Bn254Fr>,
+) -> Result<(), SynthesisError> {
+	let occupied = !occupant_is_empty.clone();
+
+	// A different key must actually differ and sit in the same slot
+	occupant_key.conditional_enforce_not_equal(key, &occupied)?;
+	let path = smt_path_bits(key, siblings.len())?;
+	let occupant_path = smt_path_bits(occupant_key, siblings.len())?;
+	for (bit, occupant_bit) in path.iter().zip(occupant_path.iter()) {
+		occupant_bit.conditional_enforce_equal(bit, &occupied)?;
+	}
+
+	let occupant_leaf = smt_leaf(cs.clone(), occupant_key, occupant_value)?;
+	let empty_leaf = FpVar::Constant(Bn254Fr::from(0u64));
+	let leaf = FpVar::conditionally_select(occupant_is_empty, &empty_leaf, &occupant_leaf)?;
+
+	let computed_root = merkle_tree_verifier(cs, &leaf, siblings, &path)?;
+	computed_root.enforce_equal(expected_root)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::infrastructure::sparse_merkle_tree::SparseMerkleTree;
+	use ark_r1cs_std::{alloc::AllocVar, R1CSVar};
+	use ark_relations::r1cs::ConstraintSystem;
+
+	const DEPTH: usize = 8;
+
+	fn sample_tree() -> SparseMerkleTree {
+		let mut tree = SparseMerkleTree::new(DEPTH).unwrap();
+		for i in [3u64, 10, 42, 100] {
+			tree.insert(Bn254Fr::from(i), Bn254Fr::from(i + 1)).unwrap();
+		}
+		tree
+	}
+
+	fn alloc_siblings(
+		cs: &ConstraintSystemRef<Bn254Fr>,
+		siblings: &[Bn254Fr],
+	) -> Vec<FpVar<Bn254Fr>> {
+		siblings
+			.iter()
+			.map(|s| FpVar::new_witness(cs.clone(), || Ok(*s)).unwrap())
+			.collect()
+	}
+
+	fn check_exclusion(tree: &SparseMerkleTree, key: Bn254Fr) -> bool {
+		let proof = tree.exclusion_proof(&key).unwrap();
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+
+		let (occupant_key, occupant_value) = proof
+			.occupant
+			.unwrap_or((Bn254Fr::from(0u64), Bn254Fr::from(0u64)));
+
+		let key_var = FpVar::new_witness(cs.clone(), || Ok(key)).unwrap();
+		let root_var = FpVar::new_input(cs.clone(), || Ok(tree.root())).unwrap();
+		let occupant_key_var = FpVar::new_witness(cs.clone(), || Ok(occupant_key)).unwrap();
+		let occupant_value_var = FpVar::new_witness(cs.clone(), || Ok(occupant_value)).unwrap();
+		let is_empty = Boolean::new_witness(cs.clone(), || Ok(proof.occupant.is_none())).unwrap();
+		let siblings = alloc_siblings(&cs, &proof.siblings);
+
+		verify_smt_exclusion(
+			cs.clone(),
+			&key_var,
+			&siblings,
+			&root_var,
+			&occupant_key_var,
+			&occupant_value_var,
+			&is_empty,
+		)
+		.unwrap();
+
+		cs.is_satisfied().unwrap()
+	}
+
+	#[test]
+	fn test_smt_inclusion_satisfied() {
+		let tree = sample_tree();
+		let key = Bn254Fr::from(42u64);
+		let proof = tree.inclusion_proof(&key).unwrap();
+
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let key_var = FpVar::new_witness(cs.clone(), || Ok(key)).unwrap();
+		let value_var = FpVar::new_witness(cs.clone(), || Ok(Bn254Fr::from(43u64))).unwrap();
+		let root_var = FpVar::new_input(cs.clone(), || Ok(tree.root())).unwrap();
+		let siblings = alloc_siblings(&cs, &proof.siblings);
+
+		verify_smt_inclusion(cs.clone(), &key_var, &value_var, &siblings, &root_var).unwrap();
+
+		assert!(cs.is_satisfied().unwrap());
+	}
+
+	#[test]
+	fn test_smt_inclusion_wrong_value_unsatisfied() {
+		let tree = sample_tree();
+		let key = Bn254Fr::from(42u64);
+		let proof = tree.inclusion_proof(&key).unwrap();
+
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let key_var = FpVar::new_witness(cs.clone(), || Ok(key)).unwrap();
+		let value_var = FpVar::new_witness(cs.clone(), || Ok(Bn254Fr::from(999u64))).unwrap();
+		let root_var = FpVar::new_input(cs.clone(), || Ok(tree.root())).unwrap();
+		let siblings = alloc_siblings(&cs, &proof.siblings);
+
+		verify_smt_inclusion(cs.clone(), &key_var, &value_var, &siblings, &root_var).unwrap();
+
+		assert!(!cs.is_satisfied().unwrap());
+	}
+
+	#[test]
+	fn test_smt_exclusion_empty_slot_satisfied() {
+		let tree = sample_tree();
+		assert!(check_exclusion(&tree, Bn254Fr::from(7u64)));
+	}
+
+	#[test]
+	fn test_smt_exclusion_occupied_by_other_key_satisfied() {
+		let tree = sample_tree();
+		// 259 = 256 + 3 shares the low 8 bits with key 3
+		assert!(check_exclusion(&tree, Bn254Fr::from(259u64)));
+	}
+
+	#[test]
+	fn test_smt_exclusion_of_present_key_unsatisfied() {
+		let tree = sample_tree();
+		let key = Bn254Fr::from(10u64);
+		let proof = tree.inclusion_proof(&key).unwrap();
+
+		// Claim the slot is empty although key 10 is stored there
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let key_var = FpVar::new_witness(cs.clone(), || Ok(key)).unwrap();
+		let root_var = FpVar::new_input(cs.clone(), || Ok(tree.root())).unwrap();
+		let zero = FpVar::new_witness(cs.clone(), || Ok(Bn254Fr::from(0u64))).unwrap();
+		let is_empty = Boolean::new_witness(cs.clone(), || Ok(true)).unwrap();
+		let siblings = alloc_siblings(&cs, &proof.siblings);
+
+		verify_smt_exclusion(
+			cs.clone(),
+			&key_var,
+			&siblings,
+			&root_var,
+			&zero,
+			&zero,
+			&is_empty,
+		)
+		.unwrap();
+
+		assert!(!cs.is_satisfied().unwrap());
+	}
+
+	#[test]
+	fn test_smt_path_bits_truncated() {
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let key_var = FpVar::new_witness(cs.clone(), || Ok(Bn254Fr::from(0b1011u64))).unwrap();
+
+		let bits = smt_path_bits(&key_var, 4).unwrap();
+		let values: Vec<bool> = bits.iter().map(|b| b.value().unwrap()).collect();
+
+		assert_eq!(values, [true, true, false, true]);
+	}
+}
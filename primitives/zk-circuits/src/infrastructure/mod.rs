@@ -5,6 +5,7 @@
 
 pub mod gadgets;
 pub mod native_crypto;
+pub mod sparse_merkle_tree;
 
 pub use gadgets::*;
 pub use native_crypto::*;
@@ -2,9 +2,12 @@
 //!
 //! Convenient function-based access to native crypto operations.
 
+use alloc::vec::Vec;
 use orbinum_zk_core::{
 	domain::{ports::PoseidonHasher, value_objects::FieldElement},
-	infrastructure::crypto::LightPoseidonHasher,
+	infrastructure::crypto::{
+		poseidon_sponge::poseidon_sponge as core_sponge, LightPoseidonHasher,
+	},
 };
 
 use ark_bn254::Fr;
@@ -43,6 +46,12 @@ pub fn poseidon_hash(inputs: &[Fr]) -> Result<Fr, &'static str> {
 	}
 }
 
+/// Sponge hash for arbitrary-length inputs (matches `orbinum-zk-core`)
+pub fn poseidon_sponge(inputs: &[Fr]) -> Fr {
+	let field_inputs: Vec<FieldElement> = inputs.iter().copied().map(FieldElement::new).collect();
+	core_sponge(&field_inputs).inner()
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -437,4 +446,23 @@ mod tests {
 		assert_ne!(result2, result3);
 		assert_ne!(result1, result3);
 	}
+
+	// ===== poseidon_sponge Tests =====
+
+	#[test]
+	fn test_poseidon_sponge_matches_core() {
+		let inputs: Vec<Fr> = (1..=9u64).map(Fr::from).collect();
+		let field_inputs: Vec<FieldElement> =
+			inputs.iter().copied().map(FieldElement::new).collect();
+
+		assert_eq!(poseidon_sponge(&inputs), core_sponge(&field_inputs).inner());
+	}
+
+	#[test]
+	fn test_poseidon_sponge_length_sensitive() {
+		let short = vec![Fr::from(7u64)];
+		let long = vec![Fr::from(7u64), Fr::from(0u64)];
+
+		assert_ne!(poseidon_sponge(&short), poseidon_sponge(&long));
+	}
 }
@@ -0,0 +1,310 @@
+//! Sparse Merkle Tree (off-chain helper)
+//!
+//! `Bn254Fr` facade over `orbinum_zk_core`'s Poseidon SMT, used to build
+//! witnesses for the `sparse_merkle` gadget. See
+//! `orbinum_zk_core::infrastructure::crypto::merkle::sparse` for the layout.
+//!
+//! Two keys sharing the same low bits collide; `insert` rejects the second one.
+
+use alloc::vec::Vec;
+use orbinum_zk_core::{
+	domain::value_objects::FieldElement,
+	infrastructure::crypto::{
+		merkle::{sparse, MerkleTreeError},
+		LightPoseidonHasher,
+	},
+};
+
+use crate::Bn254Fr;
+
+pub use sparse::MAX_SMT_DEPTH;
+
+/// Sibling path and old leaf data for an SMT proof
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmtProof {
+	/// Sibling hashes from leaf level up to the root
+	pub siblings: Vec<Bn254Fr>,
+	/// Occupant of the slot as `(key, value)`, `None` if the slot is empty
+	pub occupant: Option<(Bn254Fr, Bn254Fr)>,
+}
+
+impl From<sparse::SmtProof> for SmtProof {
+	fn from(proof: sparse::SmtProof) -> Self {
+		Self {
+			siblings: proof.siblings.iter().map(FieldElement::inner).collect(),
+			occupant: proof
+				.occupant
+				.map(|(key, value)| (key.inner(), value.inner())),
+		}
+	}
+}
+
+/// Poseidon sparse Merkle tree keyed by field elements
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree {
+	inner: sparse::SparseMerkleTree<LightPoseidonHasher>,
+}
+
+/// Computes an SMT leaf hash (native)
+///
+/// `leaf = Poseidon(key, value)`
+pub fn smt_leaf_native(key: Bn254Fr, value: Bn254Fr) -> Bn254Fr {
+	sparse::smt_leaf(&LightPoseidonHasher, key.into(), value.into()).inner()
+}
+
+/// Slot index of a key for the given depth (low `depth` bits)
+pub fn smt_slot(key: &Bn254Fr, depth: usize) -> u64 {
+	sparse::smt_slot(&FieldElement::new(*key), depth)
+}
+
+/// Computes the SMT root from a leaf and its sibling path (native)
+pub fn smt_root_from_path(leaf: Bn254Fr, slot: u64, siblings: &[Bn254Fr]) -> Bn254Fr {
+	let siblings: Vec<FieldElement> = siblings.iter().copied().map(FieldElement::new).collect();
+	sparse::smt_root_from_path(&LightPoseidonHasher, leaf.into(), slot, &siblings).inner()
+}
+
+fn error_message(err: MerkleTreeError) -> &'static str {
+	match err {
+		MerkleTreeError::SlotOccupied(_) => "SMT slot occupied by a different key",
+		_ => "SMT operation failed",
+	}
+}
+
+impl SparseMerkleTree {
+	/// Creates an empty tree of the given depth
+	pub fn new(depth: usize) -> Result<Self, &'static str> {
+		if depth == 0 {
+			return Err("SMT depth must be positive");
+		}
+		if depth > MAX_SMT_DEPTH {
+			return Err("SMT depth exceeds maximum");
+		}
+
+		let inner =
+			sparse::SparseMerkleTree::new(LightPoseidonHasher, depth).map_err(error_message)?;
+		Ok(Self { inner })
+	}
+
+	/// Tree depth
+	pub fn depth(&self) -> usize {
+		self.inner.depth()
+	}
+
+	/// Number of occupied leaves
+	pub fn len(&self) -> usize {
+		self.inner.len()
+	}
+
+	/// Whether the tree has no occupied leaves
+	pub fn is_empty(&self) -> bool {
+		self.inner.is_empty()
+	}
+
+	/// Current root
+	pub fn root(&self) -> Bn254Fr {
+		self.inner.root().inner()
+	}
+
+	/// Root of an empty tree of this depth
+	pub fn empty_root(&self) -> Bn254Fr {
+		self.inner.empty_root().inner()
+	}
+
+	/// Value stored under `key`, if present
+	pub fn get(&self, key: &Bn254Fr) -> Option<Bn254Fr> {
+		self.inner
+			.get(&FieldElement::new(*key))
+			.map(|value| value.inner())
+	}
+
+	/// Inserts or updates `key`
+	///
+	/// Fails if the slot is already occupied by a different key.
+	pub fn insert(&mut self, key: Bn254Fr, value: Bn254Fr) -> Result<Bn254Fr, &'static str> {
+		self.inner
+			.insert(key.into(), value.into())
+			.map(|root| root.inner())
+			.map_err(error_message)
+	}
+
+	/// Removes `key`, returning its previous value
+	pub fn remove(&mut self, key: &Bn254Fr) -> Option<Bn254Fr> {
+		self.inner
+			.remove(&FieldElement::new(*key))
+			.map(|value| value.inner())
+	}
+
+	/// Applies a batch of updates and recomputes each affected node once
+	///
+	/// `Some(value)` inserts/updates, `None` removes. The batch is validated
+	/// before any change is applied, so a failing batch leaves the tree untouched.
+	pub fn apply_batch(
+		&mut self,
+		updates: &[(Bn254Fr, Option<Bn254Fr>)],
+	) -> Result<Bn254Fr, &'static str> {
+		let updates: Vec<(FieldElement, Option<FieldElement>)> = updates
+			.iter()
+			.map(|(key, value)| ((*key).into(), value.map(FieldElement::new)))
+			.collect();
+		self.inner
+			.apply_batch(&updates)
+			.map(|root| root.inner())
+			.map_err(error_message)
+	}
+
+	/// Inclusion proof for `key`, `None` if the key is absent
+	pub fn inclusion_proof(&self, key: &Bn254Fr) -> Option<SmtProof> {
+		self.inner
+			.inclusion_proof(&FieldElement::new(*key))
+			.map(SmtProof::from)
+	}
+
+	/// Exclusion proof for `key`, `None` if the key is present
+	///
+	/// The slot is either empty or held by a different key (returned as occupant).
+	pub fn exclusion_proof(&self, key: &Bn254Fr) -> Option<SmtProof> {
+		self.inner
+			.exclusion_proof(&FieldElement::new(*key))
+			.map(SmtProof::from)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_new_rejects_invalid_depth() {
+		assert!(SparseMerkleTree::new(0).is_err());
+		assert!(SparseMerkleTree::new(MAX_SMT_DEPTH + 1).is_err());
+		assert!(SparseMerkleTree::new(MAX_SMT_DEPTH).is_ok());
+	}
+
+	#[test]
+	fn test_empty_tree_root() {
+		let tree = SparseMerkleTree::new(8).unwrap();
+		assert!(tree.is_empty());
+		assert_eq!(tree.root(), tree.empty_root());
+	}
+
+	#[test]
+	fn test_insert_get_remove() {
+		let mut tree = SparseMerkleTree::new(8).unwrap();
+		let key = Bn254Fr::from(5u64);
+
+		tree.insert(key, Bn254Fr::from(1u64)).unwrap();
+		assert_eq!(tree.get(&key), Some(Bn254Fr::from(1u64)));
+		assert_ne!(tree.root(), tree.empty_root());
+
+		assert_eq!(tree.remove(&key), Some(Bn254Fr::from(1u64)));
+		assert_eq!(tree.get(&key), None);
+		assert_eq!(tree.root(), tree.empty_root());
+	}
+
+	#[test]
+	fn test_slot_collision_rejected() {
+		let mut tree = SparseMerkleTree::new(4).unwrap();
+		tree.insert(Bn254Fr::from(3u64), Bn254Fr::from(1u64))
+			.unwrap();
+
+		// 19 = 0b10011 shares the low 4 bits with 3
+		let result = tree.insert(Bn254Fr::from(19u64), Bn254Fr::from(1u64));
+		assert_eq!(result, Err("SMT slot occupied by a different key"));
+	}
+
+	#[test]
+	fn test_inclusion_proof_recomputes_root() {
+		let mut tree = SparseMerkleTree::new(16).unwrap();
+		for i in 1..10u64 {
+			tree.insert(Bn254Fr::from(i * 7), Bn254Fr::from(i)).unwrap();
+		}
+
+		let key = Bn254Fr::from(21u64);
+		let proof = tree.inclusion_proof(&key).unwrap();
+		let leaf = smt_leaf_native(key, Bn254Fr::from(3u64));
+
+		assert_eq!(proof.siblings.len(), 16);
+		assert_eq!(
+			smt_root_from_path(leaf, smt_slot(&key, 16), &proof.siblings),
+			tree.root()
+		);
+	}
+
+	#[test]
+	fn test_exclusion_proof_empty_slot() {
+		let mut tree = SparseMerkleTree::new(16).unwrap();
+		tree.insert(Bn254Fr::from(1u64), Bn254Fr::from(1u64))
+			.unwrap();
+
+		let key = Bn254Fr::from(2u64);
+		let proof = tree.exclusion_proof(&key).unwrap();
+
+		assert_eq!(proof.occupant, None);
+		assert_eq!(
+			smt_root_from_path(Bn254Fr::from(0u64), smt_slot(&key, 16), &proof.siblings),
+			tree.root()
+		);
+		assert!(tree.exclusion_proof(&Bn254Fr::from(1u64)).is_none());
+	}
+
+	#[test]
+	fn test_exclusion_proof_occupied_by_other_key() {
+		let mut tree = SparseMerkleTree::new(4).unwrap();
+		tree.insert(Bn254Fr::from(3u64), Bn254Fr::from(9u64))
+			.unwrap();
+
+		let proof = tree.exclusion_proof(&Bn254Fr::from(19u64)).unwrap();
+		assert_eq!(
+			proof.occupant,
+			Some((Bn254Fr::from(3u64), Bn254Fr::from(9u64)))
+		);
+	}
+
+	#[test]
+	fn test_apply_batch_matches_sequential_inserts() {
+		let updates: Vec<(Bn254Fr, Option<Bn254Fr>)> = (1..20u64)
+			.map(|i| (Bn254Fr::from(i), Some(Bn254Fr::from(i * 100))))
+			.collect();
+
+		let mut batched = SparseMerkleTree::new(12).unwrap();
+		let batched_root = batched.apply_batch(&updates).unwrap();
+
+		let mut sequential = SparseMerkleTree::new(12).unwrap();
+		for (key, value) in &updates {
+			sequential.insert(*key, value.unwrap()).unwrap();
+		}
+
+		assert_eq!(batched_root, sequential.root());
+		assert_eq!(batched.len(), 19);
+	}
+
+	#[test]
+	fn test_apply_batch_with_removals() {
+		let mut tree = SparseMerkleTree::new(8).unwrap();
+		tree.insert(Bn254Fr::from(1u64), Bn254Fr::from(1u64))
+			.unwrap();
+		tree.insert(Bn254Fr::from(2u64), Bn254Fr::from(2u64))
+			.unwrap();
+
+		let root = tree
+			.apply_batch(&[(Bn254Fr::from(1u64), None), (Bn254Fr::from(2u64), None)])
+			.unwrap();
+
+		assert_eq!(root, tree.empty_root());
+	}
+
+	#[test]
+	fn test_apply_batch_rejects_collision_atomically() {
+		let mut tree = SparseMerkleTree::new(4).unwrap();
+		let root_before = tree.root();
+
+		let result = tree.apply_batch(&[
+			(Bn254Fr::from(3u64), Some(Bn254Fr::from(1u64))),
+			(Bn254Fr::from(19u64), Some(Bn254Fr::from(1u64))),
+		]);
+
+		assert!(result.is_err());
+		assert_eq!(tree.root(), root_before);
+		assert!(tree.is_empty());
+	}
+}
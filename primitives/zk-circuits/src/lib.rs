@@ -53,6 +53,7 @@
 //!
 //! ### Domain Layer
 //! - [`domain::value_objects`]: Core immutable types (WitnessValue, TreeDepth)
+//! - [`domain::circuit_ids`]: Circuit IDs and public-input schemas (shared with verifiers)
 //! - [`domain::services`]: Business logic (CircuitValidator)
 //! - [`domain::ports`]: Abstract interfaces (ConstraintSystem, HashGadget)
 //!
@@ -60,11 +61,17 @@
 //! - [`infrastructure::gadgets::poseidon`]: Poseidon hash gadget
 //! - [`infrastructure::gadgets::merkle`]: Merkle tree verification gadget
 //! - [`infrastructure::gadgets::commitment`]: Commitment/nullifier gadgets
+//! - [`infrastructure::gadgets::sparse_merkle`]: Sparse Merkle tree inclusion/exclusion gadget
+//! - [`infrastructure::gadgets::sha256`]: SHA-256 gadget for cross-chain preimage proofs
+//! - `infrastructure::gadgets::rescue_prime`: Rescue-Prime gadget and scheme-selected hashing
+//!   (`rescue-prime` feature)
+//! - [`infrastructure::sparse_merkle_tree`]: Off-chain SMT with batched updates (wraps `orbinum-zk-core`)
 //! - [`infrastructure::native_crypto`]: Bridge to native crypto operations
 //!
 //! ### Application Layer
 //! - [`application::circuits::note`]: Note commitment circuits
 //! - [`application::circuits::transfer`]: Private transfer circuit (use case)
+//! - [`application::circuits::viewing_key`]: Viewing key ownership circuit
 //! - [`application::dto`]: Data Transfer Objects (PublicInputs, WitnessData)
 //!
 //! ## Example
@@ -88,6 +95,7 @@
 //! ## Features
 //!
 //! - `std` (default): Standard library support
+//! - `rescue-prime`: Rescue-Prime gadgets and `SchemeHashGadget` for proving-cost comparisons
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
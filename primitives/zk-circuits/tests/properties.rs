@@ -0,0 +1,68 @@
+//! Property tests: in-circuit commitments and nullifiers equal `orbinum-zk-core`
+//!
+//! The gadgets re-implement the native primitives as R1CS constraints, so any
+//! drift between the two stacks would make honest proofs fail to verify.
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+use orbinum_zk_circuits::{
+	infrastructure::gadgets::commitment::{compute_nullifier_versioned, create_commitment},
+	Bn254Fr,
+};
+use orbinum_zk_core::{
+	Blinding, FieldElement, LightPoseidonHasher, Note, NullifierVersion, OwnerPubkey, SpendingKey,
+};
+use proptest::prelude::*;
+
+fn field_element() -> impl Strategy<Value = Bn254Fr> {
+	any::<[u8; 32]>().prop_map(|bytes| Bn254Fr::from_le_bytes_mod_order(&bytes))
+}
+
+proptest! {
+	// Constraint synthesis is slow; keep the sample small
+	#![proptest_config(ProptestConfig::with_cases(16))]
+
+	#[test]
+	fn gadgets_match_native(
+		value in any::<u64>(),
+		asset_id in any::<u64>(),
+		owner in field_element(),
+		blinding in field_element(),
+		sk in field_element(),
+	) {
+		let note = Note::new(
+			value,
+			asset_id,
+			OwnerPubkey::new(FieldElement::new(owner)),
+			Blinding::new(FieldElement::new(blinding)),
+		);
+		let key = SpendingKey::new(FieldElement::new(sk));
+
+		let cs = ConstraintSystem::<Bn254Fr>::new_ref();
+		let witness = |x: Bn254Fr| FpVar::new_witness(cs.clone(), || Ok(x)).unwrap();
+		let commitment = create_commitment(
+			cs.clone(),
+			&witness(Bn254Fr::from(value)),
+			&witness(Bn254Fr::from(asset_id)),
+			&witness(owner),
+			&witness(blinding),
+		)
+		.unwrap();
+		prop_assert_eq!(
+			commitment.value().unwrap(),
+			note.commitment(LightPoseidonHasher).as_fr()
+		);
+
+		let sk_var = witness(sk);
+		for version in NullifierVersion::ALL {
+			let nullifier =
+				compute_nullifier_versioned(cs.clone(), &commitment, &sk_var, version).unwrap();
+			prop_assert_eq!(
+				nullifier.value().unwrap(),
+				note.nullifier_versioned(LightPoseidonHasher, &key, version).as_fr()
+			);
+		}
+		prop_assert!(cs.is_satisfied().unwrap());
+	}
+}
@@ -0,0 +1,99 @@
+//! Poseidon throughput: `LightPoseidonHasher` vs `NativePoseidonHasher`
+//!
+//! Run with `cargo bench -p orbinum-zk-core`. Outside a runtime the host
+//! interface calls its native implementation directly, so the native numbers
+//! exclude the WASM -> host boundary cost and are a lower bound for weights.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use orbinum_zk_core::{
+	compute_commitments, domain::ports::PoseidonHasher, poseidon_hash_n, Blinding, FieldElement,
+	LightPoseidonHasher, Note, OwnerPubkey,
+};
+use std::hint::black_box;
+
+/// Arities with a dedicated host function
+const ARITIES: [usize; 7] = [2, 3, 4, 5, 6, 7, 8];
+
+/// Items hashed per iteration in the batch benchmarks
+const BATCH_SIZES: [usize; 4] = [1, 16, 256, 1024];
+
+fn inputs(count: usize) -> Vec<FieldElement> {
+	(1..=count as u64).map(FieldElement::from_u64).collect()
+}
+
+fn notes(count: usize) -> Vec<Note> {
+	(0..count as u64)
+		.map(|i| {
+			Note::new(
+				i,
+				0,
+				OwnerPubkey::new(FieldElement::from_u64(i + 1)),
+				Blinding::new(FieldElement::from_u64(i + 2)),
+			)
+		})
+		.collect()
+}
+
+fn bench_arity(c: &mut Criterion) {
+	let mut group = c.benchmark_group("poseidon_arity");
+	for arity in ARITIES {
+		let data = inputs(arity);
+		group.bench_with_input(BenchmarkId::new("light", arity), &data, |b, data| {
+			b.iter(|| poseidon_hash_n(black_box(data)))
+		});
+		#[cfg(feature = "poseidon-native")]
+		group.bench_with_input(BenchmarkId::new("native", arity), &data, |b, data| {
+			b.iter(|| orbinum_zk_core::NativePoseidonHasher.hash_n(black_box(data)))
+		});
+	}
+	group.finish();
+}
+
+fn bench_hash_2_batch(c: &mut Criterion) {
+	let mut group = c.benchmark_group("poseidon_hash_2_batch");
+	for size in BATCH_SIZES {
+		let pairs: Vec<[FieldElement; 2]> = inputs(2 * size)
+			.chunks_exact(2)
+			.map(|pair| [pair[0], pair[1]])
+			.collect();
+		group.throughput(Throughput::Elements(size as u64));
+		group.bench_with_input(BenchmarkId::new("light", size), &pairs, |b, pairs| {
+			b.iter(|| hash_all(&LightPoseidonHasher, black_box(pairs)))
+		});
+		#[cfg(feature = "poseidon-native")]
+		group.bench_with_input(BenchmarkId::new("native", size), &pairs, |b, pairs| {
+			b.iter(|| hash_all(&orbinum_zk_core::NativePoseidonHasher, black_box(pairs)))
+		});
+	}
+	group.finish();
+}
+
+fn bench_commitment_batch(c: &mut Criterion) {
+	let mut group = c.benchmark_group("commitment_batch");
+	for size in BATCH_SIZES {
+		let notes = notes(size);
+		group.throughput(Throughput::Elements(size as u64));
+		group.bench_with_input(BenchmarkId::new("light", size), &notes, |b, notes| {
+			b.iter(|| commit_all(LightPoseidonHasher, black_box(notes)))
+		});
+		#[cfg(feature = "poseidon-native")]
+		group.bench_with_input(BenchmarkId::new("native", size), &notes, |b, notes| {
+			b.iter(|| commit_all(orbinum_zk_core::NativePoseidonHasher, black_box(notes)))
+		});
+		group.bench_with_input(BenchmarkId::new("batch", size), &notes, |b, notes| {
+			b.iter(|| compute_commitments(black_box(notes)))
+		});
+	}
+	group.finish();
+}
+
+fn hash_all<H: PoseidonHasher>(hasher: &H, pairs: &[[FieldElement; 2]]) -> Vec<FieldElement> {
+	pairs.iter().map(|pair| hasher.hash_2(*pair)).collect()
+}
+
+fn commit_all<H: PoseidonHasher + Copy>(hasher: H, notes: &[Note]) -> Vec<FieldElement> {
+	notes.iter().map(|note| note.commitment(hasher).inner()).collect()
+}
+
+criterion_group!(benches, bench_arity, bench_hash_2_batch, bench_commitment_batch);
+criterion_main!(benches);
@@ -0,0 +1,468 @@
+//! Payment Disclosure
+//!
+//! Out-of-band evidence that a sender made a specific shielded payment. The
+//! sender hands a merchant or court the note opening (the memo plaintext,
+//! including the blinding factor) signed with a key the verifier already
+//! associates with them (e.g. their account key):
+//!
+//! ```text
+//! message = PAYMENT_DISCLOSURE_DOMAIN || commitment (32, LE) || note (81) || signer (32)
+//! ```
+//!
+//! The verifier recomputes the commitment from the opening, checks the
+//! signature, and checks the commitment against an on-chain Merkle root. A
+//! disclosure reveals the note to whoever holds it but grants no spend
+//! authority.
+//!
+//! Serialized layout (version 1, 210 bytes):
+//!
+//! ```text
+//! version (1) || commitment (32, LE) || note (81) || signer (32) || signature (64)
+//! ```
+
+use crate::{
+	application::dto::MerkleProofDto,
+	domain::{
+		entities::{note_encoding::NOTE_ENCODED_LENGTH, Note},
+		ports::{
+			DisclosureSigner, PoseidonHasher, SignatureVerifier, SignerError,
+			DISCLOSURE_SIGNATURE_LENGTH,
+		},
+		services::MerkleService,
+		value_objects::{Commitment, FieldElement},
+	},
+};
+use alloc::vec::Vec;
+
+/// Domain separator of the signed disclosure message
+pub const PAYMENT_DISCLOSURE_DOMAIN: &[u8] = b"orbinum-payment-disclosure-v1";
+
+/// Current payment disclosure encoding version
+pub const PAYMENT_DISCLOSURE_VERSION: u8 = 1;
+
+/// Encoded payment disclosure length (version 1)
+pub const PAYMENT_DISCLOSURE_LENGTH: usize =
+	1 + 32 + NOTE_ENCODED_LENGTH + 32 + DISCLOSURE_SIGNATURE_LENGTH;
+
+/// Errors that can occur when creating or verifying a payment disclosure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaymentDisclosureError {
+	/// Signer failed to sign the disclosure
+	Signing(SignerError),
+	/// Note opening does not hash to the disclosed commitment
+	CommitmentMismatch,
+	/// Signature does not verify under the disclosed signer key
+	InvalidSignature,
+	/// Merkle proof is for a different leaf than the disclosed commitment
+	LeafMismatch,
+	/// Merkle proof ends in a root other than the on-chain root
+	UnknownRoot,
+	/// Merkle path does not lead from the commitment to the root
+	InvalidMerkleProof,
+	/// Encoded disclosure is malformed
+	InvalidEncoding,
+}
+
+impl core::fmt::Display for PaymentDisclosureError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			PaymentDisclosureError::Signing(e) => write!(f, "Disclosure signing failed: {e}"),
+			PaymentDisclosureError::CommitmentMismatch => {
+				write!(f, "Note opening does not match commitment")
+			}
+			PaymentDisclosureError::InvalidSignature => {
+				write!(f, "Invalid payment disclosure signature")
+			}
+			PaymentDisclosureError::LeafMismatch => {
+				write!(f, "Merkle proof leaf does not match commitment")
+			}
+			PaymentDisclosureError::UnknownRoot => {
+				write!(f, "Merkle root is not the on-chain root")
+			}
+			PaymentDisclosureError::InvalidMerkleProof => write!(f, "Invalid Merkle proof"),
+			PaymentDisclosureError::InvalidEncoding => {
+				write!(f, "Invalid payment disclosure encoding")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PaymentDisclosureError {}
+
+impl From<SignerError> for PaymentDisclosureError {
+	fn from(e: SignerError) -> Self {
+		PaymentDisclosureError::Signing(e)
+	}
+}
+
+/// Signed opening of a shielded payment
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaymentDisclosure {
+	/// On-chain commitment of the paid note
+	pub commitment: Commitment,
+	/// Memo plaintext, including the blinding factor that opens the commitment
+	pub note: Note,
+	/// Public key of the sender
+	pub signer: [u8; 32],
+	/// Sender's signature over [`Self::signing_message`]
+	pub signature: [u8; DISCLOSURE_SIGNATURE_LENGTH],
+}
+
+impl PaymentDisclosure {
+	/// Create a disclosure of `note`, signed by `signer`
+	pub fn create<H: PoseidonHasher, S: DisclosureSigner + ?Sized>(
+		note: Note,
+		hasher: H,
+		signer: &S,
+	) -> Result<Self, PaymentDisclosureError> {
+		let commitment = note.commitment(hasher);
+		let public_key = signer.public_key();
+		let signature = signer.sign(&signing_message(&commitment, &note, &public_key))?;
+
+		Ok(Self {
+			commitment,
+			note,
+			signer: public_key,
+			signature,
+		})
+	}
+
+	/// Message covered by the signature
+	pub fn signing_message(&self) -> Vec<u8> {
+		signing_message(&self.commitment, &self.note, &self.signer)
+	}
+
+	/// Check the note opening and the signature
+	///
+	/// Does not check that the commitment is on-chain; see
+	/// [`verify_payment_disclosure`].
+	pub fn verify<H: PoseidonHasher, V: SignatureVerifier + ?Sized>(
+		&self,
+		hasher: H,
+		verifier: &V,
+	) -> Result<(), PaymentDisclosureError> {
+		if self.note.commitment(hasher) != self.commitment {
+			return Err(PaymentDisclosureError::CommitmentMismatch);
+		}
+		if !verifier.verify(&self.signer, &self.signing_message(), &self.signature) {
+			return Err(PaymentDisclosureError::InvalidSignature);
+		}
+		Ok(())
+	}
+
+	/// Encode in the version 1 layout
+	pub fn to_bytes(&self) -> [u8; PAYMENT_DISCLOSURE_LENGTH] {
+		let mut bytes = [0u8; PAYMENT_DISCLOSURE_LENGTH];
+		bytes[0] = PAYMENT_DISCLOSURE_VERSION;
+		bytes[1..33].copy_from_slice(&self.commitment.inner().to_le_bytes());
+		bytes[33..33 + NOTE_ENCODED_LENGTH].copy_from_slice(&self.note.to_bytes());
+		bytes[33 + NOTE_ENCODED_LENGTH..65 + NOTE_ENCODED_LENGTH].copy_from_slice(&self.signer);
+		bytes[65 + NOTE_ENCODED_LENGTH..].copy_from_slice(&self.signature);
+		bytes
+	}
+
+	/// Decode from the version 1 layout
+	///
+	/// Strict like [`Note::from_bytes`]; the signature is not checked.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, PaymentDisclosureError> {
+		if bytes.len() != PAYMENT_DISCLOSURE_LENGTH || bytes[0] != PAYMENT_DISCLOSURE_VERSION {
+			return Err(PaymentDisclosureError::InvalidEncoding);
+		}
+
+		let mut field = [0u8; 32];
+		field.copy_from_slice(&bytes[1..33]);
+		let commitment = FieldElement::from_canonical_le_bytes(&field)
+			.map(Commitment::from)
+			.ok_or(PaymentDisclosureError::InvalidEncoding)?;
+		let note = Note::from_bytes(&bytes[33..33 + NOTE_ENCODED_LENGTH])
+			.map_err(|_| PaymentDisclosureError::InvalidEncoding)?;
+		let mut signer = [0u8; 32];
+		signer.copy_from_slice(&bytes[33 + NOTE_ENCODED_LENGTH..65 + NOTE_ENCODED_LENGTH]);
+		let mut signature = [0u8; DISCLOSURE_SIGNATURE_LENGTH];
+		signature.copy_from_slice(&bytes[65 + NOTE_ENCODED_LENGTH..]);
+
+		Ok(Self {
+			commitment,
+			note,
+			signer,
+			signature,
+		})
+	}
+}
+
+/// Verify a payment disclosure against on-chain data
+///
+/// Checks the note opening and signature, then that `proof` places the
+/// commitment under `onchain_root` (a root the pool has accepted).
+pub fn verify_payment_disclosure<H: PoseidonHasher + Clone, V: SignatureVerifier + ?Sized>(
+	disclosure: &PaymentDisclosure,
+	proof: &MerkleProofDto,
+	onchain_root: &FieldElement,
+	hasher: H,
+	verifier: &V,
+) -> Result<(), PaymentDisclosureError> {
+	disclosure.verify(hasher.clone(), verifier)?;
+
+	let (leaf, siblings, root) = proof
+		.to_domain()
+		.map_err(|_| PaymentDisclosureError::InvalidMerkleProof)?;
+	if leaf != disclosure.commitment {
+		return Err(PaymentDisclosureError::LeafMismatch);
+	}
+	if root != *onchain_root {
+		return Err(PaymentDisclosureError::UnknownRoot);
+	}
+
+	// Bit `i` of the leaf index is set when the node at level `i` is a right child
+	let path_indices: Vec<bool> = (0..siblings.len())
+		.map(|level| level < 64 && (proof.leaf_index >> level) & 1 == 1)
+		.collect();
+	if !MerkleService::new(hasher).verify_proof(&leaf, &siblings, &path_indices, &root) {
+		return Err(PaymentDisclosureError::InvalidMerkleProof);
+	}
+	Ok(())
+}
+
+fn signing_message(commitment: &Commitment, note: &Note, signer: &[u8; 32]) -> Vec<u8> {
+	let mut message =
+		Vec::with_capacity(PAYMENT_DISCLOSURE_DOMAIN.len() + 32 + NOTE_ENCODED_LENGTH + 32);
+	message.extend_from_slice(PAYMENT_DISCLOSURE_DOMAIN);
+	message.extend_from_slice(&commitment.inner().to_le_bytes());
+	message.extend_from_slice(&note.to_bytes());
+	message.extend_from_slice(signer);
+	message
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::value_objects::{Blinding, OwnerPubkey};
+	use alloc::vec;
+
+	#[derive(Clone)]
+	struct MockHasher;
+
+	impl PoseidonHasher for MockHasher {
+		fn hash_2(&self, inputs: [FieldElement; 2]) -> FieldElement {
+			FieldElement::new(
+				inputs[0].inner() * FieldElement::from_u64(3).inner() + inputs[1].inner(),
+			)
+		}
+
+		fn hash_4(&self, inputs: [FieldElement; 4]) -> FieldElement {
+			FieldElement::new(inputs.iter().map(|x| x.inner()).sum())
+		}
+	}
+
+	/// Mock scheme: the signature is the public key followed by a message checksum
+	struct MockSigner([u8; 32]);
+
+	fn checksum(message: &[u8]) -> [u8; 32] {
+		let mut sum = [0u8; 32];
+		for (i, byte) in message.iter().enumerate() {
+			sum[i % 32] = sum[i % 32].wrapping_add(*byte).rotate_left(1);
+		}
+		sum
+	}
+
+	impl DisclosureSigner for MockSigner {
+		fn public_key(&self) -> [u8; 32] {
+			self.0
+		}
+
+		fn sign(&self, message: &[u8]) -> Result<[u8; DISCLOSURE_SIGNATURE_LENGTH], SignerError> {
+			let mut signature = [0u8; DISCLOSURE_SIGNATURE_LENGTH];
+			signature[..32].copy_from_slice(&self.0);
+			signature[32..].copy_from_slice(&checksum(message));
+			Ok(signature)
+		}
+	}
+
+	struct OfflineSigner;
+
+	impl DisclosureSigner for OfflineSigner {
+		fn public_key(&self) -> [u8; 32] {
+			[0u8; 32]
+		}
+
+		fn sign(&self, _: &[u8]) -> Result<[u8; DISCLOSURE_SIGNATURE_LENGTH], SignerError> {
+			Err(SignerError::SigningUnavailable)
+		}
+	}
+
+	struct MockVerifier;
+
+	impl SignatureVerifier for MockVerifier {
+		fn verify(
+			&self,
+			public_key: &[u8; 32],
+			message: &[u8],
+			signature: &[u8; DISCLOSURE_SIGNATURE_LENGTH],
+		) -> bool {
+			signature[..32] == public_key[..] && signature[32..] == checksum(message)
+		}
+	}
+
+	fn note() -> Note {
+		Note::new(
+			250,
+			0,
+			OwnerPubkey::from_u64(77),
+			Blinding::from_u64(123_456),
+		)
+	}
+
+	fn disclosure() -> PaymentDisclosure {
+		PaymentDisclosure::create(note(), MockHasher, &MockSigner([9u8; 32])).unwrap()
+	}
+
+	/// Depth-2 tree with the disclosed commitment at index 1
+	fn proof() -> (MerkleProofDto, FieldElement) {
+		let leaf = disclosure().commitment;
+		let (s0, s1) = (FieldElement::from_u64(5), FieldElement::from_u64(6));
+		let level1 = MockHasher.hash_2([s0, leaf.inner()]);
+		let root = MockHasher.hash_2([level1, s1]);
+		let dto = MerkleProofDto::new(
+			1,
+			leaf.inner().to_le_bytes(),
+			vec![s0.to_le_bytes(), s1.to_le_bytes()],
+			root.to_le_bytes(),
+		);
+		(dto, root)
+	}
+
+	// ===== Creation and Signature Tests =====
+
+	#[test]
+	fn test_create_and_verify() {
+		let disclosure = disclosure();
+		assert_eq!(disclosure.commitment, note().commitment(MockHasher));
+		assert_eq!(disclosure.signer, [9u8; 32]);
+		assert_eq!(disclosure.verify(MockHasher, &MockVerifier), Ok(()));
+	}
+
+	#[test]
+	fn test_signing_message_binds_domain_and_note() {
+		let message = disclosure().signing_message();
+		assert!(message.starts_with(PAYMENT_DISCLOSURE_DOMAIN));
+		assert_eq!(
+			message.len(),
+			PAYMENT_DISCLOSURE_DOMAIN.len() + 32 + NOTE_ENCODED_LENGTH + 32
+		);
+	}
+
+	#[test]
+	fn test_create_propagates_signer_error() {
+		assert_eq!(
+			PaymentDisclosure::create(note(), MockHasher, &OfflineSigner),
+			Err(PaymentDisclosureError::Signing(
+				SignerError::SigningUnavailable
+			))
+		);
+	}
+
+	#[test]
+	fn test_verify_rejects_wrong_opening() {
+		let mut disclosure = disclosure();
+		disclosure.note = Note::new(
+			251,
+			0,
+			OwnerPubkey::from_u64(77),
+			Blinding::from_u64(123_456),
+		);
+		assert_eq!(
+			disclosure.verify(MockHasher, &MockVerifier),
+			Err(PaymentDisclosureError::CommitmentMismatch)
+		);
+	}
+
+	#[test]
+	fn test_verify_rejects_other_signer() {
+		let mut disclosure = disclosure();
+		disclosure.signer = [8u8; 32];
+		assert_eq!(
+			disclosure.verify(MockHasher, &MockVerifier),
+			Err(PaymentDisclosureError::InvalidSignature)
+		);
+	}
+
+	// ===== On-chain Verification Tests =====
+
+	#[test]
+	fn test_verify_payment_disclosure_against_root() {
+		let (proof, root) = proof();
+		assert_eq!(
+			verify_payment_disclosure(&disclosure(), &proof, &root, MockHasher, &MockVerifier),
+			Ok(())
+		);
+	}
+
+	#[test]
+	fn test_verify_payment_disclosure_rejects_unknown_root() {
+		let (proof, _) = proof();
+		assert_eq!(
+			verify_payment_disclosure(
+				&disclosure(),
+				&proof,
+				&FieldElement::from_u64(1),
+				MockHasher,
+				&MockVerifier
+			),
+			Err(PaymentDisclosureError::UnknownRoot)
+		);
+	}
+
+	#[test]
+	fn test_verify_payment_disclosure_rejects_wrong_position() {
+		let (mut proof, root) = proof();
+		proof.leaf_index = 0;
+		assert_eq!(
+			verify_payment_disclosure(&disclosure(), &proof, &root, MockHasher, &MockVerifier),
+			Err(PaymentDisclosureError::InvalidMerkleProof)
+		);
+	}
+
+	#[test]
+	fn test_verify_payment_disclosure_rejects_other_leaf() {
+		let (mut proof, root) = proof();
+		proof.leaf = FieldElement::from_u64(4).to_le_bytes();
+		assert_eq!(
+			verify_payment_disclosure(&disclosure(), &proof, &root, MockHasher, &MockVerifier),
+			Err(PaymentDisclosureError::LeafMismatch)
+		);
+	}
+
+	// ===== Encoding Tests =====
+
+	#[test]
+	fn test_encoding_roundtrip() {
+		let bytes = disclosure().to_bytes();
+		assert_eq!(bytes.len(), 210);
+		assert_eq!(bytes[0], PAYMENT_DISCLOSURE_VERSION);
+		assert_eq!(PaymentDisclosure::from_bytes(&bytes), Ok(disclosure()));
+	}
+
+	#[test]
+	fn test_decoding_rejects_malformed_input() {
+		let bytes = disclosure().to_bytes();
+		assert_eq!(
+			PaymentDisclosure::from_bytes(&bytes[1..]),
+			Err(PaymentDisclosureError::InvalidEncoding)
+		);
+
+		let mut wrong_version = bytes;
+		wrong_version[0] = 2;
+		assert_eq!(
+			PaymentDisclosure::from_bytes(&wrong_version),
+			Err(PaymentDisclosureError::InvalidEncoding)
+		);
+
+		let mut non_canonical = bytes;
+		non_canonical[1..33].copy_from_slice(&[0xFF; 32]);
+		assert_eq!(
+			PaymentDisclosure::from_bytes(&non_canonical),
+			Err(PaymentDisclosureError::InvalidEncoding)
+		);
+	}
+}
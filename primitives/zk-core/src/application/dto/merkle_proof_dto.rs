@@ -11,6 +11,10 @@ use crate::domain::{
 };
 use alloc::{string::String, vec::Vec};
 use ark_bn254::Fr;
+#[cfg(feature = "substrate")]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "substrate")]
+use scale_info::TypeInfo;
 
 /// Data Transfer Object for Merkle Proof
 ///
@@ -23,6 +27,7 @@ use ark_bn254::Fr;
 /// - `siblings`: Sibling hashes along the path to root
 /// - `root`: The expected Merkle root
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "substrate", derive(Encode, Decode, TypeInfo))]
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct MerkleProofDto {
 	/// Index of the leaf in the tree
@@ -622,4 +627,16 @@ mod tests {
 
 		assert_eq!(dto, deserialized);
 	}
+
+	// ===== Codec Tests =====
+
+	#[cfg(feature = "substrate")]
+	#[test]
+	fn test_scale_roundtrip() {
+		use parity_scale_codec::{Decode, Encode};
+
+		let dto = MerkleProofDto::new(3, [1u8; 32], vec![[2u8; 32], [3u8; 32]], [4u8; 32]);
+		let encoded = dto.encode();
+		assert_eq!(MerkleProofDto::decode(&mut &encoded[..]).unwrap(), dto);
+	}
 }
@@ -1,2 +1,3 @@
+pub mod disclosure;
 pub mod dto;
 pub mod use_cases;
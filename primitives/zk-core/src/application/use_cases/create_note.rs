@@ -5,6 +5,7 @@
 
 use crate::domain::{
 	entities::Note,
+	ports::{BlindingSource, BlindingSourceError},
 	value_objects::{Blinding, OwnerPubkey},
 };
 use alloc::string::String;
@@ -39,6 +40,8 @@ pub enum CreateNoteError {
 	InvalidValue(String),
 	/// Invalid asset ID
 	InvalidAssetId(String),
+	/// Blinding source failed
+	BlindingUnavailable(BlindingSourceError),
 }
 
 impl core::fmt::Display for CreateNoteError {
@@ -46,6 +49,7 @@ impl core::fmt::Display for CreateNoteError {
 		match self {
 			CreateNoteError::InvalidValue(msg) => write!(f, "Invalid note value: {msg}"),
 			CreateNoteError::InvalidAssetId(msg) => write!(f, "Invalid asset ID: {msg}"),
+			CreateNoteError::BlindingUnavailable(err) => write!(f, "Blinding unavailable: {err}"),
 		}
 	}
 }
@@ -106,6 +110,28 @@ impl CreateNoteUseCase {
 		Ok(CreateNoteOutput { note })
 	}
 
+	/// Execute the use case with a blinding drawn from `source`
+	///
+	/// # Returns
+	/// - `Ok(CreateNoteOutput)`: The created note
+	/// - `Err(CreateNoteError)`: If validation fails or the source has no entropy
+	pub fn execute_with_blinding_source<S: BlindingSource + ?Sized>(
+		&self,
+		value: u64,
+		asset_id: u64,
+		owner_pubkey: OwnerPubkey,
+		source: &mut S,
+	) -> CreateNoteResult {
+		let blinding = Blinding::random(source).map_err(CreateNoteError::BlindingUnavailable)?;
+
+		self.execute(CreateNoteInput {
+			value,
+			asset_id,
+			owner_pubkey,
+			blinding,
+		})
+	}
+
 	/// Validate input at application level
 	///
 	/// This performs additional checks beyond domain invariants.
@@ -308,6 +334,52 @@ mod tests {
 		assert_eq!(output.note.blinding(), blinding);
 	}
 
+	// ===== Blinding Source Tests =====
+
+	struct EmptySource;
+
+	impl BlindingSource for EmptySource {
+		fn next_blinding(&mut self) -> Result<Blinding, BlindingSourceError> {
+			Err(BlindingSourceError::EntropyUnavailable)
+		}
+
+		fn provenance(&self) -> crate::domain::ports::RandomnessProvenance {
+			crate::domain::ports::RandomnessProvenance::Hardware
+		}
+	}
+
+	#[test]
+	fn test_execute_with_blinding_source() {
+		use crate::infrastructure::random::SeededBlindingSource;
+
+		let owner_pubkey = OwnerPubkey::from(Fr::from(123u64));
+		let mut source = SeededBlindingSource::new(9);
+		let expected = SeededBlindingSource::new(9).next_blinding().unwrap();
+
+		let output = CreateNoteUseCase::new()
+			.execute_with_blinding_source(100, 0, owner_pubkey, &mut source)
+			.unwrap();
+
+		assert_eq!(output.note.blinding(), expected);
+		assert_eq!(output.note.value(), 100);
+	}
+
+	#[test]
+	fn test_execute_with_failing_blinding_source() {
+		let owner_pubkey = OwnerPubkey::from(Fr::from(123u64));
+		let result = CreateNoteUseCase::new().execute_with_blinding_source(
+			100,
+			0,
+			owner_pubkey,
+			&mut EmptySource,
+		);
+
+		assert_eq!(
+			result.unwrap_err(),
+			CreateNoteError::BlindingUnavailable(BlindingSourceError::EntropyUnavailable)
+		);
+	}
+
 	// ===== Validation Tests =====
 
 	#[test]
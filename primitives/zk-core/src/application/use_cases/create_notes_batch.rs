@@ -0,0 +1,345 @@
+//! Create Notes Batch Use Case
+//!
+//! Creates the output notes of a transaction (or a `shield_batch` call) in one
+//! pass: blindings are drawn from a [`BlindingSource`] in input order, and each
+//! note is returned with its commitment and memo plaintext.
+
+use super::create_note::{CreateNoteError, CreateNoteUseCase};
+use crate::domain::{
+	entities::{note_encoding::NOTE_ENCODED_LENGTH, Note},
+	ports::{BlindingSource, PoseidonHasher},
+	value_objects::{Commitment, OwnerPubkey},
+};
+use alloc::vec::Vec;
+
+/// One requested output note
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchNoteInput {
+	/// Value of the note (amount)
+	pub value: u64,
+	/// Asset ID (0 for native token)
+	pub asset_id: u64,
+	/// Recipient's public key
+	pub owner_pubkey: OwnerPubkey,
+}
+
+/// One created output note
+#[derive(Debug, Clone)]
+pub struct BatchNote {
+	/// The created note entity
+	pub note: Note,
+	/// Commitment of the note
+	pub commitment: Commitment,
+	/// Canonical note encoding, to be encrypted into the memo
+	pub memo_plaintext: [u8; NOTE_ENCODED_LENGTH],
+}
+
+/// Output data after creating a batch of notes
+#[derive(Debug, Clone)]
+pub struct CreateNotesBatchOutput {
+	/// Created notes, in input order
+	pub notes: Vec<BatchNote>,
+}
+
+impl CreateNotesBatchOutput {
+	/// Commitments in input order
+	pub fn commitments(&self) -> Vec<Commitment> {
+		self.notes.iter().map(|output| output.commitment).collect()
+	}
+}
+
+/// Result type for the use case
+pub type CreateNotesBatchResult = Result<CreateNotesBatchOutput, CreateNotesBatchError>;
+
+/// Errors that can occur when creating a batch of notes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CreateNotesBatchError {
+	/// No notes requested
+	EmptyBatch,
+	/// Creating the note at `index` failed
+	Note {
+		/// Position of the failing input
+		index: usize,
+		/// Underlying error
+		error: CreateNoteError,
+	},
+}
+
+impl core::fmt::Display for CreateNotesBatchError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			CreateNotesBatchError::EmptyBatch => write!(f, "Empty note batch"),
+			CreateNotesBatchError::Note { index, error } => {
+				write!(f, "Note {index} in batch: {error}")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CreateNotesBatchError {}
+
+/// Use case for creating several notes at once
+///
+/// ## Application Logic Flow
+/// 1. Reject an empty batch
+/// 2. Create each note via [`CreateNoteUseCase`] with a fresh blinding
+/// 3. Compute its commitment and memo plaintext
+///
+/// Either every note is created or an error names the first failing input.
+///
+/// ## Example
+/// ```ignore
+/// let inputs = [
+///     BatchNoteInput { value: 60, asset_id: 0, owner_pubkey: recipient },
+///     BatchNoteInput { value: 40, asset_id: 0, owner_pubkey: change },
+/// ];
+///
+/// let output = CreateNotesBatchUseCase::new().execute(&inputs, hasher, &mut OsBlindingSource)?;
+/// let commitments = output.commitments();
+/// ```
+pub struct CreateNotesBatchUseCase;
+
+impl CreateNotesBatchUseCase {
+	/// Create a new instance of the use case
+	pub fn new() -> Self {
+		Self
+	}
+
+	/// Execute the use case
+	///
+	/// # Arguments
+	/// - `inputs`: Requested notes
+	/// - `hasher`: Implementation of PoseidonHasher port
+	/// - `source`: Blinding source, drawn once per note in input order
+	///
+	/// # Returns
+	/// - `Ok(CreateNotesBatchOutput)`: The created notes
+	/// - `Err(CreateNotesBatchError)`: If the batch is empty or a note fails
+	pub fn execute<H, S>(
+		&self,
+		inputs: &[BatchNoteInput],
+		hasher: H,
+		source: &mut S,
+	) -> CreateNotesBatchResult
+	where
+		H: PoseidonHasher + Clone,
+		S: BlindingSource + ?Sized,
+	{
+		if inputs.is_empty() {
+			return Err(CreateNotesBatchError::EmptyBatch);
+		}
+
+		let create_note = CreateNoteUseCase::new();
+		let notes = inputs
+			.iter()
+			.enumerate()
+			.map(|(index, input)| {
+				let note = create_note
+					.execute_with_blinding_source(
+						input.value,
+						input.asset_id,
+						input.owner_pubkey,
+						source,
+					)
+					.map_err(|error| CreateNotesBatchError::Note { index, error })?
+					.note;
+
+				Ok(BatchNote {
+					commitment: note.commitment(hasher.clone()),
+					memo_plaintext: note.to_bytes(),
+					note,
+				})
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(CreateNotesBatchOutput { notes })
+	}
+}
+
+impl Default for CreateNotesBatchUseCase {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::{
+		ports::{BlindingSourceError, RandomnessProvenance},
+		value_objects::Blinding,
+	};
+	use crate::infrastructure::{crypto::LightPoseidonHasher, random::SeededBlindingSource};
+	use alloc::{format, vec};
+
+	fn inputs() -> Vec<BatchNoteInput> {
+		vec![
+			BatchNoteInput {
+				value: 60,
+				asset_id: 0,
+				owner_pubkey: OwnerPubkey::from_u64(1),
+			},
+			BatchNoteInput {
+				value: 40,
+				asset_id: 3,
+				owner_pubkey: OwnerPubkey::from_u64(2),
+			},
+			BatchNoteInput {
+				value: 0,
+				asset_id: 0,
+				owner_pubkey: OwnerPubkey::from_u64(3),
+			},
+		]
+	}
+
+	/// Fails after `remaining` blindings
+	struct ExhaustibleSource {
+		inner: SeededBlindingSource,
+		remaining: usize,
+	}
+
+	impl BlindingSource for ExhaustibleSource {
+		fn next_blinding(&mut self) -> Result<Blinding, BlindingSourceError> {
+			if self.remaining == 0 {
+				return Err(BlindingSourceError::EntropyUnavailable);
+			}
+			self.remaining -= 1;
+			self.inner.next_blinding()
+		}
+
+		fn provenance(&self) -> RandomnessProvenance {
+			self.inner.provenance()
+		}
+	}
+
+	// ===== Execute Success Tests =====
+
+	#[test]
+	fn test_execute_matches_single_note_creation() {
+		let inputs = inputs();
+		let output = CreateNotesBatchUseCase::new()
+			.execute(
+				&inputs,
+				LightPoseidonHasher,
+				&mut SeededBlindingSource::new(5),
+			)
+			.unwrap();
+
+		let mut source = SeededBlindingSource::new(5);
+		assert_eq!(output.notes.len(), inputs.len());
+		for (created, input) in output.notes.iter().zip(&inputs) {
+			let expected = CreateNoteUseCase::new()
+				.execute_with_blinding_source(
+					input.value,
+					input.asset_id,
+					input.owner_pubkey,
+					&mut source,
+				)
+				.unwrap()
+				.note;
+
+			assert_eq!(created.note, expected);
+			assert_eq!(created.commitment, expected.commitment(LightPoseidonHasher));
+			assert_eq!(created.memo_plaintext, expected.to_bytes());
+		}
+	}
+
+	#[test]
+	fn test_memo_plaintext_decodes_to_note() {
+		let output = CreateNotesBatchUseCase::new()
+			.execute(
+				&inputs(),
+				LightPoseidonHasher,
+				&mut SeededBlindingSource::new(5),
+			)
+			.unwrap();
+
+		for created in &output.notes {
+			assert_eq!(
+				Note::from_bytes(&created.memo_plaintext),
+				Ok(created.note.clone())
+			);
+		}
+	}
+
+	#[test]
+	fn test_blindings_are_distinct() {
+		let duplicated = vec![inputs()[0]; 2];
+		let output = CreateNotesBatchUseCase::new()
+			.execute(
+				&duplicated,
+				LightPoseidonHasher,
+				&mut SeededBlindingSource::new(5),
+			)
+			.unwrap();
+
+		assert_ne!(
+			output.notes[0].note.blinding(),
+			output.notes[1].note.blinding()
+		);
+		assert_ne!(output.notes[0].commitment, output.notes[1].commitment);
+	}
+
+	#[test]
+	fn test_commitments_in_input_order() {
+		let output = CreateNotesBatchUseCase::new()
+			.execute(
+				&inputs(),
+				LightPoseidonHasher,
+				&mut SeededBlindingSource::new(5),
+			)
+			.unwrap();
+
+		let commitments = output.commitments();
+		assert_eq!(commitments.len(), 3);
+		assert_eq!(commitments[1], output.notes[1].commitment);
+	}
+
+	// ===== Execute Error Tests =====
+
+	#[test]
+	fn test_empty_batch_rejected() {
+		let result = CreateNotesBatchUseCase::new().execute(
+			&[],
+			LightPoseidonHasher,
+			&mut SeededBlindingSource::new(5),
+		);
+		assert_eq!(result.unwrap_err(), CreateNotesBatchError::EmptyBatch);
+	}
+
+	#[test]
+	fn test_blinding_failure_reports_index() {
+		let mut source = ExhaustibleSource {
+			inner: SeededBlindingSource::new(5),
+			remaining: 2,
+		};
+		let result =
+			CreateNotesBatchUseCase::new().execute(&inputs(), LightPoseidonHasher, &mut source);
+
+		assert_eq!(
+			result.unwrap_err(),
+			CreateNotesBatchError::Note {
+				index: 2,
+				error: CreateNoteError::BlindingUnavailable(
+					BlindingSourceError::EntropyUnavailable
+				),
+			}
+		);
+	}
+
+	// ===== Error Tests =====
+
+	#[test]
+	fn test_error_display() {
+		assert_eq!(
+			format!("{}", CreateNotesBatchError::EmptyBatch),
+			"Empty note batch"
+		);
+		let err = CreateNotesBatchError::Note {
+			index: 1,
+			error: CreateNoteError::BlindingUnavailable(BlindingSourceError::EntropyUnavailable),
+		};
+		assert!(format!("{err}").starts_with("Note 1 in batch: Blinding unavailable"));
+	}
+}
@@ -1,4 +1,5 @@
 pub mod compute_commitment;
 pub mod compute_nullifier;
 pub mod create_note;
+pub mod create_notes_batch;
 pub mod verify_merkle_proof;
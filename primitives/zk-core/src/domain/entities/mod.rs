@@ -1,3 +1,7 @@
 pub mod note;
+pub mod note_encoding;
+pub mod reserves_witness;
 
 pub use note::Note;
+pub use note_encoding::NoteEncodingError;
+pub use reserves_witness::{ReserveNote, ReservesError, ReservesWitness};
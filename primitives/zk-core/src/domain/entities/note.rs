@@ -4,9 +4,12 @@
 //! asset information, and cryptographic commitment data.
 
 use crate::domain::{
-	ports::PoseidonHasher,
+	ports::{BlindingSource, BlindingSourceError, PoseidonHasher},
 	services::{CommitmentService, NullifierService},
-	value_objects::{Blinding, Commitment, FieldElement, Nullifier, OwnerPubkey, SpendingKey},
+	value_objects::{
+		Blinding, Commitment, DiversifiedAddress, FieldElement, Nullifier, NullifierVersion,
+		OwnerPubkey, SpendingKey,
+	},
 };
 
 /// Private note in the shielded pool
@@ -44,6 +47,34 @@ impl Note {
 		}
 	}
 
+	/// Create a note with a fresh blinding factor from `source`
+	pub fn with_blinding_source<S: BlindingSource + ?Sized>(
+		value: u64,
+		asset_id: u64,
+		owner_pubkey: OwnerPubkey,
+		source: &mut S,
+	) -> Result<Self, BlindingSourceError> {
+		Ok(Self::new(
+			value,
+			asset_id,
+			owner_pubkey,
+			Blinding::random(source)?,
+		))
+	}
+
+	/// Create a note paying a diversified address
+	///
+	/// The commitment binds the address's diversified `owner_pubkey`, so notes
+	/// sent to different addresses of one account cannot be linked on-chain.
+	pub fn for_address(
+		value: u64,
+		asset_id: u64,
+		address: &DiversifiedAddress,
+		blinding: Blinding,
+	) -> Self {
+		Self::new(value, asset_id, address.owner_pubkey, blinding)
+	}
+
 	/// Create a zero note (for padding in circuits)
 	///
 	/// Zero notes are used in ZK circuits to pad input/output arrays
@@ -130,6 +161,18 @@ impl Note {
 		service.compute_nullifier(&commitment, spending_key)
 	}
 
+	/// Compute the nullifier under a specific derivation version
+	pub fn nullifier_versioned<H: PoseidonHasher + Clone>(
+		&self,
+		hasher: H,
+		spending_key: &SpendingKey,
+		version: NullifierVersion,
+	) -> Nullifier {
+		let commitment = self.commitment(hasher.clone());
+		let service = NullifierService::new(hasher);
+		service.compute_nullifier_versioned(&commitment, spending_key, version)
+	}
+
 	/// Check if this note can be spent by the given spending key
 	///
 	/// # Domain Rule
@@ -177,6 +220,31 @@ mod tests {
 		assert_eq!(note.blinding(), blinding);
 	}
 
+	#[test]
+	fn test_with_blinding_source() {
+		use crate::infrastructure::random::SeededBlindingSource;
+
+		let pubkey = OwnerPubkey::from(Fr::from(123u64));
+		let a =
+			Note::with_blinding_source(100, 0, pubkey, &mut SeededBlindingSource::new(1)).unwrap();
+		let b =
+			Note::with_blinding_source(100, 0, pubkey, &mut SeededBlindingSource::new(1)).unwrap();
+		let c =
+			Note::with_blinding_source(100, 0, pubkey, &mut SeededBlindingSource::new(2)).unwrap();
+
+		assert_eq!(a, b);
+		assert_ne!(a.blinding(), c.blinding());
+	}
+
+	#[test]
+	fn test_for_address() {
+		let pubkey = OwnerPubkey::from(Fr::from(123u64));
+		let address = DiversifiedAddress::new(5, FieldElement::from_u64(9), pubkey);
+		let blinding = Blinding::from(Fr::from(456u64));
+		let note = Note::for_address(100, 2, &address, blinding);
+		assert_eq!(note, Note::new(100, 2, pubkey, blinding));
+	}
+
 	#[test]
 	fn test_new_zero_value() {
 		let pubkey = OwnerPubkey::from(Fr::from(123u64));
@@ -416,6 +484,21 @@ mod tests {
 		assert_eq!(nullifier1, nullifier2);
 	}
 
+	#[test]
+	fn test_nullifier_versioned() {
+		let note = Note::zero();
+		let spending_key = SpendingKey::from(Fr::from(123u64));
+		assert_eq!(
+			note.nullifier_versioned(MockHasher, &spending_key, NullifierVersion::V1),
+			note.nullifier(MockHasher, &spending_key)
+		);
+		// V2 goes through hash_4 (MockHasher returns 100)
+		assert_eq!(
+			note.nullifier_versioned(MockHasher, &spending_key, NullifierVersion::V2),
+			Nullifier::from(Fr::from(100u64))
+		);
+	}
+
 	// ===== Can Spend Tests =====
 
 	#[test]
@@ -0,0 +1,251 @@
+//! Canonical Note Encoding
+//!
+//! Single versioned byte layout for [`Note`], shared by wallets, memos and test
+//! fixtures:
+//!
+//! ```text
+//! version (1) || value (8, LE) || asset_id (8, LE) || owner_pubkey (32, LE) || blinding (32, LE)
+//! ```
+//!
+//! Decoding is strict: unknown versions, wrong lengths and non-canonical field
+//! elements are rejected, so every note has exactly one encoding. The SCALE and
+//! serde adapters use the same bytes.
+
+use super::note::Note;
+use crate::domain::value_objects::{Blinding, FieldElement, OwnerPubkey};
+
+/// Current note encoding version
+pub const NOTE_ENCODING_VERSION: u8 = 1;
+
+/// Encoded note length (version 1)
+pub const NOTE_ENCODED_LENGTH: usize = 81;
+
+/// Errors that can occur when decoding a note
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteEncodingError {
+	/// Version byte is not supported
+	UnsupportedVersion(u8),
+	/// Input length does not match the version's layout
+	InvalidLength(usize),
+	/// Field element is not canonically encoded
+	NonCanonicalField,
+}
+
+impl core::fmt::Display for NoteEncodingError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			NoteEncodingError::UnsupportedVersion(version) => {
+				write!(f, "Unsupported note encoding version: {version}")
+			}
+			NoteEncodingError::InvalidLength(len) => {
+				write!(f, "Invalid note encoding length: {len}")
+			}
+			NoteEncodingError::NonCanonicalField => {
+				write!(f, "Non-canonical field element in note encoding")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoteEncodingError {}
+
+impl Note {
+	/// Encodes the note in the canonical layout
+	pub fn to_bytes(&self) -> [u8; NOTE_ENCODED_LENGTH] {
+		let mut bytes = [0u8; NOTE_ENCODED_LENGTH];
+		bytes[0] = NOTE_ENCODING_VERSION;
+		bytes[1..9].copy_from_slice(&self.value().to_le_bytes());
+		bytes[9..17].copy_from_slice(&self.asset_id().to_le_bytes());
+		bytes[17..49].copy_from_slice(&self.owner_pubkey().inner().to_le_bytes());
+		bytes[49..81].copy_from_slice(&self.blinding().inner().to_le_bytes());
+		bytes
+	}
+
+	/// Decodes a note from the canonical layout
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, NoteEncodingError> {
+		match bytes.first() {
+			Some(&NOTE_ENCODING_VERSION) => {}
+			Some(&version) => return Err(NoteEncodingError::UnsupportedVersion(version)),
+			None => return Err(NoteEncodingError::InvalidLength(0)),
+		}
+		if bytes.len() != NOTE_ENCODED_LENGTH {
+			return Err(NoteEncodingError::InvalidLength(bytes.len()));
+		}
+
+		let mut value = [0u8; 8];
+		value.copy_from_slice(&bytes[1..9]);
+		let mut asset_id = [0u8; 8];
+		asset_id.copy_from_slice(&bytes[9..17]);
+
+		Ok(Note::new(
+			u64::from_le_bytes(value),
+			u64::from_le_bytes(asset_id),
+			OwnerPubkey::new(canonical_field(&bytes[17..49])?),
+			Blinding::new(canonical_field(&bytes[49..81])?),
+		))
+	}
+}
+
+fn canonical_field(bytes: &[u8]) -> Result<FieldElement, NoteEncodingError> {
+	bytes
+		.try_into()
+		.ok()
+		.and_then(FieldElement::from_canonical_le_bytes)
+		.ok_or(NoteEncodingError::NonCanonicalField)
+}
+
+// ============================================================================
+// SCALE / serde adapters
+// ============================================================================
+
+#[cfg(feature = "substrate")]
+impl parity_scale_codec::Encode for Note {
+	fn size_hint(&self) -> usize {
+		NOTE_ENCODED_LENGTH
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		dest.write(&self.to_bytes());
+	}
+}
+
+#[cfg(feature = "substrate")]
+impl parity_scale_codec::Decode for Note {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let mut bytes = [0u8; NOTE_ENCODED_LENGTH];
+		input.read(&mut bytes)?;
+		Note::from_bytes(&bytes).map_err(|_| "Invalid canonical note encoding".into())
+	}
+}
+
+#[cfg(feature = "substrate")]
+impl parity_scale_codec::MaxEncodedLen for Note {
+	fn max_encoded_len() -> usize {
+		NOTE_ENCODED_LENGTH
+	}
+}
+
+#[cfg(feature = "substrate")]
+impl scale_info::TypeInfo for Note {
+	type Identity = [u8; NOTE_ENCODED_LENGTH];
+
+	fn type_info() -> scale_info::Type {
+		<[u8; NOTE_ENCODED_LENGTH] as scale_info::TypeInfo>::type_info()
+	}
+}
+
+#[cfg(feature = "std")]
+impl serde::Serialize for Note {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serde::Serialize::serialize(&self.to_bytes()[..], serializer)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'de> serde::Deserialize<'de> for Note {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let bytes: alloc::vec::Vec<u8> = serde::Deserialize::deserialize(deserializer)?;
+		Note::from_bytes(&bytes).map_err(serde::de::Error::custom)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sample_note() -> Note {
+		Note::new(
+			1_000,
+			7,
+			OwnerPubkey::from(FieldElement::from_u64(123)),
+			Blinding::from(FieldElement::from_u64(456)),
+		)
+	}
+
+	// ===== Encoding Tests =====
+
+	#[test]
+	fn test_bytes_roundtrip() {
+		let note = sample_note();
+		let bytes = note.to_bytes();
+		assert_eq!(bytes[0], NOTE_ENCODING_VERSION);
+		assert_eq!(Note::from_bytes(&bytes).unwrap(), note);
+	}
+
+	#[test]
+	fn test_layout() {
+		let bytes = sample_note().to_bytes();
+		assert_eq!(&bytes[1..9], &1_000u64.to_le_bytes());
+		assert_eq!(&bytes[9..17], &7u64.to_le_bytes());
+		assert_eq!(bytes[17], 123);
+		assert_eq!(bytes[49], 200);
+		assert_eq!(bytes[50], 1);
+	}
+
+	#[test]
+	fn test_zero_note_roundtrip() {
+		assert_eq!(
+			Note::from_bytes(&Note::zero().to_bytes()).unwrap(),
+			Note::zero()
+		);
+	}
+
+	// ===== Decoding Error Tests =====
+
+	#[test]
+	fn test_unsupported_version() {
+		let mut bytes = sample_note().to_bytes();
+		bytes[0] = 2;
+		assert_eq!(
+			Note::from_bytes(&bytes),
+			Err(NoteEncodingError::UnsupportedVersion(2))
+		);
+	}
+
+	#[test]
+	fn test_invalid_length() {
+		let bytes = sample_note().to_bytes();
+		assert_eq!(
+			Note::from_bytes(&bytes[..80]),
+			Err(NoteEncodingError::InvalidLength(80))
+		);
+		assert_eq!(
+			Note::from_bytes(&[]),
+			Err(NoteEncodingError::InvalidLength(0))
+		);
+	}
+
+	#[test]
+	fn test_non_canonical_field() {
+		let mut bytes = sample_note().to_bytes();
+		bytes[49..81].copy_from_slice(&[0xff; 32]);
+		assert_eq!(
+			Note::from_bytes(&bytes),
+			Err(NoteEncodingError::NonCanonicalField)
+		);
+	}
+
+	// ===== Adapter Tests =====
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_serde_roundtrip() {
+		let note = sample_note();
+		let json = serde_json::to_string(&note).unwrap();
+		assert_eq!(serde_json::from_str::<Note>(&json).unwrap(), note);
+	}
+
+	#[cfg(feature = "substrate")]
+	#[test]
+	fn test_scale_roundtrip() {
+		use parity_scale_codec::{Decode, Encode};
+
+		let note = sample_note();
+		let encoded = note.encode();
+		assert_eq!(encoded[..], note.to_bytes()[..]);
+		assert_eq!(Note::decode(&mut &encoded[..]).unwrap(), note);
+	}
+}
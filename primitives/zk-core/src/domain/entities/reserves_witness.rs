@@ -0,0 +1,91 @@
+//! Reserves Witness Entity
+//!
+//! Everything a "proof of reserves" circuit needs to show that a set of
+//! shielded notes owned by one viewing key adds up to a committed balance.
+//! Auditors holding the viewing key can re-open the balance commitment.
+
+use crate::domain::{
+	entities::Note,
+	value_objects::{Commitment, DiversifiedAddress, FieldElement},
+};
+use alloc::vec::Vec;
+
+/// Errors that can occur while building a reserves witness
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReservesError {
+	/// No notes were supplied
+	EmptyNoteSet,
+	/// Note at `index` has a different asset than the first note
+	MixedAssets { index: usize },
+	/// Note at `index` is not addressed to the viewing key at its diversifier index
+	NotOwned { index: usize },
+	/// Note at `index` repeats an earlier commitment
+	DuplicateNote { index: usize },
+}
+
+impl core::fmt::Display for ReservesError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			ReservesError::EmptyNoteSet => write!(f, "Reserves note set is empty"),
+			ReservesError::MixedAssets { index } => {
+				write!(f, "Note {index} has a different asset id")
+			}
+			ReservesError::NotOwned { index } => {
+				write!(f, "Note {index} is not owned by the viewing key")
+			}
+			ReservesError::DuplicateNote { index } => {
+				write!(f, "Note {index} is a duplicate")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReservesError {}
+
+/// A note counted towards reserves, with its ownership data
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReserveNote {
+	/// The note itself (private circuit input)
+	pub note: Note,
+	/// Address the note was received on (proves ownership under the viewing key)
+	pub address: DiversifiedAddress,
+	/// Note commitment (Merkle leaf)
+	pub commitment: Commitment,
+}
+
+/// Private and public inputs of a proof-of-reserves circuit
+///
+/// The circuit shows every note commitment is in the tree, every note is owned
+/// by the viewing key and `balance_commitment` commits to the sum of their values.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReservesWitness {
+	/// Asset shared by every note
+	pub asset_id: u64,
+	/// Sum of note values
+	pub total: u128,
+	/// Audit epoch the blinding is bound to
+	pub epoch: u64,
+	/// Blinding of the balance commitment (re-derivable from the viewing key)
+	pub balance_blinding: FieldElement,
+	/// `Poseidon(total, asset_id, owner_base, balance_blinding)`
+	pub balance_commitment: Commitment,
+	/// Notes counted towards `total`
+	pub notes: Vec<ReserveNote>,
+}
+
+impl ReservesWitness {
+	/// Public circuit inputs: `[balance_commitment, asset_id, epoch]`
+	pub fn public_inputs(&self) -> [FieldElement; 3] {
+		[
+			self.balance_commitment.inner(),
+			FieldElement::from_u64(self.asset_id),
+			FieldElement::from_u64(self.epoch),
+		]
+	}
+
+	/// Commitments of the counted notes, in input order
+	pub fn note_commitments(&self) -> Vec<Commitment> {
+		self.notes.iter().map(|entry| entry.commitment).collect()
+	}
+}
@@ -0,0 +1,76 @@
+//! Blinding Source Port
+//!
+//! Trait for the randomness behind note blinding factors, so wallets choose
+//! (and can report to auditors) where that randomness comes from.
+
+use crate::domain::value_objects::{Blinding, FieldElement};
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+/// Bytes sampled per blinding factor (reduced mod r, bias below 2^-250)
+pub const BLINDING_ENTROPY_BYTES: usize = 64;
+
+/// Where a blinding source gets its randomness
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RandomnessProvenance {
+	/// Operating system CSPRNG
+	OsRandom,
+	/// Deterministic stream from a seed (tests and fixtures only)
+	Seeded,
+	/// Hardware device (secure element, HSM, CPU RNG)
+	Hardware,
+}
+
+/// Errors that can occur when drawing a blinding factor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlindingSourceError {
+	/// Randomness source is unavailable or failed
+	EntropyUnavailable,
+}
+
+impl core::fmt::Display for BlindingSourceError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			BlindingSourceError::EntropyUnavailable => write!(f, "Entropy source unavailable"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlindingSourceError {}
+
+/// Port for blinding factor generation
+pub trait BlindingSource {
+	/// Draw a fresh blinding factor
+	fn next_blinding(&mut self) -> Result<Blinding, BlindingSourceError>;
+
+	/// Where this source's randomness comes from
+	fn provenance(&self) -> RandomnessProvenance;
+}
+
+/// Reduces uniformly random bytes to a blinding factor
+pub fn blinding_from_entropy(entropy: &[u8; BLINDING_ENTROPY_BYTES]) -> Blinding {
+	Blinding::new(FieldElement::new(Fr::from_le_bytes_mod_order(entropy)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_blinding_from_entropy_deterministic() {
+		let entropy = [7u8; BLINDING_ENTROPY_BYTES];
+		assert_eq!(
+			blinding_from_entropy(&entropy),
+			blinding_from_entropy(&entropy)
+		);
+	}
+
+	#[test]
+	fn test_blinding_from_entropy_uses_all_bytes() {
+		let low = [0u8; BLINDING_ENTROPY_BYTES];
+		let mut high = low;
+		high[BLINDING_ENTROPY_BYTES - 1] = 1;
+		assert_ne!(blinding_from_entropy(&low), blinding_from_entropy(&high));
+	}
+}
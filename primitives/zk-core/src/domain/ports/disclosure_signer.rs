@@ -0,0 +1,46 @@
+//! Disclosure Signer Port
+//!
+//! Traits for signing and verifying payment disclosures with a key the
+//! recipient of the disclosure already associates with the sender (e.g. an
+//! account key), independent of the signature scheme.
+
+/// Signature length in bytes (ed25519 / sr25519)
+pub const DISCLOSURE_SIGNATURE_LENGTH: usize = 64;
+
+/// Errors that can occur when signing a disclosure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignerError {
+	/// Signing key is unavailable or the device refused to sign
+	SigningUnavailable,
+}
+
+impl core::fmt::Display for SignerError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			SignerError::SigningUnavailable => write!(f, "Signing key unavailable"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SignerError {}
+
+/// Port for signing disclosure messages
+pub trait DisclosureSigner {
+	/// Public key identifying the signer
+	fn public_key(&self) -> [u8; 32];
+
+	/// Sign `message`
+	fn sign(&self, message: &[u8]) -> Result<[u8; DISCLOSURE_SIGNATURE_LENGTH], SignerError>;
+}
+
+/// Port for verifying disclosure signatures
+pub trait SignatureVerifier {
+	/// Check `signature` over `message` under `public_key`
+	fn verify(
+		&self,
+		public_key: &[u8; 32],
+		message: &[u8],
+		signature: &[u8; DISCLOSURE_SIGNATURE_LENGTH],
+	) -> bool;
+}
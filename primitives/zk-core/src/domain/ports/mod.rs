@@ -1,3 +1,9 @@
+pub mod blinding_source;
+pub mod disclosure_signer;
 pub mod hasher;
 
+pub use blinding_source::{BlindingSource, BlindingSourceError, RandomnessProvenance};
+pub use disclosure_signer::{
+	DisclosureSigner, SignatureVerifier, SignerError, DISCLOSURE_SIGNATURE_LENGTH,
+};
 pub use hasher::PoseidonHasher;
@@ -17,6 +17,8 @@ pub enum RepositoryError {
 	TreeEmpty,
 	/// Internal storage error
 	StorageError(String),
+	/// Checkpoint does not belong to this tree (or is ahead of it)
+	InvalidCheckpoint,
 }
 
 impl core::fmt::Display for RepositoryError {
@@ -27,6 +29,7 @@ impl core::fmt::Display for RepositoryError {
 			}
 			RepositoryError::TreeEmpty => write!(f, "Merkle tree is empty"),
 			RepositoryError::StorageError(msg) => write!(f, "Storage error: {msg}"),
+			RepositoryError::InvalidCheckpoint => write!(f, "Invalid Merkle checkpoint"),
 		}
 	}
 }
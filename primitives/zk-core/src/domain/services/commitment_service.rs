@@ -11,6 +11,9 @@ use crate::domain::{
 ///
 /// Creates cryptographic commitments using Poseidon hash:
 /// `commitment = Poseidon(value, asset_id, owner_pubkey, blinding)`
+///
+/// For diversified addresses `owner_pubkey` is the address's diversified key
+/// `Poseidon(owner_base, d_j)` (see `HdKeyService`).
 pub struct CommitmentService<H: PoseidonHasher> {
 	hasher: H,
 }
@@ -0,0 +1,368 @@
+//! HD Key Service
+//!
+//! Hierarchical deterministic key derivation (ZIP32-style) over Poseidon, so a
+//! single seed recovers every shielded account and address:
+//!
+//! ```text
+//! seed ─▶ master ─▶ m/32'/coin_type'/account' ─▶ diversified addresses
+//! ```
+//!
+//! - Master: `I = absorb(MASTER_DOMAIN, len, seed in 31-byte chunks)`
+//! - Child (hardened only): `I = Poseidon(CHILD_DOMAIN, chain_code, spending_key, index)`
+//! - Split: `spending_key = Poseidon(I, 0)`, `chain_code = Poseidon(I, 1)`
+//! - Viewing key: `vk = Poseidon(VIEWING_DOMAIN, spending_key)`
+//! - Address `j`: `d_j = Poseidon(dk, j)`, `owner_pubkey = Poseidon(owner_base, d_j)` with
+//!   `dk = Poseidon(DIVERSIFIER_DOMAIN, vk)` and `owner_base = Poseidon(OWNER_DOMAIN, vk)`
+//!
+//! Addresses depend only on the viewing key, so watch-only wallets can derive
+//! and recognize them without the spending key.
+
+use crate::domain::{
+	ports::PoseidonHasher,
+	value_objects::{
+		extended_spending_key::{hardened, KeyDerivationError, HARDENED_OFFSET},
+		DiversifiedAddress, ExtendedSpendingKey, FieldElement, OwnerPubkey, SpendingKey,
+	},
+};
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+/// Minimum seed length in bytes
+pub const MIN_SEED_LENGTH: usize = 32;
+
+/// Maximum seed length in bytes
+pub const MAX_SEED_LENGTH: usize = 252;
+
+/// Purpose level of account paths (`m/32'/...`, as in ZIP32)
+pub const HD_PURPOSE: u32 = 32;
+
+/// Domain separator for master key generation
+pub const MASTER_KEY_DOMAIN: &[u8] = b"orbinum-hd-master-v1";
+
+/// Domain separator for child key derivation
+pub const CHILD_KEY_DOMAIN: &[u8] = b"orbinum-hd-child-v1";
+
+/// Domain separator for the account's viewing key
+pub const VIEWING_KEY_DOMAIN: &[u8] = b"orbinum-hd-viewing-v1";
+
+/// Domain separator for the account's base owner key
+pub const OWNER_KEY_DOMAIN: &[u8] = b"orbinum-hd-owner-v1";
+
+/// Domain separator for the diversifier key
+pub const DIVERSIFIER_KEY_DOMAIN: &[u8] = b"orbinum-hd-diversifier-v1";
+
+/// Domain separator for key fingerprints
+pub const FINGERPRINT_DOMAIN: &[u8] = b"orbinum-hd-fingerprint-v1";
+
+/// Seed bytes absorbed per field element (always below the modulus)
+const SEED_CHUNK_SIZE: usize = 31;
+
+fn domain(tag: &[u8]) -> FieldElement {
+	FieldElement::new(Fr::from_le_bytes_mod_order(tag))
+}
+
+/// Domain service for HD key derivation
+pub struct HdKeyService<H: PoseidonHasher> {
+	hasher: H,
+}
+
+impl<H: PoseidonHasher> HdKeyService<H> {
+	/// Create a new HD key service with the given hasher
+	pub fn new(hasher: H) -> Self {
+		Self { hasher }
+	}
+
+	/// Derive the master key from a seed (e.g. a BIP39 seed)
+	pub fn master_key(&self, seed: &[u8]) -> Result<ExtendedSpendingKey, KeyDerivationError> {
+		if seed.len() < MIN_SEED_LENGTH || seed.len() > MAX_SEED_LENGTH {
+			return Err(KeyDerivationError::InvalidSeedLength(seed.len()));
+		}
+
+		let mut state = self.hasher.hash_2([
+			domain(MASTER_KEY_DOMAIN),
+			FieldElement::from_u64(seed.len() as u64),
+		]);
+		for chunk in seed.chunks(SEED_CHUNK_SIZE) {
+			let chunk = FieldElement::new(Fr::from_le_bytes_mod_order(chunk));
+			state = self.hasher.hash_2([state, chunk]);
+		}
+
+		Ok(self.split(state, 0, [0u8; 4], 0))
+	}
+
+	/// Derive a hardened child key
+	pub fn derive_child(
+		&self,
+		parent: &ExtendedSpendingKey,
+		index: u32,
+	) -> Result<ExtendedSpendingKey, KeyDerivationError> {
+		if index & HARDENED_OFFSET == 0 {
+			return Err(KeyDerivationError::NonHardenedIndex(index));
+		}
+		let depth = parent
+			.depth
+			.checked_add(1)
+			.ok_or(KeyDerivationError::MaxDepthExceeded)?;
+
+		let state = self.hasher.hash_4([
+			domain(CHILD_KEY_DOMAIN),
+			parent.chain_code,
+			parent.spending_key.inner(),
+			FieldElement::from_u64(index as u64),
+		]);
+
+		Ok(self.split(state, depth, self.fingerprint(parent), index))
+	}
+
+	/// Derive the key at `path` (all indices hardened) from a seed
+	pub fn derive_path(
+		&self,
+		seed: &[u8],
+		path: &[u32],
+	) -> Result<ExtendedSpendingKey, KeyDerivationError> {
+		let mut key = self.master_key(seed)?;
+		for index in path {
+			key = self.derive_child(&key, *index)?;
+		}
+		Ok(key)
+	}
+
+	/// Derive the account key at `m/32'/coin_type'/account'`
+	pub fn account_key(
+		&self,
+		seed: &[u8],
+		coin_type: u32,
+		account: u32,
+	) -> Result<ExtendedSpendingKey, KeyDerivationError> {
+		self.derive_path(
+			seed,
+			&[hardened(HD_PURPOSE), hardened(coin_type), hardened(account)],
+		)
+	}
+
+	/// Viewing key of an account (derives and recognizes addresses, cannot spend)
+	pub fn viewing_key(&self, key: &ExtendedSpendingKey) -> FieldElement {
+		self.hasher
+			.hash_2([domain(VIEWING_KEY_DOMAIN), key.spending_key.inner()])
+	}
+
+	/// Base owner key of a viewing key (never used directly in notes)
+	pub fn owner_base(&self, viewing_key: FieldElement) -> FieldElement {
+		self.hasher.hash_2([domain(OWNER_KEY_DOMAIN), viewing_key])
+	}
+
+	/// Diversifier key of a viewing key
+	pub fn diversifier_key(&self, viewing_key: FieldElement) -> FieldElement {
+		self.hasher
+			.hash_2([domain(DIVERSIFIER_KEY_DOMAIN), viewing_key])
+	}
+
+	/// Diversified receiving address `index` of a viewing key
+	///
+	/// Addresses of the same account are unlinkable without the viewing key.
+	pub fn derive_diversified_address(
+		&self,
+		viewing_key: FieldElement,
+		index: u64,
+	) -> DiversifiedAddress {
+		let diversifier = self.hasher.hash_2([
+			self.diversifier_key(viewing_key),
+			FieldElement::from_u64(index),
+		]);
+		let owner_pubkey = self
+			.hasher
+			.hash_2([self.owner_base(viewing_key), diversifier]);
+
+		DiversifiedAddress::new(index, diversifier, OwnerPubkey::new(owner_pubkey))
+	}
+
+	/// Diversified receiving address `index` of an account
+	pub fn address(&self, key: &ExtendedSpendingKey, index: u64) -> DiversifiedAddress {
+		self.derive_diversified_address(self.viewing_key(key), index)
+	}
+
+	/// Whether `address` was derived from `viewing_key` (at its claimed index)
+	pub fn is_own_address(&self, viewing_key: FieldElement, address: &DiversifiedAddress) -> bool {
+		self.derive_diversified_address(viewing_key, address.index) == *address
+	}
+
+	/// First 4 bytes (LE) of `Poseidon(FINGERPRINT_DOMAIN, owner_base)`
+	pub fn fingerprint(&self, key: &ExtendedSpendingKey) -> [u8; 4] {
+		let owner_base = self.owner_base(self.viewing_key(key));
+		let hash = self.hasher.hash_2([domain(FINGERPRINT_DOMAIN), owner_base]);
+		let mut fingerprint = [0u8; 4];
+		fingerprint.copy_from_slice(&hash.to_le_bytes()[..4]);
+		fingerprint
+	}
+
+	fn split(
+		&self,
+		state: FieldElement,
+		depth: u8,
+		parent_fingerprint: [u8; 4],
+		child_index: u32,
+	) -> ExtendedSpendingKey {
+		ExtendedSpendingKey {
+			depth,
+			parent_fingerprint,
+			child_index,
+			chain_code: self.hasher.hash_2([state, FieldElement::from_u64(1)]),
+			spending_key: SpendingKey::new(self.hasher.hash_2([state, FieldElement::zero()])),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::LightPoseidonHasher;
+	use alloc::collections::BTreeSet;
+
+	const SEED: [u8; 64] = [7u8; 64];
+
+	fn service() -> HdKeyService<LightPoseidonHasher> {
+		HdKeyService::new(LightPoseidonHasher)
+	}
+
+	// ===== Master Key Tests =====
+
+	#[test]
+	fn test_master_key_deterministic() {
+		let a = service().master_key(&SEED).unwrap();
+		let b = service().master_key(&SEED).unwrap();
+		assert_eq!(a, b);
+		assert_eq!(a.depth, 0);
+		assert_eq!(a.parent_fingerprint, [0u8; 4]);
+	}
+
+	#[test]
+	fn test_master_key_depends_on_seed() {
+		let mut other = SEED;
+		other[63] ^= 1;
+		assert_ne!(
+			service().master_key(&SEED).unwrap(),
+			service().master_key(&other).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_master_key_seed_length_bounds() {
+		assert_eq!(
+			service().master_key(&[0u8; 31]),
+			Err(KeyDerivationError::InvalidSeedLength(31))
+		);
+		assert!(service().master_key(&[0u8; MIN_SEED_LENGTH]).is_ok());
+		assert!(service().master_key(&[0u8; MAX_SEED_LENGTH]).is_ok());
+		assert!(service().master_key(&[0u8; MAX_SEED_LENGTH + 1]).is_err());
+	}
+
+	#[test]
+	fn test_trailing_zero_seeds_differ() {
+		let short = [1u8; 32];
+		let mut long = [0u8; 33];
+		long[..32].copy_from_slice(&short);
+		assert_ne!(
+			service().master_key(&short).unwrap(),
+			service().master_key(&long).unwrap()
+		);
+	}
+
+	// ===== Child Derivation Tests =====
+
+	#[test]
+	fn test_non_hardened_child_rejected() {
+		let master = service().master_key(&SEED).unwrap();
+		assert_eq!(
+			service().derive_child(&master, 5),
+			Err(KeyDerivationError::NonHardenedIndex(5))
+		);
+	}
+
+	#[test]
+	fn test_account_path_metadata() {
+		let service = service();
+		let master = service.master_key(&SEED).unwrap();
+		let purpose = service.derive_child(&master, hardened(HD_PURPOSE)).unwrap();
+		let coin = service.derive_child(&purpose, hardened(1)).unwrap();
+		let account = service.account_key(&SEED, 1, 0).unwrap();
+
+		assert_eq!(account.depth, 3);
+		assert_eq!(account.child_index, hardened(0));
+		assert_eq!(account.parent_fingerprint, service.fingerprint(&coin));
+		assert_eq!(account, service.derive_child(&coin, hardened(0)).unwrap());
+	}
+
+	#[test]
+	fn test_accounts_are_distinct() {
+		let keys: BTreeSet<[u8; 73]> = (0..5)
+			.map(|account| service().account_key(&SEED, 1, account).unwrap().to_bytes())
+			.collect();
+		assert_eq!(keys.len(), 5);
+	}
+
+	#[test]
+	fn test_exported_key_recovers_addresses() {
+		let service = service();
+		let account = service.account_key(&SEED, 1, 2).unwrap();
+		let restored = ExtendedSpendingKey::from_bytes(&account.to_bytes()).unwrap();
+
+		assert_eq!(service.address(&restored, 9), service.address(&account, 9));
+	}
+
+	#[test]
+	fn test_max_depth_exceeded() {
+		let mut key = service().master_key(&SEED).unwrap();
+		key.depth = u8::MAX;
+		assert_eq!(
+			service().derive_child(&key, hardened(0)),
+			Err(KeyDerivationError::MaxDepthExceeded)
+		);
+	}
+
+	// ===== Address Tests =====
+
+	#[test]
+	fn test_diversified_addresses_unlinkable() {
+		let service = service();
+		let account = service.account_key(&SEED, 1, 0).unwrap();
+
+		let a = service.address(&account, 0);
+		let b = service.address(&account, 1);
+
+		assert_eq!(a.index, 0);
+		assert_ne!(a.diversifier, b.diversifier);
+		assert_ne!(a.owner_pubkey, b.owner_pubkey);
+		assert_ne!(
+			a.owner_pubkey.inner(),
+			service.owner_base(service.viewing_key(&account))
+		);
+	}
+
+	#[test]
+	fn test_viewing_key_derives_same_addresses() {
+		let service = service();
+		let account = service.account_key(&SEED, 1, 0).unwrap();
+		let viewing_key = service.viewing_key(&account);
+
+		assert_ne!(viewing_key, account.spending_key.inner());
+		assert_eq!(
+			service.derive_diversified_address(viewing_key, 4),
+			service.address(&account, 4)
+		);
+	}
+
+	#[test]
+	fn test_is_own_address() {
+		let service = service();
+		let mine = service.viewing_key(&service.account_key(&SEED, 1, 0).unwrap());
+		let other = service.viewing_key(&service.account_key(&SEED, 1, 1).unwrap());
+		let address = service.derive_diversified_address(mine, 17);
+
+		assert!(service.is_own_address(mine, &address));
+		assert!(!service.is_own_address(other, &address));
+
+		let mut wrong_index = address;
+		wrong_index.index = 18;
+		assert!(!service.is_own_address(mine, &wrong_index));
+	}
+}
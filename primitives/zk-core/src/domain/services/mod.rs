@@ -1,7 +1,11 @@
 pub mod commitment_service;
+pub mod hd_key_service;
 pub mod merkle_service;
 pub mod nullifier_service;
+pub mod reserves_service;
 
 pub use commitment_service::CommitmentService;
+pub use hd_key_service::HdKeyService;
 pub use merkle_service::MerkleService;
 pub use nullifier_service::NullifierService;
+pub use reserves_service::ReservesService;
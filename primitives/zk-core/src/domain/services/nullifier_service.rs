@@ -4,12 +4,13 @@
 
 use crate::domain::{
 	ports::PoseidonHasher,
-	value_objects::{Commitment, Nullifier, SpendingKey},
+	value_objects::{Commitment, FieldElement, Nullifier, NullifierVersion, SpendingKey},
 };
 
 /// Domain service for computing nullifiers
 ///
-/// Computes: `nullifier = Poseidon(commitment, spending_key)`
+/// Computes: `nullifier = Poseidon(commitment, spending_key)` (V1), or the
+/// domain-tagged preimage of a later [`NullifierVersion`].
 pub struct NullifierService<H: PoseidonHasher> {
 	hasher: H,
 }
@@ -34,6 +35,28 @@ impl<H: PoseidonHasher> NullifierService<H> {
 		let hash = self.hasher.hash_2(inputs);
 		Nullifier::from(hash)
 	}
+
+	/// Compute the nullifier for a commitment under a specific derivation version
+	///
+	/// `V1` matches [`compute_nullifier`](Self::compute_nullifier). Tagged
+	/// versions absorb their domain tag first, so no two versions can produce
+	/// the same nullifier for a note.
+	pub fn compute_nullifier_versioned(
+		&self,
+		commitment: &Commitment,
+		spending_key: &SpendingKey,
+		version: NullifierVersion,
+	) -> Nullifier {
+		match version.domain_tag() {
+			None => self.compute_nullifier(commitment, spending_key),
+			Some(tag) => Nullifier::from(self.hasher.hash_4([
+				tag,
+				commitment.inner(),
+				spending_key.inner(),
+				FieldElement::zero(),
+			])),
+		}
+	}
 }
 
 #[cfg(test)]
@@ -455,4 +478,51 @@ mod tests {
 		assert_eq!(n2.inner(), FieldElement::from_u64(300)); // Sum
 		assert_eq!(n3.inner(), commitment.inner()); // First
 	}
+
+	// ===== Versioned Tests =====
+
+	struct MockHasherTag;
+
+	impl PoseidonHasher for MockHasherTag {
+		fn hash_2(&self, inputs: [FieldElement; 2]) -> FieldElement {
+			inputs[0]
+		}
+
+		fn hash_4(&self, inputs: [FieldElement; 4]) -> FieldElement {
+			assert!(inputs[3].is_zero());
+			inputs[0]
+		}
+	}
+
+	#[test]
+	fn test_versioned_v1_matches_legacy() {
+		let service = NullifierService::new(MockHasherSum);
+		let commitment = Commitment::from(Fr::from(100u64));
+		let spending_key = SpendingKey::from(Fr::from(50u64));
+		assert_eq!(
+			service.compute_nullifier_versioned(&commitment, &spending_key, NullifierVersion::V1),
+			service.compute_nullifier(&commitment, &spending_key)
+		);
+	}
+
+	#[test]
+	fn test_versioned_v2_absorbs_tag() {
+		let service = NullifierService::new(MockHasherTag);
+		let commitment = Commitment::from(Fr::from(100u64));
+		let spending_key = SpendingKey::from(Fr::from(50u64));
+		let nullifier =
+			service.compute_nullifier_versioned(&commitment, &spending_key, NullifierVersion::V2);
+		assert_eq!(Some(nullifier.inner()), NullifierVersion::V2.domain_tag());
+	}
+
+	#[test]
+	fn test_versions_do_not_collide() {
+		let service = NullifierService::new(MockHasherSum);
+		let commitment = Commitment::from(Fr::from(100u64));
+		let spending_key = SpendingKey::from(Fr::from(50u64));
+		assert_ne!(
+			service.compute_nullifier_versioned(&commitment, &spending_key, NullifierVersion::V1),
+			service.compute_nullifier_versioned(&commitment, &spending_key, NullifierVersion::V2)
+		);
+	}
 }
@@ -0,0 +1,268 @@
+//! Reserves Service
+//!
+//! Builds proof-of-reserves witnesses, letting an exchange commit to its total
+//! shielded holdings of one asset without revealing individual notes:
+//!
+//! ```text
+//! blinding   = Poseidon(RESERVES_DOMAIN, viewing_key, epoch, 0)
+//! commitment = Poseidon(total, asset_id, owner_base, blinding)
+//! ```
+//!
+//! The blinding depends only on the viewing key and the audit epoch, so an
+//! auditor given the viewing key can open the commitment, while each epoch's
+//! commitment stays unlinkable to the others for everyone else.
+
+use crate::domain::{
+	entities::{
+		reserves_witness::{ReserveNote, ReservesError, ReservesWitness},
+		Note,
+	},
+	ports::PoseidonHasher,
+	services::HdKeyService,
+	value_objects::{Commitment, FieldElement},
+};
+use alloc::{collections::BTreeSet, vec::Vec};
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+/// Domain separator for balance commitment blindings
+pub const RESERVES_DOMAIN: &[u8] = b"orbinum-reserves-v1";
+
+/// Domain service for proof-of-reserves witnesses
+pub struct ReservesService<H: PoseidonHasher + Clone> {
+	hasher: H,
+}
+
+impl<H: PoseidonHasher + Clone> ReservesService<H> {
+	/// Create a new reserves service with the given hasher
+	pub fn new(hasher: H) -> Self {
+		Self { hasher }
+	}
+
+	/// Blinding of the balance commitment for `epoch`
+	pub fn balance_blinding(&self, viewing_key: FieldElement, epoch: u64) -> FieldElement {
+		self.hasher.hash_4([
+			FieldElement::new(Fr::from_le_bytes_mod_order(RESERVES_DOMAIN)),
+			viewing_key,
+			FieldElement::from_u64(epoch),
+			FieldElement::zero(),
+		])
+	}
+
+	/// Commitment to a total balance of `asset_id` held under `viewing_key`
+	pub fn balance_commitment(
+		&self,
+		viewing_key: FieldElement,
+		asset_id: u64,
+		total: u128,
+		epoch: u64,
+	) -> Commitment {
+		let owner_base = HdKeyService::new(self.hasher.clone()).owner_base(viewing_key);
+		Commitment::from(self.hasher.hash_4([
+			FieldElement::new(Fr::from(total)),
+			FieldElement::from_u64(asset_id),
+			owner_base,
+			self.balance_blinding(viewing_key, epoch),
+		]))
+	}
+
+	/// Build the witness for `notes`, each paired with the diversifier index
+	/// of the address it was received on
+	///
+	/// # Errors
+	/// If the set is empty, mixes assets, repeats a note, or contains a note
+	/// not addressed to `viewing_key`.
+	pub fn build_witness(
+		&self,
+		viewing_key: FieldElement,
+		notes: &[(Note, u64)],
+		epoch: u64,
+	) -> Result<ReservesWitness, ReservesError> {
+		let asset_id = notes
+			.first()
+			.map(|(note, _)| note.asset_id())
+			.ok_or(ReservesError::EmptyNoteSet)?;
+
+		let keys = HdKeyService::new(self.hasher.clone());
+		let mut seen = BTreeSet::new();
+		let mut total: u128 = 0;
+		let mut entries = Vec::with_capacity(notes.len());
+
+		for (index, (note, diversifier_index)) in notes.iter().enumerate() {
+			if note.asset_id() != asset_id {
+				return Err(ReservesError::MixedAssets { index });
+			}
+			let address = keys.derive_diversified_address(viewing_key, *diversifier_index);
+			if address.owner_pubkey != note.owner_pubkey() {
+				return Err(ReservesError::NotOwned { index });
+			}
+			let commitment = note.commitment(self.hasher.clone());
+			if !seen.insert(commitment.inner().to_le_bytes()) {
+				return Err(ReservesError::DuplicateNote { index });
+			}
+
+			// At most `usize::MAX` values below 2^64, so this cannot overflow
+			total += note.value() as u128;
+			entries.push(ReserveNote {
+				note: note.clone(),
+				address,
+				commitment,
+			});
+		}
+
+		Ok(ReservesWitness {
+			asset_id,
+			total,
+			epoch,
+			balance_blinding: self.balance_blinding(viewing_key, epoch),
+			balance_commitment: self.balance_commitment(viewing_key, asset_id, total, epoch),
+			notes: entries,
+		})
+	}
+
+	/// Whether `commitment` opens to `total` of `asset_id` under `viewing_key`
+	pub fn verify_opening(
+		&self,
+		viewing_key: FieldElement,
+		asset_id: u64,
+		total: u128,
+		epoch: u64,
+		commitment: &Commitment,
+	) -> bool {
+		self.balance_commitment(viewing_key, asset_id, total, epoch) == *commitment
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{domain::value_objects::Blinding, LightPoseidonHasher};
+	use alloc::vec;
+
+	const VIEWING_KEY: u64 = 1234;
+	const ASSET: u64 = 7;
+
+	fn service() -> ReservesService<LightPoseidonHasher> {
+		ReservesService::new(LightPoseidonHasher)
+	}
+
+	fn owned_note(value: u64, asset_id: u64, index: u64, blinding: u64) -> (Note, u64) {
+		let address = HdKeyService::new(LightPoseidonHasher)
+			.derive_diversified_address(FieldElement::from_u64(VIEWING_KEY), index);
+		let note = Note::new(
+			value,
+			asset_id,
+			address.owner_pubkey,
+			Blinding::new(FieldElement::from_u64(blinding)),
+		);
+		(note, index)
+	}
+
+	fn viewing_key() -> FieldElement {
+		FieldElement::from_u64(VIEWING_KEY)
+	}
+
+	// ===== Witness Tests =====
+
+	#[test]
+	fn test_build_witness_sums_values() {
+		let notes = vec![
+			owned_note(100, ASSET, 0, 1),
+			owned_note(250, ASSET, 3, 2),
+			owned_note(u64::MAX, ASSET, 3, 3),
+		];
+		let witness = service().build_witness(viewing_key(), &notes, 5).unwrap();
+
+		assert_eq!(witness.asset_id, ASSET);
+		assert_eq!(witness.total, 350 + u64::MAX as u128);
+		assert_eq!(witness.notes.len(), 3);
+		assert_eq!(witness.notes[1].address.index, 3);
+		assert_eq!(
+			witness.note_commitments()[0],
+			notes[0].0.commitment(LightPoseidonHasher)
+		);
+	}
+
+	#[test]
+	fn test_public_inputs() {
+		let witness = service()
+			.build_witness(viewing_key(), &[owned_note(10, ASSET, 0, 1)], 9)
+			.unwrap();
+
+		assert_eq!(
+			witness.public_inputs(),
+			[
+				witness.balance_commitment.inner(),
+				FieldElement::from_u64(ASSET),
+				FieldElement::from_u64(9),
+			]
+		);
+	}
+
+	#[test]
+	fn test_empty_note_set_rejected() {
+		assert_eq!(
+			service().build_witness(viewing_key(), &[], 0),
+			Err(ReservesError::EmptyNoteSet)
+		);
+	}
+
+	#[test]
+	fn test_mixed_assets_rejected() {
+		let notes = vec![owned_note(1, ASSET, 0, 1), owned_note(1, ASSET + 1, 0, 2)];
+		assert_eq!(
+			service().build_witness(viewing_key(), &notes, 0),
+			Err(ReservesError::MixedAssets { index: 1 })
+		);
+	}
+
+	#[test]
+	fn test_foreign_note_rejected() {
+		let (note, _) = owned_note(1, ASSET, 4, 1);
+		// Claimed at the wrong diversifier index
+		let notes = vec![owned_note(1, ASSET, 0, 2), (note, 5)];
+		assert_eq!(
+			service().build_witness(viewing_key(), &notes, 0),
+			Err(ReservesError::NotOwned { index: 1 })
+		);
+		assert_eq!(
+			service().build_witness(FieldElement::from_u64(1), &notes[..1], 0),
+			Err(ReservesError::NotOwned { index: 0 })
+		);
+	}
+
+	#[test]
+	fn test_duplicate_note_rejected() {
+		let notes = vec![owned_note(1, ASSET, 0, 1), owned_note(1, ASSET, 0, 1)];
+		assert_eq!(
+			service().build_witness(viewing_key(), &notes, 0),
+			Err(ReservesError::DuplicateNote { index: 1 })
+		);
+	}
+
+	// ===== Opening Tests =====
+
+	#[test]
+	fn test_auditor_opens_commitment() {
+		let notes = vec![owned_note(40, ASSET, 0, 1), owned_note(2, ASSET, 1, 2)];
+		let witness = service().build_witness(viewing_key(), &notes, 3).unwrap();
+		let commitment = witness.balance_commitment;
+
+		assert!(service().verify_opening(viewing_key(), ASSET, 42, 3, &commitment));
+		assert!(!service().verify_opening(viewing_key(), ASSET, 41, 3, &commitment));
+		assert!(!service().verify_opening(viewing_key(), ASSET + 1, 42, 3, &commitment));
+		assert!(!service().verify_opening(viewing_key(), ASSET, 42, 4, &commitment));
+		assert!(!service().verify_opening(FieldElement::from_u64(1), ASSET, 42, 3, &commitment));
+	}
+
+	#[test]
+	fn test_epochs_unlinkable() {
+		let a = service().balance_commitment(viewing_key(), ASSET, 42, 1);
+		let b = service().balance_commitment(viewing_key(), ASSET, 42, 2);
+		assert_ne!(a, b);
+		assert_ne!(
+			service().balance_blinding(viewing_key(), 1),
+			service().balance_blinding(viewing_key(), 2)
+		);
+	}
+}
@@ -2,13 +2,20 @@
 //!
 //! Random blinding factor used to hide note commitments and ensure unlinkability.
 
-use crate::domain::value_objects::field_element::FieldElement;
+use crate::domain::{
+	ports::{BlindingSource, BlindingSourceError},
+	value_objects::field_element::FieldElement,
+};
 use ark_bn254::Fr;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
 
 /// Random blinding factor for hiding note commitments
 ///
 /// Provides unlinkability: `commitment = Poseidon(value, asset_id, owner_pubkey, blinding)`
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///
+/// Compared in constant time. `Copy`, so callers zeroize their own copies.
+#[derive(Clone, Copy, Debug)]
 pub struct Blinding(FieldElement);
 
 impl Blinding {
@@ -17,6 +24,11 @@ impl Blinding {
 		Self(value)
 	}
 
+	/// Draw a fresh blinding factor from `source`
+	pub fn random<S: BlindingSource + ?Sized>(source: &mut S) -> Result<Self, BlindingSourceError> {
+		source.next_blinding()
+	}
+
 	/// Get the inner field element
 	pub fn inner(&self) -> FieldElement {
 		self.0
@@ -52,6 +64,26 @@ impl From<Blinding> for Fr {
 	}
 }
 
+impl ConstantTimeEq for Blinding {
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.0.ct_eq(&other.0)
+	}
+}
+
+impl PartialEq for Blinding {
+	fn eq(&self, other: &Self) -> bool {
+		self.ct_eq(other).into()
+	}
+}
+
+impl Eq for Blinding {}
+
+impl Zeroize for Blinding {
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -370,4 +402,23 @@ mod tests {
 		let b2 = Blinding::from(Fr::from(101u64));
 		assert_ne!(b1, b2);
 	}
+
+	// ===== Secret Hygiene Tests =====
+
+	#[test]
+	fn test_ct_eq() {
+		assert!(bool::from(
+			Blinding::from_u64(7).ct_eq(&Blinding::from_u64(7))
+		));
+		assert!(!bool::from(
+			Blinding::from_u64(7).ct_eq(&Blinding::from_u64(8))
+		));
+	}
+
+	#[test]
+	fn test_zeroize() {
+		let mut value = Blinding::from_u64(7);
+		value.zeroize();
+		assert_eq!(value, Blinding::from_u64(0));
+	}
 }
@@ -4,11 +4,17 @@
 
 use crate::domain::value_objects::field_element::FieldElement;
 use ark_bn254::Fr;
+#[cfg(feature = "substrate")]
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+#[cfg(feature = "substrate")]
+use scale_info::TypeInfo;
 
 /// Cryptographic commitment to a note
 ///
 /// Hides note details: `commitment = Poseidon(value, asset_id, owner_pubkey, blinding)`
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "substrate", derive(Encode, Decode, MaxEncodedLen, TypeInfo))]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct Commitment(FieldElement);
 
 impl Commitment {
@@ -384,4 +390,17 @@ mod tests {
 			Commitment(_) => {}
 		}
 	}
+
+	// ===== Codec Tests =====
+
+	#[cfg(feature = "substrate")]
+	#[test]
+	fn test_scale_roundtrip() {
+		use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+
+		let commitment = Commitment::from(Fr::from(42u64));
+		let encoded = commitment.encode();
+		assert_eq!(encoded.len(), Commitment::max_encoded_len());
+		assert_eq!(Commitment::decode(&mut &encoded[..]).unwrap(), commitment);
+	}
 }
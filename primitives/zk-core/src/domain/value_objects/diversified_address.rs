@@ -0,0 +1,28 @@
+//! Diversified Address Value Object
+//!
+//! One of many unlinkable receiving addresses of a shielded account. Notes sent
+//! to the address commit to its `owner_pubkey`.
+
+use crate::domain::value_objects::{FieldElement, OwnerPubkey};
+
+/// Receiving address derived from an account key and a diversifier index
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiversifiedAddress {
+	/// Diversifier index the address was derived at
+	pub index: u64,
+	/// Diversifier value `d_j`
+	pub diversifier: FieldElement,
+	/// Owner public key used in note commitments
+	pub owner_pubkey: OwnerPubkey,
+}
+
+impl DiversifiedAddress {
+	/// Create a new diversified address
+	pub fn new(index: u64, diversifier: FieldElement, owner_pubkey: OwnerPubkey) -> Self {
+		Self {
+			index,
+			diversifier,
+			owner_pubkey,
+		}
+	}
+}
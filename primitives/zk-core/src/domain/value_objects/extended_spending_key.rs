@@ -0,0 +1,169 @@
+//! Extended Spending Key Value Object
+//!
+//! Spending key plus chain code and position in a hierarchical deterministic
+//! (ZIP32-style) key tree. Serializes to a fixed 73-byte layout so wallets can
+//! export and re-import account keys.
+
+use crate::domain::value_objects::{FieldElement, SpendingKey};
+use zeroize::Zeroize;
+
+/// Serialized extended key length:
+/// `depth (1) || parent_fingerprint (4) || child_index (4) || chain_code (32) || spending_key (32)`
+pub const EXTENDED_KEY_LENGTH: usize = 73;
+
+/// Flag marking a hardened child index
+pub const HARDENED_OFFSET: u32 = 1 << 31;
+
+/// Errors that can occur during HD key derivation or decoding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyDerivationError {
+	/// Seed length outside the accepted range
+	InvalidSeedLength(usize),
+	/// Only hardened derivation is supported for spending keys
+	NonHardenedIndex(u32),
+	/// Key tree is deeper than 255 levels
+	MaxDepthExceeded,
+	/// Serialized key has the wrong length or a non-canonical field element
+	InvalidEncoding,
+}
+
+impl core::fmt::Display for KeyDerivationError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			KeyDerivationError::InvalidSeedLength(len) => write!(f, "Invalid seed length: {len}"),
+			KeyDerivationError::NonHardenedIndex(index) => {
+				write!(f, "Non-hardened child index: {index}")
+			}
+			KeyDerivationError::MaxDepthExceeded => write!(f, "Maximum key depth exceeded"),
+			KeyDerivationError::InvalidEncoding => write!(f, "Invalid extended key encoding"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyDerivationError {}
+
+/// Hardened child index (`index'`)
+pub const fn hardened(index: u32) -> u32 {
+	index | HARDENED_OFFSET
+}
+
+/// Extended spending key (key + chain code + tree position)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExtendedSpendingKey {
+	/// Depth in the key tree (master = 0)
+	pub depth: u8,
+	/// First 4 bytes of the parent's fingerprint (zero for master)
+	pub parent_fingerprint: [u8; 4],
+	/// Index this key was derived at (zero for master)
+	pub child_index: u32,
+	/// Chain code used to derive children
+	pub chain_code: FieldElement,
+	/// Spending key at this node
+	pub spending_key: SpendingKey,
+}
+
+impl ExtendedSpendingKey {
+	/// Serializes to the fixed 73-byte layout (field elements little-endian)
+	pub fn to_bytes(&self) -> [u8; EXTENDED_KEY_LENGTH] {
+		let mut bytes = [0u8; EXTENDED_KEY_LENGTH];
+		bytes[0] = self.depth;
+		bytes[1..5].copy_from_slice(&self.parent_fingerprint);
+		bytes[5..9].copy_from_slice(&self.child_index.to_le_bytes());
+		bytes[9..41].copy_from_slice(&self.chain_code.to_le_bytes());
+		bytes[41..73].copy_from_slice(&self.spending_key.inner().to_le_bytes());
+		bytes
+	}
+
+	/// Parses the 73-byte layout, rejecting non-canonical field elements
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, KeyDerivationError> {
+		if bytes.len() != EXTENDED_KEY_LENGTH {
+			return Err(KeyDerivationError::InvalidEncoding);
+		}
+
+		let mut parent_fingerprint = [0u8; 4];
+		parent_fingerprint.copy_from_slice(&bytes[1..5]);
+		let mut child_index = [0u8; 4];
+		child_index.copy_from_slice(&bytes[5..9]);
+
+		Ok(Self {
+			depth: bytes[0],
+			parent_fingerprint,
+			child_index: u32::from_le_bytes(child_index),
+			chain_code: canonical_field(&bytes[9..41])?,
+			spending_key: SpendingKey::new(canonical_field(&bytes[41..73])?),
+		})
+	}
+
+	/// Whether this key was derived at a hardened index
+	pub fn is_hardened(&self) -> bool {
+		self.child_index & HARDENED_OFFSET != 0
+	}
+}
+
+impl Zeroize for ExtendedSpendingKey {
+	fn zeroize(&mut self) {
+		self.chain_code.zeroize();
+		self.spending_key.zeroize();
+	}
+}
+
+fn canonical_field(bytes: &[u8]) -> Result<FieldElement, KeyDerivationError> {
+	bytes
+		.try_into()
+		.ok()
+		.and_then(FieldElement::from_canonical_le_bytes)
+		.ok_or(KeyDerivationError::InvalidEncoding)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sample_key() -> ExtendedSpendingKey {
+		ExtendedSpendingKey {
+			depth: 3,
+			parent_fingerprint: [1, 2, 3, 4],
+			child_index: hardened(7),
+			chain_code: FieldElement::from_u64(11),
+			spending_key: SpendingKey::from_u64(22),
+		}
+	}
+
+	// ===== Encoding Tests =====
+
+	#[test]
+	fn test_bytes_roundtrip() {
+		let key = sample_key();
+		let bytes = key.to_bytes();
+		assert_eq!(bytes.len(), EXTENDED_KEY_LENGTH);
+		assert_eq!(ExtendedSpendingKey::from_bytes(&bytes).unwrap(), key);
+	}
+
+	#[test]
+	fn test_from_bytes_wrong_length() {
+		let bytes = sample_key().to_bytes();
+		assert_eq!(
+			ExtendedSpendingKey::from_bytes(&bytes[..72]),
+			Err(KeyDerivationError::InvalidEncoding)
+		);
+	}
+
+	#[test]
+	fn test_from_bytes_non_canonical_field() {
+		let mut bytes = sample_key().to_bytes();
+		bytes[41..73].copy_from_slice(&[0xff; 32]);
+		assert_eq!(
+			ExtendedSpendingKey::from_bytes(&bytes),
+			Err(KeyDerivationError::InvalidEncoding)
+		);
+	}
+
+	// ===== Index Tests =====
+
+	#[test]
+	fn test_hardened_index() {
+		assert_eq!(hardened(0), HARDENED_OFFSET);
+		assert!(sample_key().is_hardened());
+	}
+}
@@ -2,7 +2,38 @@
 //!
 //! Base value object for BN254 scalar field elements.
 
+use alloc::string::String;
 use ark_bn254::Fr;
+use ark_ff::{BigInteger, BigInteger256, PrimeField};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
+
+/// Maximum decimal digits of a 256-bit integer
+const MAX_DECIMAL_DIGITS: usize = 78;
+
+/// Errors that can occur when parsing a field element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldElementError {
+	/// Input is empty or longer than 32 bytes / 64 hex digits
+	InvalidLength,
+	/// Input contains a character outside the expected radix
+	InvalidCharacter,
+	/// Value is not below the field modulus
+	NonCanonical,
+}
+
+impl core::fmt::Display for FieldElementError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			FieldElementError::InvalidLength => write!(f, "Invalid field element length"),
+			FieldElementError::InvalidCharacter => write!(f, "Invalid character in field element"),
+			FieldElementError::NonCanonical => write!(f, "Field element not below modulus"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldElementError {}
 
 /// Field element in BN254 scalar field
 ///
@@ -35,6 +66,88 @@ impl FieldElement {
 	pub fn is_zero(&self) -> bool {
 		self.0 == Fr::from(0u64)
 	}
+
+	/// Canonical 32-byte little-endian encoding
+	pub fn to_le_bytes(&self) -> [u8; 32] {
+		let mut bytes = [0u8; 32];
+		bytes.copy_from_slice(&self.0.into_bigint().to_bytes_le()[..32]);
+		bytes
+	}
+
+	/// Parses a canonical little-endian encoding (`None` if not reduced mod r)
+	pub fn from_canonical_le_bytes(bytes: &[u8; 32]) -> Option<Self> {
+		let value = Self(Fr::from_le_bytes_mod_order(bytes));
+		(value.to_le_bytes() == *bytes).then_some(value)
+	}
+
+	/// Big-endian 256-bit integer encoding (as used by snarkjs and Solidity)
+	pub fn to_u256_be(&self) -> [u8; 32] {
+		let mut bytes = self.to_le_bytes();
+		bytes.reverse();
+		bytes
+	}
+
+	/// Parses a big-endian 256-bit integer, rejecting values `>= r`
+	pub fn from_u256_be(bytes: &[u8; 32]) -> Result<Self, FieldElementError> {
+		let mut le = *bytes;
+		le.reverse();
+		Self::from_canonical_le_bytes(&le).ok_or(FieldElementError::NonCanonical)
+	}
+
+	/// `0x`-prefixed, zero-padded big-endian hex (64 digits)
+	pub fn to_hex(&self) -> String {
+		const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+		let mut hex = String::with_capacity(66);
+		hex.push_str("0x");
+		for byte in self.to_u256_be() {
+			hex.push(DIGITS[(byte >> 4) as usize] as char);
+			hex.push(DIGITS[(byte & 0x0f) as usize] as char);
+		}
+		hex
+	}
+
+	/// Parses big-endian hex (optional `0x`, 1..=64 digits), rejecting values `>= r`
+	pub fn from_hex(hex: &str) -> Result<Self, FieldElementError> {
+		let digits = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+		if digits.is_empty() || digits.len() > 64 {
+			return Err(FieldElementError::InvalidLength);
+		}
+
+		let mut bytes = [0u8; 32];
+		for (i, digit) in digits.iter().rev().enumerate() {
+			let nibble = (*digit as char)
+				.to_digit(16)
+				.ok_or(FieldElementError::InvalidCharacter)? as u8;
+			bytes[31 - i / 2] |= nibble << (4 * (i & 1));
+		}
+		Self::from_u256_be(&bytes)
+	}
+
+	/// Parses an unsigned decimal string, rejecting values `>= r`
+	pub fn from_decimal_str(decimal: &str) -> Result<Self, FieldElementError> {
+		if decimal.is_empty() || decimal.len() > MAX_DECIMAL_DIGITS {
+			return Err(FieldElementError::InvalidLength);
+		}
+
+		let mut limbs = [0u64; 4];
+		for digit in decimal.chars() {
+			let mut carry = digit
+				.to_digit(10)
+				.ok_or(FieldElementError::InvalidCharacter)? as u128;
+			for limb in limbs.iter_mut() {
+				let product = (*limb as u128) * 10 + carry;
+				*limb = product as u64;
+				carry = product >> 64;
+			}
+			if carry != 0 {
+				return Err(FieldElementError::NonCanonical);
+			}
+		}
+		Fr::from_bigint(BigInteger256::new(limbs))
+			.map(Self)
+			.ok_or(FieldElementError::NonCanonical)
+	}
 }
 
 impl From<Fr> for FieldElement {
@@ -55,6 +168,76 @@ impl From<u64> for FieldElement {
 	}
 }
 
+/// Compares the Montgomery limbs (canonical, so equal elements have equal limbs)
+impl ConstantTimeEq for FieldElement {
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.0 .0 .0[..].ct_eq(&other.0 .0 .0[..])
+	}
+}
+
+impl Zeroize for FieldElement {
+	fn zeroize(&mut self) {
+		self.0 .0 .0.zeroize();
+	}
+}
+
+// ============================================================================
+// SCALE / serde adapters (canonical 32-byte little-endian encoding)
+// ============================================================================
+
+#[cfg(feature = "substrate")]
+impl parity_scale_codec::Encode for FieldElement {
+	fn size_hint(&self) -> usize {
+		32
+	}
+
+	fn encode_to<T: parity_scale_codec::Output + ?Sized>(&self, dest: &mut T) {
+		dest.write(&self.to_le_bytes());
+	}
+}
+
+#[cfg(feature = "substrate")]
+impl parity_scale_codec::Decode for FieldElement {
+	fn decode<I: parity_scale_codec::Input>(
+		input: &mut I,
+	) -> Result<Self, parity_scale_codec::Error> {
+		let bytes = <[u8; 32]>::decode(input)?;
+		Self::from_canonical_le_bytes(&bytes).ok_or_else(|| "Non-canonical field element".into())
+	}
+}
+
+#[cfg(feature = "substrate")]
+impl parity_scale_codec::MaxEncodedLen for FieldElement {
+	fn max_encoded_len() -> usize {
+		32
+	}
+}
+
+#[cfg(feature = "substrate")]
+impl scale_info::TypeInfo for FieldElement {
+	type Identity = [u8; 32];
+
+	fn type_info() -> scale_info::Type {
+		<[u8; 32] as scale_info::TypeInfo>::type_info()
+	}
+}
+
+#[cfg(feature = "std")]
+impl serde::Serialize for FieldElement {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serde::Serialize::serialize(&self.to_le_bytes(), serializer)
+	}
+}
+
+#[cfg(feature = "std")]
+impl<'de> serde::Deserialize<'de> for FieldElement {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		let bytes: [u8; 32] = serde::Deserialize::deserialize(deserializer)?;
+		Self::from_canonical_le_bytes(&bytes)
+			.ok_or_else(|| serde::de::Error::custom("Non-canonical field element"))
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -429,4 +612,164 @@ mod tests {
 		assert_eq!(e2, e3);
 		assert_eq!(e3, e4);
 	}
+
+	// ===== Secret Hygiene Tests =====
+
+	#[test]
+	fn test_ct_eq() {
+		assert!(bool::from(
+			FieldElement::from_u64(7).ct_eq(&FieldElement::from_u64(7))
+		));
+		assert!(!bool::from(
+			FieldElement::from_u64(7).ct_eq(&FieldElement::from_u64(8))
+		));
+	}
+
+	#[test]
+	fn test_zeroize() {
+		let mut value = FieldElement::from_u64(7);
+		value.zeroize();
+		assert_eq!(value, FieldElement::from_u64(0));
+	}
+
+	// ===== Codec Tests =====
+
+	#[test]
+	fn test_le_bytes_roundtrip() {
+		let elem = FieldElement::from_u64(0x0102);
+		let bytes = elem.to_le_bytes();
+		assert_eq!(&bytes[..2], &[0x02, 0x01]);
+		assert_eq!(FieldElement::from_canonical_le_bytes(&bytes), Some(elem));
+		assert_eq!(FieldElement::from_canonical_le_bytes(&[0xff; 32]), None);
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_serde_roundtrip() {
+		let elem = FieldElement::from_u64(42);
+		let json = serde_json::to_string(&elem).unwrap();
+		assert_eq!(serde_json::from_str::<FieldElement>(&json).unwrap(), elem);
+	}
+
+	#[cfg(feature = "substrate")]
+	#[test]
+	fn test_scale_rejects_non_canonical() {
+		use parity_scale_codec::{Decode, Encode};
+
+		let elem = FieldElement::from_u64(42);
+		let encoded = elem.encode();
+		assert_eq!(encoded, elem.to_le_bytes().to_vec());
+		assert_eq!(FieldElement::decode(&mut &encoded[..]).unwrap(), elem);
+		assert!(FieldElement::decode(&mut &[0xffu8; 32][..]).is_err());
+	}
+
+	// ===== Conversion Tests =====
+
+	/// r - 1, the largest canonical element
+	const MODULUS_MINUS_ONE_HEX: &str =
+		"0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000";
+	const MODULUS_HEX: &str = "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+	const MODULUS_DECIMAL: &str =
+		"21888242871839275222246405745257275088548364400416034343698204186575808495617";
+
+	#[test]
+	fn test_hex_roundtrip() {
+		let elem = FieldElement::from_u64(0xabcd);
+		let hex = elem.to_hex();
+		assert_eq!(hex.len(), 66);
+		assert!(hex.ends_with("abcd"));
+		assert_eq!(FieldElement::from_hex(&hex), Ok(elem));
+		assert_eq!(FieldElement::from_hex("abcd"), Ok(elem));
+		assert_eq!(FieldElement::from_hex("0xABCD"), Ok(elem));
+		assert_eq!(FieldElement::from_hex("0x1"), Ok(FieldElement::from_u64(1)));
+	}
+
+	#[test]
+	fn test_hex_canonical_range() {
+		let max = FieldElement::from_hex(MODULUS_MINUS_ONE_HEX).unwrap();
+		assert_eq!(max.to_hex(), MODULUS_MINUS_ONE_HEX);
+		assert_eq!(
+			FieldElement::from_hex(MODULUS_HEX),
+			Err(FieldElementError::NonCanonical)
+		);
+	}
+
+	#[test]
+	fn test_hex_rejects_malformed() {
+		assert_eq!(
+			FieldElement::from_hex(""),
+			Err(FieldElementError::InvalidLength)
+		);
+		assert_eq!(
+			FieldElement::from_hex("0x"),
+			Err(FieldElementError::InvalidLength)
+		);
+		assert_eq!(
+			FieldElement::from_hex(&format!("0x0{}", &MODULUS_MINUS_ONE_HEX[2..])),
+			Err(FieldElementError::InvalidLength)
+		);
+		assert_eq!(
+			FieldElement::from_hex("0x12g4"),
+			Err(FieldElementError::InvalidCharacter)
+		);
+		assert_eq!(
+			FieldElement::from_hex("+12"),
+			Err(FieldElementError::InvalidCharacter)
+		);
+	}
+
+	#[test]
+	fn test_u256_be_roundtrip() {
+		let elem = FieldElement::from_u64(0x0102);
+		let bytes = elem.to_u256_be();
+		assert_eq!(&bytes[30..], &[0x01, 0x02]);
+		assert_eq!(FieldElement::from_u256_be(&bytes), Ok(elem));
+		assert_eq!(
+			FieldElement::from_u256_be(&[0xff; 32]),
+			Err(FieldElementError::NonCanonical)
+		);
+	}
+
+	#[test]
+	fn test_decimal_str() {
+		assert_eq!(
+			FieldElement::from_decimal_str("0"),
+			Ok(FieldElement::zero())
+		);
+		assert_eq!(
+			FieldElement::from_decimal_str("18446744073709551616"),
+			Ok(FieldElement::new(Fr::from(u64::MAX) + Fr::from(1u64)))
+		);
+		assert_eq!(
+			FieldElement::from_decimal_str(MODULUS_DECIMAL),
+			Err(FieldElementError::NonCanonical)
+		);
+		assert_eq!(
+			FieldElement::from_decimal_str(&"9".repeat(78)),
+			Err(FieldElementError::NonCanonical)
+		);
+		assert_eq!(
+			FieldElement::from_decimal_str(""),
+			Err(FieldElementError::InvalidLength)
+		);
+		assert_eq!(
+			FieldElement::from_decimal_str("-1"),
+			Err(FieldElementError::InvalidCharacter)
+		);
+		assert_eq!(
+			FieldElement::from_decimal_str("0x10"),
+			Err(FieldElementError::InvalidCharacter)
+		);
+	}
+
+	#[test]
+	fn test_decimal_matches_hex() {
+		let max = FieldElement::from_hex(MODULUS_MINUS_ONE_HEX).unwrap();
+		assert_eq!(
+			FieldElement::from_decimal_str(
+				"21888242871839275222246405745257275088548364400416034343698204186575808495616"
+			),
+			Ok(max)
+		);
+	}
 }
@@ -0,0 +1,62 @@
+//! Hash Scheme Value Object
+//!
+//! Selects the algebraic hash behind the `PoseidonHasher` port. Poseidon is the
+//! production scheme; Rescue-Prime (`rescue-prime` feature) exists so research
+//! deployments can compare proving costs on the same circuits.
+
+#[cfg(feature = "substrate")]
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+#[cfg(feature = "substrate")]
+use scale_info::TypeInfo;
+
+/// Algebraic hash used for commitments, nullifiers and Merkle nodes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "substrate", derive(Encode, Decode, MaxEncodedLen, TypeInfo))]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum HashScheme {
+	/// circomlib-compatible Poseidon (production)
+	#[default]
+	Poseidon,
+	/// Rescue-Prime over BN254 (research only; not accepted on-chain)
+	RescuePrime,
+}
+
+impl HashScheme {
+	/// Every scheme, production first
+	pub const ALL: [Self; 2] = [Self::Poseidon, Self::RescuePrime];
+
+	/// Numeric scheme identifier
+	pub fn as_u8(&self) -> u8 {
+		match self {
+			Self::Poseidon => 0,
+			Self::RescuePrime => 1,
+		}
+	}
+
+	/// Parses a numeric scheme identifier
+	pub fn from_u8(scheme: u8) -> Option<Self> {
+		match scheme {
+			0 => Some(Self::Poseidon),
+			1 => Some(Self::RescuePrime),
+			_ => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_u8_roundtrip() {
+		for scheme in HashScheme::ALL {
+			assert_eq!(HashScheme::from_u8(scheme.as_u8()), Some(scheme));
+		}
+		assert_eq!(HashScheme::from_u8(2), None);
+	}
+
+	#[test]
+	fn test_poseidon_is_default() {
+		assert_eq!(HashScheme::default(), HashScheme::Poseidon);
+	}
+}
@@ -1,13 +1,27 @@
 pub mod blinding;
 pub mod commitment;
+pub mod diversified_address;
+pub mod extended_spending_key;
 pub mod field_element;
+pub mod hash_scheme;
 pub mod nullifier;
+pub mod nullifier_version;
 pub mod owner_pubkey;
+pub mod payment_request;
+pub mod shielded_address;
 pub mod spending_key;
 
 pub use blinding::Blinding;
 pub use commitment::Commitment;
-pub use field_element::FieldElement;
+pub use diversified_address::DiversifiedAddress;
+pub use extended_spending_key::{ExtendedSpendingKey, KeyDerivationError};
+pub use field_element::{FieldElement, FieldElementError};
+pub use hash_scheme::HashScheme;
 pub use nullifier::Nullifier;
+pub use nullifier_version::{NullifierVersion, NULLIFIER_DOMAIN_V2};
 pub use owner_pubkey::OwnerPubkey;
+pub use payment_request::{
+	PaymentRequest, PaymentRequestError, MAX_PAYMENT_REQUEST_MEMO_LENGTH, PAYMENT_REQUEST_SCHEME,
+};
+pub use shielded_address::{AddressError, ShieldedAddress};
 pub use spending_key::SpendingKey;
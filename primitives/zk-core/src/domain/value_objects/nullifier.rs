@@ -4,11 +4,17 @@
 
 use crate::domain::value_objects::field_element::FieldElement;
 use ark_bn254::Fr;
+#[cfg(feature = "substrate")]
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+#[cfg(feature = "substrate")]
+use scale_info::TypeInfo;
 
 /// Nullifier that marks a note as spent
 ///
 /// Computed as: `nullifier = Poseidon(commitment, spending_key)`
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "substrate", derive(Encode, Decode, MaxEncodedLen, TypeInfo))]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nullifier(FieldElement);
 
 impl Nullifier {
@@ -385,4 +391,14 @@ mod tests {
 			Nullifier(_) => {}
 		}
 	}
+
+	// ===== Codec Tests =====
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_serde_roundtrip() {
+		let nullifier = Nullifier::from(Fr::from(42u64));
+		let json = serde_json::to_string(&nullifier).unwrap();
+		assert_eq!(serde_json::from_str::<Nullifier>(&json).unwrap(), nullifier);
+	}
 }
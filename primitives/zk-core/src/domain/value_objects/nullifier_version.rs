@@ -0,0 +1,98 @@
+//! Nullifier Version Value Object
+//!
+//! Versioned nullifier derivations, so a new preimage (e.g. adding the leaf
+//! position) can run alongside the old one during a migration window instead
+//! of invalidating every existing note at once.
+
+use crate::domain::value_objects::field_element::FieldElement;
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+#[cfg(feature = "substrate")]
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+#[cfg(feature = "substrate")]
+use scale_info::TypeInfo;
+
+/// Domain separation tag for version 2 nullifiers
+pub const NULLIFIER_DOMAIN_V2: &[u8] = b"orbinum-nullifier-v2";
+
+/// Nullifier derivation version
+///
+/// - `V1`: `Poseidon(commitment, spending_key)` (untagged, all existing notes)
+/// - `V2`: `Poseidon(tag_v2, commitment, spending_key, 0)`; the last slot is
+///   reserved for the next preimage extension
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "substrate", derive(Encode, Decode, MaxEncodedLen, TypeInfo))]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum NullifierVersion {
+	/// Legacy untagged derivation
+	#[default]
+	V1,
+	/// Domain-tagged derivation
+	V2,
+}
+
+impl NullifierVersion {
+	/// Version used for newly spent notes
+	pub const CURRENT: Self = Self::V1;
+
+	/// Every version the chain accepts, oldest first
+	pub const ALL: [Self; 2] = [Self::V1, Self::V2];
+
+	/// Numeric version identifier
+	pub fn as_u8(&self) -> u8 {
+		match self {
+			Self::V1 => 1,
+			Self::V2 => 2,
+		}
+	}
+
+	/// Parses a numeric version identifier
+	pub fn from_u8(version: u8) -> Option<Self> {
+		match version {
+			1 => Some(Self::V1),
+			2 => Some(Self::V2),
+			_ => None,
+		}
+	}
+
+	/// Domain tag absorbed into the preimage (`None` for the untagged V1)
+	pub fn domain_tag(&self) -> Option<FieldElement> {
+		match self {
+			Self::V1 => None,
+			Self::V2 => Some(FieldElement::new(Fr::from_le_bytes_mod_order(
+				NULLIFIER_DOMAIN_V2,
+			))),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_u8_roundtrip() {
+		for version in NullifierVersion::ALL {
+			assert_eq!(NullifierVersion::from_u8(version.as_u8()), Some(version));
+		}
+		assert_eq!(NullifierVersion::from_u8(0), None);
+		assert_eq!(NullifierVersion::from_u8(3), None);
+	}
+
+	#[test]
+	fn test_domain_tags() {
+		assert_eq!(NullifierVersion::V1.domain_tag(), None);
+		let tag = NullifierVersion::V2.domain_tag().unwrap();
+		assert!(!tag.is_zero());
+		// The tag fits in 31 bytes, so it is its own canonical encoding
+		assert_eq!(
+			&tag.to_le_bytes()[..NULLIFIER_DOMAIN_V2.len()],
+			NULLIFIER_DOMAIN_V2
+		);
+	}
+
+	#[test]
+	fn test_current_is_default() {
+		assert_eq!(NullifierVersion::default(), NullifierVersion::CURRENT);
+	}
+}
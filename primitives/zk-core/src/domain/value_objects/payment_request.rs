@@ -0,0 +1,465 @@
+//! Payment Request Value Object
+//!
+//! `orbinum:` URIs that wallets and merchants exchange (e.g. as QR codes) to
+//! request a shielded payment. Modelled on BIP-21:
+//!
+//! ```text
+//! orbinum:<orb1 address>[?amount=<u64>][&asset=<u64>][&memo=<percent-encoded UTF-8>]
+//! ```
+//!
+//! `encode` always emits parameters in this order, omits `asset` for the
+//! native asset (0) and percent-encodes everything outside the RFC 3986
+//! unreserved set, so a request has exactly one canonical URI. `decode` also
+//! accepts an uppercase scheme (QR alphanumeric mode), any parameter order and
+//! unknown parameters, except unknown `req-` parameters, which must be
+//! rejected.
+
+use crate::domain::value_objects::{AddressError, ShieldedAddress};
+use alloc::{
+	string::{String, ToString},
+	vec::Vec,
+};
+
+/// URI scheme of payment requests
+pub const PAYMENT_REQUEST_SCHEME: &str = "orbinum";
+
+/// Maximum memo text length in bytes (UTF-8, before percent-encoding)
+pub const MAX_PAYMENT_REQUEST_MEMO_LENGTH: usize = 512;
+
+/// Errors that can occur when decoding a payment request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaymentRequestError {
+	/// URI does not start with `orbinum:`
+	InvalidScheme,
+	/// Recipient address is malformed
+	InvalidAddress(AddressError),
+	/// Amount is not a plain decimal `u64`
+	InvalidAmount,
+	/// Asset is not a plain decimal `u64`
+	InvalidAsset,
+	/// Malformed percent-encoding or memo is not UTF-8
+	InvalidEncoding,
+	/// Memo longer than [`MAX_PAYMENT_REQUEST_MEMO_LENGTH`] bytes
+	MemoTooLong(usize),
+	/// Parameter given more than once
+	DuplicateParameter(String),
+	/// Unknown `req-` parameter
+	UnsupportedRequiredParameter(String),
+}
+
+impl core::fmt::Display for PaymentRequestError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			PaymentRequestError::InvalidScheme => write!(f, "Invalid payment request scheme"),
+			PaymentRequestError::InvalidAddress(e) => {
+				write!(f, "Invalid payment request address: {e}")
+			}
+			PaymentRequestError::InvalidAmount => write!(f, "Invalid payment request amount"),
+			PaymentRequestError::InvalidAsset => write!(f, "Invalid payment request asset"),
+			PaymentRequestError::InvalidEncoding => {
+				write!(f, "Invalid percent-encoding in payment request")
+			}
+			PaymentRequestError::MemoTooLong(len) => {
+				write!(f, "Payment request memo too long: {len} bytes")
+			}
+			PaymentRequestError::DuplicateParameter(name) => {
+				write!(f, "Duplicate payment request parameter: {name}")
+			}
+			PaymentRequestError::UnsupportedRequiredParameter(name) => {
+				write!(f, "Unsupported required parameter: {name}")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PaymentRequestError {}
+
+impl From<AddressError> for PaymentRequestError {
+	fn from(e: AddressError) -> Self {
+		PaymentRequestError::InvalidAddress(e)
+	}
+}
+
+/// Machine-readable shielded payment request
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentRequest {
+	/// Recipient address
+	pub address: ShieldedAddress,
+	/// Requested asset (0 = native)
+	pub asset_id: u64,
+	/// Requested amount; `None` lets the payer choose
+	pub amount: Option<u64>,
+	/// Free-form text for the payer (e.g. an invoice reference)
+	pub memo: Option<String>,
+}
+
+impl PaymentRequest {
+	/// Create a request for any amount of the native asset
+	pub fn new(address: ShieldedAddress) -> Self {
+		Self {
+			address,
+			asset_id: 0,
+			amount: None,
+			memo: None,
+		}
+	}
+
+	/// Set the requested amount
+	pub fn with_amount(mut self, amount: u64) -> Self {
+		self.amount = Some(amount);
+		self
+	}
+
+	/// Set the requested asset
+	pub fn with_asset(mut self, asset_id: u64) -> Self {
+		self.asset_id = asset_id;
+		self
+	}
+
+	/// Set the memo text
+	pub fn with_memo(mut self, memo: impl Into<String>) -> Result<Self, PaymentRequestError> {
+		let memo = memo.into();
+		if memo.len() > MAX_PAYMENT_REQUEST_MEMO_LENGTH {
+			return Err(PaymentRequestError::MemoTooLong(memo.len()));
+		}
+		self.memo = Some(memo);
+		Ok(self)
+	}
+
+	/// Encodes as the canonical `orbinum:` URI
+	pub fn encode(&self) -> String {
+		let mut uri = String::from(PAYMENT_REQUEST_SCHEME);
+		uri.push(':');
+		uri.push_str(&self.address.encode());
+
+		let mut separator = '?';
+		let mut push_param = |uri: &mut String, name: &str, value: &str| {
+			uri.push(separator);
+			uri.push_str(name);
+			uri.push('=');
+			uri.push_str(value);
+			separator = '&';
+		};
+
+		if let Some(amount) = self.amount {
+			push_param(&mut uri, "amount", &amount.to_string());
+		}
+		if self.asset_id != 0 {
+			push_param(&mut uri, "asset", &self.asset_id.to_string());
+		}
+		if let Some(memo) = &self.memo {
+			push_param(&mut uri, "memo", &percent_encode(memo));
+		}
+		uri
+	}
+
+	/// Decodes an `orbinum:` URI
+	pub fn decode(uri: &str) -> Result<Self, PaymentRequestError> {
+		let (scheme, rest) = uri
+			.split_once(':')
+			.ok_or(PaymentRequestError::InvalidScheme)?;
+		if !scheme.eq_ignore_ascii_case(PAYMENT_REQUEST_SCHEME) {
+			return Err(PaymentRequestError::InvalidScheme);
+		}
+
+		let (address, query) = match rest.split_once('?') {
+			Some((address, query)) => (address, Some(query)),
+			None => (rest, None),
+		};
+		let mut request = Self::new(ShieldedAddress::decode(address)?);
+
+		let mut seen: Vec<&str> = Vec::new();
+		for param in query.into_iter().flat_map(|q| q.split('&')) {
+			let (name, value) = param.split_once('=').unwrap_or((param, ""));
+			if seen.contains(&name) {
+				return Err(PaymentRequestError::DuplicateParameter(name.into()));
+			}
+			seen.push(name);
+
+			match name {
+				"amount" => {
+					request.amount =
+						Some(parse_decimal(value).ok_or(PaymentRequestError::InvalidAmount)?)
+				}
+				"asset" => {
+					request.asset_id =
+						parse_decimal(value).ok_or(PaymentRequestError::InvalidAsset)?
+				}
+				"memo" => request = request.with_memo(percent_decode(value)?)?,
+				_ if name.starts_with("req-") => {
+					return Err(PaymentRequestError::UnsupportedRequiredParameter(
+						name.into(),
+					))
+				}
+				_ => {}
+			}
+		}
+
+		Ok(request)
+	}
+}
+
+impl core::fmt::Display for PaymentRequest {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(&self.encode())
+	}
+}
+
+impl core::str::FromStr for PaymentRequest {
+	type Err = PaymentRequestError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::decode(s)
+	}
+}
+
+/// Parses plain decimal digits (no sign, no whitespace)
+fn parse_decimal(value: &str) -> Option<u64> {
+	if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+	value.parse().ok()
+}
+
+fn percent_encode(text: &str) -> String {
+	const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+	let mut encoded = String::with_capacity(text.len());
+	for byte in text.bytes() {
+		if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+			encoded.push(byte as char);
+		} else {
+			encoded.push('%');
+			encoded.push(HEX[(byte >> 4) as usize] as char);
+			encoded.push(HEX[(byte & 0x0f) as usize] as char);
+		}
+	}
+	encoded
+}
+
+fn percent_decode(text: &str) -> Result<String, PaymentRequestError> {
+	let bytes = text.as_bytes();
+	let mut decoded = Vec::with_capacity(bytes.len());
+	let mut i = 0;
+
+	while i < bytes.len() {
+		if bytes[i] == b'%' {
+			let hex = bytes
+				.get(i + 1..i + 3)
+				.and_then(|hex| core::str::from_utf8(hex).ok())
+				.filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+				.ok_or(PaymentRequestError::InvalidEncoding)?;
+			decoded.push(
+				u8::from_str_radix(hex, 16).map_err(|_| PaymentRequestError::InvalidEncoding)?,
+			);
+			i += 3;
+		} else {
+			decoded.push(bytes[i]);
+			i += 1;
+		}
+	}
+
+	String::from_utf8(decoded).map_err(|_| PaymentRequestError::InvalidEncoding)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::value_objects::{FieldElement, OwnerPubkey};
+	use alloc::format;
+
+	fn address() -> ShieldedAddress {
+		ShieldedAddress::new(
+			OwnerPubkey::from_u64(12345),
+			FieldElement::from_u64(678),
+			None,
+		)
+	}
+
+	fn full_request() -> PaymentRequest {
+		PaymentRequest::new(address())
+			.with_amount(1_500)
+			.with_asset(7)
+			.with_memo("Invoice #42 — café")
+			.unwrap()
+	}
+
+	// ===== Encoding Tests =====
+
+	#[test]
+	fn test_minimal_request() {
+		let request = PaymentRequest::new(address());
+		let uri = request.encode();
+
+		assert_eq!(uri, format!("orbinum:{}", address().encode()));
+		assert_eq!(PaymentRequest::decode(&uri), Ok(request));
+	}
+
+	#[test]
+	fn test_canonical_encoding() {
+		assert_eq!(
+			full_request().encode(),
+			format!(
+				"orbinum:{}?amount=1500&asset=7&memo=Invoice%20%2342%20%E2%80%94%20caf%C3%A9",
+				address().encode()
+			)
+		);
+	}
+
+	#[test]
+	fn test_native_asset_omitted() {
+		let uri = PaymentRequest::new(address()).with_amount(5).encode();
+		assert!(uri.ends_with("?amount=5"));
+	}
+
+	#[test]
+	fn test_roundtrip() {
+		let request = full_request();
+		assert_eq!(request.encode().parse::<PaymentRequest>(), Ok(request));
+	}
+
+	#[test]
+	fn test_roundtrip_with_viewing_key() {
+		let address = ShieldedAddress::new(
+			OwnerPubkey::from_u64(1),
+			FieldElement::from_u64(2),
+			Some(FieldElement::from_u64(3)),
+		);
+		let request = PaymentRequest::new(address).with_amount(u64::MAX);
+		assert_eq!(PaymentRequest::decode(&request.encode()), Ok(request));
+	}
+
+	#[test]
+	fn test_percent_encoding_reserved_characters() {
+		let memo = "a&b=c?d%e+f/g";
+		let request = PaymentRequest::new(address()).with_memo(memo).unwrap();
+		let uri = request.encode();
+
+		assert!(uri.ends_with("memo=a%26b%3Dc%3Fd%25e%2Bf%2Fg"));
+		assert_eq!(
+			PaymentRequest::decode(&uri).unwrap().memo.as_deref(),
+			Some(memo)
+		);
+	}
+
+	// ===== Decoding Tests =====
+
+	#[test]
+	fn test_uppercase_qr_form_accepted() {
+		let uri = format!(
+			"ORBINUM:{}?amount=10",
+			address().encode().to_ascii_uppercase()
+		);
+		let request = PaymentRequest::decode(&uri).unwrap();
+
+		assert_eq!(request.address, address());
+		assert_eq!(request.amount, Some(10));
+	}
+
+	#[test]
+	fn test_parameter_order_and_unknown_parameters() {
+		let uri = format!(
+			"orbinum:{}?label=shop&memo=hi&asset=3&amount=9",
+			address().encode()
+		);
+		let request = PaymentRequest::decode(&uri).unwrap();
+
+		assert_eq!(request.amount, Some(9));
+		assert_eq!(request.asset_id, 3);
+		assert_eq!(request.memo.as_deref(), Some("hi"));
+	}
+
+	// ===== Decoding Error Tests =====
+
+	#[test]
+	fn test_invalid_scheme_rejected() {
+		let uri = format!("bitcoin:{}", address().encode());
+		assert_eq!(
+			PaymentRequest::decode(&uri),
+			Err(PaymentRequestError::InvalidScheme)
+		);
+		assert_eq!(
+			PaymentRequest::decode("orb1qqqq"),
+			Err(PaymentRequestError::InvalidScheme)
+		);
+	}
+
+	#[test]
+	fn test_invalid_address_rejected() {
+		assert_eq!(
+			PaymentRequest::decode("orbinum:orb1bqqqqqqq"),
+			Err(PaymentRequestError::InvalidAddress(
+				AddressError::InvalidCharacter('b')
+			))
+		);
+	}
+
+	#[test]
+	fn test_invalid_amount_rejected() {
+		for amount in ["", "-1", "+1", "1.5", "18446744073709551616"] {
+			let uri = format!("orbinum:{}?amount={amount}", address().encode());
+			assert_eq!(
+				PaymentRequest::decode(&uri),
+				Err(PaymentRequestError::InvalidAmount),
+				"amount {amount:?}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_invalid_asset_rejected() {
+		let uri = format!("orbinum:{}?asset=native", address().encode());
+		assert_eq!(
+			PaymentRequest::decode(&uri),
+			Err(PaymentRequestError::InvalidAsset)
+		);
+	}
+
+	#[test]
+	fn test_duplicate_parameter_rejected() {
+		let uri = format!("orbinum:{}?amount=1&amount=2", address().encode());
+		assert_eq!(
+			PaymentRequest::decode(&uri),
+			Err(PaymentRequestError::DuplicateParameter("amount".into()))
+		);
+	}
+
+	#[test]
+	fn test_unknown_required_parameter_rejected() {
+		let uri = format!("orbinum:{}?req-expiry=100", address().encode());
+		assert_eq!(
+			PaymentRequest::decode(&uri),
+			Err(PaymentRequestError::UnsupportedRequiredParameter(
+				"req-expiry".into()
+			))
+		);
+	}
+
+	#[test]
+	fn test_malformed_percent_encoding_rejected() {
+		for memo in ["%", "%4", "%zz", "%FF"] {
+			let uri = format!("orbinum:{}?memo={memo}", address().encode());
+			assert_eq!(
+				PaymentRequest::decode(&uri),
+				Err(PaymentRequestError::InvalidEncoding),
+				"memo {memo:?}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_memo_too_long_rejected() {
+		let memo = "x".repeat(MAX_PAYMENT_REQUEST_MEMO_LENGTH + 1);
+		assert_eq!(
+			PaymentRequest::new(address()).with_memo(memo.clone()),
+			Err(PaymentRequestError::MemoTooLong(memo.len()))
+		);
+
+		let uri = format!("orbinum:{}?memo={memo}", address().encode());
+		assert_eq!(
+			PaymentRequest::decode(&uri),
+			Err(PaymentRequestError::MemoTooLong(memo.len()))
+		);
+	}
+}
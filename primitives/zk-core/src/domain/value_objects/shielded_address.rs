@@ -0,0 +1,430 @@
+//! Shielded Address Value Object
+//!
+//! Canonical human-readable address format (`orb1...`) shared by wallets, RPC
+//! responses and the memo format. Bech32m (BIP-350) over the payload
+//!
+//! ```text
+//! owner_pubkey (32) || diversifier (32) [|| viewing_key (32)]
+//! ```
+//!
+//! with field elements little-endian. As with Zcash unified addresses, the
+//! 90-character Bech32 length limit does not apply.
+
+use crate::domain::value_objects::{DiversifiedAddress, FieldElement, OwnerPubkey};
+use alloc::{string::String, vec::Vec};
+
+/// Human-readable part of shielded addresses
+pub const ADDRESS_HRP: &str = "orb";
+
+/// Payload length without the viewing component
+pub const ADDRESS_PAYLOAD_LENGTH: usize = 64;
+
+/// Payload length with the viewing component
+pub const ADDRESS_PAYLOAD_LENGTH_WITH_VIEWING_KEY: usize = 96;
+
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+const CHECKSUM_LENGTH: usize = 6;
+
+/// Errors that can occur when decoding a shielded address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+	/// Missing `1` separator or empty human-readable part
+	MissingSeparator,
+	/// Human-readable part is not `orb`
+	InvalidHrp,
+	/// Character outside the Bech32 charset
+	InvalidCharacter(char),
+	/// Both upper- and lowercase characters
+	MixedCase,
+	/// Bech32m checksum does not match
+	InvalidChecksum,
+	/// Non-zero padding bits in the data part
+	InvalidPadding,
+	/// Payload is neither 64 nor 96 bytes
+	InvalidLength(usize),
+	/// Field element is not canonically encoded
+	NonCanonicalField,
+}
+
+impl core::fmt::Display for AddressError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			AddressError::MissingSeparator => write!(f, "Missing address separator"),
+			AddressError::InvalidHrp => write!(f, "Invalid address prefix"),
+			AddressError::InvalidCharacter(c) => write!(f, "Invalid address character: {c}"),
+			AddressError::MixedCase => write!(f, "Mixed-case address"),
+			AddressError::InvalidChecksum => write!(f, "Invalid address checksum"),
+			AddressError::InvalidPadding => write!(f, "Invalid address padding"),
+			AddressError::InvalidLength(len) => write!(f, "Invalid address payload length: {len}"),
+			AddressError::NonCanonicalField => write!(f, "Non-canonical field element in address"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AddressError {}
+
+/// Shielded receiving address
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ShieldedAddress {
+	/// Owner public key used in note commitments
+	pub owner_pubkey: OwnerPubkey,
+	/// Diversifier the owner key was derived with
+	pub diversifier: FieldElement,
+	/// Optional viewing component for memo encryption / audit
+	pub viewing_key: Option<FieldElement>,
+}
+
+impl ShieldedAddress {
+	/// Create a new shielded address
+	pub fn new(
+		owner_pubkey: OwnerPubkey,
+		diversifier: FieldElement,
+		viewing_key: Option<FieldElement>,
+	) -> Self {
+		Self {
+			owner_pubkey,
+			diversifier,
+			viewing_key,
+		}
+	}
+
+	/// Raw payload (64 or 96 bytes)
+	pub fn to_payload(&self) -> Vec<u8> {
+		let mut payload = Vec::with_capacity(ADDRESS_PAYLOAD_LENGTH_WITH_VIEWING_KEY);
+		payload.extend_from_slice(&self.owner_pubkey.inner().to_le_bytes());
+		payload.extend_from_slice(&self.diversifier.to_le_bytes());
+		if let Some(viewing_key) = &self.viewing_key {
+			payload.extend_from_slice(&viewing_key.to_le_bytes());
+		}
+		payload
+	}
+
+	/// Parses a raw payload, rejecting non-canonical field elements
+	pub fn from_payload(payload: &[u8]) -> Result<Self, AddressError> {
+		let viewing_key = match payload.len() {
+			ADDRESS_PAYLOAD_LENGTH => None,
+			ADDRESS_PAYLOAD_LENGTH_WITH_VIEWING_KEY => Some(canonical_field(&payload[64..96])?),
+			len => return Err(AddressError::InvalidLength(len)),
+		};
+
+		Ok(Self {
+			owner_pubkey: OwnerPubkey::new(canonical_field(&payload[..32])?),
+			diversifier: canonical_field(&payload[32..64])?,
+			viewing_key,
+		})
+	}
+
+	/// Encodes as a lowercase `orb1...` Bech32m string
+	pub fn encode(&self) -> String {
+		let data = convert_bits(&self.to_payload(), 8, 5, true)
+			.expect("8-to-5 conversion with padding cannot fail");
+		let checksum = create_checksum(ADDRESS_HRP.as_bytes(), &data);
+
+		let mut encoded =
+			String::with_capacity(ADDRESS_HRP.len() + 1 + data.len() + CHECKSUM_LENGTH);
+		encoded.push_str(ADDRESS_HRP);
+		encoded.push('1');
+		for value in data.iter().chain(checksum.iter()) {
+			encoded.push(CHARSET[*value as usize] as char);
+		}
+		encoded
+	}
+
+	/// Decodes an `orb1...` string (all lower- or all uppercase)
+	pub fn decode(address: &str) -> Result<Self, AddressError> {
+		let (hrp, data) = split_and_check(address)?;
+		if hrp != ADDRESS_HRP {
+			return Err(AddressError::InvalidHrp);
+		}
+
+		let payload = convert_bits(&data[..data.len() - CHECKSUM_LENGTH], 5, 8, false)
+			.ok_or(AddressError::InvalidPadding)?;
+		Self::from_payload(&payload)
+	}
+}
+
+impl From<DiversifiedAddress> for ShieldedAddress {
+	fn from(address: DiversifiedAddress) -> Self {
+		Self::new(address.owner_pubkey, address.diversifier, None)
+	}
+}
+
+impl core::fmt::Display for ShieldedAddress {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(&self.encode())
+	}
+}
+
+impl core::str::FromStr for ShieldedAddress {
+	type Err = AddressError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::decode(s)
+	}
+}
+
+/// Whether `encoded` is a well-formed Bech32m string (any human-readable part)
+pub fn verify_checksum(encoded: &str) -> bool {
+	split_and_check(encoded).is_ok()
+}
+
+// ============================================================================
+// Bech32m
+// ============================================================================
+
+/// Splits into lowercase HRP and 5-bit values (checksum included) and verifies the checksum
+fn split_and_check(encoded: &str) -> Result<(String, Vec<u8>), AddressError> {
+	let has_lower = encoded.bytes().any(|b| b.is_ascii_lowercase());
+	let has_upper = encoded.bytes().any(|b| b.is_ascii_uppercase());
+	if has_lower && has_upper {
+		return Err(AddressError::MixedCase);
+	}
+
+	let separator = encoded.rfind('1').ok_or(AddressError::MissingSeparator)?;
+	if separator == 0 || encoded.len() - separator - 1 < CHECKSUM_LENGTH {
+		return Err(AddressError::MissingSeparator);
+	}
+
+	let hrp = encoded[..separator].to_ascii_lowercase();
+	if let Some(c) = hrp.chars().find(|c| !('!'..='~').contains(c)) {
+		return Err(AddressError::InvalidCharacter(c));
+	}
+
+	let data = encoded[separator + 1..]
+		.chars()
+		.map(|c| {
+			let lower = c.to_ascii_lowercase();
+			CHARSET
+				.iter()
+				.position(|&b| b as char == lower)
+				.map(|value| value as u8)
+				.ok_or(AddressError::InvalidCharacter(c))
+		})
+		.collect::<Result<Vec<u8>, _>>()?;
+
+	let mut values = hrp_expand(hrp.as_bytes());
+	values.extend_from_slice(&data);
+	if polymod(&values) != BECH32M_CONST {
+		return Err(AddressError::InvalidChecksum);
+	}
+
+	Ok((hrp, data))
+}
+
+fn polymod(values: &[u8]) -> u32 {
+	const GENERATOR: [u32; 5] = [
+		0x3b6a_57b2,
+		0x2650_8e6d,
+		0x1ea1_19fa,
+		0x3d42_33dd,
+		0x2a14_62b3,
+	];
+
+	let mut checksum: u32 = 1;
+	for value in values {
+		let top = checksum >> 25;
+		checksum = ((checksum & 0x01ff_ffff) << 5) ^ u32::from(*value);
+		for (i, generator) in GENERATOR.iter().enumerate() {
+			if (top >> i) & 1 == 1 {
+				checksum ^= generator;
+			}
+		}
+	}
+	checksum
+}
+
+fn hrp_expand(hrp: &[u8]) -> Vec<u8> {
+	let mut expanded = Vec::with_capacity(hrp.len() * 2 + 1);
+	expanded.extend(hrp.iter().map(|b| b >> 5));
+	expanded.push(0);
+	expanded.extend(hrp.iter().map(|b| b & 0x1f));
+	expanded
+}
+
+fn create_checksum(hrp: &[u8], data: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+	let mut values = hrp_expand(hrp);
+	values.extend_from_slice(data);
+	values.extend_from_slice(&[0u8; CHECKSUM_LENGTH]);
+	let checksum = polymod(&values) ^ BECH32M_CONST;
+
+	let mut result = [0u8; CHECKSUM_LENGTH];
+	for (i, value) in result.iter_mut().enumerate() {
+		*value = ((checksum >> (5 * (5 - i))) & 0x1f) as u8;
+	}
+	result
+}
+
+/// Regroups bits; `None` on invalid input or non-zero/excess padding
+fn convert_bits(data: &[u8], from: u32, to: u32, pad: bool) -> Option<Vec<u8>> {
+	let max_value = (1u32 << to) - 1;
+	let mut accumulator: u32 = 0;
+	let mut bits: u32 = 0;
+	let mut result = Vec::with_capacity(data.len() * from as usize / to as usize + 1);
+
+	for value in data {
+		let value = u32::from(*value);
+		if value >> from != 0 {
+			return None;
+		}
+		accumulator = (accumulator << from) | value;
+		bits += from;
+		while bits >= to {
+			bits -= to;
+			result.push(((accumulator >> bits) & max_value) as u8);
+		}
+	}
+
+	if pad {
+		if bits > 0 {
+			result.push(((accumulator << (to - bits)) & max_value) as u8);
+		}
+	} else if bits >= from || (accumulator << (to - bits)) & max_value != 0 {
+		return None;
+	}
+
+	Some(result)
+}
+
+fn canonical_field(bytes: &[u8]) -> Result<FieldElement, AddressError> {
+	bytes
+		.try_into()
+		.ok()
+		.and_then(FieldElement::from_canonical_le_bytes)
+		.ok_or(AddressError::NonCanonicalField)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sample(viewing_key: Option<u64>) -> ShieldedAddress {
+		ShieldedAddress::new(
+			OwnerPubkey::from_u64(12345),
+			FieldElement::from_u64(678),
+			viewing_key.map(FieldElement::from_u64),
+		)
+	}
+
+	// ===== Bech32m Tests =====
+
+	#[test]
+	fn test_bip350_vectors() {
+		assert!(verify_checksum("A1LQFN3A"));
+		assert!(verify_checksum("a1lqfn3a"));
+		assert!(verify_checksum(
+			"abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx"
+		));
+		assert!(verify_checksum(
+			"split1checkupstagehandshakeupstreamerranterredcaperredlc445v"
+		));
+		// Bech32 (not Bech32m) checksum
+		assert!(!verify_checksum("a12uel5l"));
+		assert!(!verify_checksum("1lqfn3a"));
+	}
+
+	// ===== Encoding Tests =====
+
+	#[test]
+	fn test_roundtrip_without_viewing_key() {
+		let address = sample(None);
+		let encoded = address.encode();
+
+		assert!(encoded.starts_with("orb1"));
+		assert!(verify_checksum(&encoded));
+		assert_eq!(ShieldedAddress::decode(&encoded).unwrap(), address);
+	}
+
+	#[test]
+	fn test_roundtrip_with_viewing_key() {
+		let address = sample(Some(99));
+		let encoded = address.encode();
+
+		assert!(encoded.len() > sample(None).encode().len());
+		assert_eq!(ShieldedAddress::decode(&encoded).unwrap(), address);
+		assert_eq!(encoded.parse::<ShieldedAddress>().unwrap(), address);
+	}
+
+	#[test]
+	fn test_uppercase_accepted() {
+		let encoded = sample(None).encode().to_ascii_uppercase();
+		assert_eq!(ShieldedAddress::decode(&encoded).unwrap(), sample(None));
+	}
+
+	#[test]
+	fn test_from_diversified_address() {
+		let diversified =
+			DiversifiedAddress::new(3, FieldElement::from_u64(7), OwnerPubkey::from_u64(8));
+		let address = ShieldedAddress::from(diversified);
+		assert_eq!(address.owner_pubkey, diversified.owner_pubkey);
+		assert_eq!(address.viewing_key, None);
+	}
+
+	// ===== Decoding Error Tests =====
+
+	#[test]
+	fn test_mixed_case_rejected() {
+		let mut encoded = sample(None).encode();
+		encoded.replace_range(..1, "O");
+		assert_eq!(
+			ShieldedAddress::decode(&encoded),
+			Err(AddressError::MixedCase)
+		);
+	}
+
+	#[test]
+	fn test_corrupted_character_rejected() {
+		let encoded = sample(None).encode();
+		let mut bytes = encoded.into_bytes();
+		bytes[10] = if bytes[10] == b'q' { b'p' } else { b'q' };
+		let corrupted = String::from_utf8(bytes).unwrap();
+
+		assert_eq!(
+			ShieldedAddress::decode(&corrupted),
+			Err(AddressError::InvalidChecksum)
+		);
+	}
+
+	#[test]
+	fn test_wrong_hrp_rejected() {
+		let data = convert_bits(&sample(None).to_payload(), 8, 5, true).unwrap();
+		let checksum = create_checksum(b"zcash", &data);
+		let mut encoded = String::from("zcash1");
+		for value in data.iter().chain(checksum.iter()) {
+			encoded.push(CHARSET[*value as usize] as char);
+		}
+
+		assert!(verify_checksum(&encoded));
+		assert_eq!(
+			ShieldedAddress::decode(&encoded),
+			Err(AddressError::InvalidHrp)
+		);
+	}
+
+	#[test]
+	fn test_invalid_character_rejected() {
+		assert_eq!(
+			ShieldedAddress::decode("orb1bqqqqqqq"),
+			Err(AddressError::InvalidCharacter('b'))
+		);
+	}
+
+	#[test]
+	fn test_invalid_payload_length() {
+		assert_eq!(
+			ShieldedAddress::from_payload(&[0u8; 63]),
+			Err(AddressError::InvalidLength(63))
+		);
+	}
+
+	#[test]
+	fn test_non_canonical_field_rejected() {
+		let mut payload = sample(None).to_payload();
+		payload[..32].copy_from_slice(&[0xff; 32]);
+		assert_eq!(
+			ShieldedAddress::from_payload(&payload),
+			Err(AddressError::NonCanonicalField)
+		);
+	}
+}
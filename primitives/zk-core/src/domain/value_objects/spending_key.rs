@@ -4,11 +4,15 @@
 
 use crate::domain::value_objects::field_element::FieldElement;
 use ark_bn254::Fr;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::Zeroize;
 
 /// Private spending key for spending notes
 ///
 /// Used to compute nullifier: `nullifier = Poseidon(commitment, spending_key)`
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+///
+/// Compared in constant time. `Copy`, so callers zeroize their own copies.
+#[derive(Clone, Copy, Debug)]
 pub struct SpendingKey(FieldElement);
 
 impl SpendingKey {
@@ -52,6 +56,26 @@ impl From<SpendingKey> for Fr {
 	}
 }
 
+impl ConstantTimeEq for SpendingKey {
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.0.ct_eq(&other.0)
+	}
+}
+
+impl PartialEq for SpendingKey {
+	fn eq(&self, other: &Self) -> bool {
+		self.ct_eq(other).into()
+	}
+}
+
+impl Eq for SpendingKey {}
+
+impl Zeroize for SpendingKey {
+	fn zeroize(&mut self) {
+		self.0.zeroize();
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -357,4 +381,23 @@ mod tests {
 		let key2 = SpendingKey::from(Fr::from(2u64));
 		assert_ne!(key1, key2);
 	}
+
+	// ===== Secret Hygiene Tests =====
+
+	#[test]
+	fn test_ct_eq() {
+		assert!(bool::from(
+			SpendingKey::from_u64(7).ct_eq(&SpendingKey::from_u64(7))
+		));
+		assert!(!bool::from(
+			SpendingKey::from_u64(7).ct_eq(&SpendingKey::from_u64(8))
+		));
+	}
+
+	#[test]
+	fn test_zeroize() {
+		let mut value = SpendingKey::from_u64(7);
+		value.zeroize();
+		assert_eq!(value, SpendingKey::from_u64(0));
+	}
 }
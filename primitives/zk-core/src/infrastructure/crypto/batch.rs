@@ -0,0 +1,175 @@
+//! Batch commitment and nullifier computation.
+//!
+//! For wallets rescanning thousands of candidate notes. Poseidon parameters are
+//! built once per worker instead of once per hash, and with `std` large batches
+//! are split across threads. Results match `Note::commitment` / `Note::nullifier`
+//! element by element.
+
+use crate::domain::{
+	entities::Note,
+	value_objects::{Commitment, FieldElement, Nullifier, SpendingKey},
+};
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use light_poseidon_nostd::{Poseidon, PoseidonHasher as LightHasher};
+
+/// Minimum items per worker thread (smaller batches run on the calling thread)
+#[cfg(feature = "std")]
+const MIN_ITEMS_PER_THREAD: usize = 256;
+
+/// Poseidon instances reused across a batch
+struct BatchHasher {
+	poseidon_2: Poseidon<Fr>,
+	poseidon_4: Poseidon<Fr>,
+}
+
+impl BatchHasher {
+	fn new() -> Self {
+		Self {
+			poseidon_2: Poseidon::<Fr>::new_circom(2)
+				.expect("Failed to initialize Poseidon with 2 inputs"),
+			poseidon_4: Poseidon::<Fr>::new_circom(4)
+				.expect("Failed to initialize Poseidon with 4 inputs"),
+		}
+	}
+
+	/// `Poseidon(value, asset_id, owner_pubkey, blinding)`
+	fn commitment(&mut self, note: &Note) -> Commitment {
+		let hash = self
+			.poseidon_4
+			.hash(&[
+				Fr::from(note.value()),
+				Fr::from(note.asset_id()),
+				note.owner_pubkey().as_fr(),
+				note.blinding().inner().inner(),
+			])
+			.expect("Poseidon hash failed");
+		Commitment::from(FieldElement::new(hash))
+	}
+
+	/// `Poseidon(commitment, spending_key)`
+	fn nullifier(&mut self, note: &Note, spending_key: &SpendingKey) -> Nullifier {
+		let commitment = self.commitment(note);
+		let hash = self
+			.poseidon_2
+			.hash(&[commitment.inner().inner(), spending_key.inner().inner()])
+			.expect("Poseidon hash failed");
+		Nullifier::from(FieldElement::new(hash))
+	}
+}
+
+/// Computes the commitment of every note, in order
+pub fn compute_commitments(notes: &[Note]) -> Vec<Commitment> {
+	map_batch(notes, |hasher, note| hasher.commitment(note))
+}
+
+/// Computes the nullifier of every `(note, spending_key)` pair, in order
+pub fn compute_nullifiers(notes: &[(Note, SpendingKey)]) -> Vec<Nullifier> {
+	map_batch(notes, |hasher, (note, spending_key)| {
+		hasher.nullifier(note, spending_key)
+	})
+}
+
+fn map_chunk<T, R>(items: &[T], f: fn(&mut BatchHasher, &T) -> R) -> Vec<R> {
+	let mut hasher = BatchHasher::new();
+	items.iter().map(|item| f(&mut hasher, item)).collect()
+}
+
+#[cfg(not(feature = "std"))]
+fn map_batch<T, R>(items: &[T], f: fn(&mut BatchHasher, &T) -> R) -> Vec<R> {
+	map_chunk(items, f)
+}
+
+#[cfg(feature = "std")]
+fn map_batch<T: Sync, R: Send>(items: &[T], f: fn(&mut BatchHasher, &T) -> R) -> Vec<R> {
+	let threads = std::thread::available_parallelism()
+		.map(|n| n.get())
+		.unwrap_or(1)
+		.min(items.len() / MIN_ITEMS_PER_THREAD);
+	if threads <= 1 {
+		return map_chunk(items, f);
+	}
+
+	let chunk_size = items.len().div_ceil(threads);
+	std::thread::scope(|scope| {
+		let workers: Vec<_> = items
+			.chunks(chunk_size)
+			.map(|chunk| scope.spawn(move || map_chunk(chunk, f)))
+			.collect();
+
+		let mut results = Vec::with_capacity(items.len());
+		for worker in workers {
+			results.extend(worker.join().expect("Batch worker panicked"));
+		}
+		results
+	})
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		domain::value_objects::{Blinding, OwnerPubkey},
+		infrastructure::crypto::LightPoseidonHasher,
+	};
+
+	fn notes(count: u64) -> Vec<Note> {
+		(0..count)
+			.map(|i| {
+				Note::new(
+					i * 10,
+					i % 3,
+					OwnerPubkey::from(FieldElement::from_u64(1000 + i)),
+					Blinding::from(FieldElement::from_u64(5000 + i)),
+				)
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_empty_batch() {
+		assert!(compute_commitments(&[]).is_empty());
+		assert!(compute_nullifiers(&[]).is_empty());
+	}
+
+	#[test]
+	fn test_commitments_match_single() {
+		let notes = notes(20);
+		let expected: Vec<Commitment> = notes
+			.iter()
+			.map(|note| note.commitment(LightPoseidonHasher))
+			.collect();
+
+		assert_eq!(compute_commitments(&notes), expected);
+	}
+
+	#[test]
+	fn test_nullifiers_match_single() {
+		let pairs: Vec<(Note, SpendingKey)> = notes(20)
+			.into_iter()
+			.enumerate()
+			.map(|(i, note)| (note, SpendingKey::from_u64(i as u64 + 1)))
+			.collect();
+		let expected: Vec<Nullifier> = pairs
+			.iter()
+			.map(|(note, key)| note.nullifier(LightPoseidonHasher, key))
+			.collect();
+
+		assert_eq!(compute_nullifiers(&pairs), expected);
+	}
+
+	#[test]
+	fn test_large_batch_preserves_order() {
+		let notes = notes(2_000);
+		let batch = compute_commitments(&notes);
+
+		assert_eq!(batch.len(), notes.len());
+		for index in [0, 255, 256, 1_023, 1_999] {
+			assert_eq!(batch[index], notes[index].commitment(LightPoseidonHasher));
+		}
+	}
+}
@@ -0,0 +1,404 @@
+//! Incremental Merkle Tree
+//!
+//! Append-only Poseidon Merkle tree that keeps only the frontier (the rightmost
+//! left-child at each level) to compute roots in O(depth) per append. The append
+//! algorithm is the same as `pallet-shielded-pool`'s on-chain tree, so wallets,
+//! the RPC indexer and the pallet agree on every root.
+//!
+//! Leaves are retained (when known) to produce witnesses for any position.
+//! Checkpoints capture the frontier in a serializable form so a tree can be
+//! rewound after a reorg or restored without replaying every leaf.
+
+use super::{bytes_to_field, field_to_bytes, zero_hashes, MerkleTreeError};
+use crate::domain::{ports::PoseidonHasher, repositories::MerklePath, value_objects::FieldElement};
+use alloc::{vec, vec::Vec};
+
+/// Maximum supported depth (positions are `u64`, the pallet uses `u32` indices)
+pub const MAX_DEPTH: usize = 32;
+
+// ============================================================================
+// Checkpoint
+// ============================================================================
+
+/// Serializable snapshot of an incremental tree
+///
+/// Field elements are stored as 32-byte little-endian values, matching the
+/// pallet's storage encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct MerkleCheckpoint {
+	/// Tree depth
+	pub depth: u32,
+	/// Number of leaves at the time of the checkpoint
+	pub size: u64,
+	/// Frontier node per level (`depth` entries)
+	pub frontier: Vec<[u8; 32]>,
+	/// Root at the time of the checkpoint
+	pub root: [u8; 32],
+}
+
+// ============================================================================
+// Incremental Merkle Tree
+// ============================================================================
+
+/// Frontier-based incremental Merkle tree
+#[derive(Debug, Clone)]
+pub struct IncrementalMerkleTree<H: PoseidonHasher> {
+	hasher: H,
+	depth: usize,
+	/// Zero hash per level (`depth + 1` entries)
+	zeros: Vec<FieldElement>,
+	/// Rightmost left-child per level
+	frontier: Vec<FieldElement>,
+	size: u64,
+	root: FieldElement,
+	/// All leaves, or `None` if the tree was restored from a checkpoint
+	leaves: Option<Vec<FieldElement>>,
+}
+
+impl<H: PoseidonHasher> IncrementalMerkleTree<H> {
+	/// Creates an empty tree
+	///
+	/// # Panics
+	/// If `depth` is zero or greater than [`MAX_DEPTH`].
+	pub fn new(hasher: H, depth: usize) -> Self {
+		assert!(
+			depth > 0 && depth <= MAX_DEPTH,
+			"Merkle tree depth must be in 1..={MAX_DEPTH}"
+		);
+
+		let zeros = zero_hashes(&hasher, depth);
+		let root = zeros[depth];
+
+		Self {
+			hasher,
+			depth,
+			frontier: vec![FieldElement::zero(); depth],
+			zeros,
+			size: 0,
+			root,
+			leaves: Some(Vec::new()),
+		}
+	}
+
+	/// Restores a tree from a checkpoint
+	///
+	/// The restored tree can append and compute roots, but can only produce
+	/// witnesses if the checkpoint is empty (earlier leaves are not known).
+	pub fn from_checkpoint(
+		hasher: H,
+		checkpoint: &MerkleCheckpoint,
+	) -> Result<Self, MerkleTreeError> {
+		let depth = checkpoint.depth as usize;
+		if depth == 0 || depth > MAX_DEPTH || checkpoint.frontier.len() != depth {
+			return Err(MerkleTreeError::InvalidCheckpoint);
+		}
+
+		let mut tree = Self::new(hasher, depth);
+		if checkpoint.size > tree.capacity() {
+			return Err(MerkleTreeError::InvalidCheckpoint);
+		}
+
+		tree.restore(checkpoint);
+		if checkpoint.size > 0 {
+			tree.leaves = None;
+		}
+
+		Ok(tree)
+	}
+
+	/// Tree depth
+	pub fn depth(&self) -> usize {
+		self.depth
+	}
+
+	/// Number of leaves appended so far
+	pub fn size(&self) -> u64 {
+		self.size
+	}
+
+	/// Maximum number of leaves
+	pub fn capacity(&self) -> u64 {
+		1u64 << self.depth
+	}
+
+	/// Check if the tree is full
+	pub fn is_full(&self) -> bool {
+		self.size >= self.capacity()
+	}
+
+	/// Current root
+	pub fn root(&self) -> FieldElement {
+		self.root
+	}
+
+	/// Root of an empty tree of this depth
+	pub fn empty_root(&self) -> FieldElement {
+		self.zeros[self.depth]
+	}
+
+	/// Leaf at `position`, if retained
+	pub fn leaf(&self, position: u64) -> Option<FieldElement> {
+		self.leaves
+			.as_ref()
+			.and_then(|leaves| leaves.get(usize::try_from(position).ok()?).copied())
+	}
+
+	/// Appends a leaf and returns its position
+	pub fn append(&mut self, leaf: FieldElement) -> Result<u64, MerkleTreeError> {
+		if self.is_full() {
+			return Err(MerkleTreeError::TreeFull);
+		}
+
+		let position = self.size;
+		let mut current = leaf;
+		let mut index = position;
+
+		for level in 0..self.depth {
+			if index & 1 == 0 {
+				// Left child: becomes the frontier, sibling is still empty
+				self.frontier[level] = current;
+				current = self.hasher.hash_2([current, self.zeros[level]]);
+			} else {
+				// Right child: sibling is the frontier node
+				current = self.hasher.hash_2([self.frontier[level], current]);
+			}
+			index /= 2;
+		}
+
+		self.root = current;
+		self.size += 1;
+		if let Some(leaves) = self.leaves.as_mut() {
+			leaves.push(leaf);
+		}
+
+		Ok(position)
+	}
+
+	/// Authentication path for the leaf at `position` against the current root
+	///
+	/// Siblings are ordered from leaf to root; left/right is given by the bits of
+	/// `position` (bit `i` set = node is the right child at level `i`).
+	pub fn witness(&self, position: u64) -> Result<MerklePath, MerkleTreeError> {
+		if position >= self.size {
+			return Err(MerkleTreeError::PositionOutOfRange(position));
+		}
+		let leaves = self
+			.leaves
+			.as_ref()
+			.ok_or(MerkleTreeError::LeavesUnavailable)?;
+
+		let mut siblings = Vec::with_capacity(self.depth);
+		let mut nodes = leaves.clone();
+		let mut index = position as usize;
+
+		for level in 0..self.depth {
+			let zero = self.zeros[level];
+			siblings.push(nodes.get(index ^ 1).copied().unwrap_or(zero));
+
+			nodes = nodes
+				.chunks(2)
+				.map(|pair| {
+					let right = pair.get(1).copied().unwrap_or(zero);
+					self.hasher.hash_2([pair[0], right])
+				})
+				.collect();
+			index /= 2;
+		}
+
+		Ok(MerklePath::new(position, siblings))
+	}
+
+	/// Snapshot of the current frontier
+	pub fn checkpoint(&self) -> MerkleCheckpoint {
+		MerkleCheckpoint {
+			depth: self.depth as u32,
+			size: self.size,
+			frontier: self.frontier.iter().map(field_to_bytes).collect(),
+			root: field_to_bytes(&self.root),
+		}
+	}
+
+	/// Rewinds the tree to an earlier checkpoint
+	pub fn rewind(&mut self, checkpoint: &MerkleCheckpoint) -> Result<(), MerkleTreeError> {
+		if checkpoint.depth as usize != self.depth
+			|| checkpoint.frontier.len() != self.depth
+			|| checkpoint.size > self.size
+		{
+			return Err(MerkleTreeError::InvalidCheckpoint);
+		}
+
+		self.restore(checkpoint);
+		if let Some(leaves) = self.leaves.as_mut() {
+			leaves.truncate(checkpoint.size as usize);
+		}
+
+		Ok(())
+	}
+
+	fn restore(&mut self, checkpoint: &MerkleCheckpoint) {
+		self.frontier = checkpoint.frontier.iter().map(bytes_to_field).collect();
+		self.size = checkpoint.size;
+		self.root = bytes_to_field(&checkpoint.root);
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{domain::services::merkle_service::MerkleService, Commitment, LightPoseidonHasher};
+
+	const DEPTH: usize = 4;
+
+	fn leaf(value: u64) -> FieldElement {
+		FieldElement::from_u64(value)
+	}
+
+	fn tree_with(count: u64) -> IncrementalMerkleTree<LightPoseidonHasher> {
+		let mut tree = IncrementalMerkleTree::new(LightPoseidonHasher, DEPTH);
+		for i in 0..count {
+			tree.append(leaf(i + 1)).unwrap();
+		}
+		tree
+	}
+
+	/// Root computed over the full, zero-padded tree
+	fn naive_root(leaves: &[FieldElement]) -> FieldElement {
+		let hasher = LightPoseidonHasher;
+		let mut nodes = leaves.to_vec();
+		nodes.resize(1 << DEPTH, FieldElement::zero());
+		while nodes.len() > 1 {
+			nodes = nodes
+				.chunks(2)
+				.map(|pair| hasher.hash_2([pair[0], pair[1]]))
+				.collect();
+		}
+		nodes[0]
+	}
+
+	fn verifies(tree: &IncrementalMerkleTree<LightPoseidonHasher>, position: u64) -> bool {
+		let path = tree.witness(position).unwrap();
+		let indices: Vec<bool> = (0..DEPTH).map(|i| (position >> i) & 1 == 1).collect();
+		MerkleService::new(LightPoseidonHasher).verify_proof(
+			&Commitment::new(tree.leaf(position).unwrap()),
+			&path.siblings,
+			&indices,
+			&tree.root(),
+		)
+	}
+
+	// ===== Root Tests =====
+
+	#[test]
+	fn test_empty_root() {
+		let tree = tree_with(0);
+		assert_eq!(tree.root(), naive_root(&[]));
+		assert_eq!(tree.root(), tree.empty_root());
+	}
+
+	#[test]
+	fn test_roots_match_full_tree() {
+		let mut tree = tree_with(0);
+		let mut leaves = Vec::new();
+		for i in 0..(1u64 << DEPTH) {
+			tree.append(leaf(i + 1)).unwrap();
+			leaves.push(leaf(i + 1));
+			assert_eq!(tree.root(), naive_root(&leaves));
+		}
+	}
+
+	#[test]
+	fn test_append_returns_positions() {
+		let mut tree = tree_with(0);
+		assert_eq!(tree.append(leaf(1)).unwrap(), 0);
+		assert_eq!(tree.append(leaf(2)).unwrap(), 1);
+		assert_eq!(tree.size(), 2);
+	}
+
+	#[test]
+	fn test_full_tree_rejects_append() {
+		let mut tree = tree_with(1 << DEPTH);
+		assert!(tree.is_full());
+		assert_eq!(tree.append(leaf(99)), Err(MerkleTreeError::TreeFull));
+	}
+
+	// ===== Witness Tests =====
+
+	#[test]
+	fn test_witness_verifies_for_every_position() {
+		let tree = tree_with(11);
+		for position in 0..11 {
+			let path = tree.witness(position).unwrap();
+			assert_eq!(path.depth(), DEPTH);
+			assert!(verifies(&tree, position));
+		}
+	}
+
+	#[test]
+	fn test_witness_out_of_range() {
+		let tree = tree_with(3);
+		assert_eq!(tree.witness(3), Err(MerkleTreeError::PositionOutOfRange(3)));
+	}
+
+	// ===== Checkpoint Tests =====
+
+	#[test]
+	fn test_rewind_restores_root_and_size() {
+		let mut tree = tree_with(5);
+		let checkpoint = tree.checkpoint();
+		let root = tree.root();
+
+		tree.append(leaf(100)).unwrap();
+		tree.append(leaf(101)).unwrap();
+		tree.rewind(&checkpoint).unwrap();
+
+		assert_eq!(tree.root(), root);
+		assert_eq!(tree.size(), 5);
+		assert!(tree.leaf(5).is_none());
+		assert!(verifies(&tree, 4));
+	}
+
+	#[test]
+	fn test_rewind_rejects_future_checkpoint() {
+		let mut tree = tree_with(5);
+		let checkpoint = tree_with(6).checkpoint();
+		assert_eq!(
+			tree.rewind(&checkpoint),
+			Err(MerkleTreeError::InvalidCheckpoint)
+		);
+	}
+
+	#[test]
+	fn test_restored_tree_continues_with_same_roots() {
+		let mut full = tree_with(6);
+		let mut restored =
+			IncrementalMerkleTree::from_checkpoint(LightPoseidonHasher, &full.checkpoint())
+				.unwrap();
+
+		for i in 0..4 {
+			full.append(leaf(50 + i)).unwrap();
+			restored.append(leaf(50 + i)).unwrap();
+			assert_eq!(restored.root(), full.root());
+		}
+		assert_eq!(restored.witness(6), Err(MerkleTreeError::LeavesUnavailable));
+	}
+
+	#[test]
+	fn test_from_checkpoint_rejects_bad_frontier() {
+		let mut checkpoint = tree_with(2).checkpoint();
+		checkpoint.frontier.pop();
+		assert!(IncrementalMerkleTree::from_checkpoint(LightPoseidonHasher, &checkpoint).is_err());
+	}
+
+	#[test]
+	fn test_checkpoint_serde_roundtrip() {
+		let checkpoint = tree_with(7).checkpoint();
+		let json = serde_json::to_string(&checkpoint).unwrap();
+		let decoded: MerkleCheckpoint = serde_json::from_str(&json).unwrap();
+		assert_eq!(decoded, checkpoint);
+	}
+}
@@ -0,0 +1,83 @@
+//! Poseidon Merkle Trees
+//!
+//! Off-chain Merkle tree implementations shared by wallets, the RPC indexer and
+//! the pallet:
+//! - [`IncrementalMerkleTree`]: append-only commitment tree (same roots as
+//!   `pallet-shielded-pool`)
+//! - [`SparseMerkleTree`]: keyed tree for non-membership and allowlists
+//!
+//! All trees hash with the `PoseidonHasher` port and share the node layout:
+//! - Empty leaf: zero field element
+//! - Node: `Poseidon(left, right)`
+//! - Bytes: 32-byte little-endian field elements
+
+pub mod incremental;
+pub mod sparse;
+
+pub use incremental::{IncrementalMerkleTree, MerkleCheckpoint, MAX_DEPTH};
+pub use sparse::{SmtProof, SparseMerkleTree, MAX_SMT_DEPTH};
+
+use crate::domain::{ports::PoseidonHasher, value_objects::FieldElement};
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+
+/// Errors returned by Merkle tree operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MerkleTreeError {
+	/// Tree has no room for another leaf
+	TreeFull,
+	/// Position is not in the tree
+	PositionOutOfRange(u64),
+	/// Leaves needed for a witness were not retained
+	LeavesUnavailable,
+	/// Checkpoint does not belong to this tree
+	InvalidCheckpoint,
+	/// Depth is zero or above the supported maximum
+	InvalidDepth(usize),
+	/// Sparse tree slot is held by a different key
+	SlotOccupied(u64),
+}
+
+impl core::fmt::Display for MerkleTreeError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			MerkleTreeError::TreeFull => write!(f, "Merkle tree is full"),
+			MerkleTreeError::PositionOutOfRange(position) => {
+				write!(f, "Position out of range: {position}")
+			}
+			MerkleTreeError::LeavesUnavailable => write!(f, "Leaves not retained for witness"),
+			MerkleTreeError::InvalidCheckpoint => write!(f, "Invalid checkpoint"),
+			MerkleTreeError::InvalidDepth(depth) => write!(f, "Invalid tree depth: {depth}"),
+			MerkleTreeError::SlotOccupied(slot) => {
+				write!(f, "Slot {slot} occupied by a different key")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MerkleTreeError {}
+
+/// Zero hashes per level: `zeros[0] = 0`, `zeros[n] = H(zeros[n-1], zeros[n-1])`
+///
+/// Returns `depth + 1` entries; the last one is the empty tree root.
+pub fn zero_hashes<H: PoseidonHasher>(hasher: &H, depth: usize) -> Vec<FieldElement> {
+	let mut zeros = Vec::with_capacity(depth + 1);
+	zeros.push(FieldElement::zero());
+	for level in 0..depth {
+		let zero = zeros[level];
+		zeros.push(hasher.hash_2([zero, zero]));
+	}
+	zeros
+}
+
+/// Encodes a field element as 32 little-endian bytes
+pub fn field_to_bytes(value: &FieldElement) -> [u8; 32] {
+	value.to_le_bytes()
+}
+
+/// Decodes 32 little-endian bytes into a field element (reduced mod order)
+pub fn bytes_to_field(bytes: &[u8; 32]) -> FieldElement {
+	FieldElement::new(Fr::from_le_bytes_mod_order(bytes))
+}
@@ -0,0 +1,479 @@
+//! Sparse Merkle Tree
+//!
+//! Poseidon sparse Merkle tree keyed by field elements, backing nullifier
+//! non-membership and allowlist proofs. Proofs use the layout consumed by the
+//! `sparse_merkle` gadget in `orbinum-zk-circuits`:
+//!
+//! - A key occupies the slot given by its low `depth` bits (LSB = level 0)
+//! - Occupied leaf: `Poseidon(key, value)`. Empty leaf: `0`
+//! - Internal node: `Poseidon(left, right)`; empty subtrees use precomputed defaults
+//! - Siblings are ordered from the leaf level up to the root
+//!
+//! Two keys sharing the same low bits collide; `insert` rejects the second one.
+
+use super::{zero_hashes, MerkleTreeError};
+use crate::domain::{ports::PoseidonHasher, value_objects::FieldElement};
+use alloc::{
+	collections::{BTreeMap, BTreeSet},
+	vec::Vec,
+};
+use ark_ff::PrimeField;
+
+/// Maximum supported SMT depth (slot index must fit in a `u64`)
+pub const MAX_SMT_DEPTH: usize = 64;
+
+/// Slot index of a key for the given depth (low `depth` bits)
+pub fn smt_slot(key: &FieldElement, depth: usize) -> u64 {
+	let low = key.inner().into_bigint().0[0];
+	if depth >= MAX_SMT_DEPTH {
+		low
+	} else {
+		low & ((1u64 << depth) - 1)
+	}
+}
+
+/// SMT leaf hash: `Poseidon(key, value)`
+pub fn smt_leaf<H: PoseidonHasher>(
+	hasher: &H,
+	key: FieldElement,
+	value: FieldElement,
+) -> FieldElement {
+	hasher.hash_2([key, value])
+}
+
+/// Computes the SMT root from a leaf and its sibling path
+pub fn smt_root_from_path<H: PoseidonHasher>(
+	hasher: &H,
+	leaf: FieldElement,
+	slot: u64,
+	siblings: &[FieldElement],
+) -> FieldElement {
+	let mut current = leaf;
+	for (level, sibling) in siblings.iter().enumerate() {
+		current = if (slot >> level) & 1 == 1 {
+			hasher.hash_2([*sibling, current])
+		} else {
+			hasher.hash_2([current, *sibling])
+		};
+	}
+	current
+}
+
+// ============================================================================
+// Proof
+// ============================================================================
+
+/// Sibling path and slot occupant for an SMT proof
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmtProof {
+	/// Sibling hashes from leaf level up to the root
+	pub siblings: Vec<FieldElement>,
+	/// Occupant of the slot as `(key, value)`, `None` if the slot is empty
+	pub occupant: Option<(FieldElement, FieldElement)>,
+}
+
+impl SmtProof {
+	/// Checks that `(key, value)` is stored under `root`
+	pub fn verify_inclusion<H: PoseidonHasher>(
+		&self,
+		hasher: &H,
+		root: &FieldElement,
+		key: &FieldElement,
+		value: &FieldElement,
+	) -> bool {
+		if self.occupant != Some((*key, *value)) {
+			return false;
+		}
+		let leaf = smt_leaf(hasher, *key, *value);
+		let slot = smt_slot(key, self.siblings.len());
+		smt_root_from_path(hasher, leaf, slot, &self.siblings) == *root
+	}
+
+	/// Checks that `key` is absent from the tree with `root`
+	pub fn verify_exclusion<H: PoseidonHasher>(
+		&self,
+		hasher: &H,
+		root: &FieldElement,
+		key: &FieldElement,
+	) -> bool {
+		let depth = self.siblings.len();
+		let slot = smt_slot(key, depth);
+
+		let leaf = match self.occupant {
+			None => FieldElement::zero(),
+			Some((occupant_key, occupant_value)) => {
+				// The occupant must be a different key living in the same slot
+				if occupant_key == *key || smt_slot(&occupant_key, depth) != slot {
+					return false;
+				}
+				smt_leaf(hasher, occupant_key, occupant_value)
+			}
+		};
+
+		smt_root_from_path(hasher, leaf, slot, &self.siblings) == *root
+	}
+}
+
+// ============================================================================
+// Sparse Merkle Tree
+// ============================================================================
+
+/// Poseidon sparse Merkle tree keyed by field elements
+#[derive(Debug, Clone)]
+pub struct SparseMerkleTree<H: PoseidonHasher> {
+	hasher: H,
+	depth: usize,
+	/// Default (empty subtree) hash per level, `defaults[0]` is the empty leaf
+	defaults: Vec<FieldElement>,
+	/// Occupied slots: slot index -> (key, value)
+	leaves: BTreeMap<u64, (FieldElement, FieldElement)>,
+	/// Non-default nodes: (level, index) -> hash
+	nodes: BTreeMap<(usize, u64), FieldElement>,
+}
+
+impl<H: PoseidonHasher> SparseMerkleTree<H> {
+	/// Creates an empty tree of the given depth
+	pub fn new(hasher: H, depth: usize) -> Result<Self, MerkleTreeError> {
+		if depth == 0 || depth > MAX_SMT_DEPTH {
+			return Err(MerkleTreeError::InvalidDepth(depth));
+		}
+
+		Ok(Self {
+			defaults: zero_hashes(&hasher, depth),
+			hasher,
+			depth,
+			leaves: BTreeMap::new(),
+			nodes: BTreeMap::new(),
+		})
+	}
+
+	/// Tree depth
+	pub fn depth(&self) -> usize {
+		self.depth
+	}
+
+	/// Number of occupied leaves
+	pub fn len(&self) -> usize {
+		self.leaves.len()
+	}
+
+	/// Whether the tree has no occupied leaves
+	pub fn is_empty(&self) -> bool {
+		self.leaves.is_empty()
+	}
+
+	/// Current root
+	pub fn root(&self) -> FieldElement {
+		self.node(self.depth, 0)
+	}
+
+	/// Root of an empty tree of this depth
+	pub fn empty_root(&self) -> FieldElement {
+		self.defaults[self.depth]
+	}
+
+	/// Value stored under `key`, if present
+	pub fn get(&self, key: &FieldElement) -> Option<FieldElement> {
+		self.leaves
+			.get(&smt_slot(key, self.depth))
+			.filter(|(stored_key, _)| stored_key == key)
+			.map(|(_, value)| *value)
+	}
+
+	/// Inserts or updates `key` and returns the new root
+	///
+	/// Fails if the slot is already occupied by a different key.
+	pub fn insert(
+		&mut self,
+		key: FieldElement,
+		value: FieldElement,
+	) -> Result<FieldElement, MerkleTreeError> {
+		let slot = smt_slot(&key, self.depth);
+		if let Some((occupant, _)) = self.leaves.get(&slot) {
+			if *occupant != key {
+				return Err(MerkleTreeError::SlotOccupied(slot));
+			}
+		}
+
+		self.leaves.insert(slot, (key, value));
+		self.rehash_path(slot);
+		Ok(self.root())
+	}
+
+	/// Removes `key`, returning its previous value
+	pub fn remove(&mut self, key: &FieldElement) -> Option<FieldElement> {
+		let previous = self.get(key)?;
+		let slot = smt_slot(key, self.depth);
+		self.leaves.remove(&slot);
+		self.rehash_path(slot);
+		Some(previous)
+	}
+
+	/// Applies a batch of updates and recomputes each affected node once
+	///
+	/// `Some(value)` inserts/updates, `None` removes. The batch is validated
+	/// before any change is applied, so a failing batch leaves the tree untouched.
+	pub fn apply_batch(
+		&mut self,
+		updates: &[(FieldElement, Option<FieldElement>)],
+	) -> Result<FieldElement, MerkleTreeError> {
+		let mut staged: BTreeMap<u64, Option<(FieldElement, FieldElement)>> = BTreeMap::new();
+		for (key, value) in updates {
+			let slot = smt_slot(key, self.depth);
+			let occupant = match staged.get(&slot) {
+				Some(pending) => *pending,
+				None => self.leaves.get(&slot).copied(),
+			};
+			if let Some((occupant_key, _)) = occupant {
+				if occupant_key != *key && value.is_some() {
+					return Err(MerkleTreeError::SlotOccupied(slot));
+				}
+				if occupant_key != *key {
+					continue;
+				}
+			}
+			staged.insert(slot, value.map(|value| (*key, value)));
+		}
+
+		let mut dirty: BTreeSet<u64> = BTreeSet::new();
+		for (slot, entry) in staged {
+			match entry {
+				Some(leaf) => self.leaves.insert(slot, leaf),
+				None => self.leaves.remove(&slot),
+			};
+			dirty.insert(slot);
+		}
+
+		for level in 0..=self.depth {
+			let mut parents = BTreeSet::new();
+			for index in dirty {
+				self.recompute_node(level, index);
+				parents.insert(index >> 1);
+			}
+			dirty = parents;
+		}
+
+		Ok(self.root())
+	}
+
+	/// Inclusion proof for `key`, `None` if the key is absent
+	pub fn inclusion_proof(&self, key: &FieldElement) -> Option<SmtProof> {
+		let value = self.get(key)?;
+		Some(SmtProof {
+			siblings: self.siblings(smt_slot(key, self.depth)),
+			occupant: Some((*key, value)),
+		})
+	}
+
+	/// Exclusion proof for `key`, `None` if the key is present
+	///
+	/// The slot is either empty or held by a different key (returned as occupant).
+	pub fn exclusion_proof(&self, key: &FieldElement) -> Option<SmtProof> {
+		if self.get(key).is_some() {
+			return None;
+		}
+		let slot = smt_slot(key, self.depth);
+		Some(SmtProof {
+			siblings: self.siblings(slot),
+			occupant: self.leaves.get(&slot).copied(),
+		})
+	}
+
+	fn node(&self, level: usize, index: u64) -> FieldElement {
+		self.nodes
+			.get(&(level, index))
+			.copied()
+			.unwrap_or(self.defaults[level])
+	}
+
+	fn siblings(&self, slot: u64) -> Vec<FieldElement> {
+		(0..self.depth)
+			.map(|level| self.node(level, (slot >> level) ^ 1))
+			.collect()
+	}
+
+	fn rehash_path(&mut self, slot: u64) {
+		for level in 0..=self.depth {
+			self.recompute_node(level, slot.checked_shr(level as u32).unwrap_or(0));
+		}
+	}
+
+	fn recompute_node(&mut self, level: usize, index: u64) {
+		let hash = if level == 0 {
+			self.leaves
+				.get(&index)
+				.map(|(key, value)| smt_leaf(&self.hasher, *key, *value))
+				.unwrap_or(self.defaults[0])
+		} else {
+			let left = self.node(level - 1, index << 1);
+			let right = self.node(level - 1, (index << 1) | 1);
+			self.hasher.hash_2([left, right])
+		};
+
+		if hash == self.defaults[level] {
+			self.nodes.remove(&(level, index));
+		} else {
+			self.nodes.insert((level, index), hash);
+		}
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::LightPoseidonHasher;
+
+	fn fe(value: u64) -> FieldElement {
+		FieldElement::from_u64(value)
+	}
+
+	fn tree(depth: usize) -> SparseMerkleTree<LightPoseidonHasher> {
+		SparseMerkleTree::new(LightPoseidonHasher, depth).unwrap()
+	}
+
+	// ===== Construction Tests =====
+
+	#[test]
+	fn test_new_rejects_invalid_depth() {
+		assert_eq!(
+			SparseMerkleTree::new(LightPoseidonHasher, 0).unwrap_err(),
+			MerkleTreeError::InvalidDepth(0)
+		);
+		assert!(SparseMerkleTree::new(LightPoseidonHasher, MAX_SMT_DEPTH + 1).is_err());
+		assert!(SparseMerkleTree::new(LightPoseidonHasher, MAX_SMT_DEPTH).is_ok());
+	}
+
+	#[test]
+	fn test_empty_tree_root() {
+		let tree = tree(8);
+		assert!(tree.is_empty());
+		assert_eq!(tree.root(), tree.empty_root());
+	}
+
+	// ===== Insert / Update / Remove Tests =====
+
+	#[test]
+	fn test_insert_update_remove() {
+		let mut tree = tree(8);
+		let key = fe(5);
+
+		tree.insert(key, fe(1)).unwrap();
+		assert_eq!(tree.get(&key), Some(fe(1)));
+
+		let updated_root = tree.insert(key, fe(2)).unwrap();
+		assert_eq!(tree.get(&key), Some(fe(2)));
+		assert_eq!(tree.len(), 1);
+		assert_eq!(updated_root, tree.root());
+
+		assert_eq!(tree.remove(&key), Some(fe(2)));
+		assert_eq!(tree.root(), tree.empty_root());
+	}
+
+	#[test]
+	fn test_slot_collision_rejected() {
+		let mut tree = tree(4);
+		tree.insert(fe(3), fe(1)).unwrap();
+
+		// 19 = 0b10011 shares the low 4 bits with 3
+		assert_eq!(
+			tree.insert(fe(19), fe(1)),
+			Err(MerkleTreeError::SlotOccupied(3))
+		);
+	}
+
+	#[test]
+	fn test_root_independent_of_insertion_order() {
+		let mut forward = tree(16);
+		let mut backward = tree(16);
+		for i in 1..10u64 {
+			forward.insert(fe(i * 7), fe(i)).unwrap();
+		}
+		for i in (1..10u64).rev() {
+			backward.insert(fe(i * 7), fe(i)).unwrap();
+		}
+		assert_eq!(forward.root(), backward.root());
+	}
+
+	// ===== Proof Tests =====
+
+	#[test]
+	fn test_inclusion_proof_verifies() {
+		let mut tree = tree(16);
+		for i in 1..10u64 {
+			tree.insert(fe(i * 7), fe(i)).unwrap();
+		}
+
+		let proof = tree.inclusion_proof(&fe(21)).unwrap();
+		assert_eq!(proof.siblings.len(), 16);
+		assert!(proof.verify_inclusion(&LightPoseidonHasher, &tree.root(), &fe(21), &fe(3)));
+		assert!(!proof.verify_inclusion(&LightPoseidonHasher, &tree.root(), &fe(21), &fe(4)));
+		assert!(tree.inclusion_proof(&fe(22)).is_none());
+	}
+
+	#[test]
+	fn test_exclusion_proof_empty_slot() {
+		let mut tree = tree(16);
+		tree.insert(fe(1), fe(1)).unwrap();
+
+		let proof = tree.exclusion_proof(&fe(2)).unwrap();
+		assert_eq!(proof.occupant, None);
+		assert!(proof.verify_exclusion(&LightPoseidonHasher, &tree.root(), &fe(2)));
+		assert!(tree.exclusion_proof(&fe(1)).is_none());
+	}
+
+	#[test]
+	fn test_exclusion_proof_occupied_by_other_key() {
+		let mut tree = tree(4);
+		tree.insert(fe(3), fe(9)).unwrap();
+
+		let proof = tree.exclusion_proof(&fe(19)).unwrap();
+		assert_eq!(proof.occupant, Some((fe(3), fe(9))));
+		assert!(proof.verify_exclusion(&LightPoseidonHasher, &tree.root(), &fe(19)));
+
+		// The same proof cannot show the occupant itself is absent
+		assert!(!proof.verify_exclusion(&LightPoseidonHasher, &tree.root(), &fe(3)));
+	}
+
+	#[test]
+	fn test_stale_proof_rejected_after_update() {
+		let mut tree = tree(8);
+		tree.insert(fe(1), fe(1)).unwrap();
+		let proof = tree.exclusion_proof(&fe(2)).unwrap();
+
+		tree.insert(fe(2), fe(5)).unwrap();
+		assert!(!proof.verify_exclusion(&LightPoseidonHasher, &tree.root(), &fe(2)));
+	}
+
+	// ===== Batch Tests =====
+
+	#[test]
+	fn test_apply_batch_matches_sequential_inserts() {
+		let updates: Vec<(FieldElement, Option<FieldElement>)> =
+			(1..20u64).map(|i| (fe(i), Some(fe(i * 100)))).collect();
+
+		let mut batched = tree(12);
+		let batched_root = batched.apply_batch(&updates).unwrap();
+
+		let mut sequential = tree(12);
+		for (key, value) in &updates {
+			sequential.insert(*key, value.unwrap()).unwrap();
+		}
+
+		assert_eq!(batched_root, sequential.root());
+		assert_eq!(batched.len(), 19);
+	}
+
+	#[test]
+	fn test_apply_batch_rejects_collision_atomically() {
+		let mut tree = tree(4);
+		let result = tree.apply_batch(&[(fe(3), Some(fe(1))), (fe(19), Some(fe(1)))]);
+
+		assert!(result.is_err());
+		assert_eq!(tree.root(), tree.empty_root());
+		assert!(tree.is_empty());
+	}
+}
@@ -1,10 +1,27 @@
+pub mod batch;
+pub mod merkle;
 pub mod poseidon_hash_1;
+pub mod poseidon_hash_n;
 pub mod poseidon_hasher;
+pub mod poseidon_sponge;
+
+#[cfg(feature = "std")]
+pub mod poseidon_params;
 
 #[cfg(feature = "poseidon-native")]
 pub mod native_poseidon_hasher;
 
+#[cfg(feature = "rescue-prime")]
+pub mod rescue_prime;
+#[cfg(feature = "rescue-prime")]
+pub mod scheme_hasher;
+
 pub use poseidon_hasher::LightPoseidonHasher;
 
 #[cfg(feature = "poseidon-native")]
 pub use native_poseidon_hasher::NativePoseidonHasher;
+
+#[cfg(feature = "rescue-prime")]
+pub use rescue_prime::RescuePrimeHasher;
+#[cfg(feature = "rescue-prime")]
+pub use scheme_hasher::SchemeHasher;
@@ -5,8 +5,9 @@
 //! Poseidon hashing in the native runtime instead of the WASM interpreter.
 
 use crate::domain::{ports::PoseidonHasher, value_objects::FieldElement};
+use alloc::vec::Vec;
 use ark_bn254::Fr;
-use ark_ff::{BigInteger, PrimeField};
+use ark_ff::PrimeField;
 
 /// Native Poseidon hasher using host functions
 ///
@@ -55,14 +56,54 @@ impl PoseidonHasher for NativePoseidonHasher {
 }
 
 impl NativePoseidonHasher {
+	/// Hash 1..=8 field elements, using the matching host function
+	///
+	/// Single-element hashes have no host function and run in WASM.
+	///
+	/// # Panics
+	/// If `inputs` is empty or longer than `MAX_POSEIDON_ARITY`.
+	pub fn hash_n(&self, inputs: &[FieldElement]) -> FieldElement {
+		use crate::infrastructure::{
+			crypto::{poseidon_hash_1::poseidon_hash_1, poseidon_hash_n::MAX_POSEIDON_ARITY},
+			host_interface::poseidon_host_interface,
+		};
+
+		let packed = Self::pack(inputs);
+		let result_vec = match inputs.len() {
+			1 => return poseidon_hash_1(inputs[0]),
+			2 => return self.hash_2([inputs[0], inputs[1]]),
+			4 => return self.hash_4([inputs[0], inputs[1], inputs[2], inputs[3]]),
+			3 => poseidon_host_interface::poseidon_hash_3(&packed),
+			5 => poseidon_host_interface::poseidon_hash_5(&packed),
+			6 => poseidon_host_interface::poseidon_hash_6(&packed),
+			7 => poseidon_host_interface::poseidon_hash_7(&packed),
+			8 => poseidon_host_interface::poseidon_hash_8(&packed),
+			_ => panic!("Poseidon arity must be in 1..={MAX_POSEIDON_ARITY}"),
+		};
+
+		Self::bytes_to_field(&result_vec)
+	}
+
+	/// Sponge hash of an arbitrary number of field elements (host function)
+	pub fn sponge(&self, inputs: &[FieldElement]) -> FieldElement {
+		use crate::infrastructure::host_interface::poseidon_host_interface;
+
+		let result_vec = poseidon_host_interface::poseidon_sponge(&Self::pack(inputs));
+		Self::bytes_to_field(&result_vec)
+	}
+
+	/// Concatenate field elements as 32-byte little-endian values
+	fn pack(inputs: &[FieldElement]) -> Vec<u8> {
+		inputs
+			.iter()
+			.flat_map(|input| Self::field_to_bytes(input.inner()))
+			.collect()
+	}
+
 	/// Convert field element to 32-byte array (little-endian)
 	#[inline]
 	fn field_to_bytes(field: Fr) -> [u8; 32] {
-		let bigint = field.into_bigint();
-		let bytes = bigint.to_bytes_le();
-		let mut result = [0u8; 32];
-		result.copy_from_slice(&bytes[..32]);
-		result
+		FieldElement::new(field).to_le_bytes()
 	}
 
 	/// Convert bytes to field element (little-endian mod order)
@@ -144,6 +185,28 @@ mod tests {
 		assert_eq!(hash1, hash2);
 	}
 
+	#[test]
+	fn test_native_hash_n_matches_wasm() {
+		use crate::infrastructure::crypto::poseidon_hash_n::{poseidon_hash_n, MAX_POSEIDON_ARITY};
+
+		let native = NativePoseidonHasher;
+		for count in 1..=MAX_POSEIDON_ARITY as u64 {
+			let inputs: Vec<FieldElement> = (1..=count).map(FieldElement::from_u64).collect();
+			assert_eq!(native.hash_n(&inputs), poseidon_hash_n(&inputs));
+		}
+	}
+
+	#[test]
+	fn test_native_sponge_matches_wasm() {
+		use crate::infrastructure::crypto::poseidon_sponge::poseidon_sponge;
+
+		let inputs: Vec<FieldElement> = (0..9).map(FieldElement::from_u64).collect();
+		assert_eq!(
+			NativePoseidonHasher.sponge(&inputs),
+			poseidon_sponge(&inputs)
+		);
+	}
+
 	#[test]
 	fn test_native_vs_wasm_compatibility() {
 		use crate::infrastructure::crypto::LightPoseidonHasher;
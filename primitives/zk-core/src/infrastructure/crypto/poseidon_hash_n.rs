@@ -0,0 +1,102 @@
+//! Poseidon hash of a fixed number of field elements.
+//!
+//! Covers the arities outside the `PoseidonHasher` port (3, 5..=8), used by
+//! memo hashes, multi-field commitments and wider trees. Circom-compatible, so
+//! results match `Poseidon(n)` in circuits.
+//!
+//! With `poseidon-native`, the host interface exposes the same arities as
+//! `poseidon_hash_3` ..= `poseidon_hash_8`.
+
+use crate::domain::value_objects::FieldElement;
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use light_poseidon_nostd::{Poseidon, PoseidonHasher as LightHasher};
+
+/// Highest arity with a dedicated host function
+pub const MAX_POSEIDON_ARITY: usize = 8;
+
+/// Computes the circom-compatible Poseidon hash of `inputs`.
+///
+/// # Panics
+/// If `inputs` is empty or longer than [`MAX_POSEIDON_ARITY`].
+pub fn poseidon_hash_n(inputs: &[FieldElement]) -> FieldElement {
+	assert!(
+		!inputs.is_empty() && inputs.len() <= MAX_POSEIDON_ARITY,
+		"Poseidon arity must be in 1..={MAX_POSEIDON_ARITY}"
+	);
+
+	let frs: Vec<Fr> = inputs.iter().map(FieldElement::inner).collect();
+	let result = Poseidon::<Fr>::new_circom(frs.len())
+		.expect("Failed to initialize Poseidon")
+		.hash(&frs)
+		.expect("Poseidon hash failed");
+
+	FieldElement::new(result)
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		domain::ports::PoseidonHasher,
+		infrastructure::crypto::{poseidon_hash_1::poseidon_hash_1, LightPoseidonHasher},
+	};
+
+	fn inputs(count: u64) -> Vec<FieldElement> {
+		(1..=count).map(FieldElement::from_u64).collect()
+	}
+
+	#[test]
+	fn test_matches_fixed_arity_hashers() {
+		let hasher = LightPoseidonHasher;
+		let two = inputs(2);
+		let four = inputs(4);
+
+		assert_eq!(poseidon_hash_n(&two), hasher.hash_2([two[0], two[1]]));
+		assert_eq!(
+			poseidon_hash_n(&four),
+			hasher.hash_4([four[0], four[1], four[2], four[3]])
+		);
+		assert_eq!(
+			poseidon_hash_n(&inputs(1)),
+			poseidon_hash_1(FieldElement::from_u64(1))
+		);
+	}
+
+	#[test]
+	fn test_every_arity_distinct() {
+		let hashes: Vec<FieldElement> = (1..=MAX_POSEIDON_ARITY as u64)
+			.map(|count| poseidon_hash_n(&inputs(count)))
+			.collect();
+
+		for (i, a) in hashes.iter().enumerate() {
+			assert!(!a.is_zero());
+			for b in &hashes[i + 1..] {
+				assert_ne!(a, b);
+			}
+		}
+	}
+
+	#[test]
+	fn test_order_matters() {
+		let mut reversed = inputs(5);
+		reversed.reverse();
+		assert_ne!(poseidon_hash_n(&inputs(5)), poseidon_hash_n(&reversed));
+	}
+
+	#[test]
+	#[should_panic(expected = "Poseidon arity must be in")]
+	fn test_empty_input_panics() {
+		poseidon_hash_n(&[]);
+	}
+
+	#[test]
+	#[should_panic(expected = "Poseidon arity must be in")]
+	fn test_arity_above_max_panics() {
+		poseidon_hash_n(&inputs(MAX_POSEIDON_ARITY as u64 + 1));
+	}
+}
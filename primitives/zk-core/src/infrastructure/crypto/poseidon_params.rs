@@ -0,0 +1,336 @@
+//! Poseidon parameter generation (std only)
+//!
+//! Reproduces circomlib's procedure for the BN254 x^5 instances: the Grain
+//! LFSR from the Poseidon reference script seeds the round constants
+//! (rejection-sampled) and then a Cauchy MDS matrix `1 / (x_i + y_j)`.
+//! circomlib uses the first Cauchy candidate for every width, which is what
+//! this generator returns; the later script's subspace-trail checks are not
+//! re-run. Golden values in `res/poseidon_params_golden.txt` pin the output so
+//! new gadget arities cannot silently drift from the hashes in circuits.
+
+use alloc::{vec, vec::Vec};
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, BigInteger256, Field, PrimeField};
+
+/// Smallest supported state width (arity 1)
+pub const MIN_POSEIDON_WIDTH: usize = 2;
+
+/// Largest supported state width (arity 8)
+pub const MAX_POSEIDON_WIDTH: usize = 9;
+
+/// Full rounds used by circomlib for every width
+pub const POSEIDON_FULL_ROUNDS: usize = 8;
+
+/// circomlib partial rounds, indexed by `width - MIN_POSEIDON_WIDTH`
+const PARTIAL_ROUNDS: [usize; MAX_POSEIDON_WIDTH - MIN_POSEIDON_WIDTH + 1] =
+	[56, 57, 56, 60, 60, 63, 64, 63];
+
+/// Bit length of the BN254 scalar field
+const FIELD_BITS: usize = 254;
+
+/// Errors that can occur during parameter generation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PoseidonParamsError {
+	/// Width outside `MIN_POSEIDON_WIDTH..=MAX_POSEIDON_WIDTH`
+	UnsupportedWidth(usize),
+}
+
+impl core::fmt::Display for PoseidonParamsError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			PoseidonParamsError::UnsupportedWidth(width) => write!(
+				f,
+				"Unsupported Poseidon width {width} (expected {MIN_POSEIDON_WIDTH}..={MAX_POSEIDON_WIDTH})"
+			),
+		}
+	}
+}
+
+impl std::error::Error for PoseidonParamsError {}
+
+/// Round constants and MDS matrix for one Poseidon width
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoseidonParameters {
+	/// State width `t` (arity + 1)
+	pub width: usize,
+	/// Number of full rounds
+	pub full_rounds: usize,
+	/// Number of partial rounds
+	pub partial_rounds: usize,
+	/// `(full_rounds + partial_rounds) * width` constants, round-major
+	pub round_constants: Vec<Fr>,
+	/// `width x width` MDS matrix, row-major
+	pub mds: Vec<Vec<Fr>>,
+}
+
+impl PoseidonParameters {
+	/// Generates the circomlib parameters for `width`
+	pub fn generate(width: usize) -> Result<Self, PoseidonParamsError> {
+		if !(MIN_POSEIDON_WIDTH..=MAX_POSEIDON_WIDTH).contains(&width) {
+			return Err(PoseidonParamsError::UnsupportedWidth(width));
+		}
+		let full_rounds = POSEIDON_FULL_ROUNDS;
+		let partial_rounds = PARTIAL_ROUNDS[width - MIN_POSEIDON_WIDTH];
+
+		let mut grain = Grain::new(width, full_rounds, partial_rounds);
+		let round_constants = (0..(full_rounds + partial_rounds) * width)
+			.map(|_| grain.next_field_element())
+			.collect();
+
+		// The reference script reduces (rather than rejects) the MDS seeds
+		let seeds: Vec<Fr> = (0..2 * width)
+			.map(|_| Fr::from_be_bytes_mod_order(&grain.next_bigint().to_bytes_be()))
+			.collect();
+		let (xs, ys) = seeds.split_at(width);
+		let mds = xs
+			.iter()
+			.map(|x| {
+				ys.iter()
+					.map(|y| {
+						(*x + y)
+							.inverse()
+							.expect("Cauchy seeds are distinct with overwhelming probability")
+					})
+					.collect()
+			})
+			.collect();
+
+		Ok(Self {
+			width,
+			full_rounds,
+			partial_rounds,
+			round_constants,
+			mds,
+		})
+	}
+
+	/// Applies the Poseidon permutation to `state` in place
+	///
+	/// # Panics
+	/// If `state.len() != width`.
+	pub fn permute(&self, state: &mut [Fr]) {
+		assert_eq!(state.len(), self.width, "State length must equal width");
+
+		let half_full = self.full_rounds / 2;
+		let mut mixed = vec![Fr::from(0u64); self.width];
+		for (round, constants) in self.round_constants.chunks_exact(self.width).enumerate() {
+			for (element, constant) in state.iter_mut().zip(constants) {
+				*element += constant;
+			}
+			if round < half_full || round >= half_full + self.partial_rounds {
+				state
+					.iter_mut()
+					.for_each(|element| *element = element.pow([5u64]));
+			} else {
+				state[0] = state[0].pow([5u64]);
+			}
+			for (out, row) in mixed.iter_mut().zip(&self.mds) {
+				*out = row.iter().zip(state.iter()).map(|(m, s)| *m * s).sum();
+			}
+			state.copy_from_slice(&mixed);
+		}
+	}
+
+	/// Circom-compatible hash of `width - 1` inputs (capacity element is zero)
+	///
+	/// # Panics
+	/// If `inputs.len() != width - 1`.
+	pub fn hash(&self, inputs: &[Fr]) -> Fr {
+		assert_eq!(
+			inputs.len() + 1,
+			self.width,
+			"Input count must be width - 1"
+		);
+
+		let mut state = Vec::with_capacity(self.width);
+		state.push(Fr::from(0u64));
+		state.extend_from_slice(inputs);
+		self.permute(&mut state);
+		state[0]
+	}
+}
+
+// ============================================================================
+// Grain LFSR
+// ============================================================================
+
+/// Grain LFSR in self-shrinking mode, as in the Poseidon reference script
+struct Grain {
+	state: [bool; 80],
+	head: usize,
+}
+
+impl Grain {
+	fn new(width: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+		// field = 1 (prime), sbox = 0 (x^alpha), n, t, R_F, R_P, then 30 ones
+		let fields = [
+			(1, 2),
+			(0, 4),
+			(FIELD_BITS, 12),
+			(width, 12),
+			(full_rounds, 10),
+			(partial_rounds, 10),
+			((1 << 30) - 1, 30),
+		];
+		let mut state = [false; 80];
+		let mut bits = fields
+			.iter()
+			.flat_map(|&(value, len)| (0..len).rev().map(move |i| (value >> i) & 1 == 1));
+		state
+			.iter_mut()
+			.for_each(|bit| *bit = bits.next().unwrap_or(false));
+
+		let mut grain = Self { state, head: 0 };
+		for _ in 0..160 {
+			grain.clock();
+		}
+		grain
+	}
+
+	fn clock(&mut self) -> bool {
+		let tap = |offset: usize| self.state[(self.head + offset) % 80];
+		let bit = tap(62) ^ tap(51) ^ tap(38) ^ tap(23) ^ tap(13) ^ tap(0);
+		self.state[self.head] = bit;
+		self.head = (self.head + 1) % 80;
+		bit
+	}
+
+	fn next_bit(&mut self) -> bool {
+		loop {
+			let keep = self.clock();
+			let bit = self.clock();
+			if keep {
+				return bit;
+			}
+		}
+	}
+
+	fn next_bigint(&mut self) -> BigInteger256 {
+		let bits: Vec<bool> = (0..FIELD_BITS).map(|_| self.next_bit()).collect();
+		BigInteger256::from_bits_be(&bits)
+	}
+
+	fn next_field_element(&mut self) -> Fr {
+		loop {
+			if let Some(element) = Fr::from_bigint(self.next_bigint()) {
+				return element;
+			}
+		}
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		domain::value_objects::FieldElement,
+		infrastructure::crypto::poseidon_hash_n::poseidon_hash_n,
+	};
+
+	const GOLDEN: &str = include_str!("./res/poseidon_params_golden.txt");
+
+	fn parse_hex(hex: &str) -> Fr {
+		let digits = hex.trim_start_matches("0x");
+		let bytes: Vec<u8> = (0..digits.len())
+			.step_by(2)
+			.map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap())
+			.collect();
+		Fr::from_be_bytes_mod_order(&bytes)
+	}
+
+	// ===== Golden Tests =====
+
+	#[test]
+	fn test_matches_golden_file() {
+		let lines: Vec<&str> = GOLDEN
+			.lines()
+			.filter(|line| !line.starts_with('#'))
+			.collect();
+		assert_eq!(lines.len(), MAX_POSEIDON_WIDTH - MIN_POSEIDON_WIDTH + 1);
+
+		for line in lines {
+			let fields: Vec<&str> = line.split_whitespace().collect();
+			let width: usize = fields[0].parse().unwrap();
+			let params = PoseidonParameters::generate(width).unwrap();
+			let inputs: Vec<Fr> = (1..width as u64).map(Fr::from).collect();
+
+			assert_eq!(params.partial_rounds, fields[1].parse::<usize>().unwrap());
+			assert_eq!(params.round_constants[0], parse_hex(fields[2]), "t={width}");
+			assert_eq!(
+				*params.round_constants.last().unwrap(),
+				parse_hex(fields[3]),
+				"t={width}"
+			);
+			assert_eq!(params.mds[0][0], parse_hex(fields[4]), "t={width}");
+			assert_eq!(params.mds[width - 1][width - 1], parse_hex(fields[5]));
+			assert_eq!(params.hash(&inputs), parse_hex(fields[6]), "t={width}");
+		}
+	}
+
+	#[test]
+	fn test_circomlib_reference_hashes() {
+		// circomlibjs poseidon([1, 2]) and poseidon([1, 2, 3, 4])
+		let hash_2 = PoseidonParameters::generate(3)
+			.unwrap()
+			.hash(&[Fr::from(1u64), Fr::from(2u64)]);
+		assert_eq!(
+			hash_2,
+			"7853200120776062878684798364095072458815029376092732009249414926327459813530"
+				.parse::<Fr>()
+				.unwrap()
+		);
+
+		let inputs: Vec<Fr> = (1..=4u64).map(Fr::from).collect();
+		assert_eq!(
+			PoseidonParameters::generate(5).unwrap().hash(&inputs),
+			"18821383157269793795438455681495246036402687001665670618754263018637548127333"
+				.parse::<Fr>()
+				.unwrap()
+		);
+	}
+
+	#[test]
+	fn test_matches_light_poseidon() {
+		for width in MIN_POSEIDON_WIDTH..=MAX_POSEIDON_WIDTH {
+			let inputs: Vec<FieldElement> = (1..width as u64).map(FieldElement::from_u64).collect();
+			let frs: Vec<Fr> = inputs.iter().map(FieldElement::inner).collect();
+
+			assert_eq!(
+				PoseidonParameters::generate(width).unwrap().hash(&frs),
+				poseidon_hash_n(&inputs).inner(),
+				"t={width}"
+			);
+		}
+	}
+
+	// ===== Shape Tests =====
+
+	#[test]
+	fn test_parameter_shape() {
+		for width in MIN_POSEIDON_WIDTH..=MAX_POSEIDON_WIDTH {
+			let params = PoseidonParameters::generate(width).unwrap();
+			assert_eq!(
+				params.round_constants.len(),
+				(params.full_rounds + params.partial_rounds) * width
+			);
+			assert_eq!(params.mds.len(), width);
+			assert!(params.mds.iter().all(|row| row.len() == width));
+		}
+	}
+
+	#[test]
+	fn test_unsupported_width() {
+		assert_eq!(
+			PoseidonParameters::generate(1),
+			Err(PoseidonParamsError::UnsupportedWidth(1))
+		);
+		assert_eq!(
+			PoseidonParameters::generate(10),
+			Err(PoseidonParamsError::UnsupportedWidth(10))
+		);
+	}
+}
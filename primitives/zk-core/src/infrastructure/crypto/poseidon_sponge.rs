@@ -0,0 +1,131 @@
+//! Variable-length Poseidon sponge.
+//!
+//! Hashes an arbitrary number of field elements by absorbing them in blocks of
+//! [`SPONGE_RATE`] elements into a single-element state:
+//!
+//! ```text
+//! state_0     = input_length
+//! state_{i+1} = Poseidon(state_i, block_i[0], block_i[1], block_i[2], block_i[3])
+//! output      = state_n
+//! ```
+//!
+//! The last block is zero-padded. Seeding the state with the input length keeps
+//! inputs that only differ by trailing zeros from colliding. The empty input still
+//! absorbs one (all-zero) block.
+//!
+//! The in-circuit gadget in `orbinum-zk-circuits` follows the same schedule, so
+//! on-circuit and off-circuit hashes agree.
+
+use crate::domain::value_objects::FieldElement;
+use alloc::vec::Vec;
+use ark_bn254::Fr;
+use light_poseidon_nostd::{Poseidon, PoseidonHasher as LightHasher};
+
+/// Number of input elements absorbed per permutation
+pub const SPONGE_RATE: usize = 4;
+
+/// Computes the sponge hash of an arbitrary-length slice of field elements.
+pub fn poseidon_sponge(inputs: &[FieldElement]) -> FieldElement {
+	let mut hasher = Poseidon::<Fr>::new_circom(SPONGE_RATE + 1)
+		.expect("Failed to initialize Poseidon with 5 inputs");
+
+	let mut state = Fr::from(inputs.len() as u64);
+	for block in sponge_blocks(inputs) {
+		let mut absorb = [Fr::from(0u64); SPONGE_RATE + 1];
+		absorb[0] = state;
+		absorb[1..].copy_from_slice(&block);
+		state = hasher.hash(&absorb).expect("Poseidon sponge absorb failed");
+	}
+
+	FieldElement::new(state)
+}
+
+/// Splits inputs into zero-padded blocks of [`SPONGE_RATE`] elements.
+///
+/// Always yields at least one block.
+pub fn sponge_blocks(inputs: &[FieldElement]) -> Vec<[Fr; SPONGE_RATE]> {
+	let block_count = inputs.len().div_ceil(SPONGE_RATE).max(1);
+	let mut blocks = Vec::with_capacity(block_count);
+
+	for index in 0..block_count {
+		let mut block = [Fr::from(0u64); SPONGE_RATE];
+		for (offset, slot) in block.iter_mut().enumerate() {
+			if let Some(element) = inputs.get(index * SPONGE_RATE + offset) {
+				*slot = element.inner();
+			}
+		}
+		blocks.push(block);
+	}
+
+	blocks
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use alloc::vec;
+
+	fn elements(values: &[u64]) -> Vec<FieldElement> {
+		values.iter().map(|v| FieldElement::from_u64(*v)).collect()
+	}
+
+	#[test]
+	fn test_sponge_deterministic() {
+		let inputs = elements(&[1, 2, 3, 4, 5, 6, 7]);
+		assert_eq!(poseidon_sponge(&inputs), poseidon_sponge(&inputs));
+	}
+
+	#[test]
+	fn test_sponge_empty_input_non_zero() {
+		assert!(!poseidon_sponge(&[]).is_zero());
+	}
+
+	#[test]
+	fn test_sponge_trailing_zero_changes_hash() {
+		let short = elements(&[1, 2, 3]);
+		let padded = elements(&[1, 2, 3, 0]);
+		assert_ne!(poseidon_sponge(&short), poseidon_sponge(&padded));
+	}
+
+	#[test]
+	fn test_sponge_order_matters() {
+		let a = elements(&[1, 2, 3, 4, 5]);
+		let b = elements(&[5, 4, 3, 2, 1]);
+		assert_ne!(poseidon_sponge(&a), poseidon_sponge(&b));
+	}
+
+	#[test]
+	fn test_sponge_multi_block_differs_from_single_block() {
+		let one_block = elements(&[1, 2, 3, 4]);
+		let two_blocks = elements(&[1, 2, 3, 4, 1, 2, 3, 4]);
+		assert_ne!(poseidon_sponge(&one_block), poseidon_sponge(&two_blocks));
+	}
+
+	#[test]
+	fn test_sponge_blocks_padding() {
+		let blocks = sponge_blocks(&elements(&[1, 2, 3, 4, 5]));
+		assert_eq!(blocks.len(), 2);
+		assert_eq!(
+			blocks[1],
+			[
+				Fr::from(5u64),
+				Fr::from(0u64),
+				Fr::from(0u64),
+				Fr::from(0u64)
+			]
+		);
+
+		let empty = sponge_blocks(&[]);
+		assert_eq!(empty, vec![[Fr::from(0u64); SPONGE_RATE]]);
+	}
+
+	#[test]
+	fn test_sponge_large_input() {
+		let inputs: Vec<FieldElement> = (0..64u64).map(FieldElement::from_u64).collect();
+		assert!(!poseidon_sponge(&inputs).is_zero());
+	}
+}
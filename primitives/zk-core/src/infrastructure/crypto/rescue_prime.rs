@@ -0,0 +1,386 @@
+//! Rescue-Prime hash (`rescue-prime` feature)
+//!
+//! Alternative algebraic hash for research deployments comparing proving costs
+//! against Poseidon. Parameters follow the Rescue-Prime reference procedure for
+//! BN254 (`alpha = 5`, capacity 1, 128-bit security): round constants from
+//! SHAKE256 over `"Rescue-XLIX(p,m,1,128)"` and the MDS matrix from the echelon
+//! form of a Vandermonde matrix in the generator 5.
+//!
+//! Hashing is fixed-length: `width - 1` inputs fill the rate, the capacity
+//! element holds the input count, and one permutation yields `state[0]`.
+//! Golden values in `res/rescue_prime_golden.txt` pin the output.
+
+use crate::domain::{ports::PoseidonHasher, value_objects::FieldElement};
+use alloc::{format, sync::Arc, vec::Vec};
+use ark_bn254::Fr;
+use ark_ff::{FftField, Field, PrimeField, Zero};
+use sha3::{
+	digest::{ExtendableOutput, Update, XofReader},
+	Shake256,
+};
+
+/// Smallest supported state width (arity 1)
+pub const RESCUE_MIN_WIDTH: usize = 2;
+
+/// Largest supported state width (arity 8)
+pub const RESCUE_MAX_WIDTH: usize = 9;
+
+/// S-box exponent (smallest `alpha` coprime to `p - 1`)
+pub const RESCUE_ALPHA: u64 = 5;
+
+/// `alpha^-1 mod (p - 1)` as little-endian limbs
+pub const RESCUE_ALPHA_INV: [u64; 4] = [
+	0xcfe7f7a98ccccccd,
+	0x535cb9d394945a0d,
+	0x93736af8679aad17,
+	0x26b6a528b427b354,
+];
+
+/// Capacity elements
+const CAPACITY: usize = 1;
+
+/// Target security level in bits
+const SECURITY_LEVEL: usize = 128;
+
+/// Rounds from the reference Groebner-basis bound (+50%, minimum 8), indexed
+/// by `width - RESCUE_MIN_WIDTH`
+const ROUNDS: [usize; RESCUE_MAX_WIDTH - RESCUE_MIN_WIDTH + 1] = [20, 14, 11, 9, 8, 8, 8, 8];
+
+/// Bytes of SHAKE256 output per round constant
+const BYTES_PER_CONSTANT: usize = 33;
+
+/// Errors that can occur during parameter generation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RescuePrimeError {
+	/// Width outside `RESCUE_MIN_WIDTH..=RESCUE_MAX_WIDTH`
+	UnsupportedWidth(usize),
+}
+
+impl core::fmt::Display for RescuePrimeError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			RescuePrimeError::UnsupportedWidth(width) => write!(
+				f,
+				"Unsupported Rescue-Prime width {width} (expected {RESCUE_MIN_WIDTH}..={RESCUE_MAX_WIDTH})"
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RescuePrimeError {}
+
+/// Round constants and MDS matrix for one Rescue-Prime width
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RescuePrimeParameters {
+	/// State width `m` (arity + 1)
+	pub width: usize,
+	/// Number of rounds (each with a forward and an inverse S-box layer)
+	pub rounds: usize,
+	/// `2 * rounds * width` constants, round-major
+	pub round_constants: Vec<Fr>,
+	/// `width x width` MDS matrix, row-major
+	pub mds: Vec<Vec<Fr>>,
+}
+
+impl RescuePrimeParameters {
+	/// Generates the reference parameters for `width`
+	pub fn generate(width: usize) -> Result<Self, RescuePrimeError> {
+		if !(RESCUE_MIN_WIDTH..=RESCUE_MAX_WIDTH).contains(&width) {
+			return Err(RescuePrimeError::UnsupportedWidth(width));
+		}
+		let rounds = ROUNDS[width - RESCUE_MIN_WIDTH];
+
+		Ok(Self {
+			width,
+			rounds,
+			round_constants: round_constants(width, rounds),
+			mds: mds_matrix(width),
+		})
+	}
+
+	/// Applies the Rescue-Prime permutation to `state` in place
+	///
+	/// # Panics
+	/// If `state.len() != width`.
+	pub fn permute(&self, state: &mut [Fr]) {
+		assert_eq!(state.len(), self.width, "State length must equal width");
+
+		for constants in self.round_constants.chunks_exact(2 * self.width) {
+			let (first, second) = constants.split_at(self.width);
+			state
+				.iter_mut()
+				.for_each(|element| *element = element.pow([RESCUE_ALPHA]));
+			self.mix(state, first);
+			state
+				.iter_mut()
+				.for_each(|element| *element = element.pow(RESCUE_ALPHA_INV));
+			self.mix(state, second);
+		}
+	}
+
+	/// Fixed-length hash of `width - 1` inputs
+	///
+	/// # Panics
+	/// If `inputs.len() != width - 1`.
+	pub fn hash(&self, inputs: &[Fr]) -> Fr {
+		assert_eq!(
+			inputs.len() + CAPACITY,
+			self.width,
+			"Input count must be width - 1"
+		);
+
+		let mut state = Vec::with_capacity(self.width);
+		state.extend_from_slice(inputs);
+		state.push(Fr::from(inputs.len() as u64));
+		self.permute(&mut state);
+		state[0]
+	}
+
+	/// `state = MDS * state + constants`
+	fn mix(&self, state: &mut [Fr], constants: &[Fr]) {
+		let mixed: Vec<Fr> = self
+			.mds
+			.iter()
+			.zip(constants)
+			.map(|(row, constant)| {
+				row.iter()
+					.zip(state.iter())
+					.map(|(m, s)| *m * s)
+					.sum::<Fr>() + constant
+			})
+			.collect();
+		state.copy_from_slice(&mixed);
+	}
+}
+
+/// SHAKE256-derived round constants, reduced mod `p`
+fn round_constants(width: usize, rounds: usize) -> Vec<Fr> {
+	let seed = format!(
+		"Rescue-XLIX({},{width},{CAPACITY},{SECURITY_LEVEL})",
+		Fr::MODULUS
+	);
+	let mut shake = Shake256::default();
+	shake.update(seed.as_bytes());
+	let mut reader = shake.finalize_xof();
+
+	(0..2 * width * rounds)
+		.map(|_| {
+			let mut chunk = [0u8; BYTES_PER_CONSTANT];
+			reader.read(&mut chunk);
+			Fr::from_le_bytes_mod_order(&chunk)
+		})
+		.collect()
+}
+
+/// Transposed right half of the reduced echelon form of the `width x 2 width`
+/// Vandermonde matrix `V[i][j] = g^(i * j)`
+fn mds_matrix(width: usize) -> Vec<Vec<Fr>> {
+	let generator = Fr::GENERATOR;
+	let mut matrix: Vec<Vec<Fr>> = (0..width)
+		.map(|i| {
+			(0..2 * width)
+				.map(|j| generator.pow([(i * j) as u64]))
+				.collect()
+		})
+		.collect();
+
+	// The left block is an invertible Vandermonde matrix, so every column pivots
+	for column in 0..width {
+		let pivot = (column..width)
+			.find(|&row| !matrix[row][column].is_zero())
+			.expect("Vandermonde block is invertible");
+		matrix.swap(column, pivot);
+
+		let inverse = matrix[column][column].inverse().expect("Pivot is non-zero");
+		matrix[column].iter_mut().for_each(|x| *x *= inverse);
+
+		let pivot_row = matrix[column].clone();
+		for (row, entries) in matrix.iter_mut().enumerate() {
+			let factor = entries[column];
+			if row != column && !factor.is_zero() {
+				entries
+					.iter_mut()
+					.zip(&pivot_row)
+					.for_each(|(x, p)| *x -= factor * p);
+			}
+		}
+	}
+
+	(0..width)
+		.map(|i| (0..width).map(|j| matrix[j][width + i]).collect())
+		.collect()
+}
+
+// ============================================================================
+// Hasher Adapter
+// ============================================================================
+
+/// Rescue-Prime adapter for the `PoseidonHasher` port
+///
+/// Parameters are generated once and shared between clones.
+#[derive(Debug, Clone)]
+pub struct RescuePrimeHasher {
+	width_3: Arc<RescuePrimeParameters>,
+	width_5: Arc<RescuePrimeParameters>,
+}
+
+impl RescuePrimeHasher {
+	/// Generates the width-3 and width-5 parameters
+	pub fn new() -> Self {
+		let generate =
+			|width| Arc::new(RescuePrimeParameters::generate(width).expect("Supported width"));
+		Self {
+			width_3: generate(3),
+			width_5: generate(5),
+		}
+	}
+}
+
+impl Default for RescuePrimeHasher {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl PoseidonHasher for RescuePrimeHasher {
+	fn hash_2(&self, inputs: [FieldElement; 2]) -> FieldElement {
+		FieldElement::new(self.width_3.hash(&inputs.map(|input| input.inner())))
+	}
+
+	fn hash_4(&self, inputs: [FieldElement; 4]) -> FieldElement {
+		FieldElement::new(self.width_5.hash(&inputs.map(|input| input.inner())))
+	}
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::infrastructure::crypto::LightPoseidonHasher;
+	use alloc::vec;
+	use ark_ff::BigInteger;
+
+	const GOLDEN: &str = include_str!("./res/rescue_prime_golden.txt");
+
+	fn parse_hex(hex: &str) -> Fr {
+		let digits = hex.trim_start_matches("0x");
+		let bytes: Vec<u8> = (0..digits.len())
+			.step_by(2)
+			.map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap())
+			.collect();
+		Fr::from_be_bytes_mod_order(&bytes)
+	}
+
+	// ===== Golden Tests =====
+
+	#[test]
+	fn test_matches_golden_file() {
+		let lines: Vec<&str> = GOLDEN
+			.lines()
+			.filter(|line| !line.starts_with('#'))
+			.collect();
+		assert_eq!(lines.len(), RESCUE_MAX_WIDTH - RESCUE_MIN_WIDTH + 1);
+
+		for line in lines {
+			let fields: Vec<&str> = line.split_whitespace().collect();
+			let width: usize = fields[0].parse().unwrap();
+			let params = RescuePrimeParameters::generate(width).unwrap();
+			let inputs: Vec<Fr> = (1..width as u64).map(Fr::from).collect();
+
+			assert_eq!(params.rounds, fields[1].parse::<usize>().unwrap());
+			assert_eq!(params.round_constants[0], parse_hex(fields[2]), "m={width}");
+			assert_eq!(
+				*params.round_constants.last().unwrap(),
+				parse_hex(fields[3]),
+				"m={width}"
+			);
+			assert_eq!(params.mds[0][0], parse_hex(fields[4]), "m={width}");
+			assert_eq!(params.mds[width - 1][width - 1], parse_hex(fields[5]));
+			assert_eq!(params.hash(&inputs), parse_hex(fields[6]), "m={width}");
+		}
+	}
+
+	// ===== Parameter Tests =====
+
+	#[test]
+	fn test_alpha_inverse() {
+		for value in [0u64, 1, 2, 123456789, u64::MAX] {
+			let x = Fr::from(value);
+			assert_eq!(x.pow([RESCUE_ALPHA]).pow(RESCUE_ALPHA_INV), x);
+			assert_eq!(x.pow(RESCUE_ALPHA_INV).pow([RESCUE_ALPHA]), x);
+		}
+	}
+
+	#[test]
+	fn test_parameter_shape() {
+		for width in RESCUE_MIN_WIDTH..=RESCUE_MAX_WIDTH {
+			let params = RescuePrimeParameters::generate(width).unwrap();
+			assert_eq!(params.round_constants.len(), 2 * params.rounds * width);
+			assert_eq!(params.mds.len(), width);
+			assert!(params.mds.iter().all(|row| row.len() == width));
+		}
+	}
+
+	#[test]
+	fn test_unsupported_width() {
+		assert_eq!(
+			RescuePrimeParameters::generate(1),
+			Err(RescuePrimeError::UnsupportedWidth(1))
+		);
+		assert_eq!(
+			RescuePrimeParameters::generate(10),
+			Err(RescuePrimeError::UnsupportedWidth(10))
+		);
+	}
+
+	#[test]
+	fn test_permutation_is_input_sensitive() {
+		let params = RescuePrimeParameters::generate(3).unwrap();
+		let mut a = vec![Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)];
+		let mut b = vec![Fr::from(1u64), Fr::from(2u64), Fr::from(4u64)];
+		params.permute(&mut a);
+		params.permute(&mut b);
+		assert_ne!(a, b);
+	}
+
+	// ===== Hasher Tests =====
+
+	#[test]
+	fn test_hasher_matches_parameters() {
+		let hasher = RescuePrimeHasher::new();
+		let inputs = [1u64, 2, 3, 4].map(FieldElement::from_u64);
+
+		assert_eq!(
+			hasher.hash_2([inputs[0], inputs[1]]).inner(),
+			RescuePrimeParameters::generate(3)
+				.unwrap()
+				.hash(&[Fr::from(1u64), Fr::from(2u64)])
+		);
+		assert_eq!(
+			hasher.hash_4(inputs).inner(),
+			RescuePrimeParameters::generate(5)
+				.unwrap()
+				.hash(&inputs.map(|input| input.inner()))
+		);
+	}
+
+	#[test]
+	fn test_differs_from_poseidon() {
+		let inputs = [FieldElement::from_u64(1), FieldElement::from_u64(2)];
+		assert_ne!(
+			RescuePrimeHasher::new().hash_2(inputs),
+			LightPoseidonHasher.hash_2(inputs)
+		);
+	}
+
+	#[test]
+	fn test_modulus_seed_is_decimal() {
+		let seed = format!("{}", Fr::MODULUS);
+		assert!(seed.starts_with("218882428718392752222464057452572750885483644004"));
+		assert!(Fr::MODULUS.is_odd());
+	}
+}
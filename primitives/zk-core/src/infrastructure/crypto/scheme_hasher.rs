@@ -0,0 +1,115 @@
+//! Scheme Hasher - Infrastructure Adapter
+//!
+//! `PoseidonHasher` implementation selected at runtime by `HashScheme`, so the
+//! same domain services can be instantiated over Poseidon or Rescue-Prime.
+
+use crate::{
+	domain::{
+		ports::PoseidonHasher,
+		value_objects::{FieldElement, HashScheme},
+	},
+	infrastructure::crypto::{
+		poseidon_hasher::LightPoseidonHasher, rescue_prime::RescuePrimeHasher,
+	},
+};
+
+/// Hasher dispatching to the backend for a `HashScheme`
+#[derive(Debug, Clone)]
+pub enum SchemeHasher {
+	/// circomlib-compatible Poseidon
+	Poseidon(LightPoseidonHasher),
+	/// Rescue-Prime over BN254
+	RescuePrime(RescuePrimeHasher),
+}
+
+impl SchemeHasher {
+	/// Create the hasher for `scheme`
+	pub fn new(scheme: HashScheme) -> Self {
+		match scheme {
+			HashScheme::Poseidon => Self::Poseidon(LightPoseidonHasher),
+			HashScheme::RescuePrime => Self::RescuePrime(RescuePrimeHasher::new()),
+		}
+	}
+
+	/// Scheme this hasher implements
+	pub fn scheme(&self) -> HashScheme {
+		match self {
+			Self::Poseidon(_) => HashScheme::Poseidon,
+			Self::RescuePrime(_) => HashScheme::RescuePrime,
+		}
+	}
+}
+
+impl Default for SchemeHasher {
+	fn default() -> Self {
+		Self::new(HashScheme::default())
+	}
+}
+
+impl PoseidonHasher for SchemeHasher {
+	fn hash_2(&self, inputs: [FieldElement; 2]) -> FieldElement {
+		match self {
+			Self::Poseidon(hasher) => hasher.hash_2(inputs),
+			Self::RescuePrime(hasher) => hasher.hash_2(inputs),
+		}
+	}
+
+	fn hash_4(&self, inputs: [FieldElement; 4]) -> FieldElement {
+		match self {
+			Self::Poseidon(hasher) => hasher.hash_4(inputs),
+			Self::RescuePrime(hasher) => hasher.hash_4(inputs),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::{
+		entities::Note,
+		value_objects::{Blinding, OwnerPubkey},
+	};
+
+	fn inputs() -> [FieldElement; 4] {
+		[1u64, 2, 3, 4].map(FieldElement::from_u64)
+	}
+
+	#[test]
+	fn test_scheme_roundtrip() {
+		for scheme in HashScheme::ALL {
+			assert_eq!(SchemeHasher::new(scheme).scheme(), scheme);
+		}
+		assert_eq!(SchemeHasher::default().scheme(), HashScheme::Poseidon);
+	}
+
+	#[test]
+	fn test_dispatches_to_backend() {
+		let [a, b, c, d] = inputs();
+
+		let poseidon = SchemeHasher::new(HashScheme::Poseidon);
+		assert_eq!(poseidon.hash_2([a, b]), LightPoseidonHasher.hash_2([a, b]));
+		assert_eq!(
+			poseidon.hash_4(inputs()),
+			LightPoseidonHasher.hash_4(inputs())
+		);
+
+		let rescue = SchemeHasher::new(HashScheme::RescuePrime);
+		let reference = RescuePrimeHasher::new();
+		assert_eq!(rescue.hash_2([c, d]), reference.hash_2([c, d]));
+		assert_eq!(rescue.hash_4(inputs()), reference.hash_4(inputs()));
+	}
+
+	#[test]
+	fn test_commitments_depend_on_scheme() {
+		let note = Note::new(
+			100,
+			0,
+			OwnerPubkey::new(FieldElement::from_u64(7)),
+			Blinding::new(FieldElement::from_u64(9)),
+		);
+		assert_ne!(
+			note.commitment(SchemeHasher::new(HashScheme::Poseidon)),
+			note.commitment(SchemeHasher::new(HashScheme::RescuePrime))
+		);
+	}
+}
@@ -10,6 +10,39 @@ use sp_runtime_interface::{
 	runtime_interface,
 };
 
+/// Splits packed 32-byte little-endian inputs into field elements (native side)
+#[cfg(not(substrate_runtime))]
+fn unpack_inputs(inputs: &[u8]) -> Vec<crate::domain::value_objects::FieldElement> {
+	use crate::domain::value_objects::FieldElement;
+	use ark_bn254::Fr;
+	use ark_ff::PrimeField;
+
+	assert_eq!(inputs.len() % 32, 0, "Inputs must be packed 32-byte values");
+	inputs
+		.chunks_exact(32)
+		.map(|chunk| FieldElement::new(Fr::from_le_bytes_mod_order(chunk)))
+		.collect()
+}
+
+/// Encodes a hash result as 32 little-endian bytes (native side)
+#[cfg(not(substrate_runtime))]
+fn pack_output(hash: crate::domain::value_objects::FieldElement) -> Vec<u8> {
+	hash.to_le_bytes().to_vec()
+}
+
+/// Hashes `arity` packed inputs with fixed-arity Poseidon (native side)
+#[cfg(not(substrate_runtime))]
+fn hash_packed(inputs: &[u8], arity: usize) -> Vec<u8> {
+	use crate::infrastructure::crypto::poseidon_hash_n::poseidon_hash_n;
+
+	assert_eq!(
+		inputs.len(),
+		arity * 32,
+		"Expected {arity} packed 32-byte inputs"
+	);
+	pack_output(poseidon_hash_n(&unpack_inputs(inputs)))
+}
+
 /// Native runtime interface for Poseidon hash operations
 ///
 /// Provides native host execution bypassing WASM (~3x speedup).
@@ -25,7 +58,7 @@ pub trait PoseidonHostInterface {
 			infrastructure::crypto::LightPoseidonHasher,
 		};
 		use ark_bn254::Fr;
-		use ark_ff::{BigInteger, PrimeField};
+		use ark_ff::PrimeField;
 
 		// Validate input sizes
 		assert_eq!((*left).len(), 32, "Left input must be 32 bytes");
@@ -46,9 +79,7 @@ pub trait PoseidonHostInterface {
 		let hash_result = hasher.hash_2([FieldElement::new(left_fr), FieldElement::new(right_fr)]);
 
 		// Convert back to bytes (little-endian)
-		let bigint = hash_result.inner().into_bigint();
-		let bytes = bigint.to_bytes_le();
-		bytes[..32].to_vec()
+		hash_result.to_le_bytes().to_vec()
 	}
 
 	/// Hash four 32-byte inputs (note commitment)
@@ -63,7 +94,7 @@ pub trait PoseidonHostInterface {
 			infrastructure::crypto::LightPoseidonHasher,
 		};
 		use ark_bn254::Fr;
-		use ark_ff::{BigInteger, PrimeField};
+		use ark_ff::PrimeField;
 
 		// Validate sizes
 		assert_eq!((*input1).len(), 32, "Input1 must be 32 bytes");
@@ -99,9 +130,54 @@ pub trait PoseidonHostInterface {
 		]);
 
 		// Convert back to bytes
-		let bigint = hash_result.inner().into_bigint();
-		let bytes = bigint.to_bytes_le();
-		bytes[..32].to_vec()
+		hash_result.to_le_bytes().to_vec()
+	}
+
+	/// Hash three 32-byte inputs packed as `input_0 || input_1 || input_2`
+	fn poseidon_hash_3(
+		inputs: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnFatPointer<Vec<u8>> {
+		crate::infrastructure::host_interface::hash_packed(inputs, 3)
+	}
+
+	/// Hash five packed 32-byte inputs
+	fn poseidon_hash_5(
+		inputs: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnFatPointer<Vec<u8>> {
+		crate::infrastructure::host_interface::hash_packed(inputs, 5)
+	}
+
+	/// Hash six packed 32-byte inputs
+	fn poseidon_hash_6(
+		inputs: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnFatPointer<Vec<u8>> {
+		crate::infrastructure::host_interface::hash_packed(inputs, 6)
+	}
+
+	/// Hash seven packed 32-byte inputs
+	fn poseidon_hash_7(
+		inputs: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnFatPointer<Vec<u8>> {
+		crate::infrastructure::host_interface::hash_packed(inputs, 7)
+	}
+
+	/// Hash eight packed 32-byte inputs
+	fn poseidon_hash_8(
+		inputs: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnFatPointer<Vec<u8>> {
+		crate::infrastructure::host_interface::hash_packed(inputs, 8)
+	}
+
+	/// Sponge hash over any number of packed 32-byte inputs (memo hash)
+	fn poseidon_sponge(
+		inputs: PassFatPointerAndRead<&[u8]>,
+	) -> AllocateAndReturnFatPointer<Vec<u8>> {
+		use crate::infrastructure::{
+			crypto::poseidon_sponge::poseidon_sponge,
+			host_interface::{pack_output, unpack_inputs},
+		};
+
+		pack_output(poseidon_sponge(&unpack_inputs(inputs)))
 	}
 }
 
@@ -436,6 +512,74 @@ mod tests {
 		poseidon_host_interface::poseidon_hash_4(&valid, &valid, &valid, &invalid);
 	}
 
+	// ===== Higher Arity Tests =====
+
+	fn packed(count: u64) -> Vec<u8> {
+		(1..=count).flat_map(u64_to_bytes).collect()
+	}
+
+	fn expected_hash_n(count: u64) -> Vec<u8> {
+		use crate::{
+			domain::value_objects::FieldElement,
+			infrastructure::crypto::poseidon_hash_n::poseidon_hash_n,
+		};
+
+		let inputs: Vec<FieldElement> = (1..=count).map(FieldElement::from_u64).collect();
+		pack_output(poseidon_hash_n(&inputs))
+	}
+
+	#[test]
+	fn test_hash_3_to_8_match_wasm() {
+		assert_eq!(
+			poseidon_host_interface::poseidon_hash_3(&packed(3)),
+			expected_hash_n(3)
+		);
+		assert_eq!(
+			poseidon_host_interface::poseidon_hash_5(&packed(5)),
+			expected_hash_n(5)
+		);
+		assert_eq!(
+			poseidon_host_interface::poseidon_hash_6(&packed(6)),
+			expected_hash_n(6)
+		);
+		assert_eq!(
+			poseidon_host_interface::poseidon_hash_7(&packed(7)),
+			expected_hash_n(7)
+		);
+		assert_eq!(
+			poseidon_host_interface::poseidon_hash_8(&packed(8)),
+			expected_hash_n(8)
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "Expected 3 packed 32-byte inputs")]
+	fn test_hash_3_invalid_size() {
+		poseidon_host_interface::poseidon_hash_3(&packed(2));
+	}
+
+	#[test]
+	fn test_sponge_matches_wasm() {
+		use crate::{
+			domain::value_objects::FieldElement,
+			infrastructure::crypto::poseidon_sponge::poseidon_sponge,
+		};
+
+		let inputs: Vec<FieldElement> = (1..=11).map(FieldElement::from_u64).collect();
+		let expected = pack_output(poseidon_sponge(&inputs));
+
+		assert_eq!(
+			poseidon_host_interface::poseidon_sponge(&packed(11)),
+			expected
+		);
+	}
+
+	#[test]
+	#[should_panic(expected = "Inputs must be packed 32-byte values")]
+	fn test_sponge_rejects_partial_input() {
+		poseidon_host_interface::poseidon_sponge(&[0u8; 33]);
+	}
+
 	// ===== Cross-function Tests =====
 
 	#[test]
@@ -1,5 +1,9 @@
 pub mod crypto;
+pub mod random;
 pub mod repositories;
 
 #[cfg(feature = "poseidon-native")]
 pub mod host_interface;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
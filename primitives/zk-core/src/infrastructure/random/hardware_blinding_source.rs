@@ -0,0 +1,98 @@
+//! Hardware Blinding Source - Infrastructure Adapter
+//!
+//! Blinding factors from a hardware entropy device (secure element, HSM, CPU
+//! RNG). Wallets implement [`EntropyDevice`] for their device driver; device
+//! failures surface as `EntropyUnavailable` instead of falling back silently.
+
+use crate::domain::{
+	ports::{
+		blinding_source::{blinding_from_entropy, BLINDING_ENTROPY_BYTES},
+		BlindingSource, BlindingSourceError, RandomnessProvenance,
+	},
+	value_objects::Blinding,
+};
+
+/// Hardware entropy device driver
+pub trait EntropyDevice {
+	/// Fill `dest` with device entropy
+	fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), BlindingSourceError>;
+}
+
+/// Blinding source backed by a hardware entropy device
+#[derive(Debug, Clone)]
+pub struct HardwareBlindingSource<D: EntropyDevice> {
+	device: D,
+}
+
+impl<D: EntropyDevice> HardwareBlindingSource<D> {
+	/// Create a source drawing from `device`
+	pub fn new(device: D) -> Self {
+		Self { device }
+	}
+
+	/// Get the underlying device
+	pub fn device(&self) -> &D {
+		&self.device
+	}
+}
+
+impl<D: EntropyDevice> BlindingSource for HardwareBlindingSource<D> {
+	fn next_blinding(&mut self) -> Result<Blinding, BlindingSourceError> {
+		let mut entropy = [0u8; BLINDING_ENTROPY_BYTES];
+		self.device.fill_entropy(&mut entropy)?;
+		Ok(blinding_from_entropy(&entropy))
+	}
+
+	fn provenance(&self) -> RandomnessProvenance {
+		RandomnessProvenance::Hardware
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	// ===== Mock Devices =====
+
+	struct CountingDevice(u8);
+
+	impl EntropyDevice for CountingDevice {
+		fn fill_entropy(&mut self, dest: &mut [u8]) -> Result<(), BlindingSourceError> {
+			self.0 += 1;
+			dest.fill(self.0);
+			Ok(())
+		}
+	}
+
+	struct FailingDevice;
+
+	impl EntropyDevice for FailingDevice {
+		fn fill_entropy(&mut self, _dest: &mut [u8]) -> Result<(), BlindingSourceError> {
+			Err(BlindingSourceError::EntropyUnavailable)
+		}
+	}
+
+	// ===== Source Tests =====
+
+	#[test]
+	fn test_draws_from_device() {
+		let mut source = HardwareBlindingSource::new(CountingDevice(0));
+		let blinding = source.next_blinding().unwrap();
+
+		assert_eq!(
+			blinding,
+			blinding_from_entropy(&[1u8; BLINDING_ENTROPY_BYTES])
+		);
+		assert_eq!(source.device().0, 1);
+		assert_eq!(source.provenance(), RandomnessProvenance::Hardware);
+	}
+
+	#[test]
+	fn test_device_failure_propagates() {
+		let mut source = HardwareBlindingSource::new(FailingDevice);
+		assert_eq!(
+			source.next_blinding(),
+			Err(BlindingSourceError::EntropyUnavailable)
+		);
+	}
+}
@@ -0,0 +1,11 @@
+pub mod hardware_blinding_source;
+pub mod seeded_blinding_source;
+
+#[cfg(feature = "std")]
+pub mod os_blinding_source;
+
+pub use hardware_blinding_source::{EntropyDevice, HardwareBlindingSource};
+pub use seeded_blinding_source::SeededBlindingSource;
+
+#[cfg(feature = "std")]
+pub use os_blinding_source::OsBlindingSource;
@@ -0,0 +1,47 @@
+//! OS Blinding Source - Infrastructure Adapter
+//!
+//! Blinding factors from the operating system CSPRNG (`getrandom`). The default
+//! choice for wallets.
+
+use crate::domain::{
+	ports::{
+		blinding_source::{blinding_from_entropy, BLINDING_ENTROPY_BYTES},
+		BlindingSource, BlindingSourceError, RandomnessProvenance,
+	},
+	value_objects::Blinding,
+};
+use rand_core::{OsRng, RngCore};
+
+/// Blinding source backed by the OS CSPRNG
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OsBlindingSource;
+
+impl BlindingSource for OsBlindingSource {
+	fn next_blinding(&mut self) -> Result<Blinding, BlindingSourceError> {
+		let mut entropy = [0u8; BLINDING_ENTROPY_BYTES];
+		OsRng
+			.try_fill_bytes(&mut entropy)
+			.map_err(|_| BlindingSourceError::EntropyUnavailable)?;
+		Ok(blinding_from_entropy(&entropy))
+	}
+
+	fn provenance(&self) -> RandomnessProvenance {
+		RandomnessProvenance::OsRandom
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_os_blindings_differ() {
+		let mut source = OsBlindingSource;
+		let a = source.next_blinding().unwrap();
+		let b = source.next_blinding().unwrap();
+
+		assert_ne!(a, b);
+		assert!(!a.inner().is_zero());
+		assert_eq!(source.provenance(), RandomnessProvenance::OsRandom);
+	}
+}
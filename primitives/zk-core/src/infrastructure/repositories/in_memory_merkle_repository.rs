@@ -2,17 +2,38 @@
 //!
 //! Concrete implementation of `MerkleRepository` using in-memory storage.
 //! Suitable for testing and lightweight applications.
+//!
+//! Wallets can take a [`RepositoryCheckpoint`] before speculatively applying
+//! pending transactions and roll back to it on a reorg. With `std` the
+//! repository serializes (via serde) as its list of leaves.
 
 use crate::domain::{
 	repositories::{MerklePath, MerkleRepository, RepositoryError},
 	value_objects::{Commitment, FieldElement},
 };
-use alloc::collections::BTreeMap;
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// Snapshot of an [`InMemoryMerkleRepository`]
+///
+/// Leaves are append-only, so the size plus the last leaf identifies the state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct RepositoryCheckpoint {
+	/// Number of leaves at the time of the checkpoint
+	pub size: u64,
+	/// Last leaf at the time of the checkpoint (`None` for an empty tree)
+	pub last_leaf: Option<FieldElement>,
+}
 
 /// In-memory Merkle tree repository
 ///
 /// Stores commitments in BTreeMap indexed by position.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(
+	feature = "std",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(from = "Vec<FieldElement>", into = "Vec<FieldElement>")
+)]
 pub struct InMemoryMerkleRepository {
 	/// Leaves indexed by position
 	leaves: BTreeMap<u64, FieldElement>,
@@ -39,6 +60,51 @@ impl InMemoryMerkleRepository {
 		self.leaves.clear();
 		self.size = 0;
 	}
+
+	/// Snapshot of the current state
+	pub fn checkpoint(&self) -> RepositoryCheckpoint {
+		RepositoryCheckpoint {
+			size: self.size,
+			last_leaf: self
+				.size
+				.checked_sub(1)
+				.and_then(|last| self.leaves.get(&last).copied()),
+		}
+	}
+
+	/// Drop every leaf inserted after `checkpoint`
+	///
+	/// # Errors
+	/// `InvalidCheckpoint` if the checkpoint is ahead of this repository or
+	/// was taken from a different tree; the repository is left unchanged.
+	pub fn rollback(&mut self, checkpoint: &RepositoryCheckpoint) -> Result<(), RepositoryError> {
+		let last_leaf = checkpoint
+			.size
+			.checked_sub(1)
+			.and_then(|last| self.leaves.get(&last).copied());
+		if checkpoint.size > self.size || last_leaf != checkpoint.last_leaf {
+			return Err(RepositoryError::InvalidCheckpoint);
+		}
+
+		self.leaves.split_off(&checkpoint.size);
+		self.size = checkpoint.size;
+		Ok(())
+	}
+}
+
+impl From<Vec<FieldElement>> for InMemoryMerkleRepository {
+	fn from(leaves: Vec<FieldElement>) -> Self {
+		Self {
+			size: leaves.len() as u64,
+			leaves: (0..).zip(leaves).collect(),
+		}
+	}
+}
+
+impl From<InMemoryMerkleRepository> for Vec<FieldElement> {
+	fn from(repository: InMemoryMerkleRepository) -> Self {
+		repository.leaves.into_values().collect()
+	}
 }
 
 impl MerkleRepository for InMemoryMerkleRepository {
@@ -483,6 +549,124 @@ mod tests {
 		assert_eq!(repo.size(), 3);
 	}
 
+	// ===== Checkpoint Tests =====
+
+	#[test]
+	fn test_rollback_discards_later_leaves() {
+		let mut repo = InMemoryMerkleRepository::new();
+		for i in 0..3 {
+			repo.insert_commitment(create_test_commitment(i)).unwrap();
+		}
+		let checkpoint = repo.checkpoint();
+		assert_eq!(checkpoint.size, 3);
+		assert_eq!(checkpoint.last_leaf, Some(FieldElement::from_u64(2)));
+
+		for i in 10..15 {
+			repo.insert_commitment(create_test_commitment(i)).unwrap();
+		}
+		repo.rollback(&checkpoint).unwrap();
+
+		assert_eq!(repo.size(), 3);
+		assert!(repo.get_commitment(3).is_err());
+		assert_eq!(repo.checkpoint(), checkpoint);
+		// Positions are reused after a rollback
+		assert_eq!(repo.insert_commitment(create_test_commitment(20)), Ok(3));
+	}
+
+	#[test]
+	fn test_rollback_to_empty() {
+		let mut repo = InMemoryMerkleRepository::new();
+		let checkpoint = repo.checkpoint();
+		assert_eq!(checkpoint.last_leaf, None);
+
+		repo.insert_commitment(create_test_commitment(1)).unwrap();
+		repo.rollback(&checkpoint).unwrap();
+		assert_eq!(repo.size(), 0);
+		assert_eq!(repo.get_root(), Err(RepositoryError::TreeEmpty));
+	}
+
+	#[test]
+	fn test_rollback_to_current_state_is_noop() {
+		let mut repo = InMemoryMerkleRepository::new();
+		repo.insert_commitment(create_test_commitment(1)).unwrap();
+		let checkpoint = repo.checkpoint();
+
+		repo.rollback(&checkpoint).unwrap();
+		assert_eq!(repo.size(), 1);
+	}
+
+	#[test]
+	fn test_rollback_rejects_future_checkpoint() {
+		let mut repo = InMemoryMerkleRepository::new();
+		repo.insert_commitment(create_test_commitment(1)).unwrap();
+		let checkpoint = repo.checkpoint();
+		repo.clear();
+
+		assert_eq!(
+			repo.rollback(&checkpoint),
+			Err(RepositoryError::InvalidCheckpoint)
+		);
+	}
+
+	#[test]
+	fn test_rollback_rejects_foreign_checkpoint() {
+		let mut repo = InMemoryMerkleRepository::new();
+		let mut other = InMemoryMerkleRepository::new();
+		for i in 0..3 {
+			repo.insert_commitment(create_test_commitment(i)).unwrap();
+			other
+				.insert_commitment(create_test_commitment(i + 100))
+				.unwrap();
+		}
+		let foreign = other.checkpoint();
+
+		assert_eq!(
+			repo.rollback(&foreign),
+			Err(RepositoryError::InvalidCheckpoint)
+		);
+		assert_eq!(repo.size(), 3);
+	}
+
+	// ===== Serialization Tests =====
+
+	#[test]
+	fn test_leaves_roundtrip() {
+		let mut repo = InMemoryMerkleRepository::new();
+		for i in 0..4 {
+			repo.insert_commitment(create_test_commitment(i)).unwrap();
+		}
+
+		let leaves: alloc::vec::Vec<FieldElement> = repo.clone().into();
+		assert_eq!(leaves.len(), 4);
+		let restored = InMemoryMerkleRepository::from(leaves);
+		assert_eq!(restored.checkpoint(), repo.checkpoint());
+		assert_eq!(restored.get_commitment(3), repo.get_commitment(3));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_serde_roundtrip() {
+		let mut repo = InMemoryMerkleRepository::new();
+		for i in 0..4 {
+			repo.insert_commitment(create_test_commitment(i)).unwrap();
+		}
+		let checkpoint = repo.checkpoint();
+
+		let json = serde_json::to_string(&repo).unwrap();
+		let mut restored: InMemoryMerkleRepository = serde_json::from_str(&json).unwrap();
+		assert_eq!(restored.size(), 4);
+		assert_eq!(restored.checkpoint(), checkpoint);
+
+		let checkpoint_json = serde_json::to_string(&checkpoint).unwrap();
+		restored
+			.insert_commitment(create_test_commitment(9))
+			.unwrap();
+		restored
+			.rollback(&serde_json::from_str(&checkpoint_json).unwrap())
+			.unwrap();
+		assert_eq!(restored.size(), 4);
+	}
+
 	// ===== Trait Implementation Tests =====
 
 	#[test]
@@ -1 +1,3 @@
 pub mod in_memory_merkle_repository;
+#[cfg(feature = "substrate")]
+pub mod storage_merkle_repository;
@@ -0,0 +1,306 @@
+//! Storage Merkle Repository - Infrastructure Implementation
+//!
+//! `MerkleRepository` over runtime storage, so the pallet's tree logic can be
+//! written against the domain port and tested against
+//! `InMemoryMerkleRepository`. The pallet supplies its storage items through
+//! [`MerkleStorage`]; roots and paths are recomputed from the stored leaves with
+//! the same layout as `pallet-shielded-pool` (zero leaves, `Poseidon(left, right)`
+//! nodes, 32-byte little-endian encoding).
+//!
+//! Historic roots stay with the pallet: this adapter only maintains leaves, the
+//! tree size and the current root.
+
+use crate::{
+	domain::{
+		ports::PoseidonHasher,
+		repositories::{MerklePath, MerkleRepository, RepositoryError, RepositoryResult},
+		value_objects::{Commitment, FieldElement},
+	},
+	infrastructure::crypto::merkle::{bytes_to_field, zero_hashes, MerkleTreeError, MAX_DEPTH},
+};
+use alloc::{string::ToString, vec::Vec};
+use core::marker::PhantomData;
+
+/// Runtime storage backing a [`StorageMerkleRepository`]
+///
+/// Implemented by the pallet on a marker type, one function per storage item
+/// (e.g. `MerkleLeaves`, `MerkleTreeSize`, `PoseidonRoot`).
+pub trait MerkleStorage {
+	/// Leaf at `index`, if inserted
+	fn leaf(index: u32) -> Option<[u8; 32]>;
+	/// Store the leaf at `index`
+	fn insert_leaf(index: u32, leaf: [u8; 32]);
+	/// Number of stored leaves
+	fn size() -> u32;
+	/// Update the number of stored leaves
+	fn set_size(size: u32);
+	/// Current root
+	fn root() -> [u8; 32];
+	/// Update the current root
+	fn set_root(root: [u8; 32]);
+}
+
+/// Merkle repository over runtime storage
+pub struct StorageMerkleRepository<S: MerkleStorage, H: PoseidonHasher> {
+	hasher: H,
+	depth: usize,
+	/// Zero hash per level (`depth + 1` entries)
+	zeros: Vec<FieldElement>,
+	_storage: PhantomData<S>,
+}
+
+impl<S: MerkleStorage, H: PoseidonHasher> StorageMerkleRepository<S, H> {
+	/// Create a repository for a tree of `depth` levels
+	///
+	/// # Panics
+	/// If `depth` is zero or greater than `MAX_DEPTH`.
+	pub fn new(hasher: H, depth: usize) -> Self {
+		assert!(
+			depth > 0 && depth <= MAX_DEPTH,
+			"Merkle tree depth must be in 1..={MAX_DEPTH}"
+		);
+
+		let zeros = zero_hashes(&hasher, depth);
+		Self {
+			hasher,
+			depth,
+			zeros,
+			_storage: PhantomData,
+		}
+	}
+
+	/// Tree depth
+	pub fn depth(&self) -> usize {
+		self.depth
+	}
+
+	/// Maximum number of leaves (bounded by the `u32` storage index)
+	pub fn capacity(&self) -> u64 {
+		(1u64 << self.depth).min(u32::MAX as u64)
+	}
+
+	fn leaves(&self) -> RepositoryResult<Vec<FieldElement>> {
+		(0..S::size())
+			.map(|index| {
+				S::leaf(index)
+					.map(|leaf| bytes_to_field(&leaf))
+					.ok_or_else(|| RepositoryError::StorageError("Missing Merkle leaf".to_string()))
+			})
+			.collect()
+	}
+
+	/// Root of `nodes` and the siblings of the leaf at `index`, leaf to root
+	fn authenticate(
+		&self,
+		mut nodes: Vec<FieldElement>,
+		mut index: usize,
+	) -> (FieldElement, Vec<FieldElement>) {
+		let mut siblings = Vec::with_capacity(self.depth);
+		for level in 0..self.depth {
+			let zero = self.zeros[level];
+			siblings.push(nodes.get(index ^ 1).copied().unwrap_or(zero));
+
+			nodes = nodes
+				.chunks(2)
+				.map(|pair| {
+					let right = pair.get(1).copied().unwrap_or(zero);
+					self.hasher.hash_2([pair[0], right])
+				})
+				.collect();
+			index /= 2;
+		}
+
+		let root = nodes.first().copied().unwrap_or(self.zeros[self.depth]);
+		(root, siblings)
+	}
+}
+
+impl<S: MerkleStorage, H: PoseidonHasher> MerkleRepository for StorageMerkleRepository<S, H> {
+	fn insert_commitment(&mut self, commitment: Commitment) -> RepositoryResult<u64> {
+		let index = S::size();
+		if index as u64 >= self.capacity() {
+			return Err(RepositoryError::StorageError(
+				MerkleTreeError::TreeFull.to_string(),
+			));
+		}
+
+		S::insert_leaf(index, commitment.inner().to_le_bytes());
+		S::set_size(index + 1);
+
+		let (root, _) = self.authenticate(self.leaves()?, 0);
+		S::set_root(root.to_le_bytes());
+		Ok(index as u64)
+	}
+
+	fn get_root(&self) -> RepositoryResult<FieldElement> {
+		if S::size() == 0 {
+			return Err(RepositoryError::TreeEmpty);
+		}
+		Ok(bytes_to_field(&S::root()))
+	}
+
+	fn get_proof(&self, leaf_index: u64) -> RepositoryResult<MerklePath> {
+		if leaf_index >= S::size() as u64 {
+			return Err(RepositoryError::LeafNotFound(leaf_index));
+		}
+
+		let (_, siblings) = self.authenticate(self.leaves()?, leaf_index as usize);
+		Ok(MerklePath::new(leaf_index, siblings))
+	}
+
+	fn size(&self) -> u64 {
+		S::size() as u64
+	}
+
+	fn get_commitment(&self, leaf_index: u64) -> RepositoryResult<Commitment> {
+		u32::try_from(leaf_index)
+			.ok()
+			.and_then(S::leaf)
+			.map(|leaf| Commitment::new(bytes_to_field(&leaf)))
+			.ok_or(RepositoryError::LeafNotFound(leaf_index))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::infrastructure::{
+		crypto::{merkle::IncrementalMerkleTree, LightPoseidonHasher},
+		repositories::in_memory_merkle_repository::InMemoryMerkleRepository,
+	};
+	use std::{cell::RefCell, collections::BTreeMap};
+
+	const DEPTH: usize = 4;
+
+	std::thread_local! {
+		static LEAVES: RefCell<BTreeMap<u32, [u8; 32]>> = const { RefCell::new(BTreeMap::new()) };
+		static SIZE: RefCell<u32> = const { RefCell::new(0) };
+		static ROOT: RefCell<[u8; 32]> = const { RefCell::new([0u8; 32]) };
+	}
+
+	/// Thread-local stand-in for pallet storage (each test runs on its own thread)
+	struct TestStorage;
+
+	impl MerkleStorage for TestStorage {
+		fn leaf(index: u32) -> Option<[u8; 32]> {
+			LEAVES.with(|leaves| leaves.borrow().get(&index).copied())
+		}
+
+		fn insert_leaf(index: u32, leaf: [u8; 32]) {
+			LEAVES.with(|leaves| leaves.borrow_mut().insert(index, leaf));
+		}
+
+		fn size() -> u32 {
+			SIZE.with(|size| *size.borrow())
+		}
+
+		fn set_size(size: u32) {
+			SIZE.with(|cell| *cell.borrow_mut() = size);
+		}
+
+		fn root() -> [u8; 32] {
+			ROOT.with(|root| *root.borrow())
+		}
+
+		fn set_root(root: [u8; 32]) {
+			ROOT.with(|cell| *cell.borrow_mut() = root);
+		}
+	}
+
+	fn repository() -> StorageMerkleRepository<TestStorage, LightPoseidonHasher> {
+		StorageMerkleRepository::new(LightPoseidonHasher, DEPTH)
+	}
+
+	fn commitment(value: u64) -> Commitment {
+		Commitment::new(FieldElement::from_u64(value))
+	}
+
+	// ===== Port Behaviour Tests =====
+
+	#[test]
+	fn test_matches_in_memory_repository() {
+		let mut storage = repository();
+		let mut memory = InMemoryMerkleRepository::new();
+
+		assert_eq!(storage.get_root(), memory.get_root());
+		for value in 1..=5 {
+			assert_eq!(
+				storage.insert_commitment(commitment(value)),
+				memory.insert_commitment(commitment(value))
+			);
+		}
+
+		assert_eq!(MerkleRepository::size(&storage), memory.size());
+		for index in 0..6 {
+			assert_eq!(storage.get_commitment(index), memory.get_commitment(index));
+		}
+		assert_eq!(
+			storage.get_proof(5).unwrap_err(),
+			memory.get_proof(5).unwrap_err()
+		);
+	}
+
+	#[test]
+	fn test_empty_tree() {
+		let storage = repository();
+		assert_eq!(storage.get_root(), Err(RepositoryError::TreeEmpty));
+		assert_eq!(storage.get_proof(0), Err(RepositoryError::LeafNotFound(0)));
+		assert_eq!(
+			storage.get_commitment(u64::MAX),
+			Err(RepositoryError::LeafNotFound(u64::MAX))
+		);
+	}
+
+	// ===== Root and Proof Tests =====
+
+	#[test]
+	fn test_root_and_proofs_match_incremental_tree() {
+		let mut storage = repository();
+		let mut tree = IncrementalMerkleTree::new(LightPoseidonHasher, DEPTH);
+
+		for value in 1..=7 {
+			storage.insert_commitment(commitment(value)).unwrap();
+			tree.append(FieldElement::from_u64(value)).unwrap();
+			assert_eq!(storage.get_root().unwrap(), tree.root());
+		}
+		assert_eq!(TestStorage::root(), tree.root().to_le_bytes());
+
+		for index in 0..7 {
+			assert_eq!(
+				storage.get_proof(index).unwrap(),
+				tree.witness(index).unwrap()
+			);
+		}
+	}
+
+	#[test]
+	fn test_tree_full() {
+		let mut storage = repository();
+		for value in 0..storage.capacity() {
+			storage.insert_commitment(commitment(value)).unwrap();
+		}
+		assert!(matches!(
+			storage.insert_commitment(commitment(99)),
+			Err(RepositoryError::StorageError(_))
+		));
+		assert_eq!(MerkleRepository::size(&storage), 1 << DEPTH);
+	}
+
+	#[test]
+	fn test_missing_leaf_is_storage_error() {
+		let mut storage = repository();
+		storage.insert_commitment(commitment(1)).unwrap();
+		TestStorage::set_size(2);
+
+		assert!(matches!(
+			storage.get_proof(1),
+			Err(RepositoryError::StorageError(_))
+		));
+	}
+
+	#[test]
+	#[should_panic(expected = "Merkle tree depth must be in")]
+	fn test_invalid_depth() {
+		let _ = StorageMerkleRepository::<TestStorage, _>::new(LightPoseidonHasher, 0);
+	}
+}
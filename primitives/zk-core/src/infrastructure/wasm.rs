@@ -0,0 +1,208 @@
+//! WebAssembly bindings for browser wallets
+//!
+//! Thin `wasm-bindgen` wrappers over the same code paths the chain uses, so
+//! wallets do not maintain a drifting JS reimplementation. Field elements cross
+//! the boundary as canonical 32-byte little-endian arrays and notes as the
+//! canonical 81-byte note encoding.
+
+use crate::{
+	domain::{
+		entities::Note,
+		services::MerkleService,
+		value_objects::{Blinding, Commitment, FieldElement, OwnerPubkey, SpendingKey},
+	},
+	infrastructure::crypto::{batch, LightPoseidonHasher},
+};
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+/// Creates a note and returns its canonical encoding
+#[wasm_bindgen(js_name = createNote)]
+pub fn create_note(
+	value: u64,
+	asset_id: u64,
+	owner_pubkey: &[u8],
+	blinding: &[u8],
+) -> Result<Vec<u8>, JsError> {
+	let owner_pubkey = OwnerPubkey::new(parse_field(owner_pubkey).map_err(JsError::new)?);
+	let blinding = Blinding::new(parse_field(blinding).map_err(JsError::new)?);
+
+	Ok(Note::new(value, asset_id, owner_pubkey, blinding)
+		.to_bytes()
+		.to_vec())
+}
+
+/// Computes the commitment of an encoded note
+#[wasm_bindgen(js_name = noteCommitment)]
+pub fn note_commitment(note: &[u8]) -> Result<Vec<u8>, JsError> {
+	let note = parse_note(note).map_err(JsError::new)?;
+	Ok(note
+		.commitment(LightPoseidonHasher)
+		.inner()
+		.to_le_bytes()
+		.to_vec())
+}
+
+/// Computes the nullifier of an encoded note
+#[wasm_bindgen(js_name = noteNullifier)]
+pub fn note_nullifier(note: &[u8], spending_key: &[u8]) -> Result<Vec<u8>, JsError> {
+	let note = parse_note(note).map_err(JsError::new)?;
+	let spending_key = SpendingKey::new(parse_field(spending_key).map_err(JsError::new)?);
+
+	Ok(note
+		.nullifier(LightPoseidonHasher, &spending_key)
+		.inner()
+		.to_le_bytes()
+		.to_vec())
+}
+
+/// Computes the commitments of concatenated encoded notes (32 bytes each, in order)
+#[wasm_bindgen(js_name = computeCommitments)]
+pub fn compute_commitments(notes: &[u8]) -> Result<Vec<u8>, JsError> {
+	let notes = parse_notes(notes).map_err(JsError::new)?;
+	Ok(batch::compute_commitments(&notes)
+		.iter()
+		.flat_map(|commitment| commitment.inner().to_le_bytes())
+		.collect())
+}
+
+/// Verifies a Merkle membership proof (`siblings` are concatenated 32-byte hashes, leaf first)
+#[wasm_bindgen(js_name = verifyMerkleProof)]
+pub fn verify_merkle_proof(
+	leaf: &[u8],
+	leaf_index: u64,
+	siblings: &[u8],
+	root: &[u8],
+) -> Result<bool, JsError> {
+	merkle_proof_is_valid(leaf, leaf_index, siblings, root).map_err(JsError::new)
+}
+
+// ============================================================================
+// Parsing
+// ============================================================================
+
+fn parse_field(bytes: &[u8]) -> Result<FieldElement, &'static str> {
+	let bytes: &[u8; 32] = bytes
+		.try_into()
+		.map_err(|_| "Field element must be 32 bytes")?;
+	FieldElement::from_canonical_le_bytes(bytes).ok_or("Non-canonical field element")
+}
+
+fn parse_note(bytes: &[u8]) -> Result<Note, &'static str> {
+	Note::from_bytes(bytes).map_err(|_| "Invalid note encoding")
+}
+
+fn parse_notes(bytes: &[u8]) -> Result<Vec<Note>, &'static str> {
+	use crate::domain::entities::note_encoding::NOTE_ENCODED_LENGTH;
+
+	let notes = bytes.chunks_exact(NOTE_ENCODED_LENGTH);
+	if !notes.remainder().is_empty() {
+		return Err("Notes must be concatenated canonical encodings");
+	}
+	notes.map(parse_note).collect()
+}
+
+fn merkle_proof_is_valid(
+	leaf: &[u8],
+	leaf_index: u64,
+	siblings: &[u8],
+	root: &[u8],
+) -> Result<bool, &'static str> {
+	let chunks = siblings.chunks_exact(32);
+	if !chunks.remainder().is_empty() {
+		return Err("Siblings must be concatenated 32-byte hashes");
+	}
+	let siblings = chunks.map(parse_field).collect::<Result<Vec<_>, _>>()?;
+	if siblings.len() < 64 && leaf_index >> siblings.len() != 0 {
+		return Err("Leaf index out of range for proof depth");
+	}
+	let path_indices: Vec<bool> = (0..siblings.len())
+		.map(|level| (leaf_index >> level) & 1 == 1)
+		.collect();
+
+	Ok(MerkleService::new(LightPoseidonHasher).verify_proof(
+		&Commitment::new(parse_field(leaf)?),
+		&siblings,
+		&path_indices,
+		&parse_field(root)?,
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::domain::ports::PoseidonHasher;
+
+	fn field_bytes(value: u64) -> Vec<u8> {
+		FieldElement::from_u64(value).to_le_bytes().to_vec()
+	}
+
+	// ===== Note Tests =====
+
+	#[test]
+	fn test_note_roundtrip_matches_domain() {
+		let encoded = create_note(100, 1, &field_bytes(7), &field_bytes(9)).unwrap();
+		let note = Note::from_bytes(&encoded).unwrap();
+
+		assert_eq!(
+			note_commitment(&encoded).unwrap(),
+			note.commitment(LightPoseidonHasher)
+				.inner()
+				.to_le_bytes()
+				.to_vec()
+		);
+		assert_eq!(
+			note_nullifier(&encoded, &field_bytes(3)).unwrap(),
+			note.nullifier(
+				LightPoseidonHasher,
+				&SpendingKey::new(FieldElement::from_u64(3))
+			)
+			.inner()
+			.to_le_bytes()
+			.to_vec()
+		);
+	}
+
+	#[test]
+	fn test_batch_commitments() {
+		let mut notes = create_note(1, 0, &field_bytes(7), &field_bytes(9)).unwrap();
+		notes.extend(create_note(2, 0, &field_bytes(7), &field_bytes(9)).unwrap());
+
+		let commitments = compute_commitments(&notes).unwrap();
+		assert_eq!(commitments.len(), 64);
+		assert_eq!(
+			commitments[32..],
+			note_commitment(&notes[81..]).unwrap()[..]
+		);
+	}
+
+	#[test]
+	fn test_parse_errors() {
+		assert!(parse_field(&[0u8; 31]).is_err());
+		assert!(parse_field(&[0xff; 32]).is_err());
+		assert!(parse_note(&[0u8; 81]).is_err());
+		assert!(parse_notes(&[0u8; 80]).is_err());
+	}
+
+	// ===== Merkle Tests =====
+
+	#[test]
+	fn test_verify_merkle_proof() {
+		let hasher = LightPoseidonHasher;
+		let leaf = FieldElement::from_u64(10);
+		let sibling_0 = FieldElement::from_u64(20);
+		let sibling_1 = FieldElement::from_u64(30);
+		// leaf_index = 1: leaf is the right child at level 0, left child at level 1
+		let level_1 = hasher.hash_2([sibling_0, leaf]);
+		let root = hasher.hash_2([level_1, sibling_1]);
+
+		let mut siblings = sibling_0.to_le_bytes().to_vec();
+		siblings.extend(sibling_1.to_le_bytes());
+
+		let leaf = leaf.to_le_bytes();
+		let root = root.to_le_bytes();
+		assert!(verify_merkle_proof(&leaf, 1, &siblings, &root).unwrap());
+		assert!(!verify_merkle_proof(&leaf, 0, &siblings, &root).unwrap());
+		assert!(merkle_proof_is_valid(&leaf, 4, &siblings, &root).is_err());
+	}
+}
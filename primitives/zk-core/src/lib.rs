@@ -10,7 +10,8 @@
 //! ## Architecture
 //!
 //! - **Domain**: Pure business logic with Poseidon-based cryptographic primitives
-//! - **Application**: Use cases for note creation, commitment computation, nullifiers
+//! - **Application**: Use cases for note creation, commitment computation, nullifiers,
+//!   and signed payment disclosures
 //! - **Infrastructure**: Concrete Poseidon implementations and storage adapters
 //!
 //! ## Features
@@ -18,11 +19,17 @@
 //! - `std`: Enable standard library support (default)
 //! - `poseidon-native`: Enable native Poseidon host functions for 3x performance boost (default)
 //! - `native-poseidon`: Legacy alias for `poseidon-native` (deprecated)
+//! - `wasm`: `wasm-bindgen` exports for browser wallets
+//! - `substrate`: SCALE `Encode`/`Decode`/`TypeInfo` for `Note`, `Commitment`, `Nullifier`,
+//!   `FieldElement` and `MerkleProofDto`, plus `StorageMerkleRepository` over runtime storage
+//! - `rescue-prime`: Rescue-Prime backend (`RescuePrimeHasher`, `SchemeHasher`) for
+//!   research deployments comparing proving costs; not accepted on-chain
 //!
 //! ## Hash Functions
 //!
-//! **Poseidon Only**: This crate exclusively uses Poseidon hash functions for all
-//! cryptographic operations, ensuring ZK-circuit compatibility and optimal proof generation.
+//! **Poseidon**: All production operations use Poseidon hash functions, ensuring
+//! ZK-circuit compatibility and optimal proof generation. `HashScheme` names the
+//! scheme; the `rescue-prime` feature adds an alternative backend for research.
 //!
 //! ## No-std Support
 //!
@@ -39,26 +46,84 @@ pub mod infrastructure;
 
 // Re-export commonly used types
 pub use domain::{
-	entities::note::Note,
+	entities::{
+		note::Note,
+		note_encoding::{NoteEncodingError, NOTE_ENCODED_LENGTH},
+	},
+	ports::{
+		BlindingSource, BlindingSourceError, DisclosureSigner, RandomnessProvenance,
+		SignatureVerifier, SignerError,
+	},
+	services::hd_key_service::HdKeyService,
 	value_objects::{
-		blinding::Blinding, commitment::Commitment, field_element::FieldElement,
-		nullifier::Nullifier, owner_pubkey::OwnerPubkey, spending_key::SpendingKey,
+		blinding::Blinding,
+		commitment::Commitment,
+		diversified_address::DiversifiedAddress,
+		extended_spending_key::{hardened, ExtendedSpendingKey, KeyDerivationError},
+		field_element::{FieldElement, FieldElementError},
+		hash_scheme::HashScheme,
+		nullifier::Nullifier,
+		nullifier_version::{NullifierVersion, NULLIFIER_DOMAIN_V2},
+		owner_pubkey::OwnerPubkey,
+		payment_request::{
+			PaymentRequest, PaymentRequestError, MAX_PAYMENT_REQUEST_MEMO_LENGTH,
+			PAYMENT_REQUEST_SCHEME,
+		},
+		shielded_address::{AddressError, ShieldedAddress},
+		spending_key::SpendingKey,
 	},
 };
 
 pub use application::{
+	disclosure::{
+		verify_payment_disclosure, PaymentDisclosure, PaymentDisclosureError,
+		PAYMENT_DISCLOSURE_LENGTH,
+	},
 	dto::{merkle_proof_dto::MerkleProofDto, note_dto::NoteDto},
 	use_cases::{
-		compute_commitment::ComputeCommitmentUseCase, compute_nullifier::ComputeNullifierUseCase,
-		create_note::CreateNoteUseCase, verify_merkle_proof::VerifyMerkleProofUseCase,
+		compute_commitment::ComputeCommitmentUseCase,
+		compute_nullifier::ComputeNullifierUseCase,
+		create_note::CreateNoteUseCase,
+		create_notes_batch::{
+			BatchNote, BatchNoteInput, CreateNotesBatchError, CreateNotesBatchOutput,
+			CreateNotesBatchUseCase,
+		},
+		verify_merkle_proof::VerifyMerkleProofUseCase,
 	},
 };
 
 // Re-export infrastructure implementations
+pub use infrastructure::crypto::batch::{compute_commitments, compute_nullifiers};
+pub use infrastructure::crypto::merkle::{
+	IncrementalMerkleTree, MerkleCheckpoint, MerkleTreeError,
+};
 pub use infrastructure::crypto::poseidon_hash_1::poseidon_hash_1;
+pub use infrastructure::crypto::poseidon_hash_n::poseidon_hash_n;
 pub use infrastructure::crypto::poseidon_hasher::LightPoseidonHasher;
+pub use infrastructure::crypto::poseidon_sponge::poseidon_sponge;
+pub use infrastructure::random::{EntropyDevice, HardwareBlindingSource, SeededBlindingSource};
+
+#[cfg(feature = "std")]
+pub use infrastructure::crypto::poseidon_params::{PoseidonParameters, PoseidonParamsError};
+#[cfg(feature = "std")]
+pub use infrastructure::random::OsBlindingSource;
 
 #[cfg(feature = "poseidon-native")]
 pub use infrastructure::crypto::native_poseidon_hasher::NativePoseidonHasher;
 
-pub use infrastructure::repositories::in_memory_merkle_repository::InMemoryMerkleRepository;
+#[cfg(feature = "rescue-prime")]
+pub use infrastructure::crypto::{
+	rescue_prime::{
+		RescuePrimeError, RescuePrimeHasher, RescuePrimeParameters, RESCUE_ALPHA, RESCUE_ALPHA_INV,
+		RESCUE_MAX_WIDTH, RESCUE_MIN_WIDTH,
+	},
+	scheme_hasher::SchemeHasher,
+};
+
+pub use infrastructure::repositories::in_memory_merkle_repository::{
+	InMemoryMerkleRepository, RepositoryCheckpoint,
+};
+#[cfg(feature = "substrate")]
+pub use infrastructure::repositories::storage_merkle_repository::{
+	MerkleStorage, StorageMerkleRepository,
+};
@@ -0,0 +1,211 @@
+//! Property tests for commitment and nullifier invariants
+//!
+//! Every hasher backend must agree with an independently generated Poseidon
+//! instance (`PoseidonParameters`), so the duplicated primitive stacks cannot
+//! silently diverge.
+
+#![cfg(feature = "std")]
+
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use orbinum_zk_core::{
+	domain::{
+		ports::PoseidonHasher,
+		services::{CommitmentService, NullifierService},
+	},
+	Blinding, Commitment, FieldElement, LightPoseidonHasher, Note, NullifierVersion, OwnerPubkey,
+	PoseidonParameters, SpendingKey,
+};
+use proptest::prelude::*;
+use std::sync::OnceLock;
+
+fn field_element() -> impl Strategy<Value = FieldElement> {
+	any::<[u8; 32]>().prop_map(|bytes| FieldElement::new(Fr::from_le_bytes_mod_order(&bytes)))
+}
+
+fn note() -> impl Strategy<Value = Note> {
+	(any::<u64>(), any::<u64>(), field_element(), field_element()).prop_map(
+		|(value, asset_id, owner, blinding)| {
+			Note::new(
+				value,
+				asset_id,
+				OwnerPubkey::new(owner),
+				Blinding::new(blinding),
+			)
+		},
+	)
+}
+
+/// Independently generated parameters for arities 2 and 4
+fn reference_params(arity: usize) -> &'static PoseidonParameters {
+	static WIDTH_3: OnceLock<PoseidonParameters> = OnceLock::new();
+	static WIDTH_5: OnceLock<PoseidonParameters> = OnceLock::new();
+	let cell = match arity {
+		2 => &WIDTH_3,
+		4 => &WIDTH_5,
+		_ => unreachable!("only arities 2 and 4 are compared"),
+	};
+	cell.get_or_init(|| PoseidonParameters::generate(arity + 1).unwrap())
+}
+
+fn reference_hash(inputs: &[FieldElement]) -> FieldElement {
+	let frs: Vec<Fr> = inputs.iter().map(FieldElement::inner).collect();
+	FieldElement::new(reference_params(inputs.len()).hash(&frs))
+}
+
+fn commitment_inputs(note: &Note) -> [FieldElement; 4] {
+	[
+		FieldElement::from_u64(note.value()),
+		FieldElement::from_u64(note.asset_id()),
+		note.owner_pubkey().inner(),
+		note.blinding().inner(),
+	]
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(64))]
+
+	// ===== Determinism =====
+
+	#[test]
+	fn commitment_is_deterministic(note in note()) {
+		let service = CommitmentService::new(LightPoseidonHasher);
+		let expected = service.create_commitment(
+			note.value(),
+			note.asset_id(),
+			note.owner_pubkey(),
+			note.blinding(),
+		);
+
+		prop_assert_eq!(note.commitment(LightPoseidonHasher), expected);
+		prop_assert_eq!(note.clone().commitment(LightPoseidonHasher), expected);
+	}
+
+	#[test]
+	fn nullifier_is_deterministic(note in note(), sk in field_element()) {
+		let key = SpendingKey::new(sk);
+		for version in NullifierVersion::ALL {
+			prop_assert_eq!(
+				note.nullifier_versioned(LightPoseidonHasher, &key, version),
+				note.nullifier_versioned(LightPoseidonHasher, &key, version)
+			);
+		}
+	}
+
+	// ===== Injectivity =====
+
+	#[test]
+	fn distinct_notes_have_distinct_commitments(a in note(), b in note()) {
+		prop_assume!(commitment_inputs(&a) != commitment_inputs(&b));
+		prop_assert_ne!(a.commitment(LightPoseidonHasher), b.commitment(LightPoseidonHasher));
+	}
+
+	#[test]
+	fn single_field_changes_change_commitment(note in note(), delta in 1u64..) {
+		let changed = Note::new(
+			note.value().wrapping_add(delta),
+			note.asset_id(),
+			note.owner_pubkey(),
+			note.blinding(),
+		);
+		prop_assert_ne!(
+			note.commitment(LightPoseidonHasher),
+			changed.commitment(LightPoseidonHasher)
+		);
+	}
+
+	#[test]
+	fn distinct_inputs_have_distinct_nullifiers(
+		a in field_element(),
+		b in field_element(),
+		sk_a in field_element(),
+		sk_b in field_element(),
+	) {
+		prop_assume!((a, sk_a) != (b, sk_b));
+		let service = NullifierService::new(LightPoseidonHasher);
+		for version in NullifierVersion::ALL {
+			let nullifier_a =
+				service.compute_nullifier_versioned(&Commitment::new(a), &SpendingKey::new(sk_a), version);
+			let nullifier_b =
+				service.compute_nullifier_versioned(&Commitment::new(b), &SpendingKey::new(sk_b), version);
+			prop_assert_ne!(nullifier_a, nullifier_b);
+		}
+	}
+
+	#[test]
+	fn nullifier_versions_never_collide(commitment in field_element(), sk in field_element()) {
+		let service = NullifierService::new(LightPoseidonHasher);
+		let commitment = Commitment::new(commitment);
+		let key = SpendingKey::new(sk);
+		prop_assert_ne!(
+			service.compute_nullifier_versioned(&commitment, &key, NullifierVersion::V1),
+			service.compute_nullifier_versioned(&commitment, &key, NullifierVersion::V2)
+		);
+	}
+
+	// ===== Cross-Implementation Equality =====
+
+	#[test]
+	fn commitment_matches_reference_poseidon(note in note()) {
+		prop_assert_eq!(
+			note.commitment(LightPoseidonHasher).inner(),
+			reference_hash(&commitment_inputs(&note))
+		);
+	}
+
+	#[test]
+	fn nullifier_matches_reference_poseidon(commitment in field_element(), sk in field_element()) {
+		let service = NullifierService::new(LightPoseidonHasher);
+		let commitment = Commitment::new(commitment);
+		let key = SpendingKey::new(sk);
+
+		prop_assert_eq!(
+			service.compute_nullifier(&commitment, &key).inner(),
+			reference_hash(&[commitment.inner(), sk])
+		);
+		prop_assert_eq!(
+			service
+				.compute_nullifier_versioned(&commitment, &key, NullifierVersion::V2)
+				.inner(),
+			reference_hash(&[
+				NullifierVersion::V2.domain_tag().unwrap(),
+				commitment.inner(),
+				sk,
+				FieldElement::zero(),
+			])
+		);
+	}
+
+	#[test]
+	fn hasher_matches_reference_poseidon(
+		a in field_element(),
+		b in field_element(),
+		c in field_element(),
+		d in field_element(),
+	) {
+		prop_assert_eq!(LightPoseidonHasher.hash_2([a, b]), reference_hash(&[a, b]));
+		prop_assert_eq!(LightPoseidonHasher.hash_4([a, b, c, d]), reference_hash(&[a, b, c, d]));
+	}
+}
+
+#[cfg(feature = "poseidon-native")]
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(64))]
+
+	#[test]
+	fn native_hasher_matches_light_hasher(note in note(), sk in field_element()) {
+		use orbinum_zk_core::NativePoseidonHasher;
+
+		let key = SpendingKey::new(sk);
+		prop_assert_eq!(
+			note.commitment(NativePoseidonHasher),
+			note.commitment(LightPoseidonHasher)
+		);
+		for version in NullifierVersion::ALL {
+			prop_assert_eq!(
+				note.nullifier_versioned(NativePoseidonHasher, &key, version),
+				note.nullifier_versioned(LightPoseidonHasher, &key, version)
+			);
+		}
+	}
+}
@@ -0,0 +1,177 @@
+//! Cross-toolchain test vectors
+//!
+//! Regenerates `test-vectors/vectors.json` from fixed seeds and requires it to
+//! match the committed file bit for bit. The same file is checked against
+//! circomlibjs by `test-vectors/verify-circomlib.mjs`, so together they lock in
+//! circomlib compatibility of hashes, commitments, nullifiers and Merkle roots.
+//!
+//! After an intentional change, rewrite the fixtures with
+//! `UPDATE_TEST_VECTORS=1 cargo test -p orbinum-zk-core --test test_vectors`
+//! and re-run the circomlib script.
+
+#![cfg(feature = "std")]
+
+use orbinum_zk_core::{
+	domain::ports::{BlindingSource, PoseidonHasher},
+	Blinding, FieldElement, IncrementalMerkleTree, LightPoseidonHasher, Note, OwnerPubkey,
+	PoseidonParameters, SeededBlindingSource, SpendingKey,
+};
+use serde_json::{json, Value};
+
+const VECTORS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test-vectors/vectors.json");
+
+/// Fixture format version; bump on layout changes
+const FORMAT_VERSION: u64 = 1;
+
+/// Seeds of the note vectors
+const NOTE_SEEDS: [u64; 4] = [1, 2, 3, 4];
+
+/// `(depth, number of leaves)` of the Merkle vectors
+const MERKLE_CASES: [(usize, usize); 5] = [(4, 0), (4, 1), (4, 3), (4, 4), (20, 4)];
+
+fn decimal(value: FieldElement) -> String {
+	value.inner().to_string()
+}
+
+fn field(value: &Value) -> FieldElement {
+	FieldElement::from_decimal_str(value.as_str().expect("decimal string"))
+		.expect("canonical field element")
+}
+
+fn poseidon_vectors() -> Vec<Value> {
+	let hasher = LightPoseidonHasher;
+	let max = FieldElement::from_decimal_str(
+		"21888242871839275222246405745257275088548364400416034343698204186575808495616",
+	)
+	.unwrap();
+	let pairs = [
+		[FieldElement::zero(), FieldElement::zero()],
+		[FieldElement::from_u64(1), FieldElement::from_u64(2)],
+		[max, max],
+	];
+	let quads = [
+		[FieldElement::zero(); 4],
+		[1, 2, 3, 4].map(FieldElement::from_u64),
+		[max, FieldElement::from_u64(7), max, FieldElement::zero()],
+	];
+
+	let hash_2 = pairs.iter().map(|inputs| {
+		json!({
+			"inputs": inputs.map(decimal),
+			"output": decimal(hasher.hash_2(*inputs)),
+		})
+	});
+	let hash_4 = quads.iter().map(|inputs| {
+		json!({
+			"inputs": inputs.map(decimal),
+			"output": decimal(hasher.hash_4(*inputs)),
+		})
+	});
+	hash_2.chain(hash_4).collect()
+}
+
+/// Note for `seed`: `blinding`, `owner_pubkey` and `spending_key` are the first
+/// three values of `SeededBlindingSource::new(seed)`
+fn seeded_note(seed: u64) -> (Note, SpendingKey) {
+	let mut source = SeededBlindingSource::new(seed);
+	let mut next = || source.next_blinding().unwrap().inner();
+	let blinding = Blinding::new(next());
+	let owner_pubkey = OwnerPubkey::new(next());
+	let spending_key = SpendingKey::new(next());
+
+	let note = Note::new(seed * 1_000, seed % 2, owner_pubkey, blinding);
+	(note, spending_key)
+}
+
+fn note_vectors() -> Vec<Value> {
+	NOTE_SEEDS
+		.iter()
+		.map(|&seed| {
+			let (note, spending_key) = seeded_note(seed);
+			json!({
+				"seed": seed,
+				"value": note.value().to_string(),
+				"asset_id": note.asset_id().to_string(),
+				"owner_pubkey": decimal(note.owner_pubkey().inner()),
+				"blinding": decimal(note.blinding().inner()),
+				"spending_key": decimal(spending_key.inner()),
+				"commitment": decimal(note.commitment(LightPoseidonHasher).inner()),
+				"nullifier": decimal(note.nullifier(LightPoseidonHasher, &spending_key).inner()),
+			})
+		})
+		.collect()
+}
+
+fn merkle_vectors() -> Vec<Value> {
+	let commitments: Vec<FieldElement> = NOTE_SEEDS
+		.iter()
+		.map(|&seed| seeded_note(seed).0.commitment(LightPoseidonHasher).inner())
+		.collect();
+
+	MERKLE_CASES
+		.iter()
+		.map(|&(depth, count)| {
+			let mut tree = IncrementalMerkleTree::new(LightPoseidonHasher, depth);
+			for leaf in &commitments[..count] {
+				tree.append(*leaf).unwrap();
+			}
+			json!({
+				"depth": depth,
+				"leaves": commitments[..count].iter().copied().map(decimal).collect::<Vec<_>>(),
+				"root": decimal(tree.root()),
+			})
+		})
+		.collect()
+}
+
+fn generate() -> Value {
+	json!({
+		"version": FORMAT_VERSION,
+		"field": "bn254-scalar",
+		"encoding": "decimal",
+		"poseidon": poseidon_vectors(),
+		"notes": note_vectors(),
+		"merkle": merkle_vectors(),
+	})
+}
+
+fn committed() -> Value {
+	let contents = std::fs::read_to_string(VECTORS_PATH).expect("test-vectors/vectors.json");
+	serde_json::from_str(&contents).expect("valid JSON")
+}
+
+#[test]
+fn vectors_match_fixtures() {
+	let generated = generate();
+
+	if std::env::var_os("UPDATE_TEST_VECTORS").is_some() {
+		let mut contents = serde_json::to_string_pretty(&generated).unwrap();
+		contents.push('\n');
+		std::fs::write(VECTORS_PATH, contents).unwrap();
+	}
+
+	assert_eq!(
+		generated,
+		committed(),
+		"test vectors changed; see the module docs to regenerate"
+	);
+}
+
+#[test]
+fn poseidon_vectors_match_reference_parameters() {
+	let fixtures = committed();
+
+	for vector in fixtures["poseidon"].as_array().unwrap() {
+		let inputs: Vec<_> = vector["inputs"]
+			.as_array()
+			.unwrap()
+			.iter()
+			.map(|input| field(input).inner())
+			.collect();
+		let output = PoseidonParameters::generate(inputs.len() + 1)
+			.unwrap()
+			.hash(&inputs);
+
+		assert_eq!(FieldElement::new(output), field(&vector["output"]));
+	}
+}
@@ -0,0 +1,107 @@
+//! Verification cache (native side)
+//!
+//! Results of proofs verified ahead of block execution, e.g. in parallel
+//! during block import. The host functions return a cached result instead of
+//! verifying again. Entries are keyed by a SHA-256 digest of the exact bytes
+//! passed to the verifier, so a hit is the result the verifier would return
+//! and block execution stays deterministic whether or not the cache is warm.
+
+use alloc::{
+	collections::{BTreeMap, VecDeque},
+	vec::Vec,
+};
+use sha2::{Digest, Sha256};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::ProofJob;
+
+/// Results kept before the oldest are evicted
+pub const CACHE_CAPACITY: usize = 8192;
+
+struct Cache {
+	results: BTreeMap<[u8; 32], bool>,
+	/// Keys in insertion order, oldest first
+	order: VecDeque<[u8; 32]>,
+}
+
+static CACHE: Mutex<Cache> = Mutex::new(Cache {
+	results: BTreeMap::new(),
+	order: VecDeque::new(),
+});
+
+fn lock() -> MutexGuard<'static, Cache> {
+	CACHE.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Cache key of a verification
+fn key(vk: &[u8], proof: &[u8], public_inputs: &[u8]) -> [u8; 32] {
+	let mut hasher = Sha256::new();
+	hasher.update(b"orbinum-zk-host-cache-v1");
+	for part in [vk, proof, public_inputs] {
+		hasher.update((part.len() as u64).to_le_bytes());
+		hasher.update(part);
+	}
+	hasher.finalize().into()
+}
+
+/// Cached result of verifying `proof` against `vk` and `public_inputs`
+pub fn get(vk: &[u8], proof: &[u8], public_inputs: &[u8]) -> Option<bool> {
+	lock().results.get(&key(vk, proof, public_inputs)).copied()
+}
+
+/// Verifies `job` natively and caches the result
+pub fn verify_and_insert(job: &ProofJob) -> bool {
+	let valid = crate::verifier::verify(&job.vk, &job.proof, &job.public_inputs);
+	insert(job, valid);
+	valid
+}
+
+/// Verifies `jobs` across threads and caches the results
+///
+/// Returns the number of valid proofs.
+pub fn verify_all(jobs: &[ProofJob]) -> usize {
+	let threads = std::thread::available_parallelism()
+		.map_or(1, |n| n.get())
+		.min(jobs.len().max(1));
+	let chunk_size = jobs.len().div_ceil(threads).max(1);
+
+	std::thread::scope(|scope| {
+		let handles: Vec<_> = jobs
+			.chunks(chunk_size)
+			.map(|chunk| {
+				scope.spawn(move || chunk.iter().filter(|job| verify_and_insert(job)).count())
+			})
+			.collect();
+
+		handles
+			.into_iter()
+			.map(|handle| {
+				handle
+					.join()
+					.unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+			})
+			.sum()
+	})
+}
+
+/// Caches `valid` as the result of `job`
+///
+/// Only results produced by [`crate::verifier::verify`] may be inserted.
+pub(crate) fn insert(job: &ProofJob, valid: bool) {
+	let key = key(&job.vk, &job.proof, &job.public_inputs);
+	let mut cache = lock();
+	if cache.results.insert(key, valid).is_some() {
+		return;
+	}
+	cache.order.push_back(key);
+	while cache.order.len() > CACHE_CAPACITY {
+		if let Some(oldest) = cache.order.pop_front() {
+			cache.results.remove(&oldest);
+		}
+	}
+}
+
+/// Number of cached results
+pub fn len() -> usize {
+	lock().results.len()
+}
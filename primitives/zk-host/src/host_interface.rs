@@ -0,0 +1,50 @@
+#![allow(unexpected_cfgs)]
+//! Host interface for Groth16 verification
+//!
+//! Native runtime interface running the BN254 pairings outside the WASM
+//! interpreter, which dominate the cost of every shielded extrinsic.
+
+use alloc::vec::Vec;
+use sp_runtime_interface::{
+	pass_by::{AllocateAndReturnByCodec, PassFatPointerAndDecode, PassFatPointerAndRead},
+	runtime_interface,
+};
+
+/// Native runtime interface for Groth16 verification
+///
+/// Keys and proofs are arkworks compressed encodings; public inputs are
+/// packed 32-byte little-endian field elements.
+#[runtime_interface]
+pub trait ZkHostInterface {
+	/// Verify a single proof
+	fn groth16_verify(
+		vk: PassFatPointerAndRead<&[u8]>,
+		proof: PassFatPointerAndRead<&[u8]>,
+		public_inputs: PassFatPointerAndRead<&[u8]>,
+	) -> bool {
+		#[cfg(feature = "std")]
+		if let Some(valid) = crate::cache::get(vk, proof, public_inputs) {
+			return valid;
+		}
+		crate::verifier::verify(vk, proof, public_inputs)
+	}
+
+	/// Verify proofs of one circuit together (`None` if the batch is malformed)
+	fn groth16_batch_verify(
+		vk: PassFatPointerAndRead<&[u8]>,
+		proofs: PassFatPointerAndDecode<Vec<Vec<u8>>>,
+		public_inputs: PassFatPointerAndDecode<Vec<Vec<u8>>>,
+	) -> AllocateAndReturnByCodec<Option<bool>> {
+		// Proofs all known to be valid need no pairing check
+		#[cfg(feature = "std")]
+		if proofs.len() == public_inputs.len()
+			&& proofs
+				.iter()
+				.zip(&public_inputs)
+				.all(|(proof, inputs)| crate::cache::get(vk, proof, inputs) == Some(true))
+		{
+			return Some(true);
+		}
+		crate::verifier::batch_verify(vk, &proofs, &public_inputs)
+	}
+}
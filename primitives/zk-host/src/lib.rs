@@ -0,0 +1,268 @@
+//! # orbinum-zk-host
+//!
+//! Groth16 verification for the Orbinum runtime, executed natively through
+//! host functions.
+//!
+//! ## Features
+//!
+//! - `groth16-native`: verify through the [`host_interface`] host functions
+//!   (default). The node must register
+//!   `host_interface::zk_host_interface::HostFunctions`.
+//! - Without it, [`groth16_verify`] and [`groth16_batch_verify`] fall back to
+//!   verification inside the runtime, with identical results.
+//!
+//! With `std`, the host functions first consult the [`cache`], which the
+//! node fills by verifying the [`ProofJob`]s of a block ahead of execution.
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use orbinum_zk_host::{groth16_verify, pack_public_inputs};
+//!
+//! let valid = groth16_verify(&vk_bytes, &proof_bytes, &pack_public_inputs(&inputs));
+//! ```
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+#[cfg(feature = "std")]
+pub mod cache;
+#[cfg(feature = "groth16-native")]
+pub mod host_interface;
+pub mod verifier;
+
+pub use verifier::{pack_public_inputs, PUBLIC_INPUT_SIZE};
+
+/// A Groth16 verification the runtime will perform, in host function layout
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct ProofJob {
+	/// Compressed verifying key
+	pub vk: Vec<u8>,
+	/// Compressed proof
+	pub proof: Vec<u8>,
+	/// Packed 32-byte little-endian public inputs
+	pub public_inputs: Vec<u8>,
+}
+
+impl ProofJob {
+	/// Job verifying `proof` against `vk` with `public_inputs` in schema order
+	pub fn new(vk: Vec<u8>, proof: Vec<u8>, public_inputs: &[[u8; PUBLIC_INPUT_SIZE]]) -> Self {
+		Self {
+			vk,
+			proof,
+			public_inputs: pack_public_inputs(public_inputs),
+		}
+	}
+}
+
+/// Verifies a compressed Groth16 proof against a compressed verifying key
+///
+/// `public_inputs` are packed 32-byte little-endian field elements. Invalid
+/// or malformed proofs return `false`.
+pub fn groth16_verify(vk: &[u8], proof: &[u8], public_inputs: &[u8]) -> bool {
+	#[cfg(feature = "groth16-native")]
+	{
+		host_interface::zk_host_interface::groth16_verify(vk, proof, public_inputs)
+	}
+	#[cfg(not(feature = "groth16-native"))]
+	{
+		verifier::verify(vk, proof, public_inputs)
+	}
+}
+
+/// Verifies proofs of one circuit with a single pairing check
+///
+/// See [`verifier::batch_verify`].
+pub fn groth16_batch_verify(
+	vk: &[u8],
+	proofs: Vec<Vec<u8>>,
+	public_inputs: Vec<Vec<u8>>,
+) -> Option<bool> {
+	#[cfg(feature = "groth16-native")]
+	{
+		host_interface::zk_host_interface::groth16_batch_verify(vk, proofs, public_inputs)
+	}
+	#[cfg(not(feature = "groth16-native"))]
+	{
+		verifier::batch_verify(vk, &proofs, &public_inputs)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orbinum_zk_circuits::{
+		application::{
+			circuits::viewing_key::ViewingKeyOwnershipCircuit,
+			prover::{self, ProverRng},
+		},
+		Bn254Fr,
+	};
+	use std::sync::OnceLock;
+
+	struct Fixture {
+		vk: Vec<u8>,
+		/// (proof, packed public inputs)
+		proofs: Vec<(Vec<u8>, Vec<u8>)>,
+	}
+
+	fn fixture() -> &'static Fixture {
+		static FIXTURE: OnceLock<Fixture> = OnceLock::new();
+		FIXTURE.get_or_init(|| {
+			let mut rng = ProverRng::deterministic(7);
+			let (pk, vk) =
+				prover::setup(ViewingKeyOwnershipCircuit::new_for_setup(), &mut rng).unwrap();
+			let proofs = (1..=3u64)
+				.map(|viewing_key| {
+					let circuit = ViewingKeyOwnershipCircuit::new(
+						Bn254Fr::from(viewing_key),
+						Bn254Fr::from(42u64),
+					);
+					let proof =
+						prover::prove_viewing_key_ownership(&pk, circuit, &mut rng).unwrap();
+					(proof.proof, pack_public_inputs(&proof.public_inputs))
+				})
+				.collect();
+			Fixture {
+				vk: prover::serialize_vk(&vk).unwrap(),
+				proofs,
+			}
+		})
+	}
+
+	fn split(proofs: &[(Vec<u8>, Vec<u8>)]) -> (Vec<Vec<u8>>, Vec<Vec<u8>>) {
+		proofs.iter().cloned().unzip()
+	}
+
+	#[test]
+	fn verifies_valid_proof() {
+		let fixture = fixture();
+		let (proof, inputs) = &fixture.proofs[0];
+
+		assert!(groth16_verify(&fixture.vk, proof, inputs));
+		assert!(verifier::verify(&fixture.vk, proof, inputs));
+	}
+
+	#[test]
+	fn rejects_proof_for_other_inputs() {
+		let fixture = fixture();
+		let (proof, _) = &fixture.proofs[0];
+		let (_, other_inputs) = &fixture.proofs[1];
+
+		assert!(!groth16_verify(&fixture.vk, proof, other_inputs));
+		assert!(!verifier::verify(&fixture.vk, proof, other_inputs));
+	}
+
+	#[test]
+	fn rejects_malformed_input_without_panicking() {
+		let fixture = fixture();
+		let (proof, inputs) = &fixture.proofs[0];
+
+		assert!(!groth16_verify(&fixture.vk, proof, &inputs[1..]));
+		assert!(!groth16_verify(&fixture.vk, &proof[1..], inputs));
+		assert!(!groth16_verify(&[0u8; 8], proof, inputs));
+	}
+
+	#[test]
+	fn cached_result_matches_verification() {
+		let fixture = fixture();
+		let (proof, inputs) = &fixture.proofs[2];
+		let job = ProofJob {
+			vk: fixture.vk.clone(),
+			proof: proof.clone(),
+			public_inputs: inputs.clone(),
+		};
+		let invalid = ProofJob {
+			public_inputs: fixture.proofs[0].1.clone(),
+			..job.clone()
+		};
+
+		assert!(cache::verify_and_insert(&job));
+		assert!(!cache::verify_and_insert(&invalid));
+		assert_eq!(
+			cache::get(&job.vk, &job.proof, &job.public_inputs),
+			Some(true)
+		);
+		assert_eq!(
+			cache::get(&invalid.vk, &invalid.proof, &invalid.public_inputs),
+			Some(false)
+		);
+		assert!(groth16_verify(&job.vk, &job.proof, &job.public_inputs));
+		assert!(!groth16_verify(
+			&invalid.vk,
+			&invalid.proof,
+			&invalid.public_inputs
+		));
+	}
+
+	#[test]
+	fn verify_all_caches_every_job() {
+		let fixture = fixture();
+		let mut jobs: Vec<ProofJob> = fixture
+			.proofs
+			.iter()
+			.map(|(proof, inputs)| ProofJob {
+				vk: fixture.vk.clone(),
+				proof: proof.clone(),
+				public_inputs: inputs.clone(),
+			})
+			.collect();
+		jobs[2].public_inputs = fixture.proofs[1].1.clone();
+
+		assert_eq!(cache::verify_all(&jobs), 2);
+		assert_eq!(cache::verify_all(&[]), 0);
+		for (job, valid) in jobs.iter().zip([true, true, false]) {
+			assert_eq!(
+				cache::get(&job.vk, &job.proof, &job.public_inputs),
+				Some(valid)
+			);
+		}
+	}
+
+	#[test]
+	fn batch_verifies_valid_proofs() {
+		let fixture = fixture();
+		let (proofs, inputs) = split(&fixture.proofs);
+
+		assert_eq!(
+			groth16_batch_verify(&fixture.vk, proofs.clone(), inputs.clone()),
+			Some(true)
+		);
+		assert_eq!(
+			verifier::batch_verify(&fixture.vk, &proofs, &inputs),
+			Some(true)
+		);
+	}
+
+	#[test]
+	fn batch_rejects_one_invalid_proof() {
+		let fixture = fixture();
+		let (proofs, mut inputs) = split(&fixture.proofs);
+		inputs.swap(0, 1);
+
+		assert_eq!(
+			groth16_batch_verify(&fixture.vk, proofs, inputs),
+			Some(false)
+		);
+	}
+
+	#[test]
+	fn batch_reports_malformed_batch() {
+		let fixture = fixture();
+		let (proofs, inputs) = split(&fixture.proofs);
+
+		assert_eq!(
+			groth16_batch_verify(&fixture.vk, proofs, inputs[1..].to_vec()),
+			None
+		);
+		assert_eq!(
+			groth16_batch_verify(&fixture.vk, vec![vec![0u8; 4]], inputs[..1].to_vec()),
+			None
+		);
+	}
+}
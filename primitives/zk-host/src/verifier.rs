@@ -0,0 +1,73 @@
+//! Groth16 verification over the byte layout shared with the host
+//!
+//! Runs natively behind the host functions, and inside the runtime when
+//! `groth16-native` is disabled. Malformed keys, proofs or inputs never
+//! panic, so a bad extrinsic cannot abort block execution.
+
+use alloc::vec::Vec;
+use orbinum_zk_verifier::{
+	domain::value_objects::{Proof, PublicInputs, VerifyingKey},
+	infrastructure::Groth16Verifier,
+};
+
+/// Size of an encoded public input
+pub const PUBLIC_INPUT_SIZE: usize = 32;
+
+/// Splits `inputs_0 || inputs_1 || ...` into 32-byte little-endian inputs
+///
+/// Returns `None` if the length is not a multiple of [`PUBLIC_INPUT_SIZE`].
+pub fn unpack_public_inputs(packed: &[u8]) -> Option<PublicInputs> {
+	if packed.len() % PUBLIC_INPUT_SIZE != 0 {
+		return None;
+	}
+	let inputs = packed
+		.chunks_exact(PUBLIC_INPUT_SIZE)
+		.map(|chunk| {
+			let mut input = [0u8; PUBLIC_INPUT_SIZE];
+			input.copy_from_slice(chunk);
+			input
+		})
+		.collect();
+	Some(PublicInputs::new(inputs))
+}
+
+/// Packs public inputs in the layout read by [`unpack_public_inputs`]
+pub fn pack_public_inputs(inputs: &[[u8; PUBLIC_INPUT_SIZE]]) -> Vec<u8> {
+	inputs.concat()
+}
+
+/// Verifies a compressed Groth16 proof against a compressed verifying key
+///
+/// `public_inputs` are packed 32-byte little-endian field elements.
+pub fn verify(vk: &[u8], proof: &[u8], public_inputs: &[u8]) -> bool {
+	let Some(public_inputs) = unpack_public_inputs(public_inputs) else {
+		return false;
+	};
+	Groth16Verifier::verify(
+		&VerifyingKey::new(vk.to_vec()),
+		&public_inputs,
+		&Proof::new(proof.to_vec()),
+	)
+	.is_ok()
+}
+
+/// Verifies proofs of the same circuit with a single pairing check
+///
+/// `proofs[i]` is checked against the packed `public_inputs[i]`. Returns
+/// `None` if the batch is malformed (length mismatch, undecodable key,
+/// proof or inputs), otherwise whether every proof is valid.
+pub fn batch_verify(vk: &[u8], proofs: &[Vec<u8>], public_inputs: &[Vec<u8>]) -> Option<bool> {
+	if proofs.len() != public_inputs.len() {
+		return None;
+	}
+	let public_inputs = public_inputs
+		.iter()
+		.map(|packed| unpack_public_inputs(packed))
+		.collect::<Option<Vec<_>>>()?;
+	let proofs: Vec<_> = proofs
+		.iter()
+		.map(|proof| Proof::new(proof.clone()))
+		.collect();
+
+	Groth16Verifier::batch_verify(&VerifyingKey::new(vk.to_vec()), &public_inputs, &proofs).ok()
+}
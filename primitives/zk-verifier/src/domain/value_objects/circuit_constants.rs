@@ -1,37 +1,57 @@
 //! Circuit IDs, public input counts, and verification cost constants.
+//!
+//! Circuit IDs and public-input layouts are defined once in
+//! `orbinum_zk_circuits::domain::circuit_ids` and re-exported here.
+
+pub use orbinum_zk_circuits::domain::circuit_ids::{self, CircuitSchema};
 
 /// Circuit identifier for transfer operations
 /// Used to lookup the correct verification key at runtime
-pub const CIRCUIT_ID_TRANSFER: u8 = 1;
+pub const CIRCUIT_ID_TRANSFER: u8 = circuit_ids::CIRCUIT_ID_TRANSFER as u8;
 
 /// Circuit identifier for unshield (withdraw) operations
 /// Used to lookup the correct verification key at runtime
-pub const CIRCUIT_ID_UNSHIELD: u8 = 2;
+pub const CIRCUIT_ID_UNSHIELD: u8 = circuit_ids::CIRCUIT_ID_UNSHIELD as u8;
 
 /// Number of public inputs for the transfer circuit
 /// Public inputs: [merkle_root, nullifier1, nullifier2, commitment1, commitment2]
-pub const TRANSFER_PUBLIC_INPUTS: usize = 5;
+pub const TRANSFER_PUBLIC_INPUTS: usize = circuit_ids::TRANSFER.public_input_count();
 
 /// Number of public inputs for the unshield circuit
-/// Public inputs: [merkle_root, nullifier, recipient, amount, asset_id]
-pub const UNSHIELD_PUBLIC_INPUTS: usize = 5;
+/// Public inputs: [merkle_root, nullifier, amount, recipient_binding, asset_id]
+pub const UNSHIELD_PUBLIC_INPUTS: usize = circuit_ids::UNSHIELD.public_input_count();
 
 /// Circuit identifier for disclosure (selective disclosure) operations
 /// Used to lookup the correct verification key at runtime
-pub const CIRCUIT_ID_DISCLOSURE: u8 = 4;
+pub const CIRCUIT_ID_DISCLOSURE: u8 = circuit_ids::CIRCUIT_ID_DISCLOSURE as u8;
 
 /// Number of public inputs for the disclosure circuit
 /// Public inputs: [commitment, revealed_value, revealed_asset_id, revealed_owner_hash]
-pub const DISCLOSURE_PUBLIC_INPUTS: usize = 4;
+pub const DISCLOSURE_PUBLIC_INPUTS: usize = circuit_ids::DISCLOSURE.public_input_count();
 
 /// Circuit identifier for private link dispatch operations
 /// Used to lookup the correct verification key at runtime
 /// Public inputs: [commitment, call_hash_fe]
-pub const CIRCUIT_ID_PRIVATE_LINK: u8 = 5;
+pub const CIRCUIT_ID_PRIVATE_LINK: u8 = circuit_ids::CIRCUIT_ID_PRIVATE_LINK as u8;
 
 /// Number of public inputs for the private link circuit
 /// Public inputs: [commitment(32B LE field element), call_hash_fe(32B LE field element)]
-pub const PRIVATE_LINK_PUBLIC_INPUTS: usize = 2;
+pub const PRIVATE_LINK_PUBLIC_INPUTS: usize = circuit_ids::PRIVATE_LINK.public_input_count();
+
+/// Circuit identifier for viewing key ownership proofs
+/// Used to lookup the correct verification key at runtime
+pub const CIRCUIT_ID_VIEWING_KEY_OWNERSHIP: u8 =
+	circuit_ids::CIRCUIT_ID_VIEWING_KEY_OWNERSHIP as u8;
+
+/// Number of public inputs for the viewing key ownership circuit
+/// Public inputs: [viewing_key_hash, commitment]
+pub const VIEWING_KEY_OWNERSHIP_PUBLIC_INPUTS: usize =
+	circuit_ids::VIEWING_KEY_OWNERSHIP.public_input_count();
+
+/// Expected number of public inputs for a circuit ID, if the circuit is known
+pub fn expected_public_inputs(circuit_id: u32) -> Option<usize> {
+	circuit_ids::schema(circuit_id).map(CircuitSchema::public_input_count)
+}
 
 /// Base cost for Groth16 verification (pairing operations)
 /// This is a reasonable default that can be overridden in runtime configuration
@@ -64,6 +84,19 @@ mod tests {
 		assert_eq!(PRIVATE_LINK_PUBLIC_INPUTS, 2);
 	}
 
+	#[test]
+	fn test_expected_public_inputs_lookup() {
+		assert_eq!(
+			expected_public_inputs(CIRCUIT_ID_TRANSFER as u32),
+			Some(TRANSFER_PUBLIC_INPUTS)
+		);
+		assert_eq!(
+			expected_public_inputs(CIRCUIT_ID_PRIVATE_LINK as u32),
+			Some(PRIVATE_LINK_PUBLIC_INPUTS)
+		);
+		assert_eq!(expected_public_inputs(999), None);
+	}
+
 	#[test]
 	fn test_cost_constants_are_consistent() {
 		let base_verification_cost = black_box(BASE_VERIFICATION_COST);
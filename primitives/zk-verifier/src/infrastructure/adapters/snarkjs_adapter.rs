@@ -1,18 +1,20 @@
 //! Snarkjs format parser
 //!
 //! This module provides utilities to parse proofs and public inputs from
-//! the JSON format generated by snarkjs (the tool used to compile Circom circuits).
+//! the JSON format generated by snarkjs (the tool used to compile Circom circuits),
+//! and to convert verifying keys from and to snarkjs `verification_key.json`.
 
 #[cfg(feature = "std")]
 use crate::domain::value_objects::{
 	errors::VerifierError,
-	proof_types::{Proof, PublicInputs},
+	proof_types::{Proof, PublicInputs, VerifyingKey},
 };
 
 #[cfg(feature = "std")]
 use {
 	ark_bn254::{Fq, Fq2, G1Affine, G2Affine},
-	ark_ff::PrimeField,
+	ark_ec::AffineRepr,
+	ark_ff::{BigInteger, One, PrimeField, Zero},
 	ark_groth16::Proof as ArkProof,
 	num_bigint::BigUint,
 };
@@ -94,6 +96,147 @@ pub fn parse_public_inputs_from_snarkjs(
 	Ok(PublicInputs::new(inputs?))
 }
 
+/// Groth16 verifying key in snarkjs `verification_key.json` layout
+///
+/// Coordinates are decimal strings in projective form: G1 points are
+/// `[x, y, "1"]`, G2 points `[[x.c0, x.c1], [y.c0, y.c1], ["1", "0"]]`.
+/// Points at infinity use the snarkjs encoding (`["0", "1", "0"]` for G1).
+#[cfg(feature = "std")]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SnarkjsVerifyingKey {
+	/// Number of public inputs (`IC` has one more entry)
+	pub n_public: usize,
+	pub vk_alpha_1: [String; 3],
+	pub vk_beta_2: [[String; 2]; 3],
+	pub vk_gamma_2: [[String; 2]; 3],
+	pub vk_delta_2: [[String; 2]; 3],
+	pub ic: Vec<[String; 3]>,
+}
+
+/// Export a verifying key to the snarkjs `verification_key.json` layout
+///
+/// Inverse of the coordinate mapping used by [`parse_proof_from_snarkjs`], so
+/// circom-based provers can verify locally against the exact key bytes.
+#[cfg(feature = "std")]
+pub fn export_vk_to_snarkjs(vk: &VerifyingKey) -> Result<SnarkjsVerifyingKey, VerifierError> {
+	let vk = vk.to_ark_vk()?;
+
+	Ok(SnarkjsVerifyingKey {
+		n_public: vk.gamma_abc_g1.len().saturating_sub(1),
+		vk_alpha_1: g1_to_snarkjs(&vk.alpha_g1),
+		vk_beta_2: g2_to_snarkjs(&vk.beta_g2),
+		vk_gamma_2: g2_to_snarkjs(&vk.gamma_g2),
+		vk_delta_2: g2_to_snarkjs(&vk.delta_g2),
+		ic: vk.gamma_abc_g1.iter().map(g1_to_snarkjs).collect(),
+	})
+}
+
+/// Parse a verifying key from the snarkjs `verification_key.json` layout
+///
+/// Unlike the proof parsers this never panics: every coordinate must be a
+/// canonical decimal field element and every point must be affine (`z` of one,
+/// or zero for infinity), on its curve and in the prime-order subgroup.
+#[cfg(feature = "std")]
+pub fn parse_vk_from_snarkjs(vk: &SnarkjsVerifyingKey) -> Result<VerifyingKey, VerifierError> {
+	use ark_groth16::VerifyingKey as ArkVK;
+
+	if vk.ic.len() != vk.n_public + 1 {
+		return Err(VerifierError::InvalidVerifyingKey);
+	}
+
+	let ark_vk = ArkVK::<ark_bn254::Bn254> {
+		alpha_g1: g1_from_snarkjs(&vk.vk_alpha_1)?,
+		beta_g2: g2_from_snarkjs(&vk.vk_beta_2)?,
+		gamma_g2: g2_from_snarkjs(&vk.vk_gamma_2)?,
+		delta_g2: g2_from_snarkjs(&vk.vk_delta_2)?,
+		gamma_abc_g1: vk
+			.ic
+			.iter()
+			.map(g1_from_snarkjs)
+			.collect::<Result<_, _>>()?,
+	};
+
+	VerifyingKey::from_ark_vk(&ark_vk)
+}
+
+#[cfg(feature = "std")]
+fn g1_from_snarkjs([x, y, z]: &[String; 3]) -> Result<G1Affine, VerifierError> {
+	let z = checked_fq(z)?;
+	if z.is_zero() {
+		return Ok(G1Affine::zero());
+	}
+	if !z.is_one() {
+		return Err(VerifierError::InvalidVerifyingKey);
+	}
+	let point = G1Affine::new_unchecked(checked_fq(x)?, checked_fq(y)?);
+	if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+		return Err(VerifierError::InvalidVerifyingKey);
+	}
+	Ok(point)
+}
+
+#[cfg(feature = "std")]
+fn g2_from_snarkjs([x, y, z]: &[[String; 2]; 3]) -> Result<G2Affine, VerifierError> {
+	let z = checked_fq2(z)?;
+	if z.is_zero() {
+		return Ok(G2Affine::zero());
+	}
+	if !z.is_one() {
+		return Err(VerifierError::InvalidVerifyingKey);
+	}
+	let point = G2Affine::new_unchecked(checked_fq2(x)?, checked_fq2(y)?);
+	if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+		return Err(VerifierError::InvalidVerifyingKey);
+	}
+	Ok(point)
+}
+
+#[cfg(feature = "std")]
+fn checked_fq2([c0, c1]: &[String; 2]) -> Result<Fq2, VerifierError> {
+	Ok(Fq2::new(checked_fq(c0)?, checked_fq(c1)?))
+}
+
+/// Decimal string to `Fq`, rejecting non-canonical values
+#[cfg(feature = "std")]
+fn checked_fq(s: &str) -> Result<Fq, VerifierError> {
+	let bigint =
+		BigUint::parse_bytes(s.as_bytes(), 10).ok_or(VerifierError::InvalidVerifyingKey)?;
+	let modulus = BigUint::from_bytes_le(&Fq::MODULUS.to_bytes_le());
+	if bigint >= modulus {
+		return Err(VerifierError::InvalidVerifyingKey);
+	}
+	Ok(Fq::from_le_bytes_mod_order(&bigint.to_bytes_le()))
+}
+
+#[cfg(feature = "std")]
+fn g1_to_snarkjs(point: &G1Affine) -> [String; 3] {
+	match point.xy() {
+		Some((x, y)) => [fq_to_decimal(&x), fq_to_decimal(&y), "1".into()],
+		None => ["0".into(), "1".into(), "0".into()],
+	}
+}
+
+#[cfg(feature = "std")]
+fn g2_to_snarkjs(point: &G2Affine) -> [[String; 2]; 3] {
+	match point.xy() {
+		Some((x, y)) => [
+			[fq_to_decimal(&x.c0), fq_to_decimal(&x.c1)],
+			[fq_to_decimal(&y.c0), fq_to_decimal(&y.c1)],
+			["1".into(), "0".into()],
+		],
+		None => [
+			["0".into(), "0".into()],
+			["1".into(), "0".into()],
+			["0".into(), "0".into()],
+		],
+	}
+}
+
+#[cfg(feature = "std")]
+fn fq_to_decimal(element: &Fq) -> String {
+	BigUint::from_bytes_le(&element.into_bigint().to_bytes_le()).to_string()
+}
+
 #[cfg(feature = "std")]
 fn parse_fq(s: &str) -> Fq {
 	let bigint = BigUint::parse_bytes(s.as_bytes(), 10).expect("Invalid field element string");
@@ -281,6 +424,101 @@ mod tests {
 		let _ = parse_proof_from_snarkjs(proof_points);
 	}
 
+	// === export_vk_to_snarkjs Tests ===
+
+	#[test]
+	fn test_export_vk_to_snarkjs_round_trips_coordinates() {
+		use ark_groth16::VerifyingKey as ArkVK;
+
+		let g2 = G2Affine::generator();
+		let ark_vk = ArkVK::<ark_bn254::Bn254> {
+			alpha_g1: G1Affine::generator(),
+			beta_g2: g2,
+			gamma_g2: g2,
+			delta_g2: g2,
+			gamma_abc_g1: vec![G1Affine::generator(), G1Affine::zero()],
+		};
+		let vk = VerifyingKey::from_ark_vk(&ark_vk).unwrap();
+
+		let exported = export_vk_to_snarkjs(&vk).unwrap();
+
+		assert_eq!(exported.n_public, 1);
+		// The BN254 G1 generator is (1, 2)
+		assert_eq!(exported.vk_alpha_1, ["1", "2", "1"]);
+		assert_eq!(exported.ic[1], ["0", "1", "0"]);
+
+		let [[x0, x1], [y0, y1], z] = &exported.vk_beta_2;
+		let beta = G2Affine::new(
+			Fq2::new(parse_fq(x0), parse_fq(x1)),
+			Fq2::new(parse_fq(y0), parse_fq(y1)),
+		);
+		assert_eq!(beta, g2);
+		assert_eq!(z, &["1", "0"]);
+	}
+
+	// === parse_vk_from_snarkjs Tests ===
+
+	fn test_snarkjs_vk() -> (VerifyingKey, SnarkjsVerifyingKey) {
+		use ark_groth16::VerifyingKey as ArkVK;
+
+		let g2 = G2Affine::generator();
+		let ark_vk = ArkVK::<ark_bn254::Bn254> {
+			alpha_g1: G1Affine::generator(),
+			beta_g2: g2,
+			gamma_g2: (g2 + g2).into(),
+			delta_g2: G2Affine::zero(),
+			gamma_abc_g1: vec![G1Affine::generator(), G1Affine::zero()],
+		};
+		let vk = VerifyingKey::from_ark_vk(&ark_vk).unwrap();
+		let exported = export_vk_to_snarkjs(&vk).unwrap();
+		(vk, exported)
+	}
+
+	#[test]
+	fn test_parse_vk_from_snarkjs_round_trips_export() {
+		let (vk, exported) = test_snarkjs_vk();
+		assert_eq!(parse_vk_from_snarkjs(&exported).unwrap(), vk);
+	}
+
+	#[test]
+	fn test_parse_vk_from_snarkjs_rejects_point_off_curve() {
+		let (_, mut exported) = test_snarkjs_vk();
+		exported.vk_alpha_1[1] = "3".into();
+		assert!(matches!(
+			parse_vk_from_snarkjs(&exported),
+			Err(VerifierError::InvalidVerifyingKey)
+		));
+	}
+
+	#[test]
+	fn test_parse_vk_from_snarkjs_rejects_malformed_coordinates() {
+		let (_, mut exported) = test_snarkjs_vk();
+		exported.vk_beta_2[0][1] = "not_a_number".into();
+		assert!(parse_vk_from_snarkjs(&exported).is_err());
+
+		// The base field modulus is not a canonical element
+		let (_, mut exported) = test_snarkjs_vk();
+		exported.vk_alpha_1[0] =
+			"21888242871839275222246405745257275088696311157297823662689037894645226208583".into();
+		assert!(parse_vk_from_snarkjs(&exported).is_err());
+	}
+
+	#[test]
+	fn test_parse_vk_from_snarkjs_rejects_ic_count_mismatch() {
+		let (_, mut exported) = test_snarkjs_vk();
+		exported.n_public = 2;
+		assert!(parse_vk_from_snarkjs(&exported).is_err());
+	}
+
+	#[test]
+	fn test_export_vk_to_snarkjs_rejects_invalid_key() {
+		let vk = VerifyingKey::new(vec![0u8; 16]);
+		assert!(matches!(
+			export_vk_to_snarkjs(&vk),
+			Err(VerifierError::InvalidVerifyingKey)
+		));
+	}
+
 	// === Helper Function Tests ===
 
 	#[test]
@@ -25,6 +25,9 @@ extern crate alloc;
 pub type Bn254Fr = ark_bn254::Fr;
 pub use ark_bn254::Bn254;
 
+/// Shared circuit identifiers and public-input schemas
+pub use domain::value_objects::circuit_constants::circuit_ids;
+
 // Clean Architecture Layers
 pub mod application;
 pub mod domain;
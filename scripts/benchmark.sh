@@ -27,6 +27,7 @@ function bench {
     echo "benchmarking ${1}::${2}"
     WASMTIME_BACKTRACE_DETAILS=1 ${BINARY} benchmark pallet \
         --chain=dev \
+        --skip-genesis-vks \
         --steps=50 \
         --repeat=20 \
         --pallet="${1}" \
@@ -9,6 +9,7 @@
 #   ./scripts/run-dev-node.sh              # Run with default settings
 #   ./scripts/run-dev-node.sh --manual     # Enable manual seal (for testing)
 #   ./scripts/run-dev-node.sh --persist    # Use persistent storage
+#   ./scripts/run-dev-node.sh --vk-dir DIR # Register the circuit keys in DIR
 #
 # =============================================================================
 
@@ -32,6 +33,7 @@ echo -e "${BLUE}╚════════════════════
 MANUAL_SEAL=""
 TMP_FLAG="--tmp"
 DATA_PATH=""
+VK_FLAG="--skip-genesis-vks"
 
 while [[ $# -gt 0 ]]; do
     case $1 in
@@ -46,6 +48,11 @@ while [[ $# -gt 0 ]]; do
             echo -e "${YELLOW}Persistent storage mode enabled${NC}"
             shift
             ;;
+        --vk-dir)
+            VK_FLAG="--genesis-vk-dir $2"
+            echo -e "${YELLOW}Registering verification keys from $2${NC}"
+            shift 2
+            ;;
         *)
             echo -e "${RED}Unknown option: $1${NC}"
             exit 1
@@ -86,6 +93,7 @@ cd "$PROJECT_ROOT"
     $TMP_FLAG \
     $DATA_PATH \
     $MANUAL_SEAL \
+    $VK_FLAG \
     --rpc-external \
     --rpc-cors=all \
     --rpc-methods=unsafe \
@@ -1,8 +1,8 @@
-use std::{collections::BTreeMap, str::FromStr};
+use std::{collections::BTreeMap, fs::File, io::BufReader, path::Path, str::FromStr};
 
 use hex_literal::hex;
 // Substrate
-use sc_chain_spec::{ChainType, Properties};
+use sc_chain_spec::{json_merge, ChainType, Properties};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 #[allow(unused_imports)]
@@ -13,6 +13,8 @@ use sp_runtime::traits::{IdentifyAccount, Verify};
 use orbinum_runtime::{
 	evm_bytes_to_account_id_bytes, AccountId, Balance, SS58Prefix, Signature, WASM_BINARY,
 };
+use orbinum_zk_verifier::{circuit_ids, infrastructure::adapters::parse_vk_from_snarkjs};
+use pallet_zk_verifier_rpc::VerificationKeyJson;
 
 pub type ChainSpec = sc_service::GenericChainSpec;
 
@@ -58,51 +60,76 @@ const FAUCET_BALANCE: Balance = 100_000_000 * PLANCK;
 const EVM_CHAIN_ID: u64 = 270;
 const TESTNET_EVM_CHAIN_ID: u64 = 2700;
 
-pub fn development_config(enable_manual_seal: bool) -> ChainSpec {
+/// Circuits whose verifying keys are registered at genesis, by the name of
+/// their snarkjs `verification_key_<circuit>.json` file.
+const GENESIS_CIRCUITS: [(&str, u32); 3] = [
+	("transfer", circuit_ids::CIRCUIT_ID_TRANSFER),
+	("unshield", circuit_ids::CIRCUIT_ID_UNSHIELD),
+	("disclosure", circuit_ids::CIRCUIT_ID_DISCLOSURE),
+];
+
+pub fn development_config(
+	enable_manual_seal: bool,
+	vk_dir: Option<&Path>,
+) -> Result<ChainSpec, String> {
 	let initial_authorities = [authority_keys_from_seed("Alice")];
 
-	ChainSpec::builder(WASM_BINARY.expect("WASM not available"), Default::default())
-		.with_name("Development")
-		.with_id("dev")
-		.with_chain_type(ChainType::Development)
-		.with_properties(properties())
-		.with_genesis_config_preset_name("development")
-		.with_genesis_config_patch(serde_json::json!({
-			"manualSeal": { "enable": enable_manual_seal },
-			"aura": {
-				"authorities": initial_authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>()
-			},
-			"grandpa": {
-				"authorities": initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>()
-			}
-		}))
-		.build()
+	let mut patch = serde_json::json!({
+		"manualSeal": { "enable": enable_manual_seal },
+		"aura": {
+			"authorities": initial_authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>()
+		},
+		"grandpa": {
+			"authorities": initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>()
+		}
+	});
+	if let Some(vk_dir) = vk_dir {
+		json_merge(&mut patch, privacy_genesis(vk_dir)?);
+	}
+
+	Ok(
+		ChainSpec::builder(WASM_BINARY.expect("WASM not available"), Default::default())
+			.with_name("Development")
+			.with_id("dev")
+			.with_chain_type(ChainType::Development)
+			.with_properties(properties())
+			.with_genesis_config_preset_name("development")
+			.with_genesis_config_patch(patch)
+			.build(),
+	)
 }
 
-pub fn local_testnet_config() -> ChainSpec {
+pub fn local_testnet_config(vk_dir: Option<&Path>) -> Result<ChainSpec, String> {
 	let initial_authorities = [
 		authority_keys_from_seed("Alice"),
 		authority_keys_from_seed("Bob"),
 	];
 
-	ChainSpec::builder(WASM_BINARY.expect("WASM not available"), Default::default())
-		.with_name("Local Testnet")
-		.with_id("local_testnet")
-		.with_chain_type(ChainType::Local)
-		.with_properties(properties())
-		.with_genesis_config_preset_name("orbinum_local_testnet_runtime_preset")
-		.with_genesis_config_patch(serde_json::json!({
-			"aura": {
-				"authorities": initial_authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>()
-			},
-			"grandpa": {
-				"authorities": initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>()
-			}
-		}))
-		.build()
+	let mut patch = serde_json::json!({
+		"aura": {
+			"authorities": initial_authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>()
+		},
+		"grandpa": {
+			"authorities": initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>()
+		}
+	});
+	if let Some(vk_dir) = vk_dir {
+		json_merge(&mut patch, privacy_genesis(vk_dir)?);
+	}
+
+	Ok(
+		ChainSpec::builder(WASM_BINARY.expect("WASM not available"), Default::default())
+			.with_name("Local Testnet")
+			.with_id("local_testnet")
+			.with_chain_type(ChainType::Local)
+			.with_properties(properties())
+			.with_genesis_config_preset_name("orbinum_local_testnet_runtime_preset")
+			.with_genesis_config_patch(patch)
+			.build(),
+	)
 }
 
-pub fn orbinum_testnet_config() -> ChainSpec {
+pub fn orbinum_testnet_config(vk_dir: Option<&Path>) -> Result<ChainSpec, String> {
 	let sudo_key = AccountId::from(hex!(
 		"d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"
 	));
@@ -110,26 +137,36 @@ pub fn orbinum_testnet_config() -> ChainSpec {
 
 	let faucet = ethereum_account_id(hex!("0000000000000000000000000000000000000001")); // PLACEHOLDER
 
-	ChainSpec::builder(WASM_BINARY.expect("WASM not available"), Default::default())
-		.with_name("Orbinum Testnet")
-		.with_id("orbinum_testnet")
-		.with_chain_type(ChainType::Live)
-		.with_properties(properties())
-		.with_genesis_config_patch(testnet_genesis(
-			sudo_key.clone(),
-			vec![
-				(treasury, TOTAL_SUPPLY - FAUCET_BALANCE),
-				(faucet, FAUCET_BALANCE),
-				(sudo_key, DEV_BALANCE),
-			],
-			vec![
-				authority_keys_from_seed("Alice"),
-				authority_keys_from_seed("Bob"),
-			],
-			TESTNET_EVM_CHAIN_ID,
-			false,
-		))
-		.build()
+	let mut patch = testnet_genesis(
+		sudo_key.clone(),
+		vec![
+			(treasury, TOTAL_SUPPLY - FAUCET_BALANCE),
+			(faucet, FAUCET_BALANCE),
+			(sudo_key, DEV_BALANCE),
+		],
+		vec![
+			authority_keys_from_seed("Alice"),
+			authority_keys_from_seed("Bob"),
+		],
+		TESTNET_EVM_CHAIN_ID,
+		false,
+	);
+	if let Some(vk_dir) = vk_dir {
+		json_merge(&mut patch, privacy_genesis(vk_dir)?);
+	}
+
+	// The preset provides the shielded pool test assets; the patch replaces
+	// everything else it sets
+	Ok(
+		ChainSpec::builder(WASM_BINARY.expect("WASM not available"), Default::default())
+			.with_name("Orbinum Testnet")
+			.with_id("orbinum_testnet")
+			.with_chain_type(ChainType::Live)
+			.with_properties(properties())
+			.with_genesis_config_preset_name("orbinum_testnet_runtime_preset")
+			.with_genesis_config_patch(patch)
+			.build(),
+	)
 }
 
 pub fn orbinum_mainnet_config() -> ChainSpec {
@@ -182,3 +219,42 @@ fn testnet_genesis(
 		"manualSeal": { "enable": enable_manual_seal }
 	})
 }
+
+/// Genesis patch registering the verifying keys of [`GENESIS_CIRCUITS`]
+///
+/// The keys are read from the snarkjs files released by the circuits
+/// repository in `vk_dir`. The disclosure key is also set in the shielded
+/// pool, which checks disclosure proofs against its own copy.
+fn privacy_genesis(vk_dir: &Path) -> Result<serde_json::Value, String> {
+	let mut verification_keys = Vec::new();
+	let mut disclosure_key = None;
+	for (circuit, circuit_id) in GENESIS_CIRCUITS {
+		let vk = load_verifying_key(&vk_dir.join(format!("verification_key_{circuit}.json")))?;
+		if circuit_id == circuit_ids::CIRCUIT_ID_DISCLOSURE {
+			disclosure_key = Some(vk.clone());
+		}
+		verification_keys.push((circuit_id, vk));
+	}
+
+	Ok(serde_json::json!({
+		"zkVerifier": { "verificationKeys": verification_keys },
+		"shieldedPool": { "disclosureVerifyingKey": disclosure_key }
+	}))
+}
+
+/// Compressed ark-groth16 bytes of a snarkjs `verification_key.json`
+fn load_verifying_key(path: &Path) -> Result<Vec<u8>, String> {
+	let file = File::open(path).map_err(|e| format!("Cannot open {}: {e}", path.display()))?;
+	let vk: VerificationKeyJson = serde_json::from_reader(BufReader::new(file))
+		.map_err(|e| format!("Invalid verification key {}: {e}", path.display()))?;
+	if vk.protocol != "groth16" || vk.curve != "bn128" {
+		return Err(format!(
+			"Verification key {} is not a Groth16 BN254 key",
+			path.display()
+		));
+	}
+
+	parse_vk_from_snarkjs(&vk.into())
+		.map(|vk| vk.bytes)
+		.map_err(|e| format!("Invalid verification key {}: {e}", path.display()))
+}
@@ -1,4 +1,8 @@
-use crate::service::EthConfiguration;
+use std::path::{Path, PathBuf};
+
+use crate::{
+	rpc::PrivacyRpcConfiguration, service::EthConfiguration, shielded_key::ShieldedKeySubcommand,
+};
 
 /// Available Sealing methods.
 #[derive(Copy, Clone, Debug, Default, clap::ValueEnum)]
@@ -23,15 +27,48 @@ pub struct Cli {
 	#[arg(long, value_enum, ignore_case = true)]
 	pub sealing: Option<Sealing>,
 
+	/// Directory of the snarkjs `verification_key_<circuit>.json` files of the
+	/// transfer, unshield and disclosure circuits, registered at genesis by the
+	/// `dev`, `local` and `testnet` chain specs.
+	#[arg(long, value_name = "PATH", global = true)]
+	pub genesis_vk_dir: Option<PathBuf>,
+
+	/// Build the `dev`, `local` and `testnet` chain specs without verification
+	/// keys; the shielded pool rejects proofs until keys are registered.
+	#[arg(long, global = true, conflicts_with = "genesis_vk_dir")]
+	pub skip_genesis_vks: bool,
+
 	#[command(flatten)]
 	pub eth: EthConfiguration,
+
+	#[command(flatten)]
+	pub privacy_rpc: PrivacyRpcConfiguration,
+}
+
+impl Cli {
+	/// Verification key directory for a chain spec with privacy genesis
+	///
+	/// Fails unless the keys are given or explicitly skipped, so a chain is
+	/// not silently started without them.
+	pub fn required_genesis_vk_dir(&self, chain: &str) -> Result<Option<&Path>, String> {
+		match self.genesis_vk_dir.as_deref() {
+			Some(vk_dir) => Ok(Some(vk_dir)),
+			None if self.skip_genesis_vks => Ok(None),
+			None => Err(format!(
+				"The `{chain}` chain spec registers the transfer, unshield and disclosure \
+				 verification keys at genesis: pass --genesis-vk-dir <PATH> with their \
+				 snarkjs verification_key_<circuit>.json files, or --skip-genesis-vks to \
+				 start without them"
+			)),
+		}
+	}
 }
 
 #[derive(Debug, clap::Subcommand)]
 pub enum Subcommand {
 	/// Key management cli utilities
 	#[command(subcommand)]
-	Key(sc_cli::KeySubcommand),
+	Key(KeySubcommand),
 
 	/// Build a chain specification.
 	BuildSpec(sc_cli::BuildSpecCmd),
@@ -66,3 +103,15 @@ pub enum Subcommand {
 	/// Db meta columns information.
 	FrontierDb(fc_cli::FrontierDbCmd),
 }
+
+/// Substrate key utilities plus shielded account keys.
+#[derive(Debug, clap::Subcommand)]
+pub enum KeySubcommand {
+	/// Shielded account keys and diversified addresses.
+	#[command(subcommand)]
+	Shielded(ShieldedKeySubcommand),
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	Substrate(sc_cli::KeySubcommand),
+}
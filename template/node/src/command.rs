@@ -7,7 +7,7 @@ use fc_db::kv::frontier_database_dir;
 
 use crate::{
 	chain_spec,
-	cli::{Cli, Subcommand},
+	cli::{Cli, KeySubcommand, Subcommand},
 	service::{self, db_config_dir},
 };
 
@@ -43,10 +43,17 @@ impl SubstrateCli for Cli {
 		Ok(match id {
 			"dev" => {
 				let enable_manual_seal = self.sealing.map(|_| true).unwrap_or_default();
-				Box::new(chain_spec::development_config(enable_manual_seal))
+				Box::new(chain_spec::development_config(
+					enable_manual_seal,
+					self.required_genesis_vk_dir("dev")?,
+				)?)
 			}
-			"" | "local" => Box::new(chain_spec::local_testnet_config()),
-			"testnet" | "orbinum_testnet" => Box::new(chain_spec::orbinum_testnet_config()),
+			"" | "local" => Box::new(chain_spec::local_testnet_config(
+				self.required_genesis_vk_dir("local")?,
+			)?),
+			"testnet" | "orbinum_testnet" => Box::new(chain_spec::orbinum_testnet_config(
+				self.required_genesis_vk_dir("testnet")?,
+			)?),
 			"mainnet" | "orbinum_mainnet" => Box::new(chain_spec::orbinum_mainnet_config()),
 			path => Box::new(chain_spec::ChainSpec::from_json_file(
 				std::path::PathBuf::from(path),
@@ -60,7 +67,8 @@ pub fn run() -> sc_cli::Result<()> {
 	let cli = Cli::from_args();
 
 	match &cli.subcommand {
-		Some(Subcommand::Key(cmd)) => cmd.run(&cli),
+		Some(Subcommand::Key(KeySubcommand::Substrate(cmd))) => cmd.run(&cli),
+		Some(Subcommand::Key(KeySubcommand::Shielded(cmd))) => cmd.run(),
 		Some(Subcommand::BuildSpec(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run(config.chain_spec, config.network))
@@ -230,7 +238,7 @@ pub fn run() -> sc_cli::Result<()> {
 		None => {
 			let runner = cli.create_runner(&cli.run)?;
 			runner.run_node_until_exit(|config| async move {
-				service::build_full(config, cli.eth, cli.sealing)
+				service::build_full(config, cli.eth, cli.privacy_rpc, cli.sealing)
 					.map_err(Into::into)
 					.await
 			})
@@ -16,8 +16,10 @@ mod cli;
 mod client;
 mod command;
 mod eth;
+mod proof_import;
 mod rpc;
 mod service;
+mod shielded_key;
 
 fn main() -> sc_cli::Result<()> {
 	command::run()
@@ -0,0 +1,136 @@
+//! Block import pre-verifying shielded proofs
+//!
+//! Before a block is executed, the Groth16 proofs of its `private_transfer`
+//! and `unshield` extrinsics are verified in parallel and cached natively
+//! (`orbinum_zk_host::cache`). Execution then reads the cached results
+//! instead of verifying each proof in turn.
+
+use std::{marker::PhantomData, sync::Arc, time::Instant};
+
+use futures::channel::oneshot;
+// Substrate
+use sc_consensus::{BlockCheckParams, BlockImport, BlockImportParams, ImportResult, StateAction};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_core::traits::SpawnNamed;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+// Orbinum
+use pallet_shielded_pool_runtime_api::{ShieldedPoolRuntimeApi, SHIELDED_POOL_API_V2};
+
+const LOG_TARGET: &str = "proof-import";
+
+/// Block import verifying the shielded proofs of a block ahead of execution
+///
+/// Wraps the import pipeline; blocks are imported unchanged. Failing to
+/// collect the proofs only skips pre-verification, since execution
+/// verifies them anyway.
+pub struct PreVerifyBlockImport<B: BlockT, I, C> {
+	inner: I,
+	client: Arc<C>,
+	spawner: Box<dyn SpawnNamed>,
+	_marker: PhantomData<B>,
+}
+
+impl<B: BlockT, I: Clone, C> Clone for PreVerifyBlockImport<B, I, C> {
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner.clone(),
+			client: self.client.clone(),
+			spawner: self.spawner.clone(),
+			_marker: PhantomData,
+		}
+	}
+}
+
+impl<B, I, C> PreVerifyBlockImport<B, I, C>
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: ShieldedPoolRuntimeApi<B>,
+{
+	/// Wraps `inner`, collecting proofs through `client` and verifying them
+	/// on blocking tasks of `spawner`
+	pub fn new(inner: I, client: Arc<C>, spawner: Box<dyn SpawnNamed>) -> Self {
+		Self {
+			inner,
+			client,
+			spawner,
+			_marker: PhantomData,
+		}
+	}
+}
+
+/// Verifies the proofs of `body` against the state at `parent`
+fn pre_verify<B, C>(client: &C, parent: B::Hash, body: Vec<B::Extrinsic>)
+where
+	B: BlockT,
+	C: ProvideRuntimeApi<B>,
+	C::Api: ShieldedPoolRuntimeApi<B>,
+{
+	let api = client.runtime_api();
+	// Blocks built on a runtime without the query are executed as is
+	match api.api_version::<dyn ShieldedPoolRuntimeApi<B>>(parent) {
+		Ok(Some(version)) if version >= SHIELDED_POOL_API_V2 => {}
+		_ => return,
+	}
+	let jobs = match api.get_proof_jobs(parent, body) {
+		Ok(jobs) => jobs,
+		Err(err) => {
+			log::debug!(target: LOG_TARGET, "Skipping proof pre-verification: {err}");
+			return;
+		}
+	};
+	if jobs.is_empty() {
+		return;
+	}
+
+	let started = Instant::now();
+	let valid = orbinum_zk_host::cache::verify_all(&jobs);
+	log::debug!(
+		target: LOG_TARGET,
+		"Pre-verified {} shielded proofs ({valid} valid) in {:?}",
+		jobs.len(),
+		started.elapsed(),
+	);
+}
+
+#[async_trait::async_trait]
+impl<B, I, C> BlockImport<B> for PreVerifyBlockImport<B, I, C>
+where
+	B: BlockT,
+	I: BlockImport<B> + Send + Sync,
+	C: ProvideRuntimeApi<B> + Send + Sync + 'static,
+	C::Api: ShieldedPoolRuntimeApi<B>,
+{
+	type Error = I::Error;
+
+	async fn check_block(&self, block: BlockCheckParams<B>) -> Result<ImportResult, Self::Error> {
+		self.inner.check_block(block).await
+	}
+
+	async fn import_block(&self, block: BlockImportParams<B>) -> Result<ImportResult, Self::Error> {
+		// Locally authored blocks carry their post-state and are not executed again
+		let executes = matches!(
+			block.state_action,
+			StateAction::Execute | StateAction::ExecuteIfPossible
+		);
+		if let (true, Some(body)) = (executes, &block.body) {
+			let client = self.client.clone();
+			let parent = *block.header.parent_hash();
+			let body = body.clone();
+			let (done, verified) = oneshot::channel();
+			// Verification is CPU-bound and must not stall the import executor
+			self.spawner.spawn_blocking(
+				"proof-pre-verification",
+				Some("block-import"),
+				Box::pin(async move {
+					pre_verify::<B, C>(&client, parent, body);
+					let _ = done.send(());
+				}),
+			);
+			// A dropped task only loses the cache warm-up
+			let _ = verified.await;
+		}
+
+		self.inner.import_block(block).await
+	}
+}
@@ -1,9 +1,10 @@
 //! A collection of node-specific RPC methods.
 
-use std::sync::Arc;
+use std::{num::NonZeroU32, sync::Arc};
 
 use futures::channel::mpsc;
 use jsonrpsee::RpcModule;
+use prometheus_endpoint::Registry;
 // Substrate
 use sc_client_api::{
 	backend::{Backend, StorageProvider},
@@ -19,11 +20,39 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_inherents::CreateInherentDataProviders;
 use sp_runtime::traits::Block as BlockT;
 // Runtime
-use orbinum_runtime::{AccountId, Balance, Hash, Nonce};
+use orbinum_runtime::{AccountId, Balance, BlockNumber, Hash, Nonce};
+// Orbinum
+use fc_rpc_v2::{RateLimitConfig, RateLimiter};
 
 mod eth;
 pub use self::eth::{create_eth, EthDeps};
 
+/// Rate limiting options of the privacy RPC endpoints.
+#[derive(Clone, Debug, clap::Parser)]
+pub struct PrivacyRpcConfiguration {
+	/// Cost units each RPC connection may spend per second on the privacy
+	/// endpoints, where a light call costs 1 unit. Unlimited when unset.
+	#[arg(long)]
+	pub privacy_rpc_rate_limit: Option<NonZeroU32>,
+
+	/// Cost units of one heavy privacy RPC call (Merkle proofs, block range
+	/// scans, tree snapshot export).
+	#[arg(long, default_value = "10")]
+	pub privacy_rpc_heavy_cost: u32,
+}
+
+impl PrivacyRpcConfiguration {
+	/// Rate limiter shared by the privacy RPC modules, `None` when disabled.
+	pub fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+		self.privacy_rpc_rate_limit.map(|units_per_second| {
+			Arc::new(RateLimiter::new(RateLimitConfig {
+				units_per_second,
+				heavy_cost: self.privacy_rpc_heavy_cost,
+			}))
+		})
+	}
+}
+
 /// Full client dependencies.
 pub struct FullDeps<B: BlockT, C, P, CT, CIDP> {
 	/// The client instance to use.
@@ -32,6 +61,10 @@ pub struct FullDeps<B: BlockT, C, P, CT, CIDP> {
 	pub pool: Arc<P>,
 	/// Manual seal command sink
 	pub command_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
+	/// Registry the privacy RPC metrics are exported to.
+	pub prometheus_registry: Option<Registry>,
+	/// Per-connection rate limiter of the privacy RPC endpoints.
+	pub privacy_rate_limiter: Option<Arc<RateLimiter>>,
 	/// Ethereum-compatibility specific dependencies.
 	pub eth: EthDeps<B, C, P, CT, CIDP>,
 }
@@ -70,6 +103,8 @@ where
 	C::Api: fp_rpc::EthereumRuntimeRPCApi<B>,
 	C::Api: pallet_account_mapping_runtime_api::AccountMappingRuntimeApi<B, AccountId, u128>,
 	C::Api: pallet_shielded_pool_runtime_api::ShieldedPoolRuntimeApi<B>,
+	C::Api:
+		pallet_shielded_pool_runtime_api::ShieldedPoolAuditApi<B, AccountId, Balance, BlockNumber>,
 	C::Api: pallet_zk_verifier_runtime_api::ZkVerifierRuntimeApi<B>,
 	C: HeaderBackend<B> + HeaderMetadata<B, Error = BlockChainError> + 'static,
 	C: BlockchainEvents<B> + AuxStore + UsageProvider<B> + StorageProvider<B, BE>,
@@ -86,26 +121,41 @@ where
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	// Orbinum Privacy RPC
-	use fc_rpc_v2::{PrivacyApiServer, PrivacyRpcServer, SubstrateStorageAdapter};
+	use fc_rpc_v2::{
+		PrivacyApiServer, PrivacyPubSubApiServer, PrivacyPubSubServer, PrivacyRpcServer,
+		SubstrateStorageAdapter,
+	};
 
 	let mut io = RpcModule::new(());
 	let FullDeps {
 		client,
 		pool,
 		command_sink,
+		prometheus_registry,
+		privacy_rate_limiter,
 		eth,
 	} = deps;
 
 	io.merge(System::new(client.clone(), pool).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 	io.merge(AccountMapping::new(client.clone()).into_rpc())?;
-	io.merge(ShieldedPool::new(client.clone()).into_rpc())?;
-	io.merge(ZkVerifier::new(client.clone()).into_rpc())?;
 
 	// Orbinum Privacy RPC
 	let privacy_adapter = SubstrateStorageAdapter::new(client.clone());
-	let privacy_rpc = PrivacyRpcServer::new(privacy_adapter);
+	let privacy_rpc = PrivacyRpcServer::new(privacy_adapter.clone())
+		.with_prometheus(prometheus_registry.as_ref())
+		.with_rate_limiter(privacy_rate_limiter.clone());
+	io.merge(
+		ShieldedPool::<C, B, BE, orbinum_runtime::Runtime>::new(client.clone())
+			.with_rate_limiter(privacy_rate_limiter)
+			.with_metrics(privacy_rpc.metrics())
+			.into_rpc(),
+	)?;
+	io.merge(ZkVerifier::new(client.clone()).into_rpc())?;
 	io.merge(privacy_rpc.into_rpc())?;
+	let privacy_pubsub =
+		PrivacyPubSubServer::new(privacy_adapter, subscription_task_executor.clone());
+	io.merge(privacy_pubsub.into_rpc())?;
 
 	if let Some(command_sink) = command_sink {
 		io.merge(
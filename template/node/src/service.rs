@@ -19,7 +19,9 @@ use sp_consensus_aura::sr25519::{AuthorityId as AuraId, AuthorityPair as AuraPai
 use sp_core::{H256, U256};
 use sp_runtime::traits::{Block as BlockT, NumberFor};
 // Runtime
-use orbinum_runtime::{opaque::Block, AccountId, Balance, Nonce, RuntimeApi, TransactionConverter};
+use orbinum_runtime::{
+	opaque::Block, AccountId, Balance, BlockNumber, Nonce, RuntimeApi, TransactionConverter,
+};
 
 pub use crate::eth::{db_config_dir, EthConfiguration};
 use crate::{
@@ -30,6 +32,8 @@ use crate::{
 		FrontierBackend, FrontierBlockImport, FrontierPartialComponents, StorageOverride,
 		StorageOverrideHandler,
 	},
+	proof_import::PreVerifyBlockImport,
+	rpc::PrivacyRpcConfiguration,
 };
 
 /// Only enable the benchmarking host functions when we actually want to benchmark.
@@ -38,6 +42,7 @@ pub type HostFunctions = (
 	sp_io::SubstrateHostFunctions,
 	frame_benchmarking::benchmarking::HostFunctions,
 	cumulus_primitives_proof_size_hostfunction::storage_proof_size::HostFunctions,
+	orbinum_zk_host::host_interface::zk_host_interface::HostFunctions,
 	orbinum_zk_core::infrastructure::host_interface::poseidon_host_interface::HostFunctions,
 );
 #[cfg(all(feature = "runtime-benchmarks", not(feature = "poseidon-native")))]
@@ -45,18 +50,21 @@ pub type HostFunctions = (
 	sp_io::SubstrateHostFunctions,
 	frame_benchmarking::benchmarking::HostFunctions,
 	cumulus_primitives_proof_size_hostfunction::storage_proof_size::HostFunctions,
+	orbinum_zk_host::host_interface::zk_host_interface::HostFunctions,
 );
 /// Otherwise we use empty host functions for ext host functions.
 #[cfg(all(not(feature = "runtime-benchmarks"), feature = "poseidon-native"))]
 pub type HostFunctions = (
 	sp_io::SubstrateHostFunctions,
 	cumulus_primitives_proof_size_hostfunction::storage_proof_size::HostFunctions,
+	orbinum_zk_host::host_interface::zk_host_interface::HostFunctions,
 	orbinum_zk_core::infrastructure::host_interface::poseidon_host_interface::HostFunctions,
 );
 #[cfg(all(not(feature = "runtime-benchmarks"), not(feature = "poseidon-native")))]
 pub type HostFunctions = (
 	sp_io::SubstrateHostFunctions,
 	cumulus_primitives_proof_size_hostfunction::storage_proof_size::HostFunctions,
+	orbinum_zk_host::host_interface::zk_host_interface::HostFunctions,
 );
 
 pub type Backend = FullBackend<Block>;
@@ -232,6 +240,11 @@ where
 {
 	let frontier_block_import =
 		FrontierBlockImport::new(grandpa_block_import.clone(), client.clone());
+	let block_import = PreVerifyBlockImport::new(
+		frontier_block_import,
+		client.clone(),
+		Box::new(task_manager.spawn_handle()),
+	);
 
 	let slot_duration = sc_consensus_aura::slot_duration(&*client)?;
 	let target_gas_price = eth_config.target_gas_price;
@@ -248,7 +261,7 @@ where
 
 	let import_queue = sc_consensus_aura::import_queue::<AuraPair, _, _, _, _, _>(
 		sc_consensus_aura::ImportQueueParams {
-			block_import: frontier_block_import.clone(),
+			block_import: block_import.clone(),
 			justification_import: Some(Box::new(grandpa_block_import)),
 			client,
 			create_inherent_data_providers,
@@ -261,7 +274,7 @@ where
 	)
 	.map_err::<ServiceError, _>(Into::into)?;
 
-	Ok((import_queue, Box::new(frontier_block_import)))
+	Ok((import_queue, Box::new(block_import)))
 }
 
 /// Build the import queue for the template runtime (manual seal).
@@ -280,14 +293,19 @@ where
 	RA::RuntimeApi: RuntimeApiCollection<B, AuraId, AccountId, Nonce, Balance>,
 	HF: HostFunctionsT + 'static,
 {
-	let frontier_block_import = FrontierBlockImport::new(client.clone(), client);
+	let frontier_block_import = FrontierBlockImport::new(client.clone(), client.clone());
+	let block_import = PreVerifyBlockImport::new(
+		frontier_block_import,
+		client,
+		Box::new(task_manager.spawn_handle()),
+	);
 	Ok((
 		sc_consensus_manual_seal::import_queue(
-			Box::new(frontier_block_import.clone()),
+			Box::new(block_import.clone()),
 			&task_manager.spawn_essential_handle(),
 			config.prometheus_registry(),
 		),
-		Box::new(frontier_block_import),
+		Box::new(block_import),
 	))
 }
 
@@ -295,6 +313,7 @@ where
 pub async fn new_full<B, RA, HF, NB>(
 	mut config: Configuration,
 	eth_config: EthConfiguration,
+	privacy_rpc_config: PrivacyRpcConfiguration,
 	sealing: Option<Sealing>,
 ) -> Result<TaskManager, ServiceError>
 where
@@ -307,6 +326,8 @@ where
 	RA::RuntimeApi:
 		pallet_account_mapping_runtime_api::AccountMappingRuntimeApi<B, AccountId, u128>,
 	RA::RuntimeApi: pallet_zk_verifier_runtime_api::ZkVerifierRuntimeApi<B>,
+	RA::RuntimeApi:
+		pallet_shielded_pool_runtime_api::ShieldedPoolAuditApi<B, AccountId, Balance, BlockNumber>,
 	HF: HostFunctionsT + 'static,
 	NB: sc_network::NetworkBackend<B, <B as BlockT>::Hash>,
 {
@@ -444,6 +465,8 @@ where
 		let pubsub_notification_sinks = pubsub_notification_sinks.clone();
 		let storage_override = storage_override.clone();
 		let fee_history_cache = fee_history_cache.clone();
+		let prometheus_registry = prometheus_registry.clone();
+		let privacy_rate_limiter = privacy_rpc_config.rate_limiter();
 		let block_data_cache = Arc::new(fc_rpc::EthBlockDataCacheTask::new(
 			task_manager.spawn_handle(),
 			storage_override.clone(),
@@ -497,6 +520,8 @@ where
 				} else {
 					None
 				},
+				prometheus_registry: prometheus_registry.clone(),
+				privacy_rate_limiter: privacy_rate_limiter.clone(),
 				eth: eth_deps,
 			};
 			crate::rpc::create_full(
@@ -754,10 +779,14 @@ where
 pub async fn build_full(
 	config: Configuration,
 	eth_config: EthConfiguration,
+	privacy_rpc_config: PrivacyRpcConfiguration,
 	sealing: Option<Sealing>,
 ) -> Result<TaskManager, ServiceError> {
 	new_full::<Block, RuntimeApi, HostFunctions, sc_network::NetworkWorker<_, _>>(
-		config, eth_config, sealing,
+		config,
+		eth_config,
+		privacy_rpc_config,
+		sealing,
 	)
 	.await
 }
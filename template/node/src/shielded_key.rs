@@ -0,0 +1,249 @@
+//! `key shielded` subcommands: shielded accounts and their diversified addresses.
+
+use bip39::Mnemonic;
+use orbinum_wallet_core::Account;
+use orbinum_zk_core::{
+	ExtendedSpendingKey, FieldElement, HdKeyService, LightPoseidonHasher, ShieldedAddress,
+};
+use rand_core::{OsRng, RngCore};
+use sc_cli::{Error, OutputType};
+use serde_json::json;
+
+/// Coin type used when none is given (SLIP-44 "testnet, all coins")
+const DEFAULT_COIN_TYPE: u32 = 1;
+
+/// Shielded key management cli utilities
+#[derive(Debug, clap::Subcommand)]
+pub enum ShieldedKeySubcommand {
+	/// Generate a shielded account from a new BIP39 phrase.
+	Generate(GenerateCmd),
+
+	/// Print the keys and first address of a shielded account.
+	Inspect(InspectCmd),
+
+	/// Derive diversified receiving addresses of a shielded account.
+	DeriveAddress(DeriveAddressCmd),
+}
+
+/// Account derivation and output options shared by the subcommands.
+#[derive(Debug, Clone, clap::Args)]
+pub struct AccountParams {
+	/// Coin type of the derivation path `m/32'/coin_type'/account'`.
+	#[arg(long, default_value_t = DEFAULT_COIN_TYPE)]
+	pub coin_type: u32,
+
+	/// Account index of the derivation path.
+	#[arg(long, default_value_t = 0)]
+	pub account: u32,
+
+	/// BIP39 passphrase of the secret phrase.
+	#[arg(long)]
+	pub password: Option<String>,
+
+	/// Output format.
+	#[arg(
+		long,
+		value_name = "FORMAT",
+		value_enum,
+		ignore_case = true,
+		default_value = "text"
+	)]
+	pub output_type: OutputType,
+}
+
+/// `key shielded generate`
+#[derive(Debug, Clone, clap::Parser)]
+pub struct GenerateCmd {
+	/// Number of words of the secret phrase (12, 15, 18, 21 or 24).
+	#[arg(short = 'w', long, default_value_t = 12)]
+	pub words: usize,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub params: AccountParams,
+}
+
+/// `key shielded inspect`
+#[derive(Debug, Clone, clap::Parser)]
+pub struct InspectCmd {
+	/// BIP39 secret phrase, or `0x`-prefixed extended spending key (the
+	/// derivation path options are ignored for extended keys).
+	pub secret: String,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub params: AccountParams,
+}
+
+/// `key shielded derive-address`
+#[derive(Debug, Clone, clap::Parser)]
+pub struct DeriveAddressCmd {
+	/// BIP39 secret phrase, or `0x`-prefixed extended spending key.
+	#[arg(
+		required_unless_present = "viewing_key",
+		conflicts_with = "viewing_key"
+	)]
+	pub secret: Option<String>,
+
+	/// HD viewing key of the account, to derive addresses without its secret.
+	#[arg(long)]
+	pub viewing_key: Option<String>,
+
+	/// Diversifier index of the first address.
+	#[arg(long, default_value_t = 0)]
+	pub index: u64,
+
+	/// Number of consecutive addresses to derive.
+	#[arg(long, default_value_t = 1)]
+	pub count: u64,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	pub params: AccountParams,
+}
+
+impl ShieldedKeySubcommand {
+	/// Run the subcommand.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		match self {
+			ShieldedKeySubcommand::Generate(cmd) => cmd.run(),
+			ShieldedKeySubcommand::Inspect(cmd) => cmd.run(),
+			ShieldedKeySubcommand::DeriveAddress(cmd) => cmd.run(),
+		}
+	}
+}
+
+impl GenerateCmd {
+	/// Run the command.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let entropy_length = match self.words {
+			12 | 15 | 18 | 21 | 24 => self.words / 3 * 4,
+			words => return Err(Error::Input(format!("Invalid number of words: {words}"))),
+		};
+		let mut entropy = [0u8; 32];
+		OsRng
+			.try_fill_bytes(&mut entropy[..entropy_length])
+			.map_err(|e| Error::Application(Box::new(e)))?;
+		let mnemonic = Mnemonic::from_entropy(&entropy[..entropy_length])
+			.map_err(|e| Error::Input(e.to_string()))?;
+
+		print_account(&mnemonic.to_string(), &self.params)
+	}
+}
+
+impl InspectCmd {
+	/// Run the command.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		print_account(&self.secret, &self.params)
+	}
+}
+
+impl DeriveAddressCmd {
+	/// Run the command.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let viewing_key = match (&self.secret, &self.viewing_key) {
+			(_, Some(viewing_key)) => FieldElement::from_hex(viewing_key)
+				.map_err(|e| Error::Input(format!("Invalid viewing key: {e}")))?,
+			(Some(secret), None) => load_account(secret, &self.params)?.viewing_key(),
+			(None, None) => return Err(Error::Input("Missing secret or viewing key".into())),
+		};
+
+		let last = self
+			.index
+			.checked_add(self.count)
+			.ok_or_else(|| Error::Input("Address index overflow".into()))?;
+		let hd = HdKeyService::new(LightPoseidonHasher);
+		let addresses: Vec<_> = (self.index..last)
+			.map(|index| {
+				let address = hd.derive_diversified_address(viewing_key, index);
+				(index, ShieldedAddress::from(address).encode())
+			})
+			.collect();
+
+		match self.params.output_type {
+			OutputType::Json => {
+				let addresses: Vec<_> = addresses
+					.iter()
+					.map(|(index, address)| json!({ "index": index, "address": address }))
+					.collect();
+				println!("{}", to_json(&json!(addresses))?);
+			}
+			OutputType::Text => {
+				for (index, address) in addresses {
+					println!("Address ({index}): {address}");
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Account of a secret phrase or extended spending key.
+fn load_account(secret: &str, params: &AccountParams) -> sc_cli::Result<Account> {
+	if let Some(digits) = secret.strip_prefix("0x") {
+		let bytes = hex::decode(digits)
+			.map_err(|_| Error::Input("Invalid extended spending key hex".into()))?;
+		let extended_key = ExtendedSpendingKey::from_bytes(&bytes)
+			.map_err(|e| Error::Input(format!("Invalid extended spending key: {e}")))?;
+		return Ok(Account::from_extended_key(extended_key));
+	}
+
+	let mnemonic =
+		Mnemonic::parse(secret).map_err(|e| Error::Input(format!("Invalid secret phrase: {e}")))?;
+	let seed = mnemonic.to_seed(params.password.as_deref().unwrap_or(""));
+	Account::from_seed(&seed, params.coin_type, params.account)
+		.map_err(|e| Error::Input(format!("Key derivation failed: {e}")))
+}
+
+/// Prints the keys of the account of `secret` and its first address.
+fn print_account(secret: &str, params: &AccountParams) -> sc_cli::Result<()> {
+	let account = load_account(secret, params)?;
+	let hd = HdKeyService::new(LightPoseidonHasher);
+	let is_phrase = !secret.starts_with("0x");
+
+	let path = format!("m/32'/{}'/{}'", params.coin_type, params.account);
+	let extended_key = to_hex(&account.extended_key().to_bytes());
+	let viewing_key = account.viewing_key().to_hex();
+	let memo_viewing_key = to_hex(account.keys().viewing_key.as_bytes());
+	let fingerprint = to_hex(&hd.fingerprint(account.extended_key()));
+	let address = account.shielded_address(0).encode();
+
+	match params.output_type {
+		OutputType::Json => {
+			let mut output = json!({
+				"extendedSpendingKey": extended_key,
+				"viewingKey": viewing_key,
+				"memoViewingKey": memo_viewing_key,
+				"fingerprint": fingerprint,
+				"address": address,
+			});
+			if is_phrase {
+				output["secretPhrase"] = json!(secret);
+				output["derivationPath"] = json!(path);
+			}
+			println!("{}", to_json(&output)?);
+		}
+		OutputType::Text => {
+			if is_phrase {
+				println!("Secret phrase:           {secret}");
+				println!("  Derivation path:       {path}");
+			} else {
+				println!("Extended key account");
+			}
+			println!("  Extended spending key: {extended_key}");
+			println!("  Viewing key:           {viewing_key}");
+			println!("  Memo viewing key:      {memo_viewing_key}");
+			println!("  Fingerprint:           {fingerprint}");
+			println!("  Address (0):           {address}");
+		}
+	}
+	Ok(())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+	format!("0x{}", hex::encode(bytes))
+}
+
+fn to_json(value: &serde_json::Value) -> sc_cli::Result<String> {
+	serde_json::to_string_pretty(value).map_err(|e| Error::Application(Box::new(e)))
+}
@@ -5,7 +5,7 @@ mod testnet;
 
 use crate::{
 	AccountId, BalancesConfig, EVMChainIdConfig, EVMConfig, EthereumConfig, ManualSealConfig,
-	RuntimeGenesisConfig, SudoConfig,
+	RuntimeGenesisConfig, ShieldedPoolConfig, SudoConfig,
 };
 use hex_literal::hex;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -34,6 +34,15 @@ pub(super) const TOTAL_SUPPLY: u128 = 1_000_000_000 * PLANCK;
 pub(super) const DEV_BALANCE: u128 = 10_000 * PLANCK;
 // ──────────────────────────────────────────────────────────────────────────────
 
+/// Shielded assets `(name, symbol, decimals)` registered and verified at
+/// genesis on development and test networks, with ids from 1.
+pub(super) fn test_assets() -> Vec<(Vec<u8>, Vec<u8>, u8)> {
+	vec![
+		(b"Test USD".to_vec(), b"tUSD".to_vec(), 6),
+		(b"Test Wrapped Ether".to_vec(), b"tWETH".to_vec(), 18),
+	]
+}
+
 pub(super) const DEV_PRESET_ID: &str = sp_genesis_builder::DEV_RUNTIME_PRESET;
 pub(super) const LOCAL_PRESET_ID: &str = "orbinum_local_testnet_runtime_preset";
 pub(super) const TESTNET_PRESET_ID: &str = "orbinum_testnet_runtime_preset";
@@ -46,6 +55,7 @@ pub(super) fn build_genesis(
 	_initial_authorities: Vec<(AuraId, GrandpaId)>,
 	chain_id: u64,
 	enable_manual_seal: bool,
+	shielded_assets: Vec<(Vec<u8>, Vec<u8>, u8)>,
 ) -> serde_json::Value {
 	let evm_accounts = {
 		let mut map = sp_std::collections::btree_map::BTreeMap::new();
@@ -91,7 +101,10 @@ pub(super) fn build_genesis(
 		},
 		transaction_payment: Default::default(),
 		zk_verifier: Default::default(),
-		shielded_pool: Default::default(),
+		shielded_pool: ShieldedPoolConfig {
+			assets: shielded_assets,
+			..Default::default()
+		},
 	};
 
 	serde_json::to_value(&config).expect("Could not build genesis config.")
@@ -1,5 +1,5 @@
 use crate::genesis_config_preset::{
-	build_genesis, ethereum_to_account_id, DEV_BALANCE, TOTAL_SUPPLY,
+	build_genesis, ethereum_to_account_id, test_assets, DEV_BALANCE, TOTAL_SUPPLY,
 };
 use crate::AccountId;
 use hex_literal::hex;
@@ -61,5 +61,6 @@ pub fn development() -> serde_json::Value {
 		vec![],
 		42,
 		false,
+		test_assets(),
 	)
 }
@@ -3,5 +3,12 @@ use crate::AccountId;
 use sp_std::vec;
 
 pub fn mainnet() -> serde_json::Value {
-	build_genesis(AccountId::from([0u8; 32]), vec![], vec![], 270, false)
+	build_genesis(
+		AccountId::from([0u8; 32]),
+		vec![],
+		vec![],
+		270,
+		false,
+		vec![],
+	)
 }
@@ -1,5 +1,5 @@
 use crate::genesis_config_preset::{
-	build_genesis, ethereum_to_account_id, DEV_BALANCE, TOTAL_SUPPLY,
+	build_genesis, ethereum_to_account_id, test_assets, DEV_BALANCE, TOTAL_SUPPLY,
 };
 use crate::AccountId;
 use hex_literal::hex;
@@ -35,5 +35,6 @@ pub fn testnet() -> serde_json::Value {
 		vec![],
 		2700,
 		false,
+		test_assets(),
 	)
 }
@@ -58,6 +58,7 @@ use frame_support::{
 	weights::{constants::WEIGHT_REF_TIME_PER_MILLIS, IdentityFee, Weight},
 	PalletId,
 };
+use frame_system::EnsureRoot;
 use pallet_transaction_payment::FungibleAdapter;
 use polkadot_runtime_common::SlowAdjustingFeeUpdate;
 use sp_genesis_builder::PresetId;
@@ -149,6 +150,20 @@ pub type CheckedExtrinsic =
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 
+/// Storage migrations run on the next runtime upgrade, oldest first.
+///
+/// Each migration is versioned and becomes a no-op once the pallet storage
+/// version is bumped; rehearse them against a live-chain snapshot with
+/// `try-runtime on-runtime-upgrade` before enacting an upgrade. The shielded
+/// pool migrations run after the verifier's, whose keys they rely on.
+/// They only run when `spec_version` is bumped, and the shielded pool's
+/// commitment index and nullifier count backfills then continue in `on_idle`.
+pub type Migrations = (
+	pallet_zk_verifier::infrastructure::migrations::v1::MigrateToV1<Runtime>,
+	pallet_shielded_pool::infrastructure::migrations::v1::MigrateToV1<Runtime>,
+	pallet_shielded_pool::infrastructure::migrations::v2::MigrateToV2<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -156,6 +171,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 // Time is measured by number of blocks.
@@ -194,10 +210,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: Cow::Borrowed("orbinum"),
 	impl_name: Cow::Borrowed("orbinum"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	system_version: 1,
 };
 
@@ -506,11 +522,22 @@ pub mod pallet_manual_seal {
 
 impl pallet_manual_seal::Config for Runtime {}
 
+// Dedicated `CircuitAdmin` / `AssetAdmin` origins and their OpenGov tracks are
+// blocked on governance: the runtime has no referenda, conviction-voting or
+// collective pallet that could dispatch them. Until one lands, both admin
+// origins stay root; the pallets only need these aliases swapped.
+
+/// Manages circuit verification keys and the disclosure key
+pub type CircuitAdminOrigin = EnsureRoot<AccountId>;
+/// Manages shielded assets
+pub type AssetAdminOrigin = EnsureRoot<AccountId>;
+
 impl pallet_zk_verifier::Config for Runtime {
 	/// Max proof size: 1KB (Groth16 proofs ~256-512 bytes)
 	type MaxProofSize = ConstU32<1024>;
 	/// Max public inputs: 32 field elements per circuit
 	type MaxPublicInputs = ConstU32<32>;
+	type CircuitAdminOrigin = CircuitAdminOrigin;
 	type WeightInfo = pallet_zk_verifier::weights::SubstrateWeight<Runtime>;
 }
 
@@ -532,6 +559,10 @@ impl pallet_shielded_pool::Config for Runtime {
 	type MaxHistoricRoots = ConstU32<100>;
 	/// Minimum shield amount: prevents spam, 1 ORB = 1e18 wei
 	type MinShieldAmount = ConstU128<1_000_000_000_000_000_000>;
+	/// Disclosure requests stay open for 30 days
+	type DisclosureRequestExpiration = ConstU32<{ 30 * DAYS }>;
+	type CircuitAdminOrigin = CircuitAdminOrigin;
+	type AssetAdminOrigin = AssetAdminOrigin;
 	type WeightInfo = pallet_shielded_pool::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1129,6 +1160,7 @@ impl_runtime_apis! {
 	}
 
 	// ShieldedPool Runtime API implementation
+	#[api_version(2)]
 	impl pallet_shielded_pool_runtime_api::ShieldedPoolRuntimeApi<Block> for Runtime {
 		fn get_merkle_tree_info() -> (pallet_shielded_pool::Hash, u32, u32) {
 			ShieldedPool::get_merkle_tree_info()
@@ -1143,8 +1175,108 @@ impl_runtime_apis! {
 		) -> Option<(u32, pallet_shielded_pool::DefaultMerklePath)> {
 			ShieldedPool::get_merkle_proof_for_commitment(commitment)
 		}
+
+		fn get_commitment_index(commitment: pallet_shielded_pool::Hash) -> Option<u32> {
+			ShieldedPool::get_commitment_index(commitment)
+		}
+
+		fn get_encrypted_memo(commitment: pallet_shielded_pool::Hash) -> Option<Vec<u8>> {
+			ShieldedPool::get_encrypted_memo(commitment)
+		}
+
+		fn get_leaves(start_leaf: u32, count: u32) -> Vec<pallet_shielded_pool::Hash> {
+			ShieldedPool::get_leaves(start_leaf, count)
+		}
+
+		fn get_tree_frontier() -> Vec<pallet_shielded_pool::Hash> {
+			ShieldedPool::get_tree_frontier()
+		}
+
+		fn get_memos_range(
+			start_leaf: u32,
+			count: u32,
+		) -> Vec<(u32, pallet_shielded_pool::Hash, Option<Vec<u8>>)> {
+			ShieldedPool::get_memos_range(start_leaf, count)
+		}
+
+		fn get_historic_roots() -> Vec<(pallet_shielded_pool::Hash, u64)> {
+			ShieldedPool::get_historic_roots()
+		}
+
+		fn list_assets() -> Vec<(u32, pallet_shielded_pool::EncodedAssetMetadata)> {
+			ShieldedPool::list_assets()
+		}
+
+		fn get_pool_stats(asset_id: Option<u32>) -> pallet_shielded_pool::PoolStats {
+			ShieldedPool::get_pool_stats(asset_id)
+		}
+
+		fn get_nullifier_spent_block(nullifier: pallet_shielded_pool::Hash) -> Option<u64> {
+			ShieldedPool::get_nullifier_spent_block(nullifier)
+		}
+
+		fn get_spent_nullifiers() -> Vec<pallet_shielded_pool::Hash> {
+			ShieldedPool::get_spent_nullifiers()
+		}
+
+		fn get_spent_nullifier_count() -> u32 {
+			ShieldedPool::get_spent_nullifier_count()
+		}
+
+		fn validate_transfer(
+			proof: Vec<u8>,
+			merkle_root: pallet_shielded_pool::Hash,
+			nullifiers: Vec<pallet_shielded_pool::Hash>,
+			commitments: Vec<pallet_shielded_pool::Hash>,
+		) -> pallet_shielded_pool::TransferValidation {
+			ShieldedPool::validate_transfer(proof, merkle_root, nullifiers, commitments)
+		}
+
+		fn get_proof_jobs(
+			extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+		) -> Vec<pallet_shielded_pool::ProofJob> {
+			extrinsics.iter().flat_map(|xt| match &xt.0.function {
+				RuntimeCall::ShieldedPool(call) => ShieldedPool::proof_jobs(call),
+				_ => Vec::new()
+			}).collect()
+		}
 	}
 
+	impl pallet_shielded_pool_runtime_api::ShieldedPoolAuditApi<Block, AccountId, Balance, BlockNumber>
+		for Runtime
+	{
+		fn get_audit_policy(
+			account: AccountId,
+		) -> Option<pallet_shielded_pool::AuditPolicy<AccountId, Balance, BlockNumber>> {
+			ShieldedPool::get_audit_policy(account)
+		}
+
+		fn get_disclosure_requests(
+			target: AccountId,
+		) -> Vec<pallet_shielded_pool::DisclosureRequest<AccountId, BlockNumber>> {
+			ShieldedPool::get_disclosure_requests(target)
+		}
+
+		fn get_disclosure_proof(
+			commitment: pallet_shielded_pool::Hash,
+		) -> Option<pallet_shielded_pool::DisclosureProof> {
+			ShieldedPool::get_disclosure_proof(commitment)
+		}
+
+		fn get_audit_trail(
+			trail_hash: pallet_shielded_pool::Hash,
+		) -> Option<pallet_shielded_pool::AuditTrail<AccountId, BlockNumber>> {
+			ShieldedPool::get_audit_trail(trail_hash)
+		}
+
+		fn get_pending_disclosures(
+			target: AccountId,
+		) -> Vec<(pallet_shielded_pool::DisclosureRequest<AccountId, BlockNumber>, BlockNumber)> {
+			ShieldedPool::get_pending_disclosures(target)
+		}
+	}
+
+	#[api_version(2)]
 	impl pallet_zk_verifier_runtime_api::ZkVerifierRuntimeApi<Block> for Runtime {
 		fn get_circuit_version_info(
 			circuit_id: u32,
@@ -1183,6 +1315,24 @@ impl_runtime_apis! {
 				})
 				.collect()
 		}
+
+		fn get_circuit(circuit_id: u32) -> Option<pallet_zk_verifier_runtime_api::CircuitInfo> {
+			pallet_zk_verifier::Pallet::<Runtime>::runtime_api_get_circuit(circuit_id).map(Into::into)
+		}
+
+		fn list_circuits() -> alloc::vec::Vec<pallet_zk_verifier_runtime_api::CircuitInfo> {
+			pallet_zk_verifier::Pallet::<Runtime>::runtime_api_list_circuits()
+				.into_iter()
+				.map(Into::into)
+				.collect()
+		}
+
+		fn get_verification_key(
+			circuit_id: u32,
+			version: Option<u32>,
+		) -> Option<(u32, alloc::vec::Vec<u8>)> {
+			pallet_zk_verifier::Pallet::<Runtime>::runtime_api_get_verification_key(circuit_id, version)
+		}
 	}
 
 	impl pallet_account_mapping_runtime_api::AccountMappingRuntimeApi<Block, AccountId, Balance> for Runtime {
@@ -1293,6 +1443,23 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
+			let weight = Executive::try_runtime_upgrade(checks).unwrap();
+			(weight, BlockWeights::get().max_block)
+		}
+
+		fn execute_block(
+			block: Block,
+			state_root_check: bool,
+			signature_check: bool,
+			select: frame_try_runtime::TryStateSelect,
+		) -> Weight {
+			Executive::try_execute_block(block, state_root_check, signature_check, select).unwrap()
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -91,6 +91,7 @@ export async function startFrontierNode(
 	const cmd = BINARY_PATH;
 	const args = [
 		`--chain=dev`,
+		`--skip-genesis-vks`, // EVM tests need no shielded pool keys
 		`--validator`, // Required by manual sealing to author the blocks
 		`--execution=Native`, // Faster execution using native
 		`--no-telemetry`,